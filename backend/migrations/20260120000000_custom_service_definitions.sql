@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS custom_service_definitions (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    category TEXT NOT NULL,
+    pattern JSONB NOT NULL,
+    is_generic BOOLEAN NOT NULL DEFAULT FALSE,
+    logo_url TEXT NOT NULL DEFAULT '',
+    logo_needs_white_background BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_custom_service_definitions_organization ON custom_service_definitions(organization_id);
+CREATE UNIQUE INDEX idx_custom_service_definitions_org_name ON custom_service_definitions(organization_id, name);
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS tls_certificates (
+    id UUID PRIMARY KEY,
+    port_id UUID NOT NULL REFERENCES ports(id) ON DELETE CASCADE,
+    host_id UUID NOT NULL REFERENCES hosts(id) ON DELETE CASCADE,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    subject TEXT NOT NULL,
+    issuer TEXT NOT NULL,
+    subject_alt_names TEXT[] NOT NULL DEFAULT '{}',
+    serial_number TEXT NOT NULL,
+    not_before TIMESTAMPTZ NOT NULL,
+    not_after TIMESTAMPTZ NOT NULL,
+    key_type TEXT NOT NULL,
+    key_bits INTEGER,
+    is_self_signed BOOLEAN NOT NULL DEFAULT FALSE,
+    fingerprint_sha256 TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(port_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tls_certificates_host ON tls_certificates(host_id);
+CREATE INDEX IF NOT EXISTS idx_tls_certificates_network ON tls_certificates(network_id);
+CREATE INDEX IF NOT EXISTS idx_tls_certificates_not_after ON tls_certificates(not_after);
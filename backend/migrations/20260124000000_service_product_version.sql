@@ -0,0 +1,3 @@
+-- Product and version identified from banners grabbed during discovery
+ALTER TABLE services ADD COLUMN IF NOT EXISTS product TEXT;
+ALTER TABLE services ADD COLUMN IF NOT EXISTS version TEXT;
@@ -0,0 +1,12 @@
+CREATE TABLE IF NOT EXISTS snmp_credentials (
+    id UUID PRIMARY KEY,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    config JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_snmp_credentials_network ON snmp_credentials(network_id);
+
+ALTER TABLE hosts ADD COLUMN IF NOT EXISTS device_info JSONB;
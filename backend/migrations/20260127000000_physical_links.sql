@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS physical_links (
+    id UUID PRIMARY KEY,
+    host_id UUID NOT NULL REFERENCES hosts(id) ON DELETE CASCADE,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    protocol TEXT NOT NULL,
+    local_if_index INTEGER,
+    local_port TEXT,
+    remote_mac_address MACADDR,
+    remote_ip_address INET,
+    remote_system_name TEXT,
+    remote_port TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_physical_links_host ON physical_links(host_id);
+CREATE INDEX IF NOT EXISTS idx_physical_links_network ON physical_links(network_id);
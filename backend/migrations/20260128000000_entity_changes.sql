@@ -0,0 +1,17 @@
+-- Append-only journal of changes to hosts and their children. Rows outlive the entities they
+-- describe, so there are no foreign keys other than the network.
+CREATE TABLE IF NOT EXISTS entity_changes (
+    id UUID PRIMARY KEY,
+    entity_type TEXT NOT NULL,
+    entity_id UUID NOT NULL,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    host_id UUID,
+    operation TEXT NOT NULL,
+    snapshot JSONB NOT NULL,
+    changed_by TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_entity_changes_entity ON entity_changes(entity_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_entity_changes_host ON entity_changes(host_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_entity_changes_network ON entity_changes(network_id, created_at);
@@ -0,0 +1,28 @@
+-- Durable audit log, populated from entity and auth events. Rows outlive the entities, users and
+-- networks they describe, so the organization is the only foreign key.
+CREATE TABLE IF NOT EXISTS audit_logs (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    network_id UUID,
+    category TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    entity_type TEXT,
+    entity_id UUID,
+    actor_type TEXT NOT NULL,
+    actor_id UUID,
+    actor_user_id UUID,
+    actor_name TEXT,
+    ip_address INET,
+    user_agent TEXT,
+    snapshot JSONB,
+    changes JSONB,
+    metadata JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_logs_organization ON audit_logs(organization_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_audit_logs_entity ON audit_logs(entity_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_audit_logs_actor_user ON audit_logs(actor_user_id, created_at);
+
+ALTER TABLE organizations
+    ADD COLUMN IF NOT EXISTS audit_log_retention_days INTEGER NOT NULL DEFAULT 90;
@@ -0,0 +1,12 @@
+-- Results daemons have reported during discovery sessions, so results replayed after a lost
+-- connection are applied once. The stored response is returned to replays.
+CREATE TABLE IF NOT EXISTS discovery_results (
+    id UUID NOT NULL,
+    session_id UUID NOT NULL,
+    network_id UUID NOT NULL REFERENCES networks(id) ON DELETE CASCADE,
+    response JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (session_id, id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_discovery_results_created_at ON discovery_results(created_at);
@@ -0,0 +1 @@
+ALTER TABLE interfaces ADD COLUMN IF NOT EXISTS dhcp_lease JSONB;
@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS scan_exclusions (
+    id UUID PRIMARY KEY,
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    network_id UUID REFERENCES networks(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    rule JSONB NOT NULL,
+    arp_only BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_scan_exclusions_organization ON scan_exclusions(organization_id);
+CREATE INDEX IF NOT EXISTS idx_scan_exclusions_network ON scan_exclusions(network_id);
@@ -0,0 +1 @@
+ALTER TABLE interfaces ADD COLUMN IF NOT EXISTS rtt_ms INTEGER;
@@ -20,6 +20,9 @@ use tower_http::{
 };
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+/// How often to try delivering discovery results queued while the server was unreachable
+const OUTBOX_REPLAY_INTERVAL: Duration = Duration::from_secs(30);
+
 fn main() -> anyhow::Result<()> {
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .thread_stack_size(4 * 1024 * 1024) // 4MB stack for deep async scanning
@@ -60,6 +63,8 @@ async fn async_main() -> anyhow::Result<()> {
     let interval_secs = config_store.get_heartbeat_interval().await?;
     let interval = Duration::from_secs(interval_secs);
     let concurrent_scans = config.concurrent_scans;
+    let passive_discovery = config_store.get_passive_discovery().await?;
+    let passive_deep_scan = config_store.get_passive_deep_scan().await?;
 
     // Startup banner
     tracing::info!("");
@@ -79,6 +84,8 @@ async fn async_main() -> anyhow::Result<()> {
 
     let state = DaemonAppState::new(config_store.clone(), utils).await?;
     let runtime_service = state.services.runtime_service.clone();
+    let discovery_service = state.services.discovery_service.clone();
+    let discovery_manager = state.services.discovery_manager.clone();
 
     // Create HTTP server with config values
     let api_router = create_router().with_state(state);
@@ -126,6 +133,11 @@ async fn async_main() -> anyhow::Result<()> {
     } else {
         tracing::info!("  Concurrent:      {} parallel scans", concurrent_scans);
     }
+    if passive_discovery && passive_deep_scan {
+        tracing::info!("  Passive:         listening, deep scanning new hosts");
+    } else if passive_discovery {
+        tracing::info!("  Passive:         listening, no probing");
+    }
 
     // Initialize services if we have credentials
     if let Some(network_id) = network_id {
@@ -142,6 +154,22 @@ async fn async_main() -> anyhow::Result<()> {
         tracing::info!("Missing network ID - waiting for server to hit /api/initialize...");
     }
 
+    // Replay discovery results queued while the server was unreachable
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(OUTBOX_REPLAY_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = discovery_service.replay_outbox().await {
+                tracing::warn!("Failed to replay queued discovery results: {}", e);
+            }
+        }
+    });
+
+    // Listen for hosts joining between sessions
+    if passive_discovery {
+        tokio::spawn(discovery_manager.run_passive_discovery(passive_deep_scan));
+    }
+
     // Mode-specific ready message and runtime loop
     if mode == DaemonMode::Push {
         tracing::info!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
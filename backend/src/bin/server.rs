@@ -9,7 +9,10 @@ use axum_client_ip::ClientIpSource;
 use clap::Parser;
 use reqwest::header::{self, HeaderName};
 use scanopy::server::{
-    auth::middleware::{logging::request_logging_middleware, rate_limit::rate_limit_middleware},
+    auth::middleware::{
+        logging::request_logging_middleware, rate_limit::rate_limit_middleware,
+        request_context::request_context_middleware,
+    },
     billing::plans::get_purchasable_plans,
     config::{AppState, ServerCli, ServerConfig, get_deployment_type},
     shared::handlers::{cache::AppCache, factory::create_router},
@@ -69,6 +72,13 @@ async fn main() -> anyhow::Result<()> {
     tracing::info!(target: LOG_TARGET, "  Database connected, migrations applied");
     tracing::info!(target: LOG_TARGET, "  Services initialized");
 
+    let custom_definition_count = state
+        .services
+        .custom_service_definition_service
+        .load_registry()
+        .await?;
+    tracing::info!(target: LOG_TARGET, "  Loaded {} custom service definitions", custom_definition_count);
+
     let discovery_service = state.services.discovery_service.clone();
     let billing_service = state.services.billing_service.clone();
     let deployment_type = get_deployment_type(state.clone());
@@ -89,6 +99,13 @@ async fn main() -> anyhow::Result<()> {
                 .discovery_service
                 .cleanup_old_sessions(24)
                 .await;
+
+            // Forget applied daemon results (replays are deduped against these for 7 days)
+            discovery_cleanup_state
+                .services
+                .discovery_service
+                .cleanup_old_results(24 * 7)
+                .await;
         }
     });
 
@@ -126,6 +143,26 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Create audit log retention task
+    let audit_log_service_cleanup = state.services.audit_log_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60 * 60)); // Hourly
+        loop {
+            interval.tick().await;
+            audit_log_service_cleanup.purge_expired().await;
+        }
+    });
+
+    // Resume webhook deliveries left pending, at startup and then periodically
+    let webhook_service_resume = state.services.webhook_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(60)); // Every minute
+        loop {
+            interval.tick().await;
+            webhook_service_resume.resume_pending_deliveries().await;
+        }
+    });
+
     tracing::info!(target: LOG_TARGET, "  Background tasks started");
 
     let (base_router, _openapi) = create_router(state.clone());
@@ -215,6 +252,7 @@ async fn main() -> anyhow::Result<()> {
                 state.clone(),
                 request_logging_middleware,
             ))
+            .layer(middleware::from_fn(request_context_middleware))
             .layer(Extension(app_cache))
             .layer(cache_headers)
             .layer(content_type_options)
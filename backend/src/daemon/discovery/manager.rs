@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
@@ -6,12 +7,24 @@ use tokio_util::sync::CancellationToken;
 use crate::daemon::discovery::service::base::{
     DaemonDiscoveryService, DiscoveryRunner, RunsDiscovery,
 };
+use crate::daemon::discovery::service::dhcp_leases::DhcpLeaseDiscovery;
 use crate::daemon::discovery::service::docker::DockerScanDiscovery;
+use crate::daemon::discovery::service::kubernetes::KubernetesDiscovery;
 use crate::daemon::discovery::service::network::NetworkScanDiscovery;
+use crate::daemon::discovery::service::passive::PassiveDiscovery;
+use crate::daemon::discovery::service::proxmox::ProxmoxDiscovery;
 use crate::daemon::discovery::service::self_report::SelfReportDiscovery;
+use crate::daemon::discovery::service::traceroute::TracerouteDiscovery;
 use crate::daemon::runtime::service::LOG_TARGET;
 use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
 use crate::server::discovery::r#impl::types::DiscoveryType;
+use crate::server::scan_exclusions::r#impl::base::ScanExclusions;
+use uuid::Uuid;
+
+/// Wait before restarting passive discovery after it stops, ie before the daemon is registered.
+/// Doubled each time it stops again soon after starting, up to the max.
+const PASSIVE_RESTART_DELAY: Duration = Duration::from_secs(60);
+const MAX_PASSIVE_RESTART_DELAY: Duration = Duration::from_secs(30 * 60);
 
 pub struct DaemonDiscoverySessionManager {
     current_task: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
@@ -35,6 +48,15 @@ impl DaemonDiscoverySessionManager {
             "Initiating discovery"
         );
 
+        // Passive discovery runs alongside sessions for as long as the daemon does
+        if request.discovery_type == DiscoveryType::Passive {
+            tracing::warn!(
+                session_id = %request.session_id,
+                "Passive discovery is enabled in the daemon config, not started as a session"
+            );
+            return;
+        }
+
         let cancel_token = self.start_new_session().await;
 
         let handle = match &request.discovery_type {
@@ -50,11 +72,12 @@ impl DaemonDiscoverySessionManager {
             DiscoveryType::Docker {
                 host_id,
                 host_naming_fallback,
+                endpoint,
             } => self.clone().spawn_discovery(
                 DiscoveryRunner::new(
                     self.discovery_service.clone(),
                     self.clone(),
-                    DockerScanDiscovery::new(*host_id, *host_naming_fallback),
+                    DockerScanDiscovery::new(*host_id, *host_naming_fallback, endpoint.clone()),
                 ),
                 request.clone(),
                 cancel_token,
@@ -62,20 +85,135 @@ impl DaemonDiscoverySessionManager {
             DiscoveryType::Network {
                 subnet_ids,
                 host_naming_fallback,
+                scan_profile_id,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    NetworkScanDiscovery::new(
+                        subnet_ids.clone(),
+                        *host_naming_fallback,
+                        *scan_profile_id,
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::DhcpLeases {
+                path,
+                format,
+                host_naming_fallback,
             } => self.clone().spawn_discovery(
                 DiscoveryRunner::new(
                     self.discovery_service.clone(),
                     self.clone(),
-                    NetworkScanDiscovery::new(subnet_ids.clone(), *host_naming_fallback),
+                    DhcpLeaseDiscovery::new(path.into(), *format, *host_naming_fallback),
                 ),
                 request.clone(),
                 cancel_token,
             ),
+            DiscoveryType::Traceroute {
+                targets,
+                include_remote_subnets,
+                protocol,
+                max_hops,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    TracerouteDiscovery::new(
+                        targets.clone(),
+                        *include_remote_subnets,
+                        *protocol,
+                        *max_hops,
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Kubernetes {
+                kubeconfig_path,
+                context,
+                host_naming_fallback,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    KubernetesDiscovery::new(
+                        kubeconfig_path.clone(),
+                        context.clone(),
+                        *host_naming_fallback,
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Proxmox {
+                url,
+                token_id,
+                token_secret_path,
+                allow_self_signed_certs,
+            } => self.clone().spawn_discovery(
+                DiscoveryRunner::new(
+                    self.discovery_service.clone(),
+                    self.clone(),
+                    ProxmoxDiscovery::new(
+                        url.clone(),
+                        token_id.clone(),
+                        token_secret_path.into(),
+                        *allow_self_signed_certs,
+                    ),
+                ),
+                request.clone(),
+                cancel_token,
+            ),
+            DiscoveryType::Passive => return,
         };
 
         self.set_current_task(handle).await;
     }
 
+    /// Listen for hosts passively for as long as the daemon runs. It has its own session slot,
+    /// so it doesn't hold up or get cancelled with the sessions the server starts.
+    pub async fn run_passive_discovery(self: Arc<Self>, deep_scan: bool) {
+        let runner = DiscoveryRunner::new(
+            Arc::new(self.discovery_service.detached()),
+            self.clone(),
+            PassiveDiscovery::new(deep_scan),
+        );
+        let cancel = CancellationToken::new();
+        let mut delay = PASSIVE_RESTART_DELAY;
+
+        loop {
+            let request = DaemonDiscoveryRequest {
+                session_id: Uuid::new_v4(),
+                discovery_type: DiscoveryType::Passive,
+                exclusions: ScanExclusions::default(),
+            };
+
+            // Listening only ends when something went wrong, so it's always restarted
+            let started = std::time::Instant::now();
+            let stopped = match runner.discover(request, cancel.clone()).await {
+                Ok(()) => "listeners closed".to_string(),
+                Err(e) => e.to_string(),
+            };
+
+            // A run that lasted a while failed for a new reason; start backing off afresh
+            if started.elapsed() >= MAX_PASSIVE_RESTART_DELAY {
+                delay = PASSIVE_RESTART_DELAY;
+            }
+
+            tracing::warn!(
+                retry_secs = delay.as_secs(),
+                "Passive discovery stopped: {}",
+                stopped
+            );
+
+            tokio::time::sleep(delay).await;
+            delay = std::cmp::min(delay * 2, MAX_PASSIVE_RESTART_DELAY);
+        }
+    }
+
     fn spawn_discovery<T>(
         self: Arc<Self>,
         discovery: DiscoveryRunner<T>,
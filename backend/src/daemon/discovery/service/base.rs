@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     net::IpAddr,
     sync::{
         Arc,
@@ -9,11 +10,19 @@ use std::{
 use crate::{
     daemon::{
         discovery::{manager::DaemonDiscoverySessionManager, types::base::DiscoveryCriticalError},
-        shared::api_client::DaemonApiClient,
+        shared::{
+            api_client::DaemonApiClient,
+            outbox::{DiscoveryOutbox, OutboxEntry, assigned_ids, replace_ids},
+        },
+        utils::scanner::AdaptiveScanControl,
     },
     server::{
+        custom_service_definitions::r#impl::{
+            base::CustomServiceDefinition, definition::to_runtime_definitions,
+        },
         discovery::r#impl::types::{DiscoveryType, HostNamingFallback},
         groups::r#impl::base::Group,
+        scan_exclusions::r#impl::base::ScanExclusions,
         services::{
             definitions::{docker_container::DockerContainer, open_ports::OpenPorts},
             r#impl::{
@@ -22,7 +31,9 @@ use crate::{
                     ServiceMatchServiceParams,
                 },
                 patterns::MatchConfidence,
-                virtualization::{DockerVirtualization, ServiceVirtualization},
+                virtualization::{
+                    DockerVirtualization, KubernetesVirtualization, ServiceVirtualization,
+                },
             },
         },
         shared::types::entities::{DiscoveryMetadata, EntitySource},
@@ -31,6 +42,7 @@ use crate::{
 use anyhow::{Error, anyhow};
 use async_trait::async_trait;
 use chrono::Utc;
+use serde::{Serialize, de::DeserializeOwned};
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
 
@@ -49,6 +61,7 @@ use crate::{
             base::{Host, HostBase},
         },
         interfaces::r#impl::base::Interface,
+        physical_links::r#impl::base::PhysicalLink,
         ports::r#impl::base::{Port, PortType},
         services::{
             definitions::{ServiceDefinitionRegistry, gateway::Gateway},
@@ -59,6 +72,7 @@ use crate::{
         },
         shared::types::metadata::HasId,
         subnets::r#impl::base::Subnet,
+        tls_certificates::r#impl::base::TlsCertificate,
     },
 };
 
@@ -86,15 +100,25 @@ impl<T> DiscoveryRunner<T> {
 pub struct DiscoverySession {
     pub info: DiscoverySessionInfo,
     pub gateway_ips: Vec<IpAddr>,
+    /// Hosts the session must not probe
+    pub exclusions: ScanExclusions,
+    /// TCP scan parameters adapted per subnet over the session
+    pub scan_control: Arc<AdaptiveScanControl>,
     pub last_progress: Arc<AtomicU8>,
     pub last_progress_report_time: Arc<AtomicU64>,
 }
 
 impl DiscoverySession {
-    pub fn new(info: DiscoverySessionInfo, gateway_ips: Vec<IpAddr>) -> Self {
+    pub fn new(
+        info: DiscoverySessionInfo,
+        gateway_ips: Vec<IpAddr>,
+        exclusions: ScanExclusions,
+    ) -> Self {
         Self {
             info,
             gateway_ips,
+            exclusions,
+            scan_control: Arc::new(AdaptiveScanControl::default()),
             last_progress: Arc::new(AtomicU8::new(0)),
             last_progress_report_time: Arc::new(AtomicU64::new(0)),
         }
@@ -112,16 +136,120 @@ pub struct DaemonDiscoveryService {
     pub api_client: Arc<DaemonApiClient>,
     pub utils: PlatformDaemonUtils,
     pub current_session: Arc<RwLock<Option<DiscoverySession>>>,
+    /// Results the server couldn't be reached for, waiting to be replayed
+    pub outbox: Arc<DiscoveryOutbox>,
+    /// IDs the server assigned to replayed results in place of the daemon's. A running session
+    /// still holds the daemon's IDs, so results it sends later are rewritten with these. The
+    /// daemon's IDs are random, so entries from past sessions never match anything.
+    pub assigned_ids: Arc<RwLock<HashMap<Uuid, Uuid>>>,
 }
 
 impl DaemonDiscoveryService {
     pub fn new(config_store: Arc<ConfigStore>) -> Self {
         Self {
             api_client: Arc::new(DaemonApiClient::new(config_store.clone())),
+            outbox: Arc::new(DiscoveryOutbox::new(config_store.outbox_dir())),
             config_store,
             utils: create_system_utils(),
             current_session: Arc::new(RwLock::new(None)),
+            assigned_ids: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// A service sharing this one's client and outbox, with a session slot of its own for
+    /// discovery that runs alongside sessions
+    pub fn detached(&self) -> Self {
+        Self {
+            config_store: self.config_store.clone(),
+            api_client: self.api_client.clone(),
+            utils: create_system_utils(),
+            current_session: Arc::new(RwLock::new(None)),
+            outbox: self.outbox.clone(),
+            assigned_ids: self.assigned_ids.clone(),
+        }
+    }
+
+    /// Send a result of the current session to the server. If the server can't be reached, or
+    /// earlier results are still queued, the result is queued to be replayed and `offline` is
+    /// returned in place of the server's response.
+    pub async fn submit_result<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+        context: &str,
+        offline: impl FnOnce() -> T,
+    ) -> Result<T, Error> {
+        let session_id = self.get_session().await?.info.session_id;
+        let mut entry = OutboxEntry::new(session_id, path, serde_json::to_value(body)?);
+
+        // Held until the result is sent or queued, so a replay can't assign IDs in between
+        let assigned_ids = self.assigned_ids.read().await;
+        replace_ids(&mut entry.body, &assigned_ids);
+
+        // Results are replayed in order, as later ones can refer to earlier ones
+        if self.outbox.is_empty().await {
+            match self
+                .api_client
+                .post_discovery_result(&entry, context, ENTITY_CREATION_MAX_RETRIES)
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(e) if !DaemonApiClient::is_retriable_error(&e) => return Err(e),
+                Err(e) => {
+                    tracing::warn!(
+                        session_id = %session_id,
+                        error = %e,
+                        "Server unreachable, queueing discovery results until it's back"
+                    );
+                }
+            }
+        }
+
+        self.outbox.push(&entry).await?;
+        Ok(offline())
+    }
+
+    /// Deliver queued results, oldest first, stopping at the first that still can't be. Returns
+    /// how many were delivered.
+    pub async fn replay_outbox(&self) -> Result<usize, Error> {
+        let mut delivered = 0;
+
+        for (path, entry) in self.outbox.pending().await? {
+            let result: Result<serde_json::Value, Error> = self
+                .api_client
+                .post_discovery_result(&entry, "Failed to replay discovery result", 0)
+                .await;
+
+            match result {
+                Ok(response) => {
+                    let ids = assigned_ids(&entry.body, &response);
+                    if !ids.is_empty() {
+                        let mut live_ids = self.assigned_ids.write().await;
+                        self.outbox.replace_ids(&ids).await?;
+                        live_ids.extend(ids);
+                    }
+                    delivered += 1;
+                }
+                Err(e) if DaemonApiClient::is_retriable_error(&e) => break,
+                Err(e) => {
+                    // Replaying it again won't change the answer
+                    tracing::warn!(
+                        session_id = %entry.session_id,
+                        path = %entry.path,
+                        error = %e,
+                        "Server rejected queued discovery result, dropping it"
+                    );
+                }
+            }
+
+            self.outbox.remove(&path).await?;
+        }
+
+        if delivered > 0 {
+            tracing::info!("Delivered {} queued discovery results", delivered);
         }
+
+        Ok(delivered)
     }
 
     pub async fn get_session(&self) -> Result<DiscoverySession, Error> {
@@ -194,21 +322,33 @@ pub trait RunsDiscovery: AsRef<DaemonDiscoveryService> + Send + Sync {
         let session = self.as_ref().get_session().await?;
         let discovery_type = self.discovery_type();
 
-        let payload = DiscoveryUpdatePayload::from_state_and_update(
+        let finished = update.finished_at.is_some();
+        let mut payload = DiscoveryUpdatePayload::from_state_and_update(
             discovery_type,
             session.info.clone(),
             update,
         );
+        if finished {
+            payload.scan_tuning = session.scan_control.summary();
+        }
 
         let path = format!("/api/v1/discovery/{}/update", session.info.session_id);
 
-        // Progress updates are non-critical - log errors but don't fail discovery
-        if let Err(e) = self
-            .as_ref()
-            .api_client
-            .post_no_data(&path, &payload, "Failed to report discovery update")
-            .await
-        {
+        let result = if finished {
+            // How the session ended is queued behind its results when the server can't be
+            // reached, or the server would give up on the session and never apply them
+            self.as_ref()
+                .submit_result(&path, &payload, "Failed to report discovery update", || ())
+                .await
+        } else {
+            // Progress updates are non-critical - log errors but don't fail discovery
+            self.as_ref()
+                .api_client
+                .post_no_data(&path, &payload, "Failed to report discovery update")
+                .await
+        };
+
+        if let Err(e) = result {
             tracing::warn!(
                 session_id = %session.info.session_id,
                 error = %e,
@@ -258,7 +398,7 @@ pub trait DiscoversNetworkedEntities:
             started_at: Some(Utc::now()),
         };
 
-        let session = DiscoverySession::new(session_info, gateway_ips);
+        let session = DiscoverySession::new(session_info, gateway_ips, request.exclusions);
 
         let mut current_session = self.as_ref().current_session.write().await;
         *current_session = Some(session);
@@ -266,6 +406,50 @@ pub trait DiscoversNetworkedEntities:
         Ok(())
     }
 
+    /// Fetch the exclusions on the daemon's network, for scans that don't come with a discovery
+    /// request's own. Unlike other settings there is no fallback; better not to scan at all than
+    /// to scan a host that's excluded.
+    async fn get_scan_exclusions(&self) -> Result<ScanExclusions, Error> {
+        self.as_ref()
+            .api_client
+            .get(
+                "/api/v1/scan-exclusions/resolved",
+                "Failed to get scan exclusions",
+            )
+            .await
+    }
+
+    /// Fetch the organization's custom service definitions so they are matched alongside the
+    /// built-in ones. Failures are non-fatal; discovery continues with whatever is registered.
+    async fn sync_custom_service_definitions(&self) {
+        let result: Result<Vec<CustomServiceDefinition>, Error> = self
+            .as_ref()
+            .api_client
+            .get(
+                "/api/v1/custom-service-definitions",
+                "Failed to get custom service definitions",
+            )
+            .await;
+
+        match result {
+            Ok(definitions) => {
+                let runtime_definitions = to_runtime_definitions(&definitions);
+
+                tracing::debug!(
+                    count = runtime_definitions.len(),
+                    "Loaded custom service definitions"
+                );
+                // Every definition is the daemon's organization's
+                let organization_id = definitions.first().map(|d| d.base.organization_id);
+                ServiceDefinitionRegistry::replace_matching(organization_id, runtime_definitions);
+            }
+            Err(e) => tracing::warn!(
+                error = %e,
+                "Could not fetch custom service definitions, using previously loaded set"
+            ),
+        }
+    }
+
     async fn start_discovery(&self, request: DaemonDiscoveryRequest) -> Result<(), Error> {
         let daemon_id = self.as_ref().config_store.get_id().await?;
 
@@ -278,6 +462,8 @@ pub trait DiscoversNetworkedEntities:
         self.initialize_discovery_session(request, daemon_id)
             .await?;
 
+        self.sync_custom_service_definitions().await;
+
         self.report_discovery_update(DiscoverySessionUpdate {
             phase: DiscoveryPhase::Started,
             progress: 0,
@@ -407,6 +593,7 @@ pub trait DiscoversNetworkedEntities:
             },
             virtualization: None,
             hidden: false,
+            device_info: None,
         });
 
         // Store interfaces separately to pass to server
@@ -514,11 +701,15 @@ pub trait DiscoversNetworkedEntities:
                 && !container_matched
             {
                 // If a container was matched w the provided virtualization, no others can be matched
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_id: Some(_),
-                    ..
-                })) = &service.base.virtualization
-                {
+                if matches!(
+                    &service.base.virtualization,
+                    Some(ServiceVirtualization::Docker(DockerVirtualization {
+                        container_id: Some(_),
+                        ..
+                    })) | Some(ServiceVirtualization::Kubernetes(
+                        KubernetesVirtualization { pod: Some(_), .. }
+                    ))
+                ) {
                     container_matched = true
                 }
 
@@ -569,57 +760,58 @@ pub trait CreatesDiscoveredEntities:
         interfaces: Vec<Interface>,
         ports: Vec<Port>,
         services: Vec<Service>,
+        certificates: Vec<TlsCertificate>,
+        physical_links: Option<Vec<PhysicalLink>>,
     ) -> Result<HostResponse, Error> {
         let request = DiscoveryHostRequest {
             host,
             interfaces,
             ports,
             services,
+            certificates,
+            physical_links,
         };
         self.as_ref()
-            .api_client
-            .post_with_retry(
+            .submit_result(
                 "/api/v1/hosts/discovery",
                 &request,
                 "Failed to create host",
-                ENTITY_CREATION_MAX_RETRIES,
+                || {
+                    HostResponse::from_host_with_children(
+                        request.host.clone(),
+                        request.interfaces.clone(),
+                        request.ports.clone(),
+                        request.services.clone(),
+                    )
+                },
             )
             .await
     }
 
     async fn create_subnet(&self, subnet: &Subnet) -> Result<Subnet, Error> {
         self.as_ref()
-            .api_client
-            .post_with_retry(
-                "/api/v1/subnets",
-                subnet,
-                "Failed to create subnet",
-                ENTITY_CREATION_MAX_RETRIES,
-            )
+            .submit_result("/api/v1/subnets", subnet, "Failed to create subnet", || {
+                subnet.clone()
+            })
             .await
     }
 
     async fn create_service(&self, service: &Service) -> Result<Service, Error> {
         self.as_ref()
-            .api_client
-            .post_with_retry(
+            .submit_result(
                 "/api/v1/services",
                 service,
                 "Failed to create service",
-                ENTITY_CREATION_MAX_RETRIES,
+                || service.clone(),
             )
             .await
     }
 
     async fn create_group(&self, group: &Group) -> Result<Group, Error> {
         self.as_ref()
-            .api_client
-            .post_with_retry(
-                "/api/v1/groups",
-                group,
-                "Failed to create group",
-                ENTITY_CREATION_MAX_RETRIES,
-            )
+            .submit_result("/api/v1/groups", group, "Failed to create group", || {
+                group.clone()
+            })
             .await
     }
 }
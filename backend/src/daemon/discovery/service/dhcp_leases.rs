@@ -0,0 +1,181 @@
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::{DaemonUtils, resolve_in_directory};
+use crate::daemon::utils::dhcp_leases::{self, LeaseEntry};
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DhcpLeaseFormat, DiscoveryType, HostNamingFallback};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::services::r#impl::base::ServiceMatchBaselineParams;
+use crate::server::subnets::r#impl::base::{Subnet, most_specific_subnet};
+
+pub struct DhcpLeaseDiscovery {
+    path: PathBuf,
+    format: DhcpLeaseFormat,
+    host_naming_fallback: HostNamingFallback,
+}
+
+impl DhcpLeaseDiscovery {
+    pub fn new(
+        path: PathBuf,
+        format: DhcpLeaseFormat,
+        host_naming_fallback: HostNamingFallback,
+    ) -> Self {
+        Self {
+            path,
+            format,
+            host_naming_fallback,
+        }
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<DhcpLeaseDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<DhcpLeaseDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::DhcpLeases {
+            path: self.domain.path.to_string_lossy().into_owned(),
+            format: self.domain.format,
+            host_naming_fallback: self.domain.host_naming_fallback,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.import_leases(cancel.clone()).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<DhcpLeaseDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Leases are placed on the subnets the server already knows about. The DHCP server's
+    /// scopes may not be ones the daemon has an interface on.
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<DhcpLeaseDiscovery> {
+    async fn import_leases(&self, cancel: CancellationToken) -> Result<(), Error> {
+        let lease_dir = self
+            .as_ref()
+            .config_store
+            .get_dhcp_lease_dir()
+            .await?
+            .ok_or_else(|| {
+                anyhow!(
+                    "Lease imports are disabled on this daemon; set --dhcp-lease-dir to enable them"
+                )
+            })?;
+        let path = resolve_in_directory(&lease_dir, &self.domain.path, "lease").await?;
+
+        let contents = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|e| anyhow!("Could not read lease file {}: {}", path.display(), e))?;
+        let leases = dhcp_leases::parse(&contents, self.domain.format, Utc::now())?;
+        let subnets = self.discover_create_subnets().await?;
+
+        tracing::info!(
+            path = %path.display(),
+            leases = leases.len(),
+            "Importing DHCP leases"
+        );
+
+        let total = leases.len();
+        let mut skipped = 0;
+
+        for (index, lease) in leases.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            let Some(subnet) = most_specific_subnet(lease.ip, &subnets) else {
+                skipped += 1;
+                continue;
+            };
+
+            if let Err(e) = self.report_lease(lease, subnet).await {
+                tracing::warn!(ip = %lease.ip, error = %e, "Failed to import DHCP lease");
+            }
+
+            let _ = self
+                .report_scanning_progress(((index + 1) * 100 / total).min(99) as u8)
+                .await;
+        }
+
+        if skipped > 0 {
+            tracing::info!(
+                skipped,
+                "Skipped DHCP leases outside every subnet on the network"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn report_lease(&self, entry: &LeaseEntry, subnet: &Subnet) -> Result<(), Error> {
+        let interface = Interface::new(InterfaceBase {
+            network_id: subnet.base.network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            name: None,
+            subnet_id: subnet.id,
+            ip_address: entry.ip,
+            mac_address: entry.mac,
+            position: 0,
+            dhcp_lease: Some(entry.lease.clone()),
+            rtt_ms: None,
+        });
+
+        if let Some((host, interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &interface,
+                    all_ports: &vec![],
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &None,
+                },
+                entry.lease.client_hostname.clone(),
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        {
+            self.create_host(host, interfaces, ports, services, Vec::new(), None)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@ use async_trait::async_trait;
 use bollard::{
     Docker,
     query_parameters::{InspectContainerOptions, ListContainersOptions, ListNetworksOptions},
-    secret::{ContainerInspectResponse, ContainerSummary, PortTypeEnum},
+    secret::{
+        ContainerInspectResponse, ContainerSummary, HealthStatusEnum, PortTypeEnum,
+        RestartPolicyNameEnum,
+    },
 };
 use cidr::IpCidr;
 use futures::future::try_join_all;
@@ -12,16 +15,24 @@ use futures::stream::{self, StreamExt};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::{collections::HashMap, net::IpAddr, sync::OnceLock};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    net::IpAddr,
+    sync::OnceLock,
+};
 use strum::IntoDiscriminant;
 use tokio_util::sync::CancellationToken;
 
 use crate::daemon::discovery::service::base::RunsDiscovery;
 use crate::daemon::discovery::types::base::DiscoverySessionUpdate;
 use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::dependencies::{Reference, container_references};
 use crate::daemon::utils::scanner::scan_endpoints;
 use crate::server::bindings::r#impl::base::{Binding, BindingDiscriminants};
-use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::discovery::r#impl::types::{DiscoveryType, DockerEndpoint, HostNamingFallback};
+use crate::server::groups::r#impl::base::{Group, GroupBase};
+use crate::server::groups::r#impl::types::GroupType;
+use crate::server::hosts::r#impl::api::HostResponse;
 use crate::server::hosts::r#impl::base::HostBase;
 use crate::server::interfaces::r#impl::base::ALL_INTERFACES_IP;
 use crate::server::ports::r#impl::base::Port;
@@ -30,7 +41,8 @@ use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use crate::server::services::r#impl::patterns::MatchDetails;
 use crate::server::services::r#impl::virtualization::{
-    DockerVirtualization, ServiceVirtualization,
+    DockerHealthStatus, DockerRestartPolicy, DockerStack, DockerStackKind, DockerVirtualization,
+    ServiceVirtualization,
 };
 use crate::server::shared::storage::traits::Storable;
 use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
@@ -56,6 +68,8 @@ pub struct DockerScanDiscovery {
     docker_client: OnceLock<Docker>,
     host_id: Uuid,
     host_naming_fallback: HostNamingFallback,
+    /// Remote engine on the host with `host_id`; the daemon's own engine when not set
+    endpoint: Option<Box<DockerEndpoint>>,
 }
 
 pub struct ProcessContainerParams<'a> {
@@ -72,6 +86,7 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
         DiscoveryType::Docker {
             host_id: self.domain.host_id,
             host_naming_fallback: self.domain.host_naming_fallback,
+            endpoint: self.domain.endpoint.clone(),
         }
     }
 
@@ -88,14 +103,25 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
             .await?
             .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
 
-        let docker_proxy = self.as_ref().config_store.get_docker_proxy().await;
-        let docker_proxy_ssl_info = self.as_ref().config_store.get_docker_proxy_ssl_info().await;
-
-        let docker = self
-            .as_ref()
-            .utils
-            .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
-            .await?;
+        let docker = match &self.domain.endpoint {
+            Some(endpoint) => {
+                let endpoint_dir = self.as_ref().config_store.get_docker_endpoint_dir().await?;
+                self.as_ref()
+                    .utils
+                    .new_remote_docker_client(endpoint, endpoint_dir)
+                    .await?
+            }
+            None => {
+                let docker_proxy = self.as_ref().config_store.get_docker_proxy().await;
+                let docker_proxy_ssl_info =
+                    self.as_ref().config_store.get_docker_proxy_ssl_info().await;
+
+                self.as_ref()
+                    .utils
+                    .new_local_docker_client(docker_proxy, docker_proxy_ssl_info)
+                    .await?
+            }
+        };
         self.domain
             .docker_client
             .set(docker.clone())
@@ -109,16 +135,8 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
         let subnets = self.discover_create_subnets().await?;
 
         // Get host interfaces (needed for docker daemon service host matching)
-        let interface_filter = self.as_ref().config_store.get_interface_filter().await?;
-        let (mut host_interfaces, _, _) = self
-            .as_ref()
-            .utils
-            .get_own_interfaces(
-                self.discovery_type(),
-                daemon_id,
-                network_id,
-                &interface_filter,
-            )
+        let (mut host_interfaces, _) = self
+            .get_host_interfaces_and_subnets(daemon_id, network_id)
             .await?;
 
         // Update interface subnet IDs to match created subnets (they may differ if subnets already existed)
@@ -141,10 +159,28 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
         // Get container info
         let containers = self.get_containers_and_summaries().await?;
 
+        // Containers reachable from outside the host, which front the stacks they're part of
+        let published_container_ids: HashSet<String> = containers
+            .iter()
+            .filter(|(_, summary)| {
+                summary
+                    .ports
+                    .iter()
+                    .flatten()
+                    .any(|p| p.public_port.is_some())
+            })
+            .filter_map(|(container, _)| container.id.clone())
+            .collect();
+
         // Combine host interfaces + subnets to get a map of containers to the interfaces they have + subnets those interfaces are for
         let containers_interfaces_and_subnets =
             self.get_container_interfaces(&containers, &subnets, &mut host_interfaces);
 
+        // Kept for reading the containers' configuration once their services are known
+        let container_details: Vec<ContainerInspectResponse> =
+            containers.iter().map(|(c, _)| c.clone()).collect();
+        let host_ips: Vec<IpAddr> = host_interfaces.iter().map(|i| i.base.ip_address).collect();
+
         let discovered_hosts_services = self
             .scan_and_process_containers(
                 cancel.clone(),
@@ -160,6 +196,11 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
                 discovered = %container_data.len(),
                 "Docker scan complete"
             );
+
+            self.create_stack_groups(container_data, &published_container_ids)
+                .await;
+            self.create_dependency_groups(container_data, &container_details, &host_ips)
+                .await;
         }
 
         let discovery_result = if discovered_hosts_services.is_ok() {
@@ -176,11 +217,16 @@ impl RunsDiscovery for DiscoveryRunner<DockerScanDiscovery> {
 }
 
 impl DockerScanDiscovery {
-    pub fn new(host_id: Uuid, host_naming_fallback: HostNamingFallback) -> Self {
+    pub fn new(
+        host_id: Uuid,
+        host_naming_fallback: HostNamingFallback,
+        endpoint: Option<Box<DockerEndpoint>>,
+    ) -> Self {
         Self {
             docker_client: OnceLock::new(),
             host_id,
             host_naming_fallback,
+            endpoint,
         }
     }
 }
@@ -230,16 +276,8 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<DockerScanDiscovery> {
             .await?
             .ok_or_else(|| anyhow::anyhow!("Network ID not set"))?;
 
-        let interface_filter = self.as_ref().config_store.get_interface_filter().await?;
-        let (_, host_subnets, _) = self
-            .as_ref()
-            .utils
-            .get_own_interfaces(
-                self.discovery_type(),
-                daemon_id,
-                network_id,
-                &interface_filter,
-            )
+        let (_, host_subnets) = self
+            .get_host_interfaces_and_subnets(daemon_id, network_id)
             .await?;
 
         let docker = self
@@ -275,6 +313,51 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<DockerScanDiscovery> {
 }
 
 impl DiscoveryRunner<DockerScanDiscovery> {
+    /// Interfaces and subnets of the host the engine runs on. The daemon reads its own host's;
+    /// a remote engine's host has the interfaces the server already knows about, ie from a
+    /// network scan or that host's own daemon.
+    async fn get_host_interfaces_and_subnets(
+        &self,
+        daemon_id: Uuid,
+        network_id: Uuid,
+    ) -> Result<(Vec<Interface>, Vec<Subnet>), Error> {
+        if self.domain.endpoint.is_none() {
+            let interface_filter = self.as_ref().config_store.get_interface_filter().await?;
+            let (interfaces, subnets, _) = self
+                .as_ref()
+                .utils
+                .get_own_interfaces(
+                    self.discovery_type(),
+                    daemon_id,
+                    network_id,
+                    &interface_filter,
+                )
+                .await?;
+            return Ok((interfaces, subnets));
+        }
+
+        let host: HostResponse = self
+            .as_ref()
+            .api_client
+            .get(
+                &format!("/api/v1/hosts/{}", self.domain.host_id),
+                "Failed to get Docker endpoint's host",
+            )
+            .await?;
+
+        let subnet_ids: HashSet<Uuid> = host.interfaces.iter().map(|i| i.base.subnet_id).collect();
+        let subnets: Vec<Subnet> = self
+            .as_ref()
+            .api_client
+            .get::<Vec<Subnet>>("/api/v1/subnets", "Failed to get subnets")
+            .await?
+            .into_iter()
+            .filter(|s| subnet_ids.contains(&s.id))
+            .collect();
+
+        Ok((host.interfaces, subnets))
+    }
+
     /// Create docker daemon service which has container relationship with docker daemon service
     /// Takes host_interfaces to enable proper host matching via MAC/IP addresses
     pub async fn create_docker_daemon_service(
@@ -301,12 +384,20 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             tags: Vec::new(),
             network_id,
             virtualization: None,
-            source: EntitySource::DiscoveryWithMatch {
-                metadata: vec![DiscoveryMetadata::new(
-                    DiscoveryType::SelfReport { host_id },
-                    daemon_id,
-                )],
-                details: MatchDetails::new_certain("Docker daemon self-report"),
+            product: None,
+            version: None,
+            source: match &self.domain.endpoint {
+                Some(_) => EntitySource::DiscoveryWithMatch {
+                    metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                    details: MatchDetails::new_certain("Docker engine API endpoint"),
+                },
+                None => EntitySource::DiscoveryWithMatch {
+                    metadata: vec![DiscoveryMetadata::new(
+                        DiscoveryType::SelfReport { host_id },
+                        daemon_id,
+                    )],
+                    details: MatchDetails::new_certain("Docker daemon self-report"),
+                },
             },
             position: 0,
         });
@@ -321,6 +412,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             },
             virtualization: None,
             hidden: false,
+            device_info: None,
             tags: Vec::new(),
         });
         temp_docker_daemon_host.id = self.domain.host_id;
@@ -332,6 +424,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 host_interfaces.to_vec(),
                 vec![], // No ports for docker daemon host
                 vec![docker_service],
+                vec![],
+                None,
             )
             .await?;
 
@@ -344,7 +438,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         containers: Vec<(ContainerInspectResponse, ContainerSummary)>,
         containers_interfaces_and_subnets: &HashMap<String, Vec<(Interface, Subnet)>>,
         docker_service_id: &Uuid,
-    ) -> Result<Vec<(Host, Vec<Service>)>> {
+    ) -> Result<Vec<HostResponse>> {
         let total_containers = containers.len();
 
         self.report_scanning_progress(0).await?;
@@ -393,7 +487,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             }
 
             match result {
-                Ok(Some((host, services))) => all_container_data.push((host, services)),
+                Ok(Some(host_response)) => all_container_data.push(host_response),
                 Ok(None) => {}
                 Err(e) => {
                     tracing::warn!(
@@ -411,7 +505,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
     async fn process_single_container(
         &self,
         params: &ProcessContainerParams<'_>,
-    ) -> Result<Option<(Host, Vec<Service>)>> {
+    ) -> Result<Option<HostResponse>> {
         let ProcessContainerParams {
             container,
             container_summary,
@@ -456,7 +550,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         &self,
         params: &ProcessContainerParams<'_>,
         container_id: &String,
-    ) -> Result<Option<(Host, Vec<Service>)>> {
+    ) -> Result<Option<HostResponse>> {
         let ProcessContainerParams {
             containers_interfaces_and_subnets,
             container,
@@ -473,7 +567,26 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 .unwrap_or(&"Unknown Container Name".to_string())
         );
 
-        let host_ip = self.as_ref().utils.get_own_ip_address()?;
+        let empty_vec_ref = &vec![];
+
+        let container_interfaces_and_subnets = containers_interfaces_and_subnets
+            .get(container_id)
+            .unwrap_or(empty_vec_ref);
+
+        // A remote engine's host mode containers listen on that engine's host, not the daemon's
+        let host_ip = match &self.domain.endpoint {
+            None => self.as_ref().utils.get_own_ip_address()?,
+            Some(_) => match container_interfaces_and_subnets.first() {
+                Some((interface, _)) => interface.base.ip_address,
+                None => {
+                    tracing::info!(
+                        host_id = %self.domain.host_id,
+                        "Skipped host mode container; the engine's host has no known interfaces"
+                    );
+                    return Ok(None);
+                }
+            },
+        };
 
         if let Some(Some(p)) = container.config.as_ref().map(|c| c.exposed_ports.as_ref()) {
             let open_ports: Vec<PortType> = p
@@ -495,26 +608,19 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             .map_err(|e| anyhow!("Scan task panicked: {}", e))?
             .map_err(|e| anyhow!("Endpoint scanning error: {}", e))?;
 
-            let empty_vec_ref = &vec![];
-
-            let container_interfaces_and_subnets = containers_interfaces_and_subnets
-                .get(container_id)
-                .unwrap_or(empty_vec_ref);
-
             for (interface, subnet) in container_interfaces_and_subnets {
                 let params = ServiceMatchBaselineParams {
                     subnet,
                     interface,
                     all_ports: &open_ports,
                     endpoint_responses: &endpoint_responses,
-                    virtualization: &Some(ServiceVirtualization::Docker(DockerVirtualization {
-                        container_name: container
-                            .name
-                            .clone()
-                            .map(|n| n.trim_start_matches("/").to_string()),
-                        container_id: container.id.clone(),
-                        service_id: **docker_service_id,
-                    })),
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &Some(ServiceVirtualization::Docker(container_virtualization(
+                        container,
+                        **docker_service_id,
+                    ))),
                 };
 
                 if let Ok(Some((mut host, interfaces, ports, services))) = self
@@ -523,13 +629,11 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 {
                     host.id = self.domain.host_id;
 
-                    if let Ok(host_response) =
-                        self.create_host(host, interfaces, ports, services).await
+                    if let Ok(host_response) = self
+                        .create_host(host, interfaces, ports, services, vec![], None)
+                        .await
                     {
-                        return Ok::<Option<(Host, Vec<Service>)>, Error>(Some((
-                            host_response.to_host(),
-                            host_response.services,
-                        )));
+                        return Ok::<Option<HostResponse>, Error>(Some(host_response));
                     }
                     return Ok(None);
                 }
@@ -542,7 +646,7 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         &self,
         params: &ProcessContainerParams<'_>,
         container_id: &String,
-    ) -> Result<Option<(Host, Vec<Service>)>> {
+    ) -> Result<Option<HostResponse>> {
         let ProcessContainerParams {
             containers_interfaces_and_subnets,
             container,
@@ -606,15 +710,11 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                         interface,
                         all_ports: container_ports_on_interface,
                         endpoint_responses: &endpoint_responses,
+                        banner_responses: &vec![],
+                        mdns_advertisements: &vec![],
+                        upnp_devices: &vec![],
                         virtualization: &Some(ServiceVirtualization::Docker(
-                            DockerVirtualization {
-                                container_name: container
-                                    .name
-                                    .clone()
-                                    .map(|n| n.trim_start_matches("/").to_string()),
-                                container_id: container.id.clone(),
-                                service_id: **docker_service_id,
-                            },
+                            container_virtualization(container, **docker_service_id),
                         )),
                     },
                     None,
@@ -780,13 +880,10 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                 });
 
                 if let Ok(host_response) = self
-                    .create_host(host, interfaces, ports, services.clone())
+                    .create_host(host, interfaces, ports, services.clone(), vec![], None)
                     .await
                 {
-                    return Ok::<Option<(Host, Vec<Service>)>, Error>(Some((
-                        host_response.to_host(),
-                        host_response.services,
-                    )));
+                    return Ok::<Option<HostResponse>, Error>(Some(host_response));
                 }
                 return Ok(None);
             }
@@ -795,6 +892,239 @@ impl DiscoveryRunner<DockerScanDiscovery> {
         Ok(None)
     }
 
+    /// Create a group for each Compose project or Swarm stack, so the containers of a multi-container
+    /// app are shown together. A container with published ports is the hub, since that's where
+    /// requests into the app arrive.
+    async fn create_stack_groups(
+        &self,
+        container_data: &[HostResponse],
+        published_container_ids: &HashSet<String>,
+    ) {
+        let Ok(Some(network_id)) = self.as_ref().config_store.get_network_id().await else {
+            return;
+        };
+        let Ok(daemon_id) = self.as_ref().config_store.get_id().await else {
+            return;
+        };
+
+        // Services are returned for the whole host, so each container's appears once per
+        // container on it. Keep one service with a binding per container.
+        let mut containers_by_stack: BTreeMap<
+            (DockerStackKind, String),
+            BTreeMap<String, Service>,
+        > = BTreeMap::new();
+        for service in container_data.iter().flat_map(|h| &h.services) {
+            let Some(ServiceVirtualization::Docker(DockerVirtualization {
+                container_id: Some(container_id),
+                stack: Some(stack),
+                ..
+            })) = &service.base.virtualization
+            else {
+                continue;
+            };
+            if service.base.bindings.is_empty() {
+                continue;
+            }
+
+            containers_by_stack
+                .entry((stack.kind, stack.name.clone()))
+                .or_default()
+                .entry(container_id.clone())
+                .or_insert_with(|| service.clone());
+        }
+
+        for ((kind, name), containers) in containers_by_stack {
+            if containers.len() < 2 {
+                continue;
+            }
+
+            let mut members: Vec<(&String, &Service)> = containers.iter().collect();
+            members.sort_by_key(|(container_id, service)| {
+                (
+                    !published_container_ids.contains(*container_id),
+                    service.base.name.clone(),
+                )
+            });
+
+            let label = match kind {
+                DockerStackKind::Compose => "Compose project",
+                DockerStackKind::Swarm => "Swarm stack",
+            };
+
+            let group = Group::new(GroupBase {
+                name: name.clone(),
+                network_id,
+                description: Some(format!("{} {}", label, name)),
+                group_type: GroupType::HubAndSpoke,
+                binding_ids: members
+                    .iter()
+                    .filter_map(|(_, s)| s.base.bindings.first())
+                    .map(|b| b.id())
+                    .collect(),
+                source: EntitySource::Discovery {
+                    metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                },
+                ..Default::default()
+            });
+
+            if let Err(e) = self.create_group(&group).await {
+                tracing::warn!(stack = %name, error = %e, "Failed to create stack group");
+            }
+        }
+    }
+
+    /// Propose a request path group for each service a container's configuration points to,
+    /// ie from an app to the database its DATABASE_URL names. References are resolved against
+    /// the names and addresses of the other containers, and the host's published ports, then
+    /// against the hostnames and addresses of the hosts the server already knows about.
+    async fn create_dependency_groups(
+        &self,
+        container_data: &[HostResponse],
+        containers: &[ContainerInspectResponse],
+        host_ips: &[IpAddr],
+    ) {
+        let Ok(Some(network_id)) = self.as_ref().config_store.get_network_id().await else {
+            return;
+        };
+        let Ok(daemon_id) = self.as_ref().config_store.get_id().await else {
+            return;
+        };
+
+        // Each response is for the whole host, so a service or port may be in several
+        let services: HashMap<Uuid, &Service> = container_data
+            .iter()
+            .flat_map(|h| &h.services)
+            .map(|s| (s.id, s))
+            .collect();
+        let port_numbers: HashMap<Uuid, u16> = container_data
+            .iter()
+            .flat_map(|h| &h.ports)
+            .map(|p| (p.id, p.base.port_type.number()))
+            .collect();
+
+        let mut services_by_container: HashMap<&str, Vec<&Service>> = HashMap::new();
+        for service in services.values() {
+            if let Some(ServiceVirtualization::Docker(DockerVirtualization {
+                container_id: Some(container_id),
+                ..
+            })) = &service.base.virtualization
+                && !service.base.bindings.is_empty()
+            {
+                services_by_container
+                    .entry(container_id.as_str())
+                    .or_default()
+                    .push(service);
+            }
+        }
+        for container_services in services_by_container.values_mut() {
+            container_services.sort_by_key(|s| (s.base.name.clone(), s.id));
+        }
+
+        let resolver = ContainerResolver::new(containers, host_ips);
+        let mut proposed: HashSet<(&str, &str)> = HashSet::new();
+        let mut proposed_hosts: HashSet<(&str, Uuid)> = HashSet::new();
+        // Loaded on the first reference no container answers to
+        let mut known_hosts: Option<Vec<HostResponse>> = None;
+
+        for source in containers {
+            let Some(source_id) = source.id.as_deref() else {
+                continue;
+            };
+            let Some(source_binding) = services_by_container
+                .get(source_id)
+                .and_then(|s| s.first())
+                .and_then(|s| s.base.bindings.first())
+            else {
+                continue;
+            };
+            let source_name = container_display_name(source);
+
+            for reference in dependency_references(source) {
+                let (target_binding, target_name) = match resolver.resolve(&reference, source) {
+                    Some(target) => {
+                        let Some(target_id) = target.id.as_deref() else {
+                            continue;
+                        };
+                        if target_id == source_id || !proposed.insert((source_id, target_id)) {
+                            continue;
+                        }
+
+                        let Some(target_services) = services_by_container.get(target_id) else {
+                            continue;
+                        };
+                        let bindings = || target_services.iter().flat_map(|s| &s.base.bindings);
+                        let Some(target_binding) = bindings()
+                            .find(|b| {
+                                reference.port.is_some()
+                                    && b.port_id().and_then(|id| port_numbers.get(&id).copied())
+                                        == reference.port
+                            })
+                            .or_else(|| bindings().next())
+                        else {
+                            continue;
+                        };
+
+                        (target_binding, container_display_name(target))
+                    }
+                    None => {
+                        if known_hosts.is_none() {
+                            known_hosts = Some(self.get_known_hosts().await);
+                        }
+                        let Some((host, target_binding)) = known_host_binding(
+                            known_hosts.as_deref().unwrap_or_default(),
+                            &reference,
+                        ) else {
+                            continue;
+                        };
+                        if !proposed_hosts.insert((source_id, host.id)) {
+                            continue;
+                        }
+
+                        (target_binding, host.name.clone())
+                    }
+                };
+                let reason = format!(
+                    "{} in {}'s configuration refers to {}",
+                    reference.evidence, source_name, target_name
+                );
+
+                let group = Group::new(GroupBase {
+                    name: format!("{} → {}", source_name, target_name),
+                    network_id,
+                    description: Some(reason.clone()),
+                    group_type: GroupType::RequestPath,
+                    binding_ids: vec![source_binding.id(), target_binding.id()],
+                    source: EntitySource::Inferred {
+                        metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                        reason,
+                    },
+                    ..Default::default()
+                });
+
+                if let Err(e) = self.create_group(&group).await {
+                    tracing::warn!(
+                        group = %group.base.name,
+                        error = %e,
+                        "Failed to create dependency group"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Hosts on the network, for references to services outside the containers on this host.
+    /// Dependency groups are a best effort, so a failure only leaves those unresolved.
+    async fn get_known_hosts(&self) -> Vec<HostResponse> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/hosts", "Failed to get hosts")
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Could not load hosts to resolve dependencies");
+                Vec::new()
+            })
+    }
+
     pub async fn get_containers_to_scan(&self) -> Result<Vec<ContainerSummary>, Error> {
         let docker = self
             .domain
@@ -1203,6 +1533,8 @@ impl DiscoveryRunner<DockerScanDiscovery> {
                                                 mac_address,
                                                 name: Some(network_name.to_owned()),
                                                 position: 0,
+                                                dhcp_lease: None,
+                                                rtt_ms: None,
                                             }),
                                             subnet.clone(),
                                         ));
@@ -1235,3 +1567,441 @@ impl DiscoveryRunner<DockerScanDiscovery> {
             .collect()
     }
 }
+
+/// What Docker knows about a container beyond its network: its image, labels, health, restart
+/// policy, and the Compose project or Swarm stack it belongs to
+fn container_virtualization(
+    container: &ContainerInspectResponse,
+    docker_service_id: Uuid,
+) -> DockerVirtualization {
+    let labels = container_labels(container);
+
+    let (image, image_tag) = container
+        .config
+        .as_ref()
+        .and_then(|c| c.image.as_deref())
+        .map(split_image_reference)
+        .unwrap_or_default();
+
+    let health = container
+        .state
+        .as_ref()
+        .and_then(|s| s.health.as_ref())
+        .and_then(|h| h.status)
+        .and_then(|status| match status {
+            HealthStatusEnum::STARTING => Some(DockerHealthStatus::Starting),
+            HealthStatusEnum::HEALTHY => Some(DockerHealthStatus::Healthy),
+            HealthStatusEnum::UNHEALTHY => Some(DockerHealthStatus::Unhealthy),
+            HealthStatusEnum::EMPTY | HealthStatusEnum::NONE => None,
+        });
+
+    let restart_policy = container
+        .host_config
+        .as_ref()
+        .and_then(|c| c.restart_policy.as_ref())
+        .and_then(|p| p.name)
+        .and_then(|name| match name {
+            RestartPolicyNameEnum::NO => Some(DockerRestartPolicy::No),
+            RestartPolicyNameEnum::ALWAYS => Some(DockerRestartPolicy::Always),
+            RestartPolicyNameEnum::UNLESS_STOPPED => Some(DockerRestartPolicy::UnlessStopped),
+            RestartPolicyNameEnum::ON_FAILURE => Some(DockerRestartPolicy::OnFailure),
+            RestartPolicyNameEnum::EMPTY => None,
+        });
+
+    DockerVirtualization {
+        container_name: container
+            .name
+            .clone()
+            .map(|n| n.trim_start_matches("/").to_string()),
+        container_id: container.id.clone(),
+        service_id: docker_service_id,
+        image,
+        image_tag,
+        stack: container_stack(&labels),
+        labels,
+        health,
+        restart_policy,
+    }
+}
+
+/// Finds the container a reference in another container's configuration points to
+struct ContainerResolver<'a> {
+    /// Container names, hostnames and network aliases, lowercased
+    by_name: HashMap<String, &'a ContainerInspectResponse>,
+    /// Compose service names, which are only unique within their project
+    by_compose_service: HashMap<(String, String), &'a ContainerInspectResponse>,
+    by_ip: HashMap<IpAddr, &'a ContainerInspectResponse>,
+    /// Containers by the host port they publish
+    by_published_port: HashMap<u16, &'a ContainerInspectResponse>,
+    host_ips: &'a [IpAddr],
+}
+
+impl<'a> ContainerResolver<'a> {
+    fn new(containers: &'a [ContainerInspectResponse], host_ips: &'a [IpAddr]) -> Self {
+        let mut resolver = Self {
+            by_name: HashMap::new(),
+            by_compose_service: HashMap::new(),
+            by_ip: HashMap::new(),
+            by_published_port: HashMap::new(),
+            host_ips,
+        };
+
+        for container in containers {
+            let endpoints = container
+                .network_settings
+                .as_ref()
+                .and_then(|n| n.networks.as_ref())
+                .into_iter()
+                .flat_map(|n| n.values());
+
+            let names = container
+                .name
+                .iter()
+                .map(|n| n.trim_start_matches('/').to_string())
+                .chain(container.config.as_ref().and_then(|c| c.hostname.clone()))
+                .chain(
+                    endpoints
+                        .clone()
+                        .flat_map(|e| e.aliases.iter().chain(e.dns_names.iter()).flatten())
+                        .cloned(),
+                );
+            for name in names {
+                resolver
+                    .by_name
+                    .entry(name.to_lowercase())
+                    .or_insert(container);
+            }
+
+            for ip in endpoints.filter_map(|e| e.ip_address.as_deref()?.parse().ok()) {
+                resolver.by_ip.insert(ip, container);
+            }
+
+            if let Some(DockerStack {
+                kind: DockerStackKind::Compose,
+                name: project,
+                service: Some(service),
+            }) = container_stack(&container_labels(container))
+            {
+                resolver
+                    .by_compose_service
+                    .insert((project, service.to_lowercase()), container);
+            }
+
+            let published_ports = container
+                .network_settings
+                .as_ref()
+                .and_then(|n| n.ports.as_ref())
+                .into_iter()
+                .flat_map(|ports| ports.values().flatten().flatten())
+                .filter_map(|b| b.host_port.as_deref()?.parse().ok());
+            for port in published_ports {
+                resolver.by_published_port.insert(port, container);
+            }
+        }
+
+        resolver
+    }
+
+    fn resolve(
+        &self,
+        reference: &Reference,
+        source: &ContainerInspectResponse,
+    ) -> Option<&'a ContainerInspectResponse> {
+        if let Ok(ip) = reference.host.parse::<IpAddr>() {
+            if let Some(container) = self.by_ip.get(&ip) {
+                return Some(container);
+            }
+            // The host's own address reaches the container publishing the port
+            if self.host_ips.contains(&ip) {
+                return self.by_published_port.get(&reference.port?).copied();
+            }
+            return None;
+        }
+
+        let host = reference.host.to_lowercase();
+        if let Some(DockerStack {
+            kind: DockerStackKind::Compose,
+            name: project,
+            ..
+        }) = container_stack(&container_labels(source))
+            && let Some(container) = self.by_compose_service.get(&(project, host.clone()))
+        {
+            return Some(container);
+        }
+
+        self.by_name.get(&host).copied()
+    }
+}
+
+/// The host a reference names by address or hostname, and the binding on it for the port
+/// the reference gives. References without a port take the host's first service binding.
+fn known_host_binding<'a>(
+    hosts: &'a [HostResponse],
+    reference: &Reference,
+) -> Option<(&'a HostResponse, &'a Binding)> {
+    let host = match reference.host.parse::<IpAddr>() {
+        Ok(ip) => hosts
+            .iter()
+            .find(|h| h.interfaces.iter().any(|i| i.base.ip_address == ip))?,
+        Err(_) => hosts.iter().find(|h| {
+            h.hostname.as_deref().is_some_and(|hostname| {
+                hostname.eq_ignore_ascii_case(&reference.host)
+                    || hostname
+                        .split('.')
+                        .next()
+                        .is_some_and(|label| label.eq_ignore_ascii_case(&reference.host))
+            }) || h.name.eq_ignore_ascii_case(&reference.host)
+        })?,
+    };
+
+    let mut services: Vec<&Service> = host.services.iter().collect();
+    services.sort_by_key(|s| (s.base.name.clone(), s.id));
+    let mut bindings = services.into_iter().flat_map(|s| &s.base.bindings);
+
+    let binding = match reference.port {
+        Some(port) => bindings.find(|b| {
+            b.port_id().is_some_and(|id| {
+                host.ports
+                    .iter()
+                    .any(|p| p.id == id && p.base.port_type.number() == port)
+            })
+        }),
+        None => bindings.next(),
+    }?;
+
+    Some((host, binding))
+}
+
+fn container_labels(container: &ContainerInspectResponse) -> BTreeMap<String, String> {
+    container
+        .config
+        .as_ref()
+        .and_then(|c| c.labels.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .collect()
+}
+
+fn container_display_name(container: &ContainerInspectResponse) -> String {
+    container
+        .name
+        .as_deref()
+        .map(|n| n.trim_start_matches('/').to_string())
+        .or_else(|| container.id.clone())
+        .unwrap_or_default()
+}
+
+/// References a container's env, command, Compose labels and links make to other services
+fn dependency_references(container: &ContainerInspectResponse) -> Vec<Reference> {
+    let config = container.config.as_ref();
+    container_references(
+        &config.and_then(|c| c.env.clone()).unwrap_or_default(),
+        &config.and_then(|c| c.cmd.clone()).unwrap_or_default(),
+        &container_labels(container),
+        &container
+            .host_config
+            .as_ref()
+            .and_then(|c| c.links.clone())
+            .unwrap_or_default(),
+    )
+}
+
+/// Compose project or Swarm stack named by a container's labels
+fn container_stack(labels: &BTreeMap<String, String>) -> Option<DockerStack> {
+    if let Some(project) = labels.get("com.docker.compose.project") {
+        return Some(DockerStack {
+            kind: DockerStackKind::Compose,
+            name: project.clone(),
+            service: labels.get("com.docker.compose.service").cloned(),
+        });
+    }
+
+    let stack = labels.get("com.docker.stack.namespace")?;
+    // Swarm service names are prefixed with the stack, ie "monitoring_grafana"
+    let service = labels.get("com.docker.swarm.service.name").map(|s| {
+        s.strip_prefix(&format!("{}_", stack))
+            .unwrap_or(s)
+            .to_string()
+    });
+
+    Some(DockerStack {
+        kind: DockerStackKind::Swarm,
+        name: stack.clone(),
+        service,
+    })
+}
+
+/// Split an image reference, ie "ghcr.io/org/app:1.2@sha256:...", into repository and tag. The
+/// tag is what follows the last colon after the last slash, since registries can have ports.
+fn split_image_reference(reference: &str) -> (Option<String>, Option<String>) {
+    let reference = reference.split('@').next().unwrap_or(reference);
+    let name_start = reference.rfind('/').map(|i| i + 1).unwrap_or(0);
+
+    match reference[name_start..].rfind(':') {
+        Some(colon) => {
+            let colon = name_start + colon;
+            (
+                Some(reference[..colon].to_string()),
+                Some(reference[colon + 1..].to_string()),
+            )
+        }
+        None if reference.is_empty() => (None, None),
+        None => (Some(reference.to_string()), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::secret::{ContainerConfig, ContainerState, Health, HostConfig, RestartPolicy};
+
+    #[test]
+    fn test_split_image_reference() {
+        assert_eq!(
+            split_image_reference("registry.lan:5000/org/app:1.2@sha256:abc"),
+            (
+                Some("registry.lan:5000/org/app".to_string()),
+                Some("1.2".to_string())
+            )
+        );
+        assert_eq!(
+            split_image_reference("registry.lan:5000/org/app"),
+            (Some("registry.lan:5000/org/app".to_string()), None)
+        );
+        assert_eq!(
+            split_image_reference("postgres:16"),
+            (Some("postgres".to_string()), Some("16".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_container_stack_from_labels() {
+        let labels = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+
+        assert_eq!(
+            container_stack(&labels(&[
+                ("com.docker.compose.project", "immich"),
+                ("com.docker.compose.service", "database"),
+            ])),
+            Some(DockerStack {
+                kind: DockerStackKind::Compose,
+                name: "immich".to_string(),
+                service: Some("database".to_string()),
+            })
+        );
+        assert_eq!(
+            container_stack(&labels(&[
+                ("com.docker.stack.namespace", "monitoring"),
+                ("com.docker.swarm.service.name", "monitoring_grafana"),
+            ])),
+            Some(DockerStack {
+                kind: DockerStackKind::Swarm,
+                name: "monitoring".to_string(),
+                service: Some("grafana".to_string()),
+            })
+        );
+        assert_eq!(container_stack(&labels(&[("maintainer", "me")])), None);
+    }
+
+    #[test]
+    fn test_container_virtualization() {
+        let container = ContainerInspectResponse {
+            id: Some("abc123".to_string()),
+            name: Some("/immich_server".to_string()),
+            config: Some(ContainerConfig {
+                image: Some("ghcr.io/immich-app/immich-server:release".to_string()),
+                labels: Some(HashMap::from([(
+                    "com.docker.compose.project".to_string(),
+                    "immich".to_string(),
+                )])),
+                ..Default::default()
+            }),
+            state: Some(ContainerState {
+                health: Some(Health {
+                    status: Some(HealthStatusEnum::HEALTHY),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::UNLESS_STOPPED),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let virtualization = container_virtualization(&container, Uuid::nil());
+
+        assert_eq!(
+            virtualization.container_name.as_deref(),
+            Some("immich_server")
+        );
+        assert_eq!(
+            virtualization.image.as_deref(),
+            Some("ghcr.io/immich-app/immich-server")
+        );
+        assert_eq!(virtualization.image_tag.as_deref(), Some("release"));
+        assert_eq!(virtualization.health, Some(DockerHealthStatus::Healthy));
+        assert_eq!(
+            virtualization.restart_policy,
+            Some(DockerRestartPolicy::UnlessStopped)
+        );
+        assert_eq!(
+            virtualization.stack.map(|s| s.name),
+            Some("immich".to_string())
+        );
+        assert_eq!(virtualization.labels.len(), 1);
+    }
+
+    #[test]
+    fn test_unresolved_references_fall_back_to_known_hosts() {
+        let network_id = Uuid::new_v4();
+        let host = crate::tests::host(&network_id);
+        let interface = crate::tests::interface(&network_id, &Uuid::new_v4());
+        let ssh = Port::new_hostless(PortType::new_tcp(22));
+        let postgres = Port::new_hostless(PortType::new_tcp(5432));
+        let mut service = crate::tests::service(&network_id, &host.id);
+        service.base.bindings = vec![
+            Binding::new_port_serviceless(ssh.id, None),
+            Binding::new_port_serviceless(postgres.id, None),
+        ];
+        let postgres_binding = service.base.bindings[1].id();
+        let hosts = [HostResponse::from_host_with_children(
+            host,
+            vec![interface],
+            vec![ssh, postgres],
+            vec![service],
+        )];
+
+        let reference = |host: &str, port: Option<u16>| Reference {
+            host: host.to_string(),
+            port,
+            evidence: "DATABASE_URL".to_string(),
+        };
+        let binding_for = |host: &str, port: Option<u16>| {
+            known_host_binding(&hosts, &reference(host, port)).map(|(_, b)| b.id())
+        };
+
+        assert_eq!(
+            binding_for("192.168.1.100", Some(5432)),
+            Some(postgres_binding)
+        );
+        assert_eq!(
+            binding_for("test.local", Some(5432)),
+            Some(postgres_binding)
+        );
+        assert_eq!(binding_for("TEST", Some(5432)), Some(postgres_binding));
+        assert!(binding_for("test", None).is_some());
+        assert_eq!(binding_for("test", Some(3306)), None);
+        assert_eq!(binding_for("db.lan", Some(5432)), None);
+        assert_eq!(binding_for("10.0.0.9", Some(5432)), None);
+    }
+}
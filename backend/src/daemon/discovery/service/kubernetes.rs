@@ -0,0 +1,1179 @@
+use anyhow::{Error, Result, anyhow};
+use async_trait::async_trait;
+use cidr::IpCidr;
+use futures::future::try_join_all;
+use k8s_openapi::api::core::v1::{Node, Pod, Service as KubeService};
+use k8s_openapi::api::networking::v1::Ingress;
+use k8s_openapi::api::networking::v1beta1::ServiceCIDR;
+use kube::Client;
+use kube::api::{Api, ListParams};
+use kube::config::{KubeConfigOptions, Kubeconfig};
+use std::collections::{BTreeMap, HashMap};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::{DaemonUtils, resolve_in_directory};
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::groups::r#impl::base::{Group, GroupBase};
+use crate::server::groups::r#impl::types::GroupType;
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::{Port, PortType};
+use crate::server::services::definitions::kubernetes_ingress::KubernetesIngress;
+use crate::server::services::definitions::kubernetes_service::KubernetesService;
+use crate::server::services::r#impl::base::{Service, ServiceBase, ServiceMatchBaselineParams};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::services::r#impl::virtualization::{
+    KubernetesVirtualization, ServiceVirtualization,
+};
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::{Subnet, SubnetBase, most_specific_subnet};
+use crate::server::subnets::r#impl::types::SubnetType;
+
+/// Longest prefixes inferred for service networks the cluster doesn't report, so a cluster
+/// with few Services doesn't get a subnet that fits only them
+const MAX_INFERRED_PREFIX_V4: u8 = 24;
+const MAX_INFERRED_PREFIX_V6: u8 = 112;
+
+pub struct KubernetesDiscovery {
+    kubeconfig_path: Option<String>,
+    context: Option<String>,
+    host_naming_fallback: HostNamingFallback,
+    cluster: OnceLock<ClusterSnapshot>,
+}
+
+impl KubernetesDiscovery {
+    pub fn new(
+        kubeconfig_path: Option<String>,
+        context: Option<String>,
+        host_naming_fallback: HostNamingFallback,
+    ) -> Self {
+        Self {
+            kubeconfig_path,
+            context,
+            host_naming_fallback,
+            cluster: OnceLock::new(),
+        }
+    }
+
+    /// Connect with the configured kubeconfig, or else the in-cluster service account or
+    /// default kubeconfig, whichever the environment provides. Returns the client and the host
+    /// of the API server, which names the cluster.
+    async fn connect(
+        &self,
+        kubeconfig_dir: Option<PathBuf>,
+        allow_exec: bool,
+    ) -> Result<(Client, String)> {
+        let options = KubeConfigOptions {
+            context: self.context.clone(),
+            ..Default::default()
+        };
+
+        let config = match &self.kubeconfig_path {
+            Some(path) => {
+                let kubeconfig_dir = kubeconfig_dir.ok_or_else(|| {
+                    anyhow!(
+                        "Kubeconfig files are disabled on this daemon; set --kubeconfig-dir to enable them"
+                    )
+                })?;
+                let kubeconfig =
+                    read_kubeconfig(&kubeconfig_dir, Path::new(path), allow_exec).await?;
+                kube::Config::from_custom_kubeconfig(kubeconfig, &options).await?
+            }
+            None if self.context.is_some() => kube::Config::from_kubeconfig(&options).await?,
+            None => kube::Config::infer().await?,
+        };
+
+        let name = config
+            .cluster_url
+            .host()
+            .unwrap_or("Kubernetes")
+            .to_string();
+
+        Ok((Client::try_from(config)?, name))
+    }
+}
+
+/// Read a kubeconfig from the daemon's kubeconfig directory. Credential plugins run commands on
+/// the daemon, so kubeconfigs naming one are refused unless the daemon allows them. Errors don't
+/// carry the parser's message, which can quote the file.
+async fn read_kubeconfig(dir: &Path, requested: &Path, allow_exec: bool) -> Result<Kubeconfig> {
+    let path = resolve_in_directory(dir, requested, "kubeconfig").await?;
+    let kubeconfig = Kubeconfig::read_from(&path)
+        .map_err(|_| anyhow!("{} is not a valid kubeconfig", requested.display()))?;
+
+    if !allow_exec
+        && kubeconfig
+            .auth_infos
+            .iter()
+            .filter_map(|named| named.auth_info.as_ref())
+            .any(|auth| auth.exec.is_some() || auth.auth_provider.is_some())
+    {
+        return Err(anyhow!(
+            "{} uses a credential plugin; set --allow-kubeconfig-exec to allow it",
+            requested.display()
+        ));
+    }
+
+    Ok(kubeconfig)
+}
+
+/// The cluster objects discovery works from, read once at the start of a run
+struct ClusterSnapshot {
+    /// Host of the API server, to name the cluster by
+    name: String,
+    nodes: Vec<Node>,
+    pods: Vec<Pod>,
+    services: Vec<KubeService>,
+    ingresses: Vec<Ingress>,
+    service_cidrs: Vec<IpCidr>,
+}
+
+impl ClusterSnapshot {
+    async fn fetch(client: &Client, name: String) -> Result<Self> {
+        let params = ListParams::default();
+        let nodes = Api::<Node>::all(client.clone()).list(&params).await?.items;
+        let pods = Api::<Pod>::all(client.clone()).list(&params).await?.items;
+        let services = Api::<KubeService>::all(client.clone())
+            .list(&params)
+            .await?
+            .items;
+        let ingresses = Api::<Ingress>::all(client.clone())
+            .list(&params)
+            .await?
+            .items;
+
+        // ServiceCIDR objects only exist on recent clusters; older ones don't expose their
+        // service range, so it's inferred from the addresses in use
+        let service_cidrs = match Api::<ServiceCIDR>::all(client.clone()).list(&params).await {
+            Ok(list) => list
+                .items
+                .iter()
+                .filter_map(|s| s.spec.as_ref()?.cidrs.clone())
+                .flatten()
+                .filter_map(|c| IpCidr::from_str(&c).ok())
+                .collect(),
+            Err(e) => {
+                tracing::debug!(error = %e, "ServiceCIDRs unavailable, inferring service network");
+                let cluster_ips: Vec<IpAddr> = services.iter().flat_map(cluster_ips).collect();
+                [false, true]
+                    .into_iter()
+                    .filter_map(|ipv6| {
+                        let family: Vec<IpAddr> = cluster_ips
+                            .iter()
+                            .filter(|ip| ip.is_ipv6() == ipv6)
+                            .copied()
+                            .collect();
+                        let max_len = if ipv6 {
+                            MAX_INFERRED_PREFIX_V6
+                        } else {
+                            MAX_INFERRED_PREFIX_V4
+                        };
+                        covering_cidr(&family, max_len)
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(Self {
+            name,
+            nodes,
+            pods,
+            services,
+            ingresses,
+            service_cidrs,
+        })
+    }
+
+    /// Pod and service networks, with the names their subnets are created with
+    fn cidrs(&self) -> Vec<(IpCidr, String)> {
+        let pod_cidrs = self.nodes.iter().flat_map(|node| {
+            let node_name = node.metadata.name.clone().unwrap_or_default();
+            let spec = node.spec.as_ref();
+            let cidrs = spec
+                .and_then(|s| s.pod_cidrs.clone())
+                .or_else(|| Some(vec![spec?.pod_cidr.clone()?]))
+                .unwrap_or_default();
+            cidrs
+                .into_iter()
+                .filter_map(|c| IpCidr::from_str(&c).ok())
+                .map(move |cidr| (cidr, format!("Pods on {}", node_name)))
+        });
+
+        let service_cidrs = self
+            .service_cidrs
+            .iter()
+            .map(|cidr| (*cidr, format!("{} Services", self.name)));
+
+        pod_cidrs.chain(service_cidrs).collect()
+    }
+}
+
+/// An ingress rule path and the Service it routes to
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IngressRoute {
+    /// Host and path, ie "shop.example.com/api"
+    name: String,
+    service: String,
+    port: Option<u16>,
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<KubernetesDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<KubernetesDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Kubernetes {
+            kubeconfig_path: self.domain.kubeconfig_path.clone(),
+            context: self.domain.context.clone(),
+            host_naming_fallback: self.domain.host_naming_fallback,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let kubeconfig_dir = self.as_ref().config_store.get_kubeconfig_dir().await?;
+        let allow_exec = self
+            .as_ref()
+            .config_store
+            .get_allow_kubeconfig_exec()
+            .await?;
+        let (client, name) = self.domain.connect(kubeconfig_dir, allow_exec).await?;
+        let cluster = ClusterSnapshot::fetch(&client, name).await?;
+
+        tracing::info!(
+            cluster = %cluster.name,
+            nodes = cluster.nodes.len(),
+            pods = cluster.pods.len(),
+            services = cluster.services.len(),
+            ingresses = cluster.ingresses.len(),
+            "Read Kubernetes cluster"
+        );
+
+        self.domain
+            .cluster
+            .set(cluster)
+            .map_err(|_| anyhow!("Failed to set Kubernetes cluster"))?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.import_cluster(cancel.clone()).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<KubernetesDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Nodes are placed on the subnets the server already knows about. Pod and service
+    /// networks are created from the cluster.
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let mut subnets: Vec<Subnet> = self
+            .as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await?;
+
+        let cluster_subnets: Vec<Subnet> = self
+            .cluster()?
+            .cidrs()
+            .into_iter()
+            .filter(|(cidr, _)| !subnets.iter().any(|s| s.base.cidr == *cidr))
+            .map(|(cidr, name)| {
+                Subnet::new(SubnetBase {
+                    cidr,
+                    network_id,
+                    name,
+                    description: None,
+                    subnet_type: SubnetType::Kubernetes,
+                    source: EntitySource::Discovery {
+                        metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                    },
+                    tags: Vec::new(),
+                })
+            })
+            .collect();
+
+        let subnet_futures = cluster_subnets.iter().map(|s| self.create_subnet(s));
+        subnets.extend(try_join_all(subnet_futures).await?);
+
+        Ok(subnets)
+    }
+}
+
+impl DiscoveryRunner<KubernetesDiscovery> {
+    fn cluster(&self) -> Result<&ClusterSnapshot> {
+        self.domain
+            .cluster
+            .get()
+            .ok_or_else(|| anyhow!("Kubernetes cluster unavailable"))
+    }
+
+    async fn import_cluster(&self, cancel: CancellationToken) -> Result<(), Error> {
+        let cluster = self.cluster()?;
+        let subnets = self.discover_create_subnets().await?;
+
+        // Nodes, by name, with the host they were created as and its interfaces
+        let mut nodes: HashMap<String, (Uuid, Vec<Interface>)> = HashMap::new();
+        for node in &cluster.nodes {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            let name = node.metadata.name.clone().unwrap_or_default();
+            match self.report_node(node, &subnets).await {
+                Ok(Some(created)) => {
+                    nodes.insert(name, created);
+                }
+                Ok(None) => tracing::info!(
+                    node = %name,
+                    "Skipped Kubernetes node outside every subnet on the network"
+                ),
+                Err(e) => tracing::warn!(node = %name, error = %e, "Failed to import node"),
+            }
+        }
+        let _ = self.report_scanning_progress(20).await;
+
+        // Services created for each pod, by namespace and name
+        let mut pod_services: HashMap<(String, String), Vec<Service>> = HashMap::new();
+        let total = cluster.pods.len().max(1);
+        for (index, pod) in cluster.pods.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            match self.report_pod(pod, &nodes, &subnets).await {
+                Ok(Some(services)) => {
+                    pod_services.insert(object_key(&pod.metadata), services);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    pod = ?pod.metadata.name,
+                    error = %e,
+                    "Failed to import pod"
+                ),
+            }
+
+            let _ = self
+                .report_scanning_progress((20 + (index + 1) * 60 / total) as u8)
+                .await;
+        }
+
+        let (cluster_services, cluster_ports) =
+            self.report_cluster_services(cluster, &subnets).await?;
+        let _ = self.report_scanning_progress(90).await;
+
+        self.report_request_paths(cluster, &cluster_services, &cluster_ports, &pod_services)
+            .await;
+
+        Ok(())
+    }
+
+    /// Create a node's host, with an interface for each of its addresses on a known subnet
+    async fn report_node(
+        &self,
+        node: &Node,
+        subnets: &[Subnet],
+    ) -> Result<Option<(Uuid, Vec<Interface>)>> {
+        let addresses: Vec<IpAddr> = node
+            .status
+            .as_ref()
+            .and_then(|s| s.addresses.as_ref())
+            .into_iter()
+            .flatten()
+            .filter(|a| a.type_ == "InternalIP" || a.type_ == "ExternalIP")
+            .filter_map(|a| IpAddr::from_str(&a.address).ok())
+            .collect();
+
+        let interfaces: Vec<(Interface, &Subnet)> = addresses
+            .iter()
+            .filter_map(|ip| Some((*ip, most_specific_subnet(*ip, subnets)?)))
+            .enumerate()
+            .map(|(position, (ip, subnet))| {
+                (new_interface(subnet, ip, position as i32, None), subnet)
+            })
+            .collect();
+
+        let Some((interface, subnet)) = interfaces.first() else {
+            return Ok(None);
+        };
+
+        let Some((host, mut host_interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface,
+                    all_ports: &vec![],
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &None,
+                },
+                node.metadata.name.clone(),
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        host_interfaces.extend(interfaces.iter().skip(1).map(|(i, _)| i.clone()));
+
+        let response = self
+            .create_host(host, host_interfaces, ports, services, Vec::new(), None)
+            .await?;
+
+        Ok(Some((response.id, response.interfaces)))
+    }
+
+    /// Match services in a running pod, and add them to the host of the node it runs on
+    async fn report_pod(
+        &self,
+        pod: &Pod,
+        nodes: &HashMap<String, (Uuid, Vec<Interface>)>,
+        subnets: &[Subnet],
+    ) -> Result<Option<Vec<Service>>> {
+        let (Some(spec), Some(status)) = (&pod.spec, &pod.status) else {
+            return Ok(None);
+        };
+        if status.phase.as_deref() != Some("Running") {
+            return Ok(None);
+        }
+        let Some(pod_ip) = status
+            .pod_ip
+            .as_deref()
+            .and_then(|ip| IpAddr::from_str(ip).ok())
+        else {
+            return Ok(None);
+        };
+        let Some((node_host_id, node_interfaces)) =
+            spec.node_name.as_ref().and_then(|n| nodes.get(n))
+        else {
+            return Ok(None);
+        };
+
+        // Pods on the host network share the node's interfaces
+        let interface = match node_interfaces.iter().find(|i| i.base.ip_address == pod_ip) {
+            Some(interface) => interface.clone(),
+            None => {
+                let Some(subnet) = most_specific_subnet(pod_ip, subnets) else {
+                    return Ok(None);
+                };
+                new_interface(subnet, pod_ip, 0, pod.metadata.name.clone())
+            }
+        };
+        let Some(subnet) = subnets.iter().find(|s| s.id == interface.base.subnet_id) else {
+            return Ok(None);
+        };
+
+        let ports: Vec<PortType> = spec
+            .containers
+            .iter()
+            .flat_map(|c| c.ports.iter().flatten())
+            .filter_map(|p| port_type(p.container_port, p.protocol.as_deref()))
+            .collect();
+
+        let (namespace, name) = object_key(&pod.metadata);
+        let virtualization = Some(ServiceVirtualization::Kubernetes(
+            KubernetesVirtualization {
+                namespace,
+                workload: workload_name(pod),
+                pod: Some(name),
+            },
+        ));
+
+        let Some((mut host, interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &interface,
+                    all_ports: &ports,
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &virtualization,
+                },
+                None,
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        host.id = *node_host_id;
+
+        let response = self
+            .create_host(host, interfaces, ports, services, Vec::new(), None)
+            .await?;
+
+        Ok(Some(
+            response
+                .services
+                .into_iter()
+                .filter(|s| s.base.virtualization == virtualization)
+                .collect(),
+        ))
+    }
+
+    /// Create the cluster's host, which holds its Services and Ingresses at their cluster and
+    /// load balancer addresses. Returns the created services by kind, namespace and name, and
+    /// the host's ports.
+    async fn report_cluster_services(
+        &self,
+        cluster: &ClusterSnapshot,
+        subnets: &[Subnet],
+    ) -> Result<(HashMap<(&'static str, String, String), Service>, Vec<Port>)> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+        let discovery_type = self.discovery_type();
+
+        let host = Host::new(HostBase {
+            name: cluster.name.clone(),
+            hostname: None,
+            tags: Vec::new(),
+            network_id,
+            description: Some("Kubernetes cluster".to_string()),
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(discovery_type.clone(), daemon_id)],
+            },
+            virtualization: None,
+            hidden: false,
+            device_info: None,
+        });
+
+        let mut interfaces: Vec<Interface> = Vec::new();
+        let mut ports: Vec<Port> = Vec::new();
+        let mut services: Vec<Service> = Vec::new();
+
+        // Interface at an address, reused across the objects that share it
+        let interface_for = |ip: IpAddr, interfaces: &mut Vec<Interface>| {
+            if let Some(interface) = interfaces.iter().find(|i| i.base.ip_address == ip) {
+                return Some(interface.id);
+            }
+            let subnet = most_specific_subnet(ip, subnets)?;
+            let interface = new_interface(subnet, ip, interfaces.len() as i32, None);
+            let id = interface.id;
+            interfaces.push(interface);
+            Some(id)
+        };
+        let bind = |interface_id: Uuid, port_type: PortType, ports: &mut Vec<Port>| {
+            let port = match ports.iter().find(|p| p.base.port_type == port_type) {
+                Some(port) => *port,
+                None => {
+                    let port = Port::new_hostless(port_type);
+                    ports.push(port);
+                    port
+                }
+            };
+            Binding::new_port_serviceless(port.id, Some(interface_id))
+        };
+
+        let new_service = |name: &str,
+                           namespace: &str,
+                           definition: Box<dyn ServiceDefinition>,
+                           bindings: Vec<Binding>,
+                           position: usize| {
+            Service::new(ServiceBase {
+                name: name.to_string(),
+                service_definition: definition,
+                bindings,
+                host_id: host.id,
+                tags: Vec::new(),
+                network_id,
+                virtualization: Some(ServiceVirtualization::Kubernetes(
+                    KubernetesVirtualization {
+                        namespace: namespace.to_string(),
+                        workload: None,
+                        pod: None,
+                    },
+                )),
+                product: None,
+                version: None,
+                source: EntitySource::DiscoveryWithMatch {
+                    metadata: vec![DiscoveryMetadata::new(discovery_type.clone(), daemon_id)],
+                    details: MatchDetails::new_certain("Imported from the Kubernetes API"),
+                },
+                position: position as i32,
+            })
+        };
+
+        for service in &cluster.services {
+            let (namespace, name) = object_key(&service.metadata);
+            let service_ports: Vec<PortType> = service
+                .spec
+                .as_ref()
+                .and_then(|s| s.ports.as_ref())
+                .into_iter()
+                .flatten()
+                .filter_map(|p| port_type(p.port, p.protocol.as_deref()))
+                .collect();
+
+            let addresses = cluster_ips(service).chain(load_balancer_ips(
+                service
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.load_balancer.as_ref())
+                    .and_then(|lb| lb.ingress.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|i| i.ip.as_deref()),
+            ));
+
+            let mut bindings = Vec::new();
+            for ip in addresses.collect::<Vec<_>>() {
+                let Some(interface_id) = interface_for(ip, &mut interfaces) else {
+                    continue;
+                };
+                for port_type in &service_ports {
+                    bindings.push(bind(interface_id, *port_type, &mut ports));
+                }
+            }
+            if bindings.is_empty() {
+                continue;
+            }
+
+            services.push(new_service(
+                &name,
+                &namespace,
+                Box::new(KubernetesService),
+                bindings,
+                services.len(),
+            ));
+        }
+
+        for ingress in &cluster.ingresses {
+            let (namespace, name) = object_key(&ingress.metadata);
+            let tls = ingress
+                .spec
+                .as_ref()
+                .and_then(|s| s.tls.as_ref())
+                .is_some_and(|tls| !tls.is_empty());
+            let ingress_ports = if tls {
+                vec![PortType::new_tcp(443), PortType::new_tcp(80)]
+            } else {
+                vec![PortType::new_tcp(80)]
+            };
+
+            let mut bindings = Vec::new();
+            let addresses = load_balancer_ips(
+                ingress
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.load_balancer.as_ref())
+                    .and_then(|lb| lb.ingress.as_ref())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|i| i.ip.as_deref()),
+            );
+            for ip in addresses.collect::<Vec<_>>() {
+                let Some(interface_id) = interface_for(ip, &mut interfaces) else {
+                    continue;
+                };
+                for port_type in &ingress_ports {
+                    bindings.push(bind(interface_id, *port_type, &mut ports));
+                }
+            }
+
+            services.push(new_service(
+                &name,
+                &namespace,
+                Box::new(KubernetesIngress),
+                bindings,
+                services.len(),
+            ));
+        }
+
+        if interfaces.is_empty() {
+            tracing::info!("No Kubernetes Service has an address on a known subnet");
+            return Ok((HashMap::new(), Vec::new()));
+        }
+
+        let response = self
+            .create_host(host, interfaces, ports, services, Vec::new(), None)
+            .await?;
+
+        let by_key = response
+            .services
+            .into_iter()
+            .filter_map(|s| {
+                let kind = if s.base.service_definition.id() == KubernetesIngress.id() {
+                    "Ingress"
+                } else if s.base.service_definition.id() == KubernetesService.id() {
+                    "Service"
+                } else {
+                    return None;
+                };
+                let Some(ServiceVirtualization::Kubernetes(kv)) = &s.base.virtualization else {
+                    return None;
+                };
+                Some(((kind, kv.namespace.clone(), s.base.name.clone()), s))
+            })
+            .collect();
+
+        Ok((by_key, response.ports))
+    }
+
+    /// Create a request path group for each ingress rule: from the ingress, through the Service
+    /// it routes to, to the pods the Service selects
+    async fn report_request_paths(
+        &self,
+        cluster: &ClusterSnapshot,
+        services: &HashMap<(&'static str, String, String), Service>,
+        ports: &[Port],
+        pod_services: &HashMap<(String, String), Vec<Service>>,
+    ) {
+        let Ok(Some(network_id)) = self.as_ref().config_store.get_network_id().await else {
+            return;
+        };
+        let Ok(daemon_id) = self.as_ref().config_store.get_id().await else {
+            return;
+        };
+
+        let port_number = |binding: &Binding| {
+            let port_id = binding.port_id()?;
+            ports
+                .iter()
+                .find(|p| p.id == port_id)
+                .map(|p| p.base.port_type.number())
+        };
+
+        for ingress in &cluster.ingresses {
+            let (namespace, ingress_name) = object_key(&ingress.metadata);
+            let ingress_binding = services
+                .get(&("Ingress", namespace.clone(), ingress_name.clone()))
+                .and_then(|s| s.base.bindings.first())
+                .map(|b| b.id());
+
+            for route in ingress_routes(ingress) {
+                let mut binding_ids: Vec<Uuid> = ingress_binding.into_iter().collect();
+
+                if let Some(service) =
+                    services.get(&("Service", namespace.clone(), route.service.clone()))
+                {
+                    let binding = service
+                        .base
+                        .bindings
+                        .iter()
+                        .find(|b| route.port.is_none() || port_number(b) == route.port)
+                        .or_else(|| service.base.bindings.first());
+                    binding_ids.extend(binding.map(|b| b.id()));
+                }
+
+                let selector = cluster
+                    .services
+                    .iter()
+                    .find(|s| object_key(&s.metadata) == (namespace.clone(), route.service.clone()))
+                    .and_then(|s| s.spec.as_ref()?.selector.clone())
+                    .unwrap_or_default();
+                if !selector.is_empty() {
+                    let mut selected: Vec<&Pod> = cluster
+                        .pods
+                        .iter()
+                        .filter(|p| p.metadata.namespace.as_deref() == Some(namespace.as_str()))
+                        .filter(|p| selects(&selector, p.metadata.labels.as_ref()))
+                        .collect();
+                    selected.sort_by_key(|p| p.metadata.name.clone());
+
+                    binding_ids.extend(
+                        selected
+                            .iter()
+                            .filter_map(|p| pod_services.get(&object_key(&p.metadata)))
+                            .filter_map(|s| s.first()?.base.bindings.first())
+                            .map(|b| b.id()),
+                    );
+                }
+
+                if binding_ids.len() < 2 {
+                    continue;
+                }
+
+                let group = Group::new(GroupBase {
+                    name: format!("{}/{}: {}", namespace, ingress_name, route.name),
+                    network_id,
+                    description: Some(format!(
+                        "Requests for {} routed by ingress {} to Service {}",
+                        route.name, ingress_name, route.service
+                    )),
+                    group_type: GroupType::RequestPath,
+                    binding_ids,
+                    source: EntitySource::Discovery {
+                        metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                    },
+                    ..Default::default()
+                });
+
+                if let Err(e) = self.create_group(&group).await {
+                    tracing::warn!(
+                        group = %group.base.name,
+                        error = %e,
+                        "Failed to create request path group"
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn new_interface(subnet: &Subnet, ip: IpAddr, position: i32, name: Option<String>) -> Interface {
+    Interface::new(InterfaceBase {
+        network_id: subnet.base.network_id,
+        host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+        name,
+        subnet_id: subnet.id,
+        ip_address: ip,
+        mac_address: None,
+        position,
+        dhcp_lease: None,
+        rtt_ms: None,
+    })
+}
+
+fn object_key(
+    metadata: &k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta,
+) -> (String, String) {
+    (
+        metadata
+            .namespace
+            .clone()
+            .unwrap_or_else(|| "default".to_string()),
+        metadata.name.clone().unwrap_or_default(),
+    )
+}
+
+fn port_type(number: i32, protocol: Option<&str>) -> Option<PortType> {
+    let number = u16::try_from(number).ok()?;
+    match protocol.unwrap_or("TCP") {
+        "TCP" => Some(PortType::new_tcp(number)),
+        "UDP" => Some(PortType::new_udp(number)),
+        _ => None,
+    }
+}
+
+fn cluster_ips(service: &KubeService) -> impl Iterator<Item = IpAddr> + '_ {
+    let spec = service.spec.as_ref();
+    spec.and_then(|s| s.cluster_ips.as_ref())
+        .into_iter()
+        .flatten()
+        .chain(spec.and_then(|s| s.cluster_ip.as_ref()))
+        // Headless Services have a cluster IP of "None"
+        .filter_map(|ip| IpAddr::from_str(ip).ok())
+        .fold(Vec::new(), |mut ips, ip| {
+            if !ips.contains(&ip) {
+                ips.push(ip);
+            }
+            ips
+        })
+        .into_iter()
+}
+
+fn load_balancer_ips<'a>(
+    ips: impl Iterator<Item = &'a str> + 'a,
+) -> impl Iterator<Item = IpAddr> + 'a {
+    ips.filter_map(|ip| IpAddr::from_str(ip).ok())
+}
+
+/// The workload a pod belongs to: its controller, or the Deployment behind its ReplicaSet
+fn workload_name(pod: &Pod) -> Option<String> {
+    let owner = pod
+        .metadata
+        .owner_references
+        .as_ref()?
+        .iter()
+        .find(|o| o.controller == Some(true))?;
+
+    if owner.kind == "ReplicaSet"
+        && let Some(hash) = pod
+            .metadata
+            .labels
+            .as_ref()
+            .and_then(|l| l.get("pod-template-hash"))
+        && let Some(deployment) = owner.name.strip_suffix(&format!("-{}", hash))
+    {
+        return Some(deployment.to_string());
+    }
+
+    Some(owner.name.clone())
+}
+
+/// Whether a Service's selector selects a pod with the given labels
+fn selects(selector: &BTreeMap<String, String>, labels: Option<&BTreeMap<String, String>>) -> bool {
+    let Some(labels) = labels else {
+        return false;
+    };
+    selector.iter().all(|(k, v)| labels.get(k) == Some(v))
+}
+
+/// The routes of an ingress's rules, and its default backend
+fn ingress_routes(ingress: &Ingress) -> Vec<IngressRoute> {
+    let Some(spec) = &ingress.spec else {
+        return Vec::new();
+    };
+
+    let mut routes = Vec::new();
+    for rule in spec.rules.iter().flatten() {
+        let host = rule.host.clone().unwrap_or_else(|| "*".to_string());
+        for path in rule.http.iter().flat_map(|http| &http.paths) {
+            let Some(service) = &path.backend.service else {
+                continue;
+            };
+            routes.push(IngressRoute {
+                name: format!("{}{}", host, path.path.as_deref().unwrap_or("/")),
+                service: service.name.clone(),
+                port: service
+                    .port
+                    .as_ref()
+                    .and_then(|p| p.number)
+                    .and_then(|n| u16::try_from(n).ok()),
+            });
+        }
+    }
+
+    if let Some(service) = spec
+        .default_backend
+        .as_ref()
+        .and_then(|b| b.service.as_ref())
+    {
+        routes.push(IngressRoute {
+            name: "default backend".to_string(),
+            service: service.name.clone(),
+            port: service
+                .port
+                .as_ref()
+                .and_then(|p| p.number)
+                .and_then(|n| u16::try_from(n).ok()),
+        });
+    }
+
+    routes
+}
+
+/// The smallest network covering every address, but no longer than `max_len`
+fn covering_cidr(ips: &[IpAddr], max_len: u8) -> Option<IpCidr> {
+    let first = *ips.first()?;
+    let (bits, width): (u128, u8) = match first {
+        IpAddr::V4(ip) => (u32::from(ip) as u128, 32),
+        IpAddr::V6(ip) => (u128::from(ip), 128),
+    };
+
+    let mut len = max_len.min(width);
+    for ip in &ips[1..] {
+        let other = match ip {
+            IpAddr::V4(ip) => u32::from(*ip) as u128,
+            IpAddr::V6(ip) => u128::from(*ip),
+        };
+        let common = ((bits ^ other) << (128 - width as u32)).leading_zeros() as u8;
+        len = len.min(common);
+    }
+
+    let mask = if len == 0 {
+        0
+    } else {
+        (u128::MAX << (width - len)) & (u128::MAX >> (128 - width as u32))
+    };
+    let network = bits & mask;
+    let cidr = match first {
+        IpAddr::V4(_) => format!("{}/{}", std::net::Ipv4Addr::from(network as u32), len),
+        IpAddr::V6(_) => format!("{}/{}", std::net::Ipv6Addr::from(network), len),
+    };
+    IpCidr::from_str(&cidr).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::networking::v1::IngressSpec;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+
+    #[tokio::test]
+    async fn test_kubeconfig_credential_plugins_need_opt_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let kubeconfig = |user: &str| {
+            format!(
+                "apiVersion: v1\nkind: Config\nclusters:\n- name: lab\n  cluster:\n    server: https://10.0.0.1:6443\n\
+                 contexts:\n- name: lab\n  context:\n    cluster: lab\n    user: admin\n\
+                 current-context: lab\nusers:\n- name: admin\n  user:\n{}",
+                user
+            )
+        };
+        std::fs::write(
+            dir.path().join("token.yaml"),
+            kubeconfig("    token: abc123\n"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("exec.yaml"),
+            kubeconfig(
+                "    exec:\n      apiVersion: client.authentication.k8s.io/v1\n      command: /bin/sh\n      args: [\"-c\", \"id\"]\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("broken.yaml"), "token: hunter2: [").unwrap();
+
+        assert!(
+            read_kubeconfig(dir.path(), Path::new("token.yaml"), false)
+                .await
+                .is_ok()
+        );
+        assert!(
+            read_kubeconfig(dir.path(), Path::new("exec.yaml"), false)
+                .await
+                .is_err()
+        );
+        assert!(
+            read_kubeconfig(dir.path(), Path::new("exec.yaml"), true)
+                .await
+                .is_ok()
+        );
+
+        let error = read_kubeconfig(dir.path(), Path::new("broken.yaml"), false)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(!error.contains("hunter2"), "{}", error);
+
+        assert!(
+            read_kubeconfig(dir.path(), Path::new("/etc/passwd"), true)
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_workload_name_follows_replica_set_to_deployment() {
+        let pod = |kind: &str, owner: &str| Pod {
+            metadata: ObjectMeta {
+                labels: Some(BTreeMap::from([(
+                    "pod-template-hash".to_string(),
+                    "5d9f7c6b8".to_string(),
+                )])),
+                owner_references: Some(vec![OwnerReference {
+                    kind: kind.to_string(),
+                    name: owner.to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            workload_name(&pod("ReplicaSet", "shop-api-5d9f7c6b8")),
+            Some("shop-api".to_string())
+        );
+        assert_eq!(
+            workload_name(&pod("StatefulSet", "postgres")),
+            Some("postgres".to_string())
+        );
+        assert_eq!(workload_name(&Pod::default()), None);
+    }
+
+    #[test]
+    fn test_ingress_routes() {
+        let ingress: Ingress = serde_json::from_value(serde_json::json!({
+            "metadata": { "name": "shop", "namespace": "web" },
+            "spec": {
+                "rules": [{
+                    "host": "shop.example.com",
+                    "http": { "paths": [
+                        { "path": "/api", "pathType": "Prefix", "backend": {
+                            "service": { "name": "shop-api", "port": { "number": 8080 } }
+                        }},
+                        { "path": "/", "pathType": "Prefix", "backend": {
+                            "service": { "name": "shop-web", "port": { "name": "http" } }
+                        }}
+                    ]}
+                }],
+                "defaultBackend": { "service": { "name": "not-found" } }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            ingress_routes(&ingress),
+            vec![
+                IngressRoute {
+                    name: "shop.example.com/api".to_string(),
+                    service: "shop-api".to_string(),
+                    port: Some(8080),
+                },
+                IngressRoute {
+                    name: "shop.example.com/".to_string(),
+                    service: "shop-web".to_string(),
+                    port: None,
+                },
+                IngressRoute {
+                    name: "default backend".to_string(),
+                    service: "not-found".to_string(),
+                    port: None,
+                },
+            ]
+        );
+
+        let empty = Ingress {
+            spec: Some(IngressSpec::default()),
+            ..Default::default()
+        };
+        assert!(ingress_routes(&empty).is_empty());
+    }
+
+    #[test]
+    fn test_covering_cidr() {
+        let ips = |s: &[&str]| -> Vec<IpAddr> { s.iter().map(|ip| ip.parse().unwrap()).collect() };
+
+        assert_eq!(
+            covering_cidr(&ips(&["10.96.0.1", "10.96.0.10"]), 24).map(|c| c.to_string()),
+            Some("10.96.0.0/24".to_string())
+        );
+        assert_eq!(
+            covering_cidr(&ips(&["10.96.0.1", "10.100.3.7"]), 24).map(|c| c.to_string()),
+            Some("10.96.0.0/13".to_string())
+        );
+        assert_eq!(
+            covering_cidr(&ips(&["fd00:10:96::1"]), 112).map(|c| c.to_string()),
+            Some("fd00:10:96::/112".to_string())
+        );
+        assert_eq!(covering_cidr(&[], 24), None);
+    }
+}
@@ -4,12 +4,28 @@ use crate::daemon::discovery::service::base::{
 use crate::daemon::discovery::types::base::{DiscoveryCriticalError, DiscoverySessionUpdate};
 use crate::daemon::utils::arp::{self, ArpScanResult};
 use crate::daemon::utils::base::ConcurrentPipelineOps;
-use crate::daemon::utils::scanner::{can_arp_scan, scan_endpoints, scan_tcp_ports, scan_udp_ports};
+use crate::daemon::utils::mdns::{self, MdnsBrowse, MdnsHost};
+use crate::daemon::utils::ndp::{self, NdpScanResult};
+use crate::daemon::utils::ping::{self, PingResult};
+use crate::daemon::utils::scanner::{
+    AdaptiveScanControl, ConnectOptions, ScanParams, can_arp_scan, can_ndp_scan, can_ping_sweep,
+    can_syn_scan, scan_banners, scan_certificates, scan_endpoints, scan_tcp_ports, scan_udp_ports,
+};
+use crate::daemon::utils::snmp::{self, SNMP_PORT};
+use crate::daemon::utils::ssdp::{self, SsdpSearch};
+use crate::daemon::utils::syn::SynScanner;
 use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::hosts::r#impl::device::DeviceInfo;
 use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::physical_links::r#impl::base::{PhysicalLink, PhysicalLinkBase};
 use crate::server::ports::r#impl::base::PortType;
+use crate::server::scan_exclusions::r#impl::base::{Exclusion, ScanExclusions};
+use crate::server::scan_profiles::r#impl::base::{ScanProfile, ScanSettings, UdpProbe};
 use crate::server::services::r#impl::base::{Service, ServiceMatchBaselineParams};
+use crate::server::shared::storage::traits::Storable;
+use crate::server::snmp_credentials::r#impl::base::{SnmpCredential, SnmpCredentialConfig};
 use crate::server::subnets::r#impl::types::SubnetTypeDiscriminants;
+use crate::server::tls_certificates::r#impl::base::{TlsCertificate, TlsCertificateBase};
 use crate::{
     daemon::utils::base::DaemonUtils,
     server::{
@@ -30,7 +46,10 @@ use std::collections::{HashMap, HashSet};
 use std::result::Result::Ok;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
-use std::{net::IpAddr, sync::Arc};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    sync::Arc,
+};
 use strum::IntoDiscriminant;
 use tokio::sync::mpsc as tokio_mpsc;
 use tokio::time::timeout;
@@ -40,9 +59,16 @@ use uuid::Uuid;
 /// Grace period to wait for late ARP arrivals after the last deep scan completes
 const LATE_ARRIVAL_GRACE_PERIOD: Duration = Duration::from_secs(30);
 
+/// IPv6 prefixes shorter than this are never swept address-by-address; on-link
+/// prefixes are discovered via NDP instead (a /120 is 256 addresses)
+const MIN_SWEEPABLE_IPV6_PREFIX: u8 = 120;
+
 /// Maximum interval between progress reports (heartbeat even if progress unchanged)
 const MAX_PROGRESS_REPORT_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Ports scanned per batch when deep scanning a host
+const PORTS_PER_HOST_BATCH: usize = 200;
+
 // Progress phase weights (must sum to 100)
 const PROGRESS_ARP_PHASE: u8 = 30; // 0-30%: ARP discovery
 const PROGRESS_DEEP_SCAN_PHASE: u8 = 65; // 30-95%: Deep scanning
@@ -52,13 +78,19 @@ const PROGRESS_GRACE_PHASE: u8 = 5; // 95-100%: Grace period
 pub struct NetworkScanDiscovery {
     subnet_ids: Option<Vec<Uuid>>,
     host_naming_fallback: HostNamingFallback,
+    scan_profile_id: Option<Uuid>,
 }
 
 impl NetworkScanDiscovery {
-    pub fn new(subnet_ids: Option<Vec<Uuid>>, host_naming_fallback: HostNamingFallback) -> Self {
+    pub fn new(
+        subnet_ids: Option<Vec<Uuid>>,
+        host_naming_fallback: HostNamingFallback,
+        scan_profile_id: Option<Uuid>,
+    ) -> Self {
         Self {
             subnet_ids,
             host_naming_fallback,
+            scan_profile_id,
         }
     }
 }
@@ -67,12 +99,26 @@ pub struct DeepScanParams<'a> {
     ip: IpAddr,
     subnet: &'a Subnet,
     mac: Option<MacAddress>,
+    /// Round trip time of the ping that found the host, for hosts on routed subnets
+    rtt: Option<Duration>,
     phase1_ports: Vec<PortType>,
     cancel: CancellationToken,
     port_scan_batch_size: usize,
     gateway_ips: &'a [IpAddr],
+    snmp_credentials: &'a [SnmpCredentialConfig],
+    settings: &'a ScanSettings,
+    exclusions: &'a ScanExclusions,
+    /// TCP ports from the scan settings, resolved once per discovery
+    tcp_ports: &'a [u16],
+    /// Shared SYN scanner when enabled and raw sockets are available, otherwise ports are
+    /// connect scanned
+    syn_scanner: Option<&'a Arc<SynScanner>>,
+    /// Adapts TCP batch sizes, timeouts and retries to each subnet as the session goes
+    scan_control: &'a AdaptiveScanControl,
     /// Optional counter for batch-level progress tracking
     batches_completed: Option<&'a Arc<AtomicUsize>>,
+    mdns_browse: MdnsBrowse,
+    ssdp_search: SsdpSearch,
 }
 
 impl CreatesDiscoveredEntities for DiscoveryRunner<NetworkScanDiscovery> {}
@@ -83,6 +129,7 @@ impl RunsDiscovery for DiscoveryRunner<NetworkScanDiscovery> {
         DiscoveryType::Network {
             subnet_ids: self.domain.subnet_ids.clone(),
             host_naming_fallback: self.domain.host_naming_fallback,
+            scan_profile_id: self.domain.scan_profile_id,
         }
     }
 
@@ -154,7 +201,7 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<NetworkScanDiscovery> {
                 .into_iter()
                 .filter(|s| {
 
-                    if s.base.cidr.network_length() < 10 {
+                    if s.base.cidr.is_ipv4() && s.base.cidr.network_length() < 10 {
                         tracing::warn!("Skipping {} with CIDR {}, scanning would take too long", s.base.name, s.base.cidr);
                         return false
                     }
@@ -176,12 +223,114 @@ impl DiscoversNetworkedEntities for DiscoveryRunner<NetworkScanDiscovery> {
 }
 
 impl DiscoveryRunner<NetworkScanDiscovery> {
+    /// Fetch the SNMP credentials configured for the daemon's network. Failures are non-fatal;
+    /// devices are then only queried with the default community.
+    async fn get_snmp_credentials(&self) -> Vec<SnmpCredentialConfig> {
+        let result: Result<Vec<SnmpCredential>, Error> = self
+            .as_ref()
+            .api_client
+            .get("/api/v1/snmp-credentials", "Failed to get SNMP credentials")
+            .await;
+
+        match result {
+            Ok(credentials) => {
+                tracing::debug!(count = credentials.len(), "Loaded SNMP credentials");
+                credentials.into_iter().map(|c| c.base.config).collect()
+            }
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    "Could not fetch SNMP credentials, falling back to the default community"
+                );
+                Vec::new()
+            }
+        }
+    }
+
+    /// Fetch the settings of the discovery's scan profile. A profile that was deleted or can't be
+    /// fetched fails the session, rather than scanning with settings the user didn't choose.
+    async fn get_scan_settings(&self) -> Result<ScanSettings, Error> {
+        let Some(id) = self.domain.scan_profile_id else {
+            return Ok(ScanSettings::default());
+        };
+
+        let profile: ScanProfile = self
+            .as_ref()
+            .api_client
+            .get(
+                &format!("/api/v1/scan-profiles/{}", id),
+                "Failed to get scan profile",
+            )
+            .await
+            .map_err(|e| anyhow::anyhow!("Could not load scan profile {}: {}", id, e))?;
+
+        tracing::debug!(profile = %profile.base.name, "Loaded scan profile");
+        Ok(profile.base.settings)
+    }
+
+    /// Set up the SYN scanner when the daemon is configured for it and may open raw sockets.
+    async fn get_syn_scanner(&self) -> Result<Option<Arc<SynScanner>>, Error> {
+        let config_store = &self.as_ref().config_store;
+        if !config_store.get_use_syn_scan().await? || !can_syn_scan() {
+            return Ok(None);
+        }
+
+        let rate_pps = config_store.get_syn_rate_pps().await?;
+        Ok(Some(Arc::new(SynScanner::new(rate_pps))))
+    }
+
+    /// Deep scan one host outside a sweep of its subnet, ie one passive discovery saw join.
+    /// Relies on the runner's service already holding a session.
+    pub async fn scan_single_host(
+        &self,
+        ip: IpAddr,
+        subnet: &Subnet,
+        mac: Option<MacAddress>,
+        cancel: CancellationToken,
+    ) -> Result<Option<Host>, Error> {
+        let session = self.as_ref().get_session().await?;
+        let snmp_credentials = self.get_snmp_credentials().await;
+        let settings = self.get_scan_settings().await?;
+        // Passive sessions run for days, so the exclusions they started with may be stale
+        let exclusions = self.get_scan_exclusions().await?;
+        let syn_scanner = self.get_syn_scanner().await?;
+        let ports_per_host_batch = settings
+            .max_concurrency
+            .map_or(PORTS_PER_HOST_BATCH, |max| {
+                PORTS_PER_HOST_BATCH.min(max.into())
+            });
+
+        // Browsing and searching are sweeps of their own, so a single host goes without them
+        self.deep_scan_host(DeepScanParams {
+            ip,
+            subnet,
+            mac,
+            rtt: None,
+            phase1_ports: Vec::new(),
+            cancel: cancel.clone(),
+            port_scan_batch_size: ports_per_host_batch,
+            gateway_ips: &session.gateway_ips,
+            snmp_credentials: &snmp_credentials,
+            settings: &settings,
+            exclusions: &exclusions,
+            tcp_ports: &settings.tcp_ports.tcp_ports(),
+            syn_scanner: syn_scanner.as_ref(),
+            scan_control: &session.scan_control,
+            batches_completed: None,
+            mdns_browse: mdns::spawn_browse(Vec::new(), cancel.clone()),
+            ssdp_search: ssdp::spawn_search(Vec::new(), ScanExclusions::default(), cancel),
+        })
+        .await
+    }
+
     async fn scan_and_process_hosts(
         &self,
         subnets: Vec<Subnet>,
         cancel: CancellationToken,
     ) -> Result<Vec<Host>, Error> {
         let session = self.as_ref().get_session().await?;
+        let settings = self.get_scan_settings().await?;
+        let exclusions = &session.exclusions;
 
         let interface_filter = self.as_ref().config_store.get_interface_filter().await?;
         let (_, _, subnet_cidr_to_mac) = self
@@ -195,10 +344,44 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             )
             .await?;
 
+        // Get ARP/NDP config
+        let use_npcap = self.as_ref().config_store.get_use_npcap_arp().await?;
+        let arp_retries = self.as_ref().config_store.get_arp_retries().await?;
+        let arp_rate_pps = self.as_ref().config_store.get_arp_rate_pps().await?;
+
+        // On-link IPv6 prefixes are discovered with NDP rather than swept
+        let has_interfaced_ipv6 = subnets.iter().any(|s| {
+            s.base.cidr.is_ipv6()
+                && subnet_cidr_to_mac
+                    .get(&s.base.cidr)
+                    .and_then(|m| *m)
+                    .is_some()
+        });
+        let ndp_available = has_interfaced_ipv6 && can_ndp_scan(use_npcap);
+
+        let (ndp_subnets, subnets): (Vec<Subnet>, Vec<Subnet>) =
+            subnets.into_iter().partition(|s| {
+                ndp_available
+                    && s.base.cidr.is_ipv6()
+                    && subnet_cidr_to_mac
+                        .get(&s.base.cidr)
+                        .and_then(|m| *m)
+                        .is_some()
+            });
+
+        for subnet in subnets.iter().filter(|s| {
+            s.base.cidr.is_ipv6() && s.base.cidr.network_length() < MIN_SWEEPABLE_IPV6_PREFIX
+        }) {
+            tracing::warn!(
+                cidr = %subnet.base.cidr,
+                "Skipping IPv6 subnet: too large to sweep and not reachable via NDP from this daemon"
+            );
+        }
+
         let all_ips_with_subnets: Vec<(IpAddr, Subnet)> = subnets
             .iter()
             .flat_map(|subnet| {
-                self.determine_scan_order(&subnet.base.cidr)
+                self.determine_scan_order(&subnet.base.cidr, exclusions)
                     .map(move |ip| (ip, subnet.clone()))
             })
             .collect();
@@ -213,27 +396,34 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             .map(|p| p.number())
             .collect();
 
-        // Get ARP config
-        let use_npcap = self.as_ref().config_store.get_use_npcap_arp().await?;
-        let arp_retries = self.as_ref().config_store.get_arp_retries().await?;
-        let arp_rate_pps = self.as_ref().config_store.get_arp_rate_pps().await?;
-
         // Check ARP capability once before partitioning
         let arp_available = can_arp_scan(use_npcap);
 
         // Partition IPs - only use ARP path if we have capability
         let (interfaced_ips, non_interfaced_ips): (Vec<_>, Vec<_>) = if arp_available {
-            all_ips_with_subnets.into_iter().partition(|(_, subnet)| {
-                subnet_cidr_to_mac
-                    .get(&subnet.base.cidr)
-                    .and_then(|m| *m)
-                    .is_some()
+            all_ips_with_subnets.into_iter().partition(|(ip, subnet)| {
+                ip.is_ipv4()
+                    && subnet_cidr_to_mac
+                        .get(&subnet.base.cidr)
+                        .and_then(|m| *m)
+                        .is_some()
             })
         } else {
             // No ARP capability - treat all as non-interfaced (port scan only)
             (Vec::new(), all_ips_with_subnets)
         };
 
+        // Without ARP, the only way to find a host is to probe its ports, which an ARP-only
+        // exclusion rules out too
+        let non_interfaced_ips: Vec<(IpAddr, Subnet)> = non_interfaced_ips
+            .into_iter()
+            .filter(|(ip, _)| exclusions.check(*ip, None).is_none())
+            .collect();
+
+        // Routed hosts are found by pinging them when raw sockets allow it, which is much faster
+        // than connecting to every discovery port
+        let ping_available = !non_interfaced_ips.is_empty() && can_ping_sweep();
+
         // Calculate estimated ARP duration for progress reporting
         let arp_target_count = interfaced_ips.len() as u64;
         let total_rounds = 1 + arp_retries as u64;
@@ -242,12 +432,32 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             total_rounds * (send_time_per_round_secs + arp::ROUND_WAIT.as_secs())
                 + arp::POST_SCAN_RECEIVE.as_secs(),
         );
+        // NDP runs alongside ARP, so the neighbor discovery phase lasts as long as the slower one
+        let estimated_arp_duration = if ndp_subnets.is_empty() {
+            estimated_arp_duration
+        } else {
+            estimated_arp_duration.max(Duration::from_secs(
+                total_rounds * (1 + ndp::ROUND_WAIT.as_secs()) + ndp::POST_SCAN_RECEIVE.as_secs(),
+            ))
+        };
+        // As does the echo sweep of routed subnets
+        let estimated_arp_duration = if ping_available {
+            let send_time_per_round_secs =
+                non_interfaced_ips.len() as u64 / arp_rate_pps.max(1) as u64;
+            estimated_arp_duration.max(Duration::from_secs(
+                total_rounds * (send_time_per_round_secs + ping::ROUND_WAIT.as_secs())
+                    + ping::POST_SWEEP_RECEIVE.as_secs(),
+            ))
+        } else {
+            estimated_arp_duration
+        };
         let pipeline_start = Instant::now();
 
         tracing::info!(
             total_ips = total_ips,
             interfaced_ips = interfaced_ips.len(),
             non_interfaced_ips = non_interfaced_ips.len(),
+            ndp_subnets = ndp_subnets.len(),
             estimated_arp_secs = estimated_arp_duration.as_secs(),
             arp_method = if cfg!(target_family = "windows") && !use_npcap {
                 "SendARP"
@@ -266,7 +476,8 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                 .iter()
                 .map(|(_, subnet)| subnet.base.cidr)
                 .collect();
-            unique_cidrs.len()
+            // NDP scans hold a datalink channel per prefix, same as ARP
+            unique_cidrs.len() + ndp_subnets.len()
         };
 
         // Pre-compute non-interfaced port concurrency if needed
@@ -281,7 +492,11 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         };
 
         // Get deep scan parameters with precise FD budget
-        let ports_per_host_batch = 200;
+        let ports_per_host_batch = settings
+            .max_concurrency
+            .map_or(PORTS_PER_HOST_BATCH, |max| {
+                PORTS_PER_HOST_BATCH.min(max.into())
+            });
         let concurrent_ops = ConcurrentPipelineOps {
             arp_subnet_count,
             non_interfaced_scan_concurrency,
@@ -299,10 +514,36 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             .get_own_routing_table_gateway_ips()
             .await?;
 
+        let snmp_credentials = self.get_snmp_credentials().await;
+
+        // Browse mDNS and search SSDP from our own addresses on interfaced subnets while ARP
+        // runs, so answers are in by the time hosts are deep scanned
+        let interfaced_cidrs: Vec<IpCidr> = subnets
+            .iter()
+            .chain(ndp_subnets.iter())
+            .map(|s| s.base.cidr)
+            .filter(|cidr| subnet_cidr_to_mac.get(cidr).and_then(|m| *m).is_some())
+            .collect();
+        let mdns_source_ips: Vec<IpAddr> = datalink::interfaces()
+            .into_iter()
+            .flat_map(|iface| iface.ips)
+            .map(|ip_net| ip_net.ip())
+            .filter(|ip| interfaced_cidrs.iter().any(|cidr| cidr.contains(ip)))
+            .collect();
+        let ssdp_source_ips: Vec<Ipv4Addr> = mdns_source_ips
+            .iter()
+            .filter_map(|ip| match ip {
+                IpAddr::V4(ipv4) => Some(*ipv4),
+                IpAddr::V6(_) => None,
+            })
+            .collect();
+        let mdns_browse = mdns::spawn_browse(mdns_source_ips, cancel.clone());
+        let ssdp_search = ssdp::spawn_search(ssdp_source_ips, exclusions.clone(), cancel.clone());
+
         // Create async channel for discovered hosts
         // Buffer size allows ARP to run ahead while deep scanning catches up
         let (host_tx, mut host_rx) =
-            tokio_mpsc::channel::<(IpAddr, Subnet, Option<MacAddress>)>(256);
+            tokio_mpsc::channel::<(IpAddr, Subnet, Option<MacAddress>, Option<Duration>)>(256);
 
         // Track active ARP forwarders
         let arp_forwarders_active = Arc::new(AtomicUsize::new(0));
@@ -422,6 +663,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                                 IpAddr::V4(ip),
                                                 subnet.clone(),
                                                 Some(mac),
+                                                None,
                                             ))
                                             .is_err()
                                         {
@@ -453,8 +695,192 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             }
         }
 
-        // Process non-interfaced subnets with port scanning (send to same channel)
-        if !non_interfaced_ips.is_empty() {
+        // Start NDP scanning for on-link IPv6 prefixes (send to same channel)
+        for subnet in ndp_subnets {
+            if cancel.is_cancelled() {
+                return Err(Error::msg("Discovery session was cancelled"));
+            }
+
+            let cidr = subnet.base.cidr;
+            let IpCidr::V6(prefix) = cidr else {
+                continue;
+            };
+
+            let Some(source_mac) = subnet_cidr_to_mac.get(&cidr).and_then(|m| *m) else {
+                continue;
+            };
+
+            let pnet_source_mac = pnet::util::MacAddr::from(source_mac.bytes());
+            let interface = datalink::interfaces().into_iter().find(|iface| {
+                iface.mac.unwrap_or_default() == pnet_source_mac
+                    && iface.ips.iter().any(|ip| cidr.contains(&ip.ip()))
+            });
+
+            let Some(interface) = interface else {
+                tracing::warn!(mac = %source_mac, "No interface found for MAC, skipping NDP scan");
+                continue;
+            };
+
+            let ipv6_addrs: Vec<std::net::Ipv6Addr> = interface
+                .ips
+                .iter()
+                .filter_map(|ip_net| match ip_net.ip() {
+                    IpAddr::V6(ip) => Some(ip),
+                    IpAddr::V4(_) => None,
+                })
+                .collect();
+
+            let Some(link_local) = ipv6_addrs.iter().copied().find(ndp::is_link_local) else {
+                tracing::warn!(
+                    interface = %interface.name,
+                    cidr = %cidr,
+                    "No link-local IPv6 address found on interface, skipping NDP scan"
+                );
+                continue;
+            };
+            let source_ip = ipv6_addrs.iter().copied().find(|ip| prefix.contains(ip));
+
+            tracing::debug!(
+                cidr = %cidr,
+                interface = %interface.name,
+                link_local = %link_local,
+                source_mac = %source_mac,
+                "Starting NDP scan"
+            );
+
+            match ndp::scan_subnet(
+                &interface,
+                source_mac,
+                link_local,
+                source_ip,
+                prefix,
+                arp_retries,
+                arp_rate_pps,
+            ) {
+                Ok(ndp_rx) => {
+                    let host_tx = host_tx.clone();
+                    let forwarders = arp_forwarders_active.clone();
+                    forwarders.fetch_add(1, Ordering::SeqCst);
+
+                    std::thread::spawn(move || {
+                        let mut forwarded = 0u64;
+                        loop {
+                            match ndp_rx.recv_timeout(Duration::from_millis(100)) {
+                                Ok(NdpScanResult { ip, mac }) => {
+                                    if host_tx
+                                        .blocking_send((
+                                            IpAddr::V6(ip),
+                                            subnet.clone(),
+                                            Some(mac),
+                                            None,
+                                        ))
+                                        .is_err()
+                                    {
+                                        break;
+                                    }
+                                    forwarded += 1;
+                                }
+                                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+                                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+                            }
+                        }
+                        tracing::debug!(
+                            cidr = %cidr,
+                            forwarded,
+                            "NDP forwarder completed"
+                        );
+                        forwarders.fetch_sub(1, Ordering::SeqCst);
+                    });
+                }
+                Err(e) => {
+                    tracing::warn!(cidr = %cidr, error = %e, "NDP scan failed to start");
+                }
+            }
+        }
+
+        // Process non-interfaced subnets with a ping sweep, or port scanning without one (send to
+        // same channel)
+        let echo_rx = if ping_available {
+            let targets = non_interfaced_ips.iter().map(|(ip, _)| *ip).collect();
+            match ping::sweep(targets, arp_retries, arp_rate_pps) {
+                Ok(echo_rx) => Some(echo_rx),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Echo sweep failed to start, falling back to port scanning");
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        if let Some(echo_rx) = echo_rx {
+            // Use pre-computed concurrency (calculated earlier for FD budget)
+            let port_concurrency = non_interfaced_scan_concurrency;
+
+            tracing::info!(
+                count = non_interfaced_ips.len(),
+                ping_ports = ?settings.ping_ports,
+                "Pinging non-interfaced subnets in parallel to ARP"
+            );
+
+            let host_tx = host_tx.clone();
+            let cancel = cancel.clone();
+            let connect = ConnectOptions::from(&settings);
+            let ping_ports = settings.ping_ports.clone();
+
+            tokio::spawn(async move {
+                let subnets_by_ip: HashMap<IpAddr, Subnet> =
+                    non_interfaced_ips.into_iter().collect();
+
+                // The receiver blocks, so echo replies are forwarded from a blocking task
+                let forwarded = tokio::task::spawn_blocking(move || {
+                    let mut unanswered = subnets_by_ip;
+                    while let Ok(PingResult { ip, rtt }) = echo_rx.recv() {
+                        let Some(subnet) = unanswered.remove(&ip) else {
+                            continue;
+                        };
+                        if host_tx
+                            .blocking_send((ip, subnet, None, Some(rtt)))
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    (unanswered, host_tx)
+                })
+                .await;
+
+                let Ok((unanswered, host_tx)) = forwarded else {
+                    return;
+                };
+
+                if ping_ports.is_empty() || cancel.is_cancelled() {
+                    return;
+                }
+
+                // Hosts that drop echoes may still answer on a common port
+                let ping_ports = &ping_ports;
+                let mut pings = stream::iter(unanswered)
+                    .map(|(ip, subnet)| async move {
+                        ping::tcp_ping(ip, ping_ports, connect)
+                            .await
+                            .map(|rtt| (ip, subnet, rtt))
+                    })
+                    .buffer_unordered(port_concurrency);
+
+                while let Some(result) = pings.next().await {
+                    if cancel.is_cancelled() {
+                        break;
+                    }
+                    if let Some((ip, subnet, rtt)) = result {
+                        tracing::debug!(ip = %ip, "Host responsive (TCP ping)");
+                        if host_tx.send((ip, subnet, None, Some(rtt))).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+        } else if !non_interfaced_ips.is_empty() {
             // Use pre-computed concurrency (calculated earlier for FD budget)
             let port_concurrency = non_interfaced_scan_concurrency;
 
@@ -467,6 +893,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             let host_tx = host_tx.clone();
             let discovery_ports = discovery_ports.clone();
             let cancel = cancel.clone();
+            let connect = ConnectOptions::from(&settings);
 
             // Spawn port scanning as a parallel task
             tokio::spawn(async move {
@@ -481,6 +908,10 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                 cancel,
                                 port_scan_batch_size,
                                 discovery_ports,
+                                connect,
+                                // Most addresses in a sweep are empty, so their timeouts say
+                                // nothing about the link
+                                None,
                             )
                             .await;
 
@@ -499,7 +930,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                     .await;
 
                 for (ip, subnet) in results {
-                    let _ = host_tx.send((ip, subnet, None)).await;
+                    let _ = host_tx.send((ip, subnet, None, None)).await;
                 }
             });
         }
@@ -523,7 +954,12 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
 
         // Batch-level progress tracking for smoother UX
         // TCP port scanning is the bulk of deep scan work (~328 batches per host for 65535 ports)
-        let batches_per_host = 65535_usize.div_ceil(ports_per_host_batch);
+        let tcp_ports = &settings.tcp_ports.tcp_ports();
+        let syn_scanner = self.get_syn_scanner().await?;
+        let syn_scanner = syn_scanner.as_ref();
+        let scan_control = &session.scan_control;
+        let batches_per_host = tcp_ports.len().div_ceil(ports_per_host_batch);
+        let settings = &settings;
         let total_batches = Arc::new(AtomicUsize::new(0));
         let batches_completed = Arc::new(AtomicUsize::new(0));
 
@@ -537,7 +973,8 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         let mut last_progress_time = Instant::now();
 
         // Buffer for hosts waiting to be scanned when at concurrency limit
-        let mut pending_hosts: Vec<(IpAddr, Subnet, Option<MacAddress>)> = Vec::new();
+        let mut pending_hosts: Vec<(IpAddr, Subnet, Option<MacAddress>, Option<Duration>)> =
+            Vec::new();
 
         // Use interval instead of sleep - interval persists across select iterations
         // whereas sleep creates a new future each time and gets dropped when other branches fire
@@ -585,14 +1022,34 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                 // Try to receive new hosts from the channel
                 host = host_rx.recv(), if !channel_closed => {
                     match host {
-                        Some((ip, subnet, mac)) => {
+                        Some((ip, subnet, mac, rtt)) => {
+                            // Targets were filtered by address, but MAC rules only match now
+                            let exclusion = exclusions.check(ip, mac);
+                            if exclusion == Some(Exclusion::Skip) {
+                                tracing::debug!(ip = %ip, "Skipping excluded host");
+                                continue;
+                            }
+
                             hosts_discovered.fetch_add(1, Ordering::Relaxed);
                             *last_activity.lock().unwrap() = Instant::now();
 
-                            // Spawn deep scan if under concurrency limit, otherwise buffer
-                            if pending_scans.len() < deep_scan_concurrency {
+                            if exclusion == Some(Exclusion::ArpOnly) {
+                                pending_scans.push(Box::pin(async move {
+                                    match self.record_unscanned_host(ip, &subnet, mac).await {
+                                        Ok(host) => host,
+                                        Err(e) => {
+                                            tracing::warn!(ip = %ip, error = %e, "Failed to record excluded host");
+                                            None
+                                        }
+                                    }
+                                }));
+                            } else if pending_scans.len() < deep_scan_concurrency {
+                                // Spawn deep scan if under concurrency limit, otherwise buffer
                                 let cancel = cancel.clone();
                                 let gateway_ips = gateway_ips.clone();
+                                let snmp_credentials = snmp_credentials.clone();
+                                let mdns_browse = mdns_browse.clone();
+                                let ssdp_search = ssdp_search.clone();
                                 let hosts_scanned = hosts_scanned.clone();
                                 let last_activity = last_activity.clone();
                                 let batches_completed = batches_completed.clone();
@@ -604,11 +1061,20 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                             ip,
                                             subnet: &subnet,
                                             mac,
+                                            rtt,
                                             phase1_ports: Vec::new(),
                                             cancel,
                                             port_scan_batch_size: ports_per_host_batch,
                                             gateway_ips: &gateway_ips,
+                                            snmp_credentials: &snmp_credentials,
+                                            settings,
+                                            exclusions,
+                                            tcp_ports,
+                                            syn_scanner,
+                                            scan_control,
                                             batches_completed: Some(&batches_completed),
+                                            mdns_browse,
+                                            ssdp_search,
                                         })
                                         .await;
 
@@ -631,7 +1097,7 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                             } else {
                                 // Count batches upfront so progress doesn't regress when pulled from buffer
                                 total_batches.fetch_add(batches_per_host, Ordering::Relaxed);
-                                pending_hosts.push((ip, subnet, mac));
+                                pending_hosts.push((ip, subnet, mac, rtt));
                             }
                         }
                         None => {
@@ -648,9 +1114,12 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                     }
 
                     // Spawn next buffered host if available (batches already counted when buffered)
-                    if let Some((ip, subnet, mac)) = pending_hosts.pop() {
+                    if let Some((ip, subnet, mac, rtt)) = pending_hosts.pop() {
                         let cancel = cancel.clone();
                         let gateway_ips = gateway_ips.clone();
+                        let snmp_credentials = snmp_credentials.clone();
+                        let mdns_browse = mdns_browse.clone();
+                        let ssdp_search = ssdp_search.clone();
                         let hosts_scanned = hosts_scanned.clone();
                         let last_activity = last_activity.clone();
                         let batches_completed = batches_completed.clone();
@@ -661,11 +1130,20 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
                                     ip,
                                     subnet: &subnet,
                                     mac,
+                                    rtt,
                                     phase1_ports: Vec::new(),
                                     cancel,
                                     port_scan_batch_size: ports_per_host_batch,
                                     gateway_ips: &gateway_ips,
+                                    snmp_credentials: &snmp_credentials,
+                                    settings,
+                                    exclusions,
+                                    tcp_ports,
+                                    syn_scanner,
+                                    scan_control,
                                     batches_completed: Some(&batches_completed),
+                                    mdns_browse,
+                                    ssdp_search,
                                 })
                                 .await;
 
@@ -791,19 +1269,35 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             ip,
             subnet,
             mac,
+            rtt,
             phase1_ports,
             cancel,
             port_scan_batch_size,
             gateway_ips,
+            snmp_credentials,
+            settings,
+            exclusions,
+            tcp_ports,
+            syn_scanner,
+            scan_control,
             batches_completed,
+            mdns_browse,
+            ssdp_search,
         } = params;
 
         if cancel.is_cancelled() {
             return Err(Error::msg("Discovery was cancelled"));
         }
 
+        if exclusions.check(ip, mac).is_some() {
+            tracing::debug!(ip = %ip, "Not deep scanning excluded host");
+            return Ok(None);
+        }
+
         let phase1_port_nums: HashSet<u16> = phase1_ports.iter().map(|p| p.number()).collect();
-        let remaining_tcp_ports: Vec<u16> = (1..=65535)
+        let remaining_tcp_ports: Vec<u16> = tcp_ports
+            .iter()
+            .copied()
             .filter(|p| !phase1_port_nums.contains(p))
             .collect();
 
@@ -814,21 +1308,95 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             "Starting deep scan"
         );
 
-        // Scan in batches
+        let control = scan_control.subnet(
+            subnet.base.cidr,
+            ScanParams {
+                batch_size: port_scan_batch_size,
+                connect: settings.into(),
+            },
+        );
+
         let mut all_tcp_ports = Vec::new();
-        for chunk in remaining_tcp_ports.chunks(port_scan_batch_size) {
+        let mut syn_scanned = false;
+        if let Some(scanner) = syn_scanner {
+            let scanner = scanner.clone();
+            let ports = remaining_tcp_ports.clone();
+            let connect = control.params().connect;
+            let syn_cancel = cancel.clone();
+            let syn_control = control.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                scanner.scan(ip, &ports, connect, Some(&syn_control), &syn_cancel)
+            })
+            .await
+            .map_err(Error::from)
+            .and_then(|r| r);
+
+            match result {
+                Ok(open) => {
+                    // No connection is made, so HTTPS can only be inferred from the port
+                    all_tcp_ports.extend(open.into_iter().map(|p| {
+                        let port = PortType::new_tcp(p);
+                        (port, port.is_https())
+                    }));
+                    syn_scanned = true;
+
+                    if let Some(counter) = batches_completed {
+                        counter.fetch_add(
+                            remaining_tcp_ports.len().div_ceil(port_scan_batch_size),
+                            Ordering::Relaxed,
+                        );
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        ip = %ip,
+                        error = %e,
+                        "SYN scan failed, falling back to connect scan"
+                    );
+                }
+            }
+        }
+
+        // Scan in batches, unless a SYN scan already covered the ports
+        let connect_ports: &[u16] = if syn_scanned {
+            &[]
+        } else {
+            &remaining_tcp_ports
+        };
+        let total_batches = connect_ports.len().div_ceil(port_scan_batch_size);
+        let mut batches_reported = 0;
+        let mut ports_scanned = 0;
+        while ports_scanned < connect_ports.len() {
             if cancel.is_cancelled() {
                 return Err(Error::msg("Discovery was cancelled"));
             }
 
-            let open_ports =
-                scan_tcp_ports(ip, cancel.clone(), port_scan_batch_size, chunk.to_vec()).await?;
+            // Parameters are re-read per batch, so earlier batches tune the later ones
+            let params = control.params();
+            let chunk_end = (ports_scanned + params.batch_size).min(connect_ports.len());
+            let open_ports = scan_tcp_ports(
+                ip,
+                cancel.clone(),
+                params.batch_size,
+                connect_ports[ports_scanned..chunk_end].to_vec(),
+                params.connect,
+                Some(control.clone()),
+            )
+            .await?;
             all_tcp_ports.extend(open_ports);
+            ports_scanned = chunk_end;
 
-            // Update batch-level progress
+            // Update batch-level progress, counted in batches of the initial size since the
+            // total was estimated with it
+            let batches_done = if ports_scanned == connect_ports.len() {
+                total_batches
+            } else {
+                ports_scanned / port_scan_batch_size
+            };
             if let Some(counter) = batches_completed {
-                counter.fetch_add(1, Ordering::Relaxed);
+                counter.fetch_add(batches_done - batches_reported, Ordering::Relaxed);
             }
+            batches_reported = batches_done;
         }
 
         let use_https_ports: HashMap<u16, bool> = all_tcp_ports
@@ -849,24 +1417,49 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             port_scan_batch_size,
             subnet.base.cidr,
             gateway_ips.to_vec(),
+            &settings.udp_probes,
         )
         .await?;
         open_ports.extend(udp_ports);
 
+        let snmp_report = if settings.probes(UdpProbe::Snmp) {
+            snmp::query_device(ip, snmp_credentials).await
+        } else {
+            None
+        };
+        if snmp_report.is_some() {
+            open_ports.push(PortType::new_udp(SNMP_PORT));
+        }
+
+        // A handshake is only worth attempting where TLS is expected: known HTTPS ports, and
+        // ports the connect scan saw close immediately as TLS servers do on a silent client
+        let tls_ports: Vec<PortType> = open_ports
+            .iter()
+            .filter(|p| {
+                p.is_tcp()
+                    && (p.is_https() || use_https_ports.get(&p.number()).copied().unwrap_or(false))
+            })
+            .copied()
+            .collect();
+
         let mut ports_to_check = open_ports.clone();
         let endpoint_only_ports = Service::endpoint_only_ports();
         ports_to_check.extend(endpoint_only_ports);
         ports_to_check.sort_by_key(|p| (p.number(), p.protocol()));
         ports_to_check.dedup();
 
-        let endpoint_responses = scan_endpoints(
-            ip,
-            cancel.clone(),
-            Some(ports_to_check),
-            Some(use_https_ports),
-            port_scan_batch_size,
-        )
-        .await?;
+        let endpoint_responses = if settings.probe_endpoints {
+            scan_endpoints(
+                ip,
+                cancel.clone(),
+                Some(ports_to_check),
+                Some(use_https_ports),
+                port_scan_batch_size,
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
 
         for endpoint_response in &endpoint_responses {
             let port = endpoint_response.endpoint.port_type;
@@ -889,25 +1482,80 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
             "Deep scan complete"
         );
 
-        let hostname = self.get_hostname_for_ip(ip).await?;
+        // HTTP ports are already identified by their endpoint responses, and don't greet clients
+        let banner_ports: Vec<PortType> = open_ports
+            .iter()
+            .filter(|p| {
+                !endpoint_responses
+                    .iter()
+                    .any(|r| r.endpoint.port_type == **p)
+            })
+            .copied()
+            .collect();
+        let banner_responses = if settings.probe_endpoints {
+            let params = control.params();
+            scan_banners(
+                ip,
+                cancel.clone(),
+                banner_ports,
+                params.batch_size,
+                params.connect,
+                Some(control.clone()),
+            )
+            .await?
+        } else {
+            Vec::new()
+        };
+
+        let certificates = if settings.probe_endpoints {
+            scan_certificates(ip, cancel.clone(), tls_ports, port_scan_batch_size).await?
+        } else {
+            Vec::new()
+        };
+
+        let mdns_host = mdns_browse.await.get(&ip).cloned().unwrap_or_default();
+        let upnp_devices = ssdp_search.await.get(&ip).cloned().unwrap_or_default();
+
+        // Devices without a reverse DNS entry are often still named in their own config
+        let hostname = self.get_hostname_for_ip(ip, &mdns_host).await?.or_else(|| {
+            snmp_report
+                .as_ref()
+                .and_then(|report| report.system_name.clone())
+        });
+
+        // Routed IPv6 hosts have no NDP entry, but SLAAC addresses embed the MAC,
+        // which lets the server attach them to the same host as their IPv4 siblings
+        let mac = mac.or(match ip {
+            IpAddr::V6(ipv6) => ndp::mac_from_eui64(&ipv6),
+            IpAddr::V4(_) => None,
+        });
+
+        let queried_interface = snmp_report
+            .as_ref()
+            .and_then(|report| report.queried_interface.as_ref());
 
         let interface = Interface::new(InterfaceBase {
             network_id: subnet.base.network_id,
             host_id: Uuid::nil(), // Placeholder - server will set correct host_id
-            name: None,
+            name: queried_interface.and_then(|i| i.name.clone()),
             subnet_id: subnet.id,
             ip_address: ip,
-            mac_address: mac,
+            mac_address: mac.or(queried_interface.and_then(|i| i.mac_address)),
             position: 0,
+            dhcp_lease: None,
+            rtt_ms: rtt.map(|rtt| u32::try_from(rtt.as_millis()).unwrap_or(u32::MAX)),
         });
 
-        if let Ok(Some((host, interfaces, ports, services))) = self
+        if let Ok(Some((mut host, interfaces, ports, services))) = self
             .process_host(
                 ServiceMatchBaselineParams {
                     subnet,
                     interface: &interface,
                     all_ports: &open_ports,
                     endpoint_responses: &endpoint_responses,
+                    banner_responses: &banner_responses,
+                    mdns_advertisements: &mdns_host.advertisements,
+                    upnp_devices: &upnp_devices,
                     virtualization: &None,
                 },
                 hostname,
@@ -917,7 +1565,67 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         {
             let services_count = services.len();
 
-            if let Ok(host_response) = self.create_host(host, interfaces, ports, services).await {
+            // Links are sent only when the device answered, so an SNMP timeout doesn't clear the
+            // ones it reported before
+            let physical_links = snmp_report.map(|report| {
+                host.base.description = report.device_info.host_description();
+                host.base.device_info = Some(report.device_info);
+
+                report
+                    .physical_links
+                    .into_iter()
+                    .map(|base| {
+                        PhysicalLink::new(PhysicalLinkBase {
+                            host_id: host.id,
+                            network_id: host.base.network_id,
+                            ..base
+                        })
+                    })
+                    .collect()
+            });
+
+            // Devices that don't answer SNMP often still describe themselves over UPnP
+            if let Some(upnp_device) = upnp_devices.first() {
+                let device_info = match host.base.device_info.take() {
+                    Some(mut device_info) => {
+                        device_info.merge_upnp(upnp_device);
+                        device_info
+                    }
+                    None => DeviceInfo::from_upnp(upnp_device),
+                };
+                if host.base.description.is_none() {
+                    host.base.description = device_info.host_description();
+                }
+                host.base.device_info = Some(device_info);
+            }
+
+            // Key certificates to the daemon's port IDs; the server remaps them with the ports
+            let certificates = certificates
+                .into_iter()
+                .filter_map(|(port_type, base)| {
+                    ports
+                        .iter()
+                        .find(|p| p.base.port_type == port_type)
+                        .map(|p| {
+                            TlsCertificate::new(TlsCertificateBase {
+                                port_id: p.id,
+                                ..base
+                            })
+                        })
+                })
+                .collect();
+
+            if let Ok(host_response) = self
+                .create_host(
+                    host,
+                    interfaces,
+                    ports,
+                    services,
+                    certificates,
+                    physical_links,
+                )
+                .await
+            {
                 tracing::info!(
                     ip = %ip,
                     services = services_count,
@@ -934,7 +1642,67 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         Ok(None)
     }
 
-    async fn get_hostname_for_ip(&self, ip: IpAddr) -> Result<Option<String>, Error> {
+    /// Record a host excluded from scanning from its neighbor reply alone, with nothing sent to
+    /// it beyond the ARP or NDP request that found it
+    async fn record_unscanned_host(
+        &self,
+        ip: IpAddr,
+        subnet: &Subnet,
+        mac: Option<MacAddress>,
+    ) -> Result<Option<Host>, Error> {
+        let interface = Interface::new(InterfaceBase {
+            network_id: subnet.base.network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            name: None,
+            subnet_id: subnet.id,
+            ip_address: ip,
+            mac_address: mac,
+            position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
+        });
+
+        // Reverse DNS asks the resolver, not the host
+        let hostname = self.get_hostname_for_ip(ip, &MdnsHost::default()).await?;
+
+        let Some((host, interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &interface,
+                    all_ports: &vec![],
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &None,
+                },
+                hostname,
+                self.domain.host_naming_fallback,
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let host_response = self
+            .create_host(host, interfaces, ports, services, Vec::new(), None)
+            .await?;
+        tracing::info!(ip = %ip, "Excluded host recorded without scanning");
+
+        Ok(Some(host_response.to_host()))
+    }
+
+    /// The `.local` name a host announced over mDNS, falling back to reverse DNS
+    async fn get_hostname_for_ip(
+        &self,
+        ip: IpAddr,
+        mdns_host: &MdnsHost,
+    ) -> Result<Option<String>, Error> {
+        if let Some(hostname) = mdns_host.hostname() {
+            return Ok(Some(hostname));
+        }
+
         match timeout(Duration::from_millis(800), async {
             tokio::task::spawn_blocking(move || dns_lookup::lookup_addr(&ip)).await?
         })
@@ -945,15 +1713,30 @@ impl DiscoveryRunner<NetworkScanDiscovery> {
         }
     }
 
-    /// Figure out what order to scan IPs in given allocation patterns
-    fn determine_scan_order(&self, subnet: &IpCidr) -> impl Iterator<Item = IpAddr> {
-        let mut ips: Vec<IpAddr> = subnet.iter().map(|ip| ip.address()).collect();
+    /// Figure out what order to scan IPs in given allocation patterns, leaving out excluded
+    /// addresses. ARP-only ones stay in, so they can still be found.
+    fn determine_scan_order(
+        &self,
+        subnet: &IpCidr,
+        exclusions: &ScanExclusions,
+    ) -> impl Iterator<Item = IpAddr> {
+        // IPv6 prefixes can't be swept; on-link ones are handled by NDP instead
+        if subnet.is_ipv6() && subnet.network_length() < MIN_SWEEPABLE_IPV6_PREFIX {
+            return Vec::new().into_iter();
+        }
+
+        let mut ips: Vec<IpAddr> = subnet
+            .iter()
+            .map(|ip| ip.address())
+            .filter(|ip| exclusions.check(*ip, None) != Some(Exclusion::Skip))
+            .collect();
 
         // Sort by likelihood of being active hosts - highest probability first
         ips.sort_by_key(|ip| {
             let last_octet = match ip {
                 IpAddr::V4(ipv4) => ipv4.octets()[3],
-                IpAddr::V6(_) => return 9999, // IPv6 gets lowest priority for now
+                // Small IPv6 prefixes follow the same low/high static allocation habits
+                IpAddr::V6(ipv6) => ipv6.octets()[15],
             };
 
             match last_octet {
@@ -0,0 +1,358 @@
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+use futures::future::try_join_all;
+use mac_address::MacAddress;
+use pnet::datalink::{self, NetworkInterface};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use strum::IntoDiscriminant;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::discovery::service::network::NetworkScanDiscovery;
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::passive::{self, PassiveObservation};
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::scan_exclusions::r#impl::base::Exclusion;
+use crate::server::services::r#impl::base::ServiceMatchBaselineParams;
+use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::subnets::r#impl::types::SubnetTypeDiscriminants;
+
+/// A pair that hasn't been seen for this long is reported again when it comes back, ie a phone
+/// returning to the network
+const ABSENCE_BEFORE_REPORTING_AGAIN: Duration = Duration::from_secs(60 * 60);
+
+/// Most pairs tracked at once. Past it, absent pairs are forgotten first, then the least
+/// recently seen.
+const MAX_TRACKED_PAIRS: usize = 4096;
+
+/// Deep scans running at once for newly seen hosts
+const DEEP_SCAN_CONCURRENCY: usize = 2;
+
+/// How often the organization's scan exclusions are fetched again, since a passive session
+/// outlives any change made to them
+const EXCLUSION_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub struct PassiveDiscovery {
+    deep_scan: bool,
+}
+
+impl PassiveDiscovery {
+    pub fn new(deep_scan: bool) -> Self {
+        Self { deep_scan }
+    }
+}
+
+/// When a MAC/IP pair was last seen, and whether it has been reported with a hostname
+struct Sighting {
+    last_seen: Instant,
+    has_hostname: bool,
+}
+
+/// Forget pairs once more than `max` are tracked: those absent long enough to be reported again
+/// anyway, then the least recently seen, down to three quarters of `max` so the next new pairs
+/// don't each trigger another pass
+fn prune_sightings<K: Eq + Hash + Copy>(sightings: &mut HashMap<K, Sighting>, max: usize) {
+    if sightings.len() <= max {
+        return;
+    }
+
+    sightings.retain(|_, s| s.last_seen.elapsed() < ABSENCE_BEFORE_REPORTING_AGAIN);
+
+    let target = max * 3 / 4;
+    if sightings.len() > target {
+        let mut by_last_seen: Vec<(K, Instant)> =
+            sightings.iter().map(|(k, s)| (*k, s.last_seen)).collect();
+        by_last_seen.sort_by_key(|(_, last_seen)| *last_seen);
+
+        let excess = sightings.len() - target;
+        for (key, _) in by_last_seen.into_iter().take(excess) {
+            sightings.remove(&key);
+        }
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<PassiveDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<PassiveDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Passive
+    }
+
+    /// Listen until cancelled. The session isn't reported to the server, which only uses its ID
+    /// to recognize replayed results.
+    async fn discover(
+        &self,
+        mut request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        // Passive sessions are started by the daemon itself, so nothing came with the request
+        request.exclusions = self.get_scan_exclusions().await?;
+        self.initialize_discovery_session(request, daemon_id)
+            .await?;
+        self.sync_custom_service_definitions().await;
+
+        let subnets = self.discover_create_subnets().await?;
+        self.listen(subnets, cancel).await
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<PassiveDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        let session = self.as_ref().get_session().await?;
+        let interface_filter = self.as_ref().config_store.get_interface_filter().await?;
+        let (_, subnets, _) = self
+            .as_ref()
+            .utils
+            .get_own_interfaces(
+                self.discovery_type(),
+                session.info.daemon_id,
+                session.info.network_id,
+                &interface_filter,
+            )
+            .await?;
+
+        // Containers on docker bridges are reported by docker discovery
+        let subnets: Vec<Subnet> = subnets
+            .into_iter()
+            .filter(|s| s.base.subnet_type.discriminant() != SubnetTypeDiscriminants::DockerBridge)
+            .collect();
+
+        try_join_all(subnets.iter().map(|subnet| self.create_subnet(subnet))).await
+    }
+}
+
+impl DiscoveryRunner<PassiveDiscovery> {
+    async fn listen(&self, subnets: Vec<Subnet>, cancel: CancellationToken) -> Result<(), Error> {
+        let interfaces: Vec<NetworkInterface> = datalink::interfaces()
+            .into_iter()
+            .filter(|iface| iface.is_up() && !iface.is_loopback() && iface.mac.is_some())
+            .filter(|iface| {
+                iface
+                    .ips
+                    .iter()
+                    .any(|ip| subnets.iter().any(|s| s.base.cidr.contains(&ip.ip())))
+            })
+            .collect();
+
+        if interfaces.is_empty() {
+            return Err(anyhow!(
+                "No interfaces on the daemon's subnets to listen on"
+            ));
+        }
+
+        let mut observations = passive::listen(interfaces, cancel.clone());
+        let mut exclusions = self.as_ref().get_session().await?.exclusions;
+        let mut exclusion_refresh = tokio::time::interval_at(
+            tokio::time::Instant::now() + EXCLUSION_REFRESH_INTERVAL,
+            EXCLUSION_REFRESH_INTERVAL,
+        );
+        exclusion_refresh.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        // Deep scans run on the same session, attributed to network discovery since they probe
+        let deep_scanner = self.domain.deep_scan.then(|| {
+            Arc::new(DiscoveryRunner::new(
+                self.service.clone(),
+                self.manager.clone(),
+                NetworkScanDiscovery::default(),
+            ))
+        });
+        let deep_scan_slots = Arc::new(Semaphore::new(DEEP_SCAN_CONCURRENCY));
+
+        let mut sightings: HashMap<(MacAddress, IpAddr), Sighting> = HashMap::new();
+
+        loop {
+            let observation = tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = exclusion_refresh.tick() => {
+                    match self.get_scan_exclusions().await {
+                        Ok(refreshed) => exclusions = refreshed,
+                        Err(e) => {
+                            tracing::warn!(error = %e, "Failed to refresh scan exclusions, keeping the previous ones");
+                        }
+                    }
+                    continue;
+                }
+                observation = observations.recv() => match observation {
+                    Some(observation) => observation,
+                    None => return Err(anyhow!("Every passive listener stopped")),
+                },
+            };
+
+            // Only addresses on the daemon's own subnets can be attached to a subnet
+            let Some(subnet) = subnets
+                .iter()
+                .find(|s| s.base.cidr.contains(&observation.ip))
+            else {
+                continue;
+            };
+
+            let exclusion = exclusions.check(observation.ip, Some(observation.mac));
+            if exclusion == Some(Exclusion::Skip) {
+                continue;
+            }
+
+            let key = (observation.mac, observation.ip);
+            let has_hostname = observation.hostname.is_some();
+            let (is_new, names_host) = match sightings.get(&key) {
+                Some(sighting) => (
+                    sighting.last_seen.elapsed() >= ABSENCE_BEFORE_REPORTING_AGAIN,
+                    has_hostname && !sighting.has_hostname,
+                ),
+                None => (true, has_hostname),
+            };
+
+            if !is_new && !names_host {
+                if let Some(sighting) = sightings.get_mut(&key) {
+                    sighting.last_seen = Instant::now();
+                }
+                continue;
+            }
+
+            tracing::info!(
+                ip = %observation.ip,
+                mac = %observation.mac,
+                hostname = ?observation.hostname,
+                protocol = %observation.protocol,
+                "Passive: reporting host"
+            );
+
+            if let Err(e) = self.report_host(&observation, subnet).await {
+                // Not recorded as seen, so the next packet from it tries again
+                tracing::warn!(ip = %observation.ip, error = %e, "Failed to report passively seen host");
+                continue;
+            }
+
+            let sighting = sightings.entry(key).or_insert(Sighting {
+                last_seen: Instant::now(),
+                has_hostname: false,
+            });
+            sighting.last_seen = Instant::now();
+            sighting.has_hostname |= has_hostname;
+            prune_sightings(&mut sightings, MAX_TRACKED_PAIRS);
+
+            // What was seen is all an excluded host gets recorded with
+            if let Some(deep_scanner) = &deep_scanner
+                && is_new
+                && exclusion.is_none()
+            {
+                let deep_scanner = deep_scanner.clone();
+                let deep_scan_slots = deep_scan_slots.clone();
+                let subnet = subnet.clone();
+                let cancel = cancel.clone();
+                tokio::spawn(async move {
+                    let Ok(_slot) = deep_scan_slots.acquire_owned().await else {
+                        return;
+                    };
+                    // Fetches the exclusions again itself, as the host may have been excluded
+                    // while the scan waited for a slot
+                    if let Err(e) = deep_scanner
+                        .scan_single_host(observation.ip, &subnet, Some(observation.mac), cancel)
+                        .await
+                    {
+                        tracing::warn!(ip = %observation.ip, error = %e, "Deep scan of passively seen host failed");
+                    }
+                });
+            }
+        }
+
+        tracing::info!("Passive discovery stopped");
+        Ok(())
+    }
+
+    /// Report a host from what its traffic gave away, matching only what needs no ports
+    async fn report_host(
+        &self,
+        observation: &PassiveObservation,
+        subnet: &Subnet,
+    ) -> Result<(), Error> {
+        let interface = Interface::new(InterfaceBase {
+            network_id: subnet.base.network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            name: None,
+            subnet_id: subnet.id,
+            ip_address: observation.ip,
+            mac_address: Some(observation.mac),
+            position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
+        });
+
+        if let Some((host, interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet,
+                    interface: &interface,
+                    all_ports: &vec![],
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &None,
+                },
+                observation.hostname.clone(),
+                HostNamingFallback::BestService,
+            )
+            .await?
+        {
+            self.create_host(host, interfaces, ports, services, Vec::new(), None)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_sightings_enforces_cap_when_every_pair_is_recent() {
+        let now = Instant::now();
+        let mut sightings: HashMap<u32, Sighting> = (0..9)
+            .map(|i| {
+                (
+                    i,
+                    Sighting {
+                        last_seen: now - Duration::from_secs(60 - u64::from(i)),
+                        has_hostname: false,
+                    },
+                )
+            })
+            .collect();
+
+        prune_sightings(&mut sightings, 8);
+
+        let mut kept: Vec<u32> = sightings.keys().copied().collect();
+        kept.sort();
+        assert_eq!(
+            kept,
+            vec![3, 4, 5, 6, 7, 8],
+            "Least recently seen pairs go first"
+        );
+
+        prune_sightings(&mut sightings, 8);
+        assert_eq!(sightings.len(), 6, "Nothing is pruned under the cap");
+    }
+}
@@ -0,0 +1,485 @@
+use anyhow::{Error, Result, anyhow};
+use async_trait::async_trait;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::{DaemonUtils, resolve_in_directory};
+use crate::daemon::utils::proxmox::{
+    self, ClusterNode, GuestKind, GuestNic, GuestSummary, ProxmoxClient,
+};
+use crate::server::bindings::r#impl::base::Binding;
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::DiscoveryType;
+use crate::server::hosts::r#impl::api::HostResponse;
+use crate::server::hosts::r#impl::base::{Host, HostBase};
+use crate::server::hosts::r#impl::virtualization::{HostVirtualization, ProxmoxVirtualization};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::ports::r#impl::base::{Port, PortType};
+use crate::server::services::definitions::proxmox::Proxmox;
+use crate::server::services::r#impl::base::{Service, ServiceBase};
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::MatchDetails;
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::shared::types::metadata::HasId;
+use crate::server::subnets::r#impl::base::{Subnet, most_specific_subnet};
+
+/// Port the Proxmox VE API and web interface listen on
+const PROXMOX_API_PORT: u16 = 8006;
+
+pub struct ProxmoxDiscovery {
+    url: String,
+    token_id: String,
+    token_secret_path: PathBuf,
+    allow_self_signed_certs: bool,
+}
+
+impl ProxmoxDiscovery {
+    pub fn new(
+        url: String,
+        token_id: String,
+        token_secret_path: PathBuf,
+        allow_self_signed_certs: bool,
+    ) -> Self {
+        Self {
+            url,
+            token_id,
+            token_secret_path,
+            allow_self_signed_certs,
+        }
+    }
+
+    /// Connect with the token secret read from the daemon's secrets directory. The secret file
+    /// is only ever resolved inside that directory, since the server chooses both the file
+    /// and the URL the secret is sent to.
+    async fn connect(&self, secrets_dir: Option<PathBuf>) -> Result<ProxmoxClient> {
+        let secrets_dir = secrets_dir.ok_or_else(|| {
+            anyhow!(
+                "Proxmox discovery is disabled on this daemon; set --proxmox-secrets-dir to enable it"
+            )
+        })?;
+        let path = resolve_in_directory(&secrets_dir, &self.token_secret_path, "secrets").await?;
+        let secret = tokio::fs::read_to_string(&path)
+            .await
+            .map_err(|e| anyhow!("Could not read token secret {}: {}", path.display(), e))?;
+
+        ProxmoxClient::new(
+            &self.url,
+            &self.token_id,
+            &secret,
+            self.allow_self_signed_certs,
+        )
+    }
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<ProxmoxDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<ProxmoxDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Proxmox {
+            url: self.domain.url.clone(),
+            token_id: self.domain.token_id.clone(),
+            token_secret_path: self.domain.token_secret_path.to_string_lossy().into_owned(),
+            allow_self_signed_certs: self.domain.allow_self_signed_certs,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        // Fail before the session starts if the API can't be reached or the token is rejected
+        let secrets_dir = self.as_ref().config_store.get_proxmox_secrets_dir().await?;
+        let client = self.domain.connect(secrets_dir).await?;
+        let nodes = client.nodes().await?;
+
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.import_cluster(&client, nodes, cancel.clone()).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<ProxmoxDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Nodes and guests are placed on the subnets the server already knows about, which is
+    /// where network scans would have found them
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<ProxmoxDiscovery> {
+    async fn import_cluster(
+        &self,
+        client: &ProxmoxClient,
+        nodes: Vec<ClusterNode>,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        let subnets = self.discover_create_subnets().await?;
+        let known_interfaces: Vec<Interface> = self
+            .as_ref()
+            .api_client
+            .get::<Vec<HostResponse>>("/api/v1/hosts", "Failed to get hosts")
+            .await?
+            .into_iter()
+            .flat_map(|host| host.interfaces)
+            .collect();
+
+        tracing::info!(
+            url = %self.domain.url,
+            nodes = nodes.len(),
+            "Importing Proxmox VE cluster"
+        );
+
+        let total = nodes.len().max(1);
+        let mut skipped = 0;
+
+        for (index, node) in nodes.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            if !node.online {
+                tracing::info!(node = %node.name, "Skipped offline Proxmox VE node");
+                continue;
+            }
+
+            let service_id = match self.report_node(node, &subnets).await {
+                Ok(Some(service_id)) => service_id,
+                Ok(None) => {
+                    tracing::info!(
+                        node = %node.name,
+                        ip = ?node.ip,
+                        "Skipped Proxmox VE node outside every subnet on the network"
+                    );
+                    continue;
+                }
+                Err(e) => {
+                    tracing::warn!(node = %node.name, error = %e, "Failed to import node");
+                    continue;
+                }
+            };
+
+            for kind in [GuestKind::Qemu, GuestKind::Lxc] {
+                let guests = match client.guests(&node.name, kind).await {
+                    Ok(guests) => guests,
+                    Err(e) => {
+                        tracing::warn!(node = %node.name, error = %e, "Failed to list guests");
+                        continue;
+                    }
+                };
+
+                for guest in guests.iter().filter(|g| !g.template) {
+                    if cancel.is_cancelled() {
+                        return Err(anyhow!("Discovery was cancelled"));
+                    }
+
+                    match self
+                        .report_guest(
+                            client,
+                            &node.name,
+                            kind,
+                            guest,
+                            service_id,
+                            &subnets,
+                            &known_interfaces,
+                        )
+                        .await
+                    {
+                        Ok(true) => {}
+                        Ok(false) => skipped += 1,
+                        Err(e) => tracing::warn!(
+                            vmid = guest.vmid,
+                            error = %e,
+                            "Failed to import guest"
+                        ),
+                    }
+                }
+            }
+
+            let _ = self
+                .report_scanning_progress(((index + 1) * 100 / total).min(99) as u8)
+                .await;
+        }
+
+        if skipped > 0 {
+            tracing::info!(
+                skipped,
+                "Skipped guests with no address on a subnet on the network and no MAC address \
+                 already known on it. VMs only report addresses while running with the QEMU \
+                 guest agent."
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Create the node's host with its Proxmox VE service, and return the service's ID for
+    /// the node's guests to point to
+    async fn report_node(&self, node: &ClusterNode, subnets: &[Subnet]) -> Result<Option<Uuid>> {
+        let Some(ip) = node.ip else {
+            return Ok(None);
+        };
+        let Some(subnet) = most_specific_subnet(ip, subnets) else {
+            return Ok(None);
+        };
+
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let source = || EntitySource::Discovery {
+            metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+        };
+
+        let host = Host::new(HostBase {
+            name: node.name.clone(),
+            hostname: None,
+            tags: Vec::new(),
+            network_id: subnet.base.network_id,
+            description: None,
+            source: source(),
+            virtualization: None,
+            hidden: false,
+            device_info: None,
+        });
+
+        let interface = new_interface(subnet, ip, None, 0);
+        let port = Port::new_hostless(PortType::new_tcp(PROXMOX_API_PORT));
+
+        let service = Service::new(ServiceBase {
+            name: Proxmox.name().to_string(),
+            service_definition: Box::new(Proxmox),
+            bindings: vec![Binding::new_port_serviceless(port.id, Some(interface.id))],
+            host_id: host.id,
+            tags: Vec::new(),
+            network_id: subnet.base.network_id,
+            virtualization: None,
+            product: None,
+            version: None,
+            source: EntitySource::DiscoveryWithMatch {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+                details: MatchDetails::new_certain("Imported from the Proxmox VE API"),
+            },
+            position: 0,
+        });
+
+        let response = self
+            .create_host(
+                host,
+                vec![interface],
+                vec![port],
+                vec![service],
+                Vec::new(),
+                None,
+            )
+            .await?;
+
+        Ok(response
+            .services
+            .iter()
+            .find(|s| s.base.service_definition.id() == Proxmox.id())
+            .map(|s| s.id))
+    }
+
+    /// Report a guest with an interface for each address of each of its network devices, so
+    /// the server links it by MAC or address to the host a scan found. Guests that report no
+    /// addresses, such as stopped VMs or VMs without the guest agent, are attached to the
+    /// host already holding one of their MAC addresses. Returns false when neither finds a
+    /// host on the network.
+    #[allow(clippy::too_many_arguments)]
+    async fn report_guest(
+        &self,
+        client: &ProxmoxClient,
+        node: &str,
+        kind: GuestKind,
+        guest: &GuestSummary,
+        service_id: Uuid,
+        subnets: &[Subnet],
+        known_interfaces: &[Interface],
+    ) -> Result<bool> {
+        let config = client.guest_config(node, kind, guest.vmid).await?;
+
+        let addresses = if guest.status == "running" {
+            client
+                .guest_addresses(node, kind, guest.vmid)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::debug!(
+                        vmid = guest.vmid,
+                        error = %e,
+                        "Guest addresses unavailable"
+                    );
+                    Vec::new()
+                })
+        } else {
+            Vec::new()
+        };
+
+        let nics = proxmox::guest_nics(&config, &addresses);
+        let interfaces: Vec<Interface> = nics
+            .iter()
+            .flat_map(|nic| {
+                nic.ips
+                    .iter()
+                    .filter_map(move |ip| Some((nic, *ip, most_specific_subnet(*ip, subnets)?)))
+            })
+            .enumerate()
+            .map(|(position, (nic, ip, subnet))| {
+                let mut interface =
+                    new_interface(subnet, ip, Some(nic.display_name()), position as i32);
+                interface.base.mac_address = Some(nic.mac);
+                interface
+            })
+            .collect();
+        let interfaces = if interfaces.is_empty() {
+            interfaces_by_mac(&nics, subnets, known_interfaces)
+        } else {
+            interfaces
+        };
+
+        let Some(network_id) = interfaces.first().map(|i| i.base.network_id) else {
+            return Ok(false);
+        };
+
+        let hostname = config
+            .get("hostname")
+            .and_then(|h| h.as_str())
+            .map(str::to_string);
+        let name = guest
+            .name
+            .clone()
+            .or_else(|| hostname.clone())
+            .unwrap_or_else(|| format!("{} {}", kind_label(kind), guest.vmid));
+
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let host = Host::new(HostBase {
+            name: name.clone(),
+            hostname,
+            tags: Vec::new(),
+            network_id,
+            description: None,
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            virtualization: Some(HostVirtualization::Proxmox(ProxmoxVirtualization {
+                vm_name: Some(name),
+                vm_id: Some(guest.vmid.to_string()),
+                service_id,
+            })),
+            hidden: false,
+            device_info: None,
+        });
+
+        self.create_host(host, interfaces, Vec::new(), Vec::new(), Vec::new(), None)
+            .await?;
+
+        Ok(true)
+    }
+}
+
+fn kind_label(kind: GuestKind) -> &'static str {
+    match kind {
+        GuestKind::Qemu => "VM",
+        GuestKind::Lxc => "CT",
+    }
+}
+
+/// Interfaces for a guest's network devices copied from the interfaces already known with
+/// the same MAC addresses, so the server merges the guest into the host holding them
+fn interfaces_by_mac(
+    nics: &[GuestNic],
+    subnets: &[Subnet],
+    known_interfaces: &[Interface],
+) -> Vec<Interface> {
+    nics.iter()
+        .flat_map(|nic| {
+            known_interfaces.iter().filter(move |known| {
+                known.base.mac_address == Some(nic.mac)
+                    && subnets.iter().any(|s| s.id == known.base.subnet_id)
+            })
+        })
+        .enumerate()
+        .map(|(position, known)| {
+            Interface::new(InterfaceBase {
+                host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+                position: position as i32,
+                ..known.base.clone()
+            })
+        })
+        .collect()
+}
+
+fn new_interface(subnet: &Subnet, ip: IpAddr, name: Option<String>, position: i32) -> Interface {
+    Interface::new(InterfaceBase {
+        network_id: subnet.base.network_id,
+        host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+        name,
+        subnet_id: subnet.id,
+        ip_address: ip,
+        mac_address: None,
+        position,
+        dhcp_lease: None,
+        rtt_ms: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{interface, subnet};
+
+    #[test]
+    fn test_address_less_guests_reuse_interfaces_known_by_mac() {
+        let network_id = Uuid::new_v4();
+        let known_subnet = subnet(&network_id);
+        let other_subnet = subnet(&network_id);
+
+        let mut known = interface(&network_id, &known_subnet.id);
+        known.base.host_id = Uuid::new_v4();
+        let elsewhere = interface(&network_id, &other_subnet.id);
+        let nics = [known.base.mac_address, elsewhere.base.mac_address]
+            .into_iter()
+            .flatten()
+            .map(|mac| GuestNic {
+                key: "net0".to_string(),
+                mac,
+                bridge: None,
+                vlan: None,
+                ips: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+
+        let interfaces = interfaces_by_mac(
+            &nics,
+            std::slice::from_ref(&known_subnet),
+            &[known.clone(), elsewhere],
+        );
+
+        assert_eq!(interfaces.len(), 1);
+        assert_eq!(interfaces[0].base.ip_address, known.base.ip_address);
+        assert_eq!(interfaces[0].base.mac_address, known.base.mac_address);
+        assert_eq!(interfaces[0].base.subnet_id, known_subnet.id);
+        assert_eq!(interfaces[0].base.host_id, Uuid::nil());
+        assert!(interfaces_by_mac(&nics, &[other_subnet], &[known]).is_empty());
+    }
+}
@@ -80,7 +80,7 @@ impl RunsDiscovery for DiscoveryRunner<SelfReportDiscovery> {
             started_at: Some(Utc::now()),
         };
 
-        let session = DiscoverySession::new(session_info, Vec::new());
+        let session = DiscoverySession::new(session_info, Vec::new(), request.exclusions);
         let mut current_session = self.as_ref().current_session.write().await;
         *current_session = Some(session);
         drop(current_session);
@@ -300,6 +300,7 @@ impl RunsDiscovery for DiscoveryRunner<SelfReportDiscovery> {
                 metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
             },
             hidden: false,
+            device_info: None,
             virtualization: None,
         };
 
@@ -329,6 +330,8 @@ impl RunsDiscovery for DiscoveryRunner<SelfReportDiscovery> {
                 .collect(),
             host_id: host.id,
             virtualization: None,
+            product: None,
+            version: None,
             source: EntitySource::DiscoveryWithMatch {
                 metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
                 details: MatchDetails::new_certain("Scanopy Daemon self-report"),
@@ -346,7 +349,7 @@ impl RunsDiscovery for DiscoveryRunner<SelfReportDiscovery> {
 
         // Pass interfaces and ports separately - server will create them with the correct host_id
         tracing::debug!("Creating host with interfaces, ports, and services");
-        self.create_host(host, interfaces.clone(), ports, services)
+        self.create_host(host, interfaces.clone(), ports, services, vec![], None)
             .await?;
 
         self.report_discovery_update(DiscoverySessionUpdate {
@@ -377,6 +380,7 @@ impl DiscoveryRunner<SelfReportDiscovery> {
         let capabilities = DaemonCapabilities {
             has_docker_socket,
             interfaced_subnet_ids: interfaced_subnet_ids.clone(),
+            enforces_scan_exclusions: true,
         };
 
         let daemon_id = self.as_ref().api_client.config().get_id().await?;
@@ -0,0 +1,511 @@
+use anyhow::{Error, anyhow};
+use async_trait::async_trait;
+use cidr::{IpCidr, Ipv4Cidr, Ipv6Cidr};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::daemon::discovery::service::base::{
+    CreatesDiscoveredEntities, DiscoversNetworkedEntities, DiscoveryRunner, RunsDiscovery,
+};
+use crate::daemon::utils::base::DaemonUtils;
+use crate::daemon::utils::traceroute::{self, TracedRoute};
+use crate::server::daemons::r#impl::api::DaemonDiscoveryRequest;
+use crate::server::discovery::r#impl::types::{
+    DiscoveryType, HostNamingFallback, TracerouteProtocol,
+};
+use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
+use crate::server::services::r#impl::base::ServiceMatchBaselineParams;
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
+use crate::server::subnets::r#impl::base::{Subnet, SubnetBase, most_specific_subnet};
+use crate::server::subnets::r#impl::types::SubnetType;
+
+pub struct TracerouteDiscovery {
+    targets: Vec<IpAddr>,
+    include_remote_subnets: bool,
+    protocol: TracerouteProtocol,
+    max_hops: u8,
+}
+
+impl TracerouteDiscovery {
+    pub fn new(
+        targets: Vec<IpAddr>,
+        include_remote_subnets: bool,
+        protocol: TracerouteProtocol,
+        max_hops: u8,
+    ) -> Self {
+        Self {
+            targets,
+            include_remote_subnets,
+            protocol,
+            max_hops,
+        }
+    }
+}
+
+/// A router seen on the traced routes, with the addresses it was seen or inferred to have
+#[derive(Debug)]
+struct Router {
+    /// Address it answered probes from
+    ip: IpAddr,
+    rtt_ms: Option<u32>,
+    /// Addresses on the links towards the next hops
+    downstream: Vec<IpAddr>,
+}
+
+impl CreatesDiscoveredEntities for DiscoveryRunner<TracerouteDiscovery> {}
+
+#[async_trait]
+impl RunsDiscovery for DiscoveryRunner<TracerouteDiscovery> {
+    fn discovery_type(&self) -> DiscoveryType {
+        DiscoveryType::Traceroute {
+            targets: self.domain.targets.clone(),
+            include_remote_subnets: self.domain.include_remote_subnets,
+            protocol: self.domain.protocol,
+            max_hops: self.domain.max_hops,
+        }
+    }
+
+    async fn discover(
+        &self,
+        request: DaemonDiscoveryRequest,
+        cancel: CancellationToken,
+    ) -> Result<(), Error> {
+        self.start_discovery(request).await?;
+
+        let discovery_result = self.trace_routes(cancel.clone()).await;
+
+        self.finish_discovery(discovery_result, cancel.clone())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DiscoversNetworkedEntities for DiscoveryRunner<TracerouteDiscovery> {
+    async fn get_gateway_ips(&self) -> Result<Vec<IpAddr>, Error> {
+        self.as_ref()
+            .utils
+            .get_own_routing_table_gateway_ips()
+            .await
+    }
+
+    /// Hops are placed on the subnets the server already knows about where they fall in one,
+    /// and on transit subnets created for them otherwise.
+    async fn discover_create_subnets(&self) -> Result<Vec<Subnet>, Error> {
+        self.as_ref()
+            .api_client
+            .get("/api/v1/subnets", "Failed to get subnets")
+            .await
+    }
+}
+
+impl DiscoveryRunner<TracerouteDiscovery> {
+    async fn trace_routes(&self, cancel: CancellationToken) -> Result<(), Error> {
+        if !traceroute::is_available() {
+            return Err(anyhow!(
+                "Traceroute needs raw sockets, which require root or the NET_RAW capability"
+            ));
+        }
+
+        let mut subnets = self.discover_create_subnets().await?;
+        let session = self.as_ref().get_session().await?;
+
+        let mut targets = self.domain.targets.clone();
+        if self.domain.include_remote_subnets {
+            targets.extend(remote_subnet_targets(&subnets));
+        }
+        targets.sort();
+        targets.dedup();
+        targets.retain(|ip| {
+            let excluded = session.exclusions.check(*ip, None).is_some();
+            if excluded {
+                tracing::debug!(target = %ip, "Skipping excluded traceroute target");
+            }
+            !excluded
+        });
+
+        tracing::info!(
+            targets = targets.len(),
+            protocol = %self.domain.protocol,
+            "Tracing routes"
+        );
+
+        let total = targets.len().max(1);
+        let mut routes: Vec<TracedRoute> = Vec::new();
+
+        for (index, target) in targets.iter().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            let (target, protocol, max_hops) =
+                (*target, self.domain.protocol, self.domain.max_hops);
+            match tokio::task::spawn_blocking(move || traceroute::trace(target, protocol, max_hops))
+                .await?
+            {
+                Ok(route) => routes.push(route),
+                Err(e) => tracing::warn!(target = %target, error = %e, "Failed to trace route"),
+            }
+
+            let _ = self
+                .report_scanning_progress(((index + 1) * 80 / total) as u8)
+                .await;
+        }
+
+        let routers = collect_routers(&routes, &subnets);
+        if routers.is_empty() {
+            tracing::info!("No router on the traced routes answered");
+            return Ok(());
+        }
+
+        // Routers answer as gateways, so they're matched as such
+        if let Some(session) = self.as_ref().current_session.write().await.as_mut() {
+            session.gateway_ips.extend(routers.keys().copied());
+        }
+
+        let total = routers.len();
+        for (index, router) in routers.values().enumerate() {
+            if cancel.is_cancelled() {
+                return Err(anyhow!("Discovery was cancelled"));
+            }
+
+            if let Err(e) = self.report_router(router, &mut subnets).await {
+                tracing::warn!(ip = %router.ip, error = %e, "Failed to report router");
+            }
+
+            let _ = self
+                .report_scanning_progress((80 + (index + 1) * 19 / total) as u8)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    async fn report_router(&self, router: &Router, subnets: &mut Vec<Subnet>) -> Result<(), Error> {
+        let subnet = self.subnet_for(router.ip, subnets).await?;
+        let interface = Interface::new(InterfaceBase {
+            network_id: subnet.base.network_id,
+            host_id: Uuid::nil(), // Placeholder - server will set correct host_id
+            name: None,
+            subnet_id: subnet.id,
+            ip_address: router.ip,
+            mac_address: None,
+            position: 0,
+            dhcp_lease: None,
+            rtt_ms: router.rtt_ms,
+        });
+
+        let Some((host, mut interfaces, ports, services)) = self
+            .process_host(
+                ServiceMatchBaselineParams {
+                    subnet: &subnet,
+                    interface: &interface,
+                    all_ports: &vec![],
+                    endpoint_responses: &vec![],
+                    banner_responses: &vec![],
+                    mdns_advertisements: &vec![],
+                    upnp_devices: &vec![],
+                    virtualization: &None,
+                },
+                None,
+                HostNamingFallback::Ip,
+            )
+            .await?
+        else {
+            return Ok(());
+        };
+
+        // The router's end of each link to a next hop, which puts both on the same transit
+        // subnet and so links them in the topology
+        for (position, ip) in router.downstream.iter().enumerate() {
+            let subnet = self.subnet_for(*ip, subnets).await?;
+            interfaces.push(Interface::new(InterfaceBase {
+                network_id: subnet.base.network_id,
+                host_id: Uuid::nil(),
+                name: None,
+                subnet_id: subnet.id,
+                ip_address: *ip,
+                mac_address: None,
+                position: position as i32 + 1,
+                dhcp_lease: None,
+                rtt_ms: None,
+            }));
+        }
+
+        self.create_host(host, interfaces, ports, services, Vec::new(), None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// The most specific known subnet containing `ip`, or a transit subnet created for it
+    async fn subnet_for(&self, ip: IpAddr, subnets: &mut Vec<Subnet>) -> Result<Subnet, Error> {
+        if let Some(subnet) = most_specific_subnet(ip, subnets) {
+            return Ok(subnet.clone());
+        }
+
+        let daemon_id = self.as_ref().config_store.get_id().await?;
+        let network_id = self
+            .as_ref()
+            .config_store
+            .get_network_id()
+            .await?
+            .ok_or_else(|| anyhow!("Network ID not set"))?;
+
+        let cidr = transit_cidr(ip);
+        let subnet_type = if is_public(ip) {
+            SubnetType::Internet
+        } else {
+            SubnetType::Remote
+        };
+        let subnet = Subnet::new(SubnetBase {
+            cidr,
+            network_id,
+            name: format!("Transit {}", cidr),
+            description: Some("Link between routers found by traceroute".to_string()),
+            subnet_type,
+            source: EntitySource::Discovery {
+                metadata: vec![DiscoveryMetadata::new(self.discovery_type(), daemon_id)],
+            },
+            tags: Vec::new(),
+        });
+
+        let subnet = self.create_subnet(&subnet).await?;
+        tracing::debug!(cidr = %cidr, subnet_id = %subnet.id, "Created transit subnet");
+        subnets.push(subnet.clone());
+        Ok(subnet)
+    }
+}
+
+/// Gather the routers on all routes, each once, with the addresses they must have on the links
+/// to their next hops. A next hop answers from the address of the link it was reached over,
+/// so when it's on a point-to-point link the previous router holds the other address of it.
+fn collect_routers(routes: &[TracedRoute], subnets: &[Subnet]) -> BTreeMap<IpAddr, Router> {
+    let mut routers: BTreeMap<IpAddr, Router> = BTreeMap::new();
+
+    for route in routes {
+        for hop in &route.hops {
+            let router = routers.entry(hop.ip).or_insert_with(|| Router {
+                ip: hop.ip,
+                rtt_ms: None,
+                downstream: Vec::new(),
+            });
+            let rtt_ms = hop.rtt.as_millis().min(u32::MAX as u128) as u32;
+            router.rtt_ms = Some(router.rtt_ms.map_or(rtt_ms, |rtt| rtt.min(rtt_ms)));
+        }
+
+        for pair in route.hops.windows(2) {
+            let (previous, next) = (&pair[0], &pair[1]);
+            // A silent hop between them means they aren't neighbours
+            if next.ttl != previous.ttl + 1 || most_specific_subnet(next.ip, subnets).is_some() {
+                continue;
+            }
+            let Some(mate) = link_mate(next.ip) else {
+                continue;
+            };
+            // The mate may itself have answered on another route, in which case it's a
+            // router of its own rather than an address of this one
+            if mate == previous.ip || routes.iter().flat_map(|r| &r.hops).any(|h| h.ip == mate) {
+                continue;
+            }
+
+            let downstream = &mut routers.get_mut(&previous.ip).unwrap().downstream;
+            if !downstream.contains(&mate) {
+                downstream.push(mate);
+            }
+        }
+    }
+
+    routers
+}
+
+/// An address to trace the route to on each remote subnet, leaving out the seeded 0.0.0.0/0
+/// container for remote hosts
+fn remote_subnet_targets(subnets: &[Subnet]) -> Vec<IpAddr> {
+    subnets
+        .iter()
+        .filter(|s| s.base.subnet_type == SubnetType::Remote && !s.is_organizational_subnet())
+        .filter_map(|s| first_usable_address(&s.base.cidr))
+        .collect()
+}
+
+/// The point-to-point subnet a router interface is assumed to be on: a /30 for IPv4, or a /31
+/// when the address is the network or broadcast address of its /30, and a /127 for IPv6
+fn transit_cidr(ip: IpAddr) -> IpCidr {
+    match ip {
+        IpAddr::V4(ip) => {
+            let bits = u32::from(ip);
+            let host = bits & 0b11;
+            let len = if host == 0 || host == 0b11 { 31 } else { 30 };
+            let mask = u32::MAX << (32 - len);
+            IpCidr::V4(Ipv4Cidr::new((bits & mask).into(), len).unwrap())
+        }
+        IpAddr::V6(ip) => {
+            let bits = u128::from(ip) & !1;
+            IpCidr::V6(Ipv6Cidr::new(bits.into(), 127).unwrap())
+        }
+    }
+}
+
+/// The other usable address on the transit subnet of `ip`
+fn link_mate(ip: IpAddr) -> Option<IpAddr> {
+    match (ip, transit_cidr(ip)) {
+        (IpAddr::V4(ip), IpCidr::V4(cidr)) if cidr.network_length() == 30 => {
+            let bits = u32::from(ip);
+            Some(IpAddr::V4((bits ^ 0b11).into()))
+        }
+        (IpAddr::V4(ip), _) => Some(IpAddr::V4((u32::from(ip) ^ 1).into())),
+        (IpAddr::V6(ip), _) => Some(IpAddr::V6((u128::from(ip) ^ 1).into())),
+    }
+}
+
+/// The first host address of a subnet, to trace the route to it
+fn first_usable_address(cidr: &IpCidr) -> Option<IpAddr> {
+    match cidr {
+        IpCidr::V4(cidr) if cidr.network_length() < 31 => {
+            Some(IpAddr::V4((u32::from(cidr.first_address()) + 1).into()))
+        }
+        IpCidr::V6(cidr) if cidr.network_length() < 127 => {
+            Some(IpAddr::V6((u128::from(cidr.first_address()) + 1).into()))
+        }
+        IpCidr::V4(cidr) => Some(IpAddr::V4(cidr.first_address())),
+        IpCidr::V6(cidr) => Some(IpAddr::V6(cidr.first_address())),
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                // Carrier-grade NAT, 100.64.0.0/10
+                || (ip.octets()[0] == 100 && ip.octets()[1] & 0xc0 == 64)
+                || ip.is_unspecified())
+        }
+        IpAddr::V6(ip) => {
+            !(ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || ip.is_loopback()
+                || ip.is_unspecified())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::utils::traceroute::Hop;
+    use crate::server::shared::storage::seed_data;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    fn hop(ttl: u8, ip: &str) -> Hop {
+        Hop {
+            ttl,
+            ip: IpAddr::from_str(ip).unwrap(),
+            rtt: Duration::from_millis(ttl as u64 * 5),
+        }
+    }
+
+    #[test]
+    fn test_transit_cidr_and_mate() {
+        let ip = |s: &str| IpAddr::from_str(s).unwrap();
+
+        assert_eq!(transit_cidr(ip("10.0.0.6")).to_string(), "10.0.0.4/30");
+        assert_eq!(link_mate(ip("10.0.0.6")), Some(ip("10.0.0.5")));
+        assert_eq!(transit_cidr(ip("10.0.0.4")).to_string(), "10.0.0.4/31");
+        assert_eq!(link_mate(ip("10.0.0.4")), Some(ip("10.0.0.5")));
+        assert_eq!(link_mate(ip("10.0.0.7")), Some(ip("10.0.0.6")));
+
+        assert_eq!(
+            transit_cidr(ip("2001:db8::3")).to_string(),
+            "2001:db8::2/127"
+        );
+        assert_eq!(link_mate(ip("2001:db8::3")), Some(ip("2001:db8::2")));
+
+        assert_eq!(
+            first_usable_address(&IpCidr::from_str("172.16.8.0/24").unwrap()),
+            Some(IpAddr::V4(Ipv4Addr::new(172, 16, 8, 1)))
+        );
+        assert_eq!(
+            first_usable_address(&IpCidr::from_str("fd00:1::/64").unwrap()),
+            Some(IpAddr::V6(Ipv6Addr::from_str("fd00:1::1").unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_collect_routers_links_neighbouring_hops() {
+        let target = IpAddr::V4(Ipv4Addr::new(172, 16, 8, 1));
+        let routes = vec![
+            TracedRoute {
+                target,
+                hops: vec![
+                    hop(1, "192.168.1.1"),
+                    hop(2, "10.0.0.2"),
+                    hop(4, "10.0.0.10"),
+                    hop(5, "10.0.0.14"),
+                ],
+                reached: true,
+            },
+            TracedRoute {
+                target: IpAddr::V4(Ipv4Addr::new(172, 16, 9, 1)),
+                hops: vec![hop(1, "192.168.1.1"), hop(2, "10.0.0.2")],
+                reached: false,
+            },
+        ];
+
+        let routers = collect_routers(&routes, &[]);
+        assert_eq!(routers.len(), 4);
+
+        let ip = |s: &str| IpAddr::from_str(s).unwrap();
+        let first = &routers[&ip("192.168.1.1")];
+        assert_eq!(first.downstream, vec![ip("10.0.0.1")]);
+        assert_eq!(first.rtt_ms, Some(5));
+        // A silent hop separates them, so they may not share a link
+        assert!(routers[&ip("10.0.0.2")].downstream.is_empty());
+        assert_eq!(routers[&ip("10.0.0.10")].downstream, vec![ip("10.0.0.13")]);
+        assert!(routers[&ip("10.0.0.14")].downstream.is_empty());
+    }
+
+    #[test]
+    fn test_seeded_organizational_subnets_are_ignored() {
+        let network_id = Uuid::new_v4();
+        let branch = Subnet::new(SubnetBase {
+            cidr: IpCidr::from_str("172.16.8.0/24").unwrap(),
+            network_id,
+            subnet_type: SubnetType::Remote,
+            ..Default::default()
+        });
+        let subnets = vec![
+            seed_data::create_wan_subnet(network_id),
+            seed_data::create_remote_subnet(network_id),
+            branch,
+        ];
+
+        assert_eq!(
+            remote_subnet_targets(&subnets),
+            vec![IpAddr::V4(Ipv4Addr::new(172, 16, 8, 1))]
+        );
+
+        let ip = |s: &str| IpAddr::from_str(s).unwrap();
+        assert!(most_specific_subnet(ip("10.0.0.2"), &subnets).is_none());
+        assert_eq!(
+            most_specific_subnet(ip("172.16.8.1"), &subnets).map(|s| s.base.cidr.to_string()),
+            Some("172.16.8.0/24".to_string())
+        );
+
+        let routes = vec![TracedRoute {
+            target: ip("172.16.8.1"),
+            hops: vec![hop(1, "192.168.1.1"), hop(2, "10.0.0.2")],
+            reached: false,
+        }];
+        let routers = collect_routers(&routes, &subnets);
+        assert_eq!(routers[&ip("192.168.1.1")].downstream, vec![ip("10.0.0.1")]);
+    }
+}
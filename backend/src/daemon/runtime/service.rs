@@ -424,6 +424,7 @@ impl DaemonRuntimeService {
             capabilities: DaemonCapabilities {
                 has_docker_socket,
                 interfaced_subnet_ids: Vec::new(),
+                enforces_scan_exclusions: true,
             },
             user_id,
             version: Some(version.to_string()),
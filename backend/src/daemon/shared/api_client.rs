@@ -1,4 +1,8 @@
 use crate::daemon::shared::config::ConfigStore;
+use crate::daemon::shared::outbox::OutboxEntry;
+use crate::server::discovery::r#impl::results::{
+    DISCOVERY_RESULT_ID_HEADER, DISCOVERY_SESSION_ID_HEADER,
+};
 use crate::server::shared::types::api::ApiResponse;
 use anyhow::{Error, bail};
 use reqwest::{Client, Method, RequestBuilder};
@@ -174,11 +178,54 @@ impl DaemonApiClient {
         context: &str,
         max_retries: u32,
     ) -> Result<T, Error> {
+        self.with_retry(path, max_retries, || self.post(path, body, context))
+            .await
+    }
+
+    /// POST a discovery result, tagged with its session and result IDs so the server applies
+    /// it once however many times it's replayed. Retries like `post_with_retry`. Endpoints that
+    /// respond without data, like session updates, are read as `null`.
+    pub async fn post_discovery_result<T: DeserializeOwned>(
+        &self,
+        entry: &OutboxEntry,
+        context: &str,
+        max_retries: u32,
+    ) -> Result<T, Error> {
+        self.with_retry(&entry.path, max_retries, || async {
+            let request = self
+                .build_request(Method::POST, &entry.path)
+                .await?
+                .header(DISCOVERY_SESSION_ID_HEADER, entry.session_id.to_string())
+                .header(DISCOVERY_RESULT_ID_HEADER, entry.id.to_string())
+                .json(&entry.body);
+            let response = request.send().await?;
+            let data = self
+                .check_response(response, context)
+                .await?
+                .data
+                .unwrap_or_default();
+
+            serde_json::from_value(data)
+                .map_err(|e| anyhow::anyhow!("{}: Failed to parse response data: {}", context, e))
+        })
+        .await
+    }
+
+    async fn with_retry<T, F, Fut>(
+        &self,
+        path: &str,
+        max_retries: u32,
+        request: F,
+    ) -> Result<T, Error>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
         let mut attempt = 0;
         let mut delay = Duration::from_millis(500);
 
         loop {
-            match self.post(path, body, context).await {
+            match request().await {
                 Ok(result) => return Ok(result),
                 Err(e) => {
                     attempt += 1;
@@ -200,7 +247,7 @@ impl DaemonApiClient {
     }
 
     /// Check if an error is retriable (transient network/server issues)
-    fn is_retriable_error(e: &Error) -> bool {
+    pub fn is_retriable_error(e: &Error) -> bool {
         let err_str = e.to_string().to_lowercase();
         err_str.contains("connection refused")
             || err_str.contains("error sending request")
@@ -97,6 +97,42 @@ pub struct DaemonCli {
     #[arg(long)]
     arp_rate_pps: Option<u32>,
 
+    /// Listen for ARP, DHCP, mDNS and NetBIOS broadcasts between discovery sessions and report newly seen hosts without probing them. Requires the same raw socket access as ARP scanning
+    #[arg(long)]
+    passive_discovery: Option<bool>,
+
+    /// Deep scan hosts that passive discovery sees for the first time. Leave disabled on segments that must not be probed
+    #[arg(long)]
+    passive_deep_scan: Option<bool>,
+
+    /// Find open TCP ports with half-open SYN scans instead of full connections. Linux only, requires the NET_RAW capability. Falls back to connect scans when raw sockets are unavailable
+    #[arg(long)]
+    use_syn_scan: Option<bool>,
+
+    /// Maximum SYN packets per second across all hosts being scanned (default: 1000)
+    #[arg(long)]
+    syn_rate_pps: Option<u32>,
+
+    /// Directory DHCP lease files may be imported from. Lease imports name a file in it; leave unset to disable lease imports
+    #[arg(long)]
+    dhcp_lease_dir: Option<String>,
+
+    /// Directory Proxmox VE token secret files may be read from. Proxmox discoveries name a file in it; leave unset to disable Proxmox discovery
+    #[arg(long)]
+    proxmox_secrets_dir: Option<String>,
+
+    /// Directory kubeconfig files may be read from. Kubernetes discoveries that name a kubeconfig name a file in it; leave unset to only use the daemon's own service account or default kubeconfig
+    #[arg(long)]
+    kubeconfig_dir: Option<String>,
+
+    /// Directory TLS files and forwarded sockets for remote Docker endpoints may be read from. Docker discoveries with a unix:// endpoint or TLS files name them in it; leave unset to only allow tcp:// and http:// endpoints
+    #[arg(long)]
+    docker_endpoint_dir: Option<String>,
+
+    /// Allow kubeconfigs read from the kubeconfig directory to run exec or auth provider credential plugins. Leave disabled unless you trust every file in that directory
+    #[arg(long)]
+    allow_kubeconfig_exec: Option<bool>,
+
     /// Restrict daemon to specific network interface(s). Comma-separated for multiple (e.g., eth0,eth1). Leave empty for all interfaces. Only applies to network discovery
     #[arg(long, value_delimiter = ',')]
     interfaces: Option<Vec<String>>,
@@ -154,6 +190,24 @@ pub struct AppConfig {
     /// Network interfaces to restrict scanning to. Empty means all interfaces.
     #[serde(default)]
     pub interface_filter: Vec<String>,
+    #[serde(default)]
+    pub passive_discovery: bool,
+    #[serde(default)]
+    pub passive_deep_scan: bool,
+    #[serde(default)]
+    pub use_syn_scan: bool,
+    #[serde(default = "default_syn_rate_pps")]
+    pub syn_rate_pps: u32,
+    #[serde(default)]
+    pub dhcp_lease_dir: Option<String>,
+    #[serde(default)]
+    pub proxmox_secrets_dir: Option<String>,
+    #[serde(default)]
+    pub kubeconfig_dir: Option<String>,
+    #[serde(default)]
+    pub docker_endpoint_dir: Option<String>,
+    #[serde(default)]
+    pub allow_kubeconfig_exec: bool,
 }
 
 fn default_arp_retries() -> u32 {
@@ -164,6 +218,10 @@ fn default_arp_rate_pps() -> u32 {
     50 // Default: 50 pps, safe for most enterprise switches
 }
 
+fn default_syn_rate_pps() -> u32 {
+    1000 // Default: 1000 pps, well below what stateful firewalls flag as a flood
+}
+
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
@@ -193,6 +251,15 @@ impl Default for AppConfig {
             arp_retries: default_arp_retries(),
             arp_rate_pps: default_arp_rate_pps(),
             interface_filter: Vec::new(),
+            passive_discovery: false,
+            passive_deep_scan: false,
+            use_syn_scan: false,
+            syn_rate_pps: default_syn_rate_pps(),
+            dhcp_lease_dir: None,
+            proxmox_secrets_dir: None,
+            kubeconfig_dir: None,
+            docker_endpoint_dir: None,
+            allow_kubeconfig_exec: false,
         }
     }
 }
@@ -306,6 +373,33 @@ impl AppConfig {
         if let Some(arp_rate_pps) = cli_args.arp_rate_pps {
             figment = figment.merge(("arp_rate_pps", arp_rate_pps));
         }
+        if let Some(passive_discovery) = cli_args.passive_discovery {
+            figment = figment.merge(("passive_discovery", passive_discovery));
+        }
+        if let Some(passive_deep_scan) = cli_args.passive_deep_scan {
+            figment = figment.merge(("passive_deep_scan", passive_deep_scan));
+        }
+        if let Some(use_syn_scan) = cli_args.use_syn_scan {
+            figment = figment.merge(("use_syn_scan", use_syn_scan));
+        }
+        if let Some(syn_rate_pps) = cli_args.syn_rate_pps {
+            figment = figment.merge(("syn_rate_pps", syn_rate_pps));
+        }
+        if let Some(dhcp_lease_dir) = cli_args.dhcp_lease_dir {
+            figment = figment.merge(("dhcp_lease_dir", dhcp_lease_dir));
+        }
+        if let Some(proxmox_secrets_dir) = cli_args.proxmox_secrets_dir {
+            figment = figment.merge(("proxmox_secrets_dir", proxmox_secrets_dir));
+        }
+        if let Some(kubeconfig_dir) = cli_args.kubeconfig_dir {
+            figment = figment.merge(("kubeconfig_dir", kubeconfig_dir));
+        }
+        if let Some(docker_endpoint_dir) = cli_args.docker_endpoint_dir {
+            figment = figment.merge(("docker_endpoint_dir", docker_endpoint_dir));
+        }
+        if let Some(allow_kubeconfig_exec) = cli_args.allow_kubeconfig_exec {
+            figment = figment.merge(("allow_kubeconfig_exec", allow_kubeconfig_exec));
+        }
         if let Some(interface) = cli_args.interfaces {
             figment = figment.merge(("interface_filter", interface));
         }
@@ -331,6 +425,12 @@ impl ConfigStore {
         }
     }
 
+    /// Directory for discovery results waiting to be delivered to the server, next to the
+    /// config file so each named daemon has its own
+    pub fn outbox_dir(&self) -> PathBuf {
+        self.path.with_file_name("outbox")
+    }
+
     pub async fn initialize(&self) -> Result<()> {
         // Ensure parent directory exists
         if let Some(parent) = self.path.parent() {
@@ -533,6 +633,51 @@ impl ConfigStore {
         Ok(config.arp_rate_pps)
     }
 
+    pub async fn get_passive_discovery(&self) -> Result<bool> {
+        let config = self.config.read().await;
+        Ok(config.passive_discovery)
+    }
+
+    pub async fn get_passive_deep_scan(&self) -> Result<bool> {
+        let config = self.config.read().await;
+        Ok(config.passive_deep_scan)
+    }
+
+    pub async fn get_use_syn_scan(&self) -> Result<bool> {
+        let config = self.config.read().await;
+        Ok(config.use_syn_scan)
+    }
+
+    pub async fn get_syn_rate_pps(&self) -> Result<u32> {
+        let config = self.config.read().await;
+        Ok(config.syn_rate_pps)
+    }
+
+    pub async fn get_dhcp_lease_dir(&self) -> Result<Option<PathBuf>> {
+        let config = self.config.read().await;
+        Ok(config.dhcp_lease_dir.as_ref().map(PathBuf::from))
+    }
+
+    pub async fn get_proxmox_secrets_dir(&self) -> Result<Option<PathBuf>> {
+        let config = self.config.read().await;
+        Ok(config.proxmox_secrets_dir.as_ref().map(PathBuf::from))
+    }
+
+    pub async fn get_kubeconfig_dir(&self) -> Result<Option<PathBuf>> {
+        let config = self.config.read().await;
+        Ok(config.kubeconfig_dir.as_ref().map(PathBuf::from))
+    }
+
+    pub async fn get_docker_endpoint_dir(&self) -> Result<Option<PathBuf>> {
+        let config = self.config.read().await;
+        Ok(config.docker_endpoint_dir.as_ref().map(PathBuf::from))
+    }
+
+    pub async fn get_allow_kubeconfig_exec(&self) -> Result<bool> {
+        let config = self.config.read().await;
+        Ok(config.allow_kubeconfig_exec)
+    }
+
     pub async fn get_interface_filter(&self) -> Result<Vec<String>> {
         let config = self.config.read().await;
         Ok(config.interface_filter.clone())
@@ -2,4 +2,5 @@ pub mod api_client;
 pub mod config;
 pub mod handlers;
 pub mod middleware;
+pub mod outbox;
 pub mod services;
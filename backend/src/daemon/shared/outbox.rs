@@ -0,0 +1,292 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, path::PathBuf};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// A discovery result that couldn't be delivered to the server, kept on disk until it is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Result ID the server dedupes replays by, together with the session
+    pub id: Uuid,
+    pub session_id: Uuid,
+    /// Server endpoint the result is posted to
+    pub path: String,
+    pub body: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OutboxEntry {
+    pub fn new(session_id: Uuid, path: &str, body: Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            session_id,
+            path: path.to_string(),
+            body,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Filenames sort in the order entries were queued, so they're replayed in that order
+    fn file_name(&self) -> String {
+        format!(
+            "{:020}-{}.json",
+            self.created_at.timestamp_micros(),
+            self.id
+        )
+    }
+}
+
+/// Durable queue of discovery results, one JSON file per entry. Entries are written to a
+/// temporary file and renamed into place, so a crash never leaves a partial entry behind.
+pub struct DiscoveryOutbox {
+    dir: PathBuf,
+    /// Serializes writes, so rewriting pending entries can't race with queueing new ones
+    lock: Mutex<()>,
+}
+
+impl DiscoveryOutbox {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub async fn push(&self, entry: &OutboxEntry) -> Result<()> {
+        let _guard = self.lock.lock().await;
+        async_fs::create_dir_all(&self.dir)
+            .await
+            .context("Failed to create outbox directory")?;
+        self.write(&self.dir.join(entry.file_name()), entry).await
+    }
+
+    /// Entries waiting to be delivered, oldest first
+    pub async fn pending(&self) -> Result<Vec<(PathBuf, OutboxEntry)>> {
+        let mut entries = Vec::new();
+        for path in self.paths().await? {
+            let content = async_fs::read_to_string(&path).await?;
+            match serde_json::from_str(&content) {
+                Ok(entry) => entries.push((path, entry)),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), error = %e, "Discarding unreadable outbox entry");
+                    let _ = async_fs::remove_file(&path).await;
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.paths().await.map(|p| p.is_empty()).unwrap_or(true)
+    }
+
+    /// Paths of queued entries, oldest first
+    async fn paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths: Vec<PathBuf> = match async_fs::read_dir(&self.dir).await {
+            Ok(dir) => {
+                dir.filter_map(|entry| async move { entry.ok().map(|e| e.path()) })
+                    .filter(|path| {
+                        futures::future::ready(path.extension().is_some_and(|ext| ext == "json"))
+                    })
+                    .collect()
+                    .await
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).context("Failed to read outbox directory"),
+        };
+        paths.sort();
+        Ok(paths)
+    }
+
+    pub async fn remove(&self, path: &PathBuf) -> Result<()> {
+        let _guard = self.lock.lock().await;
+        match async_fs::remove_file(path).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(e).context("Failed to remove outbox entry")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Rewrite pending entries to use the IDs the server assigned. The daemon generates IDs
+    /// for what it discovers, but the server may match them to existing entities with other
+    /// IDs, and results queued later refer to them by the daemon's IDs.
+    pub async fn replace_ids(&self, ids: &HashMap<Uuid, Uuid>) -> Result<()> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        for (path, mut entry) in self.pending().await? {
+            if replace_ids(&mut entry.body, ids) {
+                let _guard = self.lock.lock().await;
+                self.write(&path, &entry).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn write(&self, path: &PathBuf, entry: &OutboxEntry) -> Result<()> {
+        let temp_path = path.with_extension("tmp");
+        async_fs::write(&temp_path, serde_json::to_vec(entry)?)
+            .await
+            .context("Failed to write outbox entry")?;
+        async_fs::rename(&temp_path, path)
+            .await
+            .context("Failed to move outbox entry into place")?;
+        Ok(())
+    }
+}
+
+/// IDs the server assigned in place of the ones a result was sent with: the entity's own, and
+/// those of a host's services, which are matched by name
+pub fn assigned_ids(sent: &Value, returned: &Value) -> HashMap<Uuid, Uuid> {
+    let mut ids = HashMap::new();
+    let id = |value: &Value| {
+        value
+            .get("id")
+            .and_then(Value::as_str)
+            .and_then(|id| Uuid::parse_str(id).ok())
+    };
+
+    // Host results wrap the host alongside its children
+    let entity = sent.get("host").unwrap_or(sent);
+    if let (Some(from), Some(to)) = (id(entity), id(returned))
+        && from != to
+    {
+        ids.insert(from, to);
+    }
+
+    if let (Some(sent_services), Some(returned_services)) = (
+        sent.get("services").and_then(Value::as_array),
+        returned.get("services").and_then(Value::as_array),
+    ) {
+        for service in sent_services {
+            let matching = returned_services
+                .iter()
+                .find(|s| s.get("name").is_some() && s.get("name") == service.get("name"));
+            if let (Some(from), Some(to)) = (id(service), matching.and_then(id))
+                && from != to
+            {
+                ids.insert(from, to);
+            }
+        }
+    }
+
+    ids
+}
+
+/// Replace every string equal to one of the IDs, at any depth. Returns whether any was.
+pub fn replace_ids(value: &mut Value, ids: &HashMap<Uuid, Uuid>) -> bool {
+    match value {
+        Value::String(s) => match Uuid::parse_str(s).ok().and_then(|id| ids.get(&id)) {
+            Some(replacement) => {
+                *s = replacement.to_string();
+                true
+            }
+            None => false,
+        },
+        Value::Array(values) => values
+            .iter_mut()
+            .fold(false, |replaced, v| replace_ids(v, ids) || replaced),
+        Value::Object(fields) => fields
+            .values_mut()
+            .fold(false, |replaced, v| replace_ids(v, ids) || replaced),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_outbox_replays_in_order_and_rewrites_ids() {
+        let dir = tempfile::tempdir().unwrap();
+        let outbox = DiscoveryOutbox::new(dir.path().join("outbox"));
+        assert!(outbox.is_empty().await);
+
+        let session_id = Uuid::new_v4();
+        let local_subnet_id = Uuid::new_v4();
+        let server_subnet_id = Uuid::new_v4();
+
+        let subnet = OutboxEntry::new(
+            session_id,
+            "/api/v1/subnets",
+            json!({"id": local_subnet_id, "cidr": "10.0.0.0/24"}),
+        );
+        outbox.push(&subnet).await.unwrap();
+        let host = OutboxEntry::new(
+            session_id,
+            "/api/v1/hosts/discovery",
+            json!({"host": {"id": Uuid::new_v4()}, "interfaces": [{"subnet_id": local_subnet_id}]}),
+        );
+        outbox.push(&host).await.unwrap();
+
+        let pending = outbox.pending().await.unwrap();
+        assert_eq!(
+            pending.iter().map(|(_, e)| e.id).collect::<Vec<_>>(),
+            vec![subnet.id, host.id]
+        );
+
+        // The server already had the subnet under another ID
+        let ids = assigned_ids(
+            &subnet.body,
+            &json!({"id": server_subnet_id, "cidr": "10.0.0.0/24"}),
+        );
+        assert_eq!(ids, HashMap::from([(local_subnet_id, server_subnet_id)]));
+        outbox.remove(&pending[0].0).await.unwrap();
+        outbox.replace_ids(&ids).await.unwrap();
+
+        let pending = outbox.pending().await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(
+            pending[0].1.body["interfaces"][0]["subnet_id"],
+            json!(server_subnet_id)
+        );
+        assert_eq!(pending[0].1.id, host.id);
+    }
+
+    #[test]
+    fn test_assigned_ids_matches_services_by_name() {
+        let host_id = Uuid::new_v4();
+        let (local_service, server_service) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let ids = assigned_ids(
+            &json!({
+                "host": {"id": host_id},
+                "services": [{"id": local_service, "name": "Docker"}],
+            }),
+            &json!({
+                "id": host_id,
+                "services": [{"id": server_service, "name": "Docker"}],
+            }),
+        );
+
+        assert_eq!(ids, HashMap::from([(local_service, server_service)]));
+    }
+
+    #[test]
+    fn test_replace_ids_rewrites_references_at_any_depth() {
+        let (local, server) = (Uuid::new_v4(), Uuid::new_v4());
+        let unrelated = Uuid::new_v4();
+        let ids = HashMap::from([(local, server)]);
+
+        let mut group = json!({
+            "id": unrelated,
+            "binding_ids": [local, unrelated],
+        });
+        assert!(replace_ids(&mut group, &ids));
+        assert_eq!(group["id"], json!(unrelated));
+        assert_eq!(group["binding_ids"], json!([server, unrelated]));
+
+        let mut untouched = json!({"id": unrelated});
+        assert!(!replace_ids(&mut untouched, &ids));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::server::discovery::r#impl::types::DiscoveryType;
+use crate::server::discovery::r#impl::types::{DiscoveryType, DockerEndpoint};
 use crate::server::interfaces::r#impl::base::{Interface, InterfaceBase};
 use crate::server::shared::storage::traits::Storable;
 use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
@@ -16,7 +16,7 @@ use net_route::Handle;
 use pnet::ipnetwork::IpNetwork;
 use std::collections::HashMap;
 use std::net::IpAddr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::time::Duration;
 use uuid::Uuid;
@@ -198,6 +198,8 @@ pub trait DaemonUtils {
                     ip_address: ip_addr,
                     mac_address,
                     position: interfaces.len() as i32,
+                    dhcp_lease: None,
+                    rtt_ms: None,
                 }));
             }
         }
@@ -212,10 +214,6 @@ pub trait DaemonUtils {
         docker_proxy: Result<Option<String>, Error>,
         docker_proxy_ssl_info: Result<Option<(String, String, String)>, Error>,
     ) -> Result<Docker, Error> {
-        use tokio::time::timeout;
-
-        const DOCKER_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
-
         tracing::debug!("Creating Docker client connection");
         let start = std::time::Instant::now();
 
@@ -247,39 +245,54 @@ pub trait DaemonUtils {
                 .map_err(|e| anyhow::anyhow!("Failed to connect to Docker: {}", e))?
         };
 
-        // Add timeout to Docker ping to prevent indefinite blocking
-        tracing::debug!(
-            "Pinging Docker daemon (timeout: {:?})",
-            DOCKER_CONNECT_TIMEOUT
-        );
-        match timeout(DOCKER_CONNECT_TIMEOUT, client.ping()).await {
-            Ok(Ok(_)) => {
-                tracing::info!(
-                    elapsed_ms = start.elapsed().as_millis(),
-                    "Docker client connected successfully"
-                );
-                Ok(client)
-            }
-            Ok(Err(e)) => {
-                tracing::warn!(
-                    elapsed_ms = start.elapsed().as_millis(),
-                    error = %e,
-                    "Docker ping failed"
-                );
-                Err(anyhow::anyhow!("Docker ping failed: {}", e))
-            }
-            Err(_) => {
-                tracing::warn!(
-                    elapsed_ms = start.elapsed().as_millis(),
-                    "Docker ping timed out after {:?}",
-                    DOCKER_CONNECT_TIMEOUT
-                );
-                Err(anyhow::anyhow!(
-                    "Docker connection timed out after {:?}",
-                    DOCKER_CONNECT_TIMEOUT
-                ))
-            }
+        ping_docker_client(client, start).await
+    }
+
+    /// Connect to a remote Docker engine, directly, through a socket proxy, or through a socket
+    /// forwarded into the endpoint directory. TLS files and sockets must be in that directory.
+    async fn new_remote_docker_client(
+        &self,
+        endpoint: &DockerEndpoint,
+        endpoint_dir: Option<PathBuf>,
+    ) -> Result<Docker, Error> {
+        tracing::debug!(url = %endpoint.url, "Creating remote Docker client connection");
+        let start = std::time::Instant::now();
+
+        let endpoint_dir = || {
+            endpoint_dir.as_deref().ok_or_else(|| {
+                anyhow!(
+                    "Docker endpoints with sockets or TLS files are disabled on this daemon; set --docker-endpoint-dir to enable them"
+                )
+            })
+        };
+
+        let client = if let Some(socket) = endpoint.url.strip_prefix("unix://") {
+            let dir = endpoint_dir()?;
+            let socket =
+                resolve_socket_in_directory(dir, Path::new(socket), "Docker endpoint").await?;
+            Docker::connect_with_socket(&socket.to_string_lossy(), 4, API_DEFAULT_VERSION)
+        } else if let Some(tls) = &endpoint.tls {
+            let dir = endpoint_dir()?;
+            let key_path =
+                resolve_in_directory(dir, Path::new(&tls.key_path), "Docker endpoint").await?;
+            let cert_path =
+                resolve_in_directory(dir, Path::new(&tls.cert_path), "Docker endpoint").await?;
+            let ca_path =
+                resolve_in_directory(dir, Path::new(&tls.ca_path), "Docker endpoint").await?;
+            Docker::connect_with_ssl(
+                &endpoint.url,
+                &key_path,
+                &cert_path,
+                &ca_path,
+                4,
+                API_DEFAULT_VERSION,
+            )
+        } else {
+            Docker::connect_with_http(&endpoint.url, 4, API_DEFAULT_VERSION)
         }
+        .map_err(|e| anyhow::anyhow!("Failed to connect to Docker at {}: {}", endpoint.url, e))?;
+
+        ping_docker_client(client, start).await
     }
 
     async fn get_subnets_from_docker_networks(
@@ -510,3 +523,230 @@ pub type PlatformDaemonUtils = WindowsDaemonUtils;
 pub fn create_system_utils() -> PlatformDaemonUtils {
     PlatformDaemonUtils::new()
 }
+
+/// Resolve a file requested by a discovery against a directory configured on the daemon.
+/// Relative paths are taken from the directory; the resolved file, after following symlinks,
+/// must be inside it, so the server can't point the daemon at arbitrary files.
+pub async fn resolve_in_directory(
+    dir: &Path,
+    requested: &Path,
+    kind: &str,
+) -> Result<PathBuf, Error> {
+    resolve_entry_in_directory(dir, requested, kind, "file", |metadata| metadata.is_file()).await
+}
+
+/// Like [`resolve_in_directory`], for a socket such as a Docker engine's forwarded over SSH
+pub async fn resolve_socket_in_directory(
+    dir: &Path,
+    requested: &Path,
+    kind: &str,
+) -> Result<PathBuf, Error> {
+    resolve_entry_in_directory(dir, requested, kind, "socket", |metadata| {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+            metadata.file_type().is_socket()
+        }
+        #[cfg(not(unix))]
+        {
+            !metadata.is_dir()
+        }
+    })
+    .await
+}
+
+async fn resolve_entry_in_directory(
+    dir: &Path,
+    requested: &Path,
+    kind: &str,
+    entry: &str,
+    is_entry: impl Fn(&std::fs::Metadata) -> bool,
+) -> Result<PathBuf, Error> {
+    let dir = tokio::fs::canonicalize(dir)
+        .await
+        .map_err(|e| anyhow!("Could not open {} directory {}: {}", kind, dir.display(), e))?;
+    let path = tokio::fs::canonicalize(dir.join(requested))
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Could not read {} {} {}: {}",
+                kind,
+                entry,
+                requested.display(),
+                e
+            )
+        })?;
+
+    let is_entry = tokio::fs::metadata(&path)
+        .await
+        .is_ok_and(|metadata| is_entry(&metadata));
+    if !path.starts_with(&dir) || !is_entry {
+        return Err(anyhow!(
+            "{} is not a {} in the daemon's {} directory",
+            requested.display(),
+            entry,
+            kind
+        ));
+    }
+
+    Ok(path)
+}
+
+/// Ping a new Docker client, with a timeout so an unreachable engine can't block discovery
+async fn ping_docker_client(client: Docker, start: std::time::Instant) -> Result<Docker, Error> {
+    use tokio::time::timeout;
+
+    const DOCKER_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    // Add timeout to Docker ping to prevent indefinite blocking
+    tracing::debug!(
+        "Pinging Docker daemon (timeout: {:?})",
+        DOCKER_CONNECT_TIMEOUT
+    );
+    match timeout(DOCKER_CONNECT_TIMEOUT, client.ping()).await {
+        Ok(Ok(_)) => {
+            tracing::info!(
+                elapsed_ms = start.elapsed().as_millis(),
+                "Docker client connected successfully"
+            );
+            Ok(client)
+        }
+        Ok(Err(e)) => {
+            tracing::warn!(
+                elapsed_ms = start.elapsed().as_millis(),
+                error = %e,
+                "Docker ping failed"
+            );
+            Err(anyhow::anyhow!("Docker ping failed: {}", e))
+        }
+        Err(_) => {
+            tracing::warn!(
+                elapsed_ms = start.elapsed().as_millis(),
+                "Docker ping timed out after {:?}",
+                DOCKER_CONNECT_TIMEOUT
+            );
+            Err(anyhow::anyhow!(
+                "Docker connection timed out after {:?}",
+                DOCKER_CONNECT_TIMEOUT
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolved_path_must_stay_in_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let lease_dir = root.path().join("leases");
+        std::fs::create_dir(&lease_dir).unwrap();
+        std::fs::write(lease_dir.join("dhcpd.leases"), "").unwrap();
+        std::fs::write(root.path().join("secret"), "").unwrap();
+
+        let resolved = resolve_in_directory(&lease_dir, Path::new("dhcpd.leases"), "lease")
+            .await
+            .unwrap();
+        assert!(resolved.ends_with("leases/dhcpd.leases"));
+
+        let absolute = lease_dir.join("dhcpd.leases");
+        assert!(
+            resolve_in_directory(&lease_dir, &absolute, "lease")
+                .await
+                .is_ok()
+        );
+
+        for outside in ["../secret", "/etc/passwd", "."] {
+            assert!(
+                resolve_in_directory(&lease_dir, Path::new(outside), "lease")
+                    .await
+                    .is_err(),
+                "{} should be refused",
+                outside
+            );
+        }
+        assert!(
+            resolve_in_directory(&lease_dir, &root.path().join("secret"), "lease")
+                .await
+                .is_err()
+        );
+
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(root.path().join("secret"), lease_dir.join("link")).unwrap();
+            assert!(
+                resolve_in_directory(&lease_dir, Path::new("link"), "lease")
+                    .await
+                    .is_err()
+            );
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_remote_docker_paths_must_stay_in_endpoint_directory() {
+        use crate::server::discovery::r#impl::types::DockerTlsFiles;
+
+        let root = tempfile::tempdir().unwrap();
+        let endpoint_dir = root.path().join("docker");
+        std::fs::create_dir(&endpoint_dir).unwrap();
+        let _forwarded =
+            std::os::unix::net::UnixListener::bind(endpoint_dir.join("nas.sock")).unwrap();
+        let _outside =
+            std::os::unix::net::UnixListener::bind(root.path().join("other.sock")).unwrap();
+        std::fs::write(endpoint_dir.join("cert.pem"), "").unwrap();
+
+        assert!(
+            resolve_socket_in_directory(&endpoint_dir, Path::new("nas.sock"), "Docker endpoint")
+                .await
+                .is_ok()
+        );
+        assert!(
+            resolve_socket_in_directory(&endpoint_dir, Path::new("cert.pem"), "Docker endpoint")
+                .await
+                .is_err()
+        );
+
+        let utils = create_system_utils();
+        let unix = |path: &Path| DockerEndpoint {
+            url: format!("unix://{}", path.display()),
+            tls: None,
+        };
+        let tls = |ca_path: &str| DockerEndpoint {
+            url: "https://docker.lan:2376".to_string(),
+            tls: Some(DockerTlsFiles {
+                cert_path: "cert.pem".to_string(),
+                key_path: "cert.pem".to_string(),
+                ca_path: ca_path.to_string(),
+            }),
+        };
+
+        for (endpoint, dir) in [
+            (unix(&endpoint_dir.join("nas.sock")), None),
+            (
+                unix(&root.path().join("other.sock")),
+                Some(endpoint_dir.clone()),
+            ),
+            (
+                unix(Path::new("/var/run/docker.sock")),
+                Some(endpoint_dir.clone()),
+            ),
+            (tls("cert.pem"), None),
+            (
+                tls("/etc/ssl/certs/ca-certificates.crt"),
+                Some(endpoint_dir.clone()),
+            ),
+            (tls("../other.sock"), Some(endpoint_dir.clone())),
+        ] {
+            assert!(
+                utils
+                    .new_remote_docker_client(&endpoint, dir)
+                    .await
+                    .is_err(),
+                "{:?} should be refused",
+                endpoint
+            );
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! References to other services in a container's configuration.
+//!
+//! Containers name what they depend on in their environment, ie `DATABASE_URL=postgres://db/app`
+//! or `REDIS_HOST=cache` with `REDIS_PORT=6380`, in their command line, and in the `depends_on`
+//! and `links` of their Compose file. Each reference is a host, which may be a container name or
+//! an address, and the port when one is given or implied by a URL's scheme.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+use url::Url;
+
+/// Env var suffixes whose value is a bare host, with the port in a sibling var if anywhere
+const HOST_KEY_SUFFIXES: [&str; 5] = ["_HOST", "_HOSTNAME", "_SERVER", "_ADDR", "_ADDRESS"];
+
+/// Compose records `depends_on` as "db:service_started:false,cache:service_healthy:true"
+const COMPOSE_DEPENDS_ON_LABEL: &str = "com.docker.compose.depends_on";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    pub host: String,
+    pub port: Option<u16>,
+    /// Where the reference was found, ie "DATABASE_URL" or "depends_on"
+    pub evidence: String,
+}
+
+/// References in a container's env ("KEY=value" entries), command line, labels and links,
+/// leaving out the ones that point back at the container itself
+pub fn container_references(
+    env: &[String],
+    args: &[String],
+    labels: &BTreeMap<String, String>,
+    links: &[String],
+) -> Vec<Reference> {
+    let env: BTreeMap<&str, &str> = env.iter().filter_map(|e| e.split_once('=')).collect();
+    let mut references = Vec::new();
+
+    for (key, value) in &env {
+        let found = endpoints_in(value);
+        if !found.is_empty() {
+            references.extend(found.into_iter().map(|(host, port)| Reference {
+                host,
+                port,
+                evidence: key.to_string(),
+            }));
+            continue;
+        }
+
+        let Some(prefix) = HOST_KEY_SUFFIXES
+            .iter()
+            .find_map(|suffix| key.strip_suffix(suffix))
+        else {
+            continue;
+        };
+        if is_host(value) {
+            references.push(Reference {
+                host: value.to_string(),
+                port: env
+                    .get(format!("{}_PORT", prefix).as_str())
+                    .and_then(|p| p.parse().ok()),
+                evidence: key.to_string(),
+            });
+        }
+    }
+
+    for arg in args {
+        // Options are often given as "--db=postgres://db/app"
+        let value = match arg.split_once('=') {
+            Some((option, value)) if option.starts_with('-') => value,
+            _ => arg,
+        };
+        references.extend(
+            endpoints_in(value)
+                .into_iter()
+                .map(|(host, port)| Reference {
+                    host,
+                    port,
+                    evidence: "command".to_string(),
+                }),
+        );
+    }
+
+    if let Some(depends_on) = labels.get(COMPOSE_DEPENDS_ON_LABEL) {
+        references.extend(
+            depends_on
+                .split(',')
+                .filter_map(|d| d.split(':').next())
+                .filter(|service| !service.is_empty())
+                .map(|service| Reference {
+                    host: service.to_string(),
+                    port: None,
+                    evidence: "depends_on".to_string(),
+                }),
+        );
+    }
+
+    // Legacy links, as "/db:/app/db" from inspect or "db:alias" as written
+    references.extend(links.iter().filter_map(|link| {
+        let name = link.split(':').next()?.trim_start_matches('/');
+        (!name.is_empty()).then(|| Reference {
+            host: name.to_string(),
+            port: None,
+            evidence: "links".to_string(),
+        })
+    }));
+
+    let mut unique: Vec<Reference> = Vec::new();
+    for reference in references {
+        if is_loopback(&reference.host) {
+            continue;
+        }
+        match unique
+            .iter_mut()
+            .find(|r| r.host.eq_ignore_ascii_case(&reference.host))
+        {
+            // A reference with a port says more than one without, ie a DATABASE_URL over a
+            // depends_on for the same container
+            Some(existing) if existing.port.is_none() && reference.port.is_some() => {
+                *existing = reference
+            }
+            Some(existing) if existing.port == reference.port || reference.port.is_none() => {}
+            _ => unique.push(reference),
+        }
+    }
+
+    unique
+}
+
+/// URLs and host:port pairs in a value, which may be a list like "kafka-1:9092,kafka-2:9092"
+fn endpoints_in(value: &str) -> Vec<(String, Option<u16>)> {
+    value
+        .split(|c: char| c.is_whitespace() || c == ',' || c == ';')
+        .filter(|token| !token.is_empty())
+        .filter_map(|token| {
+            if token.contains("://") {
+                return url_endpoint(token);
+            }
+
+            let (host, port) = token.rsplit_once(':')?;
+            let port = port.parse::<u16>().ok()?;
+            (is_host(host) && (host.parse::<IpAddr>().is_ok() || has_letter(host)))
+                .then(|| (host.to_string(), Some(port)))
+        })
+        .collect()
+}
+
+fn url_endpoint(token: &str) -> Option<(String, Option<u16>)> {
+    // JDBC URLs wrap the database's own, ie "jdbc:postgresql://db:5432/app"
+    let token = token.strip_prefix("jdbc:").unwrap_or(token);
+    let url = Url::parse(token).ok()?;
+    let host = url.host_str()?.trim_matches(|c| c == '[' || c == ']');
+    if host.is_empty() {
+        return None;
+    }
+
+    let port = url
+        .port()
+        .or_else(|| default_port(url.scheme()))
+        .or_else(|| url.port_or_known_default());
+    Some((host.to_string(), port))
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "postgres" | "postgresql" => Some(5432),
+        "mysql" | "mariadb" => Some(3306),
+        "redis" | "rediss" => Some(6379),
+        "mongodb" => Some(27017),
+        "amqp" => Some(5672),
+        "amqps" => Some(5671),
+        "nats" => Some(4222),
+        "mqtt" => Some(1883),
+        "memcached" => Some(11211),
+        "ldap" => Some(389),
+        "ldaps" => Some(636),
+        _ => None,
+    }
+}
+
+fn is_host(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= 253
+        && (value.parse::<IpAddr>().is_ok()
+            || value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_')))
+        && !value.starts_with(['-', '.'])
+}
+
+fn has_letter(value: &str) -> bool {
+    value.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback() || ip.is_unspecified())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn reference(host: &str, port: Option<u16>, evidence: &str) -> Reference {
+        Reference {
+            host: host.to_string(),
+            port,
+            evidence: evidence.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_references_in_env() {
+        let env = strings(&[
+            "DATABASE_URL=postgres://app:secret@db/app?sslmode=disable",
+            "REDIS_HOST=cache",
+            "REDIS_PORT=6380",
+            "KAFKA_BROKERS=kafka-1:9092,kafka-2:9092",
+            "SMTP_SERVER=localhost",
+            "TZ=Europe/Berlin",
+            "BACKUP_TIME=12:30",
+            "PATH=/usr/local/bin:/usr/bin",
+        ]);
+
+        assert_eq!(
+            container_references(&env, &[], &BTreeMap::new(), &[]),
+            vec![
+                reference("db", Some(5432), "DATABASE_URL"),
+                reference("kafka-1", Some(9092), "KAFKA_BROKERS"),
+                reference("kafka-2", Some(9092), "KAFKA_BROKERS"),
+                reference("cache", Some(6380), "REDIS_HOST"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_references_in_args_labels_and_links() {
+        let args = strings(&[
+            "--upstream=http://10.0.0.20:8080/api",
+            "--jdbc",
+            "jdbc:mysql://mariadb/app",
+        ]);
+        let labels = BTreeMap::from([(
+            COMPOSE_DEPENDS_ON_LABEL.to_string(),
+            "mariadb:service_started:false,search:service_healthy:true".to_string(),
+        )]);
+        let links = strings(&["/search:/app/search"]);
+
+        assert_eq!(
+            container_references(&[], &args, &labels, &links),
+            vec![
+                reference("10.0.0.20", Some(8080), "command"),
+                reference("mariadb", Some(3306), "command"),
+                reference("search", None, "depends_on"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,563 @@
+//! DHCP lease database parsing.
+//!
+//! Reads the lease files DHCP servers keep on disk and turns them into per-address leases. Each
+//! format is an append-style log, so a later entry for an address replaces an earlier one, and an
+//! entry saying the address was released removes it.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use mac_address::MacAddress;
+use serde::Deserialize;
+
+use crate::server::discovery::r#impl::types::DhcpLeaseFormat;
+use crate::server::interfaces::r#impl::dhcp::DhcpLease;
+
+/// Kea's lifetime for leases that never run out
+const KEA_INFINITE_LIFETIME: i64 = u32::MAX as i64;
+
+/// An address handed out by a DHCP server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaseEntry {
+    pub ip: IpAddr,
+    pub mac: Option<MacAddress>,
+    pub lease: DhcpLease,
+}
+
+/// Parse a lease database, leaving out leases that have already run out
+pub fn parse(
+    contents: &str,
+    format: DhcpLeaseFormat,
+    now: DateTime<Utc>,
+) -> Result<Vec<LeaseEntry>> {
+    let entries = match format {
+        DhcpLeaseFormat::IscDhcpd => parse_isc(contents, now)?,
+        DhcpLeaseFormat::Dnsmasq => parse_dnsmasq(contents, now),
+        DhcpLeaseFormat::Kea => parse_kea(contents, now)?,
+        DhcpLeaseFormat::Json => parse_json(contents, now)?,
+    };
+
+    Ok(entries
+        .into_values()
+        .flatten()
+        .filter(|entry| !entry.lease.is_expired(now))
+        .collect())
+}
+
+/// Latest entry per address; `None` where the latest entry released it
+type LeaseTable = BTreeMap<IpAddr, Option<LeaseEntry>>;
+
+fn lease_entry(
+    ip: IpAddr,
+    mac: Option<MacAddress>,
+    client_hostname: Option<String>,
+    starts: Option<DateTime<Utc>>,
+    ends: Option<DateTime<Utc>>,
+    reserved: bool,
+    now: DateTime<Utc>,
+) -> LeaseEntry {
+    LeaseEntry {
+        ip,
+        mac,
+        lease: DhcpLease {
+            starts,
+            ends,
+            reserved,
+            client_hostname: client_hostname.filter(|h| !h.is_empty()),
+            collected_at: now,
+        },
+    }
+}
+
+// ISC dhcpd
+
+#[derive(Debug, PartialEq)]
+enum IscToken {
+    Word(String),
+    Quoted(String),
+    Open,
+    Close,
+    End,
+}
+
+fn tokenize_isc(contents: &str) -> Result<Vec<IscToken>> {
+    let mut tokens = Vec::new();
+    let mut chars = contents.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '{' => tokens.push(IscToken::Open),
+            '}' => tokens.push(IscToken::Close),
+            ';' => tokens.push(IscToken::End),
+            '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            if let Some(escaped) = chars.next() {
+                                value.push('\\');
+                                value.push(escaped);
+                            }
+                        }
+                        Some(c) => value.push(c),
+                        None => return Err(anyhow!("Unterminated string in lease file")),
+                    }
+                }
+                tokens.push(IscToken::Quoted(value));
+            }
+            c if c.is_whitespace() => {}
+            c => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || matches!(next, '{' | '}' | ';' | '"' | '#') {
+                        break;
+                    }
+                    word.push(next);
+                    chars.next();
+                }
+                tokens.push(IscToken::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A statement and, for declarations like `lease` and `host`, the statements in its block
+#[derive(Debug, Default)]
+struct IscStatement {
+    words: Vec<String>,
+    block: Vec<IscStatement>,
+}
+
+impl IscStatement {
+    fn keyword(&self) -> Option<&str> {
+        self.words.first().map(String::as_str)
+    }
+
+    /// Arguments of the first statement in the block with these leading words
+    fn find(&self, keywords: &[&str]) -> Option<&[String]> {
+        self.block
+            .iter()
+            .find(|s| s.words.len() >= keywords.len() && s.words[..keywords.len()] == *keywords)
+            .map(|s| &s.words[keywords.len()..])
+    }
+}
+
+fn parse_isc_block(
+    tokens: &mut std::vec::IntoIter<IscToken>,
+    nested: bool,
+) -> Result<Vec<IscStatement>> {
+    let mut statements = Vec::new();
+    let mut current = IscStatement::default();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            IscToken::Word(word) | IscToken::Quoted(word) => current.words.push(word),
+            IscToken::End => {
+                if !current.words.is_empty() {
+                    statements.push(std::mem::take(&mut current));
+                }
+            }
+            IscToken::Open => {
+                current.block = parse_isc_block(tokens, true)?;
+                statements.push(std::mem::take(&mut current));
+            }
+            IscToken::Close if nested => {
+                if !current.words.is_empty() {
+                    statements.push(current);
+                }
+                return Ok(statements);
+            }
+            IscToken::Close => return Err(anyhow!("Unexpected '}}' in lease file")),
+        }
+    }
+
+    if nested {
+        return Err(anyhow!("Unterminated block in lease file"));
+    }
+    if !current.words.is_empty() {
+        statements.push(current);
+    }
+    Ok(statements)
+}
+
+/// `4 2024/01/11 10:00:00` (UTC), `epoch 1704967200` or `never`
+fn parse_isc_time(args: &[String]) -> Option<DateTime<Utc>> {
+    match args {
+        [epoch, seconds, ..] if epoch == "epoch" => {
+            DateTime::from_timestamp(seconds.parse().ok()?, 0)
+        }
+        [_weekday, date, time, ..] => {
+            NaiveDateTime::parse_from_str(&format!("{date} {time}"), "%Y/%m/%d %H:%M:%S")
+                .ok()
+                .map(|t| t.and_utc())
+        }
+        _ => None,
+    }
+}
+
+fn parse_isc_mac(args: Option<&[String]>) -> Option<MacAddress> {
+    match args? {
+        [hw_type, mac, ..] if hw_type == "ethernet" => mac.parse().ok(),
+        _ => None,
+    }
+}
+
+fn parse_isc(contents: &str, now: DateTime<Utc>) -> Result<LeaseTable> {
+    let statements = parse_isc_block(&mut tokenize_isc(contents)?.into_iter(), false)?;
+    let mut table = LeaseTable::new();
+    collect_isc(&statements, &mut table, now);
+    Ok(table)
+}
+
+/// Walk declarations in order, descending into `subnet`, `group` and the like so host
+/// declarations from a `dhcpd.conf` are found wherever they are
+fn collect_isc(statements: &[IscStatement], table: &mut LeaseTable, now: DateTime<Utc>) {
+    for statement in statements {
+        match statement.keyword() {
+            Some("lease") => {
+                let Some(ip) = statement.words.get(1).and_then(|ip| ip.parse().ok()) else {
+                    continue;
+                };
+
+                // No binding state means the server predates binding states; those only
+                // wrote active leases
+                let active = statement
+                    .find(&["binding", "state"])
+                    .is_none_or(|state| state.first().is_some_and(|s| s == "active"));
+
+                let entry = active.then(|| {
+                    lease_entry(
+                        ip,
+                        parse_isc_mac(statement.find(&["hardware"])),
+                        statement
+                            .find(&["client-hostname"])
+                            .and_then(|args| args.first().cloned()),
+                        statement.find(&["starts"]).and_then(parse_isc_time),
+                        statement.find(&["ends"]).and_then(parse_isc_time),
+                        false,
+                        now,
+                    )
+                });
+                table.insert(ip, entry);
+            }
+            Some("host") => {
+                let Some(addresses) = statement.find(&["fixed-address"]) else {
+                    continue;
+                };
+                let mac = parse_isc_mac(statement.find(&["hardware"]));
+                let hostname = statement
+                    .find(&["option", "host-name"])
+                    .and_then(|args| args.first().cloned())
+                    .or_else(|| statement.words.get(1).cloned());
+
+                // `fixed-address a, b` may also list DNS names, which can't be placed on a subnet
+                for ip in addresses
+                    .iter()
+                    .flat_map(|a| a.split(','))
+                    .filter_map(|a| a.trim().parse::<IpAddr>().ok())
+                {
+                    table.insert(
+                        ip,
+                        Some(lease_entry(
+                            ip,
+                            mac,
+                            hostname.clone(),
+                            None,
+                            None,
+                            true,
+                            now,
+                        )),
+                    );
+                }
+            }
+            _ if !statement.block.is_empty() => collect_isc(&statement.block, table, now),
+            _ => {}
+        }
+    }
+}
+
+// dnsmasq
+
+/// `<expiry> <mac> <ip> <hostname> <client id>`, with `*` for an unknown hostname and an expiry
+/// of 0 for infinite leases. DHCPv6 leases follow a `duid` line and put the IAID where the MAC
+/// would be.
+fn parse_dnsmasq(contents: &str, now: DateTime<Utc>) -> LeaseTable {
+    let mut table = LeaseTable::new();
+
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [expiry, mac, ip, hostname, ..] = fields[..] else {
+            continue;
+        };
+        let (Ok(expiry), Ok(ip)) = (expiry.parse::<i64>(), ip.parse::<IpAddr>()) else {
+            continue;
+        };
+
+        let ends = match expiry {
+            0 => None,
+            expiry => DateTime::from_timestamp(expiry, 0),
+        };
+        let hostname = (hostname != "*").then(|| hostname.to_string());
+
+        table.insert(
+            ip,
+            Some(lease_entry(
+                ip,
+                mac.parse().ok(),
+                hostname,
+                None,
+                ends,
+                false,
+                now,
+            )),
+        );
+    }
+
+    table
+}
+
+// Kea
+
+/// Kea memfile CSV, v4 or v6. Columns are looked up by header name since they differ between the
+/// two and have grown over releases.
+fn parse_kea(contents: &str, now: DateTime<Utc>) -> Result<LeaseTable> {
+    let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+    let header: Vec<&str> = lines
+        .next()
+        .ok_or_else(|| anyhow!("Kea lease file is empty"))?
+        .split(',')
+        .map(str::trim)
+        .collect();
+
+    let column = |name: &str| header.iter().position(|h| *h == name);
+    let address =
+        column("address").ok_or_else(|| anyhow!("Kea lease file has no address column"))?;
+    let (hwaddr, valid_lifetime, expire, hostname, state) = (
+        column("hwaddr"),
+        column("valid_lifetime"),
+        column("expire"),
+        column("hostname"),
+        column("state"),
+    );
+
+    let mut table = LeaseTable::new();
+
+    for line in lines {
+        let fields: Vec<&str> = line.split(',').collect();
+        let field = |index: Option<usize>| index.and_then(|i| fields.get(i)).map(|f| f.trim());
+
+        let Some(Ok(ip)) = field(Some(address)).map(str::parse::<IpAddr>) else {
+            continue;
+        };
+
+        let lifetime = field(valid_lifetime).and_then(|v| v.parse::<i64>().ok());
+        let expire = field(expire).and_then(|e| e.parse::<i64>().ok());
+
+        // Deleted leases are written with a zero lifetime; 1 is declined and 2 reclaimed
+        let active = lifetime != Some(0) && field(state).is_none_or(|s| s == "0" || s.is_empty());
+        if !active {
+            table.insert(ip, None);
+            continue;
+        }
+
+        let (starts, ends) = match (expire, lifetime) {
+            (_, Some(KEA_INFINITE_LIFETIME)) => (None, None),
+            (Some(expire), Some(lifetime)) => (
+                DateTime::from_timestamp(expire - lifetime, 0),
+                DateTime::from_timestamp(expire, 0),
+            ),
+            (Some(expire), None) => (None, DateTime::from_timestamp(expire, 0)),
+            (None, _) => (None, None),
+        };
+
+        // Kea escapes commas in values
+        let hostname = field(hostname).map(|h| h.replace("&#x2c", ","));
+
+        table.insert(
+            ip,
+            Some(lease_entry(
+                ip,
+                field(hwaddr).and_then(|m| m.parse().ok()),
+                hostname,
+                starts,
+                ends,
+                false,
+                now,
+            )),
+        );
+    }
+
+    Ok(table)
+}
+
+// JSON
+
+#[derive(Deserialize)]
+struct JsonLease {
+    ip_address: IpAddr,
+    #[serde(default)]
+    mac_address: Option<String>,
+    #[serde(default)]
+    hostname: Option<String>,
+    #[serde(default)]
+    starts: Option<DateTime<Utc>>,
+    #[serde(default)]
+    ends: Option<DateTime<Utc>>,
+    #[serde(default)]
+    reserved: bool,
+}
+
+fn parse_json(contents: &str, now: DateTime<Utc>) -> Result<LeaseTable> {
+    let leases: Vec<JsonLease> = serde_json::from_str(contents)
+        .map_err(|e| anyhow!("Lease file is not a JSON array of leases: {}", e))?;
+
+    Ok(leases
+        .into_iter()
+        .map(|l| {
+            let entry = lease_entry(
+                l.ip_address,
+                l.mac_address.and_then(|m| m.parse().ok()),
+                l.hostname,
+                l.starts,
+                l.ends,
+                l.reserved,
+                now,
+            );
+            (l.ip_address, Some(entry))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_704_970_000, 0).unwrap()
+    }
+
+    #[test]
+    fn test_isc_last_entry_wins_and_hosts_are_reserved() {
+        let contents = r#"
+# The format of this file is documented in the dhcpd.leases(5) manual page.
+lease 192.168.1.10 {
+  starts 4 2024/01/11 08:00:00;
+  ends 4 2024/01/11 20:00:00;
+  binding state active;
+  hardware ethernet 00:11:22:33:44:55;
+  client-hostname "laptop";
+}
+lease 192.168.1.11 {
+  starts epoch 1704960000; # Thu Jan 11 08:00:00 2024
+  ends epoch 1705003200;
+  binding state active;
+  hardware ethernet 00:11:22:33:44:66;
+}
+lease 192.168.1.11 {
+  binding state free;
+  hardware ethernet 00:11:22:33:44:66;
+}
+subnet 192.168.1.0 netmask 255.255.255.0 {
+  host printer {
+    hardware ethernet 00:11:22:33:44:77;
+    fixed-address 192.168.1.5, printer.lan;
+  }
+}
+"#;
+
+        let leases = parse(contents, DhcpLeaseFormat::IscDhcpd, now()).unwrap();
+        assert_eq!(leases.len(), 2);
+
+        let printer = &leases[0];
+        assert_eq!(printer.ip, "192.168.1.5".parse::<IpAddr>().unwrap());
+        assert!(printer.lease.reserved);
+        assert_eq!(printer.lease.client_hostname.as_deref(), Some("printer"));
+
+        let laptop = &leases[1];
+        assert_eq!(laptop.mac, Some("00:11:22:33:44:55".parse().unwrap()));
+        assert_eq!(laptop.lease.client_hostname.as_deref(), Some("laptop"));
+        assert_eq!(
+            laptop.lease.ends,
+            DateTime::from_timestamp(1_705_003_200, 0)
+        );
+    }
+
+    #[test]
+    fn test_dnsmasq_skips_expired_and_unnamed() {
+        let contents = "\
+1705003200 00:11:22:33:44:55 192.168.1.10 laptop 01:00:11:22:33:44:55
+1704900000 00:11:22:33:44:66 192.168.1.11 old *
+0 00:11:22:33:44:77 192.168.1.12 * *
+duid 00:01:00:01:2c:3d:4e:5f:00:11:22:33:44:55
+";
+
+        let leases = parse(contents, DhcpLeaseFormat::Dnsmasq, now()).unwrap();
+        assert_eq!(leases.len(), 2);
+        assert_eq!(leases[0].lease.client_hostname.as_deref(), Some("laptop"));
+        assert_eq!(leases[1].lease.client_hostname, None);
+        assert_eq!(leases[1].lease.ends, None);
+    }
+
+    #[test]
+    fn test_kea_uses_header_and_drops_released() {
+        let contents = "\
+address,hwaddr,client_id,valid_lifetime,expire,subnet_id,fqdn_fwd,fqdn_rev,hostname,state,user_context,pool_id
+192.168.1.10,00:11:22:33:44:55,,3600,1704972000,1,0,0,laptop,0,,0
+192.168.1.11,00:11:22:33:44:66,,3600,1704972000,1,0,0,phone,0,,0
+192.168.1.11,00:11:22:33:44:66,,0,1704972000,1,0,0,phone,0,,0
+";
+
+        let leases = parse(contents, DhcpLeaseFormat::Kea, now()).unwrap();
+        assert_eq!(leases.len(), 1);
+        assert_eq!(
+            leases[0].lease.starts,
+            DateTime::from_timestamp(1_704_968_400, 0)
+        );
+        assert_eq!(leases[0].lease.client_hostname.as_deref(), Some("laptop"));
+    }
+
+    #[test]
+    fn test_json_keeps_optional_fields_and_drops_expired() {
+        let contents = r#"[
+            {
+                "ip_address": "192.168.1.10",
+                "mac_address": "00:11:22:33:44:55",
+                "hostname": "laptop",
+                "starts": "2024-01-11T08:00:00Z",
+                "ends": "2024-01-11T20:00:00Z"
+            },
+            { "ip_address": "192.168.1.11", "ends": "2024-01-10T08:00:00Z" },
+            { "ip_address": "192.168.1.5", "mac_address": "not a mac", "reserved": true }
+        ]"#;
+
+        let leases = parse(contents, DhcpLeaseFormat::Json, now()).unwrap();
+        assert_eq!(leases.len(), 2);
+
+        let printer = &leases[0];
+        assert_eq!(printer.ip, "192.168.1.5".parse::<IpAddr>().unwrap());
+        assert_eq!(printer.mac, None);
+        assert!(printer.lease.reserved);
+
+        let laptop = &leases[1];
+        assert_eq!(laptop.mac, Some("00:11:22:33:44:55".parse().unwrap()));
+        assert_eq!(laptop.lease.client_hostname.as_deref(), Some("laptop"));
+        assert_eq!(
+            laptop.lease.ends,
+            DateTime::from_timestamp(1_705_003_200, 0)
+        );
+
+        assert!(parse(r#"{"leases": []}"#, DhcpLeaseFormat::Json, now()).is_err());
+    }
+}
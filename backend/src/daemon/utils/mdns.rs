@@ -0,0 +1,168 @@
+//! mDNS / DNS-SD browsing.
+//!
+//! Sends the DNS-SD meta query (`_services._dns-sd._udp.local.`) out of the daemon's own
+//! addresses on interfaced subnets, then browses each service type that comes back and records
+//! the resolved instances by the addresses they resolve to. Multicast doesn't cross routers, so
+//! only subnets the daemon has an interface on are covered.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Error;
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
+use mdns_sd::{IfKind, Receiver, ServiceDaemon, ServiceEvent, ServiceInfo};
+use tokio_util::sync::CancellationToken;
+
+use crate::server::services::r#impl::mdns::MdnsAdvertisement;
+
+/// How long to listen for answers. Devices answer within a second or two of a query, and the
+/// browser repeats its queries with backoff, so this covers a few rounds.
+pub const BROWSE_DURATION: Duration = Duration::from_secs(10);
+
+const META_QUERY: &str = "_services._dns-sd._udp.local.";
+
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What a single address announced
+#[derive(Debug, Clone, Default)]
+pub struct MdnsHost {
+    pub advertisements: Vec<MdnsAdvertisement>,
+}
+
+impl MdnsHost {
+    /// `.local` name the host answers to, from its first advertisement
+    pub fn hostname(&self) -> Option<String> {
+        self.advertisements
+            .iter()
+            .map(|a| a.hostname.clone())
+            .find(|h| !h.is_empty())
+    }
+}
+
+/// Results of a browse that may still be running, keyed by advertised address. Clones await the
+/// same browse.
+pub type MdnsBrowse = Shared<BoxFuture<'static, Arc<HashMap<IpAddr, MdnsHost>>>>;
+
+/// Browse in the background from the given local addresses. Failures are logged and leave the
+/// results empty, since every host is still identified by its ports.
+pub fn spawn_browse(source_ips: Vec<IpAddr>, cancel: CancellationToken) -> MdnsBrowse {
+    if source_ips.is_empty() {
+        return futures::future::ready(Arc::new(HashMap::new()))
+            .boxed()
+            .shared();
+    }
+
+    // Started now rather than when first awaited, so it runs alongside neighbor discovery
+    let task = tokio::task::spawn_blocking(move || browse(&source_ips, BROWSE_DURATION, &cancel));
+
+    async move {
+        match task.await {
+            Ok(Ok(hosts)) => {
+                tracing::info!(hosts = hosts.len(), "mDNS browse complete");
+                Arc::new(hosts)
+            }
+            Ok(Err(e)) => {
+                tracing::warn!(error = %e, "mDNS browse failed");
+                Arc::new(HashMap::new())
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "mDNS browse task failed");
+                Arc::new(HashMap::new())
+            }
+        }
+    }
+    .boxed()
+    .shared()
+}
+
+/// Browse every advertised service type for `duration`. Blocks the calling thread.
+pub fn browse(
+    source_ips: &[IpAddr],
+    duration: Duration,
+    cancel: &CancellationToken,
+) -> Result<HashMap<IpAddr, MdnsHost>, Error> {
+    let daemon = ServiceDaemon::new()?;
+    daemon.disable_interface(IfKind::All)?;
+    daemon.enable_interface(
+        source_ips
+            .iter()
+            .map(|ip| IfKind::Addr(*ip))
+            .collect::<Vec<_>>(),
+    )?;
+
+    tracing::debug!(
+        source_ips = source_ips.len(),
+        duration_secs = duration.as_secs(),
+        "Starting mDNS browse"
+    );
+
+    let meta = daemon.browse(META_QUERY)?;
+    let mut browsing: HashSet<String> = HashSet::new();
+    let mut receivers: Vec<Receiver<ServiceEvent>> = Vec::new();
+    let mut hosts: HashMap<IpAddr, MdnsHost> = HashMap::new();
+
+    let deadline = Instant::now() + duration;
+    while Instant::now() < deadline && !cancel.is_cancelled() {
+        // Meta query answers name service types rather than instances
+        while let Ok(event) = meta.try_recv() {
+            if let ServiceEvent::ServiceFound(_, service_type) = event
+                && browsing.insert(service_type.to_lowercase())
+            {
+                match daemon.browse(&service_type) {
+                    Ok(receiver) => receivers.push(receiver),
+                    Err(e) => {
+                        tracing::debug!(service_type, error = %e, "Failed to browse mDNS service type")
+                    }
+                }
+            }
+        }
+
+        for receiver in &receivers {
+            while let Ok(event) = receiver.try_recv() {
+                if let ServiceEvent::ServiceResolved(info) = event {
+                    record(&mut hosts, &info);
+                }
+            }
+        }
+
+        std::thread::sleep(POLL_INTERVAL);
+    }
+
+    tracing::debug!(
+        service_types = browsing.len(),
+        hosts = hosts.len(),
+        "mDNS browse finished"
+    );
+
+    let _ = daemon.shutdown();
+    Ok(hosts)
+}
+
+fn record(hosts: &mut HashMap<IpAddr, MdnsHost>, info: &ServiceInfo) {
+    let txt: BTreeMap<String, String> = info
+        .get_properties()
+        .iter()
+        .map(|p| (p.key().to_string(), p.val_str().to_string()))
+        .collect();
+
+    let advertisement = MdnsAdvertisement::new(
+        info.get_fullname(),
+        info.get_type(),
+        info.get_hostname(),
+        info.get_port(),
+        txt,
+    );
+
+    for ip in info.get_addresses() {
+        let advertisements = &mut hosts.entry(*ip).or_default().advertisements;
+        // Instances are resolved again whenever their records are refreshed
+        advertisements.retain(|a| {
+            a.service_type != advertisement.service_type
+                || a.instance_name != advertisement.instance_name
+        });
+        advertisements.push(advertisement.clone());
+    }
+}
@@ -1,6 +1,17 @@
 pub mod arp;
 pub mod base;
+pub mod dependencies;
+pub mod dhcp_leases;
 pub mod linux;
 pub mod macos;
+pub mod mdns;
+pub mod ndp;
+pub mod passive;
+pub mod ping;
+pub mod proxmox;
 pub mod scanner;
+pub mod snmp;
+pub mod ssdp;
+pub mod syn;
+pub mod traceroute;
 pub mod windows;
@@ -0,0 +1,89 @@
+use std::net::Ipv6Addr;
+
+use cidr::Ipv6Cidr;
+use mac_address::MacAddress;
+
+/// Recover the MAC address embedded in a SLAAC/EUI-64 interface identifier.
+///
+/// EUI-64 identifiers are built by inserting `ff:fe` into the middle of the MAC
+/// and flipping the universal/local bit. Addresses with random (privacy or
+/// stable-opaque) identifiers return `None`.
+pub fn mac_from_eui64(ip: &Ipv6Addr) -> Option<MacAddress> {
+    let octets = ip.octets();
+    let iid = &octets[8..16];
+
+    if iid[3] != 0xff || iid[4] != 0xfe {
+        return None;
+    }
+
+    let mac = [iid[0] ^ 0x02, iid[1], iid[2], iid[5], iid[6], iid[7]];
+
+    if mac.iter().all(|o| *o == 0) {
+        return None;
+    }
+
+    Some(MacAddress::new(mac))
+}
+
+/// Build the SLAAC/EUI-64 address a host with `mac` would configure in `prefix`.
+///
+/// Only meaningful for /64 prefixes; returns `None` for anything longer since
+/// SLAAC can't generate addresses there.
+pub fn eui64_address(prefix: &Ipv6Cidr, mac: &MacAddress) -> Option<Ipv6Addr> {
+    if prefix.network_length() > 64 {
+        return None;
+    }
+
+    let mac = mac.bytes();
+    let mut octets = prefix.first_address().octets();
+    octets[8] = mac[0] ^ 0x02;
+    octets[9] = mac[1];
+    octets[10] = mac[2];
+    octets[11] = 0xff;
+    octets[12] = 0xfe;
+    octets[13] = mac[3];
+    octets[14] = mac[4];
+    octets[15] = mac[5];
+
+    Some(Ipv6Addr::from(octets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mac_from_eui64_recovers_mac() {
+        let ip: Ipv6Addr = "2001:db8::211:22ff:fe33:4455".parse().unwrap();
+        let mac = mac_from_eui64(&ip).unwrap();
+        assert_eq!(mac.bytes(), [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    }
+
+    #[test]
+    fn test_mac_from_eui64_rejects_random_iid() {
+        let ip: Ipv6Addr = "2001:db8::8c1a:3b2f:91d4:7e01".parse().unwrap();
+        assert!(mac_from_eui64(&ip).is_none());
+    }
+
+    #[test]
+    fn test_eui64_address_round_trips() {
+        let prefix: Ipv6Cidr = "2001:db8:1:2::/64".parse().unwrap();
+        let mac = MacAddress::new([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+
+        let ip = eui64_address(&prefix, &mac).unwrap();
+        assert_eq!(
+            ip,
+            "2001:db8:1:2:a8bb:ccff:fedd:eeff"
+                .parse::<Ipv6Addr>()
+                .unwrap()
+        );
+        assert_eq!(mac_from_eui64(&ip), Some(mac));
+    }
+
+    #[test]
+    fn test_eui64_address_requires_slaac_prefix() {
+        let prefix: Ipv6Cidr = "2001:db8::/120".parse().unwrap();
+        let mac = MacAddress::new([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+        assert!(eui64_address(&prefix, &mac).is_none());
+    }
+}
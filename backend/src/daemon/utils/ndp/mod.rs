@@ -0,0 +1,75 @@
+//! IPv6 neighbor discovery module.
+//!
+//! IPv6 subnets are typically /64s and can't be swept like IPv4, so on-link
+//! IPv6 hosts are found via NDP and ICMPv6 multicast instead. Uses the same
+//! raw datalink channel as broadcast ARP, so availability matches:
+//!
+//! | Platform | Method              | Fallback                 |
+//! |----------|---------------------|--------------------------|
+//! | Linux    | NDP (pnet)          | None (prefix not swept)  |
+//! | macOS    | NDP (pnet)          | None (prefix not swept)  |
+//! | Windows  | NDP (Npcap)         | None (prefix not swept)  |
+
+pub mod eui64;
+pub mod neighbor;
+pub mod types;
+
+use std::net::Ipv6Addr;
+
+use anyhow::Result;
+use cidr::Ipv6Cidr;
+use mac_address::MacAddress;
+use pnet::datalink::NetworkInterface;
+
+pub use eui64::{eui64_address, mac_from_eui64};
+pub use neighbor::{POST_SCAN_RECEIVE, ROUND_WAIT};
+pub use types::NdpScanResult;
+
+/// Discover live hosts on an on-link IPv6 prefix.
+///
+/// Returns a channel receiver that streams results as hosts respond.
+///
+/// # Arguments
+/// * `interface` - Network interface attached to the prefix
+/// * `source_mac` - Source MAC address for NDP packets
+/// * `link_local` - Link-local address of `interface`, used as the NDP source
+/// * `source_ip` - Address of `interface` inside `prefix`, if any, used as an echo source
+/// * `prefix` - On-link prefix to report hosts for
+/// * `retries` - Number of retry rounds (0 = single attempt)
+/// * `rate_pps` - Maximum neighbor solicitations per second
+pub fn scan_subnet(
+    interface: &NetworkInterface,
+    source_mac: MacAddress,
+    link_local: Ipv6Addr,
+    source_ip: Option<Ipv6Addr>,
+    prefix: Ipv6Cidr,
+    retries: u32,
+    rate_pps: u32,
+) -> Result<std::sync::mpsc::Receiver<NdpScanResult>> {
+    neighbor::scan_subnet(
+        interface, source_mac, link_local, source_ip, prefix, retries, rate_pps,
+    )
+}
+
+/// Check if NDP scanning is available on this platform.
+///
+/// # Arguments
+/// * `use_npcap` - (Windows only) Whether Npcap is installed and enabled
+pub fn is_available(use_npcap: bool) -> bool {
+    #[cfg(target_family = "windows")]
+    {
+        // Without Npcap there is no raw datalink access on Windows
+        use_npcap && super::arp::broadcast::is_available()
+    }
+
+    #[cfg(not(target_family = "windows"))]
+    {
+        let _ = use_npcap;
+        super::arp::broadcast::is_available()
+    }
+}
+
+/// Whether an address is IPv6 link-local (fe80::/10)
+pub fn is_link_local(ip: &Ipv6Addr) -> bool {
+    (ip.segments()[0] & 0xffc0) == 0xfe80
+}
@@ -0,0 +1,491 @@
+use std::collections::HashSet;
+use std::net::Ipv6Addr;
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, anyhow};
+use cidr::Ipv6Cidr;
+use mac_address::MacAddress;
+use pnet::datalink::{self, Channel, NetworkInterface};
+use pnet::packet::Packet;
+use pnet::packet::ethernet::{EtherTypes, EthernetPacket, MutableEthernetPacket};
+use pnet::packet::icmpv6::MutableIcmpv6Packet;
+use pnet::packet::icmpv6::ndp::NdpOptionTypes;
+use pnet::packet::icmpv6::{self, Icmpv6Code, Icmpv6Packet, Icmpv6Type, Icmpv6Types};
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::packet::ipv6::{Ipv6Packet, MutableIpv6Packet};
+use pnet::util::MacAddr;
+
+use super::eui64::eui64_address;
+use super::types::NdpScanResult;
+
+/// Wait time after each round before retrying
+pub const ROUND_WAIT: Duration = Duration::from_secs(3);
+/// Extra receive time after final round
+pub const POST_SCAN_RECEIVE: Duration = Duration::from_secs(5);
+/// Time to let all-nodes echo replies arrive before soliciting EUI-64 candidates
+const MULTICAST_SETTLE: Duration = Duration::from_secs(1);
+
+const ALL_NODES: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+const ALL_ROUTERS: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 2);
+
+const ETHERNET_HEADER_LEN: usize = 14;
+const IPV6_HEADER_LEN: usize = 40;
+const ICMPV6_HEADER_LEN: usize = 4;
+
+/// Discover live IPv6 hosts on an on-link prefix using NDP.
+///
+/// Sweeping a /64 is not feasible, so instead this:
+/// 1. Sends ICMPv6 echo requests to the all-nodes multicast group, from both
+///    the link-local and (if present) in-prefix source address. Replies to the
+///    in-prefix source come back from the responder's in-prefix address.
+/// 2. Sends a router solicitation so routers announce themselves.
+/// 3. Sends neighbor solicitations for the SLAAC/EUI-64 address of every MAC
+///    heard from, plus `prefix::1`.
+/// 4. Passively records neighbor solicitations/advertisements seen meanwhile.
+///
+/// Only addresses inside `prefix` are reported.
+pub fn scan_subnet(
+    interface: &NetworkInterface,
+    source_mac: MacAddress,
+    link_local: Ipv6Addr,
+    source_ip: Option<Ipv6Addr>,
+    prefix: Ipv6Cidr,
+    retries: u32,
+    rate_pps: u32,
+) -> Result<std::sync::mpsc::Receiver<NdpScanResult>> {
+    use std::sync::mpsc;
+
+    let interface = interface.clone();
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        if let Err(e) = scan_subnet_background(
+            &interface, source_mac, link_local, source_ip, prefix, retries, rate_pps, tx,
+        ) {
+            tracing::warn!(error = %e, "NDP scan background thread failed");
+        }
+    });
+
+    Ok(rx)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn scan_subnet_background(
+    interface: &NetworkInterface,
+    source_mac: MacAddress,
+    link_local: Ipv6Addr,
+    source_ip: Option<Ipv6Addr>,
+    prefix: Ipv6Cidr,
+    retries: u32,
+    rate_pps: u32,
+    result_tx: std::sync::mpsc::Sender<NdpScanResult>,
+) -> Result<()> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    let send_delay = Duration::from_micros(1_000_000 / rate_pps.max(1) as u64);
+
+    let config = pnet::datalink::Config {
+        read_timeout: Some(Duration::from_millis(50)),
+        read_buffer_size: 65536,
+        write_buffer_size: 65536,
+        ..Default::default()
+    };
+
+    let (mut tx, mut rx) = match datalink::channel(interface, config)? {
+        Channel::Ethernet(tx, rx) => (tx, rx),
+        _ => return Err(anyhow!("Unsupported channel type")),
+    };
+
+    let source_mac_pnet = MacAddr::from(source_mac.bytes());
+    let total_rounds = 1 + retries;
+
+    tracing::debug!(
+        interface = %interface.name,
+        link_local = %link_local,
+        source_ip = ?source_ip,
+        prefix = %prefix,
+        total_rounds,
+        "Starting NDP scan"
+    );
+
+    // Shared state between sender and receiver threads
+    let found_ips = Arc::new(Mutex::new(HashSet::<Ipv6Addr>::new()));
+    let seen_macs = Arc::new(Mutex::new(HashSet::<MacAddress>::new()));
+    let sending_done = Arc::new(AtomicBool::new(false));
+
+    let found_ips_recv = found_ips.clone();
+    let seen_macs_recv = seen_macs.clone();
+    let sending_done_recv = sending_done.clone();
+
+    let receiver_handle = thread::spawn(move || {
+        let start = Instant::now();
+        let mut final_deadline: Option<Instant> = None;
+
+        loop {
+            if let Some(deadline) = final_deadline {
+                if Instant::now() >= deadline {
+                    break;
+                }
+            } else if sending_done_recv.load(Ordering::Relaxed) {
+                final_deadline = Some(Instant::now() + POST_SCAN_RECEIVE);
+            }
+
+            let Ok(packet) = rx.next() else {
+                continue;
+            };
+
+            let Some((ip, mac)) = parse_neighbor_response(packet, source_mac_pnet) else {
+                continue;
+            };
+
+            seen_macs_recv.lock().unwrap().insert(mac);
+
+            if !prefix.contains(&ip) {
+                continue;
+            }
+
+            let mut found = found_ips_recv.lock().unwrap();
+            if found.insert(ip) {
+                tracing::debug!(ip = %ip, mac = %mac, "NDP: Host discovered");
+                let _ = result_tx.send(NdpScanResult { ip, mac });
+            }
+        }
+
+        tracing::debug!(
+            elapsed_secs = start.elapsed().as_secs(),
+            hosts_found = found_ips_recv.lock().unwrap().len(),
+            macs_seen = seen_macs_recv.lock().unwrap().len(),
+            "NDP scan completed"
+        );
+    });
+
+    thread::spawn(move || {
+        let all_nodes_mac = multicast_mac(&ALL_NODES);
+        let echo_sources: Vec<Ipv6Addr> = source_ip.into_iter().chain([link_local]).collect();
+
+        for round in 1..=total_rounds {
+            for (seq, src) in echo_sources.iter().enumerate() {
+                let packet = build_echo_request(
+                    source_mac_pnet,
+                    all_nodes_mac,
+                    *src,
+                    ALL_NODES,
+                    round as u16,
+                    seq as u16,
+                );
+                if let Some(Err(e)) = tx.send_to(&packet, None) {
+                    tracing::warn!(source = %src, error = %e, "Failed to send ICMPv6 echo request");
+                }
+            }
+
+            let packet = build_router_solicit(source_mac_pnet, link_local);
+            let _ = tx.send_to(&packet, None);
+
+            thread::sleep(MULTICAST_SETTLE);
+
+            // Solicit EUI-64 addresses for every MAC we've heard from, plus the
+            // conventional gateway address, skipping anything already found
+            let candidates: Vec<Ipv6Addr> = {
+                let found = found_ips.lock().unwrap();
+                let seen = seen_macs.lock().unwrap();
+                seen.iter()
+                    .filter_map(|mac| eui64_address(&prefix, mac))
+                    .chain(std::iter::once(prefix_gateway(&prefix)))
+                    .filter(|ip| !found.contains(ip))
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect()
+            };
+
+            tracing::debug!(
+                round,
+                total_rounds,
+                candidates = candidates.len(),
+                "Soliciting NDP candidates"
+            );
+
+            for target in &candidates {
+                let packet = build_neighbor_solicit(source_mac_pnet, link_local, *target);
+                if let Some(Err(e)) = tx.send_to(&packet, None) {
+                    tracing::warn!(target = %target, error = %e, "Failed to send neighbor solicitation");
+                }
+                thread::sleep(send_delay);
+            }
+
+            thread::sleep(ROUND_WAIT);
+        }
+
+        sending_done.store(true, Ordering::Relaxed);
+        let _ = receiver_handle.join();
+    });
+
+    Ok(())
+}
+
+/// Ethernet multicast address for an IPv6 multicast group (33:33 + low 32 bits)
+fn multicast_mac(group: &Ipv6Addr) -> MacAddr {
+    let o = group.octets();
+    MacAddr::new(0x33, 0x33, o[12], o[13], o[14], o[15])
+}
+
+/// Solicited-node multicast group for a target (ff02::1:ffXX:XXXX)
+fn solicited_node_multicast(target: &Ipv6Addr) -> Ipv6Addr {
+    let o = target.octets();
+    Ipv6Addr::from([
+        0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0xff, o[13], o[14], o[15],
+    ])
+}
+
+/// `prefix::1`, the most common static gateway address
+fn prefix_gateway(prefix: &Ipv6Cidr) -> Ipv6Addr {
+    let mut octets = prefix.first_address().octets();
+    octets[15] |= 1;
+    Ipv6Addr::from(octets)
+}
+
+fn build_icmpv6_frame(
+    source_mac: MacAddr,
+    destination_mac: MacAddr,
+    source_ip: Ipv6Addr,
+    destination_ip: Ipv6Addr,
+    icmp_type: Icmpv6Type,
+    body: &[u8],
+) -> Vec<u8> {
+    let icmp_len = ICMPV6_HEADER_LEN + body.len();
+    let mut buffer = vec![0u8; ETHERNET_HEADER_LEN + IPV6_HEADER_LEN + icmp_len];
+
+    {
+        let mut icmp =
+            MutableIcmpv6Packet::new(&mut buffer[ETHERNET_HEADER_LEN + IPV6_HEADER_LEN..]).unwrap();
+        icmp.set_icmpv6_type(icmp_type);
+        icmp.set_icmpv6_code(Icmpv6Code::new(0));
+        icmp.set_payload(body);
+        let checksum = icmpv6::checksum(&icmp.to_immutable(), &source_ip, &destination_ip);
+        icmp.set_checksum(checksum);
+    }
+
+    {
+        let mut ipv6 = MutableIpv6Packet::new(&mut buffer[ETHERNET_HEADER_LEN..]).unwrap();
+        ipv6.set_version(6);
+        ipv6.set_payload_length(icmp_len as u16);
+        ipv6.set_next_header(IpNextHeaderProtocols::Icmpv6);
+        // NDP requires hop limit 255 so receivers can reject off-link forgeries
+        ipv6.set_hop_limit(255);
+        ipv6.set_source(source_ip);
+        ipv6.set_destination(destination_ip);
+    }
+
+    let mut ethernet = MutableEthernetPacket::new(&mut buffer).unwrap();
+    ethernet.set_destination(destination_mac);
+    ethernet.set_source(source_mac);
+    ethernet.set_ethertype(EtherTypes::Ipv6);
+
+    buffer
+}
+
+fn build_echo_request(
+    source_mac: MacAddr,
+    destination_mac: MacAddr,
+    source_ip: Ipv6Addr,
+    destination_ip: Ipv6Addr,
+    identifier: u16,
+    sequence: u16,
+) -> Vec<u8> {
+    let mut body = Vec::with_capacity(4);
+    body.extend_from_slice(&identifier.to_be_bytes());
+    body.extend_from_slice(&sequence.to_be_bytes());
+
+    build_icmpv6_frame(
+        source_mac,
+        destination_mac,
+        source_ip,
+        destination_ip,
+        Icmpv6Types::EchoRequest,
+        &body,
+    )
+}
+
+fn build_neighbor_solicit(source_mac: MacAddr, source_ip: Ipv6Addr, target: Ipv6Addr) -> Vec<u8> {
+    let destination_ip = solicited_node_multicast(&target);
+
+    // Reserved (4) + target (16) + source link-layer address option (8)
+    let mut body = vec![0u8; 4];
+    body.extend_from_slice(&target.octets());
+    body.push(NdpOptionTypes::SourceLLAddr.0);
+    body.push(1);
+    body.extend_from_slice(&source_mac.octets());
+
+    build_icmpv6_frame(
+        source_mac,
+        multicast_mac(&destination_ip),
+        source_ip,
+        destination_ip,
+        Icmpv6Types::NeighborSolicit,
+        &body,
+    )
+}
+
+fn build_router_solicit(source_mac: MacAddr, source_ip: Ipv6Addr) -> Vec<u8> {
+    // Reserved (4) + source link-layer address option (8)
+    let mut body = vec![0u8; 4];
+    body.push(NdpOptionTypes::SourceLLAddr.0);
+    body.push(1);
+    body.extend_from_slice(&source_mac.octets());
+
+    build_icmpv6_frame(
+        source_mac,
+        multicast_mac(&ALL_ROUTERS),
+        source_ip,
+        ALL_ROUTERS,
+        Icmpv6Types::RouterSolicit,
+        &body,
+    )
+}
+
+/// Extract the (address, MAC) a neighbor revealed in an ICMPv6 packet.
+///
+/// Handles echo replies, neighbor advertisements (using the advertised target),
+/// and neighbor solicitations / router advertisements from non-DAD sources.
+fn parse_neighbor_response(packet: &[u8], our_mac: MacAddr) -> Option<(Ipv6Addr, MacAddress)> {
+    let ethernet = EthernetPacket::new(packet)?;
+    if ethernet.get_ethertype() != EtherTypes::Ipv6 || ethernet.get_source() == our_mac {
+        return None;
+    }
+
+    let ipv6 = Ipv6Packet::new(ethernet.payload())?;
+    if ipv6.get_next_header() != IpNextHeaderProtocols::Icmpv6 {
+        return None;
+    }
+
+    let icmp = Icmpv6Packet::new(ipv6.payload())?;
+    let ethernet_mac = MacAddress::new(ethernet.get_source().octets());
+    let source = ipv6.get_source();
+
+    match icmp.get_icmpv6_type() {
+        Icmpv6Types::EchoReply | Icmpv6Types::RouterAdvert => Some((source, ethernet_mac)),
+        Icmpv6Types::NeighborSolicit if !source.is_unspecified() => Some((source, ethernet_mac)),
+        Icmpv6Types::NeighborAdvert => {
+            let payload = icmp.payload();
+            let target: [u8; 16] = payload.get(4..20)?.try_into().ok()?;
+            let mac = target_link_layer_address(payload.get(20..)?).unwrap_or(ethernet_mac);
+            Some((Ipv6Addr::from(target), mac))
+        }
+        _ => None,
+    }
+}
+
+fn target_link_layer_address(mut options: &[u8]) -> Option<MacAddress> {
+    while options.len() >= 8 {
+        let option_type = options[0];
+        let option_len = options[1] as usize * 8;
+        if option_len == 0 || option_len > options.len() {
+            return None;
+        }
+        if option_type == NdpOptionTypes::TargetLLAddr.0 {
+            let mac: [u8; 6] = options[2..8].try_into().ok()?;
+            return Some(MacAddress::new(mac));
+        }
+        options = &options[option_len..];
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mac(last: u8) -> MacAddr {
+        MacAddr::new(0x00, 0x11, 0x22, 0x33, 0x44, last)
+    }
+
+    #[test]
+    fn test_build_neighbor_solicit_targets_solicited_node_group() {
+        let source_ip: Ipv6Addr = "fe80::1".parse().unwrap();
+        let target: Ipv6Addr = "2001:db8::211:22ff:fe33:4455".parse().unwrap();
+
+        let packet = build_neighbor_solicit(mac(0x01), source_ip, target);
+
+        let eth = EthernetPacket::new(&packet).unwrap();
+        assert_eq!(eth.get_ethertype(), EtherTypes::Ipv6);
+        assert_eq!(
+            eth.get_destination(),
+            MacAddr::new(0x33, 0x33, 0xff, 0x33, 0x44, 0x55)
+        );
+
+        let ipv6 = Ipv6Packet::new(eth.payload()).unwrap();
+        assert_eq!(ipv6.get_hop_limit(), 255);
+        assert_eq!(
+            ipv6.get_destination(),
+            "ff02::1:ff33:4455".parse::<Ipv6Addr>().unwrap()
+        );
+
+        let icmp = Icmpv6Packet::new(ipv6.payload()).unwrap();
+        assert_eq!(icmp.get_icmpv6_type(), Icmpv6Types::NeighborSolicit);
+        assert_eq!(
+            icmp.get_checksum(),
+            icmpv6::checksum(&icmp, &source_ip, &ipv6.get_destination())
+        );
+        assert_eq!(&icmp.payload()[4..20], &target.octets());
+    }
+
+    #[test]
+    fn test_parse_neighbor_advert_uses_target_and_option_mac() {
+        let target: Ipv6Addr = "2001:db8::42".parse().unwrap();
+        let advertised = mac(0x42);
+
+        let mut body = vec![0x60, 0, 0, 0];
+        body.extend_from_slice(&target.octets());
+        body.push(NdpOptionTypes::TargetLLAddr.0);
+        body.push(1);
+        body.extend_from_slice(&advertised.octets());
+
+        let packet = build_icmpv6_frame(
+            mac(0x99),
+            mac(0x01),
+            "fe80::42".parse().unwrap(),
+            "fe80::1".parse().unwrap(),
+            Icmpv6Types::NeighborAdvert,
+            &body,
+        );
+
+        let (ip, found_mac) = parse_neighbor_response(&packet, mac(0x01)).unwrap();
+        assert_eq!(ip, target);
+        assert_eq!(found_mac.bytes(), advertised.octets());
+    }
+
+    #[test]
+    fn test_parse_echo_reply_uses_source() {
+        let source: Ipv6Addr = "2001:db8::7".parse().unwrap();
+        let packet = build_icmpv6_frame(
+            mac(0x07),
+            mac(0x01),
+            source,
+            "2001:db8::1".parse().unwrap(),
+            Icmpv6Types::EchoReply,
+            &[0, 1, 0, 1],
+        );
+
+        let (ip, found_mac) = parse_neighbor_response(&packet, mac(0x01)).unwrap();
+        assert_eq!(ip, source);
+        assert_eq!(found_mac.bytes(), mac(0x07).octets());
+    }
+
+    #[test]
+    fn test_parse_ignores_own_and_dad_packets() {
+        let own = build_echo_request(
+            mac(0x01),
+            multicast_mac(&ALL_NODES),
+            "fe80::1".parse().unwrap(),
+            ALL_NODES,
+            1,
+            0,
+        );
+        assert!(parse_neighbor_response(&own, mac(0x01)).is_none());
+
+        let dad =
+            build_neighbor_solicit(mac(0x05), Ipv6Addr::UNSPECIFIED, "fe80::5".parse().unwrap());
+        assert!(parse_neighbor_response(&dad, mac(0x01)).is_none());
+    }
+}
@@ -0,0 +1,9 @@
+use mac_address::MacAddress;
+use std::net::Ipv6Addr;
+
+/// Result of NDP scanning a single host
+#[derive(Debug, Clone)]
+pub struct NdpScanResult {
+    pub ip: Ipv6Addr,
+    pub mac: MacAddress,
+}
@@ -0,0 +1,450 @@
+//! Passive host discovery.
+//!
+//! Listens on the daemon's interfaces for traffic hosts send on their own when they join or stay
+//! on a segment — ARP, DHCP requests, mDNS and NetBIOS broadcasts — and reports the MAC/IP pairs
+//! it sees. Nothing is ever sent, so it can run on segments that must not be probed.
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use dhcproto::v4::{self, Decodable, DhcpOption, OptionCode};
+use hickory_resolver::proto::op::Message as DnsMessage;
+use hickory_resolver::proto::rr::RData;
+use mac_address::MacAddress;
+use pnet::datalink::{self, Channel, NetworkInterface};
+use pnet::packet::Packet;
+use pnet::packet::arp::{ArpOperations, ArpPacket};
+use pnet::packet::ethernet::{EtherTypes, EthernetPacket};
+use pnet::packet::ip::{IpNextHeaderProtocol, IpNextHeaderProtocols};
+use pnet::packet::ipv4::Ipv4Packet;
+use pnet::packet::ipv6::Ipv6Packet;
+use pnet::packet::udp::UdpPacket;
+use strum::Display;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+const DHCP_SERVER_PORT: u16 = 67;
+const MDNS_PORT: u16 = 5353;
+const NETBIOS_NAME_PORT: u16 = 137;
+const NETBIOS_DATAGRAM_PORT: u16 = 138;
+
+/// How often listener threads wake up to check for cancellation
+const READ_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Wait after a read error before reading again, doubled for each error in a row
+const READ_ERROR_DELAY: Duration = Duration::from_millis(100);
+const MAX_READ_ERROR_DELAY: Duration = Duration::from_secs(5);
+/// Read errors in a row before giving up on an interface, ie after it went away
+const MAX_CONSECUTIVE_READ_ERRORS: u32 = 20;
+
+/// Observations waiting to be reported. Bursts are short, so a full buffer means the reporter
+/// has stopped and further observations can be dropped.
+const OBSERVATION_BUFFER: usize = 1024;
+
+/// Traffic a host was seen by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display)]
+pub enum PassiveProtocol {
+    Arp,
+    Dhcp,
+    Mdns,
+    NetBios,
+}
+
+/// A host seen on the wire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassiveObservation {
+    pub mac: MacAddress,
+    pub ip: IpAddr,
+    /// Name the host gave for itself, if the traffic carries one
+    pub hostname: Option<String>,
+    pub protocol: PassiveProtocol,
+}
+
+/// Listen on each interface until cancelled, streaming what's seen. Interfaces that can't be
+/// opened are logged and skipped.
+pub fn listen(
+    interfaces: Vec<NetworkInterface>,
+    cancel: CancellationToken,
+) -> mpsc::Receiver<PassiveObservation> {
+    let (tx, rx) = mpsc::channel(OBSERVATION_BUFFER);
+
+    for interface in interfaces {
+        let tx = tx.clone();
+        let cancel = cancel.clone();
+        std::thread::spawn(move || {
+            if let Err(e) = listen_on(&interface, &tx, &cancel) {
+                tracing::warn!(
+                    interface = %interface.name,
+                    error = %e,
+                    "Passive listener stopped"
+                );
+            }
+        });
+    }
+
+    rx
+}
+
+fn listen_on(
+    interface: &NetworkInterface,
+    tx: &mpsc::Sender<PassiveObservation>,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let config = datalink::Config {
+        read_timeout: Some(READ_TIMEOUT),
+        read_buffer_size: 65536,
+        ..Default::default()
+    };
+
+    // The sending half is dropped unused
+    let mut rx = match datalink::channel(interface, config)? {
+        Channel::Ethernet(_, rx) => rx,
+        _ => return Err(anyhow!("Unsupported channel type")),
+    };
+
+    tracing::info!(interface = %interface.name, "Listening for hosts passively");
+
+    let own_mac = interface.mac.map(|mac| MacAddress::new(mac.octets()));
+
+    let mut consecutive_errors = 0;
+
+    while !cancel.is_cancelled() {
+        let frame = match rx.next() {
+            Ok(frame) => {
+                consecutive_errors = 0;
+                frame
+            }
+            // Timeouts surface as errors, and are how cancellation gets checked
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::TimedOut | std::io::ErrorKind::WouldBlock
+                ) =>
+            {
+                continue;
+            }
+            Err(e) => {
+                consecutive_errors += 1;
+                if consecutive_errors >= MAX_CONSECUTIVE_READ_ERRORS {
+                    return Err(anyhow!(
+                        "{} read errors in a row, last: {}",
+                        consecutive_errors,
+                        e
+                    ));
+                }
+
+                tracing::debug!(interface = %interface.name, error = %e, "Passive: read error");
+                let delay = READ_ERROR_DELAY * 2u32.pow(consecutive_errors.min(6) - 1);
+                std::thread::sleep(delay.min(MAX_READ_ERROR_DELAY));
+                continue;
+            }
+        };
+
+        let Some(observation) = parse_frame(frame) else {
+            continue;
+        };
+
+        if Some(observation.mac) == own_mac {
+            continue;
+        }
+
+        tracing::trace!(
+            interface = %interface.name,
+            ip = %observation.ip,
+            mac = %observation.mac,
+            protocol = %observation.protocol,
+            "Passive: host seen"
+        );
+
+        match tx.try_send(observation) {
+            Ok(()) | Err(mpsc::error::TrySendError::Full(_)) => {}
+            Err(mpsc::error::TrySendError::Closed(_)) => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Pick the host a frame reveals, if it's one of the kinds of traffic listened for
+pub fn parse_frame(frame: &[u8]) -> Option<PassiveObservation> {
+    let ethernet = EthernetPacket::new(frame)?;
+    let source_mac = MacAddress::new(ethernet.get_source().octets());
+
+    match ethernet.get_ethertype() {
+        EtherTypes::Arp => parse_arp(ethernet.payload()),
+        EtherTypes::Ipv4 => {
+            let ipv4 = Ipv4Packet::new(ethernet.payload())?;
+            parse_udp(
+                source_mac,
+                IpAddr::V4(ipv4.get_source()),
+                ipv4.get_next_level_protocol(),
+                ipv4.payload(),
+            )
+        }
+        EtherTypes::Ipv6 => {
+            let ipv6 = Ipv6Packet::new(ethernet.payload())?;
+            parse_udp(
+                source_mac,
+                IpAddr::V6(ipv6.get_source()),
+                ipv6.get_next_header(),
+                ipv6.payload(),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn parse_arp(payload: &[u8]) -> Option<PassiveObservation> {
+    let arp = ArpPacket::new(payload)?;
+    let operation = arp.get_operation();
+    if operation != ArpOperations::Request && operation != ArpOperations::Reply {
+        return None;
+    }
+
+    // Probes for address conflicts are sent before the host has an address
+    let ip = arp.get_sender_proto_addr();
+    if ip.is_unspecified() {
+        return None;
+    }
+
+    Some(PassiveObservation {
+        mac: MacAddress::new(arp.get_sender_hw_addr().octets()),
+        ip: IpAddr::V4(ip),
+        hostname: None,
+        protocol: PassiveProtocol::Arp,
+    })
+}
+
+fn parse_udp(
+    source_mac: MacAddress,
+    source_ip: IpAddr,
+    protocol: IpNextHeaderProtocol,
+    payload: &[u8],
+) -> Option<PassiveObservation> {
+    if protocol != IpNextHeaderProtocols::Udp {
+        return None;
+    }
+    let udp = UdpPacket::new(payload)?;
+    let (source_port, destination_port) = (udp.get_source(), udp.get_destination());
+
+    if destination_port == DHCP_SERVER_PORT {
+        parse_dhcp_request(udp.payload())
+    } else if source_port == MDNS_PORT && destination_port == MDNS_PORT {
+        Some(PassiveObservation {
+            mac: source_mac,
+            ip: source_ip,
+            hostname: mdns_hostname(udp.payload(), source_ip),
+            protocol: PassiveProtocol::Mdns,
+        })
+    } else if source_port == NETBIOS_NAME_PORT || source_port == NETBIOS_DATAGRAM_PORT {
+        Some(PassiveObservation {
+            mac: source_mac,
+            ip: source_ip,
+            hostname: netbios_hostname(udp.payload(), source_port),
+            protocol: PassiveProtocol::NetBios,
+        })
+    } else {
+        None
+    }
+}
+
+/// Clients send requests from 0.0.0.0 before they have a lease, so the address is the one being
+/// requested or renewed. Discovers carry neither and are skipped.
+fn parse_dhcp_request(payload: &[u8]) -> Option<PassiveObservation> {
+    let message = v4::Message::decode(&mut dhcproto::Decoder::new(payload)).ok()?;
+    if message.opcode() != v4::Opcode::BootRequest || message.htype() != v4::HType::Eth {
+        return None;
+    }
+
+    let chaddr = message.chaddr();
+    let mac: [u8; 6] = chaddr.get(..6)?.try_into().ok()?;
+
+    let requested = match message.opts().get(OptionCode::RequestedIpAddress) {
+        Some(DhcpOption::RequestedIpAddress(ip)) => Some(*ip),
+        _ => None,
+    };
+    let ip = requested.or_else(|| Some(message.ciaddr()).filter(|ip| !ip.is_unspecified()))?;
+
+    let hostname = match message.opts().get(OptionCode::Hostname) {
+        Some(DhcpOption::Hostname(name)) => Some(name.trim().to_string()).filter(|n| !n.is_empty()),
+        _ => None,
+    };
+
+    Some(PassiveObservation {
+        mac: MacAddress::new(mac),
+        ip: IpAddr::V4(ip),
+        hostname,
+        protocol: PassiveProtocol::Dhcp,
+    })
+}
+
+/// `.local` name of an address record the host announced for its own address
+fn mdns_hostname(payload: &[u8], source_ip: IpAddr) -> Option<String> {
+    let message = DnsMessage::from_vec(payload).ok()?;
+
+    message
+        .answers()
+        .iter()
+        .chain(message.additionals())
+        .find(|record| match record.data() {
+            RData::A(a) => IpAddr::V4(a.0) == source_ip,
+            RData::AAAA(aaaa) => IpAddr::V6(aaaa.0) == source_ip,
+            _ => false,
+        })
+        .map(|record| record.name().to_utf8().trim_end_matches('.').to_string())
+}
+
+/// Name a host registers or sends datagrams under. Name queries carry the name being looked up
+/// rather than the sender's, so only registrations and refreshes are used on the name port.
+fn netbios_hostname(payload: &[u8], source_port: u16) -> Option<String> {
+    let name_offset = if source_port == NETBIOS_DATAGRAM_PORT {
+        // Type, flags, ID, source address and port, length and offset
+        14
+    } else {
+        let flags = u16::from_be_bytes([*payload.get(2)?, *payload.get(3)?]);
+        let is_response = flags & 0x8000 != 0;
+        let opcode = (flags >> 11) & 0x0F;
+        // Registration, refresh and the legacy refresh opcode
+        if is_response || !matches!(opcode, 5 | 8 | 9) {
+            return None;
+        }
+        // Group names are shared by a workgroup rather than naming the host
+        let nb_flags = *payload.get(62)?;
+        if nb_flags & 0x80 != 0 {
+            return None;
+        }
+        12
+    };
+
+    let (name, suffix) = decode_netbios_name(payload.get(name_offset..)?)?;
+
+    // Workstation and file server names are the host's own
+    match suffix {
+        0x00 | 0x20 if !name.is_empty() => Some(name),
+        _ => None,
+    }
+}
+
+/// First-level decoding of an encoded name: a length byte of 32, then each byte of the padded
+/// 16-byte name split into two nibbles, each stored as `'A' + nibble`. The last byte of the name
+/// is its type suffix.
+fn decode_netbios_name(encoded: &[u8]) -> Option<(String, u8)> {
+    if *encoded.first()? != 32 {
+        return None;
+    }
+
+    let bytes: Vec<u8> = encoded
+        .get(1..33)?
+        .chunks(2)
+        .map(|pair| {
+            let high = pair[0].checked_sub(b'A')?;
+            let low = pair[1].checked_sub(b'A')?;
+            (high < 16 && low < 16).then_some((high << 4) | low)
+        })
+        .collect::<Option<_>>()?;
+
+    let suffix = bytes[15];
+    let name = String::from_utf8_lossy(&bytes[..15]).trim_end().to_string();
+
+    Some((name, suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn ethernet_frame(source_mac: [u8; 6], ethertype: u16, payload: &[u8]) -> Vec<u8> {
+        let mut frame = vec![0xff; 6];
+        frame.extend_from_slice(&source_mac);
+        frame.extend_from_slice(&ethertype.to_be_bytes());
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    fn udp_over_ipv4(
+        source: Ipv4Addr,
+        source_port: u16,
+        destination_port: u16,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let udp_length = 8 + data.len() as u16;
+        let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, 17, 0, 0];
+        packet[2..4].copy_from_slice(&(20 + udp_length).to_be_bytes());
+        packet.extend_from_slice(&source.octets());
+        packet.extend_from_slice(&[255, 255, 255, 255]);
+        packet.extend_from_slice(&source_port.to_be_bytes());
+        packet.extend_from_slice(&destination_port.to_be_bytes());
+        packet.extend_from_slice(&udp_length.to_be_bytes());
+        packet.extend_from_slice(&[0, 0]);
+        packet.extend_from_slice(data);
+        packet
+    }
+
+    const MAC: [u8; 6] = [0x3c, 0x22, 0xfb, 0x01, 0x02, 0x03];
+
+    #[test]
+    fn test_dhcp_request_uses_requested_address_and_hostname() {
+        use dhcproto::{Encodable, Encoder};
+
+        let mut message = v4::Message::default();
+        message.set_chaddr(&MAC);
+        message
+            .opts_mut()
+            .insert(DhcpOption::MessageType(v4::MessageType::Request));
+        message
+            .opts_mut()
+            .insert(DhcpOption::RequestedIpAddress(Ipv4Addr::new(
+                192, 168, 1, 77,
+            )));
+        message
+            .opts_mut()
+            .insert(DhcpOption::Hostname("Pixel-7".to_string()));
+
+        let mut data = Vec::new();
+        message.encode(&mut Encoder::new(&mut data)).unwrap();
+
+        let frame = ethernet_frame(
+            MAC,
+            0x0800,
+            &udp_over_ipv4(Ipv4Addr::UNSPECIFIED, 68, DHCP_SERVER_PORT, &data),
+        );
+        let observation = parse_frame(&frame).unwrap();
+
+        assert_eq!(observation.ip, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 77)));
+        assert_eq!(observation.hostname.as_deref(), Some("Pixel-7"));
+        assert_eq!(observation.protocol, PassiveProtocol::Dhcp);
+    }
+
+    #[test]
+    fn test_netbios_datagram_source_name() {
+        // "DESKTOP-1" padded to 15 bytes with a workstation suffix
+        let mut name = b"DESKTOP-1".to_vec();
+        name.resize(15, b' ');
+        name.push(0x00);
+
+        let mut datagram = vec![0x11, 0x02, 0, 1, 192, 168, 1, 60, 0, 138, 0, 0, 0, 0, 32];
+        for byte in name {
+            datagram.push(b'A' + (byte >> 4));
+            datagram.push(b'A' + (byte & 0x0F));
+        }
+        datagram.push(0);
+
+        let frame = ethernet_frame(
+            MAC,
+            0x0800,
+            &udp_over_ipv4(
+                Ipv4Addr::new(192, 168, 1, 60),
+                NETBIOS_DATAGRAM_PORT,
+                NETBIOS_DATAGRAM_PORT,
+                &datagram,
+            ),
+        );
+        let observation = parse_frame(&frame).unwrap();
+
+        assert_eq!(observation.ip, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 60)));
+        assert_eq!(observation.hostname.as_deref(), Some("DESKTOP-1"));
+        assert_eq!(observation.protocol, PassiveProtocol::NetBios);
+    }
+}
@@ -0,0 +1,350 @@
+//! Liveness checks for hosts on routed subnets, which ARP and NDP can't reach.
+//!
+//! ## Platform Behavior
+//!
+//! | Platform | Method                        | Fallback                       |
+//! |----------|-------------------------------|--------------------------------|
+//! | Linux    | ICMP/ICMPv6 echo (raw socket) | TCP connect to discovery ports |
+//! | macOS    | ICMP/ICMPv6 echo (raw socket) | TCP connect to discovery ports |
+//! | Windows  | -                             | TCP connect to discovery ports |
+//!
+//! Hosts that drop echoes can still be found with a TCP ping: a connection to one of a few
+//! ports, where a refusal gives a live host away as surely as an accept.
+
+use std::io::ErrorKind;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use pnet::packet::icmp::{self, IcmpPacket, IcmpTypes};
+use pnet::packet::icmpv6::{Icmpv6Packet, Icmpv6Types};
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::transport::{TransportChannelType, TransportProtocol};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+use crate::daemon::utils::scanner::ConnectOptions;
+
+/// Wait time after each round before retrying
+pub const ROUND_WAIT: Duration = Duration::from_secs(1);
+/// Extra receive time after final round
+pub const POST_SWEEP_RECEIVE: Duration = Duration::from_secs(2);
+
+/// Type, code, checksum, identifier and sequence number
+const ECHO_HEADER_LEN: usize = 8;
+
+const TRANSPORT_BUFFER_SIZE: usize = 4096;
+
+/// A host that answered an echo request
+#[derive(Debug, Clone)]
+pub struct PingResult {
+    pub ip: IpAddr,
+    pub rtt: Duration,
+}
+
+/// Check if ICMP echo sweeps are available, ie the daemon may open raw sockets.
+pub fn is_available() -> bool {
+    #[cfg(unix)]
+    {
+        let available =
+            pnet::transport::transport_channel(TRANSPORT_BUFFER_SIZE, channel_type(false)).is_ok();
+        tracing::debug!(available, "Checking ICMP echo availability");
+        available
+    }
+
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
+
+/// Send echo requests to every target and report the ones that answer.
+///
+/// Returns a channel receiver that streams results as hosts respond.
+///
+/// # Arguments
+/// * `targets` - Addresses to ping, IPv4 and IPv6 alike
+/// * `retries` - Number of retry rounds for non-responding hosts (0 = single attempt)
+/// * `rate_pps` - Maximum echo requests per second
+pub fn sweep(
+    targets: Vec<IpAddr>,
+    retries: u32,
+    rate_pps: u32,
+) -> Result<std::sync::mpsc::Receiver<PingResult>> {
+    #[cfg(unix)]
+    {
+        echo::sweep(targets, retries, rate_pps)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (targets, retries, rate_pps);
+        Err(anyhow::anyhow!(
+            "ICMP echo sweeps are not supported on this platform"
+        ))
+    }
+}
+
+/// Connect to each port in turn until one answers, accepted or refused, and return how long the
+/// answer took.
+pub async fn tcp_ping(ip: IpAddr, ports: &[u16], connect: ConnectOptions) -> Option<Duration> {
+    for port in ports {
+        let started = Instant::now();
+        match timeout(connect.timeout, TcpStream::connect((ip, *port))).await {
+            Ok(Ok(_)) => return Some(started.elapsed()),
+            Ok(Err(e)) if e.kind() == ErrorKind::ConnectionRefused => {
+                return Some(started.elapsed());
+            }
+            _ => continue,
+        }
+    }
+
+    None
+}
+
+fn channel_type(ipv6: bool) -> TransportChannelType {
+    TransportChannelType::Layer4(if ipv6 {
+        TransportProtocol::Ipv6(IpNextHeaderProtocols::Icmpv6)
+    } else {
+        TransportProtocol::Ipv4(IpNextHeaderProtocols::Icmp)
+    })
+}
+
+pub fn build_echo_request(ipv6: bool, identifier: u16, sequence: u16) -> Vec<u8> {
+    let mut packet = vec![0u8; ECHO_HEADER_LEN];
+    packet[0] = if ipv6 {
+        Icmpv6Types::EchoRequest.0
+    } else {
+        IcmpTypes::EchoRequest.0
+    };
+    packet[4..6].copy_from_slice(&identifier.to_be_bytes());
+    packet[6..8].copy_from_slice(&sequence.to_be_bytes());
+
+    // The kernel fills in ICMPv6 checksums, which cover the source address it picks
+    if !ipv6 {
+        let checksum = icmp::checksum(&IcmpPacket::new(&packet).unwrap());
+        packet[2..4].copy_from_slice(&checksum.to_be_bytes());
+    }
+
+    packet
+}
+
+/// Identifier and sequence number of an echo reply
+fn parse_echo_reply(ipv6: bool, packet: &[u8]) -> Option<(u16, u16)> {
+    let is_reply = if ipv6 {
+        Icmpv6Packet::new(packet)?.get_icmpv6_type() == Icmpv6Types::EchoReply
+    } else {
+        IcmpPacket::new(packet)?.get_icmp_type() == IcmpTypes::EchoReply
+    };
+
+    if !is_reply || packet.len() < ECHO_HEADER_LEN {
+        return None;
+    }
+
+    Some((
+        u16::from_be_bytes([packet[4], packet[5]]),
+        u16::from_be_bytes([packet[6], packet[7]]),
+    ))
+}
+
+#[cfg(unix)]
+mod echo {
+    use std::collections::{HashMap, HashSet};
+    use std::net::IpAddr;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::Sender;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use anyhow::Result;
+    use pnet::packet::Packet;
+    use pnet::packet::icmp::IcmpPacket;
+    use pnet::packet::icmpv6::Icmpv6Packet;
+    use pnet::transport::{
+        TransportSender, icmp_packet_iter, icmpv6_packet_iter, transport_channel,
+    };
+
+    use super::{
+        POST_SWEEP_RECEIVE, PingResult, ROUND_WAIT, TRANSPORT_BUFFER_SIZE, build_echo_request,
+        channel_type, parse_echo_reply,
+    };
+
+    /// State shared between the sending thread and the receiver of each address family
+    struct SweepState {
+        identifier: u16,
+        /// When each (target, round) echo went out
+        sent: Mutex<HashMap<(IpAddr, u16), Instant>>,
+        answered: Mutex<HashSet<IpAddr>>,
+        sending_done: AtomicBool,
+    }
+
+    pub fn sweep(
+        targets: Vec<IpAddr>,
+        retries: u32,
+        rate_pps: u32,
+    ) -> Result<std::sync::mpsc::Receiver<PingResult>> {
+        let (result_tx, result_rx) = std::sync::mpsc::channel();
+        let state = Arc::new(SweepState {
+            // Raw sockets see every echo reply to the host, so ours are told apart by identifier
+            identifier: rand::random(),
+            sent: Mutex::new(HashMap::new()),
+            answered: Mutex::new(HashSet::new()),
+            sending_done: AtomicBool::new(false),
+        });
+
+        let (ipv6_targets, ipv4_targets): (Vec<IpAddr>, Vec<IpAddr>) =
+            targets.into_iter().partition(IpAddr::is_ipv6);
+
+        // Open every channel up front so a missing capability fails the sweep rather than
+        // silently skipping a family
+        let mut families: Vec<(bool, TransportSender, Vec<IpAddr>)> = Vec::new();
+        let mut receivers = Vec::new();
+        for (ipv6, family_targets) in [(false, ipv4_targets), (true, ipv6_targets)] {
+            if family_targets.is_empty() {
+                continue;
+            }
+
+            let (tx, mut rx) = transport_channel(TRANSPORT_BUFFER_SIZE, channel_type(ipv6))?;
+            let next_reply = move |wait: Duration| -> Option<(IpAddr, u16, u16)> {
+                let (ip, reply) = if ipv6 {
+                    let mut replies = icmpv6_packet_iter(&mut rx);
+                    let (packet, ip) = replies.next_with_timeout(wait).ok()??;
+                    (ip, parse_echo_reply(true, packet.packet()))
+                } else {
+                    let mut replies = icmp_packet_iter(&mut rx);
+                    let (packet, ip) = replies.next_with_timeout(wait).ok()??;
+                    (ip, parse_echo_reply(false, packet.packet()))
+                };
+                reply.map(|(identifier, sequence)| (ip, identifier, sequence))
+            };
+
+            receivers.push(spawn_receiver(next_reply, state.clone(), result_tx.clone()));
+            families.push((ipv6, tx, family_targets));
+        }
+
+        let send_delay = Duration::from_micros(1_000_000 / rate_pps.max(1) as u64);
+        let total_rounds = 1 + retries;
+
+        thread::spawn(move || {
+            for round in 0..total_rounds {
+                let mut pending = 0usize;
+
+                for (ipv6, tx, family_targets) in &mut families {
+                    let packet = build_echo_request(*ipv6, state.identifier, round as u16);
+
+                    for ip in family_targets.iter() {
+                        if state.answered.lock().unwrap().contains(ip) {
+                            continue;
+                        }
+                        pending += 1;
+
+                        state
+                            .sent
+                            .lock()
+                            .unwrap()
+                            .insert((*ip, round as u16), Instant::now());
+                        let sent = if *ipv6 {
+                            tx.send_to(Icmpv6Packet::new(&packet).unwrap(), *ip)
+                        } else {
+                            tx.send_to(IcmpPacket::new(&packet).unwrap(), *ip)
+                        };
+                        if let Err(e) = sent {
+                            tracing::trace!(ip = %ip, error = %e, "Failed to send echo request");
+                        }
+
+                        thread::sleep(send_delay);
+                    }
+                }
+
+                tracing::debug!(round = round + 1, total_rounds, pending, "Echo round sent");
+
+                if pending == 0 {
+                    break;
+                }
+                thread::sleep(ROUND_WAIT);
+            }
+
+            state.sending_done.store(true, Ordering::Relaxed);
+            for receiver in receivers {
+                let _ = receiver.join();
+            }
+        });
+
+        Ok(result_rx)
+    }
+
+    fn spawn_receiver<F>(
+        mut next_reply: F,
+        state: Arc<SweepState>,
+        result_tx: Sender<PingResult>,
+    ) -> thread::JoinHandle<()>
+    where
+        F: FnMut(Duration) -> Option<(IpAddr, u16, u16)> + Send + 'static,
+    {
+        thread::spawn(move || {
+            let mut final_deadline: Option<Instant> = None;
+
+            loop {
+                if let Some(deadline) = final_deadline {
+                    if Instant::now() >= deadline {
+                        break;
+                    }
+                } else if state.sending_done.load(Ordering::Relaxed) {
+                    final_deadline = Some(Instant::now() + POST_SWEEP_RECEIVE);
+                }
+
+                let Some((ip, identifier, sequence)) = next_reply(Duration::from_millis(100))
+                else {
+                    continue;
+                };
+
+                if identifier != state.identifier {
+                    continue;
+                }
+
+                let Some(sent_at) = state.sent.lock().unwrap().get(&(ip, sequence)).copied() else {
+                    continue;
+                };
+
+                if state.answered.lock().unwrap().insert(ip) {
+                    let rtt = sent_at.elapsed();
+                    tracing::debug!(ip = %ip, rtt_ms = rtt.as_millis(), "Echo reply received");
+                    if result_tx.send(PingResult { ip, rtt }).is_err() {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pnet::packet::icmp::echo_request::EchoRequestPacket;
+
+    #[test]
+    fn test_build_echo_request_has_valid_checksum() {
+        let packet = build_echo_request(false, 0x1234, 7);
+
+        let echo = EchoRequestPacket::new(&packet).unwrap();
+        assert_eq!(echo.get_icmp_type(), IcmpTypes::EchoRequest);
+        assert_eq!(echo.get_identifier(), 0x1234);
+        assert_eq!(echo.get_sequence_number(), 7);
+        assert_eq!(
+            echo.get_checksum(),
+            icmp::checksum(&IcmpPacket::new(&packet).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_echo_reply_only_accepts_replies() {
+        let mut reply = build_echo_request(true, 0xbeef, 2);
+        assert_eq!(parse_echo_reply(true, &reply), None);
+
+        reply[0] = Icmpv6Types::EchoReply.0;
+        assert_eq!(parse_echo_reply(true, &reply), Some((0xbeef, 2)));
+        assert_eq!(parse_echo_reply(false, &reply), None);
+    }
+}
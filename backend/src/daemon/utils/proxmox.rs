@@ -0,0 +1,401 @@
+//! Proxmox VE API client.
+//!
+//! Reads the cluster's nodes and guests, and each guest's network devices: the MAC, bridge and
+//! VLAN tag from its config, and the addresses the guest reports, through the QEMU guest agent
+//! for VMs and the container's own interfaces for LXC.
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{Result, anyhow};
+use mac_address::MacAddress;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GuestKind {
+    Qemu,
+    Lxc,
+}
+
+impl GuestKind {
+    fn path(&self) -> &'static str {
+        match self {
+            GuestKind::Qemu => "qemu",
+            GuestKind::Lxc => "lxc",
+        }
+    }
+}
+
+/// A node of the cluster, and the address it's reached at
+#[derive(Debug, Clone, Deserialize)]
+pub struct ClusterNode {
+    pub name: String,
+    #[serde(default)]
+    pub ip: Option<IpAddr>,
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub online: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GuestSummary {
+    #[serde(deserialize_with = "deserialize_vmid")]
+    pub vmid: u32,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub status: String,
+    #[serde(default, deserialize_with = "deserialize_flag")]
+    pub template: bool,
+}
+
+/// A network device in a guest's config, with the addresses the guest has on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuestNic {
+    /// Config key, ie "net0"
+    pub key: String,
+    pub mac: MacAddress,
+    pub bridge: Option<String>,
+    pub vlan: Option<u16>,
+    pub ips: Vec<IpAddr>,
+}
+
+impl GuestNic {
+    /// Interface name recording where the device is attached, ie "net0 (vmbr0, VLAN 20)"
+    pub fn display_name(&self) -> String {
+        match (&self.bridge, self.vlan) {
+            (Some(bridge), Some(vlan)) => format!("{} ({}, VLAN {})", self.key, bridge, vlan),
+            (Some(bridge), None) => format!("{} ({})", self.key, bridge),
+            (None, _) => self.key.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Envelope<T> {
+    data: T,
+}
+
+#[derive(Deserialize)]
+struct AgentResult {
+    result: Vec<AgentInterface>,
+}
+
+#[derive(Deserialize)]
+struct AgentInterface {
+    #[serde(rename = "hardware-address", default)]
+    hardware_address: Option<String>,
+    #[serde(rename = "ip-addresses", default)]
+    ip_addresses: Vec<AgentAddress>,
+}
+
+#[derive(Deserialize)]
+struct AgentAddress {
+    #[serde(rename = "ip-address")]
+    ip_address: String,
+}
+
+#[derive(Deserialize)]
+struct LxcInterface {
+    #[serde(default)]
+    hwaddr: Option<String>,
+    #[serde(default)]
+    inet: Option<String>,
+    #[serde(default)]
+    inet6: Option<String>,
+}
+
+pub struct ProxmoxClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl ProxmoxClient {
+    /// Connect to the API at `url`, ie "https://pve.lan:8006", with an API token in the form
+    /// `user@realm!tokenid` and its secret
+    pub fn new(
+        url: &str,
+        token_id: &str,
+        token_secret: &str,
+        allow_self_signed_certs: bool,
+    ) -> Result<Self> {
+        let mut headers = HeaderMap::new();
+        let mut authorization =
+            HeaderValue::from_str(&format!("PVEAPIToken={}={}", token_id, token_secret.trim()))?;
+        authorization.set_sensitive(true);
+        headers.insert(AUTHORIZATION, authorization);
+
+        let http = reqwest::Client::builder()
+            .default_headers(headers)
+            .danger_accept_invalid_certs(allow_self_signed_certs)
+            .timeout(REQUEST_TIMEOUT)
+            .build()?;
+
+        Ok(Self {
+            http,
+            base_url: format!("{}/api2/json", url.trim_end_matches('/')),
+        })
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let response = self
+            .http
+            .get(format!("{}{}", self.base_url, path))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(anyhow!("Proxmox API returned {} for {}", status, path));
+        }
+
+        Ok(response.json::<Envelope<T>>().await?.data)
+    }
+
+    /// Nodes of the cluster, or the single node of a standalone install
+    pub async fn nodes(&self) -> Result<Vec<ClusterNode>> {
+        #[derive(Deserialize)]
+        struct Entry {
+            #[serde(rename = "type")]
+            kind: String,
+            #[serde(flatten)]
+            node: serde_json::Value,
+        }
+
+        let entries: Vec<Entry> = self.get("/cluster/status").await?;
+        Ok(entries
+            .into_iter()
+            .filter(|e| e.kind == "node")
+            .filter_map(|e| serde_json::from_value(e.node).ok())
+            .collect())
+    }
+
+    pub async fn guests(&self, node: &str, kind: GuestKind) -> Result<Vec<GuestSummary>> {
+        self.get(&format!("/nodes/{}/{}", node, kind.path())).await
+    }
+
+    /// The guest's config, for its hostname and network devices
+    pub async fn guest_config(
+        &self,
+        node: &str,
+        kind: GuestKind,
+        vmid: u32,
+    ) -> Result<BTreeMap<String, serde_json::Value>> {
+        self.get(&format!("/nodes/{}/{}/{}/config", node, kind.path(), vmid))
+            .await
+    }
+
+    /// Addresses the running guest reports, by MAC. VMs without the guest agent report none.
+    pub async fn guest_addresses(
+        &self,
+        node: &str,
+        kind: GuestKind,
+        vmid: u32,
+    ) -> Result<Vec<(MacAddress, IpAddr)>> {
+        let addresses = match kind {
+            GuestKind::Qemu => {
+                let agent: AgentResult = self
+                    .get(&format!(
+                        "/nodes/{}/qemu/{}/agent/network-get-interfaces",
+                        node, vmid
+                    ))
+                    .await?;
+                agent
+                    .result
+                    .into_iter()
+                    .flat_map(|i| {
+                        let mac = i.hardware_address.as_deref().and_then(parse_mac);
+                        i.ip_addresses
+                            .into_iter()
+                            .map(move |a| (mac, strip_prefix_len(&a.ip_address)))
+                    })
+                    .collect::<Vec<_>>()
+            }
+            GuestKind::Lxc => {
+                let interfaces: Vec<LxcInterface> = self
+                    .get(&format!("/nodes/{}/lxc/{}/interfaces", node, vmid))
+                    .await?;
+                interfaces
+                    .into_iter()
+                    .flat_map(|i| {
+                        let mac = i.hwaddr.as_deref().and_then(parse_mac);
+                        [i.inet, i.inet6]
+                            .into_iter()
+                            .flatten()
+                            .map(move |a| (mac, strip_prefix_len(&a)))
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(addresses
+            .into_iter()
+            .filter_map(|(mac, ip)| Some((mac?, ip?)))
+            .filter(|(_, ip)| is_reachable(ip))
+            .collect())
+    }
+}
+
+/// Network devices in a guest's config, with the addresses reported for each MAC, and for
+/// containers the static addresses the config assigns
+pub fn guest_nics(
+    config: &BTreeMap<String, serde_json::Value>,
+    addresses: &[(MacAddress, IpAddr)],
+) -> Vec<GuestNic> {
+    let mut nics: Vec<GuestNic> = config
+        .iter()
+        .filter(|(key, _)| {
+            key.strip_prefix("net")
+                .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .filter_map(|(key, value)| parse_net(key, value.as_str()?))
+        .collect();
+
+    for nic in &mut nics {
+        for (mac, ip) in addresses {
+            if *mac == nic.mac && !nic.ips.contains(ip) {
+                nic.ips.push(*ip);
+            }
+        }
+    }
+
+    nics.sort_by_key(|nic| nic.key[3..].parse::<u32>().unwrap_or(u32::MAX));
+    nics
+}
+
+/// Parse a network device, ie "virtio=BC:24:11:2A:5F:01,bridge=vmbr0,tag=20" for a VM or
+/// "name=eth0,bridge=vmbr0,hwaddr=BC:24:11:2A:5F:01,ip=10.0.20.5/24,type=veth" for a container
+fn parse_net(key: &str, value: &str) -> Option<GuestNic> {
+    let mut mac = None;
+    let mut bridge = None;
+    let mut vlan = None;
+    let mut ips = Vec::new();
+
+    for (option, setting) in value.split(',').filter_map(|p| p.split_once('=')) {
+        match option {
+            "bridge" => bridge = Some(setting.to_string()),
+            "tag" => vlan = setting.parse().ok(),
+            "ip" | "ip6" => ips.extend(strip_prefix_len(setting).filter(is_reachable)),
+            // VMs give the MAC as the value of the device model, ie "virtio=..."
+            _ if mac.is_none() => mac = parse_mac(setting),
+            _ => {}
+        }
+    }
+
+    Some(GuestNic {
+        key: key.to_string(),
+        mac: mac?,
+        bridge,
+        vlan,
+        ips,
+    })
+}
+
+fn parse_mac(value: &str) -> Option<MacAddress> {
+    let mac = MacAddress::from_str(value).ok()?;
+    (mac.bytes() != [0; 6]).then_some(mac)
+}
+
+/// Address of "10.0.20.5/24" or "10.0.20.5". Config values like "dhcp" or "auto" give none.
+fn strip_prefix_len(value: &str) -> Option<IpAddr> {
+    IpAddr::from_str(value.split('/').next()?).ok()
+}
+
+fn is_reachable(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => !v4.is_loopback() && !v4.is_link_local() && !v4.is_unspecified(),
+        IpAddr::V6(v6) => !v6.is_loopback() && !v6.is_unicast_link_local() && !v6.is_unspecified(),
+    }
+}
+
+/// Container listings give the VMID as a string, VM listings as a number
+fn deserialize_vmid<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| serde::de::Error::custom("invalid vmid")),
+        serde_json::Value::String(s) => s.parse().map_err(serde::de::Error::custom),
+        _ => Err(serde::de::Error::custom("invalid vmid")),
+    }
+}
+
+/// Flags come as 0 and 1
+fn deserialize_flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Bool(b) => b,
+        serde_json::Value::Number(n) => n.as_u64() != Some(0),
+        serde_json::Value::String(s) => s != "0",
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mac(s: &str) -> MacAddress {
+        MacAddress::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_guest_nics_from_vm_config_and_agent() {
+        let config: BTreeMap<String, serde_json::Value> =
+            serde_json::from_value(serde_json::json!({
+                "name": "web-01",
+                "net1": "virtio=BC:24:11:00:00:02,bridge=vmbr1",
+                "net0": "virtio=BC:24:11:00:00:01,bridge=vmbr0,firewall=1,tag=20",
+                "netboot": "ignored",
+                "scsi0": "local-lvm:vm-100-disk-0,size=32G"
+            }))
+            .unwrap();
+        let addresses = vec![
+            (mac("BC:24:11:00:00:01"), "10.0.20.15".parse().unwrap()),
+            (mac("bc:24:11:00:00:01"), "fd00:20::15".parse().unwrap()),
+        ];
+
+        let nics = guest_nics(&config, &addresses);
+
+        assert_eq!(nics.len(), 2);
+        assert_eq!(nics[0].key, "net0");
+        assert_eq!(nics[0].mac, mac("BC:24:11:00:00:01"));
+        assert_eq!(nics[0].vlan, Some(20));
+        assert_eq!(nics[0].ips.len(), 2);
+        assert_eq!(nics[0].display_name(), "net0 (vmbr0, VLAN 20)");
+        assert_eq!(nics[1].display_name(), "net1 (vmbr1)");
+        assert!(nics[1].ips.is_empty());
+    }
+
+    #[test]
+    fn test_parse_net_container_static_address() {
+        let nic = parse_net(
+            "net0",
+            "name=eth0,bridge=vmbr0,hwaddr=BC:24:11:00:00:03,ip=10.0.0.5/24,ip6=dhcp,type=veth",
+        )
+        .unwrap();
+
+        assert_eq!(nic.mac, mac("BC:24:11:00:00:03"));
+        assert_eq!(nic.ips, vec!["10.0.0.5".parse::<IpAddr>().unwrap()]);
+
+        assert_eq!(parse_net("net1", "name=eth1,bridge=vmbr0,ip=dhcp"), None);
+    }
+
+    #[test]
+    fn test_guest_summary_accepts_string_vmid() {
+        let guests: Vec<GuestSummary> = serde_json::from_value(serde_json::json!([
+            { "vmid": 100, "name": "web-01", "status": "running", "template": 1 },
+            { "vmid": "101", "name": "dns", "status": "stopped" }
+        ]))
+        .unwrap();
+
+        assert_eq!(guests[0].vmid, 100);
+        assert!(guests[0].template);
+        assert_eq!(guests[1].vmid, 101);
+        assert!(!guests[1].template);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::daemon::discovery::types::base::DiscoveryCriticalError;
+use crate::server::services::r#impl::banners::{BannerResponse, MAX_BANNER_LENGTH};
 use crate::server::services::r#impl::base::Service;
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
 use anyhow::anyhow;
@@ -6,6 +7,7 @@ use anyhow::{Error, Result};
 use cidr::IpCidr;
 use dhcproto::Encodable;
 use dhcproto::v4::{self, Decodable, Encoder, Message, MessageType};
+use dhcproto::v6;
 use futures::stream::FuturesUnordered;
 use futures::stream::StreamExt;
 use hickory_resolver::Resolver;
@@ -14,19 +16,252 @@ use hickory_resolver::name_server::TokioConnectionProvider;
 use hickory_resolver::proto::xfer::Protocol;
 use rand::{Rng, SeedableRng};
 use rsntp::AsyncSntpClient;
-use snmp2::{AsyncSession, Oid};
 use std::collections::HashMap;
 use std::net::{IpAddr, SocketAddr};
 use std::pin::Pin;
+use std::sync::Arc;
 use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::net::UdpSocket;
 use tokio::{net::TcpStream, time::timeout};
 use tokio_util::sync::CancellationToken;
 
-use crate::server::ports::r#impl::base::{PortType, TransportProtocol};
+use crate::server::daemons::r#impl::api::SubnetScanTuning;
+use crate::server::ports::r#impl::base::PortType;
+use crate::server::scan_profiles::r#impl::base::{ScanSettings, UdpProbe};
+use crate::server::tls_certificates::r#impl::base::TlsCertificateBase;
 
 pub const SCAN_TIMEOUT: Duration = Duration::from_millis(800);
 
+/// How long to wait on each TCP connection, and how often to try again after a timeout
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectOptions {
+    pub timeout: Duration,
+    pub retries: u8,
+}
+
+impl Default for ConnectOptions {
+    fn default() -> Self {
+        Self::from(&ScanSettings::default())
+    }
+}
+
+impl From<&ScanSettings> for ConnectOptions {
+    fn from(settings: &ScanSettings) -> Self {
+        Self {
+            timeout: settings.connect_timeout(),
+            retries: settings.retries,
+        }
+    }
+}
+
+/// Floor and ceiling for connect timeouts derived from observed round trip times
+const MIN_ADAPTIVE_TIMEOUT: Duration = Duration::from_millis(250);
+const MAX_ADAPTIVE_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_ADAPTIVE_RETRIES: u8 = 3;
+/// Share of connection attempts timing out in a window above which a subnet is treated as
+/// congested. Filtered ports time out too, so this sits well above what packet loss alone causes
+const CONGESTION_TIMEOUT_RATIO: f64 = 0.3;
+/// Batch sizes never shrink below this fraction of the initial size, so a subnet full of
+/// firewalled hosts doesn't slow to a crawl
+const MIN_BATCH_DIVISOR: usize = 8;
+
+/// Port batch size and connection settings for one TCP scan
+#[derive(Debug, Clone, Copy)]
+pub struct ScanParams {
+    pub batch_size: usize,
+    pub connect: ConnectOptions,
+}
+
+/// Tunes TCP scan parameters for each subnet a session scans, similar to TCP congestion
+/// control: batch sizes shrink multiplicatively when connections start timing out and grow
+/// back additively while they don't, timeouts follow the smoothed round trip time and back off
+/// while the link looks slower than them, and retries go up while retried attempts keep getting
+/// answers.
+#[derive(Default)]
+pub struct AdaptiveScanControl {
+    subnets: std::sync::Mutex<HashMap<IpCidr, Arc<SubnetScanControl>>>,
+}
+
+impl AdaptiveScanControl {
+    /// Controller for `cidr`, starting from `initial` the first time the subnet is scanned.
+    /// Batch sizes never grow past the initial size, which is bounded by file descriptors.
+    pub fn subnet(&self, cidr: IpCidr, initial: ScanParams) -> Arc<SubnetScanControl> {
+        self.subnets
+            .lock()
+            .unwrap()
+            .entry(cidr)
+            .or_insert_with(|| Arc::new(SubnetScanControl::new(cidr, initial)))
+            .clone()
+    }
+
+    /// Parameters each scanned subnet ended up with
+    pub fn summary(&self) -> Vec<SubnetScanTuning> {
+        let mut summary: Vec<SubnetScanTuning> = self
+            .subnets
+            .lock()
+            .unwrap()
+            .values()
+            .map(|control| control.summary())
+            .collect();
+        summary.sort_by_key(|s| s.cidr.to_string());
+        summary
+    }
+}
+
+pub struct SubnetScanControl {
+    cidr: IpCidr,
+    initial: ScanParams,
+    state: std::sync::Mutex<ControlState>,
+}
+
+struct ControlState {
+    params: ScanParams,
+    smoothed_rtt: Option<Duration>,
+    rtt_variance: Duration,
+    window_attempts: usize,
+    window_timeouts: usize,
+    /// Retried attempts that were answered, ie attempts lost rather than filtered
+    window_late_answers: usize,
+    /// Answers that took most of the timeout they were given, ie the link is barely fast enough
+    window_slow_answers: usize,
+    attempts: u64,
+    timeouts: u64,
+}
+
+impl SubnetScanControl {
+    fn new(cidr: IpCidr, initial: ScanParams) -> Self {
+        Self {
+            cidr,
+            initial,
+            state: std::sync::Mutex::new(ControlState {
+                params: initial,
+                smoothed_rtt: None,
+                rtt_variance: Duration::ZERO,
+                window_attempts: 0,
+                window_timeouts: 0,
+                window_late_answers: 0,
+                window_slow_answers: 0,
+                attempts: 0,
+                timeouts: 0,
+            }),
+        }
+    }
+
+    pub fn params(&self) -> ScanParams {
+        self.state.lock().unwrap().params
+    }
+
+    /// Record a connection attempt that was accepted or refused after `rtt`. `attempt` counts
+    /// from 1, so later attempts mean the earlier ones were lost.
+    pub fn record_answer(&self, rtt: Duration, attempt: u32) {
+        let mut state = self.state.lock().unwrap();
+
+        if rtt * 4 >= state.params.connect.timeout * 3 {
+            state.window_slow_answers += 1;
+        }
+
+        // Smoothing as in RFC 6298
+        match state.smoothed_rtt {
+            None => {
+                state.smoothed_rtt = Some(rtt);
+                state.rtt_variance = rtt / 2;
+            }
+            Some(srtt) => {
+                let deviation = srtt.abs_diff(rtt);
+                state.rtt_variance = (state.rtt_variance * 3 + deviation) / 4;
+                state.smoothed_rtt = Some((srtt * 7 + rtt) / 8);
+            }
+        }
+        if let Some(srtt) = state.smoothed_rtt {
+            state.params.connect.timeout =
+                (srtt + state.rtt_variance * 4).clamp(MIN_ADAPTIVE_TIMEOUT, MAX_ADAPTIVE_TIMEOUT);
+        }
+
+        if attempt > 1 {
+            state.window_late_answers += 1;
+        }
+        self.record_attempt(&mut state, false);
+    }
+
+    /// Record a connection attempt that went unanswered
+    pub fn record_timeout(&self) {
+        let mut state = self.state.lock().unwrap();
+        self.record_attempt(&mut state, true);
+    }
+
+    fn record_attempt(&self, state: &mut ControlState, timed_out: bool) {
+        state.attempts += 1;
+        state.window_attempts += 1;
+        if timed_out {
+            state.timeouts += 1;
+            state.window_timeouts += 1;
+        }
+
+        // Adjust once per batch worth of attempts, like once per round trip in TCP
+        if state.window_attempts < state.params.batch_size {
+            return;
+        }
+
+        let max_batch = self.initial.batch_size.max(1);
+        let min_batch = (max_batch / MIN_BATCH_DIVISOR).max(1);
+        let timeout_ratio = state.window_timeouts as f64 / state.window_attempts as f64;
+
+        if timeout_ratio > CONGESTION_TIMEOUT_RATIO {
+            state.params.batch_size = (state.params.batch_size / 2).max(min_batch);
+            // Filtered ports and dead addresses time out however long they're given, so the
+            // timeout only backs off, as in RFC 6298 §5.5, while there's no round trip time to
+            // go by or answers only just make it. The next answer sets it from the RTT again.
+            if state.smoothed_rtt.is_none() || state.window_slow_answers > 0 {
+                state.params.connect.timeout =
+                    (state.params.connect.timeout * 2).min(MAX_ADAPTIVE_TIMEOUT);
+            }
+        } else {
+            state.params.batch_size = (state.params.batch_size + min_batch).min(max_batch);
+        }
+
+        state.params.connect.retries = if state.window_late_answers > 0 {
+            (state.params.connect.retries + 1)
+                .min(MAX_ADAPTIVE_RETRIES.max(self.initial.connect.retries))
+        } else {
+            state
+                .params
+                .connect
+                .retries
+                .saturating_sub(1)
+                .max(self.initial.connect.retries)
+        };
+
+        tracing::trace!(
+            cidr = %self.cidr,
+            timeout_ratio,
+            late_answers = state.window_late_answers,
+            batch_size = state.params.batch_size,
+            timeout_ms = state.params.connect.timeout.as_millis(),
+            retries = state.params.connect.retries,
+            "Adjusted scan parameters"
+        );
+
+        state.window_attempts = 0;
+        state.window_timeouts = 0;
+        state.window_late_answers = 0;
+        state.window_slow_answers = 0;
+    }
+
+    fn summary(&self) -> SubnetScanTuning {
+        let state = self.state.lock().unwrap();
+        SubnetScanTuning {
+            cidr: self.cidr,
+            batch_size: state.params.batch_size,
+            timeout_ms: state.params.connect.timeout.as_millis() as u64,
+            retries: state.params.connect.retries,
+            smoothed_rtt_ms: state.smoothed_rtt.map(|rtt| rtt.as_millis() as u64),
+            attempts: state.attempts,
+            timeouts: state.timeouts,
+        }
+    }
+}
+
 /// Generic batch scanner that maintains constant parallelism
 /// This is the core RustScan pattern extracted into a reusable function
 ///
@@ -109,6 +344,57 @@ pub fn can_arp_scan(use_npcap: bool) -> bool {
     available
 }
 
+/// Check if NDP scanning is available for IPv6 host discovery.
+///
+/// # Arguments
+/// * `use_npcap` - (Windows only) Whether Npcap is enabled; required for NDP on Windows
+pub fn can_ndp_scan(use_npcap: bool) -> bool {
+    let available = crate::daemon::utils::ndp::is_available(use_npcap);
+
+    if available {
+        tracing::info!("NDP scanning capability confirmed. IPv6 host discovery enabled.");
+    } else {
+        tracing::warn!(
+            "NDP scanning not available. On-link IPv6 prefixes will not be scanned. \
+             Ensure the daemon has NET_RAW and NET_ADMIN capabilities (or Npcap on Windows)."
+        );
+    }
+
+    available
+}
+
+/// Check if ICMP echo sweeps are available for finding hosts on routed subnets.
+pub fn can_ping_sweep() -> bool {
+    let available = crate::daemon::utils::ping::is_available();
+
+    if available {
+        tracing::info!("ICMP echo capability confirmed. Fast routed host discovery enabled.");
+    } else {
+        tracing::warn!(
+            "ICMP echo not available. Will fall back to TCP port scanning for hosts on routed \
+             subnets. Ensure the daemon has the NET_RAW capability."
+        );
+    }
+
+    available
+}
+
+/// Check if half-open SYN scans are available for finding open TCP ports.
+pub fn can_syn_scan() -> bool {
+    let available = crate::daemon::utils::syn::is_available();
+
+    if available {
+        tracing::info!("Raw TCP capability confirmed. SYN scanning enabled.");
+    } else {
+        tracing::warn!(
+            "SYN scanning not available. Will fall back to TCP connect scans. SYN scans require \
+             Linux and the NET_RAW capability."
+        );
+    }
+
+    available
+}
+
 pub async fn scan_ports_and_endpoints(
     ip: IpAddr,
     cancel: CancellationToken,
@@ -116,6 +402,7 @@ pub async fn scan_ports_and_endpoints(
     cidr: IpCidr,
     gateway_ips: Vec<IpAddr>,
     tcp_ports_to_check: Vec<u16>,
+    settings: &ScanSettings,
 ) -> Result<(Vec<PortType>, Vec<EndpointResponse>), Error> {
     if cancel.is_cancelled() {
         return Err(anyhow!("Operation cancelled"));
@@ -125,8 +412,15 @@ pub async fn scan_ports_and_endpoints(
     let mut endpoint_responses = Vec::new();
 
     // Scan TCP ports with batching
-    let tcp_ports =
-        scan_tcp_ports(ip, cancel.clone(), port_scan_batch_size, tcp_ports_to_check).await?;
+    let tcp_ports = scan_tcp_ports(
+        ip,
+        cancel.clone(),
+        port_scan_batch_size,
+        tcp_ports_to_check,
+        settings.into(),
+        None,
+    )
+    .await?;
 
     let use_https_ports: HashMap<u16, bool> =
         tcp_ports.iter().map(|(p, h)| (p.number(), *h)).collect();
@@ -139,14 +433,25 @@ pub async fn scan_ports_and_endpoints(
     }
 
     // Scan UDP ports with batching
-    let udp_ports =
-        scan_udp_ports(ip, cancel.clone(), port_scan_batch_size, cidr, gateway_ips).await?;
+    let udp_ports = scan_udp_ports(
+        ip,
+        cancel.clone(),
+        port_scan_batch_size,
+        cidr,
+        gateway_ips,
+        &settings.udp_probes,
+    )
+    .await?;
     open_ports.extend(udp_ports);
 
     if cancel.is_cancelled() {
         return Err(anyhow!("Operation cancelled"));
     }
 
+    if !settings.probe_endpoints {
+        return Ok((open_ports, endpoint_responses));
+    }
+
     // Scan endpoints - check on ALL open TCP ports, not just filtered ones
     let mut ports_to_check = tcp_ports.clone();
 
@@ -198,91 +503,58 @@ pub async fn scan_tcp_ports(
     cancel: CancellationToken,
     batch_size: usize,
     tcp_ports_to_check: Vec<u16>,
+    connect: ConnectOptions,
+    control: Option<Arc<SubnetScanControl>>,
 ) -> Result<Vec<(PortType, bool)>, Error> {
     let ports: Vec<PortType> = tcp_ports_to_check
         .iter()
         .map(|p| PortType::new_tcp(*p))
         .collect();
 
-    let open_ports = batch_scan(ports.clone(), batch_size, cancel, move |port| async move {
-        let socket = SocketAddr::new(ip, port.number());
-
-        // Try connection with timeout, retry once on timeout for slow hosts
-        let mut attempts = 0;
-        let max_attempts = 2;
+    let open_ports = batch_scan(ports.clone(), batch_size, cancel, move |port| {
+        let control = control.clone();
+        async move {
+            let socket = SocketAddr::new(ip, port.number());
 
-        loop {
-            attempts += 1;
             let start = std::time::Instant::now();
+            let stream = connect_with_retries(socket, connect, control.as_deref()).await?;
+            let connect_time = start.elapsed();
 
-            match timeout(SCAN_TIMEOUT, TcpStream::connect(socket)).await {
-                Ok(Ok(stream)) => {
-                    let connect_time = start.elapsed();
+            // Try to peek at the connection to detect immediate disconnects
+            let mut buf = [0u8; 1];
+            let peek_result = timeout(Duration::from_millis(50), stream.peek(&mut buf)).await;
 
-                    // Try to peek at the connection to detect immediate disconnects
-                    let mut buf = [0u8; 1];
-                    let peek_result =
-                        timeout(Duration::from_millis(50), stream.peek(&mut buf)).await;
-
-                    let use_https = match peek_result {
-                        Ok(Ok(0)) => {
-                            // Port open - HTTPS (immediate close)"
-                            true
-                        }
-                        Ok(Ok(_)) => {
-                            // Port open - got bytes
-                            false
-                        }
-                        Ok(Err(_)) => {
-                            // Port open - peek error
-                            false
-                        }
-                        Err(_) => {
-                            // Port open - no immediate response
-                            false
-                        }
-                    };
-
-                    tracing::debug!(
-                        "Found open TCP port {}:{} (took {:?})",
-                        ip,
-                        port,
-                        connect_time
-                    );
-
-                    drop(stream);
-                    return Some((
-                        PortType::new_tcp(port.number()),
-                        use_https || port.is_https(),
-                    ));
+            let use_https = match peek_result {
+                Ok(Ok(0)) => {
+                    // Port open - HTTPS (immediate close)"
+                    true
                 }
-                Ok(Err(e)) => {
-                    if DiscoveryCriticalError::is_critical_error(e.to_string()) {
-                        tracing::error!("Critical error scanning {}:{}: {}", socket.ip(), port, e);
-                    }
-                    return None;
+                Ok(Ok(_)) => {
+                    // Port open - got bytes
+                    false
+                }
+                Ok(Err(_)) => {
+                    // Port open - peek error
+                    false
                 }
                 Err(_) => {
-                    let elapsed = start.elapsed();
-
-                    if attempts < max_attempts {
-                        tracing::trace!(
-                            "Port {}:{} timeout attempt {}/{} (took {:?}), retrying...",
-                            ip,
-                            port,
-                            attempts,
-                            max_attempts,
-                            elapsed
-                        );
-                        // Small delay before retry
-                        tokio::time::sleep(Duration::from_millis(100)).await;
-                        continue;
-                    } else {
-                        tracing::trace!("Port {}:{} timeout after {} attempts", ip, port, attempts);
-                        return None;
-                    }
+                    // Port open - no immediate response
+                    false
                 }
-            }
+            };
+
+            tracing::debug!(
+                "Found open TCP port {}:{} (took {:?})",
+                ip,
+                port,
+                connect_time
+            );
+
+            drop(stream);
+            Some((
+                PortType::new_tcp(port.number()),
+                use_https || port.is_https(),
+            ))
         }
     })
     .await;
@@ -303,12 +575,14 @@ pub async fn scan_udp_ports(
     batch_size: usize,
     cidr: IpCidr,
     gateway_ips: Vec<IpAddr>,
+    probes: &[UdpProbe],
 ) -> Result<Vec<PortType>, Error> {
-    let discovery_ports = Service::all_discovery_ports();
-    let ports: Vec<u16> = discovery_ports
+    // SNMP needs the network's credentials, so it's queried separately by the caller
+    let ports: Vec<u16> = probes
         .iter()
-        .filter(|p| p.protocol() == TransportProtocol::Udp)
-        .map(|p| p.number())
+        .filter(|probe| **probe != UdpProbe::Snmp)
+        .flat_map(|probe| probe.ports())
+        .copied()
         .collect();
 
     // UDP is slower and less reliable, cap at 10 concurrent
@@ -320,9 +594,10 @@ pub async fn scan_udp_ports(
         let result = match port {
             53 => test_dns_service(ip).await,
             123 => test_ntp_service(ip).await,
-            161 => test_snmp_service(ip).await,
-            67 => {
-                if is_gateway {
+            // DHCP servers are only probed on gateways, using the subnet's address family
+            67 | 547 => {
+                let family_matches = (port == 67) == cidr.is_ipv4();
+                if is_gateway && family_matches {
                     test_dhcp_service(ip, &cidr).await
                 } else {
                     Ok(None)
@@ -357,6 +632,189 @@ pub async fn scan_udp_ports(
     Ok(open_ports)
 }
 
+/// Record the leaf certificate presented by each of the given ports that serves HTTPS. Callers
+/// pass only ports expected to speak TLS, by type or by the connect scan's HTTPS heuristic.
+pub async fn scan_certificates(
+    ip: IpAddr,
+    cancel: CancellationToken,
+    ports: Vec<PortType>,
+    batch_size: usize,
+) -> Result<Vec<(PortType, TlsCertificateBase)>, Error> {
+    let client = reqwest::Client::builder()
+        .timeout(SCAN_TIMEOUT)
+        .danger_accept_invalid_certs(true)
+        .tls_info(true)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| anyhow!("Could not build client {}", e))?;
+
+    let tcp_ports: Vec<PortType> = ports.into_iter().filter(|p| p.is_tcp()).collect();
+    let total_ports = tcp_ports.len();
+
+    let certificates = batch_scan(tcp_ports, batch_size, cancel, move |port| {
+        let client = client.clone();
+        async move {
+            let url = format!("https://{}/", SocketAddr::new(ip, port.number()));
+
+            // Status is irrelevant, the handshake is what we're after
+            let response = match client.get(&url).send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    tracing::trace!("No TLS response from {}: {}", url, e);
+                    return None;
+                }
+            };
+
+            let der = response
+                .extensions()
+                .get::<reqwest::tls::TlsInfo>()
+                .and_then(|info| info.peer_certificate())?;
+
+            match TlsCertificateBase::from_der(der) {
+                Ok(certificate) => {
+                    tracing::debug!(
+                        "Port {} on {} presented certificate for {}",
+                        port,
+                        ip,
+                        certificate.subject
+                    );
+                    Some((port, certificate))
+                }
+                Err(e) => {
+                    tracing::debug!("Could not parse certificate from {}: {}", url, e);
+                    None
+                }
+            }
+        }
+    })
+    .await;
+
+    tracing::debug!(
+        ip = %ip,
+        ports_checked = %total_ports,
+        certificates = %certificates.len(),
+        "Certificate scan complete"
+    );
+
+    Ok(certificates)
+}
+
+/// Read the banner from each port, sending the port's probe if it doesn't greet clients on its
+/// own. Only TCP ports are tried. Connections use the scan's connect timeout and retries, and
+/// feed the subnet's scan tuning like the connect scan does; reads keep the fixed timeout, since
+/// services can take longer than a round trip to send their greeting.
+pub async fn scan_banners(
+    ip: IpAddr,
+    cancel: CancellationToken,
+    ports: Vec<PortType>,
+    batch_size: usize,
+    connect: ConnectOptions,
+    control: Option<Arc<SubnetScanControl>>,
+) -> Result<Vec<BannerResponse>, Error> {
+    let tcp_ports: Vec<PortType> = ports.into_iter().filter(|p| p.is_tcp()).collect();
+    let total_ports = tcp_ports.len();
+
+    let banners = batch_scan(tcp_ports, batch_size, cancel, move |port| {
+        let control = control.clone();
+        async move {
+            let socket = SocketAddr::new(ip, port.number());
+
+            let mut stream = connect_with_retries(socket, connect, control.as_deref()).await?;
+
+            let mut buf = vec![0u8; MAX_BANNER_LENGTH];
+
+            let mut read = match timeout(SCAN_TIMEOUT, stream.read(&mut buf)).await {
+                Ok(Ok(n)) => n,
+                _ => 0,
+            };
+
+            if read == 0
+                && let Some(probe) = BannerResponse::probe_for(&port)
+            {
+                if stream.write_all(probe).await.is_err() {
+                    return None;
+                }
+                read = match timeout(SCAN_TIMEOUT, stream.read(&mut buf)).await {
+                    Ok(Ok(n)) => n,
+                    _ => 0,
+                };
+            }
+
+            if read == 0 {
+                return None;
+            }
+
+            let response = BannerResponse::from_bytes(port, &buf[..read]);
+            tracing::debug!("{} on {}", response, ip);
+            Some(response)
+        }
+    })
+    .await;
+
+    tracing::debug!(
+        ip = %ip,
+        ports_checked = %total_ports,
+        banners = %banners.len(),
+        "Banner scan complete"
+    );
+
+    Ok(banners)
+}
+
+/// Open a connection with the scan's connect timeout, retrying timeouts up to its retry count.
+/// Answers and timeouts are recorded with the subnet's scan control.
+async fn connect_with_retries(
+    socket: SocketAddr,
+    connect: ConnectOptions,
+    control: Option<&SubnetScanControl>,
+) -> Option<TcpStream> {
+    let max_attempts = 1 + u32::from(connect.retries);
+
+    for attempt in 1..=max_attempts {
+        let start = std::time::Instant::now();
+
+        match timeout(connect.timeout, TcpStream::connect(socket)).await {
+            Ok(Ok(stream)) => {
+                if let Some(control) = control {
+                    control.record_answer(start.elapsed(), attempt);
+                }
+                return Some(stream);
+            }
+            Ok(Err(e)) => {
+                if DiscoveryCriticalError::is_critical_error(e.to_string()) {
+                    tracing::error!("Critical error connecting to {}: {}", socket, e);
+                }
+                if e.kind() == std::io::ErrorKind::ConnectionRefused
+                    && let Some(control) = control
+                {
+                    control.record_answer(start.elapsed(), attempt);
+                }
+                return None;
+            }
+            Err(_) => {
+                if let Some(control) = control {
+                    control.record_timeout();
+                }
+                if attempt < max_attempts {
+                    tracing::trace!(
+                        "{} timeout attempt {}/{} (took {:?}), retrying...",
+                        socket,
+                        attempt,
+                        max_attempts,
+                        start.elapsed()
+                    );
+                    // Small delay before retry
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                } else {
+                    tracing::trace!("{} timeout after {} attempts", socket, attempt);
+                }
+            }
+        }
+    }
+
+    None
+}
+
 pub async fn scan_endpoints(
     ip: IpAddr,
     cancel: CancellationToken,
@@ -543,33 +1001,12 @@ pub async fn test_ntp_service(ip: IpAddr) -> Result<Option<u16>, Error> {
     }
 }
 
-pub async fn test_snmp_service(ip: IpAddr) -> Result<Option<u16>, Error> {
-    let target = format!("{}:161", ip);
-    let community = b"public";
-
-    match AsyncSession::new_v2c(&target, community, 0).await {
-        Ok(mut session) => {
-            let sys_descr_oid = Oid::from(&[1, 3, 6, 1, 2, 1, 1, 1, 0])
-                .map_err(|e| anyhow!("Invalid Oid: {:?}", e))?;
-
-            match timeout(Duration::from_millis(2000), session.get(&sys_descr_oid)).await {
-                Ok(Ok(mut response)) => {
-                    if let Some(_varbind) = response.varbinds.next() {
-                        Ok(Some(161))
-                    } else {
-                        Ok(None)
-                    }
-                }
-                Ok(Err(_)) => Ok(None),
-                Err(_) => Ok(None),
-            }
-        }
-        Err(_) => Ok(None),
-    }
-}
-
-/// Test if a host is running a DHCP server on port 67
+/// Test if a host is running a DHCP server on port 67 (or a DHCPv6 server on port 547)
 pub async fn test_dhcp_service(ip: IpAddr, subnet_cidr: &IpCidr) -> Result<Option<u16>, Error> {
+    let IpCidr::V4(subnet_cidr) = subnet_cidr else {
+        return test_dhcpv6_service(ip).await;
+    };
+
     let socket = match UdpSocket::bind("0.0.0.0:68").await {
         Ok(s) => s,
         Err(_) => {
@@ -588,15 +1025,7 @@ pub async fn test_dhcp_service(ip: IpAddr, subnet_cidr: &IpCidr) -> Result<Optio
     }
 
     // Calculate broadcast address for this subnet
-    let broadcast_addr = match subnet_cidr {
-        IpCidr::V4(cidr) => {
-            let broadcast_ip = cidr.last_address();
-            SocketAddr::new(IpAddr::V4(broadcast_ip), 67)
-        }
-        IpCidr::V6(_) => {
-            return Ok(None);
-        }
-    };
+    let broadcast_addr = SocketAddr::new(IpAddr::V4(subnet_cidr.last_address()), 67);
 
     // Create a more complete DHCP DISCOVER message
     let mut rng = rand::rngs::StdRng::from_os_rng();
@@ -646,6 +1075,86 @@ pub async fn test_dhcp_service(ip: IpAddr, subnet_cidr: &IpCidr) -> Result<Optio
     Ok(None)
 }
 
+/// Test if a host is running a DHCPv6 server on port 547
+///
+/// There is no broadcast in IPv6 and replies to the All_DHCP_Relay_Agents_and_Servers
+/// group come from the server's link-local address, so this sends a unicast
+/// Information-Request and only accepts a Reply from `ip` itself.
+async fn test_dhcpv6_service(ip: IpAddr) -> Result<Option<u16>, Error> {
+    let socket = match UdpSocket::bind("[::]:546").await {
+        Ok(s) => s,
+        Err(_) => match UdpSocket::bind("[::]:0").await {
+            Ok(s) => s,
+            Err(_) => return Ok(None),
+        },
+    };
+
+    let mut rng = rand::rngs::StdRng::from_os_rng();
+    let transaction_id: [u8; 3] = rng.random();
+
+    // DUID-UUID (type 4) with a random identifier so we don't leak a real one
+    let mut client_duid = vec![0x00, 0x04];
+    client_duid.extend_from_slice(&rng.random::<[u8; 16]>());
+
+    let mut msg = v6::Message::new_with_id(v6::MessageType::InformationRequest, transaction_id);
+    msg.opts_mut().insert(v6::DhcpOption::ClientId(client_duid));
+    msg.opts_mut().insert(v6::DhcpOption::ElapsedTime(0));
+    msg.opts_mut().insert(v6::DhcpOption::ORO(v6::ORO {
+        opts: vec![
+            v6::OptionCode::DomainNameServers,
+            v6::OptionCode::DomainSearchList,
+        ],
+    }));
+
+    let mut buf = Vec::new();
+    let mut encoder = Encoder::new(&mut buf);
+    msg.encode(&mut encoder)?;
+
+    if socket
+        .send_to(&buf, SocketAddr::new(ip, 547))
+        .await
+        .is_err()
+    {
+        return Ok(None);
+    }
+
+    let mut response_buf = [0u8; 1500];
+
+    for _ in 1..=3 {
+        match timeout(
+            Duration::from_millis(2000),
+            socket.recv_from(&mut response_buf),
+        )
+        .await
+        {
+            Ok(Ok((len, from))) => {
+                if len == 0 || from.ip() != ip {
+                    continue;
+                }
+
+                let Ok(response_msg) =
+                    v6::Message::decode(&mut dhcproto::Decoder::new(&response_buf[..len]))
+                else {
+                    continue;
+                };
+
+                if response_msg.xid() == transaction_id
+                    && matches!(
+                        response_msg.msg_type(),
+                        v6::MessageType::Reply | v6::MessageType::Advertise
+                    )
+                {
+                    return Ok(Some(547));
+                }
+            }
+            Ok(Err(_)) => break,
+            Err(_) => {}
+        }
+    }
+
+    Ok(None)
+}
+
 /// Helper function to wait for and validate DHCP responses (checks multiple times)
 async fn wait_for_dhcp_responses(
     socket: &UdpSocket,
@@ -718,3 +1227,120 @@ async fn wait_for_dhcp_responses(
 
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn control(batch_size: usize) -> Arc<SubnetScanControl> {
+        AdaptiveScanControl::default().subnet(
+            "10.0.0.0/24".parse().unwrap(),
+            ScanParams {
+                batch_size,
+                connect: ConnectOptions {
+                    timeout: Duration::from_millis(800),
+                    retries: 1,
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn test_adaptive_control_backs_off_and_recovers() {
+        let control = control(64);
+
+        for _ in 0..64 {
+            control.record_timeout();
+        }
+        assert_eq!(control.params().batch_size, 32);
+
+        // Never below an eighth of the initial size
+        for _ in 0..10 {
+            for _ in 0..control.params().batch_size {
+                control.record_timeout();
+            }
+        }
+        assert_eq!(control.params().batch_size, 8);
+
+        for _ in 0..300 {
+            control.record_answer(Duration::from_millis(10), 1);
+        }
+        assert_eq!(control.params().batch_size, 64);
+        assert_eq!(control.params().connect.retries, 1);
+    }
+
+    #[test]
+    fn test_adaptive_control_follows_rtt_and_loss() {
+        let control = control(4);
+
+        // Timeouts settle just above a steady round trip time
+        for _ in 0..20 {
+            control.record_answer(Duration::from_millis(600), 1);
+        }
+        let timeout = control.params().connect.timeout;
+        assert!(timeout >= Duration::from_millis(600) && timeout < Duration::from_millis(700));
+        assert_eq!(control.params().connect.retries, 1);
+
+        // Answers to retries mean attempts are being lost
+        for _ in 0..4 {
+            control.record_answer(Duration::from_secs(4), 2);
+        }
+        assert!(control.params().connect.timeout > Duration::from_secs(2));
+        assert_eq!(control.params().connect.retries, 2);
+
+        let summary = control.summary();
+        assert_eq!(summary.attempts, 24);
+        assert_eq!(summary.timeouts, 0);
+        assert_eq!(summary.retries, 2);
+    }
+
+    #[test]
+    fn test_adaptive_control_backs_off_timeout_when_nothing_answers() {
+        let control = control(4);
+
+        // A link slower than the initial timeout: every attempt times out, so there's no round
+        // trip time to go by
+        let mut previous = control.params().connect.timeout;
+        for _ in 0..3 {
+            for _ in 0..control.params().batch_size {
+                control.record_timeout();
+            }
+            let timeout = control.params().connect.timeout;
+            assert!(
+                timeout > previous,
+                "{:?} should exceed {:?}",
+                timeout,
+                previous
+            );
+            previous = timeout;
+        }
+
+        // Capped however long nothing answers
+        for _ in 0..100 {
+            control.record_timeout();
+        }
+        assert_eq!(control.params().connect.timeout, MAX_ADAPTIVE_TIMEOUT);
+
+        // Answers that finally arrive set it from the round trip time again
+        for _ in 0..20 {
+            control.record_answer(Duration::from_millis(1500), 1);
+        }
+        let timeout = control.params().connect.timeout;
+        assert!(timeout >= Duration::from_millis(1500) && timeout < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_adaptive_control_keeps_timeout_when_filtered_ports_time_out() {
+        let control = control(8);
+
+        // A mostly filtered host: few answers, all of them fast
+        for _ in 0..20 {
+            control.record_answer(Duration::from_millis(20), 1);
+            for _ in 0..7 {
+                control.record_timeout();
+            }
+        }
+
+        assert_eq!(control.params().connect.timeout, MIN_ADAPTIVE_TIMEOUT);
+    }
+}
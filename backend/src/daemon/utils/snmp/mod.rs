@@ -0,0 +1,514 @@
+//! SNMP device queries.
+//!
+//! Reads the system group, IF-MIB interface table and ENTITY-MIB chassis details from
+//! a device using the credentials configured for the daemon's network. Credentials are
+//! tried in order and the first one the device answers to is used; with none configured,
+//! the v2c community `public` is tried. Switches also report their physical neighbors; see
+//! `neighbors`.
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use anyhow::Error;
+use async_snmp::{
+    Auth, AuthProtocol, Client, Oid, PrivProtocol, Retry, UdpHandle, UsmConfig, Value,
+};
+use chrono::Utc;
+use mac_address::MacAddress;
+
+use crate::server::hosts::r#impl::device::{DeviceInfo, DeviceInterface};
+use crate::server::physical_links::r#impl::base::PhysicalLinkBase;
+use crate::server::snmp_credentials::r#impl::base::{
+    SnmpAuthProtocol, SnmpCredentialConfig, SnmpPrivProtocol,
+};
+
+mod neighbors;
+
+pub const SNMP_PORT: u16 = 161;
+
+const SNMP_TIMEOUT: Duration = Duration::from_millis(2000);
+
+/// Rows requested per GETBULK while walking a table
+const MAX_REPETITIONS: u32 = 20;
+
+/// Upper bound on rows read from a single table column, so a misbehaving agent can't stall the scan
+const MAX_TABLE_ROWS: usize = 2048;
+
+mod oids {
+    pub const SYS_DESCR: &[u32] = &[1, 3, 6, 1, 2, 1, 1, 1, 0];
+    pub const SYS_OBJECT_ID: &[u32] = &[1, 3, 6, 1, 2, 1, 1, 2, 0];
+    pub const SYS_UPTIME: &[u32] = &[1, 3, 6, 1, 2, 1, 1, 3, 0];
+    pub const SYS_NAME: &[u32] = &[1, 3, 6, 1, 2, 1, 1, 5, 0];
+
+    pub const IF_DESCR: &[u32] = &[1, 3, 6, 1, 2, 1, 2, 2, 1, 2];
+    pub const IF_PHYS_ADDRESS: &[u32] = &[1, 3, 6, 1, 2, 1, 2, 2, 1, 6];
+    pub const IF_NAME: &[u32] = &[1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 1];
+    pub const IF_ALIAS: &[u32] = &[1, 3, 6, 1, 2, 1, 31, 1, 1, 1, 18];
+
+    pub const ENT_PHYSICAL_CLASS: &[u32] = &[1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 5];
+    pub const ENT_PHYSICAL_SERIAL_NUM: &[u32] = &[1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 11];
+    pub const ENT_PHYSICAL_MODEL_NAME: &[u32] = &[1, 3, 6, 1, 2, 1, 47, 1, 1, 1, 1, 13];
+
+    /// IP-MIB ipAdEntIfIndex, indexed by IPv4 address
+    pub const IP_AD_ENT_IF_INDEX: &[u32] = &[1, 3, 6, 1, 2, 1, 4, 20, 1, 2];
+    /// IP-MIB ipAddressIfIndex, indexed by address type, length and address
+    pub const IP_ADDRESS_IF_INDEX: &[u32] = &[1, 3, 6, 1, 2, 1, 4, 34, 1, 3];
+}
+
+/// entPhysicalClass value for a chassis
+const ENT_PHYSICAL_CLASS_CHASSIS: i64 = 3;
+
+/// What a device reported over SNMP
+#[derive(Debug, Clone)]
+pub struct SnmpDeviceReport {
+    pub device_info: DeviceInfo,
+    /// sysName, usually the device's configured hostname
+    pub system_name: Option<String>,
+    /// The device's interface that holds the queried address, if the device maps it
+    pub queried_interface: Option<DeviceInterface>,
+    /// Cables to neighboring devices, from LLDP, CDP and forwarding tables. Host and network
+    /// IDs are left for the caller to fill in.
+    pub physical_links: Vec<PhysicalLinkBase>,
+}
+
+/// Owned copy of the varbind values this module reads
+#[derive(Debug, Clone, PartialEq)]
+enum SnmpValue {
+    Integer(i64),
+    OctetString(Vec<u8>),
+    ObjectIdentifier(String),
+    Timeticks(u32),
+    Other,
+}
+
+impl From<Value> for SnmpValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Integer(n) => Self::Integer(i64::from(n)),
+            Value::OctetString(bytes) => Self::OctetString(bytes.to_vec()),
+            Value::ObjectIdentifier(oid) => Self::ObjectIdentifier(oid.to_string()),
+            Value::TimeTicks(ticks) => Self::Timeticks(ticks),
+            _ => Self::Other,
+        }
+    }
+}
+
+impl SnmpValue {
+    fn as_text(&self) -> Option<String> {
+        match self {
+            Self::OctetString(bytes) => {
+                let text = String::from_utf8_lossy(bytes)
+                    .trim_matches(|c: char| c.is_whitespace() || c == '\0')
+                    .to_string();
+                (!text.is_empty()).then_some(text)
+            }
+            _ => None,
+        }
+    }
+
+    fn as_mac(&self) -> Option<MacAddress> {
+        match self {
+            Self::OctetString(bytes) => {
+                let bytes: [u8; 6] = bytes.as_slice().try_into().ok()?;
+                (bytes != [0; 6]).then(|| MacAddress::new(bytes))
+            }
+            _ => None,
+        }
+    }
+
+    fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::OctetString(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Self::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+/// A table column read by walking, keyed by the row index that follows the column OID
+type Column = BTreeMap<Vec<u32>, SnmpValue>;
+
+struct SnmpClient {
+    client: Client<UdpHandle>,
+}
+
+impl SnmpClient {
+    async fn connect(ip: IpAddr, credential: &SnmpCredentialConfig) -> Result<Self, Error> {
+        let auth = match credential {
+            SnmpCredentialConfig::V2c { community } => Auth::v2c(community.clone()),
+            SnmpCredentialConfig::V3 {
+                username,
+                auth,
+                privacy,
+            } => {
+                let usm = UsmConfig::new(username.clone());
+                match (auth, privacy) {
+                    (Some(auth), Some(privacy)) => usm
+                        .auth_priv(
+                            auth_protocol(auth.protocol),
+                            &auth.password,
+                            priv_protocol(privacy.protocol),
+                            &privacy.password,
+                        )?
+                        .into(),
+                    (Some(auth), None) => usm
+                        .auth(auth_protocol(auth.protocol), &auth.password)?
+                        .into(),
+                    (None, _) => usm.into(),
+                }
+            }
+        };
+
+        // For v3 this also discovers the engine ID and boot counters USM needs
+        let client = Client::builder(SocketAddr::new(ip, SNMP_PORT), auth)
+            .request_timeout(SNMP_TIMEOUT)
+            .retry(Retry::none())
+            .connect()
+            .await?;
+
+        Ok(Self { client })
+    }
+
+    async fn get(&self, oids: &[&[u32]]) -> Result<Vec<(Vec<u32>, SnmpValue)>, Error> {
+        let oids: Vec<Oid> = oids.iter().map(|oid| Oid::from_slice(oid)).collect();
+
+        let response = self.client.get_many(&oids).await?;
+
+        Ok(response
+            .varbinds
+            .into_iter()
+            .map(|varbind| (varbind.oid.arcs().to_vec(), varbind.value.into()))
+            .collect())
+    }
+
+    async fn walk(&self, column: &[u32]) -> Result<Column, Error> {
+        let mut rows = Column::new();
+        let mut stream = self
+            .client
+            .bulk_walk(Oid::from_slice(column), MAX_REPETITIONS)?;
+
+        while rows.len() < MAX_TABLE_ROWS {
+            let Some(varbind) = stream.next().await else {
+                break;
+            };
+            let varbind = varbind?;
+            let Some(row) = varbind.oid.arcs().strip_prefix(column) else {
+                break;
+            };
+            rows.insert(row.to_vec(), varbind.value.into());
+        }
+
+        Ok(rows)
+    }
+
+    /// Walk a column, treating a failure as an empty table since most devices only implement
+    /// some of the MIBs queried
+    async fn walk_or_empty(&self, column: &[u32]) -> Column {
+        self.walk(column).await.unwrap_or_else(|e| {
+            tracing::trace!(error = %e, "SNMP table walk failed");
+            Column::new()
+        })
+    }
+}
+
+fn auth_protocol(protocol: SnmpAuthProtocol) -> AuthProtocol {
+    match protocol {
+        SnmpAuthProtocol::Md5 => AuthProtocol::Md5,
+        SnmpAuthProtocol::Sha1 => AuthProtocol::Sha1,
+        SnmpAuthProtocol::Sha224 => AuthProtocol::Sha224,
+        SnmpAuthProtocol::Sha256 => AuthProtocol::Sha256,
+        SnmpAuthProtocol::Sha384 => AuthProtocol::Sha384,
+        SnmpAuthProtocol::Sha512 => AuthProtocol::Sha512,
+    }
+}
+
+/// AES-192/256 use the Blumenthal key extension, which is what net-snmp means by AES-192/AES-256
+fn priv_protocol(protocol: SnmpPrivProtocol) -> PrivProtocol {
+    match protocol {
+        SnmpPrivProtocol::Des => PrivProtocol::Des,
+        SnmpPrivProtocol::Aes128 => PrivProtocol::Aes128,
+        SnmpPrivProtocol::Aes192 => PrivProtocol::Aes192Blumenthal,
+        SnmpPrivProtocol::Aes256 => PrivProtocol::Aes256Blumenthal,
+    }
+}
+
+/// Query a device's system group, interfaces and chassis details. Returns None if the device
+/// doesn't answer to any of the credentials.
+pub async fn query_device(
+    ip: IpAddr,
+    credentials: &[SnmpCredentialConfig],
+) -> Option<SnmpDeviceReport> {
+    let default_credentials = [SnmpCredentialConfig::default()];
+    let credentials = if credentials.is_empty() {
+        &default_credentials[..]
+    } else {
+        credentials
+    };
+
+    for credential in credentials {
+        match query_with_credential(ip, credential).await {
+            Ok(Some(report)) => return Some(report),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::trace!(ip = %ip, error = %e, "SNMP query failed");
+            }
+        }
+    }
+
+    None
+}
+
+async fn query_with_credential(
+    ip: IpAddr,
+    credential: &SnmpCredentialConfig,
+) -> Result<Option<SnmpDeviceReport>, Error> {
+    let client = SnmpClient::connect(ip, credential).await?;
+
+    let system = client
+        .get(&[
+            oids::SYS_DESCR,
+            oids::SYS_OBJECT_ID,
+            oids::SYS_UPTIME,
+            oids::SYS_NAME,
+        ])
+        .await?;
+
+    let value_of = |oid: &[u32]| {
+        system
+            .iter()
+            .find(|(o, _)| o.as_slice() == oid)
+            .map(|(_, v)| v.clone())
+            .unwrap_or(SnmpValue::Other)
+    };
+
+    let system_description = value_of(oids::SYS_DESCR).as_text();
+    let system_object_id = match value_of(oids::SYS_OBJECT_ID) {
+        SnmpValue::ObjectIdentifier(oid) => Some(oid),
+        _ => None,
+    };
+    // sysUpTime is in hundredths of a second
+    let uptime_seconds = match value_of(oids::SYS_UPTIME) {
+        SnmpValue::Timeticks(ticks) => Some(u64::from(ticks) / 100),
+        _ => None,
+    };
+    let system_name = value_of(oids::SYS_NAME).as_text();
+
+    if system_description.is_none() && system_object_id.is_none() && system_name.is_none() {
+        return Ok(None);
+    }
+
+    let interfaces = build_interfaces(
+        &client.walk_or_empty(oids::IF_DESCR).await,
+        &client.walk_or_empty(oids::IF_NAME).await,
+        &client.walk_or_empty(oids::IF_ALIAS).await,
+        &client.walk_or_empty(oids::IF_PHYS_ADDRESS).await,
+    );
+
+    let (model, serial_number) = chassis_details(
+        &client.walk_or_empty(oids::ENT_PHYSICAL_CLASS).await,
+        &client.walk_or_empty(oids::ENT_PHYSICAL_MODEL_NAME).await,
+        &client.walk_or_empty(oids::ENT_PHYSICAL_SERIAL_NUM).await,
+    );
+
+    let queried_if_index = match client.get(&[&address_if_index_oid(ip)]).await {
+        Ok(rows) => rows.first().and_then(|(_, v)| v.as_integer()),
+        Err(_) => None,
+    };
+    let queried_if_index = match (queried_if_index, ip) {
+        (Some(index), _) => Some(index),
+        // Older agents only implement the IPv4-only ipAddrTable
+        (None, IpAddr::V4(ipv4)) => {
+            let oid: Vec<u32> = oids::IP_AD_ENT_IF_INDEX
+                .iter()
+                .copied()
+                .chain(ipv4.octets().iter().map(|o| u32::from(*o)))
+                .collect();
+            client
+                .get(&[&oid])
+                .await
+                .ok()
+                .and_then(|rows| rows.first().and_then(|(_, v)| v.as_integer()))
+        }
+        (None, IpAddr::V6(_)) => None,
+    };
+
+    let queried_interface = queried_if_index.and_then(|index| {
+        interfaces
+            .iter()
+            .find(|i| i64::from(i.index) == index)
+            .cloned()
+    });
+
+    let physical_links = neighbors::query_physical_links(&client, &interfaces).await;
+
+    Ok(Some(SnmpDeviceReport {
+        device_info: DeviceInfo {
+            system_description,
+            system_object_id,
+            manufacturer: None,
+            model,
+            serial_number,
+            friendly_name: None,
+            uptime_seconds,
+            interfaces,
+            collected_at: Utc::now(),
+        },
+        system_name,
+        queried_interface,
+        physical_links,
+    }))
+}
+
+/// ipAddressIfIndex instance for an address: address type, length, then the address bytes
+fn address_if_index_oid(ip: IpAddr) -> Vec<u32> {
+    let (address_type, octets) = match ip {
+        IpAddr::V4(ipv4) => (1, ipv4.octets().to_vec()),
+        IpAddr::V6(ipv6) => (2, ipv6.octets().to_vec()),
+    };
+
+    oids::IP_ADDRESS_IF_INDEX
+        .iter()
+        .copied()
+        .chain([address_type, octets.len() as u32])
+        .chain(octets.into_iter().map(u32::from))
+        .collect()
+}
+
+/// Join the IF-MIB columns on ifIndex
+fn build_interfaces(
+    descriptions: &Column,
+    names: &Column,
+    aliases: &Column,
+    macs: &Column,
+) -> Vec<DeviceInterface> {
+    let index_of = |row: &Vec<u32>| match row.as_slice() {
+        [index] => Some(*index),
+        _ => None,
+    };
+
+    let indexes: std::collections::BTreeSet<u32> = descriptions
+        .keys()
+        .chain(names.keys())
+        .filter_map(index_of)
+        .collect();
+
+    indexes
+        .into_iter()
+        .map(|index| {
+            let row = vec![index];
+            DeviceInterface {
+                index,
+                name: names
+                    .get(&row)
+                    .and_then(SnmpValue::as_text)
+                    .or_else(|| descriptions.get(&row).and_then(SnmpValue::as_text)),
+                alias: aliases.get(&row).and_then(SnmpValue::as_text),
+                mac_address: macs.get(&row).and_then(SnmpValue::as_mac),
+            }
+        })
+        .collect()
+}
+
+/// Model and serial of the chassis. Devices without a chassis entry (or with blank ones) use the
+/// first physical entity that has the value set.
+fn chassis_details(
+    classes: &Column,
+    models: &Column,
+    serials: &Column,
+) -> (Option<String>, Option<String>) {
+    let chassis: Vec<&Vec<u32>> = classes
+        .iter()
+        .filter(|(_, class)| class.as_integer() == Some(ENT_PHYSICAL_CLASS_CHASSIS))
+        .map(|(row, _)| row)
+        .collect();
+
+    let pick = |column: &Column| {
+        chassis
+            .iter()
+            .find_map(|row| column.get(*row).and_then(SnmpValue::as_text))
+            .or_else(|| column.values().find_map(SnmpValue::as_text))
+    };
+
+    (pick(models), pick(serials))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(rows: &[(u32, SnmpValue)]) -> Column {
+        rows.iter()
+            .map(|(index, value)| (vec![*index], value.clone()))
+            .collect()
+    }
+
+    fn text(s: &str) -> SnmpValue {
+        SnmpValue::OctetString(s.as_bytes().to_vec())
+    }
+
+    #[test]
+    fn test_build_interfaces() {
+        let descriptions = column(&[
+            (1, text("GigabitEthernet0/1")),
+            (2, text("GigabitEthernet0/2")),
+        ]);
+        let names = column(&[(1, text("Gi0/1"))]);
+        let aliases = column(&[(2, text("Uplink to core\0"))]);
+        let macs = column(&[
+            (
+                1,
+                SnmpValue::OctetString(vec![0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f]),
+            ),
+            (2, SnmpValue::OctetString(vec![0; 6])),
+        ]);
+
+        let interfaces = build_interfaces(&descriptions, &names, &aliases, &macs);
+
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces[0].name.as_deref(), Some("Gi0/1"));
+        assert_eq!(
+            interfaces[0].mac_address,
+            Some(MacAddress::new([0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f]))
+        );
+        assert_eq!(interfaces[1].name.as_deref(), Some("GigabitEthernet0/2"));
+        assert_eq!(interfaces[1].alias.as_deref(), Some("Uplink to core"));
+        assert_eq!(interfaces[1].mac_address, None);
+    }
+
+    #[test]
+    fn test_chassis_details() {
+        // Stacked switch: a blank stack entity, then the chassis, then a power supply
+        let classes = column(&[
+            (1, SnmpValue::Integer(11)),
+            (1001, SnmpValue::Integer(ENT_PHYSICAL_CLASS_CHASSIS)),
+            (1002, SnmpValue::Integer(6)),
+        ]);
+        let models = column(&[
+            (1, text("")),
+            (1001, text("WS-C2960X-48FPD-L")),
+            (1002, text("PWR-C2-640WAC")),
+        ]);
+        let serials = column(&[(1002, text("LIT1234ABCD"))]);
+
+        let (model, serial) = chassis_details(&classes, &models, &serials);
+
+        assert_eq!(model.as_deref(), Some("WS-C2960X-48FPD-L"));
+        assert_eq!(serial.as_deref(), Some("LIT1234ABCD"));
+    }
+
+    #[test]
+    fn test_address_if_index_oid() {
+        let oid = address_if_index_oid("192.168.1.20".parse().unwrap());
+        assert_eq!(
+            &oid[oids::IP_ADDRESS_IF_INDEX.len()..],
+            &[1, 4, 192, 168, 1, 20]
+        );
+    }
+}
@@ -0,0 +1,494 @@
+//! Physical links from a managed device's neighbor and forwarding tables.
+//!
+//! LLDP and CDP neighbors name the device and port at the other end of a cable. Forwarding
+//! tables only say which MACs were learned on which port, so they're used for access ports:
+//! ports without a neighbor that learned at most a couple of MACs.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use mac_address::MacAddress;
+
+use super::{Column, SnmpClient, SnmpValue};
+use crate::server::hosts::r#impl::device::DeviceInterface;
+use crate::server::physical_links::r#impl::base::{PhysicalLinkBase, PhysicalLinkProtocol};
+
+mod oids {
+    /// LLDP-MIB lldpLocPortTable, indexed by lldpLocPortNum
+    pub const LLDP_LOC_PORT_ID: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 3, 7, 1, 3];
+    pub const LLDP_LOC_PORT_DESC: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 3, 7, 1, 4];
+
+    /// LLDP-MIB lldpRemTable, indexed by time mark, local port number and neighbor index
+    pub const LLDP_REM_CHASSIS_ID_SUBTYPE: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 4];
+    pub const LLDP_REM_CHASSIS_ID: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 5];
+    pub const LLDP_REM_PORT_ID_SUBTYPE: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 6];
+    pub const LLDP_REM_PORT_ID: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 7];
+    pub const LLDP_REM_PORT_DESC: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 8];
+    pub const LLDP_REM_SYS_NAME: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 1, 1, 9];
+
+    /// LLDP-MIB lldpRemManAddrTable, indexed by the lldpRemTable index followed by the address
+    /// family and length-prefixed address
+    pub const LLDP_REM_MAN_ADDR_IF_SUBTYPE: &[u32] = &[1, 0, 8802, 1, 1, 2, 1, 4, 2, 1, 3];
+
+    /// CISCO-CDP-MIB cdpCacheTable, indexed by ifIndex and neighbor index
+    pub const CDP_CACHE_ADDRESS_TYPE: &[u32] = &[1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 3];
+    pub const CDP_CACHE_ADDRESS: &[u32] = &[1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 4];
+    pub const CDP_CACHE_DEVICE_ID: &[u32] = &[1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 6];
+    pub const CDP_CACHE_DEVICE_PORT: &[u32] = &[1, 3, 6, 1, 4, 1, 9, 9, 23, 1, 2, 1, 1, 7];
+
+    /// BRIDGE-MIB dot1dBasePortIfIndex, mapping bridge ports to ifIndex
+    pub const DOT1D_BASE_PORT_IF_INDEX: &[u32] = &[1, 3, 6, 1, 2, 1, 17, 1, 4, 1, 2];
+    /// BRIDGE-MIB dot1dTpFdbTable, indexed by MAC
+    pub const DOT1D_TP_FDB_PORT: &[u32] = &[1, 3, 6, 1, 2, 1, 17, 4, 3, 1, 2];
+    pub const DOT1D_TP_FDB_STATUS: &[u32] = &[1, 3, 6, 1, 2, 1, 17, 4, 3, 1, 3];
+    /// Q-BRIDGE-MIB dot1qTpFdbTable, indexed by filtering database (usually the VLAN) and MAC
+    pub const DOT1Q_TP_FDB_PORT: &[u32] = &[1, 3, 6, 1, 2, 1, 17, 7, 1, 2, 2, 1, 2];
+    pub const DOT1Q_TP_FDB_STATUS: &[u32] = &[1, 3, 6, 1, 2, 1, 17, 7, 1, 2, 2, 1, 3];
+}
+
+/// lldpRemChassisIdSubtype / lldpRemPortIdSubtype values
+const LLDP_CHASSIS_ID_MAC_ADDRESS: i64 = 4;
+const LLDP_PORT_ID_INTERFACE_ALIAS: i64 = 1;
+const LLDP_PORT_ID_MAC_ADDRESS: i64 = 3;
+const LLDP_PORT_ID_INTERFACE_NAME: i64 = 5;
+const LLDP_PORT_ID_LOCAL: i64 = 7;
+
+/// lldpRemManAddrSubtype / IANA address family numbers
+const ADDRESS_FAMILY_IPV4: u32 = 1;
+const ADDRESS_FAMILY_IPV6: u32 = 2;
+
+/// cdpCacheAddressType for IPv4
+const CDP_ADDRESS_TYPE_IP: i64 = 1;
+
+/// dot1dTpFdbStatus / dot1qTpFdbStatus for an entry the bridge learned from traffic
+const FDB_STATUS_LEARNED: i64 = 3;
+
+/// Ports that learned more MACs than this lead to another switch (or a hypervisor) rather than a
+/// single device. Two allows for a computer daisy-chained behind an IP phone.
+const MAX_ACCESS_PORT_MACS: usize = 2;
+
+/// Read the device's neighbor and forwarding tables. `interfaces` is the device's IF-MIB table,
+/// used to name local ports.
+pub(super) async fn query_physical_links(
+    client: &SnmpClient,
+    interfaces: &[DeviceInterface],
+) -> Vec<PhysicalLinkBase> {
+    let lldp = LldpTables {
+        local_port_ids: client.walk_or_empty(oids::LLDP_LOC_PORT_ID).await,
+        local_port_descriptions: client.walk_or_empty(oids::LLDP_LOC_PORT_DESC).await,
+        chassis_id_subtypes: client
+            .walk_or_empty(oids::LLDP_REM_CHASSIS_ID_SUBTYPE)
+            .await,
+        chassis_ids: client.walk_or_empty(oids::LLDP_REM_CHASSIS_ID).await,
+        port_id_subtypes: client.walk_or_empty(oids::LLDP_REM_PORT_ID_SUBTYPE).await,
+        port_ids: client.walk_or_empty(oids::LLDP_REM_PORT_ID).await,
+        port_descriptions: client.walk_or_empty(oids::LLDP_REM_PORT_DESC).await,
+        system_names: client.walk_or_empty(oids::LLDP_REM_SYS_NAME).await,
+        management_addresses: client
+            .walk_or_empty(oids::LLDP_REM_MAN_ADDR_IF_SUBTYPE)
+            .await,
+    };
+
+    let cdp = CdpTables {
+        address_types: client.walk_or_empty(oids::CDP_CACHE_ADDRESS_TYPE).await,
+        addresses: client.walk_or_empty(oids::CDP_CACHE_ADDRESS).await,
+        device_ids: client.walk_or_empty(oids::CDP_CACHE_DEVICE_ID).await,
+        device_ports: client.walk_or_empty(oids::CDP_CACHE_DEVICE_PORT).await,
+    };
+
+    let mut links = lldp_links(&lldp, interfaces);
+    links.extend(cdp_links(&cdp, interfaces));
+
+    // Prefer Q-BRIDGE-MIB, which covers every VLAN; BRIDGE-MIB often only shows the default one
+    let mut fdb = ForwardingTables {
+        base_port_if_indexes: client.walk_or_empty(oids::DOT1D_BASE_PORT_IF_INDEX).await,
+        ports: client.walk_or_empty(oids::DOT1Q_TP_FDB_PORT).await,
+        statuses: client.walk_or_empty(oids::DOT1Q_TP_FDB_STATUS).await,
+    };
+    if fdb.ports.is_empty() {
+        fdb.ports = client.walk_or_empty(oids::DOT1D_TP_FDB_PORT).await;
+        fdb.statuses = client.walk_or_empty(oids::DOT1D_TP_FDB_STATUS).await;
+    }
+
+    let neighbor_if_indexes: HashSet<u32> = links.iter().filter_map(|l| l.local_if_index).collect();
+    links.extend(forwarding_table_links(
+        &fdb,
+        interfaces,
+        &neighbor_if_indexes,
+    ));
+
+    links
+}
+
+struct LldpTables {
+    local_port_ids: Column,
+    local_port_descriptions: Column,
+    chassis_id_subtypes: Column,
+    chassis_ids: Column,
+    port_id_subtypes: Column,
+    port_ids: Column,
+    port_descriptions: Column,
+    system_names: Column,
+    management_addresses: Column,
+}
+
+struct CdpTables {
+    address_types: Column,
+    addresses: Column,
+    device_ids: Column,
+    device_ports: Column,
+}
+
+struct ForwardingTables {
+    base_port_if_indexes: Column,
+    ports: Column,
+    statuses: Column,
+}
+
+fn interface_name(interfaces: &[DeviceInterface], if_index: u32) -> Option<String> {
+    interfaces
+        .iter()
+        .find(|i| i.index == if_index)
+        .and_then(|i| i.name.clone())
+}
+
+fn lldp_links(tables: &LldpTables, interfaces: &[DeviceInterface]) -> Vec<PhysicalLinkBase> {
+    // lldpRemTable rows are [time mark, local port number, neighbor index]
+    let rows: BTreeSet<&Vec<u32>> = tables
+        .chassis_ids
+        .keys()
+        .chain(tables.system_names.keys())
+        .filter(|row| row.len() == 3)
+        .collect();
+
+    rows.into_iter()
+        .map(|row| {
+            let local_port_number = row[1];
+            let local_row = vec![local_port_number];
+
+            // Local port numbers are usually ifIndexes, but the MIB doesn't require it
+            let (local_if_index, local_port) = match interface_name(interfaces, local_port_number) {
+                Some(name) => (Some(local_port_number), Some(name)),
+                None => (
+                    None,
+                    tables
+                        .local_port_descriptions
+                        .get(&local_row)
+                        .and_then(SnmpValue::as_text)
+                        .or_else(|| {
+                            tables
+                                .local_port_ids
+                                .get(&local_row)
+                                .and_then(SnmpValue::as_text)
+                        }),
+                ),
+            };
+
+            let port_id_subtype = tables
+                .port_id_subtypes
+                .get(row)
+                .and_then(SnmpValue::as_integer);
+            let port_id = tables.port_ids.get(row);
+
+            // End hosts usually identify the port by the NIC's MAC, which is what their
+            // interfaces are matched by; switches use the chassis MAC
+            let remote_mac_address = match port_id_subtype {
+                Some(LLDP_PORT_ID_MAC_ADDRESS) => port_id.and_then(SnmpValue::as_mac),
+                _ => None,
+            }
+            .or_else(|| {
+                match tables
+                    .chassis_id_subtypes
+                    .get(row)
+                    .and_then(SnmpValue::as_integer)
+                {
+                    Some(LLDP_CHASSIS_ID_MAC_ADDRESS) => {
+                        tables.chassis_ids.get(row).and_then(SnmpValue::as_mac)
+                    }
+                    _ => None,
+                }
+            });
+
+            let named_port_id = match port_id_subtype {
+                Some(
+                    LLDP_PORT_ID_INTERFACE_NAME | LLDP_PORT_ID_INTERFACE_ALIAS | LLDP_PORT_ID_LOCAL,
+                ) => port_id.and_then(SnmpValue::as_text),
+                _ => None,
+            };
+            let remote_port = named_port_id.or_else(|| {
+                tables
+                    .port_descriptions
+                    .get(row)
+                    .and_then(SnmpValue::as_text)
+            });
+
+            let remote_ip_address = tables
+                .management_addresses
+                .keys()
+                .filter(|address_row| address_row.starts_with(row))
+                .find_map(|address_row| management_address(&address_row[row.len()..]));
+
+            PhysicalLinkBase {
+                protocol: PhysicalLinkProtocol::Lldp,
+                local_if_index,
+                local_port,
+                remote_mac_address,
+                remote_ip_address,
+                remote_system_name: tables.system_names.get(row).and_then(SnmpValue::as_text),
+                remote_port,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+/// Address from the tail of an lldpRemManAddrTable index: family, length, then the address
+fn management_address(index: &[u32]) -> Option<IpAddr> {
+    let [family, length, address @ ..] = index else {
+        return None;
+    };
+    if address.len() != *length as usize {
+        return None;
+    }
+    let octets: Vec<u8> = address
+        .iter()
+        .map(|o| u8::try_from(*o).ok())
+        .collect::<Option<_>>()?;
+
+    match *family {
+        ADDRESS_FAMILY_IPV4 => {
+            let octets: [u8; 4] = octets.try_into().ok()?;
+            Some(IpAddr::V4(Ipv4Addr::from(octets)))
+        }
+        ADDRESS_FAMILY_IPV6 => {
+            let octets: [u8; 16] = octets.try_into().ok()?;
+            Some(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => None,
+    }
+}
+
+fn cdp_links(tables: &CdpTables, interfaces: &[DeviceInterface]) -> Vec<PhysicalLinkBase> {
+    // cdpCacheTable rows are [ifIndex, neighbor index]
+    tables
+        .device_ids
+        .iter()
+        .filter(|(row, _)| row.len() == 2)
+        .map(|(row, device_id)| {
+            let if_index = row[0];
+
+            let remote_ip_address = match tables
+                .address_types
+                .get(row)
+                .and_then(SnmpValue::as_integer)
+            {
+                Some(CDP_ADDRESS_TYPE_IP) => tables
+                    .addresses
+                    .get(row)
+                    .and_then(SnmpValue::as_bytes)
+                    .and_then(|bytes| <[u8; 4]>::try_from(bytes).ok())
+                    .map(|octets| IpAddr::V4(Ipv4Addr::from(octets))),
+                _ => None,
+            };
+
+            // NX-OS appends the serial number to the device ID, ie `core-1(FOC1234X0AB)`
+            let remote_system_name = device_id.as_text().and_then(|id| {
+                let name = id.split('(').next().unwrap_or_default().trim();
+                (!name.is_empty()).then(|| name.to_string())
+            });
+
+            PhysicalLinkBase {
+                protocol: PhysicalLinkProtocol::Cdp,
+                local_if_index: Some(if_index),
+                local_port: interface_name(interfaces, if_index),
+                remote_mac_address: None,
+                remote_ip_address,
+                remote_system_name,
+                remote_port: tables.device_ports.get(row).and_then(SnmpValue::as_text),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn forwarding_table_links(
+    tables: &ForwardingTables,
+    interfaces: &[DeviceInterface],
+    neighbor_if_indexes: &HashSet<u32>,
+) -> Vec<PhysicalLinkBase> {
+    let own_macs: HashSet<MacAddress> = interfaces.iter().filter_map(|i| i.mac_address).collect();
+
+    // Rows end with the six MAC octets, after the VLAN for Q-BRIDGE-MIB
+    let mut macs_by_if_index: BTreeMap<u32, BTreeSet<[u8; 6]>> = BTreeMap::new();
+    for (row, port) in &tables.ports {
+        let Some(mac_arcs) = row.len().checked_sub(6).map(|start| &row[start..]) else {
+            continue;
+        };
+        let Some(mac) = mac_arcs
+            .iter()
+            .map(|o| u8::try_from(*o).ok())
+            .collect::<Option<Vec<u8>>>()
+            .and_then(|octets| <[u8; 6]>::try_from(octets).ok())
+        else {
+            continue;
+        };
+
+        let status = tables.statuses.get(row).and_then(SnmpValue::as_integer);
+        if status.is_some_and(|s| s != FDB_STATUS_LEARNED)
+            || own_macs.contains(&MacAddress::new(mac))
+        {
+            continue;
+        }
+
+        let Some(if_index) = port
+            .as_integer()
+            .and_then(|base_port| u32::try_from(base_port).ok())
+            .and_then(|base_port| tables.base_port_if_indexes.get(&vec![base_port]))
+            .and_then(SnmpValue::as_integer)
+            .and_then(|if_index| u32::try_from(if_index).ok())
+        else {
+            continue;
+        };
+
+        macs_by_if_index.entry(if_index).or_default().insert(mac);
+    }
+
+    macs_by_if_index
+        .into_iter()
+        .filter(|(if_index, macs)| {
+            !neighbor_if_indexes.contains(if_index) && macs.len() <= MAX_ACCESS_PORT_MACS
+        })
+        .flat_map(|(if_index, macs)| {
+            let local_port = interface_name(interfaces, if_index);
+            macs.into_iter().map(move |mac| PhysicalLinkBase {
+                protocol: PhysicalLinkProtocol::ForwardingTable,
+                local_if_index: Some(if_index),
+                local_port: local_port.clone(),
+                remote_mac_address: Some(MacAddress::new(mac)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> SnmpValue {
+        SnmpValue::OctetString(s.as_bytes().to_vec())
+    }
+
+    fn interface(index: u32, name: &str) -> DeviceInterface {
+        DeviceInterface {
+            index,
+            name: Some(name.to_string()),
+            alias: None,
+            mac_address: None,
+        }
+    }
+
+    #[test]
+    fn test_lldp_links() {
+        let row = vec![0, 12, 1];
+        let tables = LldpTables {
+            local_port_ids: Column::new(),
+            local_port_descriptions: Column::new(),
+            chassis_id_subtypes: Column::from([(
+                row.clone(),
+                SnmpValue::Integer(LLDP_CHASSIS_ID_MAC_ADDRESS),
+            )]),
+            chassis_ids: Column::from([(
+                row.clone(),
+                SnmpValue::OctetString(vec![0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+            )]),
+            port_id_subtypes: Column::from([(
+                row.clone(),
+                SnmpValue::Integer(LLDP_PORT_ID_INTERFACE_NAME),
+            )]),
+            port_ids: Column::from([(row.clone(), text("Gi0/24"))]),
+            port_descriptions: Column::from([(row.clone(), text("Uplink"))]),
+            system_names: Column::from([(row.clone(), text("access-2.example.com"))]),
+            management_addresses: Column::from([(
+                vec![0, 12, 1, ADDRESS_FAMILY_IPV4, 4, 192, 168, 1, 2],
+                SnmpValue::Integer(2),
+            )]),
+        };
+
+        let links = lldp_links(&tables, &[interface(12, "Gi1/0/12")]);
+
+        assert_eq!(links.len(), 1);
+        let link = &links[0];
+        assert_eq!(link.local_if_index, Some(12));
+        assert_eq!(link.local_port.as_deref(), Some("Gi1/0/12"));
+        assert_eq!(
+            link.remote_mac_address,
+            Some(MacAddress::new([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]))
+        );
+        assert_eq!(link.remote_ip_address, Some("192.168.1.2".parse().unwrap()));
+        assert_eq!(
+            link.remote_system_name.as_deref(),
+            Some("access-2.example.com")
+        );
+        assert_eq!(link.remote_port.as_deref(), Some("Gi0/24"));
+    }
+
+    #[test]
+    fn test_cdp_links() {
+        let row = vec![3, 7];
+        let tables = CdpTables {
+            address_types: Column::from([(row.clone(), SnmpValue::Integer(CDP_ADDRESS_TYPE_IP))]),
+            addresses: Column::from([(row.clone(), SnmpValue::OctetString(vec![10, 0, 0, 1]))]),
+            device_ids: Column::from([(row.clone(), text("core-1(FOC1234X0AB)"))]),
+            device_ports: Column::from([(row.clone(), text("Ethernet1/1"))]),
+        };
+
+        let links = cdp_links(&tables, &[interface(3, "Gi0/3")]);
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].local_port.as_deref(), Some("Gi0/3"));
+        assert_eq!(links[0].remote_system_name.as_deref(), Some("core-1"));
+        assert_eq!(
+            links[0].remote_ip_address,
+            Some("10.0.0.1".parse().unwrap())
+        );
+        assert_eq!(links[0].remote_port.as_deref(), Some("Ethernet1/1"));
+    }
+
+    #[test]
+    fn test_forwarding_table_links_skip_uplinks() {
+        let fdb_row =
+            |vlan: u32, last_octet: u32| vec![vlan, 0x00, 0x11, 0x22, 0x33, 0x44, last_octet];
+        let tables = ForwardingTables {
+            base_port_if_indexes: Column::from([
+                (vec![1], SnmpValue::Integer(101)),
+                (vec![2], SnmpValue::Integer(102)),
+                (vec![3], SnmpValue::Integer(103)),
+            ]),
+            ports: Column::from([
+                // Access port with a single device, seen on two VLANs
+                (fdb_row(1, 0x01), SnmpValue::Integer(1)),
+                (fdb_row(20, 0x01), SnmpValue::Integer(1)),
+                // Uplink with several devices behind it
+                (fdb_row(1, 0x02), SnmpValue::Integer(2)),
+                (fdb_row(1, 0x03), SnmpValue::Integer(2)),
+                (fdb_row(1, 0x04), SnmpValue::Integer(2)),
+                // Port with an LLDP neighbor
+                (fdb_row(1, 0x05), SnmpValue::Integer(3)),
+            ]),
+            statuses: Column::new(),
+        };
+        let neighbor_if_indexes = HashSet::from([103]);
+
+        let links =
+            forwarding_table_links(&tables, &[interface(101, "Gi0/1")], &neighbor_if_indexes);
+
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].local_if_index, Some(101));
+        assert_eq!(links[0].local_port.as_deref(), Some("Gi0/1"));
+        assert_eq!(
+            links[0].remote_mac_address,
+            Some(MacAddress::new([0x00, 0x11, 0x22, 0x33, 0x44, 0x01]))
+        );
+    }
+}
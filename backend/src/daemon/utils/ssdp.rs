@@ -0,0 +1,383 @@
+//! SSDP / UPnP discovery.
+//!
+//! Sends M-SEARCH requests to the SSDP multicast group out of the daemon's own IPv4 addresses on
+//! interfaced subnets, collects the `LOCATION` each host answers with, and fetches the UPnP
+//! device description found there. Like mDNS, multicast doesn't cross routers, so only subnets
+//! the daemon has an interface on are covered. Descriptions are only fetched from responders the
+//! session's exclusions leave open to probing.
+
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Error, anyhow};
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared, join_all};
+use futures::stream::{self, StreamExt};
+use tokio::net::UdpSocket;
+use tokio::time::{Instant, timeout_at};
+use tokio_util::sync::CancellationToken;
+
+use crate::server::scan_exclusions::r#impl::base::ScanExclusions;
+use crate::server::services::r#impl::upnp::UpnpDevice;
+
+pub const SSDP_PORT: u16 = 1900;
+
+const SSDP_MULTICAST_ADDR: Ipv4Addr = Ipv4Addr::new(239, 255, 255, 250);
+
+/// Devices answer after a random delay of up to MX seconds
+const SEARCH_MX: u64 = 3;
+
+/// How long to collect answers for, allowing for the MX delay and a repeated search
+pub const SEARCH_DURATION: Duration = Duration::from_secs(SEARCH_MX + 2);
+
+/// Search targets: everything, and root devices for hosts that only answer that
+const SEARCH_TARGETS: [&str; 2] = ["ssdp:all", "upnp:rootdevice"];
+
+const DESCRIPTION_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Descriptions are a few KB; anything larger isn't one
+const MAX_DESCRIPTION_LENGTH: usize = 64 * 1024;
+
+const CONCURRENT_DESCRIPTION_FETCHES: usize = 16;
+
+/// Answer to an M-SEARCH
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SsdpResponse {
+    pub location: String,
+    pub server: Option<String>,
+}
+
+impl SsdpResponse {
+    /// Parse an HTTP-over-UDP answer. Returns None for anything else, including the search
+    /// requests of other clients.
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let text = std::str::from_utf8(bytes).ok()?;
+        let mut lines = text.lines();
+
+        let status = lines.next()?;
+        if !status.starts_with("HTTP/1.1 200") {
+            return None;
+        }
+
+        let mut location = None;
+        let mut server = None;
+        for line in lines {
+            let Some((name, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match name.trim().to_ascii_uppercase().as_str() {
+                "LOCATION" if !value.is_empty() => location = Some(value.to_string()),
+                "SERVER" if !value.is_empty() => server = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            location: location?,
+            server,
+        })
+    }
+}
+
+/// Results of a search that may still be running, keyed by the address that answered. Clones
+/// await the same search.
+pub type SsdpSearch = Shared<BoxFuture<'static, Arc<HashMap<IpAddr, Vec<UpnpDevice>>>>>;
+
+/// Search in the background from the given local addresses. Failures are logged and leave the
+/// results empty, since every host is still identified by its ports.
+pub fn spawn_search(
+    source_ips: Vec<Ipv4Addr>,
+    exclusions: ScanExclusions,
+    cancel: CancellationToken,
+) -> SsdpSearch {
+    if source_ips.is_empty() {
+        return futures::future::ready(Arc::new(HashMap::new()))
+            .boxed()
+            .shared();
+    }
+
+    // Started now rather than when first awaited, so it runs alongside neighbor discovery
+    let task = tokio::spawn(search(source_ips, exclusions, cancel));
+
+    async move {
+        match task.await {
+            Ok(devices) => {
+                tracing::info!(hosts = devices.len(), "SSDP search complete");
+                Arc::new(devices)
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "SSDP search task failed");
+                Arc::new(HashMap::new())
+            }
+        }
+    }
+    .boxed()
+    .shared()
+}
+
+/// Search from each source address, then fetch the description each answer points to
+pub async fn search(
+    source_ips: Vec<Ipv4Addr>,
+    exclusions: ScanExclusions,
+    cancel: CancellationToken,
+) -> HashMap<IpAddr, Vec<UpnpDevice>> {
+    let answers: HashSet<(IpAddr, SsdpResponse)> =
+        join_all(source_ips.iter().map(|ip| search_from(*ip, &cancel)))
+            .await
+            .into_iter()
+            .filter_map(|result| match result {
+                Ok(answers) => Some(answers),
+                Err(e) => {
+                    tracing::debug!(error = %e, "SSDP search failed");
+                    None
+                }
+            })
+            .flatten()
+            .collect();
+
+    describe(answers, &exclusions).await
+}
+
+/// Fetch the description behind each answer, leaving excluded responders and locations hosted
+/// anywhere but the responder untouched
+async fn describe(
+    answers: HashSet<(IpAddr, SsdpResponse)>,
+    exclusions: &ScanExclusions,
+) -> HashMap<IpAddr, Vec<UpnpDevice>> {
+    let answers = answers.into_iter().filter(|(ip, answer)| {
+        location_host(&answer.location) == Some(*ip) && exclusions.check(*ip, None).is_none()
+    });
+
+    // Hosts answer every search target with the same location
+    let mut locations: HashMap<String, (IpAddr, Option<String>)> = HashMap::new();
+    for (ip, answer) in answers {
+        locations
+            .entry(answer.location)
+            .or_insert((ip, answer.server));
+    }
+
+    tracing::debug!(
+        locations = locations.len(),
+        "Fetching UPnP device descriptions"
+    );
+
+    let client = match reqwest::Client::builder()
+        .timeout(DESCRIPTION_TIMEOUT)
+        .danger_accept_invalid_certs(true)
+        // A redirect would take the fetch to a host other than the one that answered
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::warn!(error = %e, "Could not build client for UPnP descriptions");
+            return HashMap::new();
+        }
+    };
+
+    let devices: Vec<(IpAddr, UpnpDevice)> = stream::iter(locations)
+        .map(|(location, (ip, server))| {
+            let client = client.clone();
+            async move {
+                match fetch_description(&client, &location).await {
+                    Ok(xml) => UpnpDevice::from_description(&location, server, &xml)
+                        .map(|device| (ip, device)),
+                    Err(e) => {
+                        tracing::debug!(ip = %ip, location, error = %e, "Failed to fetch UPnP description");
+                        None
+                    }
+                }
+            }
+        })
+        .buffer_unordered(CONCURRENT_DESCRIPTION_FETCHES)
+        .filter_map(|device| async { device })
+        .collect()
+        .await;
+
+    let mut hosts: HashMap<IpAddr, Vec<UpnpDevice>> = HashMap::new();
+    for (ip, device) in devices {
+        hosts.entry(ip).or_default().push(device);
+    }
+    hosts
+}
+
+async fn search_from(
+    source_ip: Ipv4Addr,
+    cancel: &CancellationToken,
+) -> Result<Vec<(IpAddr, SsdpResponse)>, Error> {
+    let socket = UdpSocket::bind(SocketAddr::new(IpAddr::V4(source_ip), 0)).await?;
+    socket.set_multicast_ttl_v4(2)?;
+    let target = SocketAddr::new(IpAddr::V4(SSDP_MULTICAST_ADDR), SSDP_PORT);
+
+    for search_target in SEARCH_TARGETS {
+        let request = format!(
+            "M-SEARCH * HTTP/1.1\r\nHOST: {}:{}\r\nMAN: \"ssdp:discover\"\r\nMX: {}\r\nST: {}\r\n\r\n",
+            SSDP_MULTICAST_ADDR, SSDP_PORT, SEARCH_MX, search_target
+        );
+        socket.send_to(request.as_bytes(), target).await?;
+    }
+
+    let deadline = Instant::now() + SEARCH_DURATION;
+    let mut answers = Vec::new();
+    let mut buf = [0u8; 2048];
+
+    loop {
+        if cancel.is_cancelled() {
+            return Err(anyhow!("Discovery was cancelled"));
+        }
+
+        match timeout_at(deadline, socket.recv_from(&mut buf)).await {
+            Ok(Ok((len, from))) => {
+                if let Some(answer) = SsdpResponse::parse(&buf[..len]) {
+                    answers.push((from.ip(), answer));
+                }
+            }
+            Ok(Err(e)) => return Err(e.into()),
+            Err(_) => break,
+        }
+    }
+
+    Ok(answers)
+}
+
+fn location_host(location: &str) -> Option<IpAddr> {
+    match url::Url::parse(location).ok()?.host()? {
+        url::Host::Ipv4(ip) => Some(IpAddr::V4(ip)),
+        url::Host::Ipv6(ip) => Some(IpAddr::V6(ip)),
+        url::Host::Domain(_) => None,
+    }
+}
+
+async fn fetch_description(client: &reqwest::Client, location: &str) -> Result<String, Error> {
+    let mut response = client.get(location).send().await?.error_for_status()?;
+    if !response.status().is_success() {
+        return Err(anyhow!("Unexpected status {}", response.status()));
+    }
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(anyhow!(
+                "Description is larger than {} bytes",
+                MAX_DESCRIPTION_LENGTH
+            ));
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::server::scan_exclusions::r#impl::base::{ScanExclusionRule, ScanExclusionTarget};
+
+    /// Serve a minimal description on localhost, counting the requests it gets
+    async fn serve_description() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let location = format!("http://{}/description.xml", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await;
+                let body = "<?xml version=\"1.0\"?><root xmlns=\"urn:schemas-upnp-org:device-1-0\">\
+                    <device><deviceType>urn:schemas-upnp-org:device:MediaRenderer:1</deviceType>\
+                    <friendlyName>Living Room</friendlyName><manufacturer>Roku</manufacturer></device></root>";
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/xml\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        (location, requests)
+    }
+
+    #[tokio::test]
+    async fn test_excluded_responder_is_never_fetched() {
+        let (location, requests) = serve_description().await;
+        let responder = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let answers = HashSet::from([(
+            responder,
+            SsdpResponse {
+                location,
+                server: None,
+            },
+        )]);
+
+        for arp_only in [false, true] {
+            let exclusions = ScanExclusions(vec![ScanExclusionTarget {
+                rule: ScanExclusionRule::Ip { ip: responder },
+                arp_only,
+            }]);
+            assert!(describe(answers.clone(), &exclusions).await.is_empty());
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        // Sanity check that the same answer is fetched when nothing excludes it
+        let devices = describe(answers, &ScanExclusions::default()).await;
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(devices.get(&responder).map(Vec::len), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_location_on_another_host_is_never_fetched() {
+        let (location, requests) = serve_description().await;
+        let answers = HashSet::from([(
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)),
+            SsdpResponse {
+                location,
+                server: None,
+            },
+        )]);
+
+        assert!(
+            describe(answers, &ScanExclusions::default())
+                .await
+                .is_empty()
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_parse_search_response() {
+        let response = SsdpResponse::parse(
+            b"HTTP/1.1 200 OK\r\nCache-Control: max-age=3600\r\nST: upnp:rootdevice\r\n\
+              Location: http://192.168.1.20:8060/\r\nServer: Roku/9.4.0 UPnP/1.0 Roku/9.4.0\r\n\r\n",
+        )
+        .expect("Response should parse");
+
+        assert_eq!(response.location, "http://192.168.1.20:8060/");
+        assert_eq!(
+            response.server.as_deref(),
+            Some("Roku/9.4.0 UPnP/1.0 Roku/9.4.0")
+        );
+        assert_eq!(
+            location_host(&response.location),
+            Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)))
+        );
+
+        // Other clients' searches reach the same group
+        assert_eq!(
+            SsdpResponse::parse(
+                b"M-SEARCH * HTTP/1.1\r\nHOST: 239.255.255.250:1900\r\nST: ssdp:all\r\n\r\n"
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,444 @@
+//! Half-open TCP port scanning over raw sockets.
+//!
+//! ## Platform Behavior
+//!
+//! | Platform | Method                        | Fallback            |
+//! |----------|-------------------------------|---------------------|
+//! | Linux    | SYN scan (raw socket)         | TCP connect scans   |
+//! | macOS    | -                             | TCP connect scans   |
+//! | Windows  | -                             | TCP connect scans   |
+//!
+//! A SYN is sent to each port and never followed up: a SYN-ACK marks the port open, a RST marks
+//! it closed, and silence marks it filtered. The kernel has no socket for the handshake, so it
+//! resets any connection a SYN-ACK opens on our behalf.
+
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use pnet::packet::ip::IpNextHeaderProtocols;
+use pnet::packet::tcp::{self, MutableTcpPacket, TcpFlags, TcpOption, TcpPacket};
+use pnet::transport::{TransportChannelType, TransportProtocol};
+use tokio_util::sync::CancellationToken;
+
+use crate::daemon::utils::scanner::{ConnectOptions, SubnetScanControl};
+
+/// Least extra receive time after the final round. Hosts slower to answer than this get the
+/// full connect timeout, like every earlier round.
+pub const POST_SCAN_RECEIVE: Duration = Duration::from_millis(500);
+
+/// Fixed header plus a four byte MSS option, which some stacks insist on before answering
+const SYN_LEN: usize = 24;
+const SYN_MSS: u16 = 1460;
+const SYN_WINDOW: u16 = 1024;
+
+/// Source ports are picked above Linux's default ephemeral range (32768-60999), so replies
+/// can't be mistaken for traffic of the daemon's own connections
+pub const SOURCE_PORT_MIN: u16 = 61000;
+
+const TRANSPORT_BUFFER_SIZE: usize = 4096;
+
+/// Check if SYN scans are available, ie the daemon runs on Linux and may open raw sockets.
+pub fn is_available() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        let available =
+            pnet::transport::transport_channel(TRANSPORT_BUFFER_SIZE, channel_type(false)).is_ok();
+        tracing::debug!(available, "Checking SYN scan availability");
+        available
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        false
+    }
+}
+
+/// Sends SYNs for any number of concurrent host scans, paced to a single packet rate so
+/// scanning hosts in parallel doesn't multiply the load on the network.
+pub struct SynScanner {
+    send_interval: Duration,
+    next_send: Mutex<Instant>,
+}
+
+impl SynScanner {
+    pub fn new(rate_pps: u32) -> Self {
+        Self {
+            send_interval: Duration::from_micros(1_000_000 / rate_pps.max(1) as u64),
+            next_send: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Scan `ports` on `ip` and return the ones that answered with a SYN-ACK.
+    ///
+    /// Blocks until the scan completes, so async callers should run it with `spawn_blocking`.
+    /// Ports that stay silent are retried `connect.retries` times, each round waiting
+    /// `connect.timeout` for answers. Round trip times of answers and unanswered SYNs are
+    /// recorded with `control`, as connect scans record theirs.
+    pub fn scan(
+        &self,
+        ip: IpAddr,
+        ports: &[u16],
+        connect: ConnectOptions,
+        control: Option<&SubnetScanControl>,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<u16>> {
+        #[cfg(target_os = "linux")]
+        {
+            raw::scan(self, ip, ports, connect, control, cancel)
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = (ip, ports, connect, control, cancel);
+            Err(anyhow::anyhow!(
+                "SYN scans are not supported on this platform"
+            ))
+        }
+    }
+
+    /// Block until this scanner may send its next packet
+    fn wait_turn(&self) {
+        let wait = {
+            let mut next_send = self.next_send.lock().unwrap();
+            let now = Instant::now();
+            let slot = (*next_send).max(now);
+            *next_send = slot + self.send_interval;
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+}
+
+fn channel_type(ipv6: bool) -> TransportChannelType {
+    TransportChannelType::Layer4(if ipv6 {
+        TransportProtocol::Ipv6(IpNextHeaderProtocols::Tcp)
+    } else {
+        TransportProtocol::Ipv4(IpNextHeaderProtocols::Tcp)
+    })
+}
+
+/// Address the kernel would send from to reach `destination`, which TCP checksums cover.
+///
+/// Connecting a UDP socket only consults the routing table, nothing is sent.
+pub fn source_address(destination: IpAddr) -> Result<IpAddr> {
+    let bind: SocketAddr = if destination.is_ipv6() {
+        "[::]:0".parse()?
+    } else {
+        "0.0.0.0:0".parse()?
+    };
+    let socket = UdpSocket::bind(bind)?;
+    socket.connect(SocketAddr::new(destination, 9))?;
+    Ok(socket.local_addr()?.ip())
+}
+
+pub fn build_syn(
+    source: IpAddr,
+    destination: IpAddr,
+    source_port: u16,
+    destination_port: u16,
+    sequence: u32,
+) -> Vec<u8> {
+    let mut buffer = vec![0u8; SYN_LEN];
+    let mut packet = MutableTcpPacket::new(&mut buffer).unwrap();
+    packet.set_source(source_port);
+    packet.set_destination(destination_port);
+    packet.set_sequence(sequence);
+    packet.set_data_offset((SYN_LEN / 4) as u8);
+    packet.set_flags(TcpFlags::SYN);
+    packet.set_window(SYN_WINDOW);
+    packet.set_options(&[TcpOption::mss(SYN_MSS)]);
+
+    let checksum = match (source, destination) {
+        (IpAddr::V4(src), IpAddr::V4(dst)) => {
+            tcp::ipv4_checksum(&packet.to_immutable(), &src, &dst)
+        }
+        (IpAddr::V6(src), IpAddr::V6(dst)) => {
+            tcp::ipv6_checksum(&packet.to_immutable(), &src, &dst)
+        }
+        _ => 0,
+    };
+    packet.set_checksum(checksum);
+
+    buffer
+}
+
+/// How long to keep listening once the final round has been sent
+fn final_receive_wait(connect: &ConnectOptions) -> Duration {
+    POST_SCAN_RECEIVE.max(connect.timeout)
+}
+
+/// Port a reply to one of our SYNs came from, and whether it was open. Anything else the raw
+/// socket sees is ignored.
+fn parse_reply(packet: &[u8], source_port: u16, sequence: u32) -> Option<(u16, bool)> {
+    let reply = TcpPacket::new(packet)?;
+    if reply.get_destination() != source_port
+        || reply.get_acknowledgement() != sequence.wrapping_add(1)
+    {
+        return None;
+    }
+
+    let flags = reply.get_flags();
+    if flags & TcpFlags::RST != 0 {
+        Some((reply.get_source(), false))
+    } else if flags & (TcpFlags::SYN | TcpFlags::ACK) == TcpFlags::SYN | TcpFlags::ACK {
+        Some((reply.get_source(), true))
+    } else {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod raw {
+    use std::collections::HashMap;
+    use std::net::IpAddr;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use anyhow::Result;
+    use pnet::packet::Packet;
+    use pnet::packet::tcp::TcpPacket;
+    use pnet::transport::{tcp_packet_iter, transport_channel};
+    use rand::Rng;
+    use tokio_util::sync::CancellationToken;
+
+    use super::{
+        ConnectOptions, SOURCE_PORT_MIN, SubnetScanControl, SynScanner, TRANSPORT_BUFFER_SIZE,
+        build_syn, channel_type, final_receive_wait, parse_reply, source_address,
+    };
+
+    /// When a port's latest SYN went out, and in which round counting from 1
+    struct Sent {
+        at: Instant,
+        attempt: u32,
+    }
+
+    /// Ports that have answered, open or closed, with their round trip times, shared with the
+    /// receiving thread
+    struct ScanState {
+        sent: Mutex<HashMap<u16, Sent>>,
+        answered: Mutex<HashMap<u16, (Duration, u32)>>,
+        open: Mutex<Vec<u16>>,
+        sending_done: AtomicBool,
+    }
+
+    pub fn scan(
+        scanner: &SynScanner,
+        ip: IpAddr,
+        ports: &[u16],
+        connect: ConnectOptions,
+        control: Option<&SubnetScanControl>,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<u16>> {
+        let source = source_address(ip)?;
+        let (mut tx, mut rx) =
+            transport_channel(TRANSPORT_BUFFER_SIZE, channel_type(ip.is_ipv6()))?;
+
+        let mut rng = rand::rng();
+        let source_port: u16 = rng.random_range(SOURCE_PORT_MIN..=u16::MAX);
+        let sequence: u32 = rng.random();
+
+        let state = Arc::new(ScanState {
+            sent: Mutex::new(HashMap::new()),
+            answered: Mutex::new(HashMap::new()),
+            open: Mutex::new(Vec::new()),
+            sending_done: AtomicBool::new(false),
+        });
+
+        let final_wait = final_receive_wait(&connect);
+        let receiver = {
+            let state = state.clone();
+            thread::spawn(move || {
+                let mut replies = tcp_packet_iter(&mut rx);
+                let mut final_deadline: Option<Instant> = None;
+
+                loop {
+                    if let Some(deadline) = final_deadline {
+                        if Instant::now() >= deadline {
+                            break;
+                        }
+                    } else if state.sending_done.load(Ordering::Relaxed) {
+                        final_deadline = Some(Instant::now() + final_wait);
+                    }
+
+                    let Ok(Some((packet, from))) =
+                        replies.next_with_timeout(Duration::from_millis(100))
+                    else {
+                        continue;
+                    };
+
+                    if from != ip {
+                        continue;
+                    }
+
+                    let Some((port, open)) = parse_reply(packet.packet(), source_port, sequence)
+                    else {
+                        continue;
+                    };
+
+                    let Some(rtt) = state
+                        .sent
+                        .lock()
+                        .unwrap()
+                        .get(&port)
+                        .map(|sent| (sent.at.elapsed(), sent.attempt))
+                    else {
+                        continue;
+                    };
+
+                    if state.answered.lock().unwrap().insert(port, rtt).is_none() && open {
+                        tracing::debug!(ip = %ip, port, "Found open TCP port with SYN scan");
+                        state.open.lock().unwrap().push(port);
+                    }
+                }
+            })
+        };
+
+        let total_rounds = 1 + u32::from(connect.retries);
+        for round in 0..total_rounds {
+            let mut pending = 0usize;
+
+            for port in ports {
+                if cancel.is_cancelled() {
+                    break;
+                }
+                if state.answered.lock().unwrap().contains_key(port) {
+                    continue;
+                }
+                pending += 1;
+
+                // The previous round's SYN to this port went unanswered
+                if round > 0
+                    && let Some(control) = control
+                {
+                    control.record_timeout();
+                }
+
+                scanner.wait_turn();
+                let packet = build_syn(source, ip, source_port, *port, sequence);
+                state.sent.lock().unwrap().insert(
+                    *port,
+                    Sent {
+                        at: Instant::now(),
+                        attempt: round + 1,
+                    },
+                );
+                if let Err(e) = tx.send_to(TcpPacket::new(&packet).unwrap(), ip) {
+                    tracing::trace!(ip = %ip, port, error = %e, "Failed to send SYN");
+                }
+            }
+
+            tracing::trace!(ip = %ip, round = round + 1, total_rounds, pending, "SYN round sent");
+
+            if pending == 0 || cancel.is_cancelled() {
+                break;
+            }
+            if round + 1 < total_rounds {
+                thread::sleep(connect.timeout);
+            }
+        }
+
+        state.sending_done.store(true, Ordering::Relaxed);
+        let _ = receiver.join();
+
+        if let Some(control) = control {
+            let answered = state.answered.lock().unwrap();
+            for (rtt, attempt) in answered.values() {
+                control.record_answer(*rtt, *attempt);
+            }
+            let sent = state.sent.lock().unwrap();
+            for _ in sent.keys().filter(|port| !answered.contains_key(port)) {
+                control.record_timeout();
+            }
+        }
+
+        let mut open = std::mem::take(&mut *state.open.lock().unwrap());
+        open.sort_unstable();
+
+        tracing::debug!(
+            ip = %ip,
+            ports_scanned = ports.len(),
+            responses = state.answered.lock().unwrap().len(),
+            open = open.len(),
+            "TCP ports SYN scanned"
+        );
+
+        Ok(open)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn reply(source_port: u16, flags: u8, acknowledgement: u32) -> Vec<u8> {
+        let mut buffer = vec![0u8; 20];
+        let mut packet = MutableTcpPacket::new(&mut buffer).unwrap();
+        packet.set_source(443);
+        packet.set_destination(source_port);
+        packet.set_acknowledgement(acknowledgement);
+        packet.set_data_offset(5);
+        packet.set_flags(flags);
+        buffer
+    }
+
+    #[test]
+    fn test_final_round_waits_for_the_connect_timeout() {
+        let connect = |timeout_ms| ConnectOptions {
+            timeout: Duration::from_millis(timeout_ms),
+            ..Default::default()
+        };
+
+        assert_eq!(final_receive_wait(&connect(100)), POST_SCAN_RECEIVE);
+        assert_eq!(
+            final_receive_wait(&connect(2000)),
+            Duration::from_millis(2000)
+        );
+    }
+
+    #[test]
+    fn test_build_syn_has_valid_checksum() {
+        let source = Ipv4Addr::new(192, 168, 1, 10);
+        let destination = Ipv4Addr::new(192, 168, 1, 20);
+        let packet = build_syn(source.into(), destination.into(), 61234, 22, 0xdead_beef);
+
+        let syn = TcpPacket::new(&packet).unwrap();
+        assert_eq!(syn.get_flags(), TcpFlags::SYN);
+        assert_eq!(syn.get_source(), 61234);
+        assert_eq!(syn.get_destination(), 22);
+        assert_eq!(syn.get_sequence(), 0xdead_beef);
+        assert_eq!(
+            syn.get_checksum(),
+            tcp::ipv4_checksum(&syn, &source, &destination)
+        );
+
+        let source = Ipv6Addr::LOCALHOST;
+        let packet = build_syn(source.into(), source.into(), 61234, 22, 1);
+        let syn = TcpPacket::new(&packet).unwrap();
+        assert_eq!(
+            syn.get_checksum(),
+            tcp::ipv6_checksum(&syn, &source, &source)
+        );
+    }
+
+    #[test]
+    fn test_parse_reply_classifies_ports() {
+        let syn_ack = reply(61234, TcpFlags::SYN | TcpFlags::ACK, 8);
+        assert_eq!(parse_reply(&syn_ack, 61234, 7), Some((443, true)));
+
+        let rst = reply(61234, TcpFlags::RST | TcpFlags::ACK, 8);
+        assert_eq!(parse_reply(&rst, 61234, 7), Some((443, false)));
+
+        // Replies to another scan or another connection
+        assert_eq!(parse_reply(&syn_ack, 61235, 7), None);
+        assert_eq!(parse_reply(&syn_ack, 61234, 8), None);
+        assert_eq!(parse_reply(&reply(61234, TcpFlags::ACK, 8), 61234, 7), None);
+    }
+}
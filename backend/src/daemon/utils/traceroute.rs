@@ -0,0 +1,586 @@
+//! Route tracing, to find the routers between the daemon and remote sites.
+//!
+//! ## Platform Behavior
+//!
+//! | Platform | Method                             | Fallback |
+//! |----------|------------------------------------|----------|
+//! | Linux    | UDP/ICMP/TCP probes (raw sockets)  | -        |
+//! | macOS    | UDP/ICMP/TCP probes (raw sockets)  | -        |
+//! | Windows  | -                                  | -        |
+//!
+//! Probes go out with every TTL at once rather than one hop at a time, and each router that
+//! drops one for running out of hops answers with an ICMP Time Exceeded quoting the probe. The
+//! quote carries enough of the probe's transport header to tell which one it was.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use anyhow::Result;
+use pnet::packet::icmp::IcmpTypes;
+use pnet::packet::icmpv6::Icmpv6Types;
+use pnet::packet::ip::{IpNextHeaderProtocol, IpNextHeaderProtocols};
+use pnet::packet::tcp::{TcpFlags, TcpPacket};
+use pnet::packet::udp::{self, MutableUdpPacket, UdpPacket};
+use pnet::transport::{TransportChannelType, TransportProtocol};
+
+use crate::server::discovery::r#impl::types::TracerouteProtocol;
+
+/// Most hops a route is traced for
+pub const MAX_HOPS: u8 = 64;
+/// How long to wait for answers after each round of probes
+pub const ROUND_WAIT: Duration = Duration::from_secs(2);
+/// Rounds of probes, ie probes per hop
+pub const ROUNDS: u16 = 2;
+
+/// First destination port of UDP probes, as in Unix traceroute
+const UDP_BASE_PORT: u16 = 33434;
+/// Destination port of TCP probes
+const TCP_PORT: u16 = 443;
+/// Spacing between probes, so a burst of expiring probes doesn't hit ICMP rate limits on
+/// routers
+const PROBE_INTERVAL: Duration = Duration::from_millis(5);
+
+const UDP_HEADER_LEN: usize = 8;
+const IPV6_HEADER_LEN: usize = 40;
+const TRANSPORT_BUFFER_SIZE: usize = 4096;
+
+/// A router that answered a probe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hop {
+    pub ttl: u8,
+    pub ip: IpAddr,
+    pub rtt: Duration,
+}
+
+/// The routers a traced route went through, in order. Hops that never answered are missing, so
+/// consecutive entries are only adjacent when their TTLs are.
+#[derive(Debug, Clone)]
+pub struct TracedRoute {
+    pub target: IpAddr,
+    pub hops: Vec<Hop>,
+    /// Whether the target itself answered
+    pub reached: bool,
+}
+
+/// Check if routes can be traced, ie the daemon may open raw sockets.
+pub fn is_available() -> bool {
+    #[cfg(unix)]
+    {
+        let available = pnet::transport::transport_channel(
+            TRANSPORT_BUFFER_SIZE,
+            channel_type(false, IpNextHeaderProtocols::Icmp),
+        )
+        .is_ok();
+        tracing::debug!(available, "Checking traceroute availability");
+        available
+    }
+
+    #[cfg(not(unix))]
+    {
+        false
+    }
+}
+
+/// Trace the route to `target`.
+///
+/// Blocks until the trace completes, so async callers should run it with `spawn_blocking`.
+pub fn trace(target: IpAddr, protocol: TracerouteProtocol, max_hops: u8) -> Result<TracedRoute> {
+    #[cfg(unix)]
+    {
+        probe::trace(target, protocol, max_hops.clamp(1, MAX_HOPS))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (target, protocol, max_hops);
+        Err(anyhow::anyhow!(
+            "Traceroute is not supported on this platform"
+        ))
+    }
+}
+
+fn channel_type(ipv6: bool, protocol: IpNextHeaderProtocol) -> TransportChannelType {
+    let protocol = match (ipv6, protocol) {
+        (true, IpNextHeaderProtocols::Icmp) => IpNextHeaderProtocols::Icmpv6,
+        (_, protocol) => protocol,
+    };
+
+    TransportChannelType::Layer4(if ipv6 {
+        TransportProtocol::Ipv6(protocol)
+    } else {
+        TransportProtocol::Ipv4(protocol)
+    })
+}
+
+/// What tells this trace's probes apart from everything else the raw sockets see. Each probe
+/// carries its index in a field routers quote back.
+#[derive(Debug, Clone, Copy)]
+struct ProbeKeys {
+    protocol: TracerouteProtocol,
+    target: IpAddr,
+    /// ICMP identifier, or the source port of UDP and TCP probes
+    identifier: u16,
+    /// Sequence number of the first TCP probe
+    tcp_sequence: u32,
+}
+
+impl ProbeKeys {
+    /// Index of the probe the first eight bytes of a transport header belong to
+    fn probe_index(&self, ip_protocol: u8, transport: &[u8]) -> Option<u16> {
+        if transport.len() < 8 {
+            return None;
+        }
+        let field = |i: usize| u16::from_be_bytes([transport[i], transport[i + 1]]);
+
+        match self.protocol {
+            TracerouteProtocol::Udp if ip_protocol == IpNextHeaderProtocols::Udp.0 => (field(0)
+                == self.identifier)
+                .then(|| field(2).checked_sub(UDP_BASE_PORT))
+                .flatten(),
+            TracerouteProtocol::Icmp
+                if ip_protocol == IpNextHeaderProtocols::Icmp.0
+                    || ip_protocol == IpNextHeaderProtocols::Icmpv6.0 =>
+            {
+                let echo_request = if self.target.is_ipv6() {
+                    Icmpv6Types::EchoRequest.0
+                } else {
+                    IcmpTypes::EchoRequest.0
+                };
+                (transport[0] == echo_request && field(4) == self.identifier).then(|| field(6))
+            }
+            TracerouteProtocol::Tcp if ip_protocol == IpNextHeaderProtocols::Tcp.0 => {
+                let sequence =
+                    u32::from_be_bytes([transport[4], transport[5], transport[6], transport[7]]);
+                (field(0) == self.identifier)
+                    .then(|| u16::try_from(sequence.wrapping_sub(self.tcp_sequence)).ok())
+                    .flatten()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An answer to one of the trace's probes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Answer {
+    /// A router on the way dropped the probe for running out of hops
+    Expired(u16),
+    /// The probe was refused, by the target when it's the one answering, otherwise by a router
+    /// that won't forward it
+    Unreachable(u16),
+    /// The target answered the probe itself
+    Reached(u16),
+}
+
+/// Match an ICMP or ICMPv6 packet to a probe
+fn parse_icmp(keys: &ProbeKeys, packet: &[u8]) -> Option<Answer> {
+    if packet.len() < 8 {
+        return None;
+    }
+    let ipv6 = keys.target.is_ipv6();
+    let (expired, unreachable, echo_reply) = if ipv6 {
+        (
+            Icmpv6Types::TimeExceeded.0,
+            Icmpv6Types::DestinationUnreachable.0,
+            Icmpv6Types::EchoReply.0,
+        )
+    } else {
+        (
+            IcmpTypes::TimeExceeded.0,
+            IcmpTypes::DestinationUnreachable.0,
+            IcmpTypes::EchoReply.0,
+        )
+    };
+
+    let icmp_type = packet[0];
+    if icmp_type == echo_reply {
+        return (keys.protocol == TracerouteProtocol::Icmp
+            && u16::from_be_bytes([packet[4], packet[5]]) == keys.identifier)
+            .then(|| Answer::Reached(u16::from_be_bytes([packet[6], packet[7]])));
+    }
+    if icmp_type != expired && icmp_type != unreachable {
+        return None;
+    }
+
+    // Errors quote the offending packet's IP header and at least the start of its payload
+    let quoted = &packet[8..];
+    let (destination, ip_protocol, header_len) = if ipv6 {
+        if quoted.len() < IPV6_HEADER_LEN {
+            return None;
+        }
+        let mut destination = [0u8; 16];
+        destination.copy_from_slice(&quoted[24..40]);
+        (
+            IpAddr::V6(Ipv6Addr::from(destination)),
+            quoted[6],
+            IPV6_HEADER_LEN,
+        )
+    } else {
+        if quoted.len() < 20 {
+            return None;
+        }
+        (
+            IpAddr::V4(Ipv4Addr::new(
+                quoted[16], quoted[17], quoted[18], quoted[19],
+            )),
+            quoted[9],
+            usize::from(quoted[0] & 0x0f) * 4,
+        )
+    };
+
+    if destination != keys.target {
+        return None;
+    }
+    let index = keys.probe_index(ip_protocol, quoted.get(header_len..)?)?;
+
+    Some(if icmp_type == expired {
+        Answer::Expired(index)
+    } else {
+        Answer::Unreachable(index)
+    })
+}
+
+/// Match the target's answer to a TCP probe: a SYN-ACK or RST acknowledging its sequence number
+fn parse_tcp(keys: &ProbeKeys, packet: &[u8]) -> Option<Answer> {
+    let reply = TcpPacket::new(packet)?;
+    if reply.get_destination() != keys.identifier || reply.get_source() != TCP_PORT {
+        return None;
+    }
+    if reply.get_flags() & (TcpFlags::SYN | TcpFlags::RST) == 0 {
+        return None;
+    }
+
+    let sequence = reply.get_acknowledgement().wrapping_sub(1);
+    let index = u16::try_from(sequence.wrapping_sub(keys.tcp_sequence)).ok()?;
+    Some(Answer::Reached(index))
+}
+
+fn build_udp(source: IpAddr, keys: &ProbeKeys, index: u16) -> Vec<u8> {
+    let mut buffer = vec![0u8; UDP_HEADER_LEN];
+    {
+        let mut packet = MutableUdpPacket::new(&mut buffer).unwrap();
+        packet.set_source(keys.identifier);
+        packet.set_destination(UDP_BASE_PORT.wrapping_add(index));
+        packet.set_length(UDP_HEADER_LEN as u16);
+    }
+
+    let checksum = match (source, keys.target) {
+        (IpAddr::V4(src), IpAddr::V4(dst)) => {
+            udp::ipv4_checksum(&UdpPacket::new(&buffer).unwrap(), &src, &dst)
+        }
+        (IpAddr::V6(src), IpAddr::V6(dst)) => {
+            udp::ipv6_checksum(&UdpPacket::new(&buffer).unwrap(), &src, &dst)
+        }
+        _ => 0,
+    };
+    buffer[6..8].copy_from_slice(&checksum.to_be_bytes());
+
+    buffer
+}
+
+#[cfg(unix)]
+mod probe {
+    use std::collections::HashMap;
+    use std::net::IpAddr;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use anyhow::Result;
+    use pnet::packet::Packet;
+    use pnet::packet::icmp::IcmpPacket;
+    use pnet::packet::icmpv6::Icmpv6Packet;
+    use pnet::packet::ip::IpNextHeaderProtocols;
+    use pnet::packet::tcp::TcpPacket;
+    use pnet::packet::udp::UdpPacket;
+    use pnet::transport::{
+        TransportReceiver, TransportSender, icmp_packet_iter, icmpv6_packet_iter, tcp_packet_iter,
+        transport_channel,
+    };
+    use rand::Rng;
+
+    use super::{
+        Answer, Hop, PROBE_INTERVAL, ProbeKeys, ROUND_WAIT, ROUNDS, TCP_PORT,
+        TRANSPORT_BUFFER_SIZE, TracedRoute, build_udp, channel_type, parse_icmp, parse_tcp,
+    };
+    use crate::daemon::utils::ping::build_echo_request;
+    use crate::daemon::utils::syn::{SOURCE_PORT_MIN, build_syn, source_address};
+    use crate::server::discovery::r#impl::types::TracerouteProtocol;
+
+    pub fn trace(
+        target: IpAddr,
+        protocol: TracerouteProtocol,
+        max_hops: u8,
+    ) -> Result<TracedRoute> {
+        let ipv6 = target.is_ipv6();
+        let source = source_address(target)?;
+
+        let mut rng = rand::rng();
+        let keys = ProbeKeys {
+            protocol,
+            target,
+            identifier: match protocol {
+                TracerouteProtocol::Icmp => rng.random(),
+                _ => rng.random_range(SOURCE_PORT_MIN..=u16::MAX),
+            },
+            tcp_sequence: rng.random(),
+        };
+
+        // Errors from routers always come in over ICMP; echo probes go out the same way
+        let (mut icmp_tx, mut icmp_rx) = transport_channel(
+            TRANSPORT_BUFFER_SIZE,
+            channel_type(ipv6, IpNextHeaderProtocols::Icmp),
+        )?;
+        let mut probe_channel: Option<(TransportSender, TransportReceiver)> = match protocol {
+            TracerouteProtocol::Icmp => None,
+            TracerouteProtocol::Udp => Some(transport_channel(
+                TRANSPORT_BUFFER_SIZE,
+                channel_type(ipv6, IpNextHeaderProtocols::Udp),
+            )?),
+            TracerouteProtocol::Tcp => Some(transport_channel(
+                TRANSPORT_BUFFER_SIZE,
+                channel_type(ipv6, IpNextHeaderProtocols::Tcp),
+            )?),
+        };
+
+        let mut sent: HashMap<u16, (u8, Instant)> = HashMap::new();
+        let mut hops: HashMap<u8, Hop> = HashMap::new();
+        // TTL the target answered at, or a router refused to forward beyond
+        let mut last_ttl: Option<u8> = None;
+        let mut reached = false;
+
+        for round in 0..ROUNDS {
+            for ttl in 1..=max_hops {
+                if last_ttl.is_some_and(|last| ttl > last) {
+                    break;
+                }
+                if hops.contains_key(&ttl) {
+                    continue;
+                }
+
+                let index = round * u16::from(max_hops) + u16::from(ttl - 1);
+                let result = match (&mut probe_channel, protocol) {
+                    (Some((tx, _)), TracerouteProtocol::Tcp) => {
+                        let sequence = keys.tcp_sequence.wrapping_add(u32::from(index));
+                        let packet = build_syn(source, target, keys.identifier, TCP_PORT, sequence);
+                        tx.set_ttl(ttl)
+                            .and_then(|_| tx.send_to(TcpPacket::new(&packet).unwrap(), target))
+                    }
+                    (Some((tx, _)), _) => {
+                        let packet = build_udp(source, &keys, index);
+                        tx.set_ttl(ttl)
+                            .and_then(|_| tx.send_to(UdpPacket::new(&packet).unwrap(), target))
+                    }
+                    (None, _) => {
+                        let packet = build_echo_request(ipv6, keys.identifier, index);
+                        icmp_tx.set_ttl(ttl).and_then(|_| {
+                            if ipv6 {
+                                icmp_tx.send_to(Icmpv6Packet::new(&packet).unwrap(), target)
+                            } else {
+                                icmp_tx.send_to(IcmpPacket::new(&packet).unwrap(), target)
+                            }
+                        })
+                    }
+                };
+
+                match result {
+                    Ok(_) => {
+                        sent.insert(index, (ttl, Instant::now()));
+                    }
+                    Err(e) => {
+                        tracing::trace!(target = %target, ttl, error = %e, "Failed to send probe")
+                    }
+                }
+                thread::sleep(PROBE_INTERVAL);
+            }
+
+            let deadline = Instant::now() + ROUND_WAIT;
+            while Instant::now() < deadline {
+                let mut answers: Vec<(IpAddr, Answer)> = Vec::new();
+
+                let icmp_answer = if ipv6 {
+                    let mut packets = icmpv6_packet_iter(&mut icmp_rx);
+                    packets
+                        .next_with_timeout(Duration::from_millis(50))
+                        .ok()
+                        .flatten()
+                        .and_then(|(packet, from)| {
+                            Some((from, parse_icmp(&keys, packet.packet())?))
+                        })
+                } else {
+                    let mut packets = icmp_packet_iter(&mut icmp_rx);
+                    packets
+                        .next_with_timeout(Duration::from_millis(50))
+                        .ok()
+                        .flatten()
+                        .and_then(|(packet, from)| {
+                            Some((from, parse_icmp(&keys, packet.packet())?))
+                        })
+                };
+                answers.extend(icmp_answer);
+
+                if let (Some((_, rx)), TracerouteProtocol::Tcp) = (&mut probe_channel, protocol) {
+                    let mut packets = tcp_packet_iter(rx);
+                    let tcp_answer = packets
+                        .next_with_timeout(Duration::from_millis(50))
+                        .ok()
+                        .flatten()
+                        .filter(|(_, from)| *from == target)
+                        .and_then(|(packet, from)| {
+                            Some((from, parse_tcp(&keys, packet.packet())?))
+                        });
+                    answers.extend(tcp_answer);
+                }
+
+                for (from, answer) in answers {
+                    let index = match answer {
+                        Answer::Expired(index)
+                        | Answer::Unreachable(index)
+                        | Answer::Reached(index) => index,
+                    };
+                    let Some((ttl, sent_at)) = sent.get(&index).copied() else {
+                        continue;
+                    };
+
+                    let ends_route = from == target || !matches!(answer, Answer::Expired(_));
+                    if ends_route {
+                        reached |= from == target;
+                        last_ttl = Some(last_ttl.map_or(ttl, |last| last.min(ttl)));
+                    }
+                    if from != target {
+                        hops.entry(ttl).or_insert(Hop {
+                            ttl,
+                            ip: from,
+                            rtt: sent_at.elapsed(),
+                        });
+                    }
+                }
+            }
+
+            // Stop early once every hop up to the end of the route has answered
+            let answered_to = last_ttl.unwrap_or(max_hops);
+            if (1..answered_to).all(|ttl| hops.contains_key(&ttl)) && last_ttl.is_some() {
+                break;
+            }
+        }
+
+        let mut hops: Vec<Hop> = hops
+            .into_values()
+            .filter(|hop| last_ttl.is_none_or(|last| hop.ttl <= last))
+            .collect();
+        hops.sort_by_key(|hop| hop.ttl);
+
+        tracing::debug!(
+            target = %target,
+            hops = hops.len(),
+            reached,
+            "Route traced"
+        );
+
+        Ok(TracedRoute {
+            target,
+            hops,
+            reached,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe_keys(protocol: TracerouteProtocol, target: IpAddr) -> ProbeKeys {
+        ProbeKeys {
+            protocol,
+            target,
+            identifier: 61500,
+            tcp_sequence: 1000,
+        }
+    }
+
+    /// An ICMPv4 error quoting a probe with the given transport header
+    fn icmp_error(icmp_type: u8, protocol: u8, target: Ipv4Addr, transport: &[u8]) -> Vec<u8> {
+        let mut packet = vec![icmp_type, 0, 0, 0, 0, 0, 0, 0];
+        let mut ip_header = vec![0u8; 20];
+        ip_header[0] = 0x45;
+        ip_header[9] = protocol;
+        ip_header[16..20].copy_from_slice(&target.octets());
+        packet.extend(ip_header);
+        packet.extend_from_slice(transport);
+        packet
+    }
+
+    #[test]
+    fn test_parse_icmp_matches_quoted_udp_probe() {
+        let target = Ipv4Addr::new(10, 20, 0, 1);
+        let keys = probe_keys(TracerouteProtocol::Udp, target.into());
+        let probe = build_udp(Ipv4Addr::new(192, 168, 1, 10).into(), &keys, 7);
+
+        let expired = icmp_error(IcmpTypes::TimeExceeded.0, 17, target, &probe);
+        assert_eq!(parse_icmp(&keys, &expired), Some(Answer::Expired(7)));
+
+        let unreachable = icmp_error(IcmpTypes::DestinationUnreachable.0, 17, target, &probe);
+        assert_eq!(
+            parse_icmp(&keys, &unreachable),
+            Some(Answer::Unreachable(7))
+        );
+
+        // Someone else's probe, or one to a different target
+        let other = probe_keys(
+            TracerouteProtocol::Udp,
+            IpAddr::V4(Ipv4Addr::new(10, 20, 0, 2)),
+        );
+        assert_eq!(parse_icmp(&other, &expired), None);
+        let mut foreign = probe.clone();
+        foreign[0..2].copy_from_slice(&33000u16.to_be_bytes());
+        let foreign = icmp_error(IcmpTypes::TimeExceeded.0, 17, target, &foreign);
+        assert_eq!(parse_icmp(&keys, &foreign), None);
+    }
+
+    #[test]
+    fn test_parse_icmp_matches_echo_and_tcp_probes() {
+        let target = Ipv4Addr::new(10, 20, 0, 1);
+        let keys = probe_keys(TracerouteProtocol::Icmp, target.into());
+
+        let echo = crate::daemon::utils::ping::build_echo_request(false, 61500, 3);
+        let expired = icmp_error(IcmpTypes::TimeExceeded.0, 1, target, &echo);
+        assert_eq!(parse_icmp(&keys, &expired), Some(Answer::Expired(3)));
+
+        let mut reply = echo.clone();
+        reply[0] = IcmpTypes::EchoReply.0;
+        assert_eq!(parse_icmp(&keys, &reply), Some(Answer::Reached(3)));
+
+        let keys = ProbeKeys {
+            protocol: TracerouteProtocol::Tcp,
+            ..keys
+        };
+        let syn = crate::daemon::utils::syn::build_syn(
+            Ipv4Addr::new(192, 168, 1, 10).into(),
+            target.into(),
+            61500,
+            TCP_PORT,
+            1005,
+        );
+        let expired = icmp_error(IcmpTypes::TimeExceeded.0, 6, target, &syn[..8]);
+        assert_eq!(parse_icmp(&keys, &expired), Some(Answer::Expired(5)));
+    }
+
+    #[test]
+    fn test_parse_tcp_matches_target_answer() {
+        let keys = probe_keys(TracerouteProtocol::Tcp, Ipv4Addr::new(10, 20, 0, 1).into());
+
+        let mut buffer = vec![0u8; 20];
+        let mut reply = pnet::packet::tcp::MutableTcpPacket::new(&mut buffer).unwrap();
+        reply.set_source(TCP_PORT);
+        reply.set_destination(61500);
+        reply.set_acknowledgement(1010);
+        reply.set_data_offset(5);
+        reply.set_flags(TcpFlags::RST | TcpFlags::ACK);
+        assert_eq!(parse_tcp(&keys, &buffer), Some(Answer::Reached(9)));
+
+        let other = ProbeKeys {
+            identifier: 61501,
+            ..keys
+        };
+        assert_eq!(parse_tcp(&other, &buffer), None);
+    }
+}
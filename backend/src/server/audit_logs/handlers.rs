@@ -0,0 +1,239 @@
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{IntoResponse, Json, Response};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::audit_logs::r#impl::{
+    base::{AuditActorType, AuditLogCategory, AuditLogEntry},
+    export::AuditLogExportFormat,
+};
+use crate::server::auth::middleware::features::{AuditLogsFeature, RequireFeature};
+use crate::server::auth::middleware::permissions::{Admin, Authorized};
+use crate::server::config::AppState;
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::extractors::Query;
+use crate::server::shared::handlers::query::{FilterQueryExtractor, PaginationParams};
+use crate::server::shared::storage::{filter::StorableFilter, traits::Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, ApiResult, PaginatedApiResponse,
+};
+use crate::server::shared::validation::validate_network_access;
+
+/// Most entries a single export returns. Narrow the time range to export more.
+const MAX_EXPORT_ENTRIES: u32 = 100_000;
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_audit_logs))
+        .routes(routes!(export_audit_logs))
+}
+
+/// Query for filtering the audit log.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct AuditLogQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by kind of event
+    pub category: Option<AuditLogCategory>,
+    /// Filter by operation, ie `created` or `login_success`
+    pub operation: Option<String>,
+    /// Filter by entity type
+    pub entity_type: Option<EntityDiscriminants>,
+    /// Filter by the ID of the entity acted on
+    pub entity_id: Option<Uuid>,
+    /// Filter by kind of actor
+    pub actor_type: Option<AuditActorType>,
+    /// Filter by the ID of the user, API key or daemon that performed the action
+    pub actor_id: Option<Uuid>,
+    /// Filter by the user behind the action, including through their API keys
+    pub actor_user_id: Option<Uuid>,
+    /// Only return entries after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only return entries at or before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl FilterQueryExtractor for AuditLogQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let mut filter = filter.organization_id(&user_organization_id);
+        filter = match self.network_id {
+            Some(id) => filter.uuid_column("network_id", &id),
+            None => filter,
+        };
+        filter = match self.category {
+            Some(category) => filter.string_column("category", &category.to_string()),
+            None => filter,
+        };
+        filter = match &self.operation {
+            Some(operation) => filter.string_column("operation", operation),
+            None => filter,
+        };
+        filter = match &self.entity_type {
+            Some(entity_type) => filter.entity_type(entity_type),
+            None => filter,
+        };
+        filter = match self.entity_id {
+            Some(id) => filter.uuid_column("entity_id", &id),
+            None => filter,
+        };
+        filter = match self.actor_type {
+            Some(actor_type) => filter.string_column("actor_type", &actor_type.to_string()),
+            None => filter,
+        };
+        filter = match self.actor_id {
+            Some(id) => filter.uuid_column("actor_id", &id),
+            None => filter,
+        };
+        filter = match self.actor_user_id {
+            Some(id) => filter.uuid_column("actor_user_id", &id),
+            None => filter,
+        };
+        filter = match self.since {
+            Some(since) => filter.created_after(since),
+            None => filter,
+        };
+        filter = match self.until {
+            Some(until) => filter.created_at_or_before(until),
+            None => filter,
+        };
+
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// Audit log filter for the caller's organization
+fn audit_log_filter(
+    auth: &Authorized<Admin>,
+    query: &AuditLogQuery,
+) -> Result<StorableFilter<AuditLogEntry>, ApiError> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+    validate_network_access(query.network_id, &network_ids, "read")?;
+
+    Ok(query.apply_to_filter(StorableFilter::new(), &network_ids, organization_id))
+}
+
+/// List audit log entries
+///
+/// Returns who did what in your organization, newest first: entity changes with a diff of the
+/// fields that changed, discovery runs started and cancelled, and logins, password changes and
+/// API key rotations. Entries are kept for the organization's audit log retention period.
+/// Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "audit_logs",
+    params(AuditLogQuery),
+    responses(
+        (status = 200, description = "List of audit log entries", body = PaginatedApiResponse<AuditLogEntry>),
+        (status = 402, description = "Your plan does not include audit logs", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_audit_logs(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    RequireFeature { .. }: RequireFeature<AuditLogsFeature>,
+    Query(query): Query<AuditLogQuery>,
+) -> ApiResult<Json<PaginatedApiResponse<AuditLogEntry>>> {
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(audit_log_filter(&auth, &query)?);
+
+    let result = state
+        .services
+        .audit_log_service
+        .get_paginated(filter)
+        .await?;
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        pagination.effective_limit().unwrap_or(0),
+        pagination.effective_offset(),
+    )))
+}
+
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct AuditLogExportQuery {
+    /// File format. Default: csv.
+    #[serde(default)]
+    pub format: AuditLogExportFormat,
+}
+
+/// Export audit log entries
+///
+/// Downloads the entries matching the same filters as listing them, newest first, as CSV or JSON
+/// Lines. `limit` and `offset` are ignored; at most 100,000 entries are exported, so use `since`
+/// and `until` to export larger logs in parts. Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "/export",
+    tag = "audit_logs",
+    params(AuditLogExportQuery, AuditLogQuery),
+    responses(
+        (status = 200, description = "Audit log export", content(
+            (String = "text/csv"),
+            (String = "application/x-ndjson"),
+        )),
+        (status = 402, description = "Your plan does not include audit logs", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn export_audit_logs(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    RequireFeature { .. }: RequireFeature<AuditLogsFeature>,
+    Query(export): Query<AuditLogExportQuery>,
+    Query(query): Query<AuditLogQuery>,
+) -> ApiResult<Response> {
+    let filter = audit_log_filter(&auth, &query)?.limit(MAX_EXPORT_ENTRIES);
+
+    let entries = state.services.audit_log_service.get_all(filter).await?;
+    let body = export
+        .format
+        .render(&entries)
+        .map_err(|e| ApiError::internal_error(&e.to_string()))?;
+
+    let filename = format!(
+        "attachment; filename=\"audit-log-{}.{}\"",
+        Utc::now().format("%Y%m%dT%H%M%SZ"),
+        export.format.extension()
+    );
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                export.format.content_type().to_string(),
+            ),
+            (header::CONTENT_DISPOSITION, filename),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,250 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt::Display;
+use std::net::IpAddr;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    entity_changes::r#impl::history,
+    shared::{entities::EntityDiscriminants, secrets},
+};
+
+/// Fields whose values are replaced with a fingerprint before a snapshot is stored, so the audit
+/// log shows that a secret changed without holding it
+const REDACTED_FIELDS: &[&str] = &["key", "secret", "password", "password_hash", "community"];
+
+/// Kind of event an entry was recorded from
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogCategory {
+    /// An entity was created, updated or deleted, or a discovery was started or cancelled
+    #[default]
+    Entity,
+    /// A login, logout, password change, API key rotation or similar
+    Auth,
+}
+
+/// Who performed the action
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    User,
+    /// A user API key, acting on behalf of its user
+    ApiKey,
+    Daemon,
+    ExternalService,
+    /// The server itself, ie background jobs
+    #[default]
+    System,
+    Anonymous,
+}
+
+impl AuditActorType {
+    /// Actor type, and the ID of the user, API key or daemon, for an authenticated entity
+    pub fn of(authentication: &AuthenticatedEntity) -> (Self, Option<Uuid>) {
+        match authentication {
+            AuthenticatedEntity::User { user_id, .. } => (Self::User, Some(*user_id)),
+            AuthenticatedEntity::ApiKey { api_key_id, .. } => (Self::ApiKey, Some(*api_key_id)),
+            AuthenticatedEntity::Daemon { daemon_id, .. } => (Self::Daemon, Some(*daemon_id)),
+            AuthenticatedEntity::ExternalService { .. } => (Self::ExternalService, None),
+            AuthenticatedEntity::System => (Self::System, None),
+            AuthenticatedEntity::Anonymous => (Self::Anonymous, None),
+        }
+    }
+}
+
+/// The base data for an AuditLogEntry record (everything except id and created_at)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogEntryBase {
+    pub organization_id: Uuid,
+    pub network_id: Option<Uuid>,
+    pub category: AuditLogCategory,
+    /// Entity operation (ie `created`) or auth operation (ie `login_success`)
+    pub operation: String,
+    pub entity_type: Option<EntityDiscriminants>,
+    pub entity_id: Option<Uuid>,
+    pub actor_type: AuditActorType,
+    /// ID of the user, API key or daemon that performed the action
+    pub actor_id: Option<Uuid>,
+    /// The user behind the action: the user themselves, or the owner of the API key
+    pub actor_user_id: Option<Uuid>,
+    /// Email of the user, or name of the external service
+    pub actor_name: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+    /// The entity after the change. For deletions, as it was just before. Secrets are replaced
+    /// with a fingerprint.
+    #[schema(value_type = Option<Object>)]
+    pub snapshot: Option<Value>,
+    /// For updates, the top-level fields that changed, as `{"field": {"before": .., "after": ..}}`.
+    /// Empty when there's no earlier entry for the entity to compare against.
+    #[schema(value_type = Option<Object>)]
+    pub changes: Option<Value>,
+    #[schema(value_type = Object)]
+    pub metadata: Value,
+}
+
+/// One action in an organization's audit log. `created_at` is when the action was performed.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogEntry {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: AuditLogEntryBase,
+}
+
+impl AuditLogEntry {
+    pub fn new(base: AuditLogEntryBase) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+}
+
+impl Display for AuditLogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AuditLogEntry({} {} by {} at {})",
+            self.base.category, self.base.operation, self.base.actor_type, self.created_at
+        )
+    }
+}
+
+/// A snapshot with the values of secret fields, at every level, replaced with
+/// `redacted:<first 8 hex characters of their fingerprint>`, keyed with the server's secret key
+/// so low-entropy secrets can't be guessed from it. Just `redacted` when there's no key.
+pub fn redact(snapshot: &Value) -> Value {
+    redact_with(snapshot, &secrets::fingerprint)
+}
+
+fn redact_with(snapshot: &Value, fingerprint: &dyn Fn(&str) -> Option<String>) -> Value {
+    match snapshot {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| {
+                    let value = if REDACTED_FIELDS.contains(&key.as_str()) && !value.is_null() {
+                        Value::String(match fingerprint(&value.to_string()) {
+                            Some(fingerprint) => format!("redacted:{}", &fingerprint[..8]),
+                            None => "redacted".to_string(),
+                        })
+                    } else {
+                        redact_with(value, fingerprint)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .iter()
+                .map(|value| redact_with(value, fingerprint))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// The top-level fields that differ between two snapshots, ignoring `updated_at`, as
+/// `{"field": {"before": .., "after": ..}}`
+pub fn changes(before: &Value, after: &Value) -> Map<String, Value> {
+    history::changed_fields(before, after)
+        .into_iter()
+        .map(|field| {
+            let change = serde_json::json!({
+                "before": before.get(&field).cloned().unwrap_or(Value::Null),
+                "after": after.get(&field).cloned().unwrap_or(Value::Null),
+            });
+            (field, change)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::shared::secrets::SecretCipher;
+    use serde_json::json;
+    use sha2::Digest;
+
+    #[test]
+    fn test_redact_replaces_nested_secrets() {
+        let snapshot = json!({
+            "name": "router",
+            "config": {"version": "V2c", "community": "public"},
+            "keys": [{"key": "abc"}, {"key": null}],
+        });
+
+        let cipher = SecretCipher::from_hex(&"ab".repeat(32)).unwrap();
+        let keyed = |value: &str| Some(cipher.fingerprint(value));
+        let redacted = redact_with(&snapshot, &keyed);
+
+        assert_eq!(redacted["name"], "router");
+        assert_eq!(redacted["config"]["version"], "V2c");
+        let community = redacted["config"]["community"].as_str().unwrap();
+        assert!(community.starts_with("redacted:") && community.len() == 17);
+        // Not the unkeyed digest a dictionary could be checked against
+        assert_ne!(
+            &community[9..],
+            &hex::encode(sha2::Sha256::digest(b"\"public\""))[..8]
+        );
+        assert_ne!(redacted["keys"][0]["key"], "abc");
+        assert_eq!(redacted["keys"][1]["key"], Value::Null);
+        // Same secret, same fingerprint, so changes to it still show up in diffs
+        assert_eq!(redact_with(&snapshot, &keyed), redacted);
+
+        // Without a key there's nothing safe to fingerprint with
+        let unkeyed = redact_with(&snapshot, &|_| None);
+        assert_eq!(unkeyed["config"]["community"], "redacted");
+        assert_eq!(unkeyed["keys"][0]["key"], "redacted");
+    }
+
+    #[test]
+    fn test_changes_ignores_updated_at() {
+        let before = json!({"name": "a", "hidden": false, "updated_at": "2026-01-01T00:00:00Z"});
+        let after =
+            json!({"name": "b", "hidden": false, "tags": [], "updated_at": "2026-01-02T00:00:00Z"});
+
+        let changes = changes(&before, &after);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes["name"], json!({"before": "a", "after": "b"}));
+        assert_eq!(changes["tags"], json!({"before": null, "after": []}));
+    }
+}
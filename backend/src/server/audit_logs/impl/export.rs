@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+
+use crate::server::audit_logs::r#impl::base::AuditLogEntry;
+
+const CSV_COLUMNS: &[&str] = &[
+    "id",
+    "created_at",
+    "organization_id",
+    "network_id",
+    "category",
+    "operation",
+    "entity_type",
+    "entity_id",
+    "actor_type",
+    "actor_id",
+    "actor_user_id",
+    "actor_name",
+    "ip_address",
+    "user_agent",
+    "changes",
+    "snapshot",
+    "metadata",
+];
+
+/// File format of an audit log export
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum AuditLogExportFormat {
+    /// One row per entry. Snapshots, changes and metadata are JSON-encoded cells.
+    #[default]
+    Csv,
+    /// One JSON object per line, shaped like the entries returned by the API
+    Jsonl,
+}
+
+impl AuditLogExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Jsonl => "application/x-ndjson",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jsonl => "jsonl",
+        }
+    }
+
+    pub fn render(&self, entries: &[AuditLogEntry]) -> Result<String, serde_json::Error> {
+        match self {
+            Self::Csv => Ok(to_csv(entries)),
+            Self::Jsonl => to_jsonl(entries),
+        }
+    }
+}
+
+fn to_jsonl(entries: &[AuditLogEntry]) -> Result<String, serde_json::Error> {
+    let mut out = String::new();
+    for entry in entries {
+        out.push_str(&serde_json::to_string(entry)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+fn to_csv(entries: &[AuditLogEntry]) -> String {
+    let mut out = csv_row(CSV_COLUMNS.iter().map(|c| c.to_string()));
+
+    for entry in entries {
+        let base = &entry.base;
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        let json =
+            |value: Option<&serde_json::Value>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        out.push_str(&csv_row([
+            entry.id.to_string(),
+            entry.created_at.to_rfc3339(),
+            base.organization_id.to_string(),
+            optional(base.network_id.map(|id| id.to_string())),
+            base.category.to_string(),
+            base.operation.clone(),
+            optional(base.entity_type.map(|t| t.to_string())),
+            optional(base.entity_id.map(|id| id.to_string())),
+            base.actor_type.to_string(),
+            optional(base.actor_id.map(|id| id.to_string())),
+            optional(base.actor_user_id.map(|id| id.to_string())),
+            optional(base.actor_name.clone()),
+            optional(base.ip_address.map(|ip| ip.to_string())),
+            optional(base.user_agent.clone()),
+            json(base.changes.as_ref()),
+            json(base.snapshot.as_ref()),
+            json(Some(&base.metadata)),
+        ]));
+    }
+
+    out
+}
+
+/// RFC 4180 row: fields containing commas, quotes or line breaks are quoted, with quotes doubled
+fn csv_row(fields: impl IntoIterator<Item = String>) -> String {
+    let mut row = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::audit_logs::r#impl::base::AuditLogEntryBase;
+    use serde_json::json;
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        assert_eq!(
+            csv_row(["plain".into(), "a,b".into(), "say \"hi\"".into(), "".into()]),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\r\n"
+        );
+    }
+
+    #[test]
+    fn test_export_has_one_line_per_entry() {
+        let entries = vec![
+            AuditLogEntry::new(AuditLogEntryBase {
+                operation: "created".into(),
+                changes: Some(json!({"name": {"before": "a", "after": "b"}})),
+                metadata: json!({}),
+                ..Default::default()
+            }),
+            AuditLogEntry::new(AuditLogEntryBase {
+                operation: "login_success".into(),
+                user_agent: Some("curl/8.0, like Gecko".into()),
+                metadata: json!({}),
+                ..Default::default()
+            }),
+        ];
+
+        let csv = AuditLogExportFormat::Csv.render(&entries).unwrap();
+        let lines: Vec<&str> = csv.split("\r\n").filter(|l| !l.is_empty()).collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("id,created_at,"));
+        assert!(lines[2].contains("\"curl/8.0, like Gecko\""));
+
+        let jsonl = AuditLogExportFormat::Jsonl.render(&entries).unwrap();
+        let parsed: Vec<AuditLogEntry> = jsonl
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, entries);
+    }
+}
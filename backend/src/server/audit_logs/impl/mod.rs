@@ -0,0 +1,3 @@
+pub mod base;
+pub mod export;
+mod storage; // Storable impl for AuditLogEntry
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use serde_json::Value;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::server::{
+    audit_logs::r#impl::base::{
+        AuditActorType, AuditLogCategory, AuditLogEntry, AuditLogEntryBase,
+    },
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, Storable},
+    },
+};
+
+impl Storable for AuditLogEntry {
+    type BaseData = AuditLogEntryBase;
+
+    fn table_name() -> &'static str {
+        "audit_logs"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        AuditLogEntry::new(base)
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    network_id,
+                    category,
+                    operation,
+                    entity_type,
+                    entity_id,
+                    actor_type,
+                    actor_id,
+                    actor_user_id,
+                    actor_name,
+                    ip_address,
+                    user_agent,
+                    snapshot,
+                    changes,
+                    metadata,
+                },
+        } = self.clone();
+
+        // Stored the way SqlValue::EntityDiscriminant binds it, so the entity_type filter matches
+        let entity_type = entity_type.map(|t| serde_json::to_string(&t)).transpose()?;
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "network_id",
+                "category",
+                "operation",
+                "entity_type",
+                "entity_id",
+                "actor_type",
+                "actor_id",
+                "actor_user_id",
+                "actor_name",
+                "ip_address",
+                "user_agent",
+                "snapshot",
+                "changes",
+                "metadata",
+                "created_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::OptionalUuid(network_id),
+                SqlValue::String(category.to_string()),
+                SqlValue::String(operation),
+                SqlValue::OptionalString(entity_type),
+                SqlValue::OptionalUuid(entity_id),
+                SqlValue::String(actor_type.to_string()),
+                SqlValue::OptionalUuid(actor_id),
+                SqlValue::OptionalUuid(actor_user_id),
+                SqlValue::OptionalString(actor_name),
+                SqlValue::OptionalIpAddr(ip_address),
+                SqlValue::OptionalString(user_agent),
+                SqlValue::JsonValue(serde_json::to_value(snapshot)?),
+                SqlValue::JsonValue(serde_json::to_value(changes)?),
+                SqlValue::JsonValue(metadata),
+                SqlValue::Timestamp(created_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let category: String = row.get("category");
+        let actor_type: String = row.get("actor_type");
+        let entity_type: Option<EntityDiscriminants> = row
+            .get::<Option<String>, _>("entity_type")
+            .map(|t| serde_json::from_str(&t))
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize entity_type: {}", e))?;
+        let ip_address: Option<IpNetwork> = row
+            .try_get("ip_address")
+            .map_err(|e| anyhow::anyhow!("Failed to read ip_address: {}", e))?;
+        let json = |column: &str| {
+            row.get::<Option<Value>, _>(column)
+                .filter(|value| !value.is_null())
+        };
+
+        Ok(AuditLogEntry {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: AuditLogEntryBase {
+                organization_id: row.get("organization_id"),
+                network_id: row.get("network_id"),
+                category: AuditLogCategory::from_str(&category)
+                    .map_err(|e| anyhow::anyhow!("Failed to deserialize category: {}", e))?,
+                operation: row.get("operation"),
+                entity_type,
+                entity_id: row.get("entity_id"),
+                actor_type: AuditActorType::from_str(&actor_type)
+                    .map_err(|e| anyhow::anyhow!("Failed to deserialize actor_type: {}", e))?,
+                actor_id: row.get("actor_id"),
+                actor_user_id: row.get("actor_user_id"),
+                actor_name: row.get("actor_name"),
+                ip_address: ip_address.map(|network| network.ip()),
+                user_agent: row.get("user_agent"),
+                snapshot: json("snapshot"),
+                changes: json("changes"),
+                metadata: row.get("metadata"),
+            },
+        })
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::Result;
+use chrono::{Duration, Utc};
+use moka::future::Cache;
+use serde_json::Value;
+use std::sync::Arc;
+use strum::IntoDiscriminant;
+use uuid::Uuid;
+
+use crate::server::{
+    audit_logs::r#impl::base::{
+        AuditActorType, AuditLogCategory, AuditLogEntry, AuditLogEntryBase, changes, redact,
+    },
+    auth::middleware::{auth::AuthenticatedEntity, request_context::RequestContext},
+    organizations::r#impl::base::Organization,
+    shared::{
+        entities::Entity,
+        events::types::{AuthEvent, EntityEvent, EntityOperation, Event},
+        services::network_organizations::NetworkOrganizations,
+        storage::{
+            filter::StorableFilter,
+            generic::GenericPostgresStorage,
+            traits::{PaginatedResult, Storage},
+        },
+    },
+};
+
+const ORDER_NEWEST_FIRST: &str = "audit_logs.created_at DESC, audit_logs.id DESC";
+
+/// How long whether an organization's plan includes audit logs is trusted before it's looked up
+/// again, so every write request doesn't load its organization
+const PLAN_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+const PLAN_CACHE_CAPACITY: u64 = 10_000;
+
+/// Durable record of who did what in each organization, built from entity and auth events.
+/// Only organizations whose plan includes audit logs are recorded.
+pub struct AuditLogService {
+    storage: Arc<GenericPostgresStorage<AuditLogEntry>>,
+    organization_storage: Arc<GenericPostgresStorage<Organization>>,
+    network_organizations: Arc<NetworkOrganizations>,
+    /// Whether each organization's plan includes audit logs, as of the last lookup
+    plan_audit_logs: Cache<Uuid, bool>,
+}
+
+impl AuditLogService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<AuditLogEntry>>,
+        organization_storage: Arc<GenericPostgresStorage<Organization>>,
+        network_organizations: Arc<NetworkOrganizations>,
+    ) -> Self {
+        Self {
+            storage,
+            organization_storage,
+            network_organizations,
+            plan_audit_logs: Cache::builder()
+                .max_capacity(PLAN_CACHE_CAPACITY)
+                .time_to_live(PLAN_CACHE_TTL)
+                .build(),
+        }
+    }
+
+    pub async fn get_paginated(
+        &self,
+        filter: StorableFilter<AuditLogEntry>,
+    ) -> Result<PaginatedResult<AuditLogEntry>> {
+        self.storage.get_paginated(filter, ORDER_NEWEST_FIRST).await
+    }
+
+    pub async fn get_all(
+        &self,
+        filter: StorableFilter<AuditLogEntry>,
+    ) -> Result<Vec<AuditLogEntry>> {
+        self.storage
+            .get_all_ordered(filter, ORDER_NEWEST_FIRST)
+            .await
+    }
+
+    /// Record the action an event describes
+    pub async fn record(&self, event: &Event) -> Result<()> {
+        match event {
+            Event::Entity(entity_event) => self.record_entity_event(entity_event).await,
+            Event::Auth(auth_event) => self.record_auth_event(auth_event).await,
+            Event::Telemetry(_) => Ok(()),
+        }
+    }
+
+    /// Delete entries older than each organization's retention period
+    pub async fn purge_expired(&self) {
+        let organizations = match self
+            .organization_storage
+            .get_all(StorableFilter::new())
+            .await
+        {
+            Ok(organizations) => organizations,
+            Err(e) => {
+                tracing::error!("Failed to load organizations for audit log purge: {}", e);
+                return;
+            }
+        };
+
+        for organization in organizations {
+            let cutoff =
+                Utc::now() - Duration::days(organization.base.audit_log_retention_days.into());
+            let filter = StorableFilter::<AuditLogEntry>::new()
+                .organization_id(&organization.id)
+                .created_at_or_before(cutoff);
+
+            match self.storage.delete_by_filter(filter).await {
+                Ok(0) => {}
+                Ok(count) => {
+                    tracing::debug!(
+                        organization_id = %organization.id,
+                        "Purged {} expired audit log entries.",
+                        count
+                    );
+                }
+                Err(e) => {
+                    tracing::error!(
+                        organization_id = %organization.id,
+                        "Failed to purge expired audit log entries: {}",
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    async fn record_entity_event(&self, event: &EntityEvent) -> Result<()> {
+        // Heartbeats and last-used timestamps; nothing anyone did
+        let suppressed = event
+            .metadata
+            .get("suppress_logs")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if suppressed || matches!(event.entity_type, Entity::Topology(_) | Entity::Unknown) {
+            return Ok(());
+        }
+
+        let Some(organization_id) = self
+            .network_organizations
+            .resolve(event.organization_id, event.network_id)
+            .await?
+        else {
+            return Ok(());
+        };
+        if !self.plan_includes_audit_logs(organization_id).await? {
+            return Ok(());
+        }
+
+        let snapshot = redact(&event.entity_type.inner_json()?);
+
+        let changes = if event.operation == EntityOperation::Updated {
+            match self.latest_snapshot(event.entity_id).await? {
+                Some(before) => {
+                    let changes = changes(&before, &snapshot);
+                    // Saved without changing anything but updated_at
+                    if changes.is_empty() {
+                        return Ok(());
+                    }
+                    Some(Value::Object(changes))
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let context = RequestContext::current().unwrap_or_default();
+        let (actor_type, actor_id) = AuditActorType::of(&event.authentication);
+
+        self.storage
+            .create(&AuditLogEntry {
+                id: Uuid::new_v4(),
+                created_at: event.timestamp,
+                base: AuditLogEntryBase {
+                    organization_id,
+                    network_id: event.network_id,
+                    category: AuditLogCategory::Entity,
+                    operation: event.operation.to_string(),
+                    entity_type: Some(event.entity_type.discriminant()),
+                    entity_id: Some(event.entity_id),
+                    actor_type,
+                    actor_id,
+                    actor_user_id: event.authentication.user_id(),
+                    actor_name: actor_name(&event.authentication),
+                    ip_address: context.ip_address,
+                    user_agent: context.user_agent,
+                    snapshot: Some(snapshot),
+                    changes,
+                    metadata: event.metadata.clone(),
+                },
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_auth_event(&self, event: &AuthEvent) -> Result<()> {
+        // Failed logins for unknown emails don't belong to any organization
+        let Some(organization_id) = event.organization_id else {
+            return Ok(());
+        };
+        if !self.plan_includes_audit_logs(organization_id).await? {
+            return Ok(());
+        }
+
+        let (actor_type, actor_id) = match AuditActorType::of(&event.authentication) {
+            // Logins and registrations happen before there's a session to authenticate with
+            (AuditActorType::Anonymous, _) if event.user_id.is_some() => {
+                (AuditActorType::User, event.user_id)
+            }
+            actor => actor,
+        };
+
+        self.storage
+            .create(&AuditLogEntry {
+                id: Uuid::new_v4(),
+                created_at: event.timestamp,
+                base: AuditLogEntryBase {
+                    organization_id,
+                    network_id: None,
+                    category: AuditLogCategory::Auth,
+                    operation: event.operation.to_string(),
+                    entity_type: None,
+                    entity_id: None,
+                    actor_type,
+                    actor_id,
+                    actor_user_id: event.authentication.user_id().or(event.user_id),
+                    actor_name: actor_name(&event.authentication),
+                    ip_address: Some(event.ip_address),
+                    user_agent: event.user_agent.clone(),
+                    snapshot: None,
+                    changes: None,
+                    metadata: event.metadata.clone(),
+                },
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// The entity as of its latest entry, if it has one
+    async fn latest_snapshot(&self, entity_id: Uuid) -> Result<Option<Value>> {
+        let filter = StorableFilter::<AuditLogEntry>::new()
+            .uuid_column("entity_id", &entity_id)
+            .limit(1);
+
+        Ok(self
+            .storage
+            .get_all_ordered(filter, ORDER_NEWEST_FIRST)
+            .await?
+            .into_iter()
+            .next()
+            .and_then(|entry| entry.base.snapshot))
+    }
+
+    async fn plan_includes_audit_logs(&self, organization_id: Uuid) -> Result<bool> {
+        if let Some(included) = self.plan_audit_logs.get(&organization_id).await {
+            return Ok(included);
+        }
+
+        let included = self
+            .organization_storage
+            .get_by_id(&organization_id)
+            .await?
+            .is_some_and(|o| o.base.plan.unwrap_or_default().features().audit_logs);
+        self.plan_audit_logs.insert(organization_id, included).await;
+
+        Ok(included)
+    }
+}
+
+/// Email of a user, or name of an external service
+fn actor_name(authentication: &AuthenticatedEntity) -> Option<String> {
+    match authentication {
+        AuthenticatedEntity::ExternalService { name } => Some(name.clone()),
+        _ => authentication.email().map(|email| email.to_string()),
+    }
+}
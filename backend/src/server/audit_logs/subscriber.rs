@@ -0,0 +1,61 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use strum::IntoEnumIterator;
+
+use crate::server::{
+    audit_logs::service::AuditLogService,
+    shared::{
+        entities::EntityDiscriminants,
+        events::{
+            bus::{EventFilter, EventSubscriber},
+            types::{EntityOperation, Event},
+        },
+    },
+};
+
+#[async_trait]
+impl EventSubscriber for AuditLogService {
+    fn event_filter(&self) -> EventFilter {
+        let operations = Some(vec![
+            EntityOperation::Created,
+            EntityOperation::Updated,
+            EntityOperation::Deleted,
+            EntityOperation::DiscoveryStarted,
+            EntityOperation::DiscoveryCancelled,
+        ]);
+
+        EventFilter {
+            entity_operations: Some(
+                EntityDiscriminants::iter()
+                    .filter(|e| {
+                        !matches!(
+                            e,
+                            EntityDiscriminants::Topology | EntityDiscriminants::Unknown
+                        )
+                    })
+                    .map(|e| (e, operations.clone()))
+                    .collect(),
+            ),
+            auth_operations: None,
+            telemetry_operations: Some(vec![]),
+            network_ids: None,
+        }
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), Error> {
+        for event in events {
+            if let Err(e) = self.record(&event).await {
+                tracing::error!(event_id = %event.id(), error = %e, "Failed to record audit log entry");
+            }
+        }
+
+        Ok(())
+    }
+
+    // No debounce: events must be handled in the request's task to see its IP and user agent,
+    // and batches dedupe events by entity and operation, which would drop entries
+
+    fn name(&self) -> &str {
+        "audit_logs"
+    }
+}
@@ -248,6 +248,19 @@ impl From<User> for AuthenticatedEntity {
     }
 }
 
+/// Organization of a daemon. Daemons aren't bound to an organization directly; it's resolved
+/// through their network.
+pub async fn daemon_organization_id(state: &AppState, network_id: Uuid) -> Result<Uuid, ApiError> {
+    let network = state
+        .services
+        .network_service
+        .get_by_id(&network_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Network not found".to_string()))?;
+
+    Ok(network.base.organization_id)
+}
+
 /// Marker to cache failed auth attempts and prevent duplicate event publishing
 #[derive(Clone)]
 struct AuthAttemptFailed(ApiError);
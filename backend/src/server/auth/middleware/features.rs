@@ -142,6 +142,34 @@ impl FeatureCheck for ApiKeyFeature {
     }
 }
 
+#[derive(Default)]
+pub struct WebhooksFeature;
+
+#[async_trait]
+impl FeatureCheck for WebhooksFeature {
+    async fn check(&self, ctx: &FeatureCheckContext<'_>) -> FeatureCheckResult {
+        if !ctx.plan.features().webhooks {
+            return FeatureCheckResult::payment_required("Your plan does not include webhooks");
+        }
+
+        FeatureCheckResult::Allowed
+    }
+}
+
+#[derive(Default)]
+pub struct AuditLogsFeature;
+
+#[async_trait]
+impl FeatureCheck for AuditLogsFeature {
+    async fn check(&self, ctx: &FeatureCheckContext<'_>) -> FeatureCheckResult {
+        if !ctx.plan.features().audit_logs {
+            return FeatureCheckResult::payment_required("Your plan does not include audit logs");
+        }
+
+        FeatureCheckResult::Allowed
+    }
+}
+
 #[derive(Default)]
 pub struct CreateNetworkFeature;
 
@@ -6,3 +6,4 @@ pub mod fixture_capture;
 pub mod logging;
 pub mod permissions;
 pub mod rate_limit;
+pub mod request_context;
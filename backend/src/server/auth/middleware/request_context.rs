@@ -0,0 +1,50 @@
+use axum::{
+    extract::{FromRequestParts, Request},
+    middleware::Next,
+    response::Response,
+};
+use axum_client_ip::ClientIp;
+use std::net::IpAddr;
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+/// Where the request being handled came from. Entity events don't carry this, so subscribers
+/// that need it (the audit log) read it while handling events inline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestContext {
+    pub ip_address: Option<IpAddr>,
+    pub user_agent: Option<String>,
+}
+
+impl RequestContext {
+    /// Context of the request the current task is handling. None outside request handlers,
+    /// including tasks they spawn.
+    pub fn current() -> Option<Self> {
+        REQUEST_CONTEXT.try_with(|context| context.clone()).ok()
+    }
+}
+
+pub async fn request_context_middleware(request: Request, next: Next) -> Response {
+    let (mut parts, body) = request.into_parts();
+
+    let ip_address = ClientIp::from_request_parts(&mut parts, &())
+        .await
+        .ok()
+        .map(|ClientIp(ip)| ip);
+    let user_agent = parts
+        .headers
+        .get(axum::http::header::USER_AGENT)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string());
+
+    let context = RequestContext {
+        ip_address,
+        user_agent,
+    };
+
+    REQUEST_CONTEXT
+        .scope(context, next.run(Request::from_parts(parts, body)))
+        .await
+}
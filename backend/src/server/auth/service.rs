@@ -9,7 +9,7 @@ use crate::server::{
     },
     email::traits::EmailService,
     organizations::{
-        r#impl::base::{Organization, OrganizationBase},
+        r#impl::base::{DEFAULT_AUDIT_LOG_RETENTION_DAYS, Organization, OrganizationBase},
         service::OrganizationService,
     },
     shared::{
@@ -230,6 +230,7 @@ impl AuthService {
                         plan,
                         plan_status: None,
                         onboarding,
+                        audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
                     }),
                     AuthenticatedEntity::System,
                 )
@@ -63,7 +63,7 @@ impl HasId for Feature {
 
 impl Feature {
     pub fn is_coming_soon(&self) -> bool {
-        matches!(self, Feature::Webhooks | Feature::AuditLogs)
+        false
     }
 }
 
@@ -184,7 +184,8 @@ mod tests {
     #[test]
     fn test_feature_ids_match_billing_plan_features_fields() {
         // Get all Feature IDs
-        let feature_ids: HashSet<&str> = Feature::iter().map(|f| f.id()).collect();
+        let features_list: Vec<Feature> = Feature::iter().collect();
+        let feature_ids: HashSet<&str> = features_list.iter().map(|f| f.id()).collect();
 
         // Get all keys from BillingPlanFeatures by serializing an instance
         let features = BillingPlan::default().features();
@@ -19,6 +19,7 @@ use tower_sessions::SessionManagerLayer;
 use tower_sessions_sqlx_store::PostgresStore;
 use utoipa::ToSchema;
 
+use crate::server::shared::secrets::{self, SecretCipher};
 use crate::server::shared::storage::factory::StorageFactory;
 
 #[derive(Parser)]
@@ -100,6 +101,10 @@ pub struct ServerCli {
 
     #[arg(long)]
     pub metrics_token: Option<String>,
+
+    /// Key used to encrypt credentials stored in the database, as 64 hex characters
+    #[arg(long)]
+    pub secret_key: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -136,6 +141,9 @@ pub struct ServerConfig {
     // Metrics
     pub metrics_token: Option<String>,
 
+    // Encrypts credentials stored in the database, ie SNMP communities and webhook secrets
+    pub secret_key: Option<String>,
+
     // External service IP restrictions
     // Maps service name (lowercase) to list of allowed IPs/CIDRs
     // Populated from SCANOPY_EXTERNAL_SERVICE_<NAME>_ALLOWED_IPS env vars
@@ -193,6 +201,7 @@ impl Default for ServerConfig {
             posthog_key: None,
             enforce_billing_for_testing: false,
             metrics_token: None,
+            secret_key: None,
             external_service_allowed_ips: HashMap::new(),
         }
     }
@@ -278,6 +287,9 @@ impl ServerConfig {
         if let Some(metrics_token) = cli_args.metrics_token {
             figment = figment.merge(("metrics_token", metrics_token));
         }
+        if let Some(secret_key) = cli_args.secret_key {
+            figment = figment.merge(("secret_key", secret_key));
+        }
 
         let mut config: ServerConfig = figment
             .extract()
@@ -339,6 +351,13 @@ pub struct AppState {
 
 impl AppState {
     pub async fn new(config: ServerConfig) -> Result<Arc<Self>, Error> {
+        match config.secret_key.as_deref().filter(|k| !k.is_empty()) {
+            Some(key) => secrets::install(SecretCipher::from_hex(key)?),
+            None => tracing::warn!(
+                "SCANOPY_SECRET_KEY is not set; SNMP credentials and webhooks can't be stored until it is"
+            ),
+        }
+
         let storage =
             StorageFactory::new(&config.database_url(), config.use_secure_session_cookies).await?;
         let services = ServiceFactory::new(&storage, Some(config.clone())).await?;
@@ -0,0 +1,424 @@
+use crate::server::auth::middleware::auth::{AuthenticatedEntity, daemon_organization_id};
+use crate::server::auth::middleware::permissions::{
+    Admin, Authorized, IsDaemon, Member, Or, Viewer,
+};
+use crate::server::custom_service_definitions::r#impl::{
+    base::{CustomServiceDefinition, CustomServiceDefinitionBase},
+    spec::{DefinitionFormat, ServiceDefinitionSpec},
+};
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{create_handler, delete_handler, update_handler};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Storable, Storage};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+// ============================================================================
+// Custom Service Definition Ordering
+// ============================================================================
+
+/// Fields that custom service definitions can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomServiceDefinitionOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    Category,
+    UpdatedAt,
+}
+
+impl OrderField for CustomServiceDefinitionOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "custom_service_definitions.created_at",
+            Self::Name => "custom_service_definitions.name",
+            Self::Category => "custom_service_definitions.category",
+            Self::UpdatedAt => "custom_service_definitions.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Custom Service Definition Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering custom service definitions.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct CustomServiceDefinitionFilterQuery {
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<CustomServiceDefinitionOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<CustomServiceDefinitionOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl CustomServiceDefinitionFilterQuery {
+    /// Build the ORDER BY clause.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<CustomServiceDefinition>,
+    ) -> (StorableFilter<CustomServiceDefinition>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "custom_service_definitions.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for CustomServiceDefinitionFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for most CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(
+        CustomServiceDefinition,
+        "custom_service_definitions",
+        "custom_service_definition"
+    );
+    crate::crud_bulk_delete_handler!(CustomServiceDefinition, "custom_service_definitions");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(
+            get_all_custom_service_definitions,
+            create_custom_service_definition
+        ))
+        .routes(routes!(import_custom_service_definition))
+        .routes(routes!(
+            generated::get_by_id,
+            update_custom_service_definition,
+            delete_custom_service_definition
+        ))
+        .routes(routes!(generated::bulk_delete))
+}
+
+/// List all custom service definitions
+///
+/// Returns all custom service definitions in the authenticated user's organization.
+/// Supports pagination via `limit` and `offset` query parameters,
+/// and ordering via `group_by`, `order_by`, and `order_direction`.
+///
+/// Daemons receive every definition for their network's organization, unpaginated.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "custom_service_definitions",
+    params(CustomServiceDefinitionFilterQuery),
+    responses(
+        (status = 200, description = "List of custom service definitions", body = PaginatedApiResponse<CustomServiceDefinition>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_custom_service_definitions(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Or<Viewer, IsDaemon>>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        CustomServiceDefinitionFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth.organization_id();
+
+    match auth.into_entity() {
+        AuthenticatedEntity::Daemon { network_id, .. } => {
+            let organization_id = daemon_organization_id(&state, network_id).await?;
+            let filter =
+                StorableFilter::<CustomServiceDefinition>::new().organization_id(&organization_id);
+
+            let result = state
+                .services
+                .custom_service_definition_service
+                .get_all(filter)
+                .await?;
+            let total_count = result.len() as u64;
+
+            Ok(Json(PaginatedApiResponse::success(
+                result,
+                total_count,
+                0,
+                0,
+            )))
+        }
+        _ => {
+            let organization_id = organization_id
+                .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+            let base_filter =
+                StorableFilter::<CustomServiceDefinition>::new().organization_id(&organization_id);
+
+            // Apply pagination
+            let pagination = query.pagination();
+            let filter = pagination.apply_to_filter(base_filter);
+
+            // Apply ordering
+            let (filter, order_by) = query.apply_ordering(filter);
+
+            let result = state
+                .services
+                .custom_service_definition_service
+                .storage()
+                .get_paginated(filter, &order_by)
+                .await?;
+
+            // Get effective pagination values for response metadata
+            let limit = pagination.effective_limit().unwrap_or(0);
+            let offset = pagination.effective_offset();
+
+            Ok(Json(PaginatedApiResponse::success(
+                result.items,
+                result.total_count,
+                limit,
+                offset,
+            )))
+        }
+    }
+}
+
+/// Create a new custom service definition
+///
+/// Creates a declarative service definition scoped to your organization. Daemons pick it up
+/// at the start of their next discovery session and match it alongside the built-in definitions.
+///
+/// ### Validation
+///
+/// - Name must be 1-40 characters and unique within your organization
+/// - Category must not be `Scanopy` or `OpenPorts`
+/// - Pattern must contain at least one port, endpoint, header, banner or MAC vendor check that isn't negated
+/// - Ports must be written as `<number>/<protocol>`, ie `8443/tcp`
+/// - Endpoint paths must start with `/`; status ranges must be within 100..600
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "custom_service_definitions",
+    request_body = CustomServiceDefinition,
+    responses(
+        (status = 200, description = "Custom service definition created successfully", body = ApiResponse<CustomServiceDefinition>),
+        (status = 400, description = "Validation error in definition or pattern", body = ApiErrorResponse),
+        (status = 409, description = "Definition name already exists in this organization", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn create_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Json(mut definition): Json<CustomServiceDefinition>,
+) -> ApiResult<Json<ApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+    definition.base.organization_id = organization_id;
+
+    ensure_unique_name(&state, organization_id, &definition.base.spec.name, None).await?;
+
+    create_handler::<CustomServiceDefinition>(
+        state,
+        auth.into_permission::<Member>(),
+        Json(definition),
+    )
+    .await
+}
+
+/// Request body for importing a definition file
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ImportServiceDefinitionRequest {
+    /// Encoding of `source`
+    pub format: DefinitionFormat,
+    /// Definition file contents
+    pub source: String,
+}
+
+/// Import a custom service definition file
+///
+/// Parses a JSON, TOML or YAML definition file and creates it as a custom service definition
+/// in your organization. The file contains the same fields as the create request body, minus
+/// the organization.
+///
+/// ### Validation
+///
+/// - File must parse in the given format
+/// - Same rules as creating a definition
+#[utoipa::path(
+    post,
+    path = "/import",
+    tag = "custom_service_definitions",
+    request_body = ImportServiceDefinitionRequest,
+    responses(
+        (status = 200, description = "Custom service definition imported successfully", body = ApiResponse<CustomServiceDefinition>),
+        (status = 400, description = "Definition file could not be parsed or failed validation", body = ApiErrorResponse),
+        (status = 409, description = "Definition name already exists in this organization", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn import_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Json(request): Json<ImportServiceDefinitionRequest>,
+) -> ApiResult<Json<ApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let spec = ServiceDefinitionSpec::parse(request.format, &request.source).map_err(|e| {
+        ApiError::bad_request(&format!(
+            "Failed to parse {} definition: {}",
+            request.format, e
+        ))
+    })?;
+
+    ensure_unique_name(&state, organization_id, &spec.name, None).await?;
+
+    let definition = CustomServiceDefinition::new(CustomServiceDefinitionBase {
+        spec,
+        organization_id,
+    });
+
+    create_handler::<CustomServiceDefinition>(
+        state,
+        auth.into_permission::<Member>(),
+        Json(definition),
+    )
+    .await
+}
+
+/// Update a custom service definition
+///
+/// Daemons pick up the change at the start of their next discovery session.
+///
+/// ### Validation
+///
+/// - Same rules as creating a definition; the name must not be used by another definition
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "custom_service_definitions",
+    params(("id" = Uuid, Path, description = "CustomServiceDefinition ID")),
+    request_body = CustomServiceDefinition,
+    responses(
+        (status = 200, description = "Custom service definition updated", body = ApiResponse<CustomServiceDefinition>),
+        (status = 400, description = "Validation error in definition or pattern", body = ApiErrorResponse),
+        (status = 404, description = "Custom service definition not found", body = ApiErrorResponse),
+        (status = 409, description = "Definition name already exists in this organization", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn update_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+    Json(definition): Json<CustomServiceDefinition>,
+) -> ApiResult<Json<ApiResponse<CustomServiceDefinition>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    ensure_unique_name(
+        &state,
+        organization_id,
+        &definition.base.spec.name,
+        Some(path.0),
+    )
+    .await?;
+
+    update_handler::<CustomServiceDefinition>(
+        state,
+        auth.into_permission::<Member>(),
+        path,
+        Json(definition),
+    )
+    .await
+}
+
+/// Delete a custom service definition
+///
+/// Daemons stop matching it at the start of their next discovery session.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = "custom_service_definitions",
+    params(("id" = Uuid, Path, description = "CustomServiceDefinition ID")),
+    responses(
+        (status = 200, description = "Custom service definition deleted", body = EmptyApiResponse),
+        (status = 404, description = "Custom service definition not found", body = ApiErrorResponse),
+    ),
+     security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn delete_custom_service_definition(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    delete_handler::<CustomServiceDefinition>(state, auth.into_permission::<Member>(), path).await
+}
+
+/// Names must be unique within an organization. `except` is the definition being updated,
+/// which may keep its own name.
+async fn ensure_unique_name(
+    state: &AppState,
+    organization_id: Uuid,
+    name: &str,
+    except: Option<Uuid>,
+) -> Result<(), ApiError> {
+    let name_filter = StorableFilter::<CustomServiceDefinition>::new()
+        .organization_id(&organization_id)
+        .name(name.to_string());
+
+    if let Some(existing_with_name) = state
+        .services
+        .custom_service_definition_service
+        .get_one(name_filter)
+        .await?
+        .filter(|existing| Some(existing.id) != except)
+    {
+        return Err(ApiError::conflict(&format!(
+            "Custom service definition names must be unique; a definition named \"{}\" already exists",
+            existing_with_name.base.spec.name
+        )));
+    }
+
+    Ok(())
+}
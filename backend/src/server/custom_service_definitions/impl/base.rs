@@ -0,0 +1,58 @@
+use std::fmt::Display;
+
+use crate::server::{
+    custom_service_definitions::r#impl::spec::ServiceDefinitionSpec,
+    shared::entities::ChangeTriggersTopologyStaleness,
+};
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(
+    Debug, Clone, Validate, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema, Default,
+)]
+pub struct CustomServiceDefinitionBase {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub spec: ServiceDefinitionSpec,
+    pub organization_id: Uuid,
+}
+
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, Default, ToSchema, Validate,
+)]
+#[schema(example = crate::server::shared::types::examples::custom_service_definition)]
+pub struct CustomServiceDefinition {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: CustomServiceDefinitionBase,
+}
+
+impl ChangeTriggersTopologyStaleness<CustomServiceDefinition> for CustomServiceDefinition {
+    fn triggers_staleness(&self, _other: Option<CustomServiceDefinition>) -> bool {
+        false
+    }
+}
+
+impl Display for CustomServiceDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Custom Service Definition {}: {}",
+            self.base.spec.name, self.id
+        )
+    }
+}
@@ -0,0 +1,122 @@
+use std::{hash::Hash, sync::Arc};
+
+use uuid::Uuid;
+
+use crate::server::{
+    custom_service_definitions::r#impl::{base::CustomServiceDefinition, spec::PatternSpec},
+    services::r#impl::{
+        categories::ServiceCategory, definitions::ServiceDefinition, patterns::Pattern,
+    },
+};
+
+/// Registry id for an organization's custom definition. Names are only unique within an
+/// organization, so the entity id is used instead.
+pub fn custom_definition_id(id: &Uuid) -> String {
+    format!("custom:{}", id)
+}
+
+/// Build registry entries for stored definitions, skipping (and logging) any that no longer
+/// validate.
+pub fn to_runtime_definitions(
+    definitions: &[CustomServiceDefinition],
+) -> Vec<Box<dyn ServiceDefinition>> {
+    definitions
+        .iter()
+        .filter_map(|d| {
+            RuntimeServiceDefinition::try_from(d)
+                .inspect_err(|e| {
+                    tracing::warn!(
+                        definition_id = %d.id,
+                        error = %e,
+                        "Skipping invalid custom service definition"
+                    )
+                })
+                .ok()
+        })
+        .map(|d| Box::new(d) as Box<dyn ServiceDefinition>)
+        .collect()
+}
+
+/// A `ServiceDefinition` built from a stored declarative definition, matched through the same
+/// code path as the compiled-in definitions. Clones share the definition's strings and pattern.
+#[derive(Clone)]
+pub struct RuntimeServiceDefinition {
+    id: Arc<str>,
+    name: Arc<str>,
+    description: Arc<str>,
+    category: ServiceCategory,
+    /// Validated when the definition is built, and converted to a `Pattern` borrowing it on use
+    pattern: Arc<PatternSpec>,
+    is_generic: bool,
+    logo_url: Arc<str>,
+    logo_needs_white_background: bool,
+}
+
+impl TryFrom<&CustomServiceDefinition> for RuntimeServiceDefinition {
+    type Error = String;
+
+    fn try_from(definition: &CustomServiceDefinition) -> Result<Self, Self::Error> {
+        let spec = &definition.base.spec;
+        spec.pattern.to_pattern()?;
+
+        Ok(Self {
+            id: custom_definition_id(&definition.id).into(),
+            name: spec.name.as_str().into(),
+            description: spec.description.as_str().into(),
+            category: spec.category,
+            pattern: Arc::new(spec.pattern.clone()),
+            is_generic: spec.is_generic,
+            logo_url: spec.logo_url.as_str().into(),
+            logo_needs_white_background: spec.logo_needs_white_background,
+        })
+    }
+}
+
+impl PartialEq for RuntimeServiceDefinition {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for RuntimeServiceDefinition {}
+
+impl Hash for RuntimeServiceDefinition {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl ServiceDefinition for RuntimeServiceDefinition {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn category(&self) -> ServiceCategory {
+        self.category
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        // Validated in `try_from`, so this only falls back if validation rules tighten
+        self.pattern.to_pattern().unwrap_or(Pattern::None)
+    }
+
+    fn is_generic(&self) -> bool {
+        self.is_generic
+    }
+
+    fn logo_url(&self) -> &str {
+        &self.logo_url
+    }
+
+    fn logo_needs_white_background(&self) -> bool {
+        self.logo_needs_white_background
+    }
+
+    fn definition_id(&self) -> &str {
+        &self.id
+    }
+}
@@ -0,0 +1,22 @@
+use crate::server::{
+    config::AppState,
+    custom_service_definitions::{
+        handlers::CustomServiceDefinitionFilterQuery, r#impl::base::CustomServiceDefinition,
+        service::CustomServiceDefinitionService,
+    },
+    shared::handlers::traits::CrudHandlers,
+};
+
+impl CrudHandlers for CustomServiceDefinition {
+    type Service = CustomServiceDefinitionService;
+    type FilterQuery = CustomServiceDefinitionFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.custom_service_definition_service
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        validator::Validate::validate(self).map_err(|e| e.to_string())?;
+        self.base.spec.check()
+    }
+}
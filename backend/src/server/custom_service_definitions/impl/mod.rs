@@ -0,0 +1,5 @@
+pub mod base;
+pub mod definition;
+pub mod handlers;
+pub mod spec;
+pub mod storage;
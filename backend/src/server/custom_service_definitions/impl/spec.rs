@@ -0,0 +1,438 @@
+use std::{ops::Range, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::server::{
+    ports::r#impl::base::PortType,
+    services::r#impl::{
+        categories::ServiceCategory, mdns::normalize_service_type, patterns::Pattern,
+    },
+};
+
+/// Deepest nesting of AnyOf / AllOf / Not accepted in a declarative pattern
+const MAX_PATTERN_DEPTH: usize = 8;
+
+/// Declarative service definition, as stored per organization and as accepted in
+/// JSON / TOML / YAML definition files.
+#[derive(Debug, Clone, Validate, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct ServiceDefinitionSpec {
+    #[validate(length(
+        min = 1,
+        max = 40,
+        message = "Service name must be between 1 and 40 characters"
+    ))]
+    pub name: String,
+    #[validate(length(max = 100, message = "Description must be at most 100 characters"))]
+    #[serde(default)]
+    pub description: String,
+    pub category: ServiceCategory,
+    pub pattern: PatternSpec,
+    /// If service is not associated with a particular brand or vendor
+    #[serde(default)]
+    pub is_generic: bool,
+    /// URL of icon, or static path if serving from /logos
+    #[serde(default)]
+    pub logo_url: String,
+    #[serde(default)]
+    pub logo_needs_white_background: bool,
+}
+
+impl Default for ServiceDefinitionSpec {
+    fn default() -> Self {
+        Self {
+            name: "New Service".to_string(),
+            description: String::new(),
+            category: ServiceCategory::Custom,
+            pattern: PatternSpec::AnyOf { patterns: vec![] },
+            is_generic: false,
+            logo_url: String::new(),
+            logo_needs_white_background: false,
+        }
+    }
+}
+
+impl ServiceDefinitionSpec {
+    /// Parse a definition file in the given format
+    pub fn parse(format: DefinitionFormat, source: &str) -> Result<Self, String> {
+        match format {
+            DefinitionFormat::Json => serde_json::from_str(source).map_err(|e| e.to_string()),
+            DefinitionFormat::Toml => toml::from_str(source).map_err(|e| e.to_string()),
+            DefinitionFormat::Yaml => serde_norway::from_str(source).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Check everything the validator derive can't express: the category and the pattern tree
+    pub fn check(&self) -> Result<(), String> {
+        if matches!(
+            self.category,
+            ServiceCategory::Scanopy | ServiceCategory::OpenPorts
+        ) {
+            return Err(format!(
+                "Category {} is reserved for built-in services",
+                self.category
+            ));
+        }
+
+        self.pattern.to_pattern().map(|_| ())
+    }
+}
+
+/// Supported encodings for definition files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DefinitionFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+/// Declarative form of a match `Pattern`. Ports are written as `<number>/<protocol>`, ie
+/// `8443/tcp`.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[schema(no_recursion)]
+pub enum PatternSpec {
+    /// Match any of the listed patterns
+    AnyOf { patterns: Vec<PatternSpec> },
+    /// Must match all of the listed patterns
+    AllOf { patterns: Vec<PatternSpec> },
+    /// Inverse of pattern
+    Not { pattern: Box<PatternSpec> },
+    /// Port is open on the host
+    Port { port: String },
+    /// Response body from an HTTP endpoint contains a string
+    Endpoint {
+        port: String,
+        path: String,
+        contains: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        status: Option<StatusRange>,
+    },
+    /// Response from the host has a header containing a value
+    Header {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        port: Option<String>,
+        header: String,
+        value: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        status: Option<StatusRange>,
+    },
+    /// Banner read from a port, or any open TCP port if none is given, contains a string
+    Banner {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        port: Option<String>,
+        contains: String,
+    },
+    /// Vendor derived from the MAC address matches
+    MacVendor { vendor: String },
+    /// Host advertises a service type over mDNS, ie `_googlecast._tcp`
+    MdnsService { service_type: String },
+    /// Host published a UPnP device description whose manufacturer, and optionally model name,
+    /// contain a string
+    UpnpDevice {
+        manufacturer: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+    },
+}
+
+/// HTTP status code range, end exclusive
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct StatusRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+impl StatusRange {
+    fn to_range(self) -> Result<Range<u16>, String> {
+        if self.start < 100 || self.end > 600 || self.start >= self.end {
+            return Err(format!(
+                "Invalid status range {}..{}: must be within 100..600 with start < end",
+                self.start, self.end
+            ));
+        }
+        Ok(self.start..self.end)
+    }
+}
+
+impl PatternSpec {
+    /// Convert into a `Pattern` borrowing the spec's strings, validating as we go
+    pub fn to_pattern(&self) -> Result<Pattern<'_>, String> {
+        if !self.has_positive_match() {
+            return Err(
+                "Pattern must include a port, endpoint, header, banner, MAC vendor, mDNS or UPnP check that isn't negated"
+                    .to_string(),
+            );
+        }
+        self.convert(0)
+    }
+
+    fn convert(&self, depth: usize) -> Result<Pattern<'_>, String> {
+        if depth > MAX_PATTERN_DEPTH {
+            return Err(format!(
+                "Pattern is nested more than {} levels deep",
+                MAX_PATTERN_DEPTH
+            ));
+        }
+
+        match self {
+            PatternSpec::AnyOf { patterns } | PatternSpec::AllOf { patterns } => {
+                if patterns.is_empty() {
+                    return Err("any_of / all_of must contain at least one pattern".to_string());
+                }
+                let converted = patterns
+                    .iter()
+                    .map(|p| p.convert(depth + 1))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(match self {
+                    PatternSpec::AnyOf { .. } => Pattern::AnyOf(converted),
+                    _ => Pattern::AllOf(converted),
+                })
+            }
+            PatternSpec::Not { pattern } => Ok(Pattern::Not(Box::new(pattern.convert(depth + 1)?))),
+            PatternSpec::Port { port } => Ok(Pattern::Port(parse_port(port)?)),
+            PatternSpec::Endpoint {
+                port,
+                path,
+                contains,
+                status,
+            } => {
+                if !path.starts_with('/') {
+                    return Err(format!("Endpoint path \"{}\" must start with /", path));
+                }
+                if contains.trim().is_empty() {
+                    return Err("Endpoint match string must not be empty".to_string());
+                }
+                Ok(Pattern::Endpoint(
+                    parse_port(port)?,
+                    path,
+                    contains,
+                    status.map(StatusRange::to_range).transpose()?,
+                ))
+            }
+            PatternSpec::Header {
+                port,
+                header,
+                value,
+                status,
+            } => {
+                if header.trim().is_empty() || value.trim().is_empty() {
+                    return Err("Header name and value must not be empty".to_string());
+                }
+                Ok(Pattern::Header(
+                    port.as_deref().map(parse_port).transpose()?,
+                    header,
+                    value,
+                    status.map(StatusRange::to_range).transpose()?,
+                ))
+            }
+            PatternSpec::Banner { port, contains } => {
+                if contains.trim().is_empty() {
+                    return Err("Banner match string must not be empty".to_string());
+                }
+                Ok(Pattern::Banner(
+                    port.as_deref().map(parse_port).transpose()?,
+                    contains,
+                ))
+            }
+            PatternSpec::MacVendor { vendor } => {
+                if vendor.trim().is_empty() {
+                    return Err("MAC vendor must not be empty".to_string());
+                }
+                Ok(Pattern::MacVendor(vendor))
+            }
+            PatternSpec::MdnsService { service_type } => {
+                let normalized = normalize_service_type(service_type);
+                let valid = normalized.starts_with('_')
+                    && (normalized.ends_with("._tcp") || normalized.ends_with("._udp"));
+                if !valid {
+                    return Err(format!(
+                        "Invalid mDNS service type \"{}\", expected ie _googlecast._tcp",
+                        service_type
+                    ));
+                }
+                // Advertisements are compared after normalizing, so the spec's own string will do
+                Ok(Pattern::MdnsService(service_type))
+            }
+            PatternSpec::UpnpDevice {
+                manufacturer,
+                model,
+            } => {
+                if manufacturer.trim().is_empty()
+                    || model.as_ref().is_some_and(|m| m.trim().is_empty())
+                {
+                    return Err("UPnP manufacturer and model must not be empty".to_string());
+                }
+                Ok(Pattern::UpnpDevice(manufacturer, model.as_deref()))
+            }
+        }
+    }
+
+    /// A pattern made only of negations would match nearly every host
+    fn has_positive_match(&self) -> bool {
+        match self {
+            PatternSpec::AnyOf { patterns } => patterns.iter().all(|p| p.has_positive_match()),
+            PatternSpec::AllOf { patterns } => patterns.iter().any(|p| p.has_positive_match()),
+            PatternSpec::Not { .. } => false,
+            _ => true,
+        }
+    }
+}
+
+fn parse_port(port: &str) -> Result<PortType, String> {
+    let port_type = PortType::from_str(port)
+        .map_err(|_| format!("Invalid port \"{}\", expected ie 8080/tcp", port))?;
+
+    if port_type.number() == 0 {
+        return Err(format!(
+            "Invalid port \"{}\", port number must be 1-65535",
+            port
+        ));
+    }
+
+    Ok(port_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::ports::r#impl::base::TransportProtocol;
+
+    #[test]
+    fn test_parse_toml_definition() {
+        let source = r#"
+name = "Acme Console"
+description = "Acme appliance management console"
+category = "Monitoring"
+
+[pattern]
+type = "all_of"
+
+[[pattern.patterns]]
+type = "port"
+port = "8443/tcp"
+
+[[pattern.patterns]]
+type = "endpoint"
+port = "8443/tcp"
+path = "/login"
+contains = "Acme"
+"#;
+
+        let spec = ServiceDefinitionSpec::parse(DefinitionFormat::Toml, source).unwrap();
+        spec.check().unwrap();
+
+        let port = PortType::new(8443, TransportProtocol::Tcp);
+        assert_eq!(
+            spec.pattern.to_pattern().unwrap(),
+            Pattern::AllOf(vec![
+                Pattern::Port(port),
+                Pattern::Endpoint(port, "/login", "Acme", None),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml_and_json_definitions_agree() {
+        let yaml = r#"
+name: Acme Sensor
+category: IoT
+pattern:
+  type: any_of
+  patterns:
+    - type: mac_vendor
+      vendor: Acme Corp
+    - type: header
+      header: Server
+      value: acme-httpd
+      status: { start: 200, end: 500 }
+"#;
+        let json = r#"{
+            "name": "Acme Sensor",
+            "category": "IoT",
+            "pattern": {
+                "type": "any_of",
+                "patterns": [
+                    { "type": "mac_vendor", "vendor": "Acme Corp" },
+                    { "type": "header", "header": "Server", "value": "acme-httpd", "status": { "start": 200, "end": 500 } }
+                ]
+            }
+        }"#;
+
+        let from_yaml = ServiceDefinitionSpec::parse(DefinitionFormat::Yaml, yaml).unwrap();
+        let from_json = ServiceDefinitionSpec::parse(DefinitionFormat::Json, json).unwrap();
+
+        assert_eq!(from_yaml, from_json);
+        from_yaml.check().unwrap();
+    }
+
+    #[test]
+    fn test_invalid_patterns_are_rejected() {
+        let only_negated = PatternSpec::Not {
+            pattern: Box::new(PatternSpec::Port {
+                port: "22/tcp".to_string(),
+            }),
+        };
+        assert!(only_negated.to_pattern().is_err());
+
+        let bad_port = PatternSpec::Port {
+            port: "http".to_string(),
+        };
+        assert!(bad_port.to_pattern().is_err());
+
+        let bad_path = PatternSpec::Endpoint {
+            port: "80/tcp".to_string(),
+            path: "login".to_string(),
+            contains: "Acme".to_string(),
+            status: None,
+        };
+        assert!(bad_path.to_pattern().is_err());
+
+        let bad_status = PatternSpec::Endpoint {
+            port: "80/tcp".to_string(),
+            path: "/".to_string(),
+            contains: "Acme".to_string(),
+            status: Some(StatusRange {
+                start: 400,
+                end: 200,
+            }),
+        };
+        assert!(bad_status.to_pattern().is_err());
+
+        let empty_banner = PatternSpec::Banner {
+            port: Some("22/tcp".to_string()),
+            contains: " ".to_string(),
+        };
+        assert!(empty_banner.to_pattern().is_err());
+
+        let bad_mdns_type = PatternSpec::MdnsService {
+            service_type: "googlecast".to_string(),
+        };
+        assert!(bad_mdns_type.to_pattern().is_err());
+
+        let empty_upnp_model = PatternSpec::UpnpDevice {
+            manufacturer: "Roku".to_string(),
+            model: Some("".to_string()),
+        };
+        assert!(empty_upnp_model.to_pattern().is_err());
+
+        let empty = PatternSpec::AllOf { patterns: vec![] };
+        assert!(empty.to_pattern().is_err());
+    }
+
+    #[test]
+    fn test_reserved_categories_are_rejected() {
+        let spec = ServiceDefinitionSpec {
+            category: ServiceCategory::OpenPorts,
+            pattern: PatternSpec::Port {
+                port: "9000/tcp".to_string(),
+            },
+            ..Default::default()
+        };
+        assert!(spec.check().is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    custom_service_definitions::r#impl::{
+        base::{CustomServiceDefinition, CustomServiceDefinitionBase},
+        spec::ServiceDefinitionSpec,
+    },
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+impl Storable for CustomServiceDefinition {
+    type BaseData = CustomServiceDefinitionBase;
+
+    fn table_name() -> &'static str {
+        "custom_service_definitions"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    spec:
+                        ServiceDefinitionSpec {
+                            name,
+                            description,
+                            category,
+                            pattern,
+                            is_generic,
+                            logo_url,
+                            logo_needs_white_background,
+                        },
+                    organization_id,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "description",
+                "category",
+                "pattern",
+                "is_generic",
+                "logo_url",
+                "logo_needs_white_background",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::String(description),
+                SqlValue::String(category.to_string()),
+                SqlValue::JsonValue(serde_json::to_value(&pattern)?),
+                SqlValue::Bool(is_generic),
+                SqlValue::String(logo_url),
+                SqlValue::Bool(logo_needs_white_background),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let pattern_value: serde_json::Value = row.get("pattern");
+        let category_value = serde_json::Value::String(row.get("category"));
+
+        Ok(CustomServiceDefinition {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: CustomServiceDefinitionBase {
+                spec: ServiceDefinitionSpec {
+                    name: row.get("name"),
+                    description: row.get("description"),
+                    category: serde_json::from_value(category_value)?,
+                    pattern: serde_json::from_value(pattern_value)?,
+                    is_generic: row.get("is_generic"),
+                    logo_url: row.get("logo_url"),
+                    logo_needs_white_background: row.get("logo_needs_white_background"),
+                },
+                organization_id: row.get("organization_id"),
+            },
+        })
+    }
+}
+
+impl Entity for CustomServiceDefinition {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::CustomServiceDefinition
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "custom_service_definition"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "custom_service_definitions"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
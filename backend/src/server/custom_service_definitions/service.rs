@@ -0,0 +1,80 @@
+use crate::server::{
+    custom_service_definitions::r#impl::{
+        base::CustomServiceDefinition, definition::to_runtime_definitions,
+    },
+    services::{definitions::ServiceDefinitionRegistry, r#impl::definitions::ServiceDefinition},
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage},
+    },
+};
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CustomServiceDefinitionService {
+    storage: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<CustomServiceDefinition> for CustomServiceDefinitionService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &CustomServiceDefinition) -> Option<Uuid> {
+        None
+    }
+    fn get_organization_id(&self, entity: &CustomServiceDefinition) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<CustomServiceDefinition> for CustomServiceDefinitionService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<CustomServiceDefinition>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::tags::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl CustomServiceDefinitionService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Load every organization's custom definitions into the service definition registry, so
+    /// services resolve to them. Kept current afterwards by the subscriber.
+    pub async fn load_registry(&self) -> Result<usize> {
+        let definitions = self.get_all(StorableFilter::new()).await?;
+
+        let mut by_organization: HashMap<Uuid, Vec<CustomServiceDefinition>> = HashMap::new();
+        for definition in definitions {
+            by_organization
+                .entry(definition.base.organization_id)
+                .or_default()
+                .push(definition);
+        }
+
+        let runtime_definitions: HashMap<Uuid, Vec<Box<dyn ServiceDefinition>>> = by_organization
+            .into_iter()
+            .map(|(organization_id, definitions)| {
+                (organization_id, to_runtime_definitions(&definitions))
+            })
+            .collect();
+
+        let count = runtime_definitions.values().map(Vec::len).sum();
+        ServiceDefinitionRegistry::replace_custom(runtime_definitions);
+
+        Ok(count)
+    }
+}
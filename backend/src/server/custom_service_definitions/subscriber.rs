@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use async_trait::async_trait;
+
+use crate::server::{
+    custom_service_definitions::{
+        r#impl::definition::{RuntimeServiceDefinition, custom_definition_id},
+        service::CustomServiceDefinitionService,
+    },
+    services::definitions::ServiceDefinitionRegistry,
+    shared::{
+        entities::{Entity, EntityDiscriminants},
+        events::{
+            bus::{EventFilter, EventSubscriber},
+            types::{EntityOperation, Event},
+        },
+    },
+};
+
+#[async_trait]
+impl EventSubscriber for CustomServiceDefinitionService {
+    fn event_filter(&self) -> EventFilter {
+        EventFilter::entity_only(HashMap::from([(
+            EntityDiscriminants::CustomServiceDefinition,
+            Some(vec![
+                EntityOperation::Created,
+                EntityOperation::Updated,
+                EntityOperation::Deleted,
+            ]),
+        )]))
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), Error> {
+        for event in events {
+            if let Event::Entity(entity_event) = event
+                && let Entity::CustomServiceDefinition(definition) = &entity_event.entity_type
+            {
+                if entity_event.operation == EntityOperation::Deleted {
+                    ServiceDefinitionRegistry::unregister_custom(
+                        &definition.base.organization_id,
+                        &custom_definition_id(&definition.id),
+                    );
+                    continue;
+                }
+
+                match RuntimeServiceDefinition::try_from(definition) {
+                    Ok(runtime_definition) => ServiceDefinitionRegistry::register_custom(
+                        definition.base.organization_id,
+                        Box::new(runtime_definition),
+                    ),
+                    Err(e) => tracing::warn!(
+                        definition_id = %definition.id,
+                        error = %e,
+                        "Custom service definition could not be registered"
+                    ),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "custom_service_definition_registry"
+    }
+}
@@ -11,7 +11,7 @@ use crate::server::shared::handlers::query::{
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::storage::filter::StorableFilter;
 use crate::server::shared::storage::traits::Storable;
-use crate::server::shared::types::api::ApiErrorResponse;
+use crate::server::shared::types::api::{ApiErrorResponse, ValidationError};
 use crate::server::{
     auth::middleware::auth::AuthenticatedEntity,
     config::AppState,
@@ -395,6 +395,7 @@ async fn register_daemon(
         source: EntitySource::Discovery { metadata: vec![] },
         virtualization: None,
         hidden: false,
+        device_info: None,
         tags: Vec::new(),
     });
 
@@ -505,6 +506,7 @@ async fn register_daemon(
         let docker_discovery_type = DiscoveryType::Docker {
             host_id: host_response.id,
             host_naming_fallback: HostNamingFallback::BestService,
+            endpoint: None,
         };
 
         let docker_discovery = discovery_service
@@ -533,6 +535,7 @@ async fn register_daemon(
     let network_discovery_type = DiscoveryType::Network {
         subnet_ids: None,
         host_naming_fallback: HostNamingFallback::BestService,
+        scan_profile_id: None,
     };
 
     let network_discovery = discovery_service
@@ -553,9 +556,21 @@ async fn register_daemon(
         )
         .await?;
 
-    discovery_service
+    // A daemon that ignores the network's scan exclusions is still registered; its network
+    // discovery is refused until it's updated
+    if let Err(e) = discovery_service
         .start_session(network_discovery, AuthenticatedEntity::System)
-        .await?;
+        .await
+    {
+        if e.downcast_ref::<ValidationError>().is_none() {
+            return Err(e.into());
+        }
+        tracing::warn!(
+            daemon_id = %request.daemon_id,
+            error = %e,
+            "Initial network discovery not started"
+        );
+    }
 
     Ok(Json(ApiResponse::success(DaemonRegistrationResponse {
         daemon: registered_daemon,
@@ -10,9 +10,11 @@ use crate::{
             version::{DaemonVersionStatus, DeprecationWarning},
         },
         discovery::r#impl::types::DiscoveryType,
+        scan_exclusions::r#impl::base::ScanExclusions,
     },
 };
 use chrono::{DateTime, Utc};
+use cidr::IpCidr;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -26,14 +28,19 @@ pub struct DaemonCapabilities {
     #[serde(default)]
     #[schema(required)]
     pub interfaced_subnet_ids: Vec<Uuid>,
+    /// Whether the daemon skips the hosts a session's scan exclusions match. Daemons that
+    /// predate exclusions don't report it, and scan every host.
+    #[serde(default)]
+    #[schema(required)]
+    pub enforces_scan_exclusions: bool,
 }
 
 impl Display for DaemonCapabilities {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "DaemonCapabilities {{ has_docker_socket: {}, interfaced_subnet_ids: {:?} }}",
-            self.has_docker_socket, self.interfaced_subnet_ids
+            "DaemonCapabilities {{ has_docker_socket: {}, interfaced_subnet_ids: {:?}, enforces_scan_exclusions: {} }}",
+            self.has_docker_socket, self.interfaced_subnet_ids, self.enforces_scan_exclusions
         )
     }
 }
@@ -71,6 +78,8 @@ pub struct DaemonRegistrationResponse {
 pub struct DaemonDiscoveryRequest {
     pub session_id: Uuid,
     pub discovery_type: DiscoveryType,
+    #[serde(default)]
+    pub exclusions: ScanExclusions,
 }
 
 impl From<DiscoveryUpdatePayload> for DaemonDiscoveryRequest {
@@ -78,6 +87,7 @@ impl From<DiscoveryUpdatePayload> for DaemonDiscoveryRequest {
         Self {
             session_id: payload.session_id,
             discovery_type: payload.discovery_type,
+            exclusions: payload.exclusions,
         }
     }
 }
@@ -100,6 +110,33 @@ pub struct DiscoveryUpdatePayload {
     pub error: Option<String>,
     pub started_at: Option<DateTime<Utc>>,
     pub finished_at: Option<DateTime<Utc>>,
+    /// Hosts the daemon has to leave alone, resolved when the session is queued so pull mode
+    /// daemons get them with the session. Updates from the daemon leave it empty.
+    #[serde(default)]
+    pub exclusions: ScanExclusions,
+    /// Scan parameters the daemon adapted to for each subnet, reported when a session ends
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scan_tuning: Vec<SubnetScanTuning>,
+}
+
+/// TCP scan parameters a daemon settled on for one subnet after adapting to its round trip
+/// times and timeouts
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct SubnetScanTuning {
+    #[schema(value_type = String)]
+    pub cidr: IpCidr,
+    /// Ports scanned concurrently per host
+    pub batch_size: usize,
+    /// Connect timeout per port
+    pub timeout_ms: u64,
+    /// Retries after a timeout
+    pub retries: u8,
+    /// Smoothed round trip time of answered connection attempts
+    pub smoothed_rtt_ms: Option<u64>,
+    /// Connection attempts made
+    pub attempts: u64,
+    /// Connection attempts that timed out
+    pub timeouts: u64,
 }
 
 impl DiscoveryUpdatePayload {
@@ -119,6 +156,8 @@ impl DiscoveryUpdatePayload {
             error: None,
             started_at: None,
             finished_at: None,
+            exclusions: ScanExclusions::default(),
+            scan_tuning: Vec::new(),
         }
     }
 
@@ -137,6 +176,8 @@ impl DiscoveryUpdatePayload {
             error: update.error,
             started_at: info.started_at,
             finished_at: update.finished_at,
+            exclusions: ScanExclusions::default(),
+            scan_tuning: Vec::new(),
         }
     }
 }
@@ -4,6 +4,7 @@ use crate::server::{
     daemons::r#impl::api::DiscoveryUpdatePayload,
     discovery::r#impl::{
         base::Discovery,
+        results::DiscoveryResultKey,
         types::{DiscoveryType, RunType},
     },
     networks::r#impl::Network,
@@ -15,6 +16,7 @@ use crate::server::{
 };
 use axum::{
     extract::{Path, State},
+    http::HeaderMap,
     response::{
         Json, Sse,
         sse::{Event, KeepAlive},
@@ -65,6 +67,10 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
         (status = 200, description = "Discovery created successfully", body = ApiResponse<Discovery>),
         (status = 400, description = "Invalid subnet network", body = ApiErrorResponse),
         (status = 400, description = "Can't create historical discovery", body = ApiErrorResponse),
+        (status = 400, description = "Passive discovery can't be scheduled", body = ApiErrorResponse),
+        (status = 400, description = "Scan profile not found", body = ApiErrorResponse),
+        (status = 400, description = "Traceroute discovery has nothing to trace", body = ApiErrorResponse),
+        (status = 400, description = "Unsupported Docker endpoint", body = ApiErrorResponse),
     ),
      security(("user_api_key" = []), ("session" = []))
 )]
@@ -80,7 +86,13 @@ pub async fn create_discovery(
     // Custom validation: Check if any subnets aren't on the same network as the discovery
     #[allow(clippy::single_match)]
     match &discovery.base.discovery_type {
-        DiscoveryType::Network { subnet_ids, .. } => {
+        DiscoveryType::Network {
+            subnet_ids,
+            scan_profile_id,
+            ..
+        } => {
+            validate_scan_profile(&state, auth.organization_id(), *scan_profile_id).await?;
+
             for subnet_id in subnet_ids.as_ref().unwrap_or(&vec![]) {
                 if let Some(subnet) = state.services.subnet_service.get_by_id(subnet_id).await?
                     && subnet.base.network_id != discovery.base.network_id
@@ -91,7 +103,27 @@ pub async fn create_discovery(
                 }
             }
         }
-        DiscoveryType::Docker { .. } | DiscoveryType::SelfReport { .. } => (),
+        DiscoveryType::Passive => return Err(ApiError::discovery_passive_not_schedulable()),
+        DiscoveryType::Traceroute {
+            targets,
+            include_remote_subnets,
+            ..
+        } => {
+            if targets.is_empty() && !include_remote_subnets {
+                return Err(ApiError::discovery_traceroute_no_targets());
+            }
+        }
+        DiscoveryType::Docker {
+            endpoint: Some(endpoint),
+            ..
+        } if !endpoint.is_valid() => {
+            return Err(ApiError::discovery_docker_endpoint_invalid(&endpoint.url));
+        }
+        DiscoveryType::Docker { .. }
+        | DiscoveryType::SelfReport { .. }
+        | DiscoveryType::DhcpLeases { .. }
+        | DiscoveryType::Kubernetes { .. }
+        | DiscoveryType::Proxmox { .. } => (),
     }
 
     // Delegate to generic handler (handles validation, auth checks, creation)
@@ -109,6 +141,10 @@ pub async fn create_discovery(
         (status = 200, description = "Discovery updated successfully", body = ApiResponse<Discovery>),
         (status = 400, description = "Invalid subnet network", body = ApiErrorResponse),
         (status = 400, description = "Can't update historical discovery", body = ApiErrorResponse),
+        (status = 400, description = "Passive discovery can't be scheduled", body = ApiErrorResponse),
+        (status = 400, description = "Scan profile not found", body = ApiErrorResponse),
+        (status = 400, description = "Traceroute discovery has nothing to trace", body = ApiErrorResponse),
+        (status = 400, description = "Unsupported Docker endpoint", body = ApiErrorResponse),
     ),
      security(("user_api_key" = []), ("session" = []))
 )]
@@ -122,12 +158,58 @@ pub async fn update_discovery(
         return Err(ApiError::discovery_historical_read_only());
     }
 
+    if discovery.base.discovery_type == DiscoveryType::Passive {
+        return Err(ApiError::discovery_passive_not_schedulable());
+    }
+
+    match &discovery.base.discovery_type {
+        DiscoveryType::Network {
+            scan_profile_id, ..
+        } => validate_scan_profile(&state, auth.organization_id(), *scan_profile_id).await?,
+        DiscoveryType::Traceroute {
+            targets,
+            include_remote_subnets,
+            ..
+        } if targets.is_empty() && !include_remote_subnets => {
+            return Err(ApiError::discovery_traceroute_no_targets());
+        }
+        DiscoveryType::Docker {
+            endpoint: Some(endpoint),
+            ..
+        } if !endpoint.is_valid() => {
+            return Err(ApiError::discovery_docker_endpoint_invalid(&endpoint.url));
+        }
+        _ => (),
+    }
+
     update_handler::<Discovery>(state, auth, id, discovery).await
 }
 
+/// Check that a network discovery's scan profile belongs to the caller's organization
+async fn validate_scan_profile(
+    state: &AppState,
+    organization_id: Option<Uuid>,
+    scan_profile_id: Option<Uuid>,
+) -> ApiResult<()> {
+    let Some(id) = scan_profile_id else {
+        return Ok(());
+    };
+
+    state
+        .services
+        .scan_profile_service
+        .get_by_id(&id)
+        .await?
+        .filter(|profile| Some(profile.base.organization_id) == organization_id)
+        .map(|_| ())
+        .ok_or_else(|| ApiError::discovery_scan_profile_not_found(id))
+}
+
 /// Receive discovery progress update from daemon
 ///
-/// Internal endpoint for daemons to report discovery progress.
+/// Internal endpoint for daemons to report discovery progress. A daemon that couldn't reach the
+/// server replays the final update after the session's results; updates sent with
+/// `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are applied once.
 #[utoipa::path(
     post,
     path = "/{session_id}/update",
@@ -143,6 +225,7 @@ async fn receive_discovery_update(
     State(state): State<Arc<AppState>>,
     auth: Authorized<IsDaemon>,
     Path(_session_id): Path<Uuid>,
+    headers: HeaderMap,
     Json(update): Json<DiscoveryUpdatePayload>,
 ) -> ApiResult<Json<ApiResponse<()>>> {
     // IsDaemon guarantees exactly one network_id and a daemon_id
@@ -159,11 +242,25 @@ async fn receive_discovery_update(
         return Err(ApiError::daemon_identity_mismatch());
     }
 
-    state
-        .services
-        .discovery_service
-        .update_session(update)
-        .await?;
+    let discovery_service = &state.services.discovery_service;
+    let result_key = DiscoveryResultKey::from_headers(&headers);
+
+    // Replayed after the daemon lost the response: it was applied the first time
+    if let Some(key) = &result_key
+        && let Some(()) = discovery_service
+            .applied_result(key, daemon_network_id)
+            .await?
+    {
+        return Ok(Json(ApiResponse::success(())));
+    }
+
+    discovery_service.update_session(update).await?;
+
+    if let Some(key) = &result_key {
+        discovery_service
+            .record_result(key, daemon_network_id, &())
+            .await;
+    }
 
     Ok(Json(ApiResponse::success(())))
 }
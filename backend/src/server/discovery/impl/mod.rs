@@ -1,4 +1,5 @@
 pub mod base;
 pub mod handlers;
+pub mod results;
 pub mod storage;
 pub mod types;
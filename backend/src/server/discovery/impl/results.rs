@@ -0,0 +1,175 @@
+use axum::http::HeaderMap;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::fmt::Display;
+use uuid::Uuid;
+
+use crate::server::shared::storage::traits::{SqlValue, Storable};
+
+/// Header carrying the discovery session a daemon result belongs to
+pub const DISCOVERY_SESSION_ID_HEADER: &str = "X-Discovery-Session-ID";
+/// Header carrying the daemon-generated ID of a result, stable across replays
+pub const DISCOVERY_RESULT_ID_HEADER: &str = "X-Discovery-Result-ID";
+
+/// Identifies one result a daemon reported during a discovery session. Daemons replay results
+/// they couldn't deliver, so the same key can arrive more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiscoveryResultKey {
+    pub session_id: Uuid,
+    pub result_id: Uuid,
+}
+
+impl DiscoveryResultKey {
+    /// Key from the request headers, if the daemon sent both
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| Uuid::parse_str(v).ok())
+        };
+
+        Some(Self {
+            session_id: header(DISCOVERY_SESSION_ID_HEADER)?,
+            result_id: header(DISCOVERY_RESULT_ID_HEADER)?,
+        })
+    }
+}
+
+/// The base data for a DiscoveryResult record (everything except id and created_at)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DiscoveryResultBase {
+    pub session_id: Uuid,
+    pub network_id: Uuid,
+    /// What the server responded with when the result was first applied
+    pub response: serde_json::Value,
+}
+
+/// A daemon result that has been applied. `id` is the daemon's result ID.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct DiscoveryResult {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub base: DiscoveryResultBase,
+}
+
+impl Display for DiscoveryResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DiscoveryResult({} in session {})",
+            self.id, self.base.session_id
+        )
+    }
+}
+
+impl Storable for DiscoveryResult {
+    type BaseData = DiscoveryResultBase;
+
+    fn table_name() -> &'static str {
+        "discovery_results"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            base:
+                Self::BaseData {
+                    session_id,
+                    network_id,
+                    response,
+                },
+        } = self.clone();
+
+        Ok((
+            vec!["id", "session_id", "network_id", "response", "created_at"],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(session_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::JsonValue(response),
+                SqlValue::Timestamp(created_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        Ok(DiscoveryResult {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: DiscoveryResultBase {
+                session_id: row.get("session_id"),
+                network_id: row.get("network_id"),
+                response: row.get("response"),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_key_requires_both_headers() {
+        let session_id = Uuid::new_v4();
+        let result_id = Uuid::new_v4();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            DISCOVERY_SESSION_ID_HEADER,
+            HeaderValue::from_str(&session_id.to_string()).unwrap(),
+        );
+        assert_eq!(DiscoveryResultKey::from_headers(&headers), None);
+
+        headers.insert(
+            DISCOVERY_RESULT_ID_HEADER,
+            HeaderValue::from_str(&result_id.to_string()).unwrap(),
+        );
+        assert_eq!(
+            DiscoveryResultKey::from_headers(&headers),
+            Some(DiscoveryResultKey {
+                session_id,
+                result_id
+            })
+        );
+
+        headers.insert(
+            DISCOVERY_RESULT_ID_HEADER,
+            HeaderValue::from_static("not-a-uuid"),
+        );
+        assert_eq!(DiscoveryResultKey::from_headers(&headers), None);
+    }
+}
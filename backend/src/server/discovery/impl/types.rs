@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt::Display;
+use std::net::IpAddr;
 use strum::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -42,15 +43,94 @@ pub enum DiscoveryType {
         #[serde(default)]
         #[schema(required)]
         host_naming_fallback: HostNamingFallback,
+        /// Scan profile to scan hosts with; the default settings when not set
+        #[serde(default)]
+        #[schema(required)]
+        scan_profile_id: Option<Uuid>,
     },
     #[schema(title = "Docker")]
     Docker {
-        // ID of the host that the daemon is running on
+        // ID of the host the Docker engine runs on: the daemon's own, or the endpoint's
         host_id: Uuid,
         #[serde(default)]
         #[schema(required)]
         host_naming_fallback: HostNamingFallback,
+        /// Remote engine to scan; the daemon's local engine or configured proxy when not set
+        #[serde(default)]
+        #[schema(required)]
+        endpoint: Option<Box<DockerEndpoint>>,
+    },
+    /// Hosts the daemon saw in broadcast traffic between sessions. Runs continuously when enabled
+    /// in the daemon's config rather than being scheduled.
+    #[schema(title = "Passive")]
+    Passive,
+    /// Leases and reservations read from a DHCP server's lease file on the daemon's host
+    #[schema(title = "DhcpLeases")]
+    DhcpLeases {
+        /// Lease file to import. Must be inside the directory the daemon was started with
+        /// `--dhcp-lease-dir`; relative paths are taken from that directory.
+        path: String,
+        format: DhcpLeaseFormat,
+        #[serde(default)]
+        #[schema(required)]
+        host_naming_fallback: HostNamingFallback,
+    },
+    /// Routers on the path to remote sites, found by tracing the route to them
+    #[schema(title = "Traceroute")]
+    Traceroute {
+        /// Addresses to trace the route to
+        #[schema(value_type = Vec<String>)]
+        targets: Vec<IpAddr>,
+        /// Also trace to every remote subnet on the network
+        #[serde(default)]
+        #[schema(required)]
+        include_remote_subnets: bool,
+        #[serde(default)]
+        #[schema(required)]
+        protocol: TracerouteProtocol,
+        /// Hops to trace before giving up on a target, at most 64
+        #[serde(default = "default_traceroute_max_hops")]
+        #[schema(required)]
+        max_hops: u8,
+    },
+    /// Nodes, pods, Services and Ingresses read from a Kubernetes cluster's API
+    #[schema(title = "Kubernetes")]
+    Kubernetes {
+        /// Kubeconfig file in the directory the daemon was started with as `--kubeconfig-dir`.
+        /// Relative paths are taken from that directory. When not set, the daemon's service account
+        /// is used if it runs in the cluster, and the default kubeconfig otherwise
+        #[serde(default)]
+        #[schema(required)]
+        kubeconfig_path: Option<String>,
+        /// Kubeconfig context to use; the current context when not set
+        #[serde(default)]
+        #[schema(required)]
+        context: Option<String>,
+        #[serde(default)]
+        #[schema(required)]
+        host_naming_fallback: HostNamingFallback,
     },
+    /// Nodes, VMs and containers read from a Proxmox VE cluster's API
+    #[schema(title = "Proxmox")]
+    Proxmox {
+        /// Address of the API, ie "https://pve.lan:8006"
+        url: String,
+        /// API token in the form `user@realm!tokenid`. It needs the VM.Audit and Sys.Audit
+        /// privileges, and VM.Monitor to read guest agent addresses.
+        token_id: String,
+        /// File holding the token's secret, in the directory the daemon was started with as
+        /// `--proxmox-secrets-dir`, so the secret is never stored on the server. Relative paths
+        /// are taken from that directory.
+        token_secret_path: String,
+        /// Accept the self-signed certificate Proxmox VE installs by default
+        #[serde(default)]
+        #[schema(required)]
+        allow_self_signed_certs: bool,
+    },
+}
+
+fn default_traceroute_max_hops() -> u8 {
+    30
 }
 
 impl Default for DiscoveryType {
@@ -61,16 +141,124 @@ impl Default for DiscoveryType {
     }
 }
 
+impl DiscoveryType {
+    /// Whether two discoveries read the same source: the same Docker engine's host, Kubernetes
+    /// cluster, Proxmox VE cluster or lease file. Settings that only change how results are
+    /// named or scanned are ignored.
+    pub fn same_target(&self, other: &DiscoveryType) -> bool {
+        match (self, other) {
+            (Self::Docker { host_id: a, .. }, Self::Docker { host_id: b, .. }) => a == b,
+            (
+                Self::Kubernetes {
+                    kubeconfig_path: path_a,
+                    context: context_a,
+                    ..
+                },
+                Self::Kubernetes {
+                    kubeconfig_path: path_b,
+                    context: context_b,
+                    ..
+                },
+            ) => path_a == path_b && context_a == context_b,
+            (Self::Proxmox { url: a, .. }, Self::Proxmox { url: b, .. }) => a == b,
+            (Self::DhcpLeases { path: a, .. }, Self::DhcpLeases { path: b, .. }) => a == b,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
 impl Display for DiscoveryType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DiscoveryType::SelfReport { .. } => write!(f, "Self Report"),
             DiscoveryType::Network { .. } => write!(f, "Network Discovery"),
             DiscoveryType::Docker { .. } => write!(f, "Docker Discovery"),
+            DiscoveryType::Passive => write!(f, "Passive Discovery"),
+            DiscoveryType::DhcpLeases { .. } => write!(f, "DHCP Lease Import"),
+            DiscoveryType::Traceroute { .. } => write!(f, "Traceroute Discovery"),
+            DiscoveryType::Kubernetes { .. } => write!(f, "Kubernetes Discovery"),
+            DiscoveryType::Proxmox { .. } => write!(f, "Proxmox Discovery"),
         }
     }
 }
 
+/// Lease file formats understood by DHCP lease discovery
+#[derive(
+    Debug, Clone, Serialize, Copy, Deserialize, Eq, PartialEq, Hash, Display, Default, ToSchema,
+)]
+pub enum DhcpLeaseFormat {
+    /// ISC dhcpd `dhcpd.leases`, plus `host` declarations with a `fixed-address` as reservations
+    #[default]
+    IscDhcpd,
+    /// dnsmasq lease file (`dnsmasq.leases`)
+    Dnsmasq,
+    /// Kea memfile lease CSV
+    Kea,
+    /// A JSON array of `{ip_address, mac_address, hostname, starts, ends, reserved}` objects
+    Json,
+}
+
+/// Probes traceroute discovery sends. Routers and firewalls treat them differently, so the one
+/// that gets furthest depends on the path.
+#[derive(
+    Debug, Clone, Serialize, Copy, Deserialize, Eq, PartialEq, Hash, Display, Default, ToSchema,
+)]
+pub enum TracerouteProtocol {
+    /// UDP datagrams to high ports, like classic Unix traceroute
+    #[default]
+    Udp,
+    /// ICMP echo requests, like Windows tracert
+    Icmp,
+    /// TCP SYNs to port 443, which firewalls commonly let through
+    Tcp,
+}
+
+/// A Docker engine reached over the network rather than through the daemon's local socket
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct DockerEndpoint {
+    /// `tcp://`, `http://` or `https://` URL of the engine's API or a socket proxy in front of
+    /// it, or a `unix://` path to a socket in the directory the daemon was started with as
+    /// `--docker-endpoint-dir`. `ssh://` is out of scope: to reach an engine over SSH, forward
+    /// its socket into that directory yourself, ie
+    /// `ssh -NL /run/scanopy/docker/nas.sock:/var/run/docker.sock admin@nas`, keep the tunnel up
+    /// while discovery runs, and give the local end as `unix:///run/scanopy/docker/nas.sock`.
+    pub url: String,
+    /// Client certificate for an engine that requires TLS
+    #[serde(default)]
+    #[schema(required)]
+    pub tls: Option<DockerTlsFiles>,
+}
+
+impl DockerEndpoint {
+    /// Whether the URL has a scheme the daemon can connect with, and TLS files are given for
+    /// HTTPS and only set for TCP or HTTPS
+    pub fn is_valid(&self) -> bool {
+        let Some((scheme, rest)) = self.url.split_once("://") else {
+            return false;
+        };
+        if rest.is_empty() {
+            return false;
+        }
+
+        match scheme {
+            "tcp" => true,
+            "https" => self.tls.is_some(),
+            "http" | "unix" => self.tls.is_none(),
+            _ => false,
+        }
+    }
+}
+
+/// PEM files for a TLS-protected Docker engine, in the directory the daemon was started with as
+/// `--docker-endpoint-dir`. Relative paths are taken from that directory.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct DockerTlsFiles {
+    pub cert_path: String,
+    pub key_path: String,
+    /// CA the engine's certificate is verified against
+    pub ca_path: String,
+}
+
 #[derive(
     Debug, Clone, Serialize, Copy, Deserialize, Eq, PartialEq, Hash, Display, Default, ToSchema,
 )]
@@ -126,12 +314,12 @@ impl EntityMetadataProvider for DiscoveryType {
 
 impl TypeMetadataProvider for DiscoveryType {
     fn name(&self) -> &'static str {
-        self.id()
+        self.into()
     }
     fn description(&self) -> &'static str {
         match self {
             DiscoveryType::Docker { .. } => {
-                "Discover Docker containers and their configurations on the daemon's host"
+                "Discover Docker containers and their configurations on the daemon's host or a remote engine"
             }
             DiscoveryType::Network { .. } => {
                 "Scan network subnets to discover hosts, open ports, and running services"
@@ -139,6 +327,75 @@ impl TypeMetadataProvider for DiscoveryType {
             DiscoveryType::SelfReport { .. } => {
                 "The daemon reports its own host configuration and network details"
             }
+            DiscoveryType::Passive => {
+                "Listen for broadcast traffic to report hosts as they join, without probing them"
+            }
+            DiscoveryType::DhcpLeases { .. } => {
+                "Import leases and static reservations from a DHCP server's lease file"
+            }
+            DiscoveryType::Traceroute { .. } => {
+                "Trace routes to remote sites to map the routers and transit links between them"
+            }
+            DiscoveryType::Kubernetes { .. } => {
+                "Import nodes, pod and service networks, and workloads from a Kubernetes cluster"
+            }
+            DiscoveryType::Proxmox { .. } => {
+                "Import VMs and containers from a Proxmox VE cluster and link them to their hosts"
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(url: &str, tls: bool) -> DockerEndpoint {
+        DockerEndpoint {
+            url: url.to_string(),
+            tls: tls.then(|| DockerTlsFiles {
+                cert_path: "/etc/scanopy/docker/cert.pem".to_string(),
+                key_path: "/etc/scanopy/docker/key.pem".to_string(),
+                ca_path: "/etc/scanopy/docker/ca.pem".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_docker_endpoint_validity() {
+        assert!(endpoint("tcp://10.0.0.5:2375", false).is_valid());
+        assert!(endpoint("tcp://10.0.0.5:2376", true).is_valid());
+        assert!(endpoint("https://docker.lan:2376", true).is_valid());
+        assert!(endpoint("http://socket-proxy:2375", false).is_valid());
+        assert!(endpoint("unix:///run/scanopy/nas-docker.sock", false).is_valid());
+
+        assert!(!endpoint("https://docker.lan:2376", false).is_valid());
+        assert!(!endpoint("http://socket-proxy:2375", true).is_valid());
+        assert!(!endpoint("unix:///run/scanopy/nas-docker.sock", true).is_valid());
+        assert!(!endpoint("ssh://admin@nas", false).is_valid());
+        assert!(!endpoint("10.0.0.5:2375", false).is_valid());
+        assert!(!endpoint("tcp://", false).is_valid());
+    }
+
+    #[test]
+    fn test_same_target_ignores_naming_settings() {
+        let docker =
+            |host_id: Uuid, host_naming_fallback: HostNamingFallback| DiscoveryType::Docker {
+                host_id,
+                host_naming_fallback,
+                endpoint: None,
+            };
+        let host_id = Uuid::new_v4();
+
+        assert!(
+            docker(host_id, HostNamingFallback::Ip)
+                .same_target(&docker(host_id, HostNamingFallback::BestService))
+        );
+        assert!(
+            !docker(host_id, HostNamingFallback::Ip)
+                .same_target(&docker(Uuid::new_v4(), HostNamingFallback::Ip))
+        );
+        assert!(!docker(host_id, HostNamingFallback::Ip).same_target(&DiscoveryType::Passive));
+        assert!(DiscoveryType::Passive.same_target(&DiscoveryType::Passive));
+    }
+}
@@ -1,7 +1,9 @@
 use crate::daemon::runtime::service::LOG_TARGET;
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
 use crate::server::daemons::r#impl::base::DaemonMode;
-use crate::server::discovery::r#impl::types::RunType;
+use crate::server::discovery::r#impl::types::{DiscoveryType, RunType};
+use crate::server::scan_exclusions::r#impl::base::ScanExclusions;
+use crate::server::scan_exclusions::service::ScanExclusionService;
 use crate::server::shared::entities::{ChangeTriggersTopologyStaleness, EntityDiscriminants};
 use crate::server::shared::events::bus::EventBus;
 use crate::server::shared::events::types::{EntityEvent, EntityOperation};
@@ -14,12 +16,16 @@ use anyhow::anyhow;
 use anyhow::{Error, Result};
 use async_trait::async_trait;
 use chrono::Utc;
+use serde::{Serialize, de::DeserializeOwned};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::{RwLock, broadcast};
 use tokio_cron_scheduler::{Job, JobScheduler};
 use uuid::Uuid;
 
 use crate::server::discovery::r#impl::base::Discovery;
+use crate::server::discovery::r#impl::results::{
+    DiscoveryResult, DiscoveryResultBase, DiscoveryResultKey,
+};
 use crate::{
     daemon::discovery::types::base::DiscoveryPhase,
     server::daemons::{
@@ -31,7 +37,9 @@ use crate::{
 /// Server-side session management for discovery
 pub struct DiscoveryService {
     discovery_storage: Arc<GenericPostgresStorage<Discovery>>,
+    result_storage: Arc<GenericPostgresStorage<DiscoveryResult>>,
     daemon_service: Arc<DaemonService>,
+    scan_exclusion_service: Arc<ScanExclusionService>,
     sessions: RwLock<HashMap<Uuid, DiscoveryUpdatePayload>>, // session_id -> session state mapping
     daemon_sessions: RwLock<HashMap<Uuid, Vec<Uuid>>>,       // daemon_id -> session_id mapping
     daemon_pull_cancellations: RwLock<HashMap<Uuid, (bool, Uuid)>>, // daemon_id -> (boolean, session_id) mapping for pull mode cancellations of current session on daemon
@@ -69,7 +77,9 @@ impl CrudService<Discovery> for DiscoveryService {
 impl DiscoveryService {
     pub async fn new(
         discovery_storage: Arc<GenericPostgresStorage<Discovery>>,
+        result_storage: Arc<GenericPostgresStorage<DiscoveryResult>>,
         daemon_service: Arc<DaemonService>,
+        scan_exclusion_service: Arc<ScanExclusionService>,
         event_bus: Arc<EventBus>,
         entity_tag_service: Arc<EntityTagService>,
     ) -> Result<Arc<Self>> {
@@ -78,7 +88,9 @@ impl DiscoveryService {
 
         Ok(Arc::new(Self {
             discovery_storage,
+            result_storage,
             daemon_service,
+            scan_exclusion_service,
             sessions: RwLock::new(HashMap::new()),
             daemon_sessions: RwLock::new(HashMap::new()),
             daemon_pull_cancellations: RwLock::new(HashMap::new()),
@@ -467,13 +479,39 @@ impl DiscoveryService {
     ) -> Result<DiscoveryUpdatePayload, anyhow::Error> {
         let session_id = Uuid::new_v4();
 
-        let session_payload = DiscoveryUpdatePayload::new(
+        let mut session_payload = DiscoveryUpdatePayload::new(
             session_id,
             discovery.base.daemon_id,
             discovery.base.network_id,
             discovery.base.discovery_type.clone(),
         );
 
+        // Resolved now rather than when the session starts, so a queued session can be handed
+        // out without another lookup. Better not to scan than to scan a host that's excluded.
+        session_payload.exclusions = self
+            .scan_exclusion_service
+            .resolve_for_network(&discovery.base.network_id)
+            .await?;
+
+        let daemon = self
+            .daemon_service
+            .get_by_id(&discovery.base.daemon_id)
+            .await?;
+
+        if matches!(discovery.base.discovery_type, DiscoveryType::Network { .. })
+            && !session_payload.exclusions.is_empty()
+        {
+            // Older daemons don't report the capability, and would scan excluded hosts
+            if !daemon
+                .as_ref()
+                .is_some_and(|d| d.base.capabilities.enforces_scan_exclusions)
+            {
+                crate::bail_validation!(
+                    "This daemon ignores scan exclusions. Update it to run network discovery on this network."
+                );
+            }
+        }
+
         // Add to session map
         self.sessions
             .write()
@@ -500,10 +538,7 @@ impl DiscoveryService {
             .or_default()
             .push(session_id);
 
-        let daemon_is_push = self
-            .daemon_service
-            .get_by_id(&discovery.base.daemon_id)
-            .await?
+        let daemon_is_push = daemon
             .map(|d| d.base.mode == DaemonMode::Push)
             .unwrap_or(false);
 
@@ -515,6 +550,7 @@ impl DiscoveryService {
                     DaemonDiscoveryRequest {
                         discovery_type: discovery.base.discovery_type,
                         session_id,
+                        exclusions: session_payload.exclusions.clone(),
                     },
                     authentication,
                 )
@@ -639,7 +675,7 @@ impl DiscoveryService {
                     .and_then(|next_session_id| sessions.get_mut(next_session_id))
                     .map(|next_session| {
                         next_session.phase = DiscoveryPhase::Pending;
-                        (next_session.discovery_type.clone(), next_session.session_id)
+                        DaemonDiscoveryRequest::from(next_session.clone())
                     })
             } else {
                 None
@@ -660,20 +696,13 @@ impl DiscoveryService {
                 .map(|d| d.base.mode == DaemonMode::Push)
                 .unwrap_or(false);
 
-            if let Some((discovery_type, session_id)) = next_session_info
+            if let Some(request) = next_session_info
                 && daemon_is_push
             {
                 tracing::debug!("Starting next session");
 
                 self.daemon_service
-                    .send_discovery_request(
-                        &daemon_id,
-                        DaemonDiscoveryRequest {
-                            discovery_type,
-                            session_id,
-                        },
-                        AuthenticatedEntity::System,
-                    )
+                    .send_discovery_request(&daemon_id, request, AuthenticatedEntity::System)
                     .await?;
             }
         }
@@ -727,6 +756,8 @@ impl DiscoveryService {
                     started_at: session.started_at,
                     finished_at: Some(Utc::now()),
                     discovery_type: session.discovery_type,
+                    exclusions: ScanExclusions::default(),
+                    scan_tuning: Vec::new(),
                 };
                 let _ = self.update_tx.send(cancelled_update);
 
@@ -785,6 +816,8 @@ impl DiscoveryService {
                                             started_at: session.started_at,
                                             finished_at: Some(Utc::now()),
                                             discovery_type: session.discovery_type.clone(),
+                                            exclusions: ScanExclusions::default(),
+                                            scan_tuning: Vec::new(),
                                         };
                                         let _ = self.update_tx.send(cancelled_update.clone());
 
@@ -886,6 +919,77 @@ impl DiscoveryService {
         }
     }
 
+    /// Response recorded for a daemon result that was already applied, if it was
+    pub async fn applied_result<T: DeserializeOwned>(
+        &self,
+        key: &DiscoveryResultKey,
+        network_id: Uuid,
+    ) -> Result<Option<T>> {
+        let filter = StorableFilter::<DiscoveryResult>::new()
+            .uuid_column("session_id", &key.session_id)
+            .entity_id(&key.result_id)
+            .network_ids(&[network_id]);
+
+        match self.result_storage.get_one(filter).await? {
+            Some(result) => {
+                tracing::debug!(
+                    session_id = %key.session_id,
+                    result_id = %key.result_id,
+                    "Discovery result already applied, returning recorded response"
+                );
+                Ok(Some(serde_json::from_value(result.base.response)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Record that a daemon result was applied, so replays of it return the same response.
+    /// Failing to record only means a replay is applied again, which upserts.
+    pub async fn record_result<T: Serialize>(
+        &self,
+        key: &DiscoveryResultKey,
+        network_id: Uuid,
+        response: &T,
+    ) {
+        let result = DiscoveryResult {
+            id: key.result_id,
+            created_at: Utc::now(),
+            base: DiscoveryResultBase {
+                session_id: key.session_id,
+                network_id,
+                response: match serde_json::to_value(response) {
+                    Ok(response) => response,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Failed to serialize discovery result response");
+                        return;
+                    }
+                },
+            },
+        };
+
+        if let Err(e) = self.result_storage.create(&result).await {
+            tracing::warn!(
+                session_id = %key.session_id,
+                result_id = %key.result_id,
+                error = %e,
+                "Failed to record discovery result"
+            );
+        }
+    }
+
+    /// Forget applied results older than max_age_hours. Daemons replay results for as long as
+    /// they're offline, so this should be well beyond a plausible outage.
+    pub async fn cleanup_old_results(&self, max_age_hours: i64) {
+        let cutoff = Utc::now() - chrono::Duration::hours(max_age_hours);
+        let filter = StorableFilter::<DiscoveryResult>::new().created_at_or_before(cutoff);
+
+        match self.result_storage.delete_by_filter(filter).await {
+            Ok(0) => {}
+            Ok(count) => tracing::debug!("Cleaned up {} old discovery results", count),
+            Err(e) => tracing::error!("Failed to clean up old discovery results: {}", e),
+        }
+    }
+
     /// Cleanup stalled sessions (called periodically from background task)
     pub async fn cleanup_stalled_sessions(&self) {
         let now = Utc::now();
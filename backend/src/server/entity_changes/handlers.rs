@@ -0,0 +1,275 @@
+use axum::extract::{Path, State};
+use axum::response::Json;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::auth::middleware::permissions::{Authorized, Viewer};
+use crate::server::config::AppState;
+use crate::server::discovery::r#impl::{base::Discovery, types::RunType};
+use crate::server::entity_changes::r#impl::base::{EntityChange, HostSnapshot, NetworkChangeDiff};
+use crate::server::shared::entities::EntityDiscriminants;
+use crate::server::shared::extractors::Query;
+use crate::server::shared::handlers::query::{FilterQueryExtractor, PaginationParams};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::{filter::StorableFilter, traits::Storable};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, ApiResponse, ApiResult, PaginatedApiResponse,
+};
+use crate::server::shared::validation::validate_network_access;
+
+/// Query for filtering the change journal.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct EntityChangeQuery {
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Filter by host ID. Includes changes to the host's interfaces, ports, services and bindings.
+    pub host_id: Option<Uuid>,
+    /// Filter by the ID of the changed entity
+    pub entity_id: Option<Uuid>,
+    /// Filter by entity type
+    pub entity_type: Option<EntityDiscriminants>,
+    /// Only return changes made after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only return changes made at or before this time
+    pub until: Option<DateTime<Utc>>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl FilterQueryExtractor for EntityChangeQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let mut filter = match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]),
+            None => filter.network_ids(user_network_ids),
+        };
+        filter = match self.host_id {
+            Some(id) => filter.host_id(&id),
+            None => filter,
+        };
+        filter = match self.entity_id {
+            Some(id) => filter.uuid_column("entity_id", &id),
+            None => filter,
+        };
+        filter = match &self.entity_type {
+            Some(entity_type) => filter.entity_type(entity_type),
+            None => filter,
+        };
+        filter = match self.since {
+            Some(since) => filter.created_after(since),
+            None => filter,
+        };
+        filter = match self.until {
+            Some(until) => filter.created_at_or_before(until),
+            None => filter,
+        };
+
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// List entity changes
+///
+/// Returns the change journal for hosts, interfaces, ports, services and bindings, newest first.
+/// Each entry holds the entity as it was after the change (or just before, for deletions).
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "entity_changes",
+    params(EntityChangeQuery),
+    responses(
+        (status = 200, description = "List of entity changes", body = PaginatedApiResponse<EntityChange>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_entity_changes(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Query(query): Query<EntityChangeQuery>,
+) -> ApiResult<Json<PaginatedApiResponse<EntityChange>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    let filter = query.apply_to_filter(StorableFilter::new(), &network_ids, organization_id);
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(filter);
+
+    let result = state
+        .services
+        .entity_change_service
+        .get_paginated(filter)
+        .await?;
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        pagination.effective_limit().unwrap_or(0),
+        pagination.effective_offset(),
+    )))
+}
+
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct HostSnapshotQuery {
+    /// Point in time to rebuild the host at. Defaults to now.
+    pub at: Option<DateTime<Utc>>,
+}
+
+/// Get a host at a point in time
+///
+/// Rebuilds a host and its interfaces, ports, services and bindings from the change journal,
+/// as they were at the given time.
+#[utoipa::path(
+    get,
+    path = "/hosts/{id}",
+    tag = "entity_changes",
+    params(("id" = Uuid, Path, description = "Host ID"), HostSnapshotQuery),
+    responses(
+        (status = 200, description = "Host as it was at the given time", body = ApiResponse<HostSnapshot>),
+        (status = 404, description = "Host did not exist at the given time", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_host_snapshot(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<HostSnapshotQuery>,
+) -> ApiResult<Json<ApiResponse<HostSnapshot>>> {
+    let network_ids = auth.network_ids();
+    let at = query.at.unwrap_or_else(Utc::now);
+
+    let (network_id, snapshot) = state
+        .services
+        .entity_change_service
+        .get_host_snapshot(id, at)
+        .await?
+        .ok_or_else(|| ApiError::not_found(format!("Host {} did not exist at {}", id, at)))?;
+
+    validate_network_access(Some(network_id), &network_ids, "read")?;
+
+    Ok(Json(ApiResponse::success(snapshot)))
+}
+
+#[derive(Deserialize, Debug, Clone, IntoParams)]
+pub struct NetworkDiffQuery {
+    /// Network to compare
+    pub network_id: Uuid,
+    /// Start of the comparison. Either this or `from_discovery_id` is required.
+    pub from: Option<DateTime<Utc>>,
+    /// End of the comparison. Defaults to now.
+    pub to: Option<DateTime<Utc>>,
+    /// Start the comparison when this discovery run finished
+    pub from_discovery_id: Option<Uuid>,
+    /// End the comparison when this discovery run finished
+    pub to_discovery_id: Option<Uuid>,
+}
+
+/// Compare a network between two points in time
+///
+/// Lists the hosts, interfaces, ports, services and bindings that were added, removed or
+/// modified on a network between two times, or between two discovery runs. Modified entities
+/// include the fields that changed and their state at both ends.
+#[utoipa::path(
+    get,
+    path = "/diff",
+    tag = "entity_changes",
+    params(NetworkDiffQuery),
+    responses(
+        (status = 200, description = "Differences between the two points in time", body = ApiResponse<NetworkChangeDiff>),
+        (status = 400, description = "Missing or invalid range", body = ApiErrorResponse),
+        (status = 404, description = "Discovery run not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_network_diff(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    Query(query): Query<NetworkDiffQuery>,
+) -> ApiResult<Json<ApiResponse<NetworkChangeDiff>>> {
+    let network_ids = auth.network_ids();
+    validate_network_access(Some(query.network_id), &network_ids, "read")?;
+
+    let from = match (query.from, query.from_discovery_id) {
+        (Some(from), _) => from,
+        (None, Some(discovery_id)) => {
+            discovery_run_finished_at(&state, discovery_id, query.network_id).await?
+        }
+        (None, None) => {
+            return Err(ApiError::bad_request(
+                "Either from or from_discovery_id is required",
+            ));
+        }
+    };
+    let to = match (query.to, query.to_discovery_id) {
+        (Some(to), _) => to,
+        (None, Some(discovery_id)) => {
+            discovery_run_finished_at(&state, discovery_id, query.network_id).await?
+        }
+        (None, None) => Utc::now(),
+    };
+
+    if from >= to {
+        return Err(ApiError::bad_request(
+            "The start of the range must be before its end",
+        ));
+    }
+
+    let diff = state
+        .services
+        .entity_change_service
+        .get_network_diff(query.network_id, from, to)
+        .await?;
+
+    Ok(Json(ApiResponse::success(diff)))
+}
+
+/// When a completed discovery run on the network finished
+async fn discovery_run_finished_at(
+    state: &AppState,
+    discovery_id: Uuid,
+    network_id: Uuid,
+) -> Result<DateTime<Utc>, ApiError> {
+    let discovery = state
+        .services
+        .discovery_service
+        .get_by_id(&discovery_id)
+        .await?
+        .filter(|d| d.base.network_id == network_id)
+        .ok_or_else(|| ApiError::entity_not_found::<Discovery>(discovery_id))?;
+
+    match discovery.base.run_type {
+        RunType::Historical { results } => Ok(results.finished_at.unwrap_or(discovery.updated_at)),
+        _ => Err(ApiError::bad_request(
+            "Discovery must be a completed run, not a scheduled or ad hoc discovery",
+        )),
+    }
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_entity_changes))
+        .routes(routes!(get_host_snapshot))
+        .routes(routes!(get_network_diff))
+}
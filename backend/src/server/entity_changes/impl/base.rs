@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::shared::entities::EntityDiscriminants;
+
+/// What happened to the entity
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+pub enum ChangeOperation {
+    #[default]
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// The base data for an EntityChange record (everything except id and created_at)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct EntityChangeBase {
+    pub entity_type: EntityDiscriminants,
+    pub entity_id: Uuid,
+    pub network_id: Uuid,
+    /// Host the entity belongs to. For hosts, the host itself.
+    pub host_id: Option<Uuid>,
+    pub operation: ChangeOperation,
+    /// The entity as it was after the change. For deletions, as it was just before.
+    #[schema(value_type = Object)]
+    pub snapshot: serde_json::Value,
+    /// Who made the change, ie `user:<id>`, `daemon:<id>` or `system`
+    pub changed_by: String,
+}
+
+/// One entry in the append-only change journal. `created_at` is when the change was made.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct EntityChange {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: EntityChangeBase,
+}
+
+impl EntityChange {
+    pub fn new(base: EntityChangeBase) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+}
+
+impl Display for EntityChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntityChange({} {} {} at {})",
+            self.base.entity_type, self.base.entity_id, self.base.operation, self.created_at
+        )
+    }
+}
+
+/// A host and its children as they were at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct HostSnapshot {
+    pub host_id: Uuid,
+    pub at: DateTime<Utc>,
+    #[schema(value_type = Object)]
+    pub host: serde_json::Value,
+    #[schema(value_type = Vec<Object>)]
+    pub interfaces: Vec<serde_json::Value>,
+    #[schema(value_type = Vec<Object>)]
+    pub ports: Vec<serde_json::Value>,
+    #[schema(value_type = Vec<Object>)]
+    pub services: Vec<serde_json::Value>,
+    #[schema(value_type = Vec<Object>)]
+    pub bindings: Vec<serde_json::Value>,
+}
+
+/// How an entity differs between the two ends of a diff
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// An entity that differs between the two ends of a diff
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct EntityDiff {
+    pub entity_type: EntityDiscriminants,
+    pub entity_id: Uuid,
+    pub host_id: Option<Uuid>,
+    pub kind: DiffKind,
+    /// Top-level fields whose values differ. Empty for added and removed entities.
+    pub changed_fields: Vec<String>,
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<serde_json::Value>,
+}
+
+/// Everything that differs on a network between two points in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NetworkChangeDiff {
+    pub network_id: Uuid,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub changes: Vec<EntityDiff>,
+}
@@ -0,0 +1,251 @@
+//! Folding the change journal back into entity state.
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+use crate::server::{
+    entity_changes::r#impl::base::{
+        ChangeOperation, DiffKind, EntityChange, EntityDiff, HostSnapshot,
+    },
+    shared::entities::EntityDiscriminants,
+};
+
+/// The latest change to each entity, from changes in the order they were made. Entities whose
+/// latest change is a deletion didn't exist at that point and are left out.
+pub fn latest_states<'a>(
+    changes: impl IntoIterator<Item = &'a EntityChange>,
+) -> HashMap<Uuid, &'a EntityChange> {
+    let mut states = HashMap::new();
+    for change in changes {
+        if change.base.operation == ChangeOperation::Deleted {
+            states.remove(&change.base.entity_id);
+        } else {
+            states.insert(change.base.entity_id, change);
+        }
+    }
+    states
+}
+
+/// A snapshot with `updated_at` removed at every level. Saves that didn't change anything else
+/// (discovery re-saves every binding, for instance) compare equal.
+pub fn comparable(snapshot: &Value) -> Value {
+    match snapshot {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .filter(|(key, _)| key.as_str() != "updated_at")
+                .map(|(key, value)| (key.clone(), comparable(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.iter().map(comparable).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Top-level fields whose values differ, ignoring `updated_at`
+pub fn changed_fields(before: &Value, after: &Value) -> Vec<String> {
+    let (Value::Object(before), Value::Object(after)) = (comparable(before), comparable(after))
+    else {
+        return Vec::new();
+    };
+
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect()
+}
+
+/// Rebuild a host and its children at `at` from the host's changes up to that point, in the
+/// order they were made. Returns None if the host didn't exist then.
+pub fn host_snapshot(
+    host_id: Uuid,
+    at: DateTime<Utc>,
+    changes: &[EntityChange],
+) -> Option<HostSnapshot> {
+    let states = latest_states(changes.iter().filter(|c| c.created_at <= at));
+    let host = states.get(&host_id)?;
+
+    let mut children: Vec<&&EntityChange> = states
+        .values()
+        .filter(|c| c.base.entity_id != host_id)
+        .collect();
+    children.sort_by_key(|c| (c.created_at, c.base.entity_id));
+
+    let of_type = |entity_type: EntityDiscriminants| -> Vec<Value> {
+        children
+            .iter()
+            .filter(|c| c.base.entity_type == entity_type)
+            .map(|c| c.base.snapshot.clone())
+            .collect()
+    };
+
+    Some(HostSnapshot {
+        host_id,
+        at,
+        host: host.base.snapshot.clone(),
+        interfaces: of_type(EntityDiscriminants::Interface),
+        ports: of_type(EntityDiscriminants::Port),
+        services: of_type(EntityDiscriminants::Service),
+        bindings: of_type(EntityDiscriminants::Binding),
+    })
+}
+
+/// Compare entities at `from` and `to`, given every change to them up to `to` in the order they
+/// were made. Entities that were created and deleted in between, or that were saved without
+/// changing, are left out.
+pub fn diff(from: DateTime<Utc>, changes: &[EntityChange]) -> Vec<EntityDiff> {
+    let before = latest_states(changes.iter().filter(|c| c.created_at <= from));
+    let after = latest_states(changes);
+
+    let entity_ids: BTreeSet<Uuid> = changes.iter().map(|c| c.base.entity_id).collect();
+
+    let mut diffs: Vec<EntityDiff> = entity_ids
+        .into_iter()
+        .filter_map(|entity_id| {
+            let before = before.get(&entity_id);
+            let after = after.get(&entity_id);
+            let latest = after.or(before)?;
+
+            let (kind, changed_fields) = match (before, after) {
+                (None, Some(_)) => (DiffKind::Added, Vec::new()),
+                (Some(_), None) => (DiffKind::Removed, Vec::new()),
+                (Some(before), Some(after)) => {
+                    let fields = changed_fields(&before.base.snapshot, &after.base.snapshot);
+                    if fields.is_empty() {
+                        return None;
+                    }
+                    (DiffKind::Modified, fields)
+                }
+                (None, None) => return None,
+            };
+
+            Some(EntityDiff {
+                entity_type: latest.base.entity_type,
+                entity_id,
+                host_id: latest.base.host_id,
+                kind,
+                changed_fields,
+                before: before.map(|c| c.base.snapshot.clone()),
+                after: after.map(|c| c.base.snapshot.clone()),
+            })
+        })
+        .collect();
+
+    diffs.sort_by_key(|d| (d.host_id, d.entity_type.to_string(), d.entity_id));
+    diffs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::entity_changes::r#impl::base::EntityChangeBase;
+    use chrono::Duration;
+    use serde_json::json;
+
+    fn change(
+        entity_type: EntityDiscriminants,
+        entity_id: Uuid,
+        host_id: Uuid,
+        operation: ChangeOperation,
+        at: DateTime<Utc>,
+        snapshot: Value,
+    ) -> EntityChange {
+        EntityChange {
+            id: Uuid::new_v4(),
+            created_at: at,
+            base: EntityChangeBase {
+                entity_type,
+                entity_id,
+                network_id: Uuid::nil(),
+                host_id: Some(host_id),
+                operation,
+                snapshot,
+                changed_by: "system".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_changed_fields_ignores_updated_at() {
+        let before = json!({"name": "web", "updated_at": "2026-01-01T00:00:00Z", "bindings": [{"id": 1, "updated_at": "a"}]});
+        let after = json!({"name": "web-1", "updated_at": "2026-01-02T00:00:00Z", "bindings": [{"id": 1, "updated_at": "b"}]});
+
+        assert_eq!(changed_fields(&before, &after), vec!["name".to_string()]);
+    }
+
+    #[test]
+    fn test_host_snapshot_and_diff() {
+        let start = Utc::now();
+        let host_id = Uuid::new_v4();
+        let port_id = Uuid::new_v4();
+        let service_id = Uuid::new_v4();
+
+        let changes = vec![
+            change(
+                EntityDiscriminants::Host,
+                host_id,
+                host_id,
+                ChangeOperation::Created,
+                start,
+                json!({"name": "nas"}),
+            ),
+            change(
+                EntityDiscriminants::Port,
+                port_id,
+                host_id,
+                ChangeOperation::Created,
+                start,
+                json!({"number": 22}),
+            ),
+            change(
+                EntityDiscriminants::Host,
+                host_id,
+                host_id,
+                ChangeOperation::Updated,
+                start + Duration::minutes(10),
+                json!({"name": "nas-1"}),
+            ),
+            change(
+                EntityDiscriminants::Port,
+                port_id,
+                host_id,
+                ChangeOperation::Deleted,
+                start + Duration::minutes(10),
+                json!({"number": 22}),
+            ),
+            change(
+                EntityDiscriminants::Service,
+                service_id,
+                host_id,
+                ChangeOperation::Created,
+                start + Duration::minutes(10),
+                json!({"name": "SMB"}),
+            ),
+        ];
+
+        let early = host_snapshot(host_id, start + Duration::minutes(1), &changes).unwrap();
+        assert_eq!(early.host, json!({"name": "nas"}));
+        assert_eq!(early.ports, vec![json!({"number": 22})]);
+        assert!(early.services.is_empty());
+
+        assert!(host_snapshot(host_id, start - Duration::minutes(1), &changes).is_none());
+
+        let diffs = diff(start + Duration::minutes(1), &changes);
+        let kind_of = |id: Uuid| diffs.iter().find(|d| d.entity_id == id).map(|d| d.kind);
+
+        assert_eq!(kind_of(host_id), Some(DiffKind::Modified));
+        assert_eq!(kind_of(port_id), Some(DiffKind::Removed));
+        assert_eq!(kind_of(service_id), Some(DiffKind::Added));
+        assert_eq!(
+            diffs
+                .iter()
+                .find(|d| d.entity_id == host_id)
+                .unwrap()
+                .changed_fields,
+            vec!["name".to_string()]
+        );
+    }
+}
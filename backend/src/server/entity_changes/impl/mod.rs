@@ -0,0 +1,3 @@
+pub mod base;
+pub mod history;
+mod storage; // Storable impl for EntityChange - append-only, so no Entity impl
@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::server::{
+    entity_changes::r#impl::base::{ChangeOperation, EntityChange, EntityChangeBase},
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{SqlValue, Storable},
+    },
+};
+
+impl Storable for EntityChange {
+    type BaseData = EntityChangeBase;
+
+    fn table_name() -> &'static str {
+        "entity_changes"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        EntityChange::new(base)
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            base:
+                Self::BaseData {
+                    entity_type,
+                    entity_id,
+                    network_id,
+                    host_id,
+                    operation,
+                    snapshot,
+                    changed_by,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "entity_type",
+                "entity_id",
+                "network_id",
+                "host_id",
+                "operation",
+                "snapshot",
+                "changed_by",
+                "created_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::EntityDiscriminant(entity_type),
+                SqlValue::Uuid(entity_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::OptionalUuid(host_id),
+                SqlValue::String(operation.to_string()),
+                SqlValue::JsonValue(snapshot),
+                SqlValue::String(changed_by),
+                SqlValue::Timestamp(created_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let entity_type: EntityDiscriminants =
+            serde_json::from_str(&row.get::<String, _>("entity_type"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize entity_type: {}", e))?;
+        let operation: String = row.get("operation");
+
+        Ok(EntityChange {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: EntityChangeBase {
+                entity_type,
+                entity_id: row.get("entity_id"),
+                network_id: row.get("network_id"),
+                host_id: row.get("host_id"),
+                operation: ChangeOperation::from_str(&operation)
+                    .map_err(|e| anyhow::anyhow!("Failed to deserialize operation: {}", e))?,
+                snapshot: row.get("snapshot"),
+                changed_by: row.get("changed_by"),
+            },
+        })
+    }
+}
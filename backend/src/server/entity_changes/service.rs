@@ -0,0 +1,281 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use strum::IntoDiscriminant;
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    entity_changes::r#impl::{
+        base::{ChangeOperation, EntityChange, EntityChangeBase, HostSnapshot, NetworkChangeDiff},
+        history,
+    },
+    services::r#impl::base::Service,
+    shared::{
+        entities::{Entity, EntityDiscriminants},
+        events::types::{EntityEvent, EntityOperation},
+        storage::{
+            filter::StorableFilter,
+            generic::GenericPostgresStorage,
+            traits::{PaginatedResult, Storage},
+        },
+    },
+};
+
+const ORDER_OLDEST_FIRST: &str = "entity_changes.created_at ASC, entity_changes.id ASC";
+const ORDER_NEWEST_FIRST: &str = "entity_changes.created_at DESC, entity_changes.id DESC";
+
+/// Append-only journal of changes to hosts and their interfaces, ports, services and bindings,
+/// recorded from entity events.
+pub struct EntityChangeService {
+    storage: Arc<GenericPostgresStorage<EntityChange>>,
+    /// Bindings only reference their service, so the service's host is looked up when recording
+    service_storage: Arc<GenericPostgresStorage<Service>>,
+}
+
+impl EntityChangeService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<EntityChange>>,
+        service_storage: Arc<GenericPostgresStorage<Service>>,
+    ) -> Self {
+        Self {
+            storage,
+            service_storage,
+        }
+    }
+
+    pub async fn get_paginated(
+        &self,
+        filter: StorableFilter<EntityChange>,
+    ) -> Result<PaginatedResult<EntityChange>> {
+        self.storage.get_paginated(filter, ORDER_NEWEST_FIRST).await
+    }
+
+    /// Record the change an entity event describes. Updates that don't change anything but
+    /// `updated_at` are skipped, as discovery re-saves entities it finds again.
+    pub async fn record(&self, event: &EntityEvent) -> Result<()> {
+        let operation = match event.operation {
+            EntityOperation::Created => ChangeOperation::Created,
+            EntityOperation::Updated => ChangeOperation::Updated,
+            EntityOperation::Deleted => ChangeOperation::Deleted,
+            _ => return Ok(()),
+        };
+
+        let (network_id, host_id, snapshot) = match &event.entity_type {
+            Entity::Host(host) => (host.base.network_id, Some(host.id), to_snapshot(host)?),
+            Entity::Interface(interface) => (
+                interface.base.network_id,
+                Some(interface.base.host_id),
+                to_snapshot(interface)?,
+            ),
+            Entity::Port(port) => (
+                port.base.network_id,
+                Some(port.base.host_id),
+                to_snapshot(port)?,
+            ),
+            Entity::Service(service) => (
+                service.base.network_id,
+                Some(service.base.host_id),
+                to_snapshot(service)?,
+            ),
+            Entity::Binding(binding) => (
+                binding.network_id(),
+                self.binding_host_id(binding.base.service_id, binding.id)
+                    .await?,
+                to_snapshot(binding)?,
+            ),
+            _ => return Ok(()),
+        };
+
+        let entity_type = event.entity_type.discriminant();
+
+        if operation == ChangeOperation::Updated
+            && let Some(latest) = self.latest_for_entity(event.entity_id).await?
+            && latest.base.operation != ChangeOperation::Deleted
+            && history::comparable(&latest.base.snapshot) == history::comparable(&snapshot)
+        {
+            return Ok(());
+        }
+
+        let changed_by = changed_by(&event.authentication);
+
+        self.storage
+            .create(&EntityChange {
+                id: Uuid::new_v4(),
+                created_at: event.timestamp,
+                base: EntityChangeBase {
+                    entity_type,
+                    entity_id: event.entity_id,
+                    network_id,
+                    host_id,
+                    operation,
+                    snapshot,
+                    changed_by: changed_by.clone(),
+                },
+            })
+            .await?;
+
+        // Children removed by ON DELETE CASCADE don't publish events of their own
+        if operation == ChangeOperation::Deleted
+            && let Some(host_id) = host_id
+        {
+            match entity_type {
+                EntityDiscriminants::Host => {
+                    self.record_cascaded_deletions(host_id, event.timestamp, &changed_by, |c| {
+                        c.base.entity_id != host_id
+                    })
+                    .await?;
+                }
+                EntityDiscriminants::Service => {
+                    let service_id = serde_json::Value::from(event.entity_id.to_string());
+                    self.record_cascaded_deletions(host_id, event.timestamp, &changed_by, |c| {
+                        c.base.entity_type == EntityDiscriminants::Binding
+                            && c.base.snapshot.get("service_id") == Some(&service_id)
+                    })
+                    .await?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Record deletions for the children of a deleted host or service that still exist in the
+    /// journal
+    async fn record_cascaded_deletions(
+        &self,
+        host_id: Uuid,
+        at: DateTime<Utc>,
+        changed_by: &str,
+        is_child: impl Fn(&EntityChange) -> bool,
+    ) -> Result<()> {
+        let changes = self.get_for_host(host_id, at).await?;
+        let states = history::latest_states(&changes);
+
+        for state in states.values().filter(|c| is_child(c)) {
+            self.storage
+                .create(&EntityChange {
+                    id: Uuid::new_v4(),
+                    created_at: at,
+                    base: EntityChangeBase {
+                        operation: ChangeOperation::Deleted,
+                        changed_by: changed_by.to_string(),
+                        ..state.base.clone()
+                    },
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn binding_host_id(&self, service_id: Uuid, binding_id: Uuid) -> Result<Option<Uuid>> {
+        if let Some(service) = self.service_storage.get_by_id(&service_id).await? {
+            return Ok(Some(service.base.host_id));
+        }
+
+        Ok(self
+            .latest_for_entity(binding_id)
+            .await?
+            .and_then(|c| c.base.host_id))
+    }
+
+    async fn latest_for_entity(&self, entity_id: Uuid) -> Result<Option<EntityChange>> {
+        let filter = StorableFilter::<EntityChange>::new()
+            .uuid_column("entity_id", &entity_id)
+            .limit(1);
+
+        Ok(self
+            .storage
+            .get_all_ordered(filter, ORDER_NEWEST_FIRST)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    async fn get_for_host(&self, host_id: Uuid, until: DateTime<Utc>) -> Result<Vec<EntityChange>> {
+        let filter = StorableFilter::<EntityChange>::new()
+            .host_id(&host_id)
+            .created_at_or_before(until);
+
+        self.storage
+            .get_all_ordered(filter, ORDER_OLDEST_FIRST)
+            .await
+    }
+
+    /// The host and its children as they were at `at`. Returns None if the journal has no
+    /// record of the host existing then.
+    pub async fn get_host_snapshot(
+        &self,
+        host_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<Option<(Uuid, HostSnapshot)>> {
+        let changes = self.get_for_host(host_id, at).await?;
+
+        Ok(
+            history::host_snapshot(host_id, at, &changes).and_then(|snapshot| {
+                changes
+                    .iter()
+                    .find(|c| c.base.entity_id == host_id)
+                    .map(|c| (c.base.network_id, snapshot))
+            }),
+        )
+    }
+
+    /// What differs on a network between `from` and `to`
+    pub async fn get_network_diff(
+        &self,
+        network_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<NetworkChangeDiff> {
+        let in_window = StorableFilter::<EntityChange>::new()
+            .network_ids(&[network_id])
+            .created_after(from)
+            .created_at_or_before(to);
+        let mut entity_ids: Vec<Uuid> = self
+            .storage
+            .get_all(in_window)
+            .await?
+            .into_iter()
+            .map(|c| c.base.entity_id)
+            .collect();
+        entity_ids.sort();
+        entity_ids.dedup();
+
+        let changes = if entity_ids.is_empty() {
+            Vec::new()
+        } else {
+            let filter = StorableFilter::<EntityChange>::new()
+                .uuid_columns("entity_id", &entity_ids)
+                .created_at_or_before(to);
+            self.storage
+                .get_all_ordered(filter, ORDER_OLDEST_FIRST)
+                .await?
+        };
+
+        Ok(NetworkChangeDiff {
+            network_id,
+            from,
+            to,
+            changes: history::diff(from, &changes),
+        })
+    }
+}
+
+fn to_snapshot<T: Serialize>(entity: &T) -> Result<serde_json::Value> {
+    Ok(serde_json::to_value(entity)?)
+}
+
+fn changed_by(authentication: &AuthenticatedEntity) -> String {
+    match authentication {
+        AuthenticatedEntity::User { user_id, .. } => format!("user:{}", user_id),
+        AuthenticatedEntity::ApiKey { api_key_id, .. } => format!("api_key:{}", api_key_id),
+        AuthenticatedEntity::Daemon { daemon_id, .. } => format!("daemon:{}", daemon_id),
+        AuthenticatedEntity::ExternalService { name } => format!("external_service:{}", name),
+        AuthenticatedEntity::System => "system".to_string(),
+        AuthenticatedEntity::Anonymous => "anonymous".to_string(),
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use anyhow::Error;
+use async_trait::async_trait;
+
+use crate::server::{
+    entity_changes::service::EntityChangeService,
+    shared::{
+        entities::EntityDiscriminants,
+        events::{
+            bus::{EventFilter, EventSubscriber},
+            types::{EntityOperation, Event},
+        },
+    },
+};
+
+#[async_trait]
+impl EventSubscriber for EntityChangeService {
+    fn event_filter(&self) -> EventFilter {
+        let operations = Some(vec![
+            EntityOperation::Created,
+            EntityOperation::Updated,
+            EntityOperation::Deleted,
+        ]);
+
+        EventFilter::entity_only(HashMap::from([
+            (EntityDiscriminants::Host, operations.clone()),
+            (EntityDiscriminants::Interface, operations.clone()),
+            (EntityDiscriminants::Port, operations.clone()),
+            (EntityDiscriminants::Service, operations.clone()),
+            (EntityDiscriminants::Binding, operations),
+        ]))
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), Error> {
+        for event in events {
+            if let Event::Entity(entity_event) = event
+                && let Err(e) = self.record(&entity_event).await
+            {
+                tracing::error!(
+                    entity_type = %entity_event.entity_type,
+                    entity_id = %entity_event.entity_id,
+                    error = %e,
+                    "Failed to record entity change"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // No debounce: batches dedupe events by entity and operation, which would drop intermediate
+    // states from the journal
+
+    fn name(&self) -> &str {
+        "entity_changes"
+    }
+}
@@ -1,23 +1,27 @@
 use axum::Json;
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::server::auth::middleware::permissions::{Authorized, Member, Viewer};
+use crate::server::auth::middleware::auth::AuthenticatedEntity;
+use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
 use crate::server::bindings::r#impl::base::Binding;
 use crate::server::config::AppState;
+use crate::server::discovery::r#impl::results::DiscoveryResultKey;
 use crate::server::groups::r#impl::base::Group;
 use crate::server::shared::handlers::ordering::OrderField;
 use crate::server::shared::handlers::query::{
     FilterQueryExtractor, OrderDirection, PaginationParams,
 };
-use crate::server::shared::handlers::traits::{create_handler, update_handler};
+use crate::server::shared::handlers::traits::{CrudHandlers, create_handler, update_handler};
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::storage::filter::StorableFilter;
 use crate::server::shared::storage::traits::Storable;
 use crate::server::shared::types::api::{
     ApiError, ApiErrorResponse, ApiResponse, ApiResult, PaginatedApiResponse,
 };
+use crate::server::shared::validation::validate_entity;
 use std::sync::Arc;
 use utoipa::IntoParams;
 use utoipa_axum::{router::OpenApiRouter, routes};
@@ -196,7 +200,8 @@ async fn get_all_groups(
 )]
 async fn create_group(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<Or<Member, IsDaemon>>,
+    headers: HeaderMap,
     Json(group): Json<Group>,
 ) -> ApiResult<Json<ApiResponse<Group>>> {
     // Custom validation: Check for service bindings on different networks
@@ -217,8 +222,66 @@ async fn create_group(
         }
     }
 
+    if let AuthenticatedEntity::Daemon { network_id, .. } = &auth.entity {
+        let result_key = DiscoveryResultKey::from_headers(&headers);
+        return create_discovered_group(&state, *network_id, group, auth.into_entity(), result_key)
+            .await;
+    }
+
     // Delegate to generic handler (handles validation, auth checks, creation)
-    create_handler::<Group>(State(state), auth, Json(group)).await
+    create_handler::<Group>(State(state), auth.into_permission::<Member>(), Json(group)).await
+}
+
+/// Create a group a daemon found in discovery. Groups are matched to those of earlier runs by
+/// name and origin - the daemon and the Docker host, cluster or other source it read - so
+/// same-named stacks on different hosts stay separate. Matched groups get their bindings
+/// replaced rather than being added again. Replays of a result
+/// the daemon already delivered return the group saved the first time.
+async fn create_discovered_group(
+    state: &AppState,
+    network_id: Uuid,
+    group: Group,
+    entity: AuthenticatedEntity,
+    result_key: Option<DiscoveryResultKey>,
+) -> ApiResult<Json<ApiResponse<Group>>> {
+    if group.base.network_id != network_id {
+        return Err(ApiError::entity_network_mismatch::<Group>());
+    }
+    validate_entity(|| CrudHandlers::validate(&group), Group::entity_name())?;
+
+    let discovery_service = &state.services.discovery_service;
+    if let Some(key) = &result_key
+        && let Some(saved) = discovery_service.applied_result(key, network_id).await?
+    {
+        return Ok(Json(ApiResponse::success(saved)));
+    }
+
+    let service = &state.services.group_service;
+    let existing = service
+        .get_all(StorableFilter::<Group>::new().network_ids(&[network_id]))
+        .await?
+        .into_iter()
+        .find(|g| {
+            g.base.name == group.base.name
+                && g.base.source.shares_discovery_origin(&group.base.source)
+        });
+
+    let saved = match existing {
+        Some(mut existing) => {
+            existing.base.binding_ids = group.base.binding_ids;
+            existing.base.description = group.base.description;
+            service.update(&mut existing, entity).await?
+        }
+        None => service.create(group, entity).await?,
+    };
+
+    if let Some(key) = &result_key {
+        discovery_service
+            .record_result(key, network_id, &saved)
+            .await;
+    }
+
+    Ok(Json(ApiResponse::success(saved)))
 }
 
 /// Update a group
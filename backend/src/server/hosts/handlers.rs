@@ -12,10 +12,13 @@ use crate::server::shared::handlers::traits::{
 use crate::server::shared::services::traits::CrudService;
 use crate::server::shared::storage::{filter::StorableFilter, traits::Storable};
 use crate::server::shared::types::api::{ApiErrorResponse, EmptyApiResponse};
-use crate::server::shared::validation::{validate_network_access, validate_read_access};
+use crate::server::shared::validation::{
+    validate_network_access, validate_read_access, validate_service_definition_access,
+};
 use crate::server::{
     config::AppState,
     daemons::r#impl::base::Daemon,
+    discovery::r#impl::results::DiscoveryResultKey,
     hosts::r#impl::{
         api::{CreateHostRequest, DiscoveryHostRequest, HostResponse, UpdateHostRequest},
         base::Host,
@@ -24,6 +27,7 @@ use crate::server::{
     shared::types::api::{ApiError, ApiResponse, ApiResult, PaginatedApiResponse},
 };
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::Json;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -156,7 +160,8 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
 /// Returns all hosts the authenticated user has access to, with their
 /// interfaces, ports, and services included. Supports pagination via
 /// `limit` and `offset` query parameters, and ordering via `group_by`,
-/// `order_by`, and `order_direction`.
+/// `order_by`, and `order_direction`. Daemons get every host on their
+/// network, unpaginated.
 #[utoipa::path(
     get,
     path = "",
@@ -165,13 +170,29 @@ pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     responses(
         (status = 200, description = "List of hosts with their children", body = PaginatedApiResponse<HostResponse>),
     ),
-     security(("user_api_key" = []), ("session" = []))
+    security(("user_api_key" = []), ("session" = []), ("daemon_api_key" = []))
 )]
 async fn get_all_hosts(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<Or<Viewer, IsDaemon>>,
     Query(query): Query<HostFilterQuery>,
 ) -> ApiResult<Json<PaginatedApiResponse<HostResponse>>> {
+    if let AuthenticatedEntity::Daemon { network_id, .. } = auth.entity {
+        // Daemons read their own network's hosts to link what they find to them
+        let result = state
+            .services
+            .host_service
+            .get_all_host_responses(StorableFilter::<Host>::new().network_ids(&[network_id]))
+            .await?;
+        let total_count = result.len() as u64;
+        return Ok(Json(PaginatedApiResponse::success(
+            result,
+            total_count,
+            0,
+            0,
+        )));
+    }
+
     let network_ids = auth.network_ids();
     let organization_id = auth
         .organization_id()
@@ -225,13 +246,26 @@ async fn get_all_hosts(
         (status = 200, description = "Host found", body = ApiResponse<HostResponse>),
         (status = 404, description = "Host not found", body = ApiErrorResponse),
     ),
-     security(("user_api_key" = []), ("session" = []))
+     security(("user_api_key" = []), ("session" = []), ("daemon_api_key" = []))
 )]
 async fn get_host_by_id(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Viewer>,
+    auth: Authorized<Or<Viewer, IsDaemon>>,
     Path(id): Path<Uuid>,
 ) -> ApiResult<Json<ApiResponse<HostResponse>>> {
+    // Daemons read the hosts of remote Docker endpoints they scan, on their own network
+    if let AuthenticatedEntity::Daemon { network_id, .. } = auth.entity {
+        let host = state
+            .services
+            .host_service
+            .get_host_response(&id)
+            .await?
+            .filter(|h| h.network_id == network_id)
+            .ok_or_else(|| ApiError::entity_not_found::<Host>(id))?;
+
+        return Ok(Json(ApiResponse::success(host)));
+    }
+
     let network_ids = auth.network_ids();
     let organization_id = auth
         .organization_id()
@@ -303,7 +337,7 @@ async fn create_host(
             validate_network_access(Some(request.network_id), &network_ids, "create")?;
 
             // Validate network_id exists
-            let _network = state
+            let network = state
                 .services
                 .network_service
                 .get_by_id(&request.network_id)
@@ -328,10 +362,17 @@ async fn create_host(
                 }
             }
 
+            for service in &request.services {
+                validate_service_definition_access(
+                    &*service.service_definition,
+                    &network.base.organization_id,
+                )?;
+            }
+
             let host_response = host_service.create_from_request(request, entity).await?;
 
             Ok(Json(ApiResponse::success(HostCreateResponse::New(
-                host_response,
+                Box::new(host_response),
             ))))
         }
 
@@ -359,6 +400,7 @@ async fn create_host(
                 interfaces,
                 ports,
                 services,
+                ..
             } = discovery_request;
 
             let host_response = host_service
@@ -368,7 +410,7 @@ async fn create_host(
             let legacy_response = LegacyHostWithServicesResponse::from_host_response(host_response);
 
             Ok(Json(ApiResponse::success(HostCreateResponse::Legacy(
-                legacy_response,
+                Box::new(legacy_response),
             ))))
         }
 
@@ -457,6 +499,8 @@ async fn update_host(
 ///
 /// Used by daemons to report discovered hosts. Accepts full entities with
 /// pre-generated IDs. Uses upsert behavior to merge with existing hosts.
+/// Results sent with `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are
+/// applied once per session; replays return the response recorded the first time.
 ///
 /// Tagged as "internal" - included in OpenAPI spec for client generation
 /// but hidden from public documentation.
@@ -474,15 +518,20 @@ async fn update_host(
 async fn create_host_discovery(
     State(state): State<Arc<AppState>>,
     auth: Authorized<IsDaemon>,
+    headers: HeaderMap,
     Json(request): Json<DiscoveryHostRequest>,
 ) -> ApiResult<Json<ApiResponse<HostResponse>>> {
     let host_service = &state.services.host_service;
+    let discovery_service = &state.services.discovery_service;
+    let result_key = DiscoveryResultKey::from_headers(&headers);
 
     let DiscoveryHostRequest {
         host,
         interfaces,
         ports,
         services,
+        certificates,
+        physical_links,
     } = request;
 
     // Get daemon network_id from entity
@@ -498,10 +547,54 @@ async fn create_host_discovery(
         ));
     }
 
+    // Replayed after the daemon lost the response: it was applied the first time
+    if let Some(key) = &result_key
+        && let Some(host_response) = discovery_service
+            .applied_result(key, daemon_network_id)
+            .await?
+    {
+        return Ok(Json(ApiResponse::success(host_response)));
+    }
+
+    let entity = auth.into_entity();
+    let original_ports = ports.clone();
+
     let host_response = host_service
-        .discover_host(host, interfaces, ports, services, auth.into_entity())
+        .discover_host(host, interfaces, ports, services, entity.clone())
         .await?;
 
+    if !certificates.is_empty() {
+        state
+            .services
+            .tls_certificate_service
+            .record_discovered(
+                certificates,
+                &original_ports,
+                &host_response.ports,
+                entity.clone(),
+            )
+            .await?;
+    }
+
+    if let Some(physical_links) = physical_links {
+        state
+            .services
+            .physical_link_service
+            .record_discovered(
+                physical_links,
+                host_response.id,
+                host_response.network_id,
+                entity,
+            )
+            .await?;
+    }
+
+    if let Some(key) = &result_key {
+        discovery_service
+            .record_result(key, daemon_network_id, &host_response)
+            .await;
+    }
+
     Ok(Json(ApiResponse::success(host_response)))
 }
 
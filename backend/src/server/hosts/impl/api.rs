@@ -10,9 +10,11 @@ use crate::server::{
     bindings::r#impl::base::{Binding, BindingBase, BindingType},
     hosts::r#impl::{
         base::{Host, HostBase},
+        device::DeviceInfo,
         virtualization::HostVirtualization,
     },
     interfaces::r#impl::base::{Interface, InterfaceBase},
+    physical_links::r#impl::base::PhysicalLink,
     ports::r#impl::base::{Port, PortBase, PortConfig, PortType, TransportProtocol},
     services::r#impl::{
         base::{Service, ServiceBase},
@@ -21,6 +23,7 @@ use crate::server::{
     },
     shared::position::PositionedInput,
     shared::types::entities::EntitySource,
+    tls_certificates::r#impl::base::TlsCertificate,
 };
 
 // =============================================================================
@@ -52,6 +55,13 @@ pub struct DiscoveryHostRequest {
     pub interfaces: Vec<Interface>,
     pub ports: Vec<Port>,
     pub services: Vec<Service>,
+    /// Leaf certificates presented by the host's TLS-capable ports, keyed to `ports` by port_id
+    #[serde(default)]
+    pub certificates: Vec<TlsCertificate>,
+    /// Cabling the host reported from its LLDP/CDP neighbor and forwarding tables. None if the
+    /// host wasn't queried, which leaves previously reported links in place.
+    #[serde(default)]
+    pub physical_links: Option<Vec<PhysicalLink>>,
 }
 
 // =============================================================================
@@ -96,6 +106,8 @@ impl InterfaceInput {
                 mac_address: self.mac_address,
                 name: self.name,
                 position: self.position.unwrap_or(0),
+                dhcp_lease: None,
+                rtt_ms: None,
             },
         }
     }
@@ -201,6 +213,8 @@ impl ServiceInput {
                 name: self.name,
                 bindings,
                 virtualization: self.virtualization,
+                product: None,
+                version: None,
                 source,
                 tags: self.tags,
                 position: self.position.unwrap_or(0),
@@ -387,6 +401,7 @@ pub struct HostResponse {
     pub virtualization: Option<HostVirtualization>,
     pub hidden: bool,
     pub tags: Vec<Uuid>,
+    pub device_info: Option<DeviceInfo>,
 
     // Hydrated children (fetched by service layer)
     pub interfaces: Vec<Interface>,
@@ -411,6 +426,7 @@ impl HostResponse {
             virtualization,
             hidden,
             tags,
+            device_info,
             interfaces: _,
             ports: _,
             services: _,
@@ -429,6 +445,7 @@ impl HostResponse {
                 virtualization: virtualization.clone(),
                 hidden: *hidden,
                 tags: tags.clone(),
+                device_info: device_info.clone(),
             },
         }
     }
@@ -460,6 +477,7 @@ impl HostResponse {
             virtualization,
             hidden,
             tags,
+            device_info,
         } = base;
 
         Self {
@@ -474,6 +492,7 @@ impl HostResponse {
             virtualization,
             hidden,
             tags,
+            device_info,
             interfaces,
             ports,
             services,
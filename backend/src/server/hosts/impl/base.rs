@@ -1,3 +1,4 @@
+use crate::server::hosts::r#impl::device::DeviceInfo;
 use crate::server::hosts::r#impl::virtualization::HostVirtualization;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::types::api::deserialize_empty_string_as_none;
@@ -32,6 +33,10 @@ pub struct HostBase {
     #[serde(default)]
     #[schema(required)]
     pub tags: Vec<Uuid>,
+    /// Details the device reported over SNMP during discovery
+    #[serde(default)]
+    #[schema(read_only)]
+    pub device_info: Option<DeviceInfo>,
 }
 
 impl Default for HostBase {
@@ -45,6 +50,7 @@ impl Default for HostBase {
             virtualization: None,
             hidden: false,
             tags: Vec::new(),
+            device_info: None,
         }
     }
 }
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use mac_address::MacAddress;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::server::services::r#impl::upnp::UpnpDevice;
+
+/// Hardware details a device reported about itself over SNMP or in its UPnP device description
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct DeviceInfo {
+    /// Full sysDescr; the host description holds a truncated copy
+    pub system_description: Option<String>,
+    /// sysObjectID, which identifies the vendor and product line
+    pub system_object_id: Option<String>,
+    /// Manufacturer, from the UPnP device description
+    #[serde(default)]
+    pub manufacturer: Option<String>,
+    /// Model name of the chassis from ENTITY-MIB, or of the UPnP device
+    pub model: Option<String>,
+    /// Serial number of the chassis from ENTITY-MIB, or of the UPnP device
+    pub serial_number: Option<String>,
+    /// Name the owner gave the device, from the UPnP device description
+    #[serde(default)]
+    pub friendly_name: Option<String>,
+    /// Time since the SNMP agent last restarted, as of `collected_at`
+    pub uptime_seconds: Option<u64>,
+    /// Interfaces listed in the device's IF-MIB, ordered by ifIndex
+    #[serde(default)]
+    pub interfaces: Vec<DeviceInterface>,
+    pub collected_at: DateTime<Utc>,
+}
+
+/// Host descriptions are limited to 100 characters
+const MAX_HOST_DESCRIPTION_LENGTH: usize = 100;
+
+impl DeviceInfo {
+    /// Description to show for the host: the first line of sysDescr, which is where vendors put
+    /// the product and firmware, or else the manufacturer and model, cut to fit the host
+    /// description
+    pub fn host_description(&self) -> Option<String> {
+        let description = self
+            .system_description
+            .as_ref()
+            .and_then(|d| d.lines().next())
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .or_else(|| {
+                let product: Vec<&str> = [&self.manufacturer, &self.model]
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str)
+                    .collect();
+                (!product.is_empty()).then(|| product.join(" "))
+            })?;
+
+        Some(
+            description
+                .chars()
+                .take(MAX_HOST_DESCRIPTION_LENGTH)
+                .collect(),
+        )
+    }
+
+    /// Details from a UPnP device description alone, for devices that don't answer SNMP
+    pub fn from_upnp(device: &UpnpDevice) -> Self {
+        let mut device_info = Self {
+            system_description: None,
+            system_object_id: None,
+            manufacturer: None,
+            model: None,
+            serial_number: None,
+            friendly_name: None,
+            uptime_seconds: None,
+            interfaces: Vec::new(),
+            collected_at: Utc::now(),
+        };
+        device_info.merge_upnp(device);
+        device_info
+    }
+
+    /// Fill in what SNMP didn't report from the device's UPnP description
+    pub fn merge_upnp(&mut self, device: &UpnpDevice) {
+        self.manufacturer = self.manufacturer.take().or(device.manufacturer.clone());
+        self.model = self.model.take().or(device.model());
+        self.serial_number = self.serial_number.take().or(device.serial_number.clone());
+        self.friendly_name = self.friendly_name.take().or(device.friendly_name.clone());
+    }
+}
+
+/// An entry of the device's IF-MIB interface table
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct DeviceInterface {
+    /// ifIndex, which other MIBs use to refer to the interface
+    pub index: u32,
+    /// ifName, falling back to ifDescr when the device doesn't implement IF-MIB ifXTable
+    pub name: Option<String>,
+    /// ifAlias, the description an administrator gave the interface
+    pub alias: Option<String>,
+    #[schema(value_type = Option<String>)]
+    pub mac_address: Option<MacAddress>,
+}
@@ -123,6 +123,8 @@ impl LegacyInterface {
                 mac_address: self.mac_address,
                 name: self.name,
                 position: 0,
+                dhcp_lease: None,
+                rtt_ms: None,
             },
         }
     }
@@ -276,6 +278,8 @@ impl LegacyService {
                 name: self.name,
                 bindings,
                 virtualization: None, // Old virtualization format ignored
+                product: None,
+                version: None,
                 source: EntitySource::Discovery { metadata: vec![] },
                 tags: self.tags,
                 position: 0,
@@ -330,6 +334,7 @@ impl LegacyHostWithServicesRequest {
                 },
                 virtualization: None,
                 hidden: host.hidden,
+                device_info: None,
                 tags: host.tags,
             },
         };
@@ -339,6 +344,8 @@ impl LegacyHostWithServicesRequest {
             interfaces,
             ports,
             services,
+            certificates: Vec::new(),
+            physical_links: None,
         }
     }
 }
@@ -460,7 +467,7 @@ impl<'de> Deserialize<'de> for HostCreateRequestBody {
 #[serde(untagged)]
 pub enum HostCreateResponse {
     /// New format response
-    New(HostResponse),
+    New(Box<HostResponse>),
     /// Legacy format response for old daemons
-    Legacy(LegacyHostWithServicesResponse),
+    Legacy(Box<LegacyHostWithServicesResponse>),
 }
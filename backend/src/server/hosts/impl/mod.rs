@@ -1,5 +1,6 @@
 pub mod api;
 pub mod base;
+pub mod device;
 pub mod handlers;
 pub mod legacy;
 pub mod storage;
@@ -6,6 +6,7 @@ use uuid::Uuid;
 use crate::server::{
     hosts::r#impl::{
         base::{Host, HostBase},
+        device::DeviceInfo,
         virtualization::HostVirtualization,
     },
     shared::{
@@ -68,6 +69,7 @@ impl Storable for Host {
                     source,
                     virtualization,
                     tags: _, // Stored in entity_tags junction table
+                    device_info,
                 },
         } = self.clone();
 
@@ -83,6 +85,7 @@ impl Storable for Host {
                 "hostname",
                 "hidden",
                 "virtualization",
+                "device_info",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -95,6 +98,7 @@ impl Storable for Host {
                 SqlValue::OptionalString(hostname),
                 SqlValue::Bool(hidden),
                 SqlValue::OptionalHostVirtualization(virtualization),
+                SqlValue::JsonValue(serde_json::to_value(device_info)?),
             ],
         ))
     }
@@ -107,6 +111,12 @@ impl Storable for Host {
         let virtualization: Option<HostVirtualization> =
             serde_json::from_value(row.get::<serde_json::Value, _>("virtualization"))
                 .map_err(|e| anyhow::anyhow!("Failed to deserialize virtualization: {}", e))?;
+        let device_info: Option<DeviceInfo> = row
+            .get::<Option<serde_json::Value>, _>("device_info")
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize device_info: {}", e))?
+            .flatten();
 
         Ok(Host {
             id: row.get("id"),
@@ -121,6 +131,7 @@ impl Storable for Host {
                 hidden: row.get("hidden"),
                 virtualization,
                 tags: Vec::new(), // Hydrated from entity_tags junction table
+                device_info,
             },
         })
     }
@@ -9,7 +9,10 @@ use crate::server::{
         },
         base::{Host, HostBase},
     },
-    interfaces::{r#impl::base::Interface, service::InterfaceService},
+    interfaces::{
+        r#impl::{base::Interface, dhcp::DhcpLease},
+        service::InterfaceService,
+    },
     ports::{r#impl::base::Port, service::PortService},
     services::{r#impl::base::Service, service::ServiceService},
     shared::{
@@ -448,6 +451,7 @@ impl HostService {
             source: source.clone(),
             virtualization,
             hidden,
+            device_info: None,
             tags,
         };
         let host = Host::new(host_base);
@@ -569,6 +573,9 @@ impl HostService {
                 if let Some(existing_iface) =
                     self.interface_service.get_by_id(&interface.id).await?
                 {
+                    let existing_iface = self
+                        .fill_interface_details(existing_iface, &interface, authentication.clone())
+                        .await?;
                     created_interfaces.push(existing_iface);
                     continue;
                 }
@@ -583,19 +590,26 @@ impl HostService {
                     .into_iter()
                     .find(|i| i.base.ip_address == interface.base.ip_address)
                 {
+                    let existing_iface = self
+                        .fill_interface_details(existing_iface, &interface, authentication.clone())
+                        .await?;
                     created_interfaces.push(existing_iface);
                     continue;
                 }
 
                 // MAC fallback: find by (host_id, mac_address) when subnet differs
-                // This handles cases where subnet_id changed between discovery runs
+                // This handles cases where subnet_id changed between discovery runs.
+                // Only same address family - a NIC's IPv4 and IPv6 addresses share a MAC
+                // but are distinct interfaces
                 if let Some(mac) = &interface.base.mac_address {
                     let mac_filter = StorableFilter::<Interface>::new()
                         .host_id(&interface.base.host_id)
                         .mac_address(mac);
                     let existing_by_mac: Vec<Interface> =
                         self.interface_service.get_all(mac_filter).await?;
-                    if let Some(existing_iface) = existing_by_mac.into_iter().next() {
+                    if let Some(existing_iface) = existing_by_mac.into_iter().find(|i| {
+                        i.base.ip_address.is_ipv4() == interface.base.ip_address.is_ipv4()
+                    }) {
                         tracing::debug!(
                             interface_ip = %interface.base.ip_address,
                             interface_mac = %mac,
@@ -603,6 +617,13 @@ impl HostService {
                             incoming_subnet_id = %interface.base.subnet_id,
                             "Found existing interface by MAC address (subnet_id differs)"
                         );
+                        let existing_iface = self
+                            .fill_interface_details(
+                                existing_iface,
+                                &interface,
+                                authentication.clone(),
+                            )
+                            .await?;
                         created_interfaces.push(existing_iface);
                         continue;
                     }
@@ -749,6 +770,8 @@ impl HostService {
                 name: "Unclaimed Open Ports".to_string(),
                 bindings: orphaned_bindings,
                 virtualization: None,
+                product: None,
+                version: None,
                 source: EntitySource::Discovery { metadata: vec![] },
                 tags: Vec::new(),
                 position: 0,
@@ -850,6 +873,7 @@ impl HostService {
                 description,
                 virtualization,
                 hidden,
+                device_info: existing.base.device_info,
                 tags: tags.clone(),
             },
         };
@@ -1200,6 +1224,29 @@ impl HostService {
             existing_host.base.hostname = new_host_data.base.hostname;
         }
 
+        // Update description if not set, ie from the sysDescr of a device that answered SNMP
+        if existing_host.base.description.is_none() && new_host_data.base.description.is_some() {
+            has_updates = true;
+            existing_host.base.description = new_host_data.base.description;
+        }
+
+        // Virtualization is only reported by discovery that reads it from the hypervisor, ie
+        // Proxmox VE's API, so it replaces whatever was set before
+        if new_host_data.base.virtualization.is_some()
+            && existing_host.base.virtualization != new_host_data.base.virtualization
+        {
+            has_updates = true;
+            existing_host.base.virtualization = new_host_data.base.virtualization;
+        }
+
+        // Device info is a snapshot, so the latest one reported replaces the previous one
+        if new_host_data.base.device_info.is_some()
+            && existing_host.base.device_info != new_host_data.base.device_info
+        {
+            has_updates = true;
+            existing_host.base.device_info = new_host_data.base.device_info;
+        }
+
         // Merge entity source metadata
         existing_host.base.source = match (existing_host.base.source, new_host_data.base.source) {
             (
@@ -1261,6 +1308,62 @@ impl HostService {
         Ok(existing_host)
     }
 
+    /// Fill in the name and MAC of an existing interface from a rediscovered one, for example
+    /// once the device has answered SNMP. Values that are already set are left alone, since
+    /// users may have renamed the interface. A DHCP lease is a snapshot of the server's
+    /// database, so a newer one replaces the old one when anything but its read time changed.
+    /// Round trip times jitter from scan to scan, so one is only replaced when it has at least
+    /// doubled or halved.
+    async fn fill_interface_details(
+        &self,
+        mut existing: Interface,
+        incoming: &Interface,
+        authentication: AuthenticatedEntity,
+    ) -> Result<Interface> {
+        let fill_name = existing.base.name.is_none() && incoming.base.name.is_some();
+        let fill_mac = existing.base.mac_address.is_none() && incoming.base.mac_address.is_some();
+        let replace_lease = match (&existing.base.dhcp_lease, &incoming.base.dhcp_lease) {
+            (Some(existing), Some(incoming)) => {
+                DhcpLease {
+                    collected_at: incoming.collected_at,
+                    ..existing.clone()
+                } != *incoming
+            }
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+        let replace_rtt = match (existing.base.rtt_ms, incoming.base.rtt_ms) {
+            (Some(existing), Some(incoming)) => {
+                incoming != existing
+                    && (incoming >= existing.saturating_mul(2)
+                        || existing >= incoming.saturating_mul(2))
+            }
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+
+        if !fill_name && !fill_mac && !replace_lease && !replace_rtt {
+            return Ok(existing);
+        }
+
+        if fill_name {
+            existing.base.name = incoming.base.name.clone();
+        }
+        if fill_mac {
+            existing.base.mac_address = incoming.base.mac_address;
+        }
+        if replace_lease {
+            existing.base.dhcp_lease = incoming.base.dhcp_lease.clone();
+        }
+        if replace_rtt {
+            existing.base.rtt_ms = incoming.base.rtt_ms;
+        }
+
+        self.interface_service
+            .update(&mut existing, authentication)
+            .await
+    }
+
     pub async fn consolidate_hosts(
         &self,
         destination_host: Host,
@@ -1309,9 +1412,11 @@ impl HostService {
                 // Match by subnet + IP
                 (dest_iface.base.subnet_id == other_iface.base.subnet_id
                     && dest_iface.base.ip_address == other_iface.base.ip_address)
-                    // Or match by MAC if both have one
+                    // Or match by MAC if both have one (same address family only)
                     || (dest_iface.base.mac_address.is_some()
-                        && dest_iface.base.mac_address == other_iface.base.mac_address)
+                        && dest_iface.base.mac_address == other_iface.base.mac_address
+                        && dest_iface.base.ip_address.is_ipv4()
+                            == other_iface.base.ip_address.is_ipv4())
             });
 
             if let Some(dest_iface) = matching_dest_iface {
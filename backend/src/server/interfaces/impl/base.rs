@@ -1,3 +1,4 @@
+use crate::server::interfaces::r#impl::dhcp::DhcpLease;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::position::Positioned;
 use crate::server::subnets::r#impl::base::Subnet;
@@ -29,6 +30,14 @@ pub struct InterfaceBase {
     /// Position of this interface in the host's interface list (for ordering)
     #[serde(default)]
     pub position: i32,
+    /// Lease or reservation the DHCP server holds for this address, from DHCP lease discovery
+    #[serde(default)]
+    pub dhcp_lease: Option<DhcpLease>,
+    /// Round trip time of an echo to this address, from the ping sweep of subnets the daemon
+    /// isn't attached to
+    #[serde(default)]
+    #[schema(read_only)]
+    pub rtt_ms: Option<u32>,
 }
 
 impl Default for InterfaceBase {
@@ -41,6 +50,8 @@ impl Default for InterfaceBase {
             mac_address: None,
             name: None,
             position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
         }
     }
 }
@@ -59,6 +70,8 @@ impl InterfaceBase {
             mac_address: None,
             name: Some(subnet.base.name.clone()),
             position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
         }
     }
 }
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What a DHCP server's lease database says about the address on an interface
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
+pub struct DhcpLease {
+    /// When the lease was granted or last renewed, if the server records it
+    pub starts: Option<DateTime<Utc>>,
+    /// When the lease runs out; none for infinite leases and reservations
+    pub ends: Option<DateTime<Utc>>,
+    /// The address is reserved for this client in the server's config rather than leased from a
+    /// pool
+    pub reserved: bool,
+    /// Name the client gave when it requested the lease
+    pub client_hostname: Option<String>,
+    /// When the lease database was read
+    pub collected_at: DateTime<Utc>,
+}
+
+impl DhcpLease {
+    /// Leases that ran out are left in the database until the server reclaims the address
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        !self.reserved && self.ends.is_some_and(|ends| ends < now)
+    }
+}
@@ -1,3 +1,4 @@
 pub mod base;
+pub mod dhcp;
 pub mod handlers;
 pub mod storage;
@@ -8,7 +8,10 @@ use sqlx::postgres::PgRow;
 use uuid::Uuid;
 
 use crate::server::{
-    interfaces::r#impl::base::{Interface, InterfaceBase},
+    interfaces::r#impl::{
+        base::{Interface, InterfaceBase},
+        dhcp::DhcpLease,
+    },
     shared::{
         entities::EntityDiscriminants,
         storage::{
@@ -70,6 +73,8 @@ impl Storable for Interface {
                     mac_address,
                     name,
                     position,
+                    dhcp_lease,
+                    rtt_ms,
                 },
         } = self.clone();
 
@@ -83,6 +88,8 @@ impl Storable for Interface {
                 "mac_address",
                 "name",
                 "position",
+                "dhcp_lease",
+                "rtt_ms",
                 "created_at",
                 "updated_at",
             ],
@@ -95,6 +102,8 @@ impl Storable for Interface {
                 SqlValue::OptionalMacAddress(mac_address),
                 SqlValue::OptionalString(name),
                 SqlValue::I32(position),
+                SqlValue::JsonValue(serde_json::to_value(dhcp_lease)?),
+                SqlValue::OptionalI32(rtt_ms.map(|ms| i32::try_from(ms).unwrap_or(i32::MAX))),
                 SqlValue::Timestamp(created_at),
                 SqlValue::Timestamp(updated_at),
             ],
@@ -113,6 +122,13 @@ impl Storable for Interface {
             row.try_get::<Option<MacAddress>, _>("mac_address")
                 .map_err(|e| anyhow::anyhow!("Failed to read mac_address: {}", e))?;
 
+        let dhcp_lease: Option<DhcpLease> = row
+            .get::<Option<serde_json::Value>, _>("dhcp_lease")
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize dhcp_lease: {}", e))?
+            .flatten();
+
         Ok(Interface {
             id: row.get("id"),
             created_at: row.get("created_at"),
@@ -125,6 +141,10 @@ impl Storable for Interface {
                 mac_address,
                 name: row.get("name"),
                 position: row.get("position"),
+                dhcp_lease,
+                rtt_ms: row
+                    .get::<Option<i32>, _>("rtt_ms")
+                    .map(|ms| ms.max(0) as u32),
             },
         })
     }
@@ -161,6 +181,8 @@ impl Entity for Interface {
 
     fn preserve_immutable_fields(&mut self, existing: &Self) {
         self.created_at = existing.created_at;
+        // Measured by discovery, never set through the API
+        self.base.rtt_ms = existing.base.rtt_ms;
     }
 }
 
@@ -1,11 +1,14 @@
+pub mod audit_logs;
 pub mod auth;
 pub mod billing;
 pub mod bindings;
 pub mod config;
+pub mod custom_service_definitions;
 pub mod daemon_api_keys;
 pub mod daemons;
 pub mod discovery;
 pub mod email;
+pub mod entity_changes;
 pub mod github;
 pub mod groups;
 pub mod hosts;
@@ -16,12 +19,18 @@ pub mod metrics;
 pub mod networks;
 pub mod openapi;
 pub mod organizations;
+pub mod physical_links;
 pub mod ports;
+pub mod scan_exclusions;
+pub mod scan_profiles;
 pub mod services;
 pub mod shared;
 pub mod shares;
+pub mod snmp_credentials;
 pub mod subnets;
 pub mod tags;
+pub mod tls_certificates;
 pub mod topology;
 pub mod user_api_keys;
 pub mod users;
+pub mod webhooks;
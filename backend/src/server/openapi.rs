@@ -17,9 +17,12 @@ use utoipa::openapi::{Components, OpenApi, PathItem};
 use utoipa_scalar::{Scalar, Servable};
 
 use crate::server::config::AppState;
+use crate::server::custom_service_definitions::handlers::CustomServiceDefinitionOrderField;
 use crate::server::daemons::handlers::DaemonOrderField;
 use crate::server::groups::handlers::GroupOrderField;
 use crate::server::hosts::handlers::HostOrderField;
+use crate::server::scan_exclusions::handlers::ScanExclusionOrderField;
+use crate::server::scan_profiles::handlers::ScanProfileOrderField;
 use crate::server::services::handlers::ServiceOrderField;
 use crate::server::shared::handlers::query::{OrderDirection, PaginationParams};
 use crate::server::subnets::handlers::SubnetOrderField;
@@ -42,6 +45,9 @@ const SERVER_VERSION: &str = env!("CARGO_PKG_VERSION");
         HostOrderField,
         ServiceOrderField,
         TagOrderField,
+        CustomServiceDefinitionOrderField,
+        ScanProfileOrderField,
+        ScanExclusionOrderField,
         GroupOrderField,
         SubnetOrderField,
         DaemonOrderField
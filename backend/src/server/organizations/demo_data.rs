@@ -443,6 +443,8 @@ fn create_host(
             mac_address: None,
             name: Some("eth0".to_string()),
             position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
         },
     };
     let host = Host {
@@ -457,6 +459,7 @@ fn create_host(
             source: EntitySource::Manual,
             virtualization: None,
             hidden: false,
+            device_info: None,
             tags,
         },
     };
@@ -497,6 +500,8 @@ fn create_service(
                 name: name.to_string(),
                 bindings,
                 virtualization: None,
+                product: None,
+                version: None,
                 source: EntitySource::Manual,
                 tags,
                 position: 0,
@@ -1388,6 +1393,7 @@ fn generate_daemons(
                 capabilities: DaemonCapabilities {
                     has_docker_socket: true,
                     interfaced_subnet_ids: vec![subnet.id],
+                    enforces_scan_exclusions: true,
                 },
                 mode: DaemonMode::Push,
                 name: "HQ Daemon".to_string(),
@@ -1417,6 +1423,7 @@ fn generate_daemons(
                 capabilities: DaemonCapabilities {
                     has_docker_socket: true,
                     interfaced_subnet_ids: vec![subnet.id],
+                    enforces_scan_exclusions: true,
                 },
                 mode: DaemonMode::Push,
                 name: "Cloud Daemon".to_string(),
@@ -1445,6 +1452,7 @@ fn generate_daemons(
                 capabilities: DaemonCapabilities {
                     has_docker_socket: false,
                     interfaced_subnet_ids: vec![subnet.id],
+                    enforces_scan_exclusions: true,
                 },
                 mode: DaemonMode::Push,
                 name: "Denver Daemon".to_string(),
@@ -1472,6 +1480,7 @@ fn generate_daemons(
                 capabilities: DaemonCapabilities {
                     has_docker_socket: false,
                     interfaced_subnet_ids: vec![subnet.id],
+                    enforces_scan_exclusions: true,
                 },
                 mode: DaemonMode::Push,
                 name: "Riverside Daemon".to_string(),
@@ -1,5 +1,7 @@
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
-use crate::server::auth::middleware::features::{BlockedInDemoMode, RequireFeature};
+use crate::server::auth::middleware::features::{
+    AuditLogsFeature, BlockedInDemoMode, RequireFeature,
+};
 use crate::server::auth::middleware::permissions::{Authorized, IsUser, Member, Owner};
 use crate::server::auth::service::hash_password;
 use crate::server::billing::types::base::BillingPlan;
@@ -29,6 +31,7 @@ pub const DEMO_USER_ID: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_4466554
 pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(get_organization, update_org_name))
+        .routes(routes!(update_audit_log_retention))
         .routes(routes!(reset))
         .routes(routes!(populate_demo_data))
 }
@@ -98,6 +101,60 @@ pub async fn update_org_name(
     .await
 }
 
+/// Update audit log retention
+///
+/// Sets how many days audit log entries are kept for, between 1 and 3650. Older entries are
+/// deleted periodically.
+#[utoipa::path(
+    put,
+    path = "/{id}/audit-log-retention",
+    tag = "organizations",
+    params(("id" = Uuid, Path, description = "Organization ID")),
+    request_body = i32,
+    responses(
+        (status = 200, description = "Organization updated", body = ApiResponse<Organization>),
+        (status = 400, description = "Retention out of range", body = ApiErrorResponse),
+        (status = 402, description = "Your plan does not include audit logs", body = ApiErrorResponse),
+        (status = 403, description = "Only owners can update organization", body = ApiErrorResponse),
+        (status = 404, description = "Organization not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+pub async fn update_audit_log_retention(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Owner>,
+    RequireFeature { .. }: RequireFeature<AuditLogsFeature>,
+    Path(id): Path<Uuid>,
+    Json(days): Json<i32>,
+) -> ApiResult<Json<ApiResponse<Organization>>> {
+    if auth.require_organization_id()? != id {
+        return Err(ApiError::entity_not_found::<Organization>(id));
+    }
+    if !(1..=3650).contains(&days) {
+        return Err(ApiError::bad_request(
+            "Audit log retention must be between 1 and 3650 days",
+        ));
+    }
+
+    let mut org = state
+        .services
+        .organization_service
+        .get_by_id(&id)
+        .await?
+        .ok_or_else(|| ApiError::entity_not_found::<Organization>(id))?;
+
+    org.base.audit_log_retention_days = days;
+
+    // Not through update_handler, which keeps the stored retention
+    let updated = state
+        .services
+        .organization_service
+        .update(&mut org, auth.into_entity())
+        .await?;
+
+    Ok(Json(ApiResponse::success(updated)))
+}
+
 /// Reset all organization data (delete all entities except organization and owner user)
 #[utoipa::path(
     post,
@@ -10,9 +10,14 @@ use crate::server::{
     shared::{entities::ChangeTriggersTopologyStaleness, events::types::TelemetryOperation},
 };
 
-#[derive(
-    Debug, Clone, Serialize, Validate, Deserialize, Default, PartialEq, Eq, Hash, ToSchema,
-)]
+/// Days audit log entries are kept for, unless an owner changes it
+pub const DEFAULT_AUDIT_LOG_RETENTION_DAYS: i32 = 90;
+
+fn default_audit_log_retention_days() -> i32 {
+    DEFAULT_AUDIT_LOG_RETENTION_DAYS
+}
+
+#[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 pub struct OrganizationBase {
     #[serde(default)]
     #[schema(read_only, required)]
@@ -27,6 +32,23 @@ pub struct OrganizationBase {
     pub plan_status: Option<String>,
     #[schema(read_only, required)]
     pub onboarding: Vec<TelemetryOperation>,
+    /// Days audit log entries are kept for. Set through the audit log retention endpoint.
+    #[serde(default = "default_audit_log_retention_days")]
+    #[schema(read_only, required)]
+    pub audit_log_retention_days: i32,
+}
+
+impl Default for OrganizationBase {
+    fn default() -> Self {
+        Self {
+            stripe_customer_id: None,
+            name: String::new(),
+            plan: None,
+            plan_status: None,
+            onboarding: Vec::new(),
+            audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
+        }
+    }
 }
 
 #[derive(
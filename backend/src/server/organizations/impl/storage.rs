@@ -63,6 +63,7 @@ impl Storable for Organization {
                     plan,
                     plan_status,
                     onboarding,
+                    audit_log_retention_days,
                 },
         } = self.clone();
 
@@ -76,6 +77,7 @@ impl Storable for Organization {
                 "plan",
                 "plan_status",
                 "onboarding",
+                "audit_log_retention_days",
             ],
             vec![
                 SqlValue::Uuid(id),
@@ -86,6 +88,7 @@ impl Storable for Organization {
                 SqlValue::OptionBillingPlan(plan),
                 SqlValue::OptionalString(plan_status),
                 SqlValue::TelemetryOperation(onboarding),
+                SqlValue::I32(audit_log_retention_days),
             ],
         ))
     }
@@ -110,6 +113,7 @@ impl Storable for Organization {
                 plan,
                 plan_status: row.get("plan_status"),
                 onboarding,
+                audit_log_retention_days: row.get("audit_log_retention_days"),
             },
         })
     }
@@ -151,5 +155,7 @@ impl Entity for Organization {
         self.base.plan_status = existing.base.plan_status.clone();
         // Onboarding state is server-managed
         self.base.onboarding = existing.base.onboarding.clone();
+        // Changed through its own endpoint, which requires the audit logs feature
+        self.base.audit_log_retention_days = existing.base.audit_log_retention_days;
     }
 }
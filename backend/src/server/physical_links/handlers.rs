@@ -0,0 +1,80 @@
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::config::AppState;
+use crate::server::physical_links::{r#impl::base::PhysicalLink, service::PhysicalLinkService};
+use crate::server::shared::handlers::query::{FilterQueryExtractor, PaginationParams};
+use crate::server::shared::handlers::traits::CrudHandlers;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::api::ApiResponse;
+
+/// Query for filtering physical links by the host that reported them.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct PhysicalLinkQuery {
+    /// Filter by the host that reported the link
+    pub host_id: Option<Uuid>,
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl FilterQueryExtractor for PhysicalLinkQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let filter = match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]),
+            None => filter.network_ids(user_network_ids),
+        };
+
+        match self.host_id {
+            Some(id) => filter.host_id(&id),
+            None => filter,
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+impl CrudHandlers for PhysicalLink {
+    type Service = PhysicalLinkService;
+    type FilterQuery = PhysicalLinkQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.physical_link_service
+    }
+}
+
+// Links are only ever reported by daemons, so there's no create or update
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(PhysicalLink, "physical_links", "physical_link");
+    crate::crud_get_by_id_handler!(PhysicalLink, "physical_links", "physical_link");
+    crate::crud_delete_handler!(PhysicalLink, "physical_links", "physical_link");
+    crate::crud_bulk_delete_handler!(PhysicalLink, "physical_links");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all))
+        .routes(routes!(generated::get_by_id, generated::delete))
+        .routes(routes!(generated::bulk_delete))
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use mac_address::MacAddress;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::net::IpAddr;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+
+/// Where a physical link was learned from
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+pub enum PhysicalLinkProtocol {
+    /// LLDP neighbor table (LLDP-MIB)
+    #[default]
+    Lldp,
+    /// Cisco Discovery Protocol neighbor table (CISCO-CDP-MIB)
+    Cdp,
+    /// A single device's MAC learned on an access port (BRIDGE-MIB / Q-BRIDGE-MIB)
+    ForwardingTable,
+}
+
+impl PhysicalLinkProtocol {
+    /// Neighbor protocols are reported by both ends and name the remote port, so they're
+    /// preferred over forwarding table entries when both describe the same cable
+    pub fn is_neighbor_protocol(&self) -> bool {
+        matches!(self, PhysicalLinkProtocol::Lldp | PhysicalLinkProtocol::Cdp)
+    }
+}
+
+/// The base data for a PhysicalLink entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct PhysicalLinkBase {
+    /// The switch (or other managed device) that reported the link
+    pub host_id: Uuid,
+    pub network_id: Uuid,
+    pub protocol: PhysicalLinkProtocol,
+    /// ifIndex of the reporting device's port
+    pub local_if_index: Option<u32>,
+    /// Name of the reporting device's port, ie `Gi1/0/12`
+    pub local_port: Option<String>,
+    /// MAC address of the neighbor: the LLDP chassis ID or the forwarding table entry
+    #[schema(value_type = Option<String>)]
+    pub remote_mac_address: Option<MacAddress>,
+    /// Management address the neighbor advertised
+    #[schema(value_type = Option<String>)]
+    pub remote_ip_address: Option<IpAddr>,
+    /// System name or device ID the neighbor advertised
+    pub remote_system_name: Option<String>,
+    /// Name of the neighbor's port, if it advertised one
+    pub remote_port: Option<String>,
+}
+
+/// A cable between a port on a managed device and a neighbor, as seen by the managed device.
+/// The neighbor is identified by what the device knows about it; the topology matches that to
+/// a host when it's built.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::physical_link)]
+pub struct PhysicalLink {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: PhysicalLinkBase,
+}
+
+impl ChangeTriggersTopologyStaleness<PhysicalLink> for PhysicalLink {
+    fn triggers_staleness(&self, other: Option<PhysicalLink>) -> bool {
+        if let Some(other_link) = other {
+            self.base != other_link.base
+        } else {
+            true
+        }
+    }
+}
+
+impl Display for PhysicalLink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let remote = self
+            .base
+            .remote_system_name
+            .clone()
+            .or_else(|| self.base.remote_ip_address.map(|ip| ip.to_string()))
+            .or_else(|| self.base.remote_mac_address.map(|mac| mac.to_string()))
+            .unwrap_or_else(|| "unknown neighbor".to_string());
+
+        write!(
+            f,
+            "Physical Link {} to {} (ID: {})",
+            self.base.local_port.as_deref().unwrap_or("unknown port"),
+            remote,
+            self.id
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+use crate::server::{
+    physical_links::r#impl::base::PhysicalLink, shared::storage::child::ChildStorableEntity,
+};
+
+impl ChildStorableEntity for PhysicalLink {
+    fn parent_column() -> &'static str {
+        "host_id"
+    }
+
+    fn parent_id(&self) -> Uuid {
+        self.base.host_id
+    }
+}
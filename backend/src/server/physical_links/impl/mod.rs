@@ -0,0 +1,3 @@
+pub mod base;
+mod child_storage; // ChildStorableEntity impl for PhysicalLink - parent relationship only
+mod storage; // StorableEntity impl for PhysicalLink - full CRUD infrastructure
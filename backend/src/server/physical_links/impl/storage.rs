@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use mac_address::MacAddress;
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::server::{
+    physical_links::r#impl::base::{PhysicalLink, PhysicalLinkBase, PhysicalLinkProtocol},
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+impl Storable for PhysicalLink {
+    type BaseData = PhysicalLinkBase;
+
+    fn table_name() -> &'static str {
+        "physical_links"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    host_id,
+                    network_id,
+                    protocol,
+                    local_if_index,
+                    local_port,
+                    remote_mac_address,
+                    remote_ip_address,
+                    remote_system_name,
+                    remote_port,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "host_id",
+                "network_id",
+                "protocol",
+                "local_if_index",
+                "local_port",
+                "remote_mac_address",
+                "remote_ip_address",
+                "remote_system_name",
+                "remote_port",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(host_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::String(protocol.to_string()),
+                SqlValue::OptionalI32(local_if_index.map(|i| i as i32)),
+                SqlValue::OptionalString(local_port),
+                SqlValue::OptionalMacAddress(remote_mac_address),
+                SqlValue::OptionalIpAddr(remote_ip_address),
+                SqlValue::OptionalString(remote_system_name),
+                SqlValue::OptionalString(remote_port),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let protocol: String = row.get("protocol");
+        let local_if_index: Option<i32> = row.get("local_if_index");
+
+        let remote_mac_address: Option<MacAddress> = row
+            .try_get::<Option<MacAddress>, _>("remote_mac_address")
+            .map_err(|e| anyhow::anyhow!("Failed to read remote_mac_address: {}", e))?;
+        let remote_ip_address: Option<IpNetwork> = row
+            .try_get("remote_ip_address")
+            .map_err(|e| anyhow::anyhow!("Failed to read remote_ip_address: {}", e))?;
+
+        Ok(PhysicalLink {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: PhysicalLinkBase {
+                host_id: row.get("host_id"),
+                network_id: row.get("network_id"),
+                protocol: PhysicalLinkProtocol::from_str(&protocol).unwrap_or_default(),
+                local_if_index: local_if_index.map(|i| i as u32),
+                local_port: row.get("local_port"),
+                remote_mac_address,
+                remote_ip_address: remote_ip_address.map(|network| network.ip()),
+                remote_system_name: row.get("remote_system_name"),
+                remote_port: row.get("remote_port"),
+            },
+        })
+    }
+}
+
+impl Entity for PhysicalLink {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::PhysicalLink
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "physical_link"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "physical_links"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
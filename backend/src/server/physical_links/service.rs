@@ -0,0 +1,96 @@
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    physical_links::r#impl::base::PhysicalLink,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{ChildCrudService, CrudService, EventBusService},
+        storage::{generic::GenericPostgresStorage, traits::Storable},
+    },
+    tags::entity_tags::EntityTagService,
+};
+
+pub struct PhysicalLinkService {
+    storage: Arc<GenericPostgresStorage<PhysicalLink>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<PhysicalLink> for PhysicalLinkService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &PhysicalLink) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &PhysicalLink) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<PhysicalLink> for PhysicalLinkService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<PhysicalLink>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl ChildCrudService<PhysicalLink> for PhysicalLinkService {}
+
+impl PhysicalLinkService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<PhysicalLink>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Replace the links a device reported with the ones from its latest discovery.
+    ///
+    /// Links reference the daemon's host ID, which may have been replaced by an existing host
+    /// during upsert, so they're moved onto the stored host. Links that are unchanged keep their
+    /// record; ones the device no longer reports are deleted.
+    pub async fn record_discovered(
+        &self,
+        links: Vec<PhysicalLink>,
+        host_id: Uuid,
+        network_id: Uuid,
+        authentication: AuthenticatedEntity,
+    ) -> Result<Vec<PhysicalLink>> {
+        let mut existing = self.get_for_parent(&host_id).await?;
+        let mut recorded = Vec::with_capacity(links.len());
+
+        for mut link in links {
+            link.base.host_id = host_id;
+            link.base.network_id = network_id;
+
+            if let Some(position) = existing.iter().position(|e| e.base == link.base) {
+                recorded.push(existing.swap_remove(position));
+                continue;
+            }
+
+            if recorded.iter().any(|r: &PhysicalLink| r.base == link.base) {
+                continue;
+            }
+
+            recorded.push(
+                self.create(PhysicalLink::new(link.base), authentication.clone())
+                    .await?,
+            );
+        }
+
+        if !existing.is_empty() {
+            let stale_ids: Vec<Uuid> = existing.iter().map(|l| l.id).collect();
+            self.delete_many(&stale_ids, authentication).await?;
+        }
+
+        Ok(recorded)
+    }
+}
@@ -199,6 +199,7 @@ pub enum PortType {
     SipTls,
     Rtsp,
     Dhcp,
+    Dhcpv6,
     #[default]
     Http,
     MySql,
@@ -439,6 +440,10 @@ impl PortType {
                 number: 67,
                 protocol: TransportProtocol::Udp,
             },
+            PortType::Dhcpv6 => PortConfig {
+                number: 547,
+                protocol: TransportProtocol::Udp,
+            },
             PortType::Http => PortConfig {
                 number: 80,
                 protocol: TransportProtocol::Tcp,
@@ -610,6 +615,7 @@ impl TypeMetadataProvider for PortType {
             PortType::SipTls => "SIP TLS",
             PortType::Rtsp => "RTSP",
             PortType::Dhcp => "DHCP",
+            PortType::Dhcpv6 => "DHCPv6",
             PortType::Http => "HTTP",
             PortType::Http8080 => "HTTP 8080",
             PortType::Https => "HTTPS",
@@ -668,6 +674,7 @@ impl TypeMetadataProvider for PortType {
             PortType::Ntp => "Network Time Protocol",
             PortType::Rtsp => "Real-Time Streaming Protocol",
             PortType::Dhcp => "Dynamic Host Configuration Protocol",
+            PortType::Dhcpv6 => "Dynamic Host Configuration Protocol for IPv6",
             PortType::Http => "Hypertext Transfer Protocol",
             PortType::Http8080 => "HTTP 8080",
             PortType::Https => "Hypertext Transfer Protocol Secure",
@@ -0,0 +1,310 @@
+use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Viewer};
+use crate::server::scan_exclusions::r#impl::base::{
+    ScanExclusion, ScanExclusionRule, ScanExclusions,
+};
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{create_handler, update_handler};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Storable, Storage};
+use crate::server::shared::types::api::{ApiError, ApiErrorResponse, PaginatedApiResponse};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+// ============================================================================
+// Scan Exclusion Ordering
+// ============================================================================
+
+/// Fields that scan exclusions can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanExclusionOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    UpdatedAt,
+}
+
+impl OrderField for ScanExclusionOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "scan_exclusions.created_at",
+            Self::Name => "scan_exclusions.name",
+            Self::UpdatedAt => "scan_exclusions.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Scan Exclusion Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering scan exclusions.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct ScanExclusionFilterQuery {
+    /// Only the exclusions that apply on this network: its own and the organization-wide ones.
+    pub network_id: Option<Uuid>,
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<ScanExclusionOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<ScanExclusionOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl ScanExclusionFilterQuery {
+    /// Build the ORDER BY clause.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<ScanExclusion>,
+    ) -> (StorableFilter<ScanExclusion>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "scan_exclusions.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for ScanExclusionFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        match self.network_id {
+            Some(network_id) if user_network_ids.contains(&network_id) => {
+                filter.network_ids_or_global(&[network_id])
+            }
+            // A network the user can't see leaves only the organization-wide exclusions
+            Some(_) => filter.network_ids_or_global(&[]),
+            None => filter.network_ids_or_global(user_network_ids),
+        }
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for most CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_get_by_id_handler!(ScanExclusion, "scan_exclusions", "scan_exclusion");
+    crate::crud_delete_handler!(ScanExclusion, "scan_exclusions", "scan_exclusion");
+    crate::crud_bulk_delete_handler!(ScanExclusion, "scan_exclusions");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_scan_exclusions, create_scan_exclusion))
+        .routes(routes!(
+            generated::get_by_id,
+            update_scan_exclusion,
+            generated::delete
+        ))
+        .routes(routes!(generated::bulk_delete))
+        .routes(routes!(get_resolved_scan_exclusions))
+}
+
+/// Check that a tag rule refers to a tag in the organization
+async fn validate_rule(
+    state: &AppState,
+    organization_id: Uuid,
+    rule: &ScanExclusionRule,
+) -> ApiResult<()> {
+    let ScanExclusionRule::Tag { tag_id } = rule else {
+        return Ok(());
+    };
+
+    state
+        .services
+        .tag_service
+        .get_by_id(tag_id)
+        .await?
+        .filter(|tag| tag.base.organization_id == organization_id)
+        .map(|_| ())
+        .ok_or_else(|| ApiError::scan_exclusion_tag_not_found(*tag_id))
+}
+
+/// List all scan exclusions
+///
+/// Returns the organization-wide scan exclusions and those on networks you have access to.
+/// Pass `network_id` to get only the exclusions discoveries on that network honour.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "scan_exclusions",
+    params(ScanExclusionFilterQuery),
+    responses(
+        (status = 200, description = "List of scan exclusions", body = PaginatedApiResponse<ScanExclusion>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_scan_exclusions(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        ScanExclusionFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<ScanExclusion>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let base_filter = query.apply_to_filter(
+        StorableFilter::<ScanExclusion>::new().organization_id(&organization_id),
+        &auth.network_ids(),
+        organization_id,
+    );
+
+    // Apply pagination
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(base_filter);
+
+    // Apply ordering
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .scan_exclusion_service
+        .storage()
+        .get_paginated(filter, &order_by)
+        .await?;
+
+    // Get effective pagination values for response metadata
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Create a new scan exclusion
+///
+/// Creates an exclusion that every discovery in your organization honours, or only those on
+/// `network_id` when set. Daemons that don't report the `enforces_scan_exclusions` capability
+/// ignore exclusions, so network discovery on them is refused while any exclusion applies to
+/// their network.
+///
+/// ### Validation
+///
+/// - Name must be 1-100 characters
+/// - An OUI must be three hex octets, ie `00:1B:63`
+/// - A tag must belong to your organization
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "scan_exclusions",
+    request_body = ScanExclusion,
+    responses(
+        (status = 200, description = "Scan exclusion created successfully", body = ApiResponse<ScanExclusion>),
+        (status = 400, description = "Invalid rule", body = ApiErrorResponse),
+        (status = 400, description = "Tag not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_scan_exclusion(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Json(mut exclusion): Json<ScanExclusion>,
+) -> ApiResult<Json<ApiResponse<ScanExclusion>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+    exclusion.base.organization_id = organization_id;
+
+    validate_rule(&state, organization_id, &exclusion.base.rule).await?;
+
+    create_handler::<ScanExclusion>(state, auth, Json(exclusion)).await
+}
+
+/// Update scan exclusion
+///
+/// Same validation rules as creating a scan exclusion.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "scan_exclusions",
+    params(("id" = Uuid, Path, description = "ScanExclusion ID")),
+    request_body = ScanExclusion,
+    responses(
+        (status = 200, description = "Scan exclusion updated successfully", body = ApiResponse<ScanExclusion>),
+        (status = 400, description = "Invalid rule", body = ApiErrorResponse),
+        (status = 400, description = "Tag not found", body = ApiErrorResponse),
+        (status = 404, description = "Scan exclusion not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_scan_exclusion(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    id: Path<Uuid>,
+    Json(exclusion): Json<ScanExclusion>,
+) -> ApiResult<Json<ApiResponse<ScanExclusion>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    validate_rule(&state, organization_id, &exclusion.base.rule).await?;
+
+    update_handler::<ScanExclusion>(state, auth, id, Json(exclusion)).await
+}
+
+/// Get resolved scan exclusions
+///
+/// Internal endpoint for daemons scanning outside a discovery session, ie deep scans of hosts
+/// passive discovery sees. Returns the exclusions on the daemon's network with tag rules
+/// resolved to addresses, and MAC and OUI rules also resolved to known matching addresses.
+#[utoipa::path(
+    get,
+    path = "/resolved",
+    tags = ["scan_exclusions", "internal"],
+    responses(
+        (status = 200, description = "Exclusions on the daemon's network", body = ApiResponse<ScanExclusions>),
+    ),
+    security(("daemon_api_key" = []))
+)]
+async fn get_resolved_scan_exclusions(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<IsDaemon>,
+) -> ApiResult<Json<ApiResponse<ScanExclusions>>> {
+    // IsDaemon guarantees exactly one network_id
+    let network_id = auth.network_ids()[0];
+
+    let exclusions = state
+        .services
+        .scan_exclusion_service
+        .resolve_for_network(&network_id)
+        .await?;
+
+    Ok(Json(ApiResponse::success(exclusions)))
+}
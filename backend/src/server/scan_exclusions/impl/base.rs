@@ -0,0 +1,241 @@
+use chrono::{DateTime, Utc};
+use cidr::IpCidr;
+use mac_address::MacAddress;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::net::IpAddr;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+
+/// What a scan exclusion matches on
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum ScanExclusionRule {
+    /// A single address
+    #[schema(title = "Ip")]
+    Ip {
+        #[schema(value_type = String)]
+        ip: IpAddr,
+    },
+    /// Every address in a range
+    #[schema(title = "Cidr")]
+    Cidr {
+        #[schema(value_type = String)]
+        cidr: IpCidr,
+    },
+    /// One network adapter
+    #[schema(title = "Mac")]
+    Mac {
+        #[schema(value_type = String)]
+        mac: MacAddress,
+    },
+    /// Every adapter from one vendor, by the first three octets of its MAC, ie `00:1B:63`
+    #[schema(title = "Oui")]
+    Oui { oui: String },
+    /// The addresses of every host with the tag on the network being scanned
+    #[schema(title = "Tag")]
+    Tag { tag_id: Uuid },
+}
+
+impl Default for ScanExclusionRule {
+    fn default() -> Self {
+        Self::Ip {
+            ip: IpAddr::from([0, 0, 0, 0]),
+        }
+    }
+}
+
+impl ScanExclusionRule {
+    /// Without a MAC, only address rules can match. Tag rules never match; the server resolves
+    /// them to address and MAC rules before handing exclusions to a daemon.
+    pub fn matches(&self, ip: IpAddr, mac: Option<MacAddress>) -> bool {
+        match self {
+            ScanExclusionRule::Ip { ip: excluded } => *excluded == ip,
+            ScanExclusionRule::Cidr { cidr } => cidr.contains(&ip),
+            ScanExclusionRule::Mac { mac: excluded } => mac == Some(*excluded),
+            ScanExclusionRule::Oui { oui } => mac
+                .zip(parse_oui(oui))
+                .is_some_and(|(mac, oui)| mac.bytes()[..3] == oui),
+            ScanExclusionRule::Tag { .. } => false,
+        }
+    }
+}
+
+fn parse_oui(oui: &str) -> Option<[u8; 3]> {
+    let octets: Vec<u8> = oui
+        .split([':', '-'])
+        .map(|octet| u8::from_str_radix(octet, 16).ok())
+        .collect::<Option<_>>()?;
+    octets.try_into().ok()
+}
+
+fn validate_rule(rule: &ScanExclusionRule) -> Result<(), ValidationError> {
+    match rule {
+        ScanExclusionRule::Oui { oui } if parse_oui(oui).is_none() => {
+            let mut err = ValidationError::new("oui");
+            err.message = Some("OUI must be three hex octets, ie 00:1B:63".into());
+            Err(err)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The base data for a ScanExclusion entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct ScanExclusionBase {
+    pub organization_id: Uuid,
+    /// Network the exclusion applies to; every network in the organization when not set
+    #[serde(default)]
+    #[schema(required)]
+    pub network_id: Option<Uuid>,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[validate(custom(function = "validate_rule"))]
+    pub rule: ScanExclusionRule,
+    /// Still record matching hosts that answer ARP, with only their address and MAC
+    #[serde(default)]
+    pub arp_only: bool,
+}
+
+/// Hosts that discovery must never probe, ie a PLC or a printer that reboots when port scanned
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::scan_exclusion)]
+pub struct ScanExclusion {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: ScanExclusionBase,
+}
+
+impl ChangeTriggersTopologyStaleness<ScanExclusion> for ScanExclusion {
+    fn triggers_staleness(&self, _other: Option<ScanExclusion>) -> bool {
+        false
+    }
+}
+
+impl Display for ScanExclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Scan Exclusion {} (ID: {})", self.base.name, self.id)
+    }
+}
+
+/// An exclusion as a daemon enforces it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct ScanExclusionTarget {
+    pub rule: ScanExclusionRule,
+    pub arp_only: bool,
+}
+
+/// How discovery treats an excluded host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exclusion {
+    /// Leave the host alone entirely
+    Skip,
+    /// Record the host from its ARP reply, but never scan it
+    ArpOnly,
+}
+
+/// The exclusions a discovery session honours, handed to the daemon with the session
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(transparent)]
+pub struct ScanExclusions(pub Vec<ScanExclusionTarget>);
+
+impl ScanExclusions {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// How a host has to be treated, the strictest matching exclusion winning
+    pub fn check(&self, ip: IpAddr, mac: Option<MacAddress>) -> Option<Exclusion> {
+        self.0
+            .iter()
+            .filter(|target| target.rule.matches(ip, mac))
+            .map(|target| {
+                if target.arp_only {
+                    Exclusion::ArpOnly
+                } else {
+                    Exclusion::Skip
+                }
+            })
+            .min_by_key(|exclusion| *exclusion == Exclusion::ArpOnly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strictest_matching_exclusion_wins() {
+        let plc: IpAddr = "10.0.0.5".parse().unwrap();
+        let printer_mac: MacAddress = "00:1b:63:aa:bb:cc".parse().unwrap();
+        let exclusions = ScanExclusions(vec![
+            ScanExclusionTarget {
+                rule: ScanExclusionRule::Cidr {
+                    cidr: "10.0.0.0/29".parse().unwrap(),
+                },
+                arp_only: true,
+            },
+            ScanExclusionTarget {
+                rule: ScanExclusionRule::Ip { ip: plc },
+                arp_only: false,
+            },
+            ScanExclusionTarget {
+                rule: ScanExclusionRule::Oui {
+                    oui: "00-1B-63".to_string(),
+                },
+                arp_only: false,
+            },
+        ]);
+
+        assert_eq!(exclusions.check(plc, None), Some(Exclusion::Skip));
+        assert_eq!(
+            exclusions.check("10.0.0.6".parse().unwrap(), None),
+            Some(Exclusion::ArpOnly)
+        );
+        assert_eq!(exclusions.check("10.0.1.6".parse().unwrap(), None), None);
+        assert_eq!(
+            exclusions.check("10.0.1.6".parse().unwrap(), Some(printer_mac)),
+            Some(Exclusion::Skip)
+        );
+    }
+
+    #[test]
+    fn test_validate_oui() {
+        assert!(
+            validate_rule(&ScanExclusionRule::Oui {
+                oui: "00:1b:63".to_string()
+            })
+            .is_ok()
+        );
+        assert!(
+            validate_rule(&ScanExclusionRule::Oui {
+                oui: "00:1b".to_string()
+            })
+            .is_err()
+        );
+        assert!(
+            validate_rule(&ScanExclusionRule::Oui {
+                oui: "00:1b:6g".to_string()
+            })
+            .is_err()
+        );
+    }
+}
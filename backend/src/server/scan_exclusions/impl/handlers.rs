@@ -0,0 +1,17 @@
+use crate::server::{
+    config::AppState,
+    scan_exclusions::{
+        handlers::ScanExclusionFilterQuery, r#impl::base::ScanExclusion,
+        service::ScanExclusionService,
+    },
+    shared::handlers::traits::CrudHandlers,
+};
+
+impl CrudHandlers for ScanExclusion {
+    type Service = ScanExclusionService;
+    type FilterQuery = ScanExclusionFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.scan_exclusion_service
+    }
+}
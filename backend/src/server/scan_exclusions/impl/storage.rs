@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    scan_exclusions::r#impl::base::{ScanExclusion, ScanExclusionBase},
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+impl Storable for ScanExclusion {
+    type BaseData = ScanExclusionBase;
+
+    fn table_name() -> &'static str {
+        "scan_exclusions"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    network_id,
+                    name,
+                    description,
+                    rule,
+                    arp_only,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "network_id",
+                "name",
+                "description",
+                "rule",
+                "arp_only",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::OptionalUuid(network_id),
+                SqlValue::String(name),
+                SqlValue::OptionalString(description),
+                SqlValue::JsonValue(serde_json::to_value(&rule)?),
+                SqlValue::Bool(arp_only),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let rule_value: serde_json::Value = row.get("rule");
+
+        Ok(ScanExclusion {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: ScanExclusionBase {
+                organization_id: row.get("organization_id"),
+                network_id: row.get("network_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                rule: serde_json::from_value(rule_value)?,
+                arp_only: row.get("arp_only"),
+            },
+        })
+    }
+}
+
+impl Entity for ScanExclusion {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ScanExclusion
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "scan_exclusion"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "scan_exclusions"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        self.base.network_id
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
@@ -0,0 +1,225 @@
+use crate::server::{
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    networks::r#impl::Network,
+    scan_exclusions::r#impl::base::{
+        ScanExclusion, ScanExclusionRule, ScanExclusionTarget, ScanExclusions,
+    },
+    shared::{
+        entities::EntityDiscriminants,
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::{filter::StorableFilter, generic::GenericPostgresStorage, traits::Storage},
+    },
+};
+use anyhow::{Result, anyhow};
+use std::net::IpAddr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ScanExclusionService {
+    storage: Arc<GenericPostgresStorage<ScanExclusion>>,
+    network_storage: Arc<GenericPostgresStorage<Network>>,
+    host_storage: Arc<GenericPostgresStorage<Host>>,
+    interface_storage: Arc<GenericPostgresStorage<Interface>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<ScanExclusion> for ScanExclusionService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &ScanExclusion) -> Option<Uuid> {
+        entity.base.network_id
+    }
+    fn get_organization_id(&self, entity: &ScanExclusion) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<ScanExclusion> for ScanExclusionService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<ScanExclusion>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::tags::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl ScanExclusionService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<ScanExclusion>>,
+        network_storage: Arc<GenericPostgresStorage<Network>>,
+        host_storage: Arc<GenericPostgresStorage<Host>>,
+        interface_storage: Arc<GenericPostgresStorage<Interface>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            storage,
+            network_storage,
+            host_storage,
+            interface_storage,
+            event_bus,
+        }
+    }
+
+    /// The exclusions a discovery on the network has to honour: the organization's and the
+    /// network's own. Tag rules are resolved to the addresses and MACs of the network's hosts
+    /// carrying the tag, since daemons don't know about tags. MAC and OUI rules are kept, and
+    /// also resolved to the addresses of known interfaces they match: a daemon only learns a
+    /// MAC over ARP, so off-link hosts would otherwise never match them.
+    pub async fn resolve_for_network(&self, network_id: &Uuid) -> Result<ScanExclusions> {
+        let network = self
+            .network_storage
+            .get_by_id(network_id)
+            .await?
+            .ok_or_else(|| anyhow!("Network {} not found", network_id))?;
+
+        let exclusions = self
+            .storage
+            .get_all(
+                StorableFilter::<ScanExclusion>::new()
+                    .organization_id(&network.base.organization_id)
+                    .network_ids_or_global(&[*network_id]),
+            )
+            .await?;
+
+        let mut network_interfaces: Option<Vec<Interface>> = None;
+        let mut targets = Vec::new();
+        for exclusion in exclusions {
+            let arp_only = exclusion.base.arp_only;
+            match exclusion.base.rule {
+                ScanExclusionRule::Tag { tag_id } => {
+                    for rule in self.resolve_tag(network_id, &tag_id).await? {
+                        targets.push(ScanExclusionTarget { rule, arp_only });
+                    }
+                }
+                rule @ (ScanExclusionRule::Mac { .. } | ScanExclusionRule::Oui { .. }) => {
+                    let interfaces = match &mut network_interfaces {
+                        Some(interfaces) => interfaces,
+                        None => network_interfaces.insert(
+                            self.interface_storage
+                                .get_all(
+                                    StorableFilter::<Interface>::new().network_ids(&[*network_id]),
+                                )
+                                .await?,
+                        ),
+                    };
+                    for ip in matching_addresses(&rule, interfaces) {
+                        targets.push(ScanExclusionTarget {
+                            rule: ScanExclusionRule::Ip { ip },
+                            arp_only,
+                        });
+                    }
+                    targets.push(ScanExclusionTarget { rule, arp_only });
+                }
+                rule => targets.push(ScanExclusionTarget { rule, arp_only }),
+            }
+        }
+
+        Ok(ScanExclusions(targets))
+    }
+
+    async fn resolve_tag(
+        &self,
+        network_id: &Uuid,
+        tag_id: &Uuid,
+    ) -> Result<Vec<ScanExclusionRule>> {
+        let host_ids: Vec<Uuid> = self
+            .host_storage
+            .get_all(
+                StorableFilter::<Host>::new()
+                    .network_ids(&[*network_id])
+                    .has_any_tags(&[*tag_id], EntityDiscriminants::Host),
+            )
+            .await?
+            .iter()
+            .map(|host| host.id)
+            .collect();
+
+        if host_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let interfaces = self
+            .interface_storage
+            .get_all(StorableFilter::<Interface>::new().host_ids(&host_ids))
+            .await?;
+
+        Ok(interfaces
+            .into_iter()
+            .flat_map(|interface| {
+                let mac = interface
+                    .base
+                    .mac_address
+                    .map(|mac| ScanExclusionRule::Mac { mac });
+                std::iter::once(ScanExclusionRule::Ip {
+                    ip: interface.base.ip_address,
+                })
+                .chain(mac)
+            })
+            .collect())
+    }
+}
+
+/// Addresses of the interfaces whose MAC a rule matches
+fn matching_addresses(rule: &ScanExclusionRule, interfaces: &[Interface]) -> Vec<IpAddr> {
+    let mut addresses: Vec<IpAddr> = interfaces
+        .iter()
+        .filter(|interface| {
+            interface
+                .base
+                .mac_address
+                .is_some_and(|mac| rule.matches(interface.base.ip_address, Some(mac)))
+        })
+        .map(|interface| interface.base.ip_address)
+        .collect();
+    addresses.sort();
+    addresses.dedup();
+    addresses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::interfaces::r#impl::base::InterfaceBase;
+
+    fn interface(ip: &str, mac: Option<&str>) -> Interface {
+        Interface::new(InterfaceBase {
+            ip_address: ip.parse().unwrap(),
+            mac_address: mac.map(|mac| mac.parse().unwrap()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_mac_and_oui_rules_resolve_to_known_addresses() {
+        let interfaces = vec![
+            interface("10.1.0.5", Some("00:1b:63:aa:bb:cc")),
+            interface("10.2.0.5", Some("00:1b:63:aa:bb:cc")),
+            interface("10.1.0.6", Some("00:1b:63:11:22:33")),
+            interface("10.1.0.7", Some("52:54:00:11:22:33")),
+            interface("10.1.0.8", None),
+        ];
+
+        let mac = ScanExclusionRule::Mac {
+            mac: "00:1b:63:aa:bb:cc".parse().unwrap(),
+        };
+        assert_eq!(
+            matching_addresses(&mac, &interfaces),
+            vec![
+                "10.1.0.5".parse::<IpAddr>().unwrap(),
+                "10.2.0.5".parse().unwrap()
+            ]
+        );
+
+        let oui = ScanExclusionRule::Oui {
+            oui: "00:1B:63".to_string(),
+        };
+        assert_eq!(matching_addresses(&oui, &interfaces).len(), 3);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::server::auth::middleware::auth::{AuthenticatedEntity, daemon_organization_id};
+use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
+use crate::server::discovery::r#impl::base::Discovery;
+use crate::server::discovery::r#impl::types::{DiscoveryType, RunType};
+use crate::server::scan_profiles::r#impl::base::ScanProfile;
+use crate::server::shared::handlers::ordering::OrderField;
+use crate::server::shared::handlers::query::{
+    FilterQueryExtractor, OrderDirection, PaginationParams,
+};
+use crate::server::shared::handlers::traits::{
+    BulkDeleteResponse, bulk_delete_handler, create_handler, delete_handler, get_by_id_handler,
+};
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::{Storable, Storage};
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+// ============================================================================
+// Scan Profile Ordering
+// ============================================================================
+
+/// Fields that scan profiles can be ordered/grouped by.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanProfileOrderField {
+    #[default]
+    CreatedAt,
+    Name,
+    UpdatedAt,
+}
+
+impl OrderField for ScanProfileOrderField {
+    fn to_sql(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "scan_profiles.created_at",
+            Self::Name => "scan_profiles.name",
+            Self::UpdatedAt => "scan_profiles.updated_at",
+        }
+    }
+}
+
+// ============================================================================
+// Scan Profile Filter Query
+// ============================================================================
+
+/// Query parameters for filtering and ordering scan profiles.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct ScanProfileFilterQuery {
+    /// Primary ordering field (used for grouping). Always sorts ASC to keep groups together.
+    pub group_by: Option<ScanProfileOrderField>,
+    /// Secondary ordering field (sorting within groups or standalone sort).
+    pub order_by: Option<ScanProfileOrderField>,
+    /// Direction for order_by field (group_by always uses ASC).
+    pub order_direction: Option<OrderDirection>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+impl ScanProfileFilterQuery {
+    /// Build the ORDER BY clause.
+    pub fn apply_ordering(
+        &self,
+        filter: StorableFilter<ScanProfile>,
+    ) -> (StorableFilter<ScanProfile>, String) {
+        crate::server::shared::handlers::ordering::apply_ordering(
+            self.group_by,
+            self.order_by,
+            self.order_direction,
+            filter,
+            "scan_profiles.created_at ASC",
+        )
+    }
+}
+
+impl FilterQueryExtractor for ScanProfileFilterQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        _user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+// Generated handlers for most CRUD operations
+mod generated {
+    use super::*;
+    crate::crud_update_handler!(ScanProfile, "scan_profiles", "scan_profile");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_scan_profiles, create_scan_profile))
+        .routes(routes!(
+            get_scan_profile_by_id,
+            generated::update,
+            delete_scan_profile
+        ))
+        .routes(routes!(bulk_delete_scan_profiles))
+}
+
+/// List all scan profiles
+///
+/// Returns all scan profiles in the authenticated user's organization.
+/// Supports pagination via `limit` and `offset` query parameters,
+/// and ordering via `group_by`, `order_by`, and `order_direction`.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "scan_profiles",
+    params(ScanProfileFilterQuery),
+    responses(
+        (status = 200, description = "List of scan profiles", body = PaginatedApiResponse<ScanProfile>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_scan_profiles(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Viewer>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        ScanProfileFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<ScanProfile>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+
+    let base_filter = StorableFilter::<ScanProfile>::new().organization_id(&organization_id);
+
+    // Apply pagination
+    let pagination = query.pagination();
+    let filter = pagination.apply_to_filter(base_filter);
+
+    // Apply ordering
+    let (filter, order_by) = query.apply_ordering(filter);
+
+    let result = state
+        .services
+        .scan_profile_service
+        .storage()
+        .get_paginated(filter, &order_by)
+        .await?;
+
+    // Get effective pagination values for response metadata
+    let limit = pagination.effective_limit().unwrap_or(0);
+    let offset = pagination.effective_offset();
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        limit,
+        offset,
+    )))
+}
+
+/// Get scan profile by ID
+///
+/// Daemons fetch the profile of the network discovery they are running, as long as it belongs
+/// to their network's organization.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = "scan_profiles",
+    params(("id" = Uuid, Path, description = "ScanProfile ID")),
+    responses(
+        (status = 200, description = "ScanProfile found", body = ApiResponse<ScanProfile>),
+        (status = 404, description = "ScanProfile not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_scan_profile_by_id(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Or<Viewer, IsDaemon>>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<ScanProfile>>> {
+    let AuthenticatedEntity::Daemon { network_id, .. } = auth.entity else {
+        return get_by_id_handler::<ScanProfile>(state, auth.into_permission(), path).await;
+    };
+
+    let Path(id) = path;
+    let organization_id = daemon_organization_id(&state, network_id).await?;
+
+    let profile = state
+        .services
+        .scan_profile_service
+        .get_by_id(&id)
+        .await?
+        .filter(|p| p.base.organization_id == organization_id)
+        .ok_or_else(|| ApiError::entity_not_found::<ScanProfile>(id))?;
+
+    Ok(Json(ApiResponse::success(profile)))
+}
+
+/// Create a new scan profile
+///
+/// Creates a scan profile in your organization that network discoveries can refer to.
+/// Settings left out take the values discoveries without a profile use.
+///
+/// ### Validation
+///
+/// - Name must be 1-100 characters
+/// - `connect_timeout_ms` must be within 50..=30000 and `retries` at most 5
+/// - `max_concurrency` must be within 1..=1000 when set
+/// - A top port count must be at least 1, and a port list must not be empty or contain port 0
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "scan_profiles",
+    request_body = ScanProfile,
+    responses(
+        (status = 200, description = "Scan profile created successfully", body = ApiResponse<ScanProfile>),
+        (status = 400, description = "Invalid scan settings", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_scan_profile(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    Json(mut profile): Json<ScanProfile>,
+) -> ApiResult<Json<ApiResponse<ScanProfile>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(|| ApiError::forbidden("Organization context required"))?;
+    profile.base.organization_id = organization_id;
+
+    create_handler::<ScanProfile>(state, auth, Json(profile)).await
+}
+
+/// Delete a scan profile
+///
+/// Profiles that a scheduled or ad-hoc network discovery refers to can't be deleted; point
+/// those discoveries at another profile first.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = "scan_profiles",
+    params(("id" = Uuid, Path, description = "ScanProfile ID")),
+    responses(
+        (status = 200, description = "ScanProfile deleted", body = EmptyApiResponse),
+        (status = 400, description = "ScanProfile is used by a discovery", body = ApiErrorResponse),
+        (status = 404, description = "ScanProfile not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn delete_scan_profile(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    ensure_profiles_unused(&state, auth.organization_id(), &[path.0]).await?;
+
+    delete_handler::<ScanProfile>(state, auth, path).await
+}
+
+/// Bulk delete scan profiles
+///
+/// Nothing is deleted if any of the profiles is used by a network discovery.
+#[utoipa::path(
+    post,
+    path = "/bulk-delete",
+    tag = "scan_profiles",
+    request_body(content = Vec<Uuid>, description = "Array of scan_profiles IDs to delete"),
+    responses(
+        (status = 200, description = "ScanProfiles deleted", body = ApiResponse<BulkDeleteResponse>),
+        (status = 400, description = "A ScanProfile is used by a discovery", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn bulk_delete_scan_profiles(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Member>,
+    json: Json<Vec<Uuid>>,
+) -> ApiResult<Json<ApiResponse<BulkDeleteResponse>>> {
+    ensure_profiles_unused(&state, auth.organization_id(), &json.0).await?;
+
+    bulk_delete_handler::<ScanProfile>(state, auth, json).await
+}
+
+/// Refuse to delete profiles that a network discovery in the organization would still scan
+/// with. Historical runs only record the profile they used, so they don't count.
+async fn ensure_profiles_unused(
+    state: &AppState,
+    organization_id: Option<Uuid>,
+    ids: &[Uuid],
+) -> ApiResult<()> {
+    let organization_id = organization_id.ok_or_else(ApiError::organization_required)?;
+
+    let network_ids: Vec<Uuid> = state
+        .services
+        .network_service
+        .get_all(StorableFilter::new().organization_id(&organization_id))
+        .await?
+        .iter()
+        .map(|n| n.id)
+        .collect();
+    let discoveries = state
+        .services
+        .discovery_service
+        .get_all(StorableFilter::<Discovery>::new().network_ids(&network_ids))
+        .await?;
+
+    let in_use = discoveries
+        .iter()
+        .find_map(|d| match d.base.discovery_type {
+            DiscoveryType::Network {
+                scan_profile_id: Some(profile_id),
+                ..
+            } if ids.contains(&profile_id)
+                && !matches!(d.base.run_type, RunType::Historical { .. }) =>
+            {
+                Some((profile_id, d))
+            }
+            _ => None,
+        });
+
+    let Some((profile_id, discovery)) = in_use else {
+        return Ok(());
+    };
+
+    let profile_name = state
+        .services
+        .scan_profile_service
+        .get_by_id(&profile_id)
+        .await?
+        .map(|p| p.base.name)
+        .unwrap_or_else(|| profile_id.to_string());
+
+    Err(ApiError::entity_in_use::<ScanProfile>(
+        profile_name,
+        format!("discovery '{}'", discovery.base.name),
+    ))
+}
@@ -0,0 +1,268 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::time::Duration;
+use strum::{Display as StrumDisplay, EnumIter, IntoEnumIterator};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+
+/// Which TCP ports a deep scan connects to
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type")]
+pub enum PortSelection {
+    /// Every port from 1 to 65535
+    #[schema(title = "All")]
+    All,
+    /// The most commonly open ports, most common first
+    #[schema(title = "Top")]
+    Top { count: u16 },
+    /// Exactly these ports
+    #[schema(title = "List")]
+    List { ports: Vec<u16> },
+    /// Only the ports that service definitions match on
+    #[schema(title = "ServiceDefinitions")]
+    ServiceDefinitions,
+}
+
+impl Default for PortSelection {
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+/// UDP services probed on each host. UDP ports only answer a request in their own protocol, so
+/// they are checked per protocol rather than by port.
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumIter,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+pub enum UdpProbe {
+    /// DNS query on port 53
+    Dns,
+    /// NTP request on port 123
+    Ntp,
+    /// DHCP discover on 67 or 547, only sent to gateways
+    Dhcp,
+    /// SNMP get of the system group on port 161, with the network's SNMP credentials
+    Snmp,
+}
+
+impl UdpProbe {
+    pub fn ports(&self) -> &'static [u16] {
+        match self {
+            UdpProbe::Dns => &[53],
+            UdpProbe::Ntp => &[123],
+            UdpProbe::Dhcp => &[67, 547],
+            UdpProbe::Snmp => &[161],
+        }
+    }
+}
+
+/// How a network discovery scans each host it finds. The defaults are what discoveries without a
+/// profile use.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Validate, Serialize, Deserialize, ToSchema)]
+pub struct ScanSettings {
+    #[serde(default)]
+    #[validate(custom(function = "validate_port_selection"))]
+    pub tcp_ports: PortSelection,
+    #[serde(default = "default_udp_probes")]
+    pub udp_probes: Vec<UdpProbe>,
+    /// How long to wait for a TCP connection before counting the port as closed
+    #[serde(default = "default_connect_timeout_ms")]
+    #[validate(range(min = 50, max = 30000))]
+    pub connect_timeout_ms: u32,
+    /// Further attempts at a port whose connection timed out
+    #[serde(default = "default_retries")]
+    #[validate(range(max = 5))]
+    pub retries: u8,
+    /// Request HTTP endpoints on open ports to identify web services, read the banners other
+    /// open ports greet clients with, and record the certificates presented by HTTPS ports
+    #[serde(default = "default_probe_endpoints")]
+    pub probe_endpoints: bool,
+    /// Most connections open against one host at a time. Leave unset to let the daemon size it
+    /// from its file descriptor limit; lower it for devices that fall over under load.
+    #[serde(default)]
+    #[validate(range(min = 1, max = 1000))]
+    pub max_concurrency: Option<u16>,
+    /// Ports connected to on hosts of routed subnets that don't answer an ICMP echo. A refused
+    /// connection counts too, so closed ports on a live host still give it away. Empty to only
+    /// trust ICMP.
+    #[serde(default = "default_ping_ports")]
+    #[validate(length(max = 16), custom(function = "validate_ping_ports"))]
+    pub ping_ports: Vec<u16>,
+}
+
+fn default_udp_probes() -> Vec<UdpProbe> {
+    UdpProbe::iter().collect()
+}
+
+fn default_connect_timeout_ms() -> u32 {
+    800
+}
+
+fn default_retries() -> u8 {
+    1
+}
+
+fn default_probe_endpoints() -> bool {
+    true
+}
+
+fn default_ping_ports() -> Vec<u16> {
+    vec![22, 80, 443, 445, 3389]
+}
+
+impl Default for ScanSettings {
+    fn default() -> Self {
+        Self {
+            tcp_ports: PortSelection::default(),
+            udp_probes: default_udp_probes(),
+            connect_timeout_ms: default_connect_timeout_ms(),
+            retries: default_retries(),
+            probe_endpoints: default_probe_endpoints(),
+            max_concurrency: None,
+            ping_ports: default_ping_ports(),
+        }
+    }
+}
+
+impl ScanSettings {
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms.into())
+    }
+
+    pub fn probes(&self, probe: UdpProbe) -> bool {
+        self.udp_probes.contains(&probe)
+    }
+}
+
+fn validate_port_selection(selection: &PortSelection) -> Result<(), ValidationError> {
+    let error = |message: &'static str| {
+        let mut err = ValidationError::new("port_selection");
+        err.message = Some(message.into());
+        Err(err)
+    };
+
+    match selection {
+        PortSelection::Top { count: 0 } => error("Top port count must be at least 1"),
+        PortSelection::List { ports } if ports.is_empty() => {
+            error("Port list must contain at least one port")
+        }
+        PortSelection::List { ports } if ports.contains(&0) => error("Port 0 can't be scanned"),
+        _ => Ok(()),
+    }
+}
+
+fn validate_ping_ports(ports: &[u16]) -> Result<(), ValidationError> {
+    if ports.contains(&0) {
+        let mut err = ValidationError::new("ping_ports");
+        err.message = Some("Port 0 can't be pinged".into());
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// The base data for a ScanProfile entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct ScanProfileBase {
+    pub organization_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub settings: ScanSettings,
+}
+
+/// Reusable scan settings that network discoveries across an organization can refer to, ie a
+/// quick sweep of common ports and a full weekly scan of the same subnets
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::scan_profile)]
+pub struct ScanProfile {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: ScanProfileBase,
+}
+
+impl ChangeTriggersTopologyStaleness<ScanProfile> for ScanProfile {
+    fn triggers_staleness(&self, _other: Option<ScanProfile>) -> bool {
+        false
+    }
+}
+
+impl Display for ScanProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Scan Profile {} (ID: {})", self.base.name, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_default_when_fields_left_out() {
+        let settings: ScanSettings = serde_json::from_str("{}").unwrap();
+        assert_eq!(settings, ScanSettings::default());
+    }
+
+    #[test]
+    fn test_validate_settings() {
+        let settings = |tcp_ports, connect_timeout_ms| ScanSettings {
+            tcp_ports,
+            connect_timeout_ms,
+            ..Default::default()
+        };
+
+        assert!(
+            settings(PortSelection::Top { count: 100 }, 800)
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            settings(PortSelection::Top { count: 0 }, 800)
+                .validate()
+                .is_err()
+        );
+        assert!(
+            settings(PortSelection::List { ports: vec![] }, 800)
+                .validate()
+                .is_err()
+        );
+        assert!(settings(PortSelection::All, 10).validate().is_err());
+        assert!(
+            ScanSettings {
+                ping_ports: vec![443, 0],
+                ..Default::default()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+}
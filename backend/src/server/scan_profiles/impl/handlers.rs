@@ -0,0 +1,16 @@
+use crate::server::{
+    config::AppState,
+    scan_profiles::{
+        handlers::ScanProfileFilterQuery, r#impl::base::ScanProfile, service::ScanProfileService,
+    },
+    shared::handlers::traits::CrudHandlers,
+};
+
+impl CrudHandlers for ScanProfile {
+    type Service = ScanProfileService;
+    type FilterQuery = ScanProfileFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.scan_profile_service
+    }
+}
@@ -0,0 +1,4 @@
+pub mod base;
+pub mod handlers;
+pub mod ports;
+pub mod storage;
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use crate::server::scan_profiles::r#impl::base::PortSelection;
+use crate::server::services::r#impl::base::Service;
+
+/// The 100 TCP ports most often found open, most common first, after nmap's frequency ranking
+const TOP_TCP_PORTS: [u16; 100] = [
+    80, 23, 443, 21, 22, 25, 3389, 110, 445, 139, 143, 53, 135, 3306, 8080, 1723, 111, 995, 993,
+    5900, 1025, 587, 8888, 199, 1720, 465, 548, 113, 81, 6001, 10000, 514, 5060, 179, 1026, 2000,
+    8443, 8000, 32768, 554, 26, 1433, 49152, 2001, 515, 8008, 49154, 1027, 5666, 646, 5000, 5631,
+    631, 49153, 8081, 2049, 88, 79, 5800, 106, 2121, 1110, 49155, 6000, 513, 990, 5357, 427, 49156,
+    543, 544, 5101, 144, 7, 389, 8009, 3128, 444, 9999, 5009, 7070, 5190, 3000, 5432, 1900, 3986,
+    13, 1029, 9, 5051, 6646, 49157, 1028, 873, 1755, 2717, 4899, 9100, 119, 37,
+];
+
+impl PortSelection {
+    /// The ports to connect to, in the order they should be tried
+    pub fn tcp_ports(&self) -> Vec<u16> {
+        match self {
+            PortSelection::All => (1..=65535).collect(),
+            PortSelection::Top { count } => {
+                // Past the ranked list, the ports services are recognized by are the next most
+                // useful, then everything else in order
+                let mut seen = HashSet::new();
+                TOP_TCP_PORTS
+                    .into_iter()
+                    .chain(service_definition_tcp_ports())
+                    .chain(1..=65535)
+                    .filter(|port| seen.insert(*port))
+                    .take((*count).into())
+                    .collect()
+            }
+            PortSelection::List { ports } => {
+                let mut seen = HashSet::new();
+                ports.iter().copied().filter(|p| seen.insert(*p)).collect()
+            }
+            PortSelection::ServiceDefinitions => service_definition_tcp_ports(),
+        }
+    }
+}
+
+fn service_definition_tcp_ports() -> Vec<u16> {
+    let mut ports: Vec<u16> = Service::all_discovery_ports()
+        .iter()
+        .filter(|p| p.is_tcp())
+        .map(|p| p.number())
+        .collect();
+    ports.sort_unstable();
+    ports.dedup();
+    ports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_ports_fill_past_ranked_list_without_repeats() {
+        let top = PortSelection::Top { count: 10 }.tcp_ports();
+        assert_eq!(top[..3], [80, 23, 443]);
+        assert_eq!(top.len(), 10);
+
+        let many = PortSelection::Top { count: 2000 }.tcp_ports();
+        assert_eq!(many.len(), 2000);
+        assert_eq!(many.iter().collect::<HashSet<_>>().len(), 2000);
+        assert_eq!(many[..100], TOP_TCP_PORTS);
+    }
+}
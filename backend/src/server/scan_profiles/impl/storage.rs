@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    scan_profiles::r#impl::base::{ScanProfile, ScanProfileBase},
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+};
+
+impl Storable for ScanProfile {
+    type BaseData = ScanProfileBase;
+
+    fn table_name() -> &'static str {
+        "scan_profiles"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    name,
+                    description,
+                    settings,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "description",
+                "settings",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::OptionalString(description),
+                SqlValue::JsonValue(serde_json::to_value(&settings)?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let settings_value: serde_json::Value = row.get("settings");
+
+        Ok(ScanProfile {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: ScanProfileBase {
+                organization_id: row.get("organization_id"),
+                name: row.get("name"),
+                description: row.get("description"),
+                settings: serde_json::from_value(settings_value)?,
+            },
+        })
+    }
+}
+
+impl Entity for ScanProfile {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::ScanProfile
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "scan_profile"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "scan_profiles"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
@@ -0,0 +1,49 @@
+use crate::server::{
+    scan_profiles::r#impl::base::ScanProfile,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::generic::GenericPostgresStorage,
+    },
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct ScanProfileService {
+    storage: Arc<GenericPostgresStorage<ScanProfile>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<ScanProfile> for ScanProfileService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &ScanProfile) -> Option<Uuid> {
+        None
+    }
+    fn get_organization_id(&self, entity: &ScanProfile) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<ScanProfile> for ScanProfileService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<ScanProfile>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(
+        &self,
+    ) -> Option<&Arc<crate::server::tags::entity_tags::EntityTagService>> {
+        None
+    }
+}
+
+impl ScanProfileService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<ScanProfile>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+}
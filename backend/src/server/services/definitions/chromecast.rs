@@ -21,10 +21,14 @@ impl ServiceDefinition for ChromecastDevice {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::GOOGLE),
-            Pattern::Port(PortType::new_tcp(8008)),
-            Pattern::Port(PortType::new_tcp(8009)),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::GOOGLE),
+                Pattern::Port(PortType::new_tcp(8008)),
+                Pattern::Port(PortType::new_tcp(8009)),
+            ]),
+            // Cast targets from other vendors (TVs, speakers) announce themselves the same way
+            Pattern::MdnsService("_googlecast._tcp"),
         ])
     }
 
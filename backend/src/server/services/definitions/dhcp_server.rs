@@ -19,7 +19,10 @@ impl ServiceDefinition for DhcpServer {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::Dhcp)
+        Pattern::AnyOf(vec![
+            Pattern::Port(PortType::Dhcp),
+            Pattern::Port(PortType::Dhcpv6),
+        ])
     }
 
     fn is_generic(&self) -> bool {
@@ -18,8 +18,12 @@ impl ServiceDefinition for ESPHome {
         ServiceCategory::HomeAutomation
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        // ESPHome API port
-        Pattern::Port(PortType::new_tcp(6052))
+        Pattern::AnyOf(vec![
+            // ESPHome API port
+            Pattern::Port(PortType::new_tcp(6052)),
+            // Devices announce their native API
+            Pattern::MdnsService("_esphomelib._tcp"),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/esphome.svg"
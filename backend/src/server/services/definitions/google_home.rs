@@ -26,9 +26,12 @@ impl ServiceDefinition for GoogleHome {
                 Pattern::MacVendor(Vendor::NEST),
                 Pattern::MacVendor(Vendor::GOOGLE),
             ]),
-            Pattern::AllOf(vec![
-                Pattern::Port(PortType::new_tcp(8008)),
-                Pattern::Port(PortType::new_tcp(8009)),
+            Pattern::AnyOf(vec![
+                Pattern::AllOf(vec![
+                    Pattern::Port(PortType::new_tcp(8008)),
+                    Pattern::Port(PortType::new_tcp(8009)),
+                ]),
+                Pattern::MdnsService("_googlecast._tcp"),
             ]),
         ])
     }
@@ -0,0 +1,35 @@
+use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::categories::ServiceCategory;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::Pattern;
+
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
+pub struct KubernetesIngress;
+
+impl ServiceDefinition for KubernetesIngress {
+    fn name(&self) -> &'static str {
+        "Kubernetes Ingress"
+    }
+    fn description(&self) -> &'static str {
+        "A Kubernetes Ingress, routing HTTP requests to Services by host and path"
+    }
+    fn category(&self) -> ServiceCategory {
+        ServiceCategory::Virtualization
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        Pattern::None // Imported from the cluster API by Kubernetes discovery
+    }
+
+    fn is_generic(&self) -> bool {
+        true
+    }
+
+    fn logo_url(&self) -> &'static str {
+        "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/kubernetes.svg"
+    }
+}
+
+inventory::submit!(ServiceDefinitionFactory::new(
+    create_service::<KubernetesIngress>
+));
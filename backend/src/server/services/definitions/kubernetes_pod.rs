@@ -0,0 +1,70 @@
+use crate::server::services::definitions::{ServiceDefinitionFactory, create_service};
+use crate::server::services::r#impl::base::DiscoverySessionServiceMatchParams;
+use crate::server::services::r#impl::categories::ServiceCategory;
+use crate::server::services::r#impl::definitions::ServiceDefinition;
+use crate::server::services::r#impl::patterns::{MatchConfidence, Pattern};
+use crate::server::services::r#impl::virtualization::{
+    KubernetesVirtualization, ServiceVirtualization,
+};
+
+#[derive(Default, Clone, Eq, PartialEq, Hash)]
+pub struct KubernetesPod;
+
+impl ServiceDefinition for KubernetesPod {
+    fn name(&self) -> &'static str {
+        "Kubernetes Pod"
+    }
+    fn description(&self) -> &'static str {
+        "A generic Kubernetes pod"
+    }
+    fn category(&self) -> ServiceCategory {
+        ServiceCategory::Virtualization
+    }
+
+    fn discovery_pattern(&self) -> Pattern<'_> {
+        Pattern::AllOf(vec![
+            Pattern::KubernetesPod,
+            Pattern::Custom(
+                |p: &DiscoverySessionServiceMatchParams| {
+                    // If there's a matched service in the same pod, the pod was already detected as a non-generic service
+                    let pod = match p.baseline_params.virtualization {
+                        Some(ServiceVirtualization::Kubernetes(KubernetesVirtualization {
+                            namespace,
+                            pod: Some(pod),
+                            ..
+                        })) => (namespace, pod),
+                        _ => return false,
+                    };
+
+                    p.service_params
+                        .matched_services
+                        .iter()
+                        .all(|s| match &s.base.virtualization {
+                            Some(ServiceVirtualization::Kubernetes(KubernetesVirtualization {
+                                namespace,
+                                pod: Some(other),
+                                ..
+                            })) => (namespace, other) != pod,
+                            _ => true,
+                        })
+                },
+                |_| Vec::new(),
+                "No other services in this pod have been matched",
+                "A service in this pod has already been matched",
+                MatchConfidence::Low,
+            ),
+        ])
+    }
+
+    fn is_generic(&self) -> bool {
+        true
+    }
+
+    fn logo_url(&self) -> &'static str {
+        "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/kubernetes.svg"
+    }
+}
+
+inventory::submit!(ServiceDefinitionFactory::new(
+    create_service::<KubernetesPod>
+));
@@ -1,6 +1,9 @@
 use crate::server::services::r#impl::definitions::ServiceDefinition;
 use crate::server::shared::types::metadata::HasId;
 use inventory;
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ServiceDefinitionFactory(pub fn() -> Box<dyn ServiceDefinition>);
@@ -24,21 +27,136 @@ where
 
 inventory::collect!(ServiceDefinitionFactory);
 
+type OrganizationDefinitions = HashMap<Uuid, Vec<Box<dyn ServiceDefinition>>>;
+
+/// Definitions loaded at runtime (organization-defined, declarative), by organization. Kept
+/// separate from the compiled-in inventory so they can be added, replaced and removed without a
+/// rebuild, and so one organization's definitions are never matched or listed for another.
+static CUSTOM_DEFINITIONS: LazyLock<RwLock<OrganizationDefinitions>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// The organization whose custom definitions this process matches services against. Only a
+/// daemon sets it, as it serves a single organization; the server never matches.
+static MATCHING_ORGANIZATION: RwLock<Option<Uuid>> = RwLock::new(None);
+
 pub struct ServiceDefinitionRegistry;
 
 impl ServiceDefinitionRegistry {
-    /// Get all registered services as instances
+    /// Get all definitions services are matched against: the compiled-in ones, plus the
+    /// daemon's organization's custom ones
     pub fn all_service_definitions() -> Vec<Box<dyn ServiceDefinition>> {
+        let organization_id = MATCHING_ORGANIZATION.read().ok().and_then(|id| *id);
+
+        match organization_id {
+            Some(organization_id) => Self::organization_service_definitions(&organization_id),
+            None => Self::builtin_service_definitions(),
+        }
+    }
+
+    /// Get only the compiled-in service definitions
+    pub fn builtin_service_definitions() -> Vec<Box<dyn ServiceDefinition>> {
         inventory::iter::<ServiceDefinitionFactory>()
             .map(|factory| factory.create())
             .collect()
     }
 
+    /// Get the compiled-in service definitions and an organization's custom ones
+    pub fn organization_service_definitions(
+        organization_id: &Uuid,
+    ) -> Vec<Box<dyn ServiceDefinition>> {
+        let mut definitions = Self::builtin_service_definitions();
+        definitions.extend(Self::custom_service_definitions(organization_id));
+        definitions
+    }
+
+    /// Get only an organization's runtime-loaded service definitions
+    pub fn custom_service_definitions(organization_id: &Uuid) -> Vec<Box<dyn ServiceDefinition>> {
+        CUSTOM_DEFINITIONS
+            .read()
+            .ok()
+            .and_then(|definitions| definitions.get(organization_id).cloned())
+            .unwrap_or_default()
+    }
+
+    /// Add an organization's runtime definition, replacing any existing definition with the
+    /// same id
+    pub fn register_custom(organization_id: Uuid, definition: Box<dyn ServiceDefinition>) {
+        if let Ok(mut definitions) = CUSTOM_DEFINITIONS.write() {
+            let organization_definitions = definitions.entry(organization_id).or_default();
+            organization_definitions.retain(|d| d.id() != definition.id());
+            organization_definitions.push(definition);
+        }
+    }
+
+    /// Remove an organization's runtime definition by id
+    pub fn unregister_custom(organization_id: &Uuid, id: &str) {
+        if let Ok(mut definitions) = CUSTOM_DEFINITIONS.write()
+            && let Some(organization_definitions) = definitions.get_mut(organization_id)
+        {
+            organization_definitions.retain(|d| d.id() != id);
+        }
+    }
+
+    /// Replace the full set of runtime definitions for every organization
+    pub fn replace_custom(new_definitions: OrganizationDefinitions) {
+        if let Ok(mut definitions) = CUSTOM_DEFINITIONS.write() {
+            *definitions = new_definitions;
+        }
+    }
+
+    /// Replace a daemon's organization's runtime definitions, and match services against them.
+    /// An organization with no custom definitions leaves only the compiled-in ones to match.
+    pub fn replace_matching(
+        organization_id: Option<Uuid>,
+        new_definitions: Vec<Box<dyn ServiceDefinition>>,
+    ) {
+        Self::replace_custom(
+            organization_id
+                .map(|id| HashMap::from([(id, new_definitions)]))
+                .unwrap_or_default(),
+        );
+
+        if let Ok(mut matching_organization) = MATCHING_ORGANIZATION.write() {
+            *matching_organization = organization_id;
+        }
+    }
+
     pub fn service_exists(id: &str) -> bool {
-        inventory::iter::<ServiceDefinitionFactory>().any(|factory| factory.create().id() == id)
+        Self::find_by_id(id).is_some()
     }
 
+    /// Find a definition by id. Custom definition ids embed their entity id, so a stored
+    /// service always resolves to its own organization's definition; ids from API input are
+    /// checked with `find_for_organization` instead.
     pub fn find_by_id(id: &str) -> Option<Box<dyn ServiceDefinition>> {
+        Self::find_builtin(id).or_else(|| {
+            CUSTOM_DEFINITIONS
+                .read()
+                .ok()?
+                .values()
+                .flatten()
+                .find(|d| d.id() == id)
+                .cloned()
+        })
+    }
+
+    /// Find a compiled-in definition, or one of an organization's custom definitions, by id
+    pub fn find_for_organization(
+        id: &str,
+        organization_id: &Uuid,
+    ) -> Option<Box<dyn ServiceDefinition>> {
+        Self::find_builtin(id).or_else(|| {
+            CUSTOM_DEFINITIONS
+                .read()
+                .ok()?
+                .get(organization_id)?
+                .iter()
+                .find(|d| d.id() == id)
+                .cloned()
+        })
+    }
+
+    fn find_builtin(id: &str) -> Option<Box<dyn ServiceDefinition>> {
         inventory::iter::<ServiceDefinitionFactory>().find_map(|factory| {
             let service_definition = factory.create();
             if service_definition.id() == id {
@@ -163,6 +281,9 @@ pub mod docker_container;
 pub mod docker_daemon;
 pub mod docker_swarm;
 pub mod kubernetes;
+pub mod kubernetes_ingress;
+pub mod kubernetes_pod;
+pub mod kubernetes_service;
 pub mod nomad;
 pub mod openshift;
 pub mod portainer;
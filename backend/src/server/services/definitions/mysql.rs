@@ -18,7 +18,13 @@ impl ServiceDefinition for MySQL {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::MySql)
+        // Auth plugin names are sent in the handshake by MySQL and MariaDB alike
+        Pattern::AnyOf(vec![
+            Pattern::Port(PortType::MySql),
+            Pattern::Banner(None, "mysql_native_password"),
+            Pattern::Banner(None, "caching_sha2_password"),
+            Pattern::Banner(Some(PortType::MySql), "MariaDB"),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/mysql.svg"
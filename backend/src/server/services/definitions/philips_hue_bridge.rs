@@ -19,9 +19,12 @@ impl ServiceDefinition for PhilipsHueBridge {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::PHILIPS),
-            Pattern::Endpoint(PortType::Http, "/", "hue", None),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::PHILIPS),
+                Pattern::Endpoint(PortType::Http, "/", "hue", None),
+            ]),
+            Pattern::MdnsService("_hue._tcp"),
         ])
     }
 
@@ -19,12 +19,15 @@ impl ServiceDefinition for QNAP {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::Port(PortType::Ftp),
-            Pattern::AnyOf(vec![
-                Pattern::Endpoint(PortType::Http, "/", "QNAP", None),
-                Pattern::Endpoint(PortType::Http8080, "/", "QNAP", None),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::Port(PortType::Ftp),
+                Pattern::AnyOf(vec![
+                    Pattern::Endpoint(PortType::Http, "/", "QNAP", None),
+                    Pattern::Endpoint(PortType::Http8080, "/", "QNAP", None),
+                ]),
             ]),
+            Pattern::UpnpDevice("QNAP", None),
         ])
     }
 
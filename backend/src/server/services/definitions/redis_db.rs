@@ -18,7 +18,12 @@ impl ServiceDefinition for RedisDB {
         ServiceCategory::Database
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::Redis)
+        // INFO replies with redis_version, unless auth is required or protected mode is on
+        Pattern::AnyOf(vec![
+            Pattern::Port(PortType::Redis),
+            Pattern::Banner(Some(PortType::Redis), "redis"),
+            Pattern::Banner(Some(PortType::Redis), "NOAUTH"),
+        ])
     }
     fn logo_url(&self) -> &'static str {
         "https://cdn.jsdelivr.net/gh/homarr-labs/dashboard-icons/svg/redis.svg"
@@ -21,9 +21,13 @@ impl ServiceDefinition for RokuDevice {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::ROKU),
-            Pattern::Port(PortType::new_tcp(8060)),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::ROKU),
+                Pattern::Port(PortType::new_tcp(8060)),
+            ]),
+            // Roku TVs from other brands still publish Roku's own description
+            Pattern::UpnpDevice("Roku", None),
         ])
     }
 
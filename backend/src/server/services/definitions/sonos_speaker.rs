@@ -23,18 +23,22 @@ impl ServiceDefinition for SonosSpeaker {
     fn discovery_pattern(&self) -> Pattern<'_> {
         // Sonos speakers have very distinctive port signature:
         // TCP 1400 (HTTP API), 1443 (HTTPS API), 4444 (control)
-        Pattern::AllOf(vec![
-            Pattern::MacVendor(Vendor::SONOS),
-            Pattern::AnyOf(vec![
-                Pattern::Port(PortType::Samba),
-                Pattern::Port(PortType::new_tcp(3445)),
-                Pattern::Port(PortType::new_tcp(1400)),
-                Pattern::Port(PortType::new_tcp(1410)),
-                Pattern::Port(PortType::new_tcp(1843)),
-                Pattern::Port(PortType::new_tcp(3400)),
-                Pattern::Port(PortType::new_tcp(3401)),
-                Pattern::Port(PortType::new_tcp(3500)),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::MacVendor(Vendor::SONOS),
+                Pattern::AnyOf(vec![
+                    Pattern::Port(PortType::Samba),
+                    Pattern::Port(PortType::new_tcp(3445)),
+                    Pattern::Port(PortType::new_tcp(1400)),
+                    Pattern::Port(PortType::new_tcp(1410)),
+                    Pattern::Port(PortType::new_tcp(1843)),
+                    Pattern::Port(PortType::new_tcp(3400)),
+                    Pattern::Port(PortType::new_tcp(3401)),
+                    Pattern::Port(PortType::new_tcp(3500)),
+                ]),
             ]),
+            Pattern::MdnsService("_sonos._tcp"),
+            Pattern::UpnpDevice("Sonos", None),
         ])
     }
 
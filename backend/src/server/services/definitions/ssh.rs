@@ -18,7 +18,10 @@ impl ServiceDefinition for Ssh {
         ServiceCategory::NetworkCore
     }
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::Port(PortType::Ssh)
+        Pattern::AnyOf(vec![
+            Pattern::Port(PortType::Ssh),
+            Pattern::Banner(None, "SSH-"),
+        ])
     }
     fn is_generic(&self) -> bool {
         true
@@ -19,9 +19,12 @@ impl ServiceDefinition for Synology {
     }
 
     fn discovery_pattern(&self) -> Pattern<'_> {
-        Pattern::AllOf(vec![
-            Pattern::Endpoint(PortType::Http, "/", "synology", None),
-            Pattern::Port(PortType::Ftp),
+        Pattern::AnyOf(vec![
+            Pattern::AllOf(vec![
+                Pattern::Endpoint(PortType::Http, "/", "synology", None),
+                Pattern::Port(PortType::Ftp),
+            ]),
+            Pattern::UpnpDevice("Synology", None),
         ])
     }
 
@@ -1,4 +1,6 @@
-use crate::server::auth::middleware::permissions::{Authorized, Member, Viewer};
+use crate::server::auth::middleware::auth::AuthenticatedEntity;
+use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
+use crate::server::discovery::r#impl::results::DiscoveryResultKey;
 use crate::server::hosts::r#impl::base::Host;
 use crate::server::shared::handlers::ordering::OrderField;
 use crate::server::shared::handlers::query::{
@@ -12,13 +14,16 @@ use crate::server::shared::types::api::{
     ApiError, ApiErrorResponse, ApiResponse, ApiResult, PaginatedApiResponse,
 };
 use crate::server::shared::types::entities::EntitySource;
-use crate::server::shared::validation::validate_network_access;
+use crate::server::shared::validation::{
+    validate_network_access, validate_service_definition_access,
+};
 use crate::server::{
     config::AppState,
     services::r#impl::{api::CreateServiceRequest, base::Service},
 };
 use axum::Json;
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::IntoParams;
@@ -264,6 +269,10 @@ async fn get_all_services(
 ///   is included, any specific-interface bindings for the same port are automatically removed.
 /// - **Conflict detection**: Interface bindings conflict with port bindings on the same interface.
 ///   A port binding on all interfaces conflicts with any interface binding.
+///
+/// Daemons may also create services on their own network. Their requests sent with
+/// `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are applied once per session;
+/// replays return the service created the first time.
 #[utoipa::path(
     post,
     path = "",
@@ -277,12 +286,26 @@ async fn get_all_services(
 )]
 pub async fn create_service(
     State(state): State<Arc<AppState>>,
-    auth: Authorized<Member>,
+    auth: Authorized<Or<Member, IsDaemon>>,
+    headers: HeaderMap,
     Json(request): Json<CreateServiceRequest>,
 ) -> ApiResult<Json<ApiResponse<Service>>> {
     // Validate user has access to the network
     validate_network_access(Some(request.network_id()), &auth.network_ids(), "create")?;
 
+    // Daemons belong to their network's organization
+    let organization_id = match auth.organization_id() {
+        Some(organization_id) => organization_id,
+        None => state
+            .services
+            .network_service
+            .get_by_id(&request.network_id())
+            .await?
+            .map(|network| network.base.organization_id)
+            .ok_or_else(ApiError::organization_required)?,
+    };
+    validate_service_definition_access(&*request.service_definition, &organization_id)?;
+
     // Custom validation: Check host network matches service network
     if let Some(host) = state
         .services
@@ -294,8 +317,23 @@ pub async fn create_service(
         return Err(ApiError::entity_network_mismatch::<Host>());
     }
 
+    let discovery_service = &state.services.discovery_service;
+    let network_id = request.network_id();
+    let result_key = DiscoveryResultKey::from_headers(&headers);
+
+    // Replayed after the daemon lost the response: it was applied the first time
+    if let Some(key) = &result_key
+        && let Some(service) = discovery_service.applied_result(key, network_id).await?
+    {
+        return Ok(Json(ApiResponse::success(service)));
+    }
+
     // Convert request to Service entity
-    let service = request.into_service(EntitySource::Manual);
+    let source = match &auth.entity {
+        AuthenticatedEntity::Daemon { .. } => EntitySource::Discovery { metadata: vec![] },
+        _ => EntitySource::Manual,
+    };
+    let service = request.into_service(source);
 
     // Create the service
     let created = state
@@ -304,6 +342,12 @@ pub async fn create_service(
         .create(service, auth.into_entity())
         .await?;
 
+    if let Some(key) = &result_key {
+        discovery_service
+            .record_result(key, network_id, &created)
+            .await;
+    }
+
     Ok(Json(ApiResponse::success(created)))
 }
 
@@ -338,6 +382,11 @@ pub async fn update_service(
     Path(id): Path<Uuid>,
     Json(service): Json<Service>,
 ) -> ApiResult<Json<ApiResponse<Service>>> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+    validate_service_definition_access(&*service.base.service_definition, &organization_id)?;
+
     // Custom validation: Check host network matches service network
     if let Some(host) = state
         .services
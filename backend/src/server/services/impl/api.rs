@@ -113,6 +113,8 @@ impl CreateServiceRequest {
                 name,
                 bindings,
                 virtualization,
+                product: None,
+                version: None,
                 source,
                 tags,
                 position: 0, // Position assigned during creation based on existing services
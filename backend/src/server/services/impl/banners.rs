@@ -0,0 +1,307 @@
+use crate::server::ports::r#impl::base::PortType;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Longest banner kept, in bytes. Greetings are a line or two; anything more is payload.
+pub const MAX_BANNER_LENGTH: usize = 512;
+
+/// What a TCP port sent back when connected to, either unprompted or in reply to a probe
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BannerResponse {
+    pub port_type: PortType,
+    /// Printable text of the response; binary bytes are replaced with spaces
+    pub banner: String,
+    pub product: Option<String>,
+    pub version: Option<String>,
+}
+
+impl Display for BannerResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Banner from {}: {}", self.port_type, self.banner)
+    }
+}
+
+impl BannerResponse {
+    /// Build a response from raw bytes read off the socket, extracting product and version for
+    /// protocols that advertise them
+    pub fn from_bytes(port_type: PortType, bytes: &[u8]) -> Self {
+        let bytes = &bytes[..bytes.len().min(MAX_BANNER_LENGTH)];
+
+        let (product, version) = parse_ssh(bytes)
+            .or_else(|| parse_mysql(bytes))
+            .or_else(|| parse_redis(bytes))
+            .or_else(|| parse_smtp(bytes))
+            .or_else(|| parse_ftp(bytes))
+            .unwrap_or((None, None));
+
+        Self {
+            port_type,
+            banner: to_printable(bytes),
+            product,
+            version,
+        }
+    }
+
+    /// Bytes to send after connecting to a port that doesn't greet clients on its own
+    pub fn probe_for(port_type: &PortType) -> Option<&'static [u8]> {
+        // Compare by value, since scanned ports are created from their number
+        if *port_type == PortType::Redis {
+            Some(b"INFO server\r\n")
+        } else {
+            None
+        }
+    }
+}
+
+fn to_printable(bytes: &[u8]) -> String {
+    let text: String = String::from_utf8_lossy(bytes)
+        .chars()
+        .map(|c| {
+            if c.is_control() || c == char::REPLACEMENT_CHARACTER {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+type ProductVersion = (Option<String>, Option<String>);
+
+/// `SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13` -> (OpenSSH, 9.6p1)
+fn parse_ssh(bytes: &[u8]) -> Option<ProductVersion> {
+    let line = std::str::from_utf8(bytes).ok()?.lines().next()?;
+    let software = line.strip_prefix("SSH-")?.split_once('-')?.1;
+    let software = software.split_whitespace().next()?;
+
+    Some(match software.split_once('_') {
+        Some((product, version)) => (Some(product.to_string()), Some(version.to_string())),
+        None => (Some(software.to_string()), None),
+    })
+}
+
+/// Initial handshake packet: 3 byte length, sequence 0, protocol version 10, then the
+/// null-terminated server version. MariaDB prefixes its version with `5.5.5-` for old clients.
+fn parse_mysql(bytes: &[u8]) -> Option<ProductVersion> {
+    if bytes.len() < 6 || bytes[3] != 0 {
+        return None;
+    }
+
+    match bytes[4] {
+        0x0a => {
+            let version_bytes = &bytes[5..];
+            let end = version_bytes.iter().position(|b| *b == 0)?;
+            let version = std::str::from_utf8(&version_bytes[..end]).ok()?;
+
+            if let Some(mariadb) = version.find("-MariaDB") {
+                let version = &version[..mariadb];
+                let version = version.strip_prefix("5.5.5-").unwrap_or(version);
+                Some((Some("MariaDB".to_string()), Some(version.to_string())))
+            } else {
+                Some((Some("MySQL".to_string()), Some(version.to_string())))
+            }
+        }
+        // Error packet, ie "Host '10.0.0.5' is not allowed to connect to this MySQL server"
+        0xff if to_printable(bytes).contains("MySQL") => Some((Some("MySQL".to_string()), None)),
+        0xff if to_printable(bytes).contains("MariaDB") => {
+            Some((Some("MariaDB".to_string()), None))
+        }
+        _ => None,
+    }
+}
+
+/// Reply to `INFO server`, or the error sent when auth is required or protected mode is on
+fn parse_redis(bytes: &[u8]) -> Option<ProductVersion> {
+    let text = std::str::from_utf8(bytes).ok()?;
+
+    if let Some(version) = text
+        .lines()
+        .find_map(|line| line.strip_prefix("redis_version:"))
+    {
+        return Some((Some("Redis".to_string()), Some(version.trim().to_string())));
+    }
+
+    if text.starts_with("-NOAUTH") || text.starts_with("-DENIED Redis") {
+        return Some((Some("Redis".to_string()), None));
+    }
+
+    None
+}
+
+/// Mail servers that name themselves in their greeting, by the word they use and their name
+const SMTP_PRODUCTS: &[(&str, &str)] = &[
+    ("Postfix", "Postfix"),
+    ("Exim", "Exim"),
+    ("Sendmail", "Sendmail"),
+    ("OpenSMTPD", "OpenSMTPD"),
+    ("Haraka", "Haraka"),
+    ("Microsoft", "Microsoft Exchange"),
+];
+
+/// FTP servers that name themselves in their greeting, by the word they use and their name
+const FTP_PRODUCTS: &[(&str, &str)] = &[
+    ("vsFTPd", "vsFTPd"),
+    ("ProFTPD", "ProFTPD"),
+    ("Pure-FTPd", "Pure-FTPd"),
+    ("FileZilla", "FileZilla Server"),
+    ("Microsoft", "Microsoft FTP Service"),
+];
+
+/// `220 mail.example.com ESMTP Exim 4.96 Mon, 01 Jan 2024 ...` -> (Exim, 4.96)
+fn parse_smtp(bytes: &[u8]) -> Option<ProductVersion> {
+    let line = greeting_line(bytes)?;
+    if !line.to_uppercase().contains("SMTP") {
+        return None;
+    }
+    parse_greeting_product(line, SMTP_PRODUCTS)
+}
+
+/// `220 (vsFTPd 3.0.5)` -> (vsFTPd, 3.0.5)
+fn parse_ftp(bytes: &[u8]) -> Option<ProductVersion> {
+    let line = greeting_line(bytes)?;
+    if line.to_uppercase().contains("SMTP") {
+        return None;
+    }
+    parse_greeting_product(line, FTP_PRODUCTS)
+}
+
+/// First line of a `220` service-ready greeting, shared by SMTP and FTP. Multiline greetings
+/// (`220-`) name the server on their first line too.
+fn greeting_line(bytes: &[u8]) -> Option<&str> {
+    let line = std::str::from_utf8(bytes).ok()?.lines().next()?;
+    line.strip_prefix("220")
+}
+
+/// Find a known product among the greeting's words, taking the version from the word or two
+/// after it. Sendmail's `8.15.2/8.15.2;` reports both binary and config versions; the first
+/// is kept.
+fn parse_greeting_product(line: &str, products: &[(&str, &str)]) -> Option<ProductVersion> {
+    let words: Vec<&str> = line
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| "()[];,-".contains(c)))
+        .collect();
+
+    words.iter().enumerate().find_map(|(i, word)| {
+        let (_, product) = products
+            .iter()
+            .find(|(keyword, _)| word.eq_ignore_ascii_case(keyword))?;
+
+        let version = words
+            .iter()
+            .skip(i + 1)
+            .take(2)
+            .find(|w| w.starts_with(|c: char| c.is_ascii_digit()))
+            .and_then(|w| w.split('/').next())
+            .map(|v| v.to_string());
+
+        Some((Some(product.to_string()), version))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssh_banner() {
+        let response = BannerResponse::from_bytes(
+            PortType::Ssh,
+            b"SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13\r\n",
+        );
+
+        assert_eq!(response.banner, "SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13");
+        assert_eq!(response.product.as_deref(), Some("OpenSSH"));
+        assert_eq!(response.version.as_deref(), Some("9.6p1"));
+    }
+
+    #[test]
+    fn test_mysql_and_mariadb_handshakes() {
+        let mut mysql = vec![0x4a, 0x00, 0x00, 0x00, 0x0a];
+        mysql.extend_from_slice(b"8.0.36\0");
+        mysql.extend_from_slice(&[0x0d, 0x00, 0x00, 0x00, 0x11, 0x22]);
+        mysql.extend_from_slice(b"caching_sha2_password\0");
+
+        let response = BannerResponse::from_bytes(PortType::MySql, &mysql);
+        assert_eq!(response.product.as_deref(), Some("MySQL"));
+        assert_eq!(response.version.as_deref(), Some("8.0.36"));
+        assert!(response.banner.contains("caching_sha2_password"));
+
+        let mut mariadb = vec![0x5a, 0x00, 0x00, 0x00, 0x0a];
+        mariadb.extend_from_slice(b"5.5.5-10.11.6-MariaDB-0+deb12u1\0");
+
+        let response = BannerResponse::from_bytes(PortType::MySql, &mariadb);
+        assert_eq!(response.product.as_deref(), Some("MariaDB"));
+        assert_eq!(response.version.as_deref(), Some("10.11.6"));
+    }
+
+    #[test]
+    fn test_redis_responses() {
+        let response = BannerResponse::from_bytes(
+            PortType::Redis,
+            b"$120\r\n# Server\r\nredis_version:7.2.4\r\nredis_git_sha1:00000000\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("Redis"));
+        assert_eq!(response.version.as_deref(), Some("7.2.4"));
+
+        let response =
+            BannerResponse::from_bytes(PortType::Redis, b"-NOAUTH Authentication required.\r\n");
+        assert_eq!(response.product.as_deref(), Some("Redis"));
+        assert_eq!(response.version, None);
+    }
+
+    #[test]
+    fn test_smtp_greetings() {
+        let response = BannerResponse::from_bytes(
+            PortType::new_tcp(25),
+            b"220 mail.example.com ESMTP Exim 4.96 Mon, 01 Jan 2024 12:00:00 +0000\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("Exim"));
+        assert_eq!(response.version.as_deref(), Some("4.96"));
+
+        let response = BannerResponse::from_bytes(
+            PortType::new_tcp(25),
+            b"220 mail.example.com ESMTP Sendmail 8.15.2/8.15.2; Mon, 1 Jan 2024\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("Sendmail"));
+        assert_eq!(response.version.as_deref(), Some("8.15.2"));
+
+        let response = BannerResponse::from_bytes(
+            PortType::new_tcp(25),
+            b"220 mail.example.com ESMTP Postfix (Ubuntu)\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("Postfix"));
+        assert_eq!(response.version, None);
+    }
+
+    #[test]
+    fn test_ftp_greetings() {
+        let response = BannerResponse::from_bytes(PortType::Ftp, b"220 (vsFTPd 3.0.5)\r\n");
+        assert_eq!(response.banner, "220 (vsFTPd 3.0.5)");
+        assert_eq!(response.product.as_deref(), Some("vsFTPd"));
+        assert_eq!(response.version.as_deref(), Some("3.0.5"));
+
+        let response = BannerResponse::from_bytes(
+            PortType::Ftp,
+            b"220 ProFTPD 1.3.8 Server (Debian) [::ffff:10.0.0.5]\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("ProFTPD"));
+        assert_eq!(response.version.as_deref(), Some("1.3.8"));
+
+        let response = BannerResponse::from_bytes(
+            PortType::Ftp,
+            b"220-FileZilla Server 1.8.0\r\n220 Please visit https://filezilla-project.org/\r\n",
+        );
+        assert_eq!(response.product.as_deref(), Some("FileZilla Server"));
+        assert_eq!(response.version.as_deref(), Some("1.8.0"));
+    }
+
+    #[test]
+    fn test_unrecognized_banner() {
+        let response = BannerResponse::from_bytes(PortType::Ftp, b"220 Welcome\r\n");
+        assert_eq!(response.banner, "220 Welcome");
+        assert_eq!(response.product, None);
+        assert_eq!(response.version, None);
+    }
+}
@@ -3,12 +3,15 @@ use crate::server::discovery::r#impl::types::DiscoveryType;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::ports::r#impl::base::{Port, PortType};
 use crate::server::services::definitions::ServiceDefinitionRegistry;
+use crate::server::services::r#impl::banners::BannerResponse;
 use crate::server::services::r#impl::definitions::ServiceDefinitionExt;
 use crate::server::services::r#impl::definitions::{DefaultServiceDefinition, ServiceDefinition};
 use crate::server::services::r#impl::endpoints::{Endpoint, EndpointResponse};
+use crate::server::services::r#impl::mdns::MdnsAdvertisement;
 use crate::server::services::r#impl::patterns::{MatchConfidence, MatchReason};
+use crate::server::services::r#impl::upnp::UpnpDevice;
 use crate::server::services::r#impl::virtualization::{
-    DockerVirtualization, ServiceVirtualization,
+    DockerVirtualization, KubernetesVirtualization, ServiceVirtualization,
 };
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
 use crate::server::shared::position::Positioned;
@@ -34,6 +37,12 @@ pub struct ServiceBase {
     pub name: String,
     pub bindings: Vec<Binding>,
     pub virtualization: Option<ServiceVirtualization>,
+    /// Software identified from the service's banner during discovery, ie "OpenSSH"
+    #[serde(default)]
+    pub product: Option<String>,
+    /// Software version identified from the service's banner during discovery, ie "9.6p1"
+    #[serde(default)]
+    pub version: Option<String>,
     #[schema(read_only)]
     /// Will be automatically set to Manual for creation through API
     pub source: EntitySource,
@@ -55,6 +64,8 @@ impl Default for ServiceBase {
             name: String::new(),
             bindings: Vec::new(),
             virtualization: None,
+            product: None,
+            version: None,
             source: EntitySource::Unknown,
             tags: Vec::new(),
             position: 0,
@@ -108,6 +119,9 @@ pub struct ServiceMatchBaselineParams<'a> {
     pub interface: &'a Interface,
     pub all_ports: &'a Vec<PortType>,
     pub endpoint_responses: &'a Vec<EndpointResponse>,
+    pub banner_responses: &'a Vec<BannerResponse>,
+    pub mdns_advertisements: &'a Vec<MdnsAdvertisement>,
+    pub upnp_devices: &'a Vec<UpnpDevice>,
     pub virtualization: &'a Option<ServiceVirtualization>,
 }
 
@@ -158,16 +172,34 @@ impl PartialEq for Service {
         // === GENERIC SERVICE EQUALITY ===
         // All possible permutations of generic services on the same host:
 
+        // Kubernetes generic services in pods are keyed by the workload the pod belongs to,
+        // since a rollout replaces every pod under a new name. Bare pods and Service objects
+        // only have their name to go by.
+        if let (
+            Some(ServiceVirtualization::Kubernetes(self_kv)),
+            Some(ServiceVirtualization::Kubernetes(other_kv)),
+        ) = (&self.base.virtualization, &other.base.virtualization)
+        {
+            if self_kv.namespace != other_kv.namespace {
+                return false;
+            }
+            return match (&self_kv.workload, &other_kv.workload) {
+                (Some(self_workload), Some(other_workload)) => self_workload == other_workload,
+                (None, None) => self_kv.pod == other_kv.pod && self.base.name == other.base.name,
+                _ => false,
+            };
+        }
+
         // Extract virtualization info
-        let self_docker = self.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
+        let self_docker = match &self.base.virtualization {
+            Some(ServiceVirtualization::Docker(dv)) => Some(dv),
+            _ => None,
+        };
 
-        let other_docker = other.base.virtualization.as_ref().map(|v| {
-            let ServiceVirtualization::Docker(dv) = v;
-            dv
-        });
+        let other_docker = match &other.base.virtualization {
+            Some(ServiceVirtualization::Docker(dv)) => Some(dv),
+            _ => None,
+        };
 
         // Extract port IDs from bindings
         let self_port_ids: std::collections::HashSet<_> = self
@@ -391,12 +423,17 @@ impl Service {
             let mut name = service_definition.name().to_string();
 
             if ServiceDefinitionExt::is_generic(&service_definition) {
-                if let Some(ServiceVirtualization::Docker(DockerVirtualization {
-                    container_name: Some(c_name),
-                    ..
-                })) = virtualization
-                {
-                    name = c_name.clone()
+                match virtualization {
+                    Some(ServiceVirtualization::Docker(DockerVirtualization {
+                        container_name: Some(c_name),
+                        ..
+                    })) => name = c_name.clone(),
+                    Some(ServiceVirtualization::Kubernetes(KubernetesVirtualization {
+                        workload,
+                        pod: Some(pod),
+                        ..
+                    })) => name = workload.as_ref().unwrap_or(pod).clone(),
+                    _ => {}
                 }
 
                 // Confidence not applicable for generic services
@@ -430,6 +467,8 @@ impl Service {
                 service_definition,
                 name,
                 virtualization: virtualization.clone(),
+                product: result.banner.as_ref().and_then(|b| b.product.clone()),
+                version: result.banner.as_ref().and_then(|b| b.version.clone()),
                 tags: Vec::new(),
                 bindings,
                 source: EntitySource::DiscoveryWithMatch {
@@ -19,10 +19,10 @@ use utoipa::{PartialSchema, ToSchema};
 // Main trait used in service definition implementation
 pub trait ServiceDefinition: HasId + DynClone + DynHash + DynEq + Send + Sync {
     /// Service name, will also be used as unique identifier. < 40 characters.
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
 
     /// Service description. < 100 characters.
-    fn description(&self) -> &'static str;
+    fn description(&self) -> &str;
 
     /// Category from ServiceCategory enum
     fn category(&self) -> ServiceCategory;
@@ -41,7 +41,7 @@ pub trait ServiceDefinition: HasId + DynClone + DynHash + DynEq + Send + Sync {
     /// Simple Icons: Home Assistant -> https://simpleicons.org/icons/homeassistant.svg.
     /// Vector Logo Icons: Akamai -> https://www.vectorlogo.zone/logos/akamai/akamai-icon.svg
     /// Static file: Scanopy -> /logos/scanopy-logo.png
-    fn logo_url(&self) -> &'static str {
+    fn logo_url(&self) -> &str {
         ""
     }
 
@@ -49,27 +49,33 @@ pub trait ServiceDefinition: HasId + DynClone + DynHash + DynEq + Send + Sync {
     fn logo_needs_white_background(&self) -> bool {
         false
     }
+
+    /// Identifier used to persist and look up the definition. Defaults to the name; only
+    /// runtime-loaded definitions, whose names are not globally unique, need to override it.
+    fn definition_id(&self) -> &str {
+        self.name()
+    }
 }
 
 impl<T: ServiceDefinition> HasId for T
 where
     T: ServiceDefinition,
 {
-    fn id(&self) -> &'static str {
-        self.name()
+    fn id(&self) -> &str {
+        self.definition_id()
     }
 }
 
 impl ServiceDefinition for Box<dyn ServiceDefinition> {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         ServiceDefinition::name(&**self)
     }
 
-    fn description(&self) -> &'static str {
+    fn description(&self) -> &str {
         ServiceDefinition::description(&**self)
     }
 
-    fn logo_url(&self) -> &'static str {
+    fn logo_url(&self) -> &str {
         ServiceDefinition::logo_url(&**self)
     }
 
@@ -88,6 +94,10 @@ impl ServiceDefinition for Box<dyn ServiceDefinition> {
     fn logo_needs_white_background(&self) -> bool {
         ServiceDefinition::logo_needs_white_background(&**self)
     }
+
+    fn definition_id(&self) -> &str {
+        ServiceDefinition::definition_id(&**self)
+    }
 }
 
 // Helper methods to be used in rest of codebase, not overridable by definition implementations
@@ -153,14 +163,14 @@ impl EntityMetadataProvider for Box<dyn ServiceDefinition> {
 }
 
 impl TypeMetadataProvider for Box<dyn ServiceDefinition> {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         ServiceDefinition::name(self)
     }
-    fn description(&self) -> &'static str {
+    fn description(&self) -> &str {
         ServiceDefinition::description(self)
     }
-    fn category(&self) -> &'static str {
-        ServiceDefinition::category(self).id()
+    fn category(&self) -> &str {
+        ServiceDefinition::category(self).into()
     }
     fn metadata(&self) -> serde_json::Value {
         serde_json::json!({
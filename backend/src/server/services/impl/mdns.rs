@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// A service instance a host announced over multicast DNS
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MdnsAdvertisement {
+    /// Service type without the domain, ie `_googlecast._tcp`
+    pub service_type: String,
+    /// Instance label, ie `Living Room TV`
+    pub instance_name: String,
+    /// Host the instance's SRV record points to, ie `chromecast-1234.local`
+    pub hostname: String,
+    pub port: u16,
+    pub txt: BTreeMap<String, String>,
+}
+
+impl Display for MdnsAdvertisement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}) on {}:{}",
+            self.instance_name, self.service_type, self.hostname, self.port
+        )
+    }
+}
+
+impl MdnsAdvertisement {
+    /// Build an advertisement from the names in a resolved record, which are fully qualified:
+    /// `Living Room TV._googlecast._tcp.local.`
+    pub fn new(
+        fullname: &str,
+        service_type: &str,
+        hostname: &str,
+        port: u16,
+        txt: BTreeMap<String, String>,
+    ) -> Self {
+        let service_type = normalize_service_type(service_type);
+        let fullname = fullname.trim_end_matches('.');
+        let instance_name = fullname
+            .len()
+            .checked_sub(service_type.len() + ".local".len())
+            .and_then(|end| fullname.get(..end))
+            .and_then(|instance| instance.strip_suffix('.'))
+            .unwrap_or(fullname)
+            .to_string();
+
+        Self {
+            service_type,
+            instance_name,
+            hostname: hostname.trim_end_matches('.').to_string(),
+            port,
+            txt,
+        }
+    }
+
+    pub fn is_type(&self, service_type: &str) -> bool {
+        self.service_type == normalize_service_type(service_type)
+    }
+
+    /// Service types end in the transport, `_tcp` or `_udp`
+    pub fn is_tcp(&self) -> bool {
+        !self.service_type.ends_with("._udp")
+    }
+}
+
+/// `_GoogleCast._tcp.local.` -> `_googlecast._tcp`
+pub fn normalize_service_type(service_type: &str) -> String {
+    let service_type = service_type.trim_end_matches('.').to_lowercase();
+    match service_type.strip_suffix(".local") {
+        Some(stripped) => stripped.to_string(),
+        None => service_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advertisement_from_fully_qualified_names() {
+        let advertisement = MdnsAdvertisement::new(
+            "Living Room TV._googlecast._tcp.local.",
+            "_googlecast._tcp.local.",
+            "Chromecast-1234.local.",
+            8009,
+            BTreeMap::from([("md".to_string(), "Chromecast".to_string())]),
+        );
+
+        assert_eq!(advertisement.service_type, "_googlecast._tcp");
+        assert_eq!(advertisement.instance_name, "Living Room TV");
+        assert_eq!(advertisement.hostname, "Chromecast-1234.local");
+        assert!(advertisement.is_type("_GoogleCast._tcp.local."));
+        assert!(!advertisement.is_type("_sonos._tcp"));
+    }
+}
@@ -1,11 +1,14 @@
 pub mod api;
+pub mod banners;
 pub mod base;
 pub mod categories;
 pub mod definitions;
 pub mod endpoints;
 pub mod handlers;
+pub mod mdns;
 pub mod patterns;
 pub mod storage;
 #[cfg(test)]
 pub mod tests;
+pub mod upnp;
 pub mod virtualization;
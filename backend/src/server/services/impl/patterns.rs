@@ -6,7 +6,7 @@ use crate::server::{
                 DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
                 ServiceMatchServiceParams,
             },
-            virtualization::ServiceVirtualization,
+            virtualization::{KubernetesVirtualization, ServiceVirtualization},
         },
     },
     shared::types::metadata::TypeMetadataProvider,
@@ -21,13 +21,17 @@ use std::{net::IpAddr, ops::Range};
 use strum_macros::{Display, EnumDiscriminants, IntoStaticStr};
 use utoipa::ToSchema;
 
-use crate::server::{ports::r#impl::base::PortType, services::r#impl::endpoints::Endpoint};
+use crate::server::{
+    ports::r#impl::base::PortType,
+    services::r#impl::{banners::BannerResponse, endpoints::Endpoint},
+};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct MatchResult {
     pub ports: Vec<PortType>,
     pub endpoint: Option<Endpoint>,
     pub mac_vendor: Option<String>,
+    pub banner: Option<BannerResponse>,
     pub details: MatchDetails,
 }
 
@@ -172,6 +176,20 @@ pub enum Pattern<'a> {
     /// status_code: optional, defaults to 200..300 (any ok or redirect)
     Header(Option<PortType>, &'a str, &'a str, Option<Range<u16>>),
 
+    /// Whether the banner read from a port contains a string
+    /// PortType: If provided, only check the banner from that port. Otherwise, any open TCP port.
+    /// banner: &str - String to match on in the greeting, or the response to the port's probe
+    Banner(Option<PortType>, &'a str),
+
+    /// Whether the host advertises a service type over multicast DNS
+    /// service type: &str - ie "_googlecast._tcp"
+    MdnsService(&'a str),
+
+    /// Whether the host published a UPnP device description from a manufacturer
+    /// manufacturer: &str - String to match on in the manufacturer
+    /// model: optional, string to match on in the model name
+    UpnpDevice(&'a str, Option<&'a str>),
+
     /// Whether the subnet that the host was found on matches a subnet type
     SubnetIsType(SubnetType),
 
@@ -179,7 +197,7 @@ pub enum Pattern<'a> {
     IsGateway,
 
     /// Whether the vendor derived from the mac address (https://gist.github.com/aallan/b4bb86db86079509e6159810ae9bd3e4) matches the provided str
-    MacVendor(&'static str),
+    MacVendor(&'a str),
 
     /// Custom evaluation of discovery match params
     /// fn - constraint function
@@ -197,6 +215,9 @@ pub enum Pattern<'a> {
     /// Whether the host is a docker container
     DockerContainer,
 
+    /// Whether the service runs in a Kubernetes pod
+    KubernetesPod,
+
     /// No match pattern (only added manually or by the system)
     None,
 }
@@ -234,6 +255,14 @@ impl PartialEq for Pattern<'_> {
             ) => {
                 port_a == port_b && header_a == header_b && value_a == value_b && range_a == range_b
             }
+            (Pattern::Banner(port_a, match_a), Pattern::Banner(port_b, match_b)) => {
+                port_a == port_b && match_a == match_b
+            }
+            (Pattern::MdnsService(a), Pattern::MdnsService(b)) => a == b,
+            (
+                Pattern::UpnpDevice(manufacturer_a, model_a),
+                Pattern::UpnpDevice(manufacturer_b, model_b),
+            ) => manufacturer_a == manufacturer_b && model_a == model_b,
             (Pattern::SubnetIsType(a), Pattern::SubnetIsType(b)) => a == b,
             (Pattern::IsGateway, Pattern::IsGateway) => true,
             (Pattern::MacVendor(a), Pattern::MacVendor(b)) => a == b,
@@ -249,6 +278,7 @@ impl PartialEq for Pattern<'_> {
                     && conf_a == conf_b
             }
             (Pattern::DockerContainer, Pattern::DockerContainer) => true,
+            (Pattern::KubernetesPod, Pattern::KubernetesPod) => true,
             (Pattern::None, Pattern::None) => true,
             _ => false,
         }
@@ -311,6 +341,29 @@ impl Display for Pattern<'_> {
                     )
                 }
             }
+            Pattern::Banner(port_base, match_string) => {
+                let ip_str = if let Some(port_base) = port_base {
+                    format!("<ip>:{}", port_base.number())
+                } else {
+                    "<ip>".to_string()
+                };
+                write!(f, "Banner from {} contains \"{}\"", ip_str, match_string)
+            }
+            Pattern::MdnsService(service_type) => {
+                write!(f, "Host advertises {} over mDNS", service_type)
+            }
+            Pattern::UpnpDevice(manufacturer, model) => match model {
+                Some(model) => write!(
+                    f,
+                    "UPnP device description has manufacturer \"{}\" and model \"{}\"",
+                    manufacturer, model
+                ),
+                None => write!(
+                    f,
+                    "UPnP device description has manufacturer \"{}\"",
+                    manufacturer
+                ),
+            },
             Pattern::SubnetIsType(subnet_type) => write!(f, "Subnet is type {:?}", subnet_type),
             Pattern::IsGateway => write!(
                 f,
@@ -321,6 +374,7 @@ impl Display for Pattern<'_> {
                 write!(f, "A custom match pattern evaluated at runtime")
             }
             Pattern::DockerContainer => write!(f, "Service is running in a docker container"),
+            Pattern::KubernetesPod => write!(f, "Service is running in a Kubernetes pod"),
             Pattern::None => write!(f, "No match pattern provided"),
         }
     }
@@ -345,6 +399,9 @@ impl Pattern<'_> {
             subnet,
             interface,
             endpoint_responses,
+            banner_responses,
+            mdns_advertisements,
+            upnp_devices,
             virtualization,
             ..
         } = baseline_params;
@@ -387,6 +444,7 @@ impl Pattern<'_> {
                         ports: vec![*matched_port],
                         endpoint: None,
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(reason),
                             confidence,
@@ -459,6 +517,7 @@ impl Pattern<'_> {
                         ports: vec![response.endpoint.port_type],
                         endpoint: Some(response.endpoint.clone()),
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(reason),
                             confidence: MatchConfidence::High,
@@ -535,6 +594,7 @@ impl Pattern<'_> {
                         ports: vec![response.endpoint.port_type],
                         endpoint: Some(response.endpoint.clone()),
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(reason),
                             confidence: MatchConfidence::High,
@@ -547,6 +607,123 @@ impl Pattern<'_> {
                 }
             }
 
+            Pattern::Banner(port_base, expected_banner_match_string) => {
+                let match_result = banner_responses.iter().find(|actual| {
+                    port_base.map(|p| actual.port_type == p).unwrap_or(true)
+                        // Leave ports another service already claimed to that service
+                        && unbound_ports.iter().any(|p| {
+                            p.number() == actual.port_type.number()
+                                && p.protocol() == actual.port_type.protocol()
+                        })
+                        && actual
+                            .banner
+                            .to_lowercase()
+                            .contains(&expected_banner_match_string.to_lowercase())
+                });
+
+                match match_result {
+                    Some(response) => Ok(MatchResult {
+                        ports: vec![response.port_type],
+                        endpoint: None,
+                        mac_vendor: None,
+                        banner: Some(response.clone()),
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "Banner from {}:{} contained \"{}\"",
+                                interface.base.ip_address,
+                                response.port_type.number(),
+                                expected_banner_match_string
+                            )),
+                            confidence: MatchConfidence::High,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "Could not find a banner containing {}",
+                        expected_banner_match_string
+                    )),
+                }
+            }
+
+            Pattern::MdnsService(service_type) => {
+                let advertisement = mdns_advertisements.iter().find(|a| a.is_type(service_type));
+
+                match advertisement {
+                    Some(advertisement) => {
+                        // Only claim the advertised port if the scan saw it open
+                        let ports = unbound_ports
+                            .iter()
+                            .filter(|p| {
+                                p.number() == advertisement.port
+                                    && p.is_tcp() == advertisement.is_tcp()
+                            })
+                            .copied()
+                            .collect();
+
+                        Ok(MatchResult {
+                            ports,
+                            endpoint: None,
+                            mac_vendor: None,
+                            banner: None,
+                            details: MatchDetails {
+                                reason: MatchReason::Reason(format!(
+                                    "{} advertised {} as \"{}\" over mDNS",
+                                    interface.base.ip_address,
+                                    advertisement.service_type,
+                                    advertisement.instance_name
+                                )),
+                                confidence: MatchConfidence::High,
+                            },
+                        })
+                    }
+                    None => Err(anyhow!(
+                        "Host does not advertise {} over mDNS",
+                        service_type
+                    )),
+                }
+            }
+
+            Pattern::UpnpDevice(expected_manufacturer, expected_model) => {
+                let contains = |actual: &Option<String>, expected: &str| {
+                    actual
+                        .as_ref()
+                        .is_some_and(|a| a.to_lowercase().contains(&expected.to_lowercase()))
+                };
+
+                let device = upnp_devices.iter().find(|d| {
+                    contains(&d.manufacturer, expected_manufacturer)
+                        && expected_model.is_none_or(|model| contains(&d.model_name, model))
+                });
+
+                match device {
+                    Some(device) => Ok(MatchResult {
+                        // Only claim the port the description was served from if the scan saw it
+                        ports: unbound_ports
+                            .iter()
+                            .filter(|p| p.is_tcp() && Some(p.number()) == device.port())
+                            .copied()
+                            .collect(),
+                        endpoint: None,
+                        mac_vendor: None,
+                        banner: None,
+                        details: MatchDetails {
+                            reason: MatchReason::Reason(format!(
+                                "UPnP device description from {} names {}",
+                                interface.base.ip_address,
+                                [device.manufacturer.clone(), device.model()]
+                                    .into_iter()
+                                    .flatten()
+                                    .join(" ")
+                            )),
+                            confidence: MatchConfidence::Certain,
+                        },
+                    }),
+                    None => Err(anyhow!(
+                        "Host did not publish a UPnP device description from {}",
+                        expected_manufacturer
+                    )),
+                }
+            }
+
             Pattern::MacVendor(vendor_string) => {
                 if let Some(mac) = interface.base.mac_address {
                     let Ok(oui_db) = Oui::default() else {
@@ -575,6 +752,7 @@ impl Pattern<'_> {
                             ports: vec![],
                             endpoint: None,
                             mac_vendor: Some(entry.company_name.clone()),
+                            banner: None,
                             details: MatchDetails {
                                 reason: MatchReason::Reason(format!(
                                     "Mac address is from vendor {}",
@@ -600,6 +778,7 @@ impl Pattern<'_> {
                     ports: vec![],
                     endpoint: None,
                     mac_vendor: None,
+                    banner: None,
                     details: MatchDetails {
                         reason: MatchReason::Reason(format!("{}", e)),
                         confidence: MatchConfidence::Low,
@@ -611,6 +790,7 @@ impl Pattern<'_> {
                 let mut ports = Vec::new();
                 let mut endpoint = None;
                 let mut mac_vendor = None;
+                let mut banner = None;
                 let mut any_matched = false;
                 let mut confidence = MatchConfidence::Low;
                 let mut reasons = Vec::new();
@@ -629,6 +809,10 @@ impl Pattern<'_> {
                             mac_vendor = result.mac_vendor;
                         }
 
+                        if result.banner.is_some() && banner.is_none() {
+                            banner = result.banner;
+                        }
+
                         if result.details.confidence > confidence {
                             confidence = result.details.confidence;
                         }
@@ -646,6 +830,7 @@ impl Pattern<'_> {
                         ports,
                         endpoint: None,
                         mac_vendor: None,
+                        banner,
                         details: MatchDetails {
                             reason: MatchReason::Container("Any of".to_string(), reasons),
                             confidence,
@@ -661,6 +846,7 @@ impl Pattern<'_> {
                 let mut ports = Vec::new();
                 let mut endpoint = None;
                 let mut mac_vendor = None;
+                let mut banner = None;
                 let mut matched_confidences = Vec::new();
                 let mut reasons = Vec::new();
                 let mut no_match_errors = String::new();
@@ -677,6 +863,10 @@ impl Pattern<'_> {
                         if result.mac_vendor.is_some() && mac_vendor.is_none() {
                             mac_vendor = result.mac_vendor;
                         }
+
+                        if result.banner.is_some() && banner.is_none() {
+                            banner = result.banner;
+                        }
                     }
                     Err(e) => {
                         all_matched = false;
@@ -712,6 +902,7 @@ impl Pattern<'_> {
                         ports,
                         endpoint: None,
                         mac_vendor: None,
+                        banner,
                         details: MatchDetails {
                             reason: MatchReason::Container("All of".to_string(), reasons),
                             confidence,
@@ -767,6 +958,7 @@ impl Pattern<'_> {
                         ports: vec![],
                         endpoint: None,
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(reason),
                             confidence: MatchConfidence::High,
@@ -785,6 +977,7 @@ impl Pattern<'_> {
                         ports: vec![],
                         endpoint: None,
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(format!(
                                 "Subnet {} is type {}",
@@ -815,6 +1008,7 @@ impl Pattern<'_> {
                         ports: port_function(params),
                         endpoint: None,
                         mac_vendor: None,
+                        banner: None,
                         details: MatchDetails {
                             reason: MatchReason::Reason(reason.to_string()),
                             confidence: *confidence,
@@ -831,6 +1025,7 @@ impl Pattern<'_> {
                     ports: vec![],
                     endpoint: None,
                     mac_vendor: None,
+                    banner: None,
                     details: MatchDetails {
                         reason: MatchReason::Reason(
                             "Service is running in docker container".to_string(),
@@ -841,6 +1036,25 @@ impl Pattern<'_> {
                 _ => Err(anyhow!("Service is not running in a docker container")),
             },
 
+            Pattern::KubernetesPod => match virtualization {
+                Some(ServiceVirtualization::Kubernetes(KubernetesVirtualization {
+                    pod: Some(..),
+                    ..
+                })) => Ok(MatchResult {
+                    ports: vec![],
+                    endpoint: None,
+                    mac_vendor: None,
+                    banner: None,
+                    details: MatchDetails {
+                        reason: MatchReason::Reason(
+                            "Service is running in a Kubernetes pod".to_string(),
+                        ),
+                        confidence: MatchConfidence::Low,
+                    },
+                }),
+                _ => Err(anyhow!("Service is not running in a Kubernetes pod")),
+            },
+
             Pattern::None => Err(anyhow!("No match pattern provided")),
         }
     }
@@ -850,7 +1064,7 @@ impl Pattern<'_> {
     /// There's logic to add any endpoint-specific ports into scanning in scan_ports_and_endpoints and the docker discovery equivalent
     pub fn ports(&self) -> Vec<PortType> {
         match self {
-            Pattern::Port(port) => vec![*port],
+            Pattern::Port(port) | Pattern::Banner(Some(port), _) => vec![*port],
             Pattern::AnyOf(patterns) | Pattern::AllOf(patterns) => {
                 patterns.iter().flat_map(|p| p.ports().to_vec()).collect()
             }
@@ -899,7 +1113,7 @@ impl Pattern<'_> {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use std::net::IpAddr;
 
     use crate::server::discovery::r#impl::types::{DiscoveryType, HostNamingFallback};
@@ -915,13 +1129,16 @@ mod tests {
             services::{
                 definitions::ServiceDefinitionRegistry,
                 r#impl::{
+                    banners::BannerResponse,
                     base::{
                         DiscoverySessionServiceMatchParams, ServiceMatchBaselineParams,
                         ServiceMatchServiceParams,
                     },
                     definitions::ServiceDefinition,
                     endpoints::{Endpoint, EndpointResponse},
-                    patterns::Pattern,
+                    mdns::MdnsAdvertisement,
+                    patterns::{MatchConfidence, Pattern},
+                    upnp::UpnpDevice,
                 },
             },
             subnets::r#impl::base::Subnet,
@@ -939,6 +1156,9 @@ mod tests {
         discovery_type: DiscoveryType,
         gateway_ips: Vec<IpAddr>,
         endpoint_responses: Vec<EndpointResponse>,
+        banner_responses: Vec<BannerResponse>,
+        mdns_advertisements: Vec<MdnsAdvertisement>,
+        upnp_devices: Vec<UpnpDevice>,
         virtualization: Option<ServiceVirtualization>,
         matched_services: Vec<Service>,
     }
@@ -969,9 +1189,13 @@ mod tests {
                 discovery_type: DiscoveryType::Network {
                     subnet_ids: None,
                     host_naming_fallback: HostNamingFallback::BestService,
+                    scan_profile_id: None,
                 },
                 gateway_ips: vec![],
                 endpoint_responses,
+                banner_responses: vec![],
+                mdns_advertisements: vec![],
+                upnp_devices: vec![],
                 virtualization: None,
                 matched_services: vec![],
             }
@@ -1006,6 +1230,9 @@ mod tests {
                 interface: &self.interface,
                 all_ports,
                 endpoint_responses: &self.endpoint_responses,
+                banner_responses: &self.banner_responses,
+                mdns_advertisements: &self.mdns_advertisements,
+                upnp_devices: &self.upnp_devices,
                 virtualization: &self.virtualization,
             }
         }
@@ -1118,4 +1345,142 @@ mod tests {
             "OR pattern should not match when no conditions met"
         );
     }
+
+    #[test]
+    fn test_pattern_banner_matching() {
+        let mut ctx = TestContext::new();
+        ctx.banner_responses = vec![BannerResponse::from_bytes(
+            PortType::new_tcp(22),
+            b"SSH-2.0-OpenSSH_9.6p1 Ubuntu-3ubuntu13\r\n",
+        )];
+
+        let ports = vec![PortType::new_tcp(22)];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let ssh = ServiceDefinitionRegistry::find_by_id("SSH").expect("SSH service not found");
+        let result = ssh
+            .discovery_pattern()
+            .matches(&params)
+            .expect("SSH banner should match");
+
+        let banner = result.banner.expect("Match should carry the banner");
+        assert_eq!(banner.product.as_deref(), Some("OpenSSH"));
+        assert_eq!(banner.version.as_deref(), Some("9.6p1"));
+
+        let pattern = Pattern::Banner(Some(PortType::Ssh), "Dropbear");
+        assert!(
+            pattern.matches(&params).is_err(),
+            "Banner pattern should not match a different banner"
+        );
+
+        let pattern = Pattern::Banner(Some(PortType::new_tcp(2222)), "SSH-");
+        assert!(
+            pattern.matches(&params).is_err(),
+            "Banner pattern should not match a banner from a different port"
+        );
+
+        // SSH moved off its standard port is still recognized by its greeting
+        ctx.banner_responses = vec![BannerResponse::from_bytes(
+            PortType::new_tcp(2222),
+            b"SSH-2.0-dropbear_2022.83\r\n",
+        )];
+        let ports = vec![PortType::new_tcp(2222)];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let result = ssh
+            .discovery_pattern()
+            .matches(&params)
+            .expect("SSH banner should match on a non-standard port");
+        assert_eq!(result.ports, vec![PortType::new_tcp(2222)]);
+
+        // A port another service already bound isn't claimed again
+        let bound: Vec<PortType> = Vec::new();
+        let params = ctx.create_params_with_ports(&baseline, &bound);
+        assert!(
+            ssh.discovery_pattern().matches(&params).is_err(),
+            "Banner pattern should not match a port that is already bound"
+        );
+    }
+
+    #[test]
+    fn test_pattern_mdns_service_matching() {
+        let mut ctx = TestContext::new();
+        ctx.mdns_advertisements = vec![MdnsAdvertisement::new(
+            "Living Room TV._googlecast._tcp.local.",
+            "_googlecast._tcp.local.",
+            "Chromecast-1234.local.",
+            8009,
+            BTreeMap::new(),
+        )];
+
+        let ports = vec![PortType::new_tcp(8008), PortType::new_tcp(8009)];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let result = Pattern::MdnsService("_googlecast._tcp")
+            .matches(&params)
+            .expect("Advertised service type should match");
+        assert_eq!(result.ports, vec![PortType::new_tcp(8009)]);
+
+        let chromecast = ServiceDefinitionRegistry::find_by_id("Chromecast")
+            .expect("Chromecast service not found");
+        assert!(
+            chromecast.discovery_pattern().matches(&params).is_ok(),
+            "Chromecast should match on its advertisement without a Google MAC"
+        );
+
+        assert!(
+            Pattern::MdnsService("_sonos._tcp")
+                .matches(&params)
+                .is_err(),
+            "mDNS pattern should not match a service type that wasn't advertised"
+        );
+    }
+
+    #[test]
+    fn test_pattern_upnp_device_matching() {
+        let mut ctx = TestContext::new();
+        ctx.upnp_devices = vec![UpnpDevice {
+            location: format!("http://{}:8060/", ctx.interface.base.ip_address),
+            device_type: Some("urn:roku-com:device:player:1-0".to_string()),
+            friendly_name: Some("Living Room".to_string()),
+            manufacturer: Some("Roku".to_string()),
+            model_name: Some("Roku Ultra".to_string()),
+            model_number: Some("4800X".to_string()),
+            serial_number: None,
+            server: None,
+        }];
+
+        let ports = vec![PortType::new_tcp(8060)];
+        let baseline = ctx.create_baseline_params(&ports);
+        let params = ctx.create_params_with_ports(&baseline, &ports);
+
+        let roku = ServiceDefinitionRegistry::find_by_id("Roku Media Player")
+            .expect("Roku service not found");
+        let result = roku
+            .discovery_pattern()
+            .matches(&params)
+            .expect("Roku should match on its device description without a Roku MAC");
+        assert_eq!(result.details.confidence, MatchConfidence::Certain);
+        assert_eq!(result.ports, vec![PortType::new_tcp(8060)]);
+
+        assert!(
+            Pattern::UpnpDevice("roku", Some("ultra"))
+                .matches(&params)
+                .is_ok(),
+            "UPnP pattern should match case-insensitively"
+        );
+        assert!(
+            Pattern::UpnpDevice("Roku", Some("Express"))
+                .matches(&params)
+                .is_err(),
+            "UPnP pattern should not match a different model"
+        );
+        assert!(
+            Pattern::UpnpDevice("Sonos", None).matches(&params).is_err(),
+            "UPnP pattern should not match a different manufacturer"
+        );
+    }
 }
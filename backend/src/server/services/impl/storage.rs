@@ -69,6 +69,8 @@ impl Storable for Service {
                     host_id,
                     service_definition,
                     virtualization,
+                    product,
+                    version,
                     bindings: _, // Bindings stored in separate table, managed by BindingStorage
                     source,
                     tags: _, // Stored in entity_tags junction table
@@ -86,6 +88,8 @@ impl Storable for Service {
                 "host_id",
                 "service_definition",
                 "virtualization",
+                "product",
+                "version",
                 "source",
                 "position",
             ],
@@ -98,6 +102,8 @@ impl Storable for Service {
                 SqlValue::Uuid(host_id),
                 SqlValue::ServiceDefinition(service_definition),
                 SqlValue::OptionalServiceVirtualization(virtualization),
+                SqlValue::OptionalString(product),
+                SqlValue::OptionalString(version),
                 SqlValue::EntitySource(source),
                 SqlValue::I32(position),
             ],
@@ -125,6 +131,8 @@ impl Storable for Service {
                 host_id: row.get("host_id"),
                 service_definition,
                 virtualization,
+                product: row.get("product"),
+                version: row.get("version"),
                 bindings: Vec::new(), // Bindings loaded separately by ServiceService via BindingStorage
                 tags: Vec::new(),     // Hydrated from entity_tags junction table
                 source,
@@ -182,6 +190,11 @@ impl Entity for Service {
         if self.base.virtualization.is_none() {
             self.base.virtualization = existing.base.virtualization.clone();
         }
+        // Likewise product and version, which come from banners seen during discovery
+        if self.base.product.is_none() {
+            self.base.product = existing.base.product.clone();
+            self.base.version = existing.base.version.clone();
+        }
     }
 }
 
@@ -987,3 +987,50 @@ fn extract_service_name(content: &str) -> Option<String> {
     let name: String = chars[..end_pos].iter().collect();
     Some(name)
 }
+
+#[test]
+fn test_kubernetes_generic_services_are_keyed_by_workload() {
+    use crate::server::services::definitions::ssh::Ssh;
+    use crate::server::services::r#impl::base::{Service, ServiceBase};
+    use crate::server::services::r#impl::virtualization::{
+        KubernetesVirtualization, ServiceVirtualization,
+    };
+    use crate::server::shared::storage::traits::Storable;
+
+    let host_id = uuid::Uuid::new_v4();
+    let pod_service = |workload: Option<&str>, pod: &str| {
+        Service::new(ServiceBase {
+            host_id,
+            service_definition: Box::new(Ssh),
+            name: workload.unwrap_or(pod).to_string(),
+            virtualization: Some(ServiceVirtualization::Kubernetes(
+                KubernetesVirtualization {
+                    namespace: "default".to_string(),
+                    workload: workload.map(str::to_string),
+                    pod: Some(pod.to_string()),
+                },
+            )),
+            ..Default::default()
+        })
+    };
+
+    // A rollout replaces the pod, but it's still the same workload
+    assert_eq!(
+        pod_service(Some("bastion"), "bastion-7d4f9c-x2kq9"),
+        pod_service(Some("bastion"), "bastion-7d4f9c-h8w2m")
+    );
+    assert_ne!(
+        pod_service(Some("bastion"), "bastion-7d4f9c-x2kq9"),
+        pod_service(Some("jumpbox"), "jumpbox-5b8c7-x2kq9")
+    );
+
+    // Bare pods only have their name
+    assert_eq!(
+        pod_service(None, "debug-shell"),
+        pod_service(None, "debug-shell")
+    );
+    assert_ne!(
+        pod_service(None, "debug-shell"),
+        pod_service(None, "debug-shell-2")
+    );
+}
@@ -0,0 +1,146 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Root device of a UPnP device description, fetched from the location a host gave in its
+/// answer to an SSDP search
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UpnpDevice {
+    /// URL the description was fetched from
+    pub location: String,
+    /// ie `urn:roku-com:device:player:1-0`
+    pub device_type: Option<String>,
+    /// Name the owner gave the device, ie `Living Room Roku`
+    pub friendly_name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model_name: Option<String>,
+    pub model_number: Option<String>,
+    pub serial_number: Option<String>,
+    /// SERVER header of the search answer, ie `Roku/9.4.0 UPnP/1.0 Roku/9.4.0`
+    pub server: Option<String>,
+}
+
+impl Display for UpnpDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} at {}",
+            self.manufacturer
+                .as_deref()
+                .unwrap_or("Unknown manufacturer"),
+            self.model_name.as_deref().unwrap_or("unknown model"),
+            self.location
+        )
+    }
+}
+
+impl UpnpDevice {
+    /// Parse the root device out of a description document. Returns None if the document doesn't
+    /// describe a device.
+    pub fn from_description(location: &str, server: Option<String>, xml: &str) -> Option<Self> {
+        // Embedded devices follow the root device's own fields
+        let root = match xml.find("<deviceList") {
+            Some(end) => &xml[..end],
+            None => xml,
+        };
+
+        let device = Self {
+            location: location.to_string(),
+            device_type: element(root, "deviceType"),
+            friendly_name: element(root, "friendlyName"),
+            manufacturer: element(root, "manufacturer"),
+            model_name: element(root, "modelName"),
+            model_number: element(root, "modelNumber"),
+            serial_number: element(root, "serialNumber"),
+            server,
+        };
+
+        if device.manufacturer.is_none() && device.model_name.is_none() {
+            return None;
+        }
+
+        Some(device)
+    }
+
+    /// Port the description was served from
+    pub fn port(&self) -> Option<u16> {
+        url::Url::parse(&self.location)
+            .ok()?
+            .port_or_known_default()
+    }
+
+    /// Model name and number, ie `Roku Ultra 4800X`
+    pub fn model(&self) -> Option<String> {
+        match (&self.model_name, &self.model_number) {
+            (Some(name), Some(number)) if !name.contains(number.as_str()) => {
+                Some(format!("{} {}", name, number))
+            }
+            (Some(name), _) => Some(name.clone()),
+            (None, number) => number.clone(),
+        }
+    }
+}
+
+/// Text of the first element with the given name, ignoring namespace prefixes
+fn element(xml: &str, name: &str) -> Option<String> {
+    let pattern = format!(r"(?s)<(?:\w+:)?{name}(?:\s[^>]*)?>(.*?)</(?:\w+:)?{name}\s*>");
+    let text = Regex::new(&pattern).ok()?.captures(xml)?.get(1)?.as_str();
+    let text = unescape(text.trim());
+
+    if text.is_empty() { None } else { Some(text) }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROKU_DESCRIPTION: &str = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<root xmlns="urn:schemas-upnp-org:device-1-0">
+  <specVersion><major>1</major><minor>0</minor></specVersion>
+  <device>
+    <deviceType>urn:roku-com:device:player:1-0</deviceType>
+    <friendlyName>Living Room &amp; Kitchen</friendlyName>
+    <manufacturer>Roku</manufacturer>
+    <modelName>Roku Ultra</modelName>
+    <modelNumber>4800X</modelNumber>
+    <serialNumber>X00400ABCDEF</serialNumber>
+    <deviceList>
+      <device>
+        <manufacturer>Embedded Co</manufacturer>
+      </device>
+    </deviceList>
+  </device>
+</root>"#;
+
+    #[test]
+    fn test_root_device_from_description() {
+        let device = UpnpDevice::from_description(
+            "http://192.168.1.20:8060/",
+            Some("Roku/9.4.0 UPnP/1.0 Roku/9.4.0".to_string()),
+            ROKU_DESCRIPTION,
+        )
+        .expect("Description should parse");
+
+        assert_eq!(device.manufacturer.as_deref(), Some("Roku"));
+        assert_eq!(
+            device.friendly_name.as_deref(),
+            Some("Living Room & Kitchen")
+        );
+        assert_eq!(device.serial_number.as_deref(), Some("X00400ABCDEF"));
+        assert_eq!(device.model().as_deref(), Some("Roku Ultra 4800X"));
+        assert_eq!(device.port(), Some(8060));
+
+        assert_eq!(
+            UpnpDevice::from_description("http://192.168.1.20/", None, "<html></html>"),
+            None
+        );
+    }
+}
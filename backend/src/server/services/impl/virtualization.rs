@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::hash::Hash;
 use strum_macros::{EnumDiscriminants, IntoStaticStr};
 use utoipa::ToSchema;
@@ -30,6 +31,8 @@ use crate::server::shared::{
 pub enum ServiceVirtualization {
     #[schema(title = "Docker")]
     Docker(DockerVirtualization),
+    #[schema(title = "Kubernetes")]
+    Kubernetes(KubernetesVirtualization),
 }
 
 #[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
@@ -37,6 +40,67 @@ pub struct DockerVirtualization {
     pub container_name: Option<String>,
     pub container_id: Option<String>,
     pub service_id: Uuid,
+    /// Image the container was created from, without its tag, ie "ghcr.io/immich-app/server"
+    #[serde(default)]
+    #[schema(required)]
+    pub image: Option<String>,
+    #[serde(default)]
+    #[schema(required)]
+    pub image_tag: Option<String>,
+    #[serde(default)]
+    #[schema(required)]
+    pub labels: BTreeMap<String, String>,
+    /// Result of the container's health check, if it has one
+    #[serde(default)]
+    #[schema(required)]
+    pub health: Option<DockerHealthStatus>,
+    #[serde(default)]
+    #[schema(required)]
+    pub restart_policy: Option<DockerRestartPolicy>,
+    /// Compose project or Swarm stack the container was deployed as part of
+    #[serde(default)]
+    #[schema(required)]
+    pub stack: Option<DockerStack>,
+}
+
+#[derive(Copy, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum DockerHealthStatus {
+    Starting,
+    Healthy,
+    Unhealthy,
+}
+
+#[derive(Copy, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub enum DockerRestartPolicy {
+    No,
+    Always,
+    UnlessStopped,
+    OnFailure,
+}
+
+#[derive(
+    Copy, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema,
+)]
+pub enum DockerStackKind {
+    Compose,
+    Swarm,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct DockerStack {
+    pub kind: DockerStackKind,
+    /// Compose project or Swarm stack name
+    pub name: String,
+    /// Service within the project or stack that the container runs
+    pub service: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Validate, Deserialize, PartialEq, Eq, Hash, ToSchema)]
+pub struct KubernetesVirtualization {
+    pub namespace: String,
+    /// Deployment, StatefulSet, DaemonSet or other controller the pod belongs to
+    pub workload: Option<String>,
+    pub pod: Option<String>,
 }
 
 impl HasId for ServiceVirtualization {
@@ -56,10 +120,16 @@ impl EntityMetadataProvider for ServiceVirtualization {
 
 impl TypeMetadataProvider for ServiceVirtualization {
     fn name(&self) -> &'static str {
-        "Docker"
+        match self {
+            ServiceVirtualization::Docker(..) => "Docker",
+            ServiceVirtualization::Kubernetes(..) => "Kubernetes",
+        }
     }
 
     fn description(&self) -> &'static str {
-        "A service running in a docker container"
+        match self {
+            ServiceVirtualization::Docker(..) => "A service running in a docker container",
+            ServiceVirtualization::Kubernetes(..) => "A service running in a Kubernetes cluster",
+        }
     }
 }
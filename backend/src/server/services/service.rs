@@ -953,6 +953,16 @@ impl ServiceService {
             existing_service.base.virtualization = Some(virtualization.clone())
         }
 
+        let mut version_updated = false;
+        if new_service_data.base.product.is_some()
+            && (existing_service.base.product != new_service_data.base.product
+                || existing_service.base.version != new_service_data.base.version)
+        {
+            existing_service.base.product = new_service_data.base.product.clone();
+            existing_service.base.version = new_service_data.base.version.clone();
+            version_updated = true;
+        }
+
         existing_service.base.source = match (
             existing_service.base.source,
             new_service_data.base.source.clone(),
@@ -1037,6 +1047,10 @@ impl ServiceService {
             data.push(format!("{} bindings", binding_updates))
         };
 
+        if version_updated {
+            data.push("version".to_string())
+        };
+
         if !data.is_empty() {
             let trigger_stale = existing_service.triggers_staleness(Some(service_before_updates));
 
@@ -1058,7 +1072,7 @@ impl ServiceService {
         } else {
             tracing::debug!(
                 service_id = %existing_service.id,
-                "Service upsert - no binding changes needed"
+                "Service upsert - no binding or version changes needed"
             );
         }
 
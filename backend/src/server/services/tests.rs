@@ -1044,6 +1044,8 @@ async fn test_open_ports_singleton_per_host() {
             Some(created_iface.id),
         )],
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::Discovery { metadata: vec![] },
         tags: Vec::new(),
         position: 0,
@@ -1066,6 +1068,8 @@ async fn test_open_ports_singleton_per_host() {
             Some(created_iface.id),
         )],
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::Discovery { metadata: vec![] },
         tags: Vec::new(),
         position: 0,
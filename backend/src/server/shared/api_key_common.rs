@@ -122,7 +122,7 @@ fn generate_prefixed_api_key(key_type: ApiKeyType) -> String {
 }
 
 /// Generate a random base62 string of the specified length
-fn generate_base62_string(length: usize) -> String {
+pub fn generate_base62_string(length: usize) -> String {
     let mut rng = rand::rng();
     (0..length)
         .map(|_| {
@@ -1,11 +1,18 @@
 use crate::server::bindings::r#impl::base::Binding;
+use crate::server::custom_service_definitions::r#impl::base::CustomServiceDefinition;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::invites::r#impl::base::Invite;
+use crate::server::physical_links::r#impl::base::PhysicalLink;
 use crate::server::ports::r#impl::base::Port;
+use crate::server::scan_exclusions::r#impl::base::ScanExclusion;
+use crate::server::scan_profiles::r#impl::base::ScanProfile;
 use crate::server::services::r#impl::base::Service;
 use crate::server::shares::r#impl::base::Share;
+use crate::server::snmp_credentials::r#impl::base::SnmpCredential;
 use crate::server::subnets::r#impl::base::Subnet;
+use crate::server::tls_certificates::r#impl::base::TlsCertificate;
 use crate::server::topology::types::base::Topology;
+use crate::server::webhooks::r#impl::base::Webhook;
 use crate::server::{groups::r#impl::base::Group, tags::r#impl::base::Tag};
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumDiscriminants, EnumIter, IntoStaticStr};
@@ -80,6 +87,11 @@ pub enum Entity {
     UserApiKey(UserApiKey),
     User(User),
     Tag(Tag),
+    CustomServiceDefinition(CustomServiceDefinition),
+    SnmpCredential(SnmpCredential),
+    Webhook(Webhook),
+    ScanProfile(ScanProfile),
+    ScanExclusion(ScanExclusion),
 
     Discovery(Discovery),
     Daemon(Daemon),
@@ -89,6 +101,8 @@ pub enum Entity {
     Port(Port),
     Binding(Binding),
     Interface(Interface),
+    TlsCertificate(TlsCertificate),
+    PhysicalLink(PhysicalLink),
 
     Subnet(Subnet),
     Group(Group),
@@ -118,11 +132,18 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Invite => Color::Green,
             EntityDiscriminants::Share => Color::Teal,
             EntityDiscriminants::Tag => Color::Yellow,
+            EntityDiscriminants::CustomServiceDefinition => Color::Purple,
+            EntityDiscriminants::SnmpCredential => Color::Yellow,
+            EntityDiscriminants::Webhook => Color::Pink,
+            EntityDiscriminants::ScanProfile => Color::Green,
+            EntityDiscriminants::ScanExclusion => Color::Red,
 
             EntityDiscriminants::Host => Color::Blue,
             EntityDiscriminants::Service => Color::Purple,
             EntityDiscriminants::Interface => Color::Cyan,
             EntityDiscriminants::Port => Color::Cyan,
+            EntityDiscriminants::TlsCertificate => Color::Green,
+            EntityDiscriminants::PhysicalLink => Color::Cyan,
             EntityDiscriminants::Binding => Color::Purple,
 
             EntityDiscriminants::Subnet => Color::Orange,
@@ -139,6 +160,11 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Network => Icon::Globe,
             EntityDiscriminants::User => Icon::User,
             EntityDiscriminants::Tag => Icon::Tag,
+            EntityDiscriminants::CustomServiceDefinition => Icon::FileCode,
+            EntityDiscriminants::SnmpCredential => Icon::KeyRound,
+            EntityDiscriminants::Webhook => Icon::Webhook,
+            EntityDiscriminants::ScanProfile => Icon::SlidersHorizontal,
+            EntityDiscriminants::ScanExclusion => Icon::ShieldOff,
             EntityDiscriminants::Invite => Icon::UserPlus,
             EntityDiscriminants::Share => Icon::Share2,
             EntityDiscriminants::DaemonApiKey => Icon::Key,
@@ -149,6 +175,8 @@ impl EntityMetadataProvider for EntityDiscriminants {
             EntityDiscriminants::Service => Icon::Layers,
             EntityDiscriminants::Interface => Icon::Binary,
             EntityDiscriminants::Port => Icon::EthernetPort,
+            EntityDiscriminants::TlsCertificate => Icon::ShieldCheck,
+            EntityDiscriminants::PhysicalLink => Icon::Cable,
             EntityDiscriminants::Binding => Icon::Link,
             EntityDiscriminants::Subnet => Icon::Network,
             EntityDiscriminants::Group => Icon::Group,
@@ -159,6 +187,20 @@ impl EntityMetadataProvider for EntityDiscriminants {
     }
 }
 
+impl Entity {
+    /// The wrapped entity on its own. Entity serializes as {"Host": {...}}; this is the {...}.
+    pub fn inner_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        Ok(match serde_json::to_value(self)? {
+            serde_json::Value::Object(variant) if variant.len() == 1 => variant
+                .into_iter()
+                .next()
+                .map(|(_, entity)| entity)
+                .unwrap_or_default(),
+            other => other,
+        })
+    }
+}
+
 impl From<Organization> for Entity {
     fn from(value: Organization) -> Self {
         Self::Organization(value)
@@ -266,3 +308,45 @@ impl From<Tag> for Entity {
         Self::Tag(value)
     }
 }
+
+impl From<CustomServiceDefinition> for Entity {
+    fn from(value: CustomServiceDefinition) -> Self {
+        Self::CustomServiceDefinition(value)
+    }
+}
+
+impl From<TlsCertificate> for Entity {
+    fn from(value: TlsCertificate) -> Self {
+        Self::TlsCertificate(value)
+    }
+}
+
+impl From<PhysicalLink> for Entity {
+    fn from(value: PhysicalLink) -> Self {
+        Self::PhysicalLink(value)
+    }
+}
+
+impl From<SnmpCredential> for Entity {
+    fn from(value: SnmpCredential) -> Self {
+        Self::SnmpCredential(value)
+    }
+}
+
+impl From<Webhook> for Entity {
+    fn from(value: Webhook) -> Self {
+        Self::Webhook(value)
+    }
+}
+
+impl From<ScanProfile> for Entity {
+    fn from(value: ScanProfile) -> Self {
+        Self::ScanProfile(value)
+    }
+}
+
+impl From<ScanExclusion> for Entity {
+    fn from(value: ScanExclusion) -> Self {
+        Self::ScanExclusion(value)
+    }
+}
@@ -6,17 +6,23 @@ use crate::server::openapi::create_docs_router;
 use crate::server::shared::types::api::ApiResponse;
 use crate::server::shared::types::metadata::{__path_get_metadata_registry, get_metadata_registry};
 use crate::server::{
-    auth::handlers as auth_handlers, billing::handlers as billing_handlers,
-    bindings::handlers as binding_handlers, config::AppState,
+    audit_logs::handlers as audit_log_handlers, auth::handlers as auth_handlers,
+    billing::handlers as billing_handlers, bindings::handlers as binding_handlers,
+    config::AppState, custom_service_definitions::handlers as custom_service_definition_handlers,
     daemon_api_keys::handlers as daemon_api_key_handlers, daemons::handlers as daemon_handlers,
-    discovery::handlers as discovery_handlers, groups::handlers as group_handlers,
-    hosts::handlers as host_handlers, interfaces::handlers as interface_handlers,
-    invites::handlers as invite_handlers, metrics::handlers as metrics_handlers,
-    networks::handlers as network_handlers, organizations::handlers as organization_handlers,
-    ports::handlers as port_handlers, services::handlers as service_handlers,
-    shares::handlers as share_handlers, subnets::handlers as subnet_handlers,
-    tags::handlers as tag_handlers, topology::handlers as topology_handlers,
-    user_api_keys::handlers as user_api_key_handlers, users::handlers as user_handlers,
+    discovery::handlers as discovery_handlers, entity_changes::handlers as entity_change_handlers,
+    groups::handlers as group_handlers, hosts::handlers as host_handlers,
+    interfaces::handlers as interface_handlers, invites::handlers as invite_handlers,
+    metrics::handlers as metrics_handlers, networks::handlers as network_handlers,
+    organizations::handlers as organization_handlers,
+    physical_links::handlers as physical_link_handlers, ports::handlers as port_handlers,
+    scan_exclusions::handlers as scan_exclusion_handlers,
+    scan_profiles::handlers as scan_profile_handlers, services::handlers as service_handlers,
+    shares::handlers as share_handlers, snmp_credentials::handlers as snmp_credential_handlers,
+    subnets::handlers as subnet_handlers, tags::handlers as tag_handlers,
+    tls_certificates::handlers as tls_certificate_handlers,
+    topology::handlers as topology_handlers, user_api_keys::handlers as user_api_key_handlers,
+    users::handlers as user_handlers, webhooks::handlers as webhook_handlers,
 };
 use axum::Json;
 use axum::Router;
@@ -81,8 +87,38 @@ fn create_billed_openapi_routes() -> OpenApiRouter<Arc<AppState>> {
         )
         .nest("/api/v1/invites", invite_handlers::create_router())
         .nest("/api/v1/tags", tag_handlers::create_router())
+        .nest(
+            "/api/v1/custom-service-definitions",
+            custom_service_definition_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/snmp-credentials",
+            snmp_credential_handlers::create_router(),
+        )
         .nest("/api/v1/ports", port_handlers::create_router())
+        .nest(
+            "/api/v1/tls-certificates",
+            tls_certificate_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/physical-links",
+            physical_link_handlers::create_router(),
+        )
         .nest("/api/v1/bindings", binding_handlers::create_router())
+        .nest(
+            "/api/v1/entity-changes",
+            entity_change_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/scan-profiles",
+            scan_profile_handlers::create_router(),
+        )
+        .nest(
+            "/api/v1/scan-exclusions",
+            scan_exclusion_handlers::create_router(),
+        )
+        .nest("/api/v1/webhooks", webhook_handlers::create_router())
+        .nest("/api/v1/audit-logs", audit_log_handlers::create_router())
         // API key routes (versioned)
         .nest("/api/v1/auth/keys", user_api_key_handlers::create_router())
         .nest(
@@ -5,6 +5,7 @@ pub mod events;
 pub mod extractors;
 pub mod handlers;
 pub mod position;
+pub mod secrets;
 pub mod services;
 pub mod storage;
 pub mod types;
@@ -0,0 +1,202 @@
+use std::sync::OnceLock;
+
+use crate::server::shared::types::api::ApiError;
+use anyhow::{Error, anyhow};
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    hmac,
+    rand::{SecureRandom, SystemRandom},
+};
+
+/// Marks a stored value as encrypted. Secrets are encrypted from the moment they're first
+/// stored, so a non-empty value without it has been corrupted or tampered with.
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+static CIPHER: OnceLock<SecretCipher> = OnceLock::new();
+
+/// AES-256-GCM encryption for secrets stored in the database, such as SNMP communities and
+/// passwords. Encrypted values are `enc:v1:` followed by the hex nonce and ciphertext.
+pub struct SecretCipher {
+    key: LessSafeKey,
+    /// Derived from the key rather than being it, so fingerprints say nothing about the key
+    fingerprint_key: hmac::Key,
+    rng: SystemRandom,
+}
+
+impl SecretCipher {
+    /// Build a cipher from a 32 byte key given as 64 hex characters, ie `openssl rand -hex 32`
+    pub fn from_hex(key: &str) -> Result<Self, Error> {
+        let bytes =
+            hex::decode(key.trim()).map_err(|_| anyhow!("Secret key must be 64 hex characters"))?;
+        let key = UnboundKey::new(&AES_256_GCM, &bytes)
+            .map_err(|_| anyhow!("Secret key must be 64 hex characters"))?;
+        let fingerprint_key = hmac::sign(
+            &hmac::Key::new(hmac::HMAC_SHA256, &bytes),
+            b"scanopy secret fingerprint",
+        );
+
+        Ok(Self {
+            key: LessSafeKey::new(key),
+            fingerprint_key: hmac::Key::new(hmac::HMAC_SHA256, fingerprint_key.as_ref()),
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Hex HMAC-SHA256 of a secret. Equal secrets have equal fingerprints, but without the key
+    /// a fingerprint can't be checked against guesses.
+    pub fn fingerprint(&self, value: &str) -> String {
+        hex::encode(hmac::sign(&self.fingerprint_key, value.as_bytes()))
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, Error> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| anyhow!("Could not generate nonce"))?;
+
+        let mut in_out = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut in_out,
+            )
+            .map_err(|_| anyhow!("Could not encrypt secret"))?;
+
+        Ok(format!(
+            "{}{}{}",
+            ENCRYPTED_PREFIX,
+            hex::encode(nonce),
+            hex::encode(in_out)
+        ))
+    }
+
+    pub fn decrypt(&self, value: &str) -> Result<String, Error> {
+        let encoded = value
+            .strip_prefix(ENCRYPTED_PREFIX)
+            .ok_or_else(|| anyhow!("Stored secret is not encrypted"))?;
+
+        let bytes = hex::decode(encoded).map_err(|_| anyhow!("Malformed encrypted secret"))?;
+        if bytes.len() < NONCE_LEN {
+            return Err(anyhow!("Malformed encrypted secret"));
+        }
+        let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| anyhow!("Malformed encrypted secret"))?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| anyhow!("Could not decrypt secret; was the secret key changed?"))?;
+
+        String::from_utf8(plaintext.to_vec()).map_err(|_| anyhow!("Malformed encrypted secret"))
+    }
+}
+
+/// Install the server's cipher. Only the first call has an effect.
+pub fn install(cipher: SecretCipher) {
+    let _ = CIPHER.set(cipher);
+}
+
+pub fn is_configured() -> bool {
+    CIPHER.get().is_some()
+}
+
+/// Reject storing `what` (e.g. "Webhooks") while no secret key is configured, since its
+/// secrets couldn't be encrypted
+pub fn require_configured(what: &str) -> Result<(), ApiError> {
+    if is_configured() {
+        Ok(())
+    } else {
+        Err(ApiError::bad_request(&format!(
+            "{} can't be stored until the server has SCANOPY_SECRET_KEY set",
+            what
+        )))
+    }
+}
+
+/// Fingerprint a secret with the server's key, if one is configured
+pub fn fingerprint(value: &str) -> Option<String> {
+    CIPHER.get().map(|cipher| cipher.fingerprint(value))
+}
+
+/// Encrypt a secret for storage. Empty values are kept empty, so "not set" stays readable.
+pub fn encrypt(plaintext: &str) -> Result<String, Error> {
+    if plaintext.is_empty() {
+        return Ok(String::new());
+    }
+
+    CIPHER
+        .get()
+        .ok_or_else(|| anyhow!("No secret key is configured; set SCANOPY_SECRET_KEY"))?
+        .encrypt(plaintext)
+}
+
+/// Decrypt a stored secret. Empty values are returned empty, as `encrypt` stores them.
+pub fn decrypt(value: &str) -> Result<String, Error> {
+    if value.is_empty() {
+        return Ok(String::new());
+    }
+
+    CIPHER
+        .get()
+        .ok_or_else(|| anyhow!("No secret key is configured; set SCANOPY_SECRET_KEY"))?
+        .decrypt(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_round_trip() {
+        let cipher = SecretCipher::from_hex(KEY).unwrap();
+
+        let encrypted = cipher.encrypt("public").unwrap();
+        assert!(encrypted.starts_with(ENCRYPTED_PREFIX));
+        assert!(!encrypted.contains("public"));
+        assert_ne!(
+            encrypted,
+            cipher.encrypt("public").unwrap(),
+            "Each encryption should use a fresh nonce"
+        );
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "public");
+    }
+
+    #[test]
+    fn test_wrong_key_or_tampering_fails() {
+        let cipher = SecretCipher::from_hex(KEY).unwrap();
+        let encrypted = cipher.encrypt("authpassword").unwrap();
+
+        let other = SecretCipher::from_hex(&"ab".repeat(32)).unwrap();
+        assert!(other.decrypt(&encrypted).is_err());
+
+        let mut tampered = encrypted.clone();
+        tampered.pop();
+        tampered.push(if encrypted.ends_with('0') { '1' } else { '0' });
+        assert!(cipher.decrypt(&tampered).is_err());
+
+        // Plaintext in place of an encrypted value is rejected rather than passed through
+        assert!(cipher.decrypt("public").is_err());
+        assert!(
+            cipher
+                .decrypt(&encrypted[ENCRYPTED_PREFIX.len()..])
+                .is_err()
+        );
+
+        assert!(SecretCipher::from_hex("too short").is_err());
+    }
+
+    #[test]
+    fn test_fingerprint_is_keyed() {
+        let cipher = SecretCipher::from_hex(KEY).unwrap();
+        let other = SecretCipher::from_hex(&"ab".repeat(32)).unwrap();
+
+        assert_eq!(cipher.fingerprint("public"), cipher.fingerprint("public"));
+        assert_ne!(cipher.fingerprint("public"), cipher.fingerprint("private"));
+        assert_ne!(cipher.fingerprint("public"), other.fingerprint("public"));
+    }
+}
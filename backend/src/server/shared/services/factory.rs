@@ -1,12 +1,15 @@
 use crate::server::{
+    audit_logs::service::AuditLogService,
     auth::{oidc::OidcService, service::AuthService},
     billing::service::{BillingService, BillingServiceParams},
     bindings::service::BindingService,
     config::ServerConfig,
+    custom_service_definitions::service::CustomServiceDefinitionService,
     daemon_api_keys::service::DaemonApiKeyService,
     daemons::service::DaemonService,
     discovery::service::DiscoveryService,
     email::{plunk::PlunkEmailProvider, smtp::SmtpEmailProvider, traits::EmailService},
+    entity_changes::service::EntityChangeService,
     groups::{group_bindings::GroupBindingStorage, service::GroupService},
     hosts::service::HostService,
     interfaces::service::InterfaceService,
@@ -15,20 +18,29 @@ use crate::server::{
     metrics::service::MetricsService,
     networks::service::NetworkService,
     organizations::service::OrganizationService,
+    physical_links::service::PhysicalLinkService,
     ports::service::PortService,
+    scan_exclusions::service::ScanExclusionService,
+    scan_profiles::service::ScanProfileService,
     services::service::ServiceService,
-    shared::{events::bus::EventBus, storage::factory::StorageFactory},
+    shared::{
+        events::bus::EventBus, services::network_organizations::NetworkOrganizations,
+        storage::factory::StorageFactory,
+    },
     shares::service::ShareService,
+    snmp_credentials::service::SnmpCredentialService,
     subnets::service::SubnetService,
     tags::{
         entity_tags::{EntityTagService, EntityTagStorage},
         service::TagService,
     },
+    tls_certificates::service::TlsCertificateService,
     topology::service::main::TopologyService,
     user_api_keys::{
         r#impl::network_access::UserApiKeyNetworkAccessStorage, service::UserApiKeyService,
     },
     users::{UserNetworkAccessStorage, service::UserService},
+    webhooks::service::WebhookService,
 };
 use anyhow::Result;
 use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
@@ -61,9 +73,18 @@ pub struct ServiceFactory {
     pub logging_service: Arc<LoggingService>,
     pub metrics_service: Arc<MetricsService>,
     pub tag_service: Arc<TagService>,
+    pub custom_service_definition_service: Arc<CustomServiceDefinitionService>,
+    pub snmp_credential_service: Arc<SnmpCredentialService>,
     pub entity_tag_service: Arc<EntityTagService>,
     pub port_service: Arc<PortService>,
+    pub tls_certificate_service: Arc<TlsCertificateService>,
+    pub physical_link_service: Arc<PhysicalLinkService>,
     pub binding_service: Arc<BindingService>,
+    pub entity_change_service: Arc<EntityChangeService>,
+    pub webhook_service: Arc<WebhookService>,
+    pub scan_profile_service: Arc<ScanProfileService>,
+    pub scan_exclusion_service: Arc<ScanExclusionService>,
+    pub audit_log_service: Arc<AuditLogService>,
 }
 
 impl ServiceFactory {
@@ -84,6 +105,15 @@ impl ServiceFactory {
         let metrics_service = Arc::new(MetricsService::new(prometheus_handle));
 
         let tag_service = Arc::new(TagService::new(storage.tags.clone(), event_bus.clone()));
+        let custom_service_definition_service = Arc::new(CustomServiceDefinitionService::new(
+            storage.custom_service_definitions.clone(),
+            event_bus.clone(),
+        ));
+        let snmp_credential_service = Arc::new(SnmpCredentialService::new(
+            storage.snmp_credentials.clone(),
+            event_bus.clone(),
+        ));
+
         let entity_tag_storage = Arc::new(EntityTagStorage::new(storage.pool.clone()));
         let entity_tag_service = Arc::new(EntityTagService::new(
             entity_tag_storage,
@@ -130,16 +160,34 @@ impl ServiceFactory {
         let share_service = Arc::new(ShareService::new(storage.shares.clone(), event_bus.clone()));
 
         let port_service = Arc::new(PortService::new(storage.ports.clone(), event_bus.clone()));
+        let tls_certificate_service = Arc::new(TlsCertificateService::new(
+            storage.tls_certificates.clone(),
+            event_bus.clone(),
+        ));
+        let physical_link_service = Arc::new(PhysicalLinkService::new(
+            storage.physical_links.clone(),
+            event_bus.clone(),
+        ));
 
         let binding_service = Arc::new(BindingService::new(
             storage.bindings.clone(),
             event_bus.clone(),
         ));
 
+        let scan_exclusion_service = Arc::new(ScanExclusionService::new(
+            storage.scan_exclusions.clone(),
+            storage.networks.clone(),
+            storage.hosts.clone(),
+            storage.interfaces.clone(),
+            event_bus.clone(),
+        ));
+
         // Already implements Arc internally due to scheduler + sessions
         let discovery_service = DiscoveryService::new(
             storage.discovery.clone(),
+            storage.discovery_results.clone(),
             daemon_service.clone(),
+            scan_exclusion_service.clone(),
             event_bus.clone(),
             entity_tag_service.clone(),
         )
@@ -153,6 +201,32 @@ impl ServiceFactory {
             entity_tag_service.clone(),
         ));
 
+        let entity_change_service = Arc::new(EntityChangeService::new(
+            storage.entity_changes.clone(),
+            storage.services.clone(),
+        ));
+
+        let scan_profile_service = Arc::new(ScanProfileService::new(
+            storage.scan_profiles.clone(),
+            event_bus.clone(),
+        ));
+
+        let network_organizations = Arc::new(NetworkOrganizations::new(storage.networks.clone()));
+
+        let webhook_service = Arc::new(WebhookService::new(
+            storage.webhooks.clone(),
+            storage.webhook_deliveries.clone(),
+            storage.organizations.clone(),
+            network_organizations.clone(),
+            event_bus.clone(),
+        ));
+
+        let audit_log_service = Arc::new(AuditLogService::new(
+            storage.audit_logs.clone(),
+            storage.organizations.clone(),
+            network_organizations,
+        ));
+
         // InterfaceService must be created before HostService
         let interface_service = Arc::new(InterfaceService::new(
             storage.interfaces.clone(),
@@ -186,6 +260,7 @@ impl ServiceFactory {
             service_service.clone(),
             port_service.clone(),
             binding_service.clone(),
+            physical_link_service.clone(),
             storage.topologies.clone(),
             event_bus.clone(),
         ));
@@ -284,6 +359,16 @@ impl ServiceFactory {
         event_bus
             .register_subscriber(organization_service.clone())
             .await;
+        event_bus
+            .register_subscriber(custom_service_definition_service.clone())
+            .await;
+        event_bus
+            .register_subscriber(entity_change_service.clone())
+            .await;
+        event_bus.register_subscriber(webhook_service.clone()).await;
+        event_bus
+            .register_subscriber(audit_log_service.clone())
+            .await;
 
         if let Some(billing_service) = billing_service.clone() {
             event_bus.register_subscriber(billing_service).await;
@@ -317,9 +402,18 @@ impl ServiceFactory {
             logging_service,
             metrics_service,
             tag_service,
+            custom_service_definition_service,
+            snmp_credential_service,
             entity_tag_service,
             port_service,
+            tls_certificate_service,
+            physical_link_service,
             binding_service,
+            entity_change_service,
+            webhook_service,
+            scan_profile_service,
+            scan_exclusion_service,
+            audit_log_service,
         })
     }
 }
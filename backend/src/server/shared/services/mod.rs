@@ -1,2 +1,3 @@
 pub mod factory;
+pub mod network_organizations;
 pub mod traits;
@@ -0,0 +1,54 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::server::{
+    networks::r#impl::Network,
+    shared::storage::{generic::GenericPostgresStorage, traits::Storage},
+};
+
+/// Resolves the organization of events that only carry a network. Networks don't move between
+/// organizations, so each network's organization is looked up once.
+pub struct NetworkOrganizations {
+    network_storage: Arc<GenericPostgresStorage<Network>>,
+    organizations: RwLock<HashMap<Uuid, Uuid>>,
+}
+
+impl NetworkOrganizations {
+    pub fn new(network_storage: Arc<GenericPostgresStorage<Network>>) -> Self {
+        Self {
+            network_storage,
+            organizations: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// `organization_id` when given, or else the organization of `network_id`
+    pub async fn resolve(
+        &self,
+        organization_id: Option<Uuid>,
+        network_id: Option<Uuid>,
+    ) -> Result<Option<Uuid>> {
+        if organization_id.is_some() {
+            return Ok(organization_id);
+        }
+        let Some(network_id) = network_id else {
+            return Ok(None);
+        };
+
+        if let Some(organization_id) = self.organizations.read().await.get(&network_id) {
+            return Ok(Some(*organization_id));
+        }
+
+        let Some(network) = self.network_storage.get_by_id(&network_id).await? else {
+            return Ok(None);
+        };
+        self.organizations
+            .write()
+            .await
+            .insert(network_id, network.base.organization_id);
+
+        Ok(Some(network.base.organization_id))
+    }
+}
@@ -5,14 +5,34 @@ use tower_sessions::{Expiry, SessionManagerLayer};
 use tower_sessions_sqlx_store::PostgresStore;
 
 use crate::server::{
-    bindings::r#impl::base::Binding, daemon_api_keys::r#impl::base::DaemonApiKey,
-    daemons::r#impl::base::Daemon, discovery::r#impl::base::Discovery, groups::r#impl::base::Group,
-    hosts::r#impl::base::Host, interfaces::r#impl::base::Interface, invites::r#impl::base::Invite,
-    networks::r#impl::Network, organizations::r#impl::base::Organization,
-    ports::r#impl::base::Port, services::r#impl::base::Service,
-    shared::storage::generic::GenericPostgresStorage, shares::r#impl::base::Share,
-    subnets::r#impl::base::Subnet, tags::r#impl::base::Tag, topology::types::base::Topology,
-    user_api_keys::r#impl::base::UserApiKey, users::r#impl::base::User,
+    audit_logs::r#impl::base::AuditLogEntry,
+    bindings::r#impl::base::Binding,
+    custom_service_definitions::r#impl::base::CustomServiceDefinition,
+    daemon_api_keys::r#impl::base::DaemonApiKey,
+    daemons::r#impl::base::Daemon,
+    discovery::r#impl::{base::Discovery, results::DiscoveryResult},
+    entity_changes::r#impl::base::EntityChange,
+    groups::r#impl::base::Group,
+    hosts::r#impl::base::Host,
+    interfaces::r#impl::base::Interface,
+    invites::r#impl::base::Invite,
+    networks::r#impl::Network,
+    organizations::r#impl::base::Organization,
+    physical_links::r#impl::base::PhysicalLink,
+    ports::r#impl::base::Port,
+    scan_exclusions::r#impl::base::ScanExclusion,
+    scan_profiles::r#impl::base::ScanProfile,
+    services::r#impl::base::Service,
+    shared::storage::generic::GenericPostgresStorage,
+    shares::r#impl::base::Share,
+    snmp_credentials::r#impl::base::SnmpCredential,
+    subnets::r#impl::base::Subnet,
+    tags::r#impl::base::Tag,
+    tls_certificates::r#impl::base::TlsCertificate,
+    topology::types::base::Topology,
+    user_api_keys::r#impl::base::UserApiKey,
+    users::r#impl::base::User,
+    webhooks::r#impl::{base::Webhook, delivery::WebhookDelivery},
 };
 
 pub struct StorageFactory {
@@ -32,10 +52,21 @@ pub struct StorageFactory {
     pub invites: Arc<GenericPostgresStorage<Invite>>,
     pub shares: Arc<GenericPostgresStorage<Share>>,
     pub discovery: Arc<GenericPostgresStorage<Discovery>>,
+    pub discovery_results: Arc<GenericPostgresStorage<DiscoveryResult>>,
     pub topologies: Arc<GenericPostgresStorage<Topology>>,
     pub tags: Arc<GenericPostgresStorage<Tag>>,
+    pub custom_service_definitions: Arc<GenericPostgresStorage<CustomServiceDefinition>>,
+    pub snmp_credentials: Arc<GenericPostgresStorage<SnmpCredential>>,
     pub ports: Arc<GenericPostgresStorage<Port>>,
+    pub tls_certificates: Arc<GenericPostgresStorage<TlsCertificate>>,
+    pub physical_links: Arc<GenericPostgresStorage<PhysicalLink>>,
     pub bindings: Arc<GenericPostgresStorage<Binding>>,
+    pub entity_changes: Arc<GenericPostgresStorage<EntityChange>>,
+    pub webhooks: Arc<GenericPostgresStorage<Webhook>>,
+    pub webhook_deliveries: Arc<GenericPostgresStorage<WebhookDelivery>>,
+    pub audit_logs: Arc<GenericPostgresStorage<AuditLogEntry>>,
+    pub scan_profiles: Arc<GenericPostgresStorage<ScanProfile>>,
+    pub scan_exclusions: Arc<GenericPostgresStorage<ScanExclusion>>,
 }
 
 pub async fn create_session_store(
@@ -66,6 +97,7 @@ impl StorageFactory {
             pool: pool.clone(),
             sessions,
             discovery: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            discovery_results: Arc::new(GenericPostgresStorage::new(pool.clone())),
             organizations: Arc::new(GenericPostgresStorage::new(pool.clone())),
             invites: Arc::new(GenericPostgresStorage::new(pool.clone())),
             shares: Arc::new(GenericPostgresStorage::new(pool.clone())),
@@ -81,8 +113,18 @@ impl StorageFactory {
             services: Arc::new(GenericPostgresStorage::new(pool.clone())),
             topologies: Arc::new(GenericPostgresStorage::new(pool.clone())),
             tags: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            custom_service_definitions: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            snmp_credentials: Arc::new(GenericPostgresStorage::new(pool.clone())),
             ports: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            tls_certificates: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            physical_links: Arc::new(GenericPostgresStorage::new(pool.clone())),
             bindings: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            entity_changes: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            webhooks: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            webhook_deliveries: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            audit_logs: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            scan_profiles: Arc::new(GenericPostgresStorage::new(pool.clone())),
+            scan_exclusions: Arc::new(GenericPostgresStorage::new(pool.clone())),
         })
     }
 }
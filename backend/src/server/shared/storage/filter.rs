@@ -172,6 +172,33 @@ impl<T: Storable> StorableFilter<T> {
         self
     }
 
+    /// Entities on any of the networks, or on none at all, ie organization-wide ones
+    pub fn network_ids_or_global(mut self, ids: &[Uuid]) -> Self {
+        let col = self.qualify_column("network_id");
+        let placeholders: Vec<String> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, _)| format!("${}", self.values.len() + i + 1))
+            .collect();
+
+        if placeholders.is_empty() {
+            self.conditions.push(format!("{} IS NULL", col));
+        } else {
+            self.conditions.push(format!(
+                "({} IS NULL OR {} IN ({}))",
+                col,
+                col,
+                placeholders.join(", ")
+            ));
+        }
+
+        for id in ids {
+            self.values.push(SqlValue::Uuid(*id));
+        }
+
+        self
+    }
+
     pub fn user_id(mut self, id: &Uuid) -> Self {
         let col = self.qualify_column("user_id");
         self.conditions
@@ -350,6 +377,33 @@ impl<T: Storable> StorableFilter<T> {
         self
     }
 
+    /// Filter by not_after (for TLS certificates expiring at or before a point in time)
+    pub fn not_after_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("not_after");
+        self.conditions
+            .push(format!("{} <= ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    /// Filter by created_at strictly after a point in time (for windows of append-only records)
+    pub fn created_after(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("created_at");
+        self.conditions
+            .push(format!("{} > ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
+    /// Filter by created_at at or before a point in time
+    pub fn created_at_or_before(mut self, timestamp: DateTime<Utc>) -> Self {
+        let col = self.qualify_column("created_at");
+        self.conditions
+            .push(format!("{} <= ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::Timestamp(timestamp));
+        self
+    }
+
     /// Generic UUID filter for any column name.
     /// Used by generic child entity handlers to filter by parent_column dynamically.
     pub fn uuid_column(mut self, column: &str, id: &Uuid) -> Self {
@@ -360,6 +414,15 @@ impl<T: Storable> StorableFilter<T> {
         self
     }
 
+    /// Generic text equality filter for any column name.
+    pub fn string_column(mut self, column: &str, value: &str) -> Self {
+        let col = self.qualify_column(column);
+        self.conditions
+            .push(format!("{} = ${}", col, self.values.len() + 1));
+        self.values.push(SqlValue::String(value.to_string()));
+        self
+    }
+
     /// Generic UUID IN filter for any column name.
     /// Used by generic child entity services to filter by parent_column dynamically.
     pub fn uuid_columns(mut self, column: &str, ids: &[Uuid]) -> Self {
@@ -109,6 +109,7 @@ where
             SqlValue::String(v) => query.bind(v),
             SqlValue::U16(v) => query.bind(Into::<i32>::into(*v)),
             SqlValue::I32(v) => query.bind(v),
+            SqlValue::OptionalI32(v) => query.bind(v),
             SqlValue::Bool(v) => query.bind(v),
             SqlValue::Timestamp(v) => query.bind(v),
             SqlValue::OptionTimestamp(v) => query.bind(v),
@@ -128,6 +129,7 @@ where
                 let network = IpNetwork::from(*v);
                 query.bind(network)
             }
+            SqlValue::OptionalIpAddr(v) => query.bind(v.map(IpNetwork::from)),
             SqlValue::RunType(v) => query.bind(serde_json::to_value(v)?),
             SqlValue::DiscoveryType(v) => query.bind(serde_json::to_value(v)?),
             SqlValue::Email(v) => query.bind(v.as_str()),
@@ -89,6 +89,7 @@ pub fn create_remote_host(
         source: EntitySource::System,
         virtualization: None,
         hidden: false,
+        device_info: None,
     };
 
     let host = Host::new(base);
@@ -101,6 +102,8 @@ pub fn create_remote_host(
         service_definition: Box::new(Client),
         bindings: vec![binding],
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         position: 0,
     });
@@ -128,6 +131,7 @@ pub fn create_internet_connectivity_host(
         source: EntitySource::System,
         virtualization: None,
         hidden: false,
+        device_info: None,
     };
 
     let host = Host::new(base);
@@ -140,6 +144,8 @@ pub fn create_internet_connectivity_host(
         service_definition: Box::new(WebService),
         bindings: vec![binding],
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         position: 0,
     });
@@ -167,6 +173,7 @@ pub fn create_public_dns_host(
         source: EntitySource::System,
         virtualization: None,
         hidden: false,
+        device_info: None,
     };
 
     let host = Host::new(base);
@@ -179,6 +186,8 @@ pub fn create_public_dns_host(
         service_definition: Box::new(DnsServer),
         bindings: vec![binding],
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         position: 0,
     });
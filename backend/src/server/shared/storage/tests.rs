@@ -1,24 +1,33 @@
 use crate::server::{
+    audit_logs::r#impl::base::AuditLogEntry,
     bindings::r#impl::base::Binding,
+    custom_service_definitions::r#impl::base::CustomServiceDefinition,
     daemon_api_keys::r#impl::base::DaemonApiKey,
     daemons::r#impl::base::Daemon,
-    discovery::r#impl::base::Discovery,
+    discovery::r#impl::{base::Discovery, results::DiscoveryResult},
+    entity_changes::r#impl::base::EntityChange,
     groups::{group_bindings::GroupBinding, r#impl::base::Group},
     hosts::r#impl::base::Host,
     interfaces::r#impl::base::Interface,
     invites::r#impl::base::Invite,
     networks::r#impl::Network,
     organizations::r#impl::base::Organization,
+    physical_links::r#impl::base::PhysicalLink,
     ports::r#impl::base::Port,
+    scan_exclusions::r#impl::base::ScanExclusion,
+    scan_profiles::r#impl::base::ScanProfile,
     services::r#impl::base::Service,
     shared::storage::traits::Storable,
     shares::r#impl::base::Share,
+    snmp_credentials::r#impl::base::SnmpCredential,
     subnets::r#impl::base::Subnet,
     tags::entity_tags::EntityTag,
     tags::r#impl::base::Tag,
+    tls_certificates::r#impl::base::TlsCertificate,
     topology::types::base::Topology,
     user_api_keys::r#impl::base::UserApiKey,
     users::r#impl::base::User,
+    webhooks::r#impl::{base::Webhook, delivery::WebhookDelivery},
 };
 use sqlx::postgres::PgRow;
 use std::collections::HashMap;
@@ -59,6 +68,22 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        DiscoveryResult::table_name(),
+        Box::new(|row| {
+            DiscoveryResult::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        EntityChange::table_name(),
+        Box::new(|row| {
+            EntityChange::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map.insert(
         Group::table_name(),
         Box::new(|row| {
@@ -163,6 +188,22 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        TlsCertificate::table_name(),
+        Box::new(|row| {
+            TlsCertificate::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        PhysicalLink::table_name(),
+        Box::new(|row| {
+            PhysicalLink::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map.insert(
         Binding::table_name(),
         Box::new(|row| {
@@ -195,6 +236,62 @@ fn get_entity_deserializers() -> HashMap<&'static str, DeserializeFn> {
         }),
     );
 
+    map.insert(
+        CustomServiceDefinition::table_name(),
+        Box::new(|row| {
+            CustomServiceDefinition::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        SnmpCredential::table_name(),
+        Box::new(|row| {
+            SnmpCredential::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        ScanProfile::table_name(),
+        Box::new(|row| {
+            ScanProfile::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        ScanExclusion::table_name(),
+        Box::new(|row| {
+            ScanExclusion::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        Webhook::table_name(),
+        Box::new(|row| {
+            Webhook::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        WebhookDelivery::table_name(),
+        Box::new(|row| {
+            WebhookDelivery::from_row(row)?;
+            Ok(())
+        }),
+    );
+
+    map.insert(
+        AuditLogEntry::table_name(),
+        Box::new(|row| {
+            AuditLogEntry::from_row(row)?;
+            Ok(())
+        }),
+    );
+
     map
 }
 
@@ -160,6 +160,7 @@ pub enum SqlValue {
     String(String),
     OptionalString(Option<String>),
     I32(i32),
+    OptionalI32(Option<i32>),
     U16(u16),
     Bool(bool),
     Email(EmailAddress),
@@ -168,6 +169,7 @@ pub enum SqlValue {
     UuidArray(Vec<Uuid>),
     IpCidr(IpCidr),
     IpAddr(IpAddr),
+    OptionalIpAddr(Option<IpAddr>),
     EntitySource(EntitySource),
     EntityDiscriminant(EntityDiscriminants),
     ServiceDefinition(Box<dyn ServiceDefinition>),
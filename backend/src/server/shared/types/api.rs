@@ -179,6 +179,12 @@ impl<T> ApiResponse<T> {
             meta: ApiMeta::default(),
         }
     }
+
+    /// Apply `f` to the data, if any, e.g. to redact secrets before responding
+    pub fn map_data(mut self, f: impl FnOnce(T) -> T) -> Self {
+        self.data = self.data.map(f);
+        self
+    }
 }
 
 /// Response type for paginated list endpoints (pagination is always present in meta)
@@ -260,6 +266,18 @@ impl ApiError {
         )
     }
 
+    /// Entity can't be deleted while others refer to it (400)
+    pub fn entity_in_use<T: Entity>(name: impl ToString, used_by: impl ToString) -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::EntityInUse {
+                entity: T::entity_name_singular().to_string(),
+                name: name.to_string(),
+                used_by: used_by.to_string(),
+            },
+        )
+    }
+
     pub fn conflict(message: &str) -> Self {
         Self::new(StatusCode::CONFLICT, message.to_string())
     }
@@ -534,6 +552,48 @@ impl ApiError {
         )
     }
 
+    /// Bad request (400) - passive discovery can't be created as a discovery
+    pub fn discovery_passive_not_schedulable() -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::DiscoveryPassiveNotSchedulable,
+        )
+    }
+
+    /// Bad request (400) - traceroute discovery has nothing to trace
+    pub fn discovery_traceroute_no_targets() -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::DiscoveryTracerouteNoTargets,
+        )
+    }
+
+    /// Bad request (400) - Docker discovery's endpoint can't be connected to
+    pub fn discovery_docker_endpoint_invalid(url: &str) -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::DiscoveryDockerEndpointInvalid {
+                url: url.to_string(),
+            },
+        )
+    }
+
+    /// Bad request (400) - scan profile doesn't exist in the discovery's organization
+    pub fn discovery_scan_profile_not_found(id: Uuid) -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::DiscoveryScanProfileNotFound { id },
+        )
+    }
+
+    /// Bad request (400) - tag doesn't exist in the scan exclusion's organization
+    pub fn scan_exclusion_tag_not_found(id: Uuid) -> Self {
+        Self::coded(
+            StatusCode::BAD_REQUEST,
+            ErrorCode::ScanExclusionTagNotFound { id },
+        )
+    }
+
     /// Bad request (400) - subnet is on a different network than the discovery
     pub fn discovery_subnet_network_mismatch(subnet: &str) -> Self {
         Self::coded(
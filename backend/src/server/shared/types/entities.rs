@@ -26,6 +26,13 @@ pub enum EntitySource {
         metadata: Vec<DiscoveryMetadata>,
         details: MatchDetails,
     },
+    /// Proposed by discovery from indirect evidence, like a container's configuration naming
+    /// another service, rather than observed on the network
+    #[schema(title = "Inferred")]
+    Inferred {
+        metadata: Vec<DiscoveryMetadata>,
+        reason: String,
+    },
     #[schema(title = "Unknown")]
     Unknown,
 }
@@ -35,9 +42,31 @@ impl EntitySource {
     pub fn is_from_discovery(&self) -> bool {
         matches!(
             self,
-            EntitySource::Discovery { .. } | EntitySource::DiscoveryWithMatch { .. }
+            EntitySource::Discovery { .. }
+                | EntitySource::DiscoveryWithMatch { .. }
+                | EntitySource::Inferred { .. }
         )
     }
+
+    /// Discovery runs that created or updated this entity
+    pub fn discovery_metadata(&self) -> &[DiscoveryMetadata] {
+        match self {
+            EntitySource::Discovery { metadata }
+            | EntitySource::DiscoveryWithMatch { metadata, .. }
+            | EntitySource::Inferred { metadata, .. } => metadata,
+            _ => &[],
+        }
+    }
+
+    /// Returns true if both entities were found by the same daemon reading the same source, so
+    /// entities named alike by discoveries of different hosts or clusters are kept apart
+    pub fn shares_discovery_origin(&self, other: &EntitySource) -> bool {
+        self.discovery_metadata().iter().any(|a| {
+            other.discovery_metadata().iter().any(|b| {
+                a.daemon_id == b.daemon_id && a.discovery_type.same_target(&b.discovery_type)
+            })
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq, Hash, ToSchema)]
@@ -64,6 +93,7 @@ impl Default for DiscoveryMetadata {
             discovery_type: DiscoveryType::Network {
                 subnet_ids: None,
                 host_naming_fallback: HostNamingFallback::BestService,
+                scan_profile_id: None,
             },
             daemon_id: Uuid::new_v4(),
             date: Utc::now(),
@@ -134,6 +134,18 @@ pub enum ErrorCode {
     DiscoverySubnetNetworkMismatch { subnet: String },
     /// Discovery session not found
     DiscoverySessionNotFound { id: Uuid },
+    /// Passive discovery is enabled in the daemon's config, not scheduled
+    DiscoveryPassiveNotSchedulable,
+    /// Traceroute discovery has neither targets nor remote subnets to trace
+    DiscoveryTracerouteNoTargets,
+    /// Docker endpoint URL has an unsupported scheme, or TLS files missing or not usable with it
+    DiscoveryDockerEndpointInvalid { url: String },
+    /// Scan profile doesn't exist in the discovery's organization
+    DiscoveryScanProfileNotFound { id: Uuid },
+
+    // === Scan Exclusion ===
+    /// Tag doesn't exist in the exclusion's organization
+    ScanExclusionTagNotFound { id: Uuid },
 
     // === Interface ===
     /// IP address is not within subnet range
@@ -253,6 +265,19 @@ impl ErrorCode {
                 "Subnet '{subnet}' is on a different network"
             }
             Self::DiscoverySessionNotFound { .. } => "Discovery session '{id}' not found",
+            Self::DiscoveryPassiveNotSchedulable => {
+                "Passive discovery is enabled in the daemon's config rather than scheduled"
+            }
+            Self::DiscoveryTracerouteNoTargets => {
+                "Traceroute discovery needs at least one target, or remote subnets to trace"
+            }
+            Self::DiscoveryDockerEndpointInvalid { .. } => {
+                "Docker endpoint '{url}' must be a tcp://, http:// or unix:// URL, or https:// with TLS files. ssh:// isn't supported; forward the engine's socket into the daemon's --docker-endpoint-dir and give the local end as a unix:// path"
+            }
+            Self::DiscoveryScanProfileNotFound { .. } => "Scan profile '{id}' not found",
+
+            // Scan Exclusion
+            Self::ScanExclusionTagNotFound { .. } => "Tag '{id}' not found",
 
             // Interface
             Self::InterfaceIpOutOfRange { .. } => {
@@ -311,6 +336,8 @@ impl ErrorCode {
             | Self::InviteAlreadyAccepted
             | Self::InviteEmailMismatch
             | Self::DiscoveryHistoricalReadOnly
+            | Self::DiscoveryPassiveNotSchedulable
+            | Self::DiscoveryTracerouteNoTargets
             | Self::DaemonNetworkMismatch
             | Self::DaemonIdentityMismatch
             | Self::BillingPaymentRequired
@@ -367,6 +394,9 @@ impl ErrorCode {
                 Some(json_map! { "subnet" => subnet })
             }
             Self::DiscoverySessionNotFound { id } => Some(json_map! {"id" => id}),
+            Self::DiscoveryDockerEndpointInvalid { url } => Some(json_map! { "url" => url }),
+            Self::DiscoveryScanProfileNotFound { id } => Some(json_map! {"id" => id}),
+            Self::ScanExclusionTagNotFound { id } => Some(json_map! {"id" => id}),
             Self::InterfaceIpOutOfRange { ip, subnet } => {
                 Some(json_map! { "ip" => ip, "subnet" => subnet })
             }
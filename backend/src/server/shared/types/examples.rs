@@ -13,6 +13,10 @@ use std::net::{IpAddr, Ipv4Addr};
 
 use crate::server::{
     bindings::r#impl::base::Binding,
+    custom_service_definitions::r#impl::{
+        base::{CustomServiceDefinition, CustomServiceDefinitionBase},
+        spec::{PatternSpec, ServiceDefinitionSpec},
+    },
     daemon_api_keys::r#impl::base::{DaemonApiKey, DaemonApiKeyBase},
     daemons::r#impl::{
         api::DaemonCapabilities,
@@ -34,23 +38,37 @@ use crate::server::{
     },
     interfaces::r#impl::base::{Interface, InterfaceBase},
     networks::r#impl::{Network, NetworkBase},
-    organizations::r#impl::base::{Organization, OrganizationBase},
+    organizations::r#impl::base::{
+        DEFAULT_AUDIT_LOG_RETENTION_DAYS, Organization, OrganizationBase,
+    },
+    physical_links::r#impl::base::{PhysicalLink, PhysicalLinkBase, PhysicalLinkProtocol},
     ports::r#impl::base::{Port, PortBase, PortType, TransportProtocol},
+    scan_exclusions::r#impl::base::{ScanExclusion, ScanExclusionBase, ScanExclusionRule},
+    scan_profiles::r#impl::base::{
+        PortSelection, ScanProfile, ScanProfileBase, ScanSettings, UdpProbe,
+    },
     services::{
         definitions::ServiceDefinitionRegistry,
         r#impl::base::{Service, ServiceBase},
+        r#impl::categories::ServiceCategory,
     },
     shared::types::{Color, entities::EntitySource},
+    snmp_credentials::r#impl::base::{
+        SnmpAuthProtocol, SnmpCredential, SnmpCredentialBase, SnmpCredentialConfig,
+        SnmpPrivProtocol, SnmpV3Auth, SnmpV3Privacy,
+    },
     subnets::r#impl::{
         base::{Subnet, SubnetBase},
         types::SubnetType,
     },
     tags::r#impl::base::{Tag, TagBase},
+    tls_certificates::r#impl::base::{CertificateKeyType, TlsCertificate, TlsCertificateBase},
     topology::types::edges::EdgeStyle,
     users::r#impl::{
         base::{User, UserBase},
         permissions::UserOrgPermissions,
     },
+    webhooks::r#impl::base::{Webhook, WebhookBase, WebhookEventType},
 };
 
 // =============================================================================
@@ -76,6 +94,14 @@ pub mod ids {
     pub const DAEMON: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_44665544000c);
     pub const USER: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_44665544000d);
     pub const DISCOVERY: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_44665544000e);
+    pub const CUSTOM_SERVICE_DEFINITION: Uuid =
+        Uuid::from_u128(0x550e8400_e29b_41d4_a716_44665544000f);
+    pub const TLS_CERTIFICATE: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440010);
+    pub const SNMP_CREDENTIAL: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440011);
+    pub const PHYSICAL_LINK: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440012);
+    pub const WEBHOOK: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440013);
+    pub const SCAN_PROFILE: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440014);
+    pub const SCAN_EXCLUSION: Uuid = Uuid::from_u128(0x550e8400_e29b_41d4_a716_446655440015);
 }
 
 /// Example timestamp for created_at/updated_at fields.
@@ -115,6 +141,7 @@ pub fn host() -> Host {
             source: EntitySource::Manual,
             virtualization: None,
             hidden: false,
+            device_info: None,
             tags: vec![],
         },
     }
@@ -152,6 +179,8 @@ pub fn interface() -> Interface {
             mac_address: Some(MacAddress::new([0xDE, 0xAD, 0xBE, 0xEF, 0x12, 0x34])),
             name: Some("eth0".to_string()),
             position: 0,
+            dhcp_lease: None,
+            rtt_ms: None,
         },
     }
 }
@@ -170,6 +199,51 @@ pub fn port() -> Port {
     }
 }
 
+/// Example TlsCertificate entity.
+pub fn tls_certificate() -> TlsCertificate {
+    TlsCertificate {
+        id: ids::TLS_CERTIFICATE,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: TlsCertificateBase {
+            port_id: ids::PORT,
+            host_id: ids::HOST,
+            network_id: ids::NETWORK,
+            subject: "CN=nas.example.com".to_string(),
+            issuer: "CN=nas.example.com".to_string(),
+            subject_alt_names: vec!["nas.example.com".to_string(), "192.168.1.100".to_string()],
+            serial_number: "4a:0b:6e:1f:92:3c:d5:07".to_string(),
+            not_before: example_timestamp(),
+            not_after: example_timestamp() + chrono::Duration::days(365),
+            key_type: CertificateKeyType::Ec,
+            key_bits: Some(256),
+            is_self_signed: true,
+            fingerprint_sha256: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+                .to_string(),
+        },
+    }
+}
+
+/// Example PhysicalLink entity.
+pub fn physical_link() -> PhysicalLink {
+    PhysicalLink {
+        id: ids::PHYSICAL_LINK,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: PhysicalLinkBase {
+            host_id: ids::HOST,
+            network_id: ids::NETWORK,
+            protocol: PhysicalLinkProtocol::Lldp,
+            local_if_index: Some(10112),
+            local_port: Some("Gi1/0/12".to_string()),
+            remote_mac_address: Some(MacAddress::new([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E])),
+            remote_ip_address: Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2))),
+            remote_system_name: Some("access-switch-2".to_string()),
+            remote_port: Some("Gi0/24".to_string()),
+        },
+    }
+}
+
 /// Example Group entity.
 pub fn group() -> Group {
     Group {
@@ -206,6 +280,8 @@ pub fn service() -> Service {
             service_definition: service_def,
             bindings: vec![binding()],
             virtualization: None,
+            product: None,
+            version: None,
             source: EntitySource::Manual,
             tags: vec![],
             position: 0,
@@ -233,6 +309,127 @@ pub fn tag() -> Tag {
     }
 }
 
+/// Example SnmpCredential entity.
+pub fn snmp_credential() -> SnmpCredential {
+    SnmpCredential {
+        id: ids::SNMP_CREDENTIAL,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: SnmpCredentialBase {
+            network_id: ids::NETWORK,
+            name: "Core switches".to_string(),
+            config: SnmpCredentialConfig::V3 {
+                username: "scanopy".to_string(),
+                auth: Some(SnmpV3Auth {
+                    protocol: SnmpAuthProtocol::Sha256,
+                    password: "auth-password".to_string(),
+                }),
+                privacy: Some(SnmpV3Privacy {
+                    protocol: SnmpPrivProtocol::Aes128,
+                    password: "privacy-password".to_string(),
+                }),
+            },
+        },
+    }
+}
+
+/// Example Webhook entity.
+pub fn webhook() -> Webhook {
+    Webhook {
+        id: ids::WEBHOOK,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: WebhookBase {
+            organization_id: ids::ORGANIZATION,
+            name: "New device alerts".to_string(),
+            url: "https://hooks.example.com/scanopy".to_string(),
+            secret: "whsec_0123456789abcdefghijklmnopqrstuv".to_string(),
+            events: vec![
+                WebhookEventType::HostCreated,
+                WebhookEventType::ServiceDeleted,
+                WebhookEventType::DiscoveryFailed,
+            ],
+            network_ids: vec![],
+            enabled: true,
+        },
+    }
+}
+
+/// Example ScanProfile entity.
+pub fn scan_profile() -> ScanProfile {
+    ScanProfile {
+        id: ids::SCAN_PROFILE,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: ScanProfileBase {
+            organization_id: ids::ORGANIZATION,
+            name: "Quick sweep".to_string(),
+            description: Some("Common ports only, for hourly runs".to_string()),
+            settings: ScanSettings {
+                tcp_ports: PortSelection::Top { count: 1000 },
+                udp_probes: vec![UdpProbe::Dns, UdpProbe::Snmp],
+                connect_timeout_ms: 500,
+                retries: 0,
+                probe_endpoints: true,
+                max_concurrency: Some(100),
+                ping_ports: vec![22, 443],
+            },
+        },
+    }
+}
+
+/// Example ScanExclusion entity.
+pub fn scan_exclusion() -> ScanExclusion {
+    ScanExclusion {
+        id: ids::SCAN_EXCLUSION,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: ScanExclusionBase {
+            organization_id: ids::ORGANIZATION,
+            network_id: Some(ids::NETWORK),
+            name: "Label printers".to_string(),
+            description: Some("Reboot when port scanned".to_string()),
+            rule: ScanExclusionRule::Oui {
+                oui: "00:07:4D".to_string(),
+            },
+            arp_only: true,
+        },
+    }
+}
+
+/// Example CustomServiceDefinition entity.
+pub fn custom_service_definition() -> CustomServiceDefinition {
+    CustomServiceDefinition {
+        id: ids::CUSTOM_SERVICE_DEFINITION,
+        created_at: example_timestamp(),
+        updated_at: example_timestamp(),
+        base: CustomServiceDefinitionBase {
+            spec: ServiceDefinitionSpec {
+                name: "Acme Console".to_string(),
+                description: "Acme appliance management console".to_string(),
+                category: ServiceCategory::Monitoring,
+                pattern: PatternSpec::AllOf {
+                    patterns: vec![
+                        PatternSpec::Port {
+                            port: "8443/tcp".to_string(),
+                        },
+                        PatternSpec::Endpoint {
+                            port: "8443/tcp".to_string(),
+                            path: "/login".to_string(),
+                            contains: "Acme".to_string(),
+                            status: None,
+                        },
+                    ],
+                },
+                is_generic: false,
+                logo_url: String::new(),
+                logo_needs_white_background: false,
+            },
+            organization_id: ids::ORGANIZATION,
+        },
+    }
+}
+
 /// Example DaemonApiKey entity.
 pub fn daemon_api_key() -> DaemonApiKey {
     DaemonApiKey {
@@ -265,6 +462,7 @@ pub fn daemon() -> Daemon {
             capabilities: DaemonCapabilities {
                 has_docker_socket: true,
                 interfaced_subnet_ids: vec![ids::SUBNET],
+                enforces_scan_exclusions: true,
             },
             last_seen: example_timestamp(),
             name: "home-daemon".to_string(),
@@ -314,6 +512,7 @@ pub fn organization() -> Organization {
             plan: None,
             plan_status: None,
             onboarding: vec![],
+            audit_log_retention_days: DEFAULT_AUDIT_LOG_RETENTION_DAYS,
         },
     }
 }
@@ -331,6 +530,7 @@ pub fn discovery() -> Discovery {
             discovery_type: DiscoveryType::Network {
                 subnet_ids: Some(vec![ids::SUBNET]),
                 host_naming_fallback: Default::default(),
+                scan_profile_id: Some(ids::SCAN_PROFILE),
             },
             run_type: RunType::AdHoc {
                 last_run: Some(example_timestamp()),
@@ -6,6 +6,7 @@ use strum::{IntoDiscriminant, IntoEnumIterator};
 use utoipa::ToSchema;
 
 use crate::server::{
+    auth::middleware::auth::{AuthError, AuthenticatedEntity},
     billing::types::{base::BillingPlan, features::Feature},
     discovery::r#impl::types::DiscoveryType,
     groups::r#impl::types::GroupType,
@@ -36,13 +37,13 @@ pub struct MetadataRegistry {
 
 #[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct TypeMetadata {
-    pub id: &'static str,
+    pub id: String,
     #[schema(required)]
-    pub name: Option<&'static str>,
+    pub name: Option<String>,
     #[schema(required)]
-    pub description: Option<&'static str>,
+    pub description: Option<String>,
     #[schema(required)]
-    pub category: Option<&'static str>,
+    pub category: Option<String>,
     #[schema(value_type = Option<String>, required)]
     pub icon: Option<Icon>,
     pub color: Color,
@@ -52,14 +53,14 @@ pub struct TypeMetadata {
 
 #[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct EntityMetadata {
-    pub id: &'static str,
+    pub id: String,
     pub color: Color,
     #[schema(value_type = String)]
     pub icon: Icon,
 }
 
 pub trait HasId {
-    fn id(&self) -> &'static str;
+    fn id(&self) -> &str;
 }
 
 pub trait MetadataProvider<T>: HasId {
@@ -72,11 +73,11 @@ pub trait EntityMetadataProvider: MetadataProvider<EntityMetadata> {
 }
 
 pub trait TypeMetadataProvider: EntityMetadataProvider + MetadataProvider<TypeMetadata> {
-    fn name(&self) -> &'static str;
-    fn description(&self) -> &'static str {
+    fn name(&self) -> &str;
+    fn description(&self) -> &str {
         ""
     }
-    fn category(&self) -> &'static str {
+    fn category(&self) -> &str {
         ""
     }
     fn metadata(&self) -> serde_json::Value {
@@ -90,7 +91,7 @@ where
 {
     fn to_metadata(&self) -> EntityMetadata {
         EntityMetadata {
-            id: self.id(),
+            id: self.id().to_string(),
             color: self.color(),
             icon: self.icon(),
         }
@@ -111,10 +112,10 @@ where
         let metadata = self.metadata();
 
         TypeMetadata {
-            id,
-            name: (!name.is_empty()).then_some(name),
-            description: (!description.is_empty()).then_some(description),
-            category: (!category.is_empty()).then_some(category),
+            id: id.to_string(),
+            name: (!name.is_empty()).then(|| name.to_string()),
+            description: (!description.is_empty()).then(|| description.to_string()),
+            category: (!category.is_empty()).then(|| category.to_string()),
             icon: Some(icon),
             color,
             metadata: (!metadata.as_object().is_some_and(|obj| obj.is_empty())).then_some(metadata),
@@ -125,6 +126,7 @@ where
 /// Get metadata registry
 ///
 /// Returns metadata about all entity types, service definitions, and other system metadata.
+/// Signed-in requests also get their organization's custom service definitions.
 #[utoipa::path(
     get,
     path = "/api/metadata",
@@ -133,9 +135,18 @@ where
         (status = 200, description = "Metadata registry", body = ApiResponse<MetadataRegistry>)
     )
 )]
-pub async fn get_metadata_registry() -> impl IntoResponse {
+pub async fn get_metadata_registry(
+    auth: Result<AuthenticatedEntity, AuthError>,
+) -> impl IntoResponse {
+    let service_definitions = match auth.ok().and_then(|a| a.organization_id()) {
+        Some(organization_id) => {
+            ServiceDefinitionRegistry::organization_service_definitions(&organization_id)
+        }
+        None => ServiceDefinitionRegistry::builtin_service_definitions(),
+    };
+
     let registry = MetadataRegistry {
-        service_definitions: ServiceDefinitionRegistry::all_service_definitions()
+        service_definitions: service_definitions
             .iter()
             .map(|t| t.to_metadata())
             .collect(),
@@ -1,5 +1,7 @@
 use crate::server::{
-    networks::r#impl::Network, organizations::r#impl::base::Organization,
+    networks::r#impl::Network,
+    organizations::r#impl::base::Organization,
+    services::{definitions::ServiceDefinitionRegistry, r#impl::definitions::ServiceDefinition},
     shared::types::api::ApiError,
 };
 use uuid::Uuid;
@@ -36,6 +38,22 @@ pub fn validate_organization_access(
     Ok(())
 }
 
+/// Validates that a service definition is built in or one of the organization's own custom
+/// definitions, so another organization's can't be referenced.
+pub fn validate_service_definition_access(
+    service_definition: &dyn ServiceDefinition,
+    organization_id: &Uuid,
+) -> Result<(), ApiError> {
+    let id = service_definition.id();
+    if ServiceDefinitionRegistry::find_for_organization(id, organization_id).is_none() {
+        return Err(ApiError::bad_request(&format!(
+            "Service definition '{}' not found",
+            id
+        )));
+    }
+    Ok(())
+}
+
 /// Validates entity field constraints (custom validation logic).
 /// Returns a bad request error if validation fails.
 pub fn validate_entity<F>(validate_fn: F, entity_name: &str) -> Result<(), ApiError>
@@ -0,0 +1,216 @@
+use crate::server::auth::middleware::auth::AuthenticatedEntity;
+use crate::server::auth::middleware::permissions::{Admin, Authorized, IsDaemon, Member, Or};
+use crate::server::shared::handlers::query::{FilterQueryExtractor, NetworkFilterQuery};
+use crate::server::shared::handlers::traits::{
+    CrudHandlers, create_handler, get_by_id_handler, update_handler,
+};
+use crate::server::shared::secrets;
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::types::api::{ApiErrorResponse, PaginatedApiResponse};
+use crate::server::snmp_credentials::{
+    r#impl::base::SnmpCredential, service::SnmpCredentialService,
+};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use std::sync::Arc;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for SnmpCredential {
+    type Service = SnmpCredentialService;
+    type FilterQuery = NetworkFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.snmp_credential_service
+    }
+}
+
+// Generated handlers for operations that don't expose secrets
+mod generated {
+    use super::*;
+    crate::crud_delete_handler!(SnmpCredential, "snmp_credentials", "snmp_credential");
+    crate::crud_bulk_delete_handler!(SnmpCredential, "snmp_credentials");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_snmp_credentials, create_snmp_credential))
+        .routes(routes!(
+            get_snmp_credential_by_id,
+            update_snmp_credential,
+            generated::delete
+        ))
+        .routes(routes!(generated::bulk_delete))
+}
+
+/// List all SNMP credentials
+///
+/// Returns the SNMP credentials for the networks you have access to, with their communities and
+/// passwords blanked. Requires admin permissions.
+///
+/// Daemons receive every credential for their network in creation order, unpaginated, with
+/// their secrets.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "snmp_credentials",
+    params(NetworkFilterQuery),
+    responses(
+        (status = 200, description = "List of SNMP credentials", body = PaginatedApiResponse<SnmpCredential>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_snmp_credentials(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Or<Admin, IsDaemon>>,
+    crate::server::shared::extractors::Query(query): crate::server::shared::extractors::Query<
+        NetworkFilterQuery,
+    >,
+) -> ApiResult<Json<PaginatedApiResponse<SnmpCredential>>> {
+    let network_ids = auth.network_ids();
+    let organization_id = auth.organization_id();
+
+    match auth.into_entity() {
+        AuthenticatedEntity::Daemon { network_id, .. } => {
+            let filter = StorableFilter::<SnmpCredential>::new().network_ids(&[network_id]);
+
+            let result = state
+                .services
+                .snmp_credential_service
+                .get_all(filter)
+                .await?;
+            let total_count = result.len() as u64;
+
+            Ok(Json(PaginatedApiResponse::success(
+                result,
+                total_count,
+                0,
+                0,
+            )))
+        }
+        _ => {
+            let filter = query.apply_to_filter(
+                StorableFilter::<SnmpCredential>::new(),
+                &network_ids,
+                organization_id.unwrap_or_default(),
+            );
+
+            // Apply pagination
+            let pagination = query.pagination();
+            let filter = pagination.apply_to_filter(filter);
+
+            let result = state
+                .services
+                .snmp_credential_service
+                .get_paginated(filter)
+                .await?;
+
+            // Get effective pagination values for response metadata
+            let limit = pagination.effective_limit().unwrap_or(0);
+            let offset = pagination.effective_offset();
+
+            Ok(Json(PaginatedApiResponse::success(
+                result
+                    .items
+                    .into_iter()
+                    .map(SnmpCredential::redacted)
+                    .collect(),
+                result.total_count,
+                limit,
+                offset,
+            )))
+        }
+    }
+}
+
+/// Get SNMP credential by ID
+///
+/// The community or passwords are blanked in the response. Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = "snmp_credentials",
+    params(("id" = Uuid, Path, description = "SnmpCredential ID")),
+    responses(
+        (status = 200, description = "SnmpCredential found", body = ApiResponse<SnmpCredential>),
+        (status = 404, description = "SnmpCredential not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_snmp_credential_by_id(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<SnmpCredential>>> {
+    let Json(response) =
+        get_by_id_handler::<SnmpCredential>(state, auth.into_permission(), path).await?;
+    Ok(Json(response.map_data(SnmpCredential::redacted)))
+}
+
+/// Create a new SNMP credential
+///
+/// Daemons on the credential's network use it from their next discovery session on.
+///
+/// ### Validation
+///
+/// - Name must be 1-100 characters
+/// - v2c communities and v3 usernames must not be empty
+/// - v3 privacy requires authentication
+/// - v3 authentication and privacy passwords must be at least 8 characters
+/// - The server must have a secret key configured to encrypt the credential with
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "snmp_credentials",
+    request_body = SnmpCredential,
+    responses(
+        (status = 200, description = "SNMP credential created successfully", body = ApiResponse<SnmpCredential>),
+        (status = 400, description = "Invalid credential", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_snmp_credential(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    body: Json<SnmpCredential>,
+) -> ApiResult<Json<ApiResponse<SnmpCredential>>> {
+    secrets::require_configured("SNMP credentials")?;
+    let Json(response) =
+        create_handler::<SnmpCredential>(state, auth.into_permission::<Member>(), body).await?;
+    Ok(Json(response.map_data(SnmpCredential::redacted)))
+}
+
+/// Update SNMP credential
+///
+/// Same validation rules as creating a credential. Leave the community or a password empty to
+/// keep the current one.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "snmp_credentials",
+    params(("id" = Uuid, Path, description = "SnmpCredential ID")),
+    request_body = SnmpCredential,
+    responses(
+        (status = 200, description = "SNMP credential updated", body = ApiResponse<SnmpCredential>),
+        (status = 400, description = "Invalid credential", body = ApiErrorResponse),
+        (status = 404, description = "SNMP credential not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_snmp_credential(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+    body: Json<SnmpCredential>,
+) -> ApiResult<Json<ApiResponse<SnmpCredential>>> {
+    secrets::require_configured("SNMP credentials")?;
+    let Json(response) =
+        update_handler::<SnmpCredential>(state, auth.into_permission::<Member>(), path, body)
+            .await?;
+    Ok(Json(response.map_data(SnmpCredential::redacted)))
+}
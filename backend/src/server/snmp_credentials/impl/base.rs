@@ -0,0 +1,347 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::Display as StrumDisplay;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+
+/// SNMPv3 USM authentication protocol
+#[derive(
+    Copy, Debug, Clone, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize, ToSchema,
+)]
+pub enum SnmpAuthProtocol {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+/// SNMPv3 USM privacy (encryption) protocol
+#[derive(
+    Copy, Debug, Clone, PartialEq, Eq, Hash, StrumDisplay, Serialize, Deserialize, ToSchema,
+)]
+pub enum SnmpPrivProtocol {
+    Des,
+    Aes128,
+    Aes192,
+    Aes256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct SnmpV3Auth {
+    pub protocol: SnmpAuthProtocol,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub struct SnmpV3Privacy {
+    pub protocol: SnmpPrivProtocol,
+    pub password: String,
+}
+
+/// SNMP version and the secrets needed to query a device with it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "version")]
+pub enum SnmpCredentialConfig {
+    #[schema(title = "V2c")]
+    V2c { community: String },
+    /// USM user. Leave out `auth` for noAuthNoPriv, and `privacy` for authNoPriv.
+    #[schema(title = "V3")]
+    V3 {
+        username: String,
+        #[serde(default)]
+        auth: Option<SnmpV3Auth>,
+        #[serde(default)]
+        privacy: Option<SnmpV3Privacy>,
+    },
+}
+
+impl Default for SnmpCredentialConfig {
+    fn default() -> Self {
+        Self::V2c {
+            community: "public".to_string(),
+        }
+    }
+}
+
+impl SnmpCredentialConfig {
+    /// Apply `f` to each secret: the v2c community, and the v3 authentication and privacy
+    /// passwords
+    pub fn try_map_secrets(
+        self,
+        f: impl Fn(&str) -> Result<String, anyhow::Error>,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(match self {
+            Self::V2c { community } => Self::V2c {
+                community: f(&community)?,
+            },
+            Self::V3 {
+                username,
+                auth,
+                privacy,
+            } => Self::V3 {
+                username,
+                auth: auth
+                    .map(|a| {
+                        Ok::<_, anyhow::Error>(SnmpV3Auth {
+                            protocol: a.protocol,
+                            password: f(&a.password)?,
+                        })
+                    })
+                    .transpose()?,
+                privacy: privacy
+                    .map(|p| {
+                        Ok::<_, anyhow::Error>(SnmpV3Privacy {
+                            protocol: p.protocol,
+                            password: f(&p.password)?,
+                        })
+                    })
+                    .transpose()?,
+            },
+        })
+    }
+
+    /// The config with its secrets blanked, as returned to users. Secrets are write-only; only
+    /// daemons receive them.
+    pub fn redacted(&self) -> Self {
+        self.clone()
+            .try_map_secrets(|_| Ok(String::new()))
+            .unwrap_or_default()
+    }
+
+    /// Fill secrets left empty in an update from the stored config, so a credential can be
+    /// edited without re-entering its community or passwords
+    pub fn keep_secrets_from(&mut self, existing: &Self) {
+        match (self, existing) {
+            (Self::V2c { community }, Self::V2c { community: current }) => {
+                if community.is_empty() {
+                    *community = current.clone();
+                }
+            }
+            (
+                Self::V3 { auth, privacy, .. },
+                Self::V3 {
+                    auth: current_auth,
+                    privacy: current_privacy,
+                    ..
+                },
+            ) => {
+                if let (Some(auth), Some(current)) = (auth, current_auth)
+                    && auth.password.is_empty()
+                {
+                    auth.password = current.password.clone();
+                }
+                if let (Some(privacy), Some(current)) = (privacy, current_privacy)
+                    && privacy.password.is_empty()
+                {
+                    privacy.password = current.password.clone();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// USM keys are derived from passwords, which RFC 3414 requires to be at least 8 characters
+const MIN_V3_PASSWORD_LENGTH: usize = 8;
+
+fn validate_config(config: &SnmpCredentialConfig) -> Result<(), ValidationError> {
+    let error = |message: &'static str| {
+        let mut err = ValidationError::new("snmp_credential_config");
+        err.message = Some(message.into());
+        Err(err)
+    };
+
+    match config {
+        SnmpCredentialConfig::V2c { community } => {
+            if community.is_empty() {
+                return error("Community must not be empty");
+            }
+        }
+        SnmpCredentialConfig::V3 {
+            username,
+            auth,
+            privacy,
+        } => {
+            if username.is_empty() {
+                return error("Username must not be empty");
+            }
+            if privacy.is_some() && auth.is_none() {
+                return error("Privacy requires authentication");
+            }
+            if auth
+                .as_ref()
+                .is_some_and(|a| a.password.len() < MIN_V3_PASSWORD_LENGTH)
+                || privacy
+                    .as_ref()
+                    .is_some_and(|p| p.password.len() < MIN_V3_PASSWORD_LENGTH)
+            {
+                return error("Authentication and privacy passwords must be at least 8 characters");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The base data for a SnmpCredential entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct SnmpCredentialBase {
+    pub network_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    #[validate(custom(function = "validate_config"))]
+    pub config: SnmpCredentialConfig,
+}
+
+/// Credentials daemons on a network use to query devices over SNMP. Daemons try each of the
+/// network's credentials in creation order and use the first one a device answers to.
+///
+/// Communities and passwords are encrypted at rest and write-only: they're blank in responses
+/// to users, and left blank in an update to keep the current ones.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::snmp_credential)]
+pub struct SnmpCredential {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: SnmpCredentialBase,
+}
+
+impl ChangeTriggersTopologyStaleness<SnmpCredential> for SnmpCredential {
+    fn triggers_staleness(&self, _other: Option<SnmpCredential>) -> bool {
+        false
+    }
+}
+
+impl SnmpCredential {
+    pub fn redacted(mut self) -> Self {
+        self.base.config = self.base.config.redacted();
+        self
+    }
+}
+
+impl Display for SnmpCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SNMP Credential {} (ID: {})", self.base.name, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credential(config: SnmpCredentialConfig) -> SnmpCredentialBase {
+        SnmpCredentialBase {
+            network_id: Uuid::new_v4(),
+            name: "Core switches".to_string(),
+            config,
+        }
+    }
+
+    #[test]
+    fn test_validate_v3_config() {
+        let auth_priv = credential(SnmpCredentialConfig::V3 {
+            username: "scanopy".to_string(),
+            auth: Some(SnmpV3Auth {
+                protocol: SnmpAuthProtocol::Sha256,
+                password: "authpassword".to_string(),
+            }),
+            privacy: Some(SnmpV3Privacy {
+                protocol: SnmpPrivProtocol::Aes128,
+                password: "privpassword".to_string(),
+            }),
+        });
+        assert!(auth_priv.validate().is_ok());
+
+        let priv_without_auth = credential(SnmpCredentialConfig::V3 {
+            username: "scanopy".to_string(),
+            auth: None,
+            privacy: Some(SnmpV3Privacy {
+                protocol: SnmpPrivProtocol::Aes128,
+                password: "privpassword".to_string(),
+            }),
+        });
+        assert!(priv_without_auth.validate().is_err());
+
+        let short_password = credential(SnmpCredentialConfig::V3 {
+            username: "scanopy".to_string(),
+            auth: Some(SnmpV3Auth {
+                protocol: SnmpAuthProtocol::Sha1,
+                password: "short".to_string(),
+            }),
+            privacy: None,
+        });
+        assert!(short_password.validate().is_err());
+    }
+
+    #[test]
+    fn test_redact_and_keep_secrets() {
+        let stored = SnmpCredentialConfig::V3 {
+            username: "scanopy".to_string(),
+            auth: Some(SnmpV3Auth {
+                protocol: SnmpAuthProtocol::Sha256,
+                password: "authpassword".to_string(),
+            }),
+            privacy: Some(SnmpV3Privacy {
+                protocol: SnmpPrivProtocol::Aes128,
+                password: "privpassword".to_string(),
+            }),
+        };
+
+        let mut update = stored.redacted();
+        let SnmpCredentialConfig::V3 { auth, privacy, .. } = &update else {
+            panic!("Redacting should keep the version");
+        };
+        assert_eq!(auth.as_ref().unwrap().password, "");
+        assert_eq!(privacy.as_ref().unwrap().password, "");
+
+        update.keep_secrets_from(&stored);
+        assert_eq!(update, stored);
+
+        let mut v2c = SnmpCredentialConfig::V2c {
+            community: "private".to_string(),
+        };
+        v2c.keep_secrets_from(&SnmpCredentialConfig::default());
+        assert_eq!(
+            v2c,
+            SnmpCredentialConfig::V2c {
+                community: "private".to_string()
+            },
+            "A new secret should replace the stored one"
+        );
+    }
+
+    #[test]
+    fn test_validate_v2c_config() {
+        assert!(
+            credential(SnmpCredentialConfig::default())
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            credential(SnmpCredentialConfig::V2c {
+                community: String::new()
+            })
+            .validate()
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub mod base;
+mod storage; // StorableEntity impl for SnmpCredential - full CRUD infrastructure
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        secrets,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+    snmp_credentials::r#impl::base::{SnmpCredential, SnmpCredentialBase, SnmpCredentialConfig},
+};
+
+impl Storable for SnmpCredential {
+    type BaseData = SnmpCredentialBase;
+
+    fn table_name() -> &'static str {
+        "snmp_credentials"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    network_id,
+                    name,
+                    config,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "network_id",
+                "name",
+                "config",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(network_id),
+                SqlValue::String(name),
+                SqlValue::JsonValue(serde_json::to_value(
+                    config.try_map_secrets(secrets::encrypt)?,
+                )?),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let config: SnmpCredentialConfig =
+            serde_json::from_value(row.get::<serde_json::Value, _>("config"))
+                .map_err(|e| anyhow::anyhow!("Failed to deserialize config: {}", e))?;
+        let config = config.try_map_secrets(secrets::decrypt)?;
+
+        Ok(SnmpCredential {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: SnmpCredentialBase {
+                network_id: row.get("network_id"),
+                name: row.get("name"),
+                config,
+            },
+        })
+    }
+}
+
+impl Entity for SnmpCredential {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::SnmpCredential
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "snmp_credential"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "snmp_credentials"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+
+    fn preserve_immutable_fields(&mut self, existing: &Self) {
+        // Secrets are blank in responses, so blank ones in an update mean "unchanged"
+        self.base.config.keep_secrets_from(&existing.base.config);
+    }
+}
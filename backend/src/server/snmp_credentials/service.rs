@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        events::bus::EventBus,
+        services::traits::{CrudService, EventBusService},
+        storage::generic::GenericPostgresStorage,
+    },
+    snmp_credentials::r#impl::base::SnmpCredential,
+    tags::entity_tags::EntityTagService,
+};
+
+pub struct SnmpCredentialService {
+    storage: Arc<GenericPostgresStorage<SnmpCredential>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<SnmpCredential> for SnmpCredentialService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &SnmpCredential) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &SnmpCredential) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<SnmpCredential> for SnmpCredentialService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<SnmpCredential>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl SnmpCredentialService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<SnmpCredential>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::server::auth::middleware::auth::AuthenticatedEntity;
 use crate::server::auth::middleware::permissions::{Authorized, IsDaemon, Member, Or, Viewer};
+use crate::server::discovery::r#impl::results::DiscoveryResultKey;
 use crate::server::interfaces::r#impl::base::Interface;
 use crate::server::networks::r#impl::Network;
 use crate::server::shared::extractors::Query;
@@ -16,6 +17,7 @@ use crate::server::shared::types::api::{
 };
 use crate::server::{config::AppState, subnets::r#impl::base::Subnet};
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::Json;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -228,6 +230,7 @@ async fn get_all_subnets(
 async fn create_subnet(
     state: State<Arc<AppState>>,
     auth: Authorized<Or<Member, IsDaemon>>,
+    headers: HeaderMap,
     ApiJson(request): ApiJson<Subnet>,
 ) -> ApiResult<Json<ApiResponse<Subnet>>> {
     let network_ids = auth.network_ids();
@@ -258,6 +261,17 @@ async fn create_subnet(
     let created = match &entity {
         AuthenticatedEntity::Daemon { network_id, .. } => {
             if *network_id == request.base.network_id {
+                let discovery_service = &state.services.discovery_service;
+                let result_key = DiscoveryResultKey::from_headers(&headers);
+
+                // Replayed after the daemon lost the response: it was applied the first time
+                if let Some(key) = &result_key
+                    && let Some(subnet) = discovery_service.applied_result(key, *network_id).await?
+                {
+                    return Ok(Json(ApiResponse::success(subnet)));
+                }
+
+                let network_id = *network_id;
                 let service = Subnet::get_service(&state);
                 let created = service.create(request, entity).await.map_err(|e| {
                     tracing::error!(
@@ -266,6 +280,13 @@ async fn create_subnet(
                     );
                     ApiError::internal_error(&e.to_string())
                 })?;
+
+                if let Some(key) = &result_key {
+                    discovery_service
+                        .record_result(key, network_id, &created)
+                        .await;
+                }
+
                 Json(ApiResponse::success(created))
             } else {
                 return Err(ApiError::entity_network_mismatch::<Subnet>());
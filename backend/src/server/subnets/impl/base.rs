@@ -1,5 +1,5 @@
 use std::fmt::Display;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 
 use crate::server::discovery::r#impl::types::DiscoveryType;
 use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
@@ -8,7 +8,7 @@ use crate::server::shared::types::api::deserialize_empty_string_as_none;
 use crate::server::shared::types::entities::{DiscoveryMetadata, EntitySource};
 use crate::server::subnets::r#impl::types::SubnetType;
 use chrono::{DateTime, Utc};
-use cidr::{IpCidr, Ipv4Cidr};
+use cidr::{IpCidr, Ipv4Cidr, Ipv6Cidr};
 use pnet::ipnetwork::IpNetwork;
 use serde::de::Error as DeError;
 use serde::{Deserialize, Serialize};
@@ -108,7 +108,34 @@ impl Subnet {
         let subnet_type = SubnetType::from_interface_name(&interface_name);
 
         match ip_network {
-            IpNetwork::V6(_) => None,
+            IpNetwork::V6(ipv6_network) => {
+                let ip = ipv6_network.ip();
+
+                // Link-local is present on every interface and isn't a routable subnet;
+                // single addresses (/128) aren't subnets either
+                if (ip.segments()[0] & 0xffc0) == 0xfe80
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || ipv6_network.prefix() == 128
+                {
+                    return None;
+                }
+
+                let cidr =
+                    IpCidr::V6(Ipv6Cidr::new(ipv6_network.network(), ipv6_network.prefix()).ok()?);
+
+                Some(Subnet::new(SubnetBase {
+                    cidr,
+                    network_id,
+                    description: None,
+                    tags: Vec::new(),
+                    name: cidr.to_string(),
+                    subnet_type,
+                    source: EntitySource::Discovery {
+                        metadata: vec![DiscoveryMetadata::new(discovery_type.clone(), daemon_id)],
+                    },
+                }))
+            }
             IpNetwork::V4(ipv4_network) => {
                 let (network_addr, prefix_len) = match (&subnet_type, ipv4_network.prefix()) {
                     // VPN tunnels with /32 -> expand to /24
@@ -164,6 +191,16 @@ impl Subnet {
     }
 }
 
+/// The most specific subnet containing `ip`, so an address lands on a VLAN rather than a
+/// supernet covering it. The 0.0.0.0/0 subnets every network is seeded with contain any
+/// address, but only group hosts in the UI, so they're never picked.
+pub fn most_specific_subnet(ip: IpAddr, subnets: &[Subnet]) -> Option<&Subnet> {
+    subnets
+        .iter()
+        .filter(|s| !s.is_organizational_subnet() && s.base.cidr.contains(&ip))
+        .max_by_key(|s| s.base.cidr.network_length())
+}
+
 impl PartialEq for Subnet {
     fn eq(&self, other: &Self) -> bool {
         let network_match =
@@ -44,6 +44,7 @@ pub enum SubnetType {
     DockerBridge,
     MacVlan,
     IpVlan,
+    Kubernetes,
     Management,
     Storage,
 
@@ -69,6 +70,7 @@ impl FromStr for SubnetType {
             "DockerBridge" => Ok(SubnetType::DockerBridge),
             "MacVlan" => Ok(SubnetType::MacVlan),
             "IpVlan" => Ok(SubnetType::IpVlan),
+            "Kubernetes" => Ok(SubnetType::Kubernetes),
             "Management" => Ok(SubnetType::Management),
             "Storage" => Ok(SubnetType::Storage),
             "Unknown" => Ok(SubnetType::Unknown),
@@ -197,6 +199,7 @@ impl EntityMetadataProvider for SubnetType {
             SubnetType::DockerBridge => Concept::Virtualization.color(),
             SubnetType::MacVlan => Concept::Virtualization.color(),
             SubnetType::IpVlan => Concept::Virtualization.color(),
+            SubnetType::Kubernetes => Concept::Virtualization.color(),
             SubnetType::Storage => Concept::Storage.color(),
 
             SubnetType::Unknown => Color::Gray,
@@ -221,6 +224,7 @@ impl EntityMetadataProvider for SubnetType {
             SubnetType::DockerBridge => Icon::Box,
             SubnetType::MacVlan => Icon::Network,
             SubnetType::IpVlan => Icon::Network,
+            SubnetType::Kubernetes => Icon::Boxes,
             SubnetType::Storage => Concept::Storage.icon(),
 
             SubnetType::Unknown => EntityDiscriminants::Subnet.icon(),
@@ -248,6 +252,7 @@ impl TypeMetadataProvider for SubnetType {
             SubnetType::DockerBridge => "Docker Bridge",
             SubnetType::MacVlan => "MacVLAN",
             SubnetType::IpVlan => "IpVLAN",
+            SubnetType::Kubernetes => "Kubernetes",
             SubnetType::Storage => "Storage",
 
             SubnetType::Unknown => "Unknown",
@@ -273,6 +278,7 @@ impl TypeMetadataProvider for SubnetType {
             SubnetType::DockerBridge => "Docker bridge network",
             SubnetType::MacVlan => "MacVLAN network",
             SubnetType::IpVlan => "IpVLAN network",
+            SubnetType::Kubernetes => "Kubernetes pod or service network",
             SubnetType::Storage => "Storage network",
 
             SubnetType::Unknown => "Unknown network type",
@@ -283,12 +289,18 @@ impl TypeMetadataProvider for SubnetType {
     fn metadata(&self) -> serde_json::Value {
         let network_scan_discovery_eligible = !matches!(
             &self,
-            SubnetType::Remote | SubnetType::Internet | SubnetType::DockerBridge
+            SubnetType::Remote
+                | SubnetType::Internet
+                | SubnetType::DockerBridge
+                | SubnetType::Kubernetes
         );
 
         let is_for_containers = matches!(
             self,
-            SubnetType::DockerBridge | SubnetType::MacVlan | SubnetType::IpVlan
+            SubnetType::DockerBridge
+                | SubnetType::MacVlan
+                | SubnetType::IpVlan
+                | SubnetType::Kubernetes
         );
 
         serde_json::json!({
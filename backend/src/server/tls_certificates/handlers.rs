@@ -0,0 +1,142 @@
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Deserializer};
+use std::sync::Arc;
+use utoipa::IntoParams;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+use crate::server::config::AppState;
+use crate::server::shared::handlers::query::{FilterQueryExtractor, PaginationParams};
+use crate::server::shared::handlers::traits::CrudHandlers;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::storage::traits::Storable;
+use crate::server::shared::types::api::ApiResponse;
+use crate::server::tls_certificates::{
+    r#impl::base::TlsCertificate, service::TlsCertificateService,
+};
+
+/// Query for filtering TLS certificates by host, port or expiry.
+#[derive(Deserialize, Default, Debug, Clone, IntoParams)]
+pub struct TlsCertificateQuery {
+    /// Filter by host ID
+    pub host_id: Option<Uuid>,
+    /// Filter by port ID
+    pub port_id: Option<Uuid>,
+    /// Filter by network ID
+    pub network_id: Option<Uuid>,
+    /// Only return certificates that expire within this many days from now, including those
+    /// that have already expired
+    #[serde(default, deserialize_with = "deserialize_expiry_window")]
+    #[param(minimum = 0, maximum = 36500)]
+    pub expiring_within_days: Option<u32>,
+    /// Maximum number of results to return (1-1000, default: 50). Use 0 for no limit.
+    #[param(minimum = 0, maximum = 1000)]
+    pub limit: Option<u32>,
+    /// Number of results to skip. Default: 0.
+    #[param(minimum = 0)]
+    pub offset: Option<u32>,
+}
+
+/// Longest expiry window accepted, so the cutoff stays well inside the range of a timestamp
+const MAX_EXPIRY_WINDOW_DAYS: u32 = 36500;
+
+fn deserialize_expiry_window<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<u32>::deserialize(deserializer)? {
+        Some(days) if days > MAX_EXPIRY_WINDOW_DAYS => Err(serde::de::Error::custom(format!(
+            "expiring_within_days must be at most {}",
+            MAX_EXPIRY_WINDOW_DAYS
+        ))),
+        days => Ok(days),
+    }
+}
+
+impl FilterQueryExtractor for TlsCertificateQuery {
+    fn apply_to_filter<T: Storable>(
+        &self,
+        filter: StorableFilter<T>,
+        user_network_ids: &[Uuid],
+        _user_organization_id: Uuid,
+    ) -> StorableFilter<T> {
+        let mut filter = match self.network_id {
+            Some(id) if user_network_ids.contains(&id) => filter.network_ids(&[id]),
+            Some(_) => filter.network_ids(&[]),
+            None => filter.network_ids(user_network_ids),
+        };
+        filter = match self.host_id {
+            Some(id) => filter.host_id(&id),
+            None => filter,
+        };
+        filter = match self.port_id {
+            Some(id) => filter.uuid_column("port_id", &id),
+            None => filter,
+        };
+        filter = match self.expiring_within_days {
+            Some(days) => filter.not_after_before(Utc::now() + Duration::days(days.into())),
+            None => filter,
+        };
+
+        filter
+    }
+
+    fn pagination(&self) -> PaginationParams {
+        PaginationParams {
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+impl CrudHandlers for TlsCertificate {
+    type Service = TlsCertificateService;
+    type FilterQuery = TlsCertificateQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.tls_certificate_service
+    }
+}
+
+// Certificates are only ever reported by daemons, so there's no create or update
+mod generated {
+    use super::*;
+    crate::crud_get_all_handler!(TlsCertificate, "tls_certificates", "tls_certificate");
+    crate::crud_get_by_id_handler!(TlsCertificate, "tls_certificates", "tls_certificate");
+    crate::crud_delete_handler!(TlsCertificate, "tls_certificates", "tls_certificate");
+    crate::crud_bulk_delete_handler!(TlsCertificate, "tls_certificates");
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(generated::get_all))
+        .routes(routes!(generated::get_by_id, generated::delete))
+        .routes(routes!(generated::bulk_delete))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_window_is_bounded() {
+        let parse = |days: u64| {
+            serde_json::from_value::<TlsCertificateQuery>(
+                serde_json::json!({ "expiring_within_days": days }),
+            )
+        };
+
+        let query = parse(MAX_EXPIRY_WINDOW_DAYS.into()).unwrap();
+        assert_eq!(query.expiring_within_days, Some(MAX_EXPIRY_WINDOW_DAYS));
+        query.apply_to_filter(StorableFilter::<TlsCertificate>::new(), &[], Uuid::nil());
+
+        assert!(parse(u64::from(MAX_EXPIRY_WINDOW_DAYS) + 1).is_err());
+        assert!(parse(u32::MAX.into()).is_err());
+        assert_eq!(
+            serde_json::from_str::<TlsCertificateQuery>("{}")
+                .unwrap()
+                .expiring_within_days,
+            None
+        );
+    }
+}
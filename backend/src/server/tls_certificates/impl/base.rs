@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::server::shared::entities::ChangeTriggersTopologyStaleness;
+
+/// Public key algorithm of a certificate
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+pub enum CertificateKeyType {
+    Rsa,
+    Ec,
+    Ed25519,
+    Ed448,
+    Dsa,
+    #[default]
+    Unknown,
+}
+
+/// The base data for a TlsCertificate entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct TlsCertificateBase {
+    pub port_id: Uuid,
+    pub host_id: Uuid,
+    pub network_id: Uuid,
+    /// Subject distinguished name, ie `CN=nas.example.com, O=Example`
+    pub subject: String,
+    /// Issuer distinguished name
+    pub issuer: String,
+    /// DNS names, IP addresses, emails and URIs from the subjectAltName extension
+    pub subject_alt_names: Vec<String>,
+    /// Serial number as colon-separated hex
+    pub serial_number: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub key_type: CertificateKeyType,
+    /// Key size in bits, if known for the key type
+    pub key_bits: Option<u32>,
+    /// Subject and issuer are the same name
+    pub is_self_signed: bool,
+    /// Hex SHA-256 of the DER-encoded certificate
+    pub fingerprint_sha256: String,
+}
+
+/// Leaf certificate presented by a TLS-capable port
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::tls_certificate)]
+pub struct TlsCertificate {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: TlsCertificateBase,
+}
+
+impl ChangeTriggersTopologyStaleness<TlsCertificate> for TlsCertificate {
+    fn triggers_staleness(&self, _other: Option<TlsCertificate>) -> bool {
+        false
+    }
+}
+
+impl Display for TlsCertificate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TLS Certificate {} (ID: {})", self.base.subject, self.id)
+    }
+}
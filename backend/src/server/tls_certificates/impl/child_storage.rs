@@ -0,0 +1,15 @@
+use uuid::Uuid;
+
+use crate::server::{
+    shared::storage::child::ChildStorableEntity, tls_certificates::r#impl::base::TlsCertificate,
+};
+
+impl ChildStorableEntity for TlsCertificate {
+    fn parent_column() -> &'static str {
+        "port_id"
+    }
+
+    fn parent_id(&self) -> Uuid {
+        self.base.port_id
+    }
+}
@@ -0,0 +1,4 @@
+pub mod base;
+mod child_storage; // ChildStorableEntity impl for TlsCertificate - parent relationship only
+pub mod parse;
+mod storage; // StorableEntity impl for TlsCertificate - full CRUD infrastructure
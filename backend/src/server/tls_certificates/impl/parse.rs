@@ -0,0 +1,156 @@
+use std::net::IpAddr;
+
+use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use x509_parser::{
+    certificate::X509Certificate,
+    extensions::GeneralName,
+    oid_registry::{OID_SIG_ED448, OID_SIG_ED25519},
+    prelude::FromDer,
+    public_key::PublicKey,
+    time::ASN1Time,
+};
+
+use crate::server::tls_certificates::r#impl::base::{CertificateKeyType, TlsCertificateBase};
+
+impl TlsCertificateBase {
+    /// Parse a DER-encoded leaf certificate, as presented during a TLS handshake. Port, host and
+    /// network are left nil for the caller to fill in.
+    pub fn from_der(der: &[u8]) -> Result<Self> {
+        let (_, cert) = X509Certificate::from_der(der)
+            .map_err(|e| anyhow!("Failed to parse certificate: {}", e))?;
+
+        let subject_alt_names = match cert.subject_alternative_name() {
+            Ok(Some(san)) => san
+                .value
+                .general_names
+                .iter()
+                .filter_map(general_name_to_string)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let (key_type, key_bits) = key_info(&cert);
+
+        Ok(Self {
+            port_id: Uuid::nil(),
+            host_id: Uuid::nil(),
+            network_id: Uuid::nil(),
+            subject: cert.subject().to_string(),
+            issuer: cert.issuer().to_string(),
+            subject_alt_names,
+            serial_number: cert.raw_serial_as_string(),
+            not_before: asn1_to_datetime(cert.validity().not_before)?,
+            not_after: asn1_to_datetime(cert.validity().not_after)?,
+            key_type,
+            key_bits,
+            is_self_signed: cert.subject().as_raw() == cert.issuer().as_raw(),
+            fingerprint_sha256: hex::encode(Sha256::digest(der)),
+        })
+    }
+}
+
+fn asn1_to_datetime(time: ASN1Time) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp(time.timestamp(), 0)
+        .ok_or_else(|| anyhow!("Certificate time {} is out of range", time))
+}
+
+fn key_info(cert: &X509Certificate) -> (CertificateKeyType, Option<u32>) {
+    let spki = cert.public_key();
+
+    // EdDSA keys have no parameters to parse, only an algorithm OID
+    if spki.algorithm.algorithm == OID_SIG_ED25519 {
+        return (CertificateKeyType::Ed25519, Some(256));
+    }
+    if spki.algorithm.algorithm == OID_SIG_ED448 {
+        return (CertificateKeyType::Ed448, Some(456));
+    }
+
+    let Ok(key) = spki.parsed() else {
+        return (CertificateKeyType::Unknown, None);
+    };
+
+    let key_type = match key {
+        PublicKey::RSA(_) => CertificateKeyType::Rsa,
+        PublicKey::EC(_) => CertificateKeyType::Ec,
+        PublicKey::DSA(_) => CertificateKeyType::Dsa,
+        _ => CertificateKeyType::Unknown,
+    };
+
+    let key_bits = match key.key_size() {
+        0 => None,
+        bits => Some(bits as u32),
+    };
+
+    (key_type, key_bits)
+}
+
+fn general_name_to_string(name: &GeneralName) -> Option<String> {
+    match name {
+        GeneralName::DNSName(name) => Some(name.to_string()),
+        GeneralName::RFC822Name(email) => Some(email.to_string()),
+        GeneralName::URI(uri) => Some(uri.to_string()),
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => <[u8; 4]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(b).to_string()),
+            16 => <[u8; 16]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(b).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use x509_parser::pem::parse_x509_pem;
+
+    const SELF_SIGNED_EC_PEM: &str = "-----BEGIN CERTIFICATE-----
+MIIBzjCCAXWgAwIBAgIUOwZmS+x5D4p0Z+ldlnFJZwBTMNUwCgYIKoZIzj0EAwIw
+LDEYMBYGA1UEAwwPbmFzLmV4YW1wbGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMB4X
+DTI2MTAxODA2MjgzNFoXDTM2MTAxNTA2MjgzNFowLDEYMBYGA1UEAwwPbmFzLmV4
+YW1wbGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMFkwEwYHKoZIzj0CAQYIKoZIzj0D
+AQcDQgAEGkwCVrDdU974ahpJZykd1Q11rQnEZyKqeid7YFyeft3oqX4W7KEW1n+T
+9wohW76vAuLDkRTQDDTKw2dSmb5iHaN1MHMwHQYDVR0OBBYEFHGqTm8p/bGfL40T
+VdlE0W2qnQiRMB8GA1UdIwQYMBaAFHGqTm8p/bGfL40TVdlE0W2qnQiRMA8GA1Ud
+EwEB/wQFMAMBAf8wIAYDVR0RBBkwF4IPbmFzLmV4YW1wbGUuY29thwTAqAFkMAoG
+CCqGSM49BAMCA0cAMEQCIE+0g1Tu5j72mUrtp0s5NPO4R6kIaOr3/W1u383E8ZEv
+AiA9KMNs+kfosES6r9hzHAmJEPG23hPh2174vjyLFFCpAg==
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_parse_self_signed_certificate() {
+        let (_, pem) = parse_x509_pem(SELF_SIGNED_EC_PEM.as_bytes()).unwrap();
+        let certificate = TlsCertificateBase::from_der(&pem.contents).unwrap();
+
+        assert_eq!(certificate.subject, "CN=nas.example.com, O=Example");
+        assert_eq!(certificate.issuer, certificate.subject);
+        assert!(certificate.is_self_signed);
+        assert_eq!(
+            certificate.subject_alt_names,
+            vec!["nas.example.com".to_string(), "192.168.1.100".to_string()]
+        );
+        assert_eq!(certificate.key_type, CertificateKeyType::Ec);
+        assert_eq!(certificate.key_bits, Some(256));
+        assert_eq!(
+            certificate.not_before,
+            DateTime::parse_from_rfc3339("2026-10-18T06:28:34Z").unwrap()
+        );
+        assert_eq!(
+            certificate.not_after,
+            DateTime::parse_from_rfc3339("2036-10-15T06:28:34Z").unwrap()
+        );
+        assert_eq!(certificate.fingerprint_sha256.len(), 64);
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        assert!(TlsCertificateBase::from_der(b"not a certificate").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+    tls_certificates::r#impl::base::{CertificateKeyType, TlsCertificate, TlsCertificateBase},
+};
+
+impl Storable for TlsCertificate {
+    type BaseData = TlsCertificateBase;
+
+    fn table_name() -> &'static str {
+        "tls_certificates"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    port_id,
+                    host_id,
+                    network_id,
+                    subject,
+                    issuer,
+                    subject_alt_names,
+                    serial_number,
+                    not_before,
+                    not_after,
+                    key_type,
+                    key_bits,
+                    is_self_signed,
+                    fingerprint_sha256,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "port_id",
+                "host_id",
+                "network_id",
+                "subject",
+                "issuer",
+                "subject_alt_names",
+                "serial_number",
+                "not_before",
+                "not_after",
+                "key_type",
+                "key_bits",
+                "is_self_signed",
+                "fingerprint_sha256",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(port_id),
+                SqlValue::Uuid(host_id),
+                SqlValue::Uuid(network_id),
+                SqlValue::String(subject),
+                SqlValue::String(issuer),
+                SqlValue::StringArray(subject_alt_names),
+                SqlValue::String(serial_number),
+                SqlValue::Timestamp(not_before),
+                SqlValue::Timestamp(not_after),
+                SqlValue::String(key_type.to_string()),
+                SqlValue::OptionalI32(key_bits.map(|b| b as i32)),
+                SqlValue::Bool(is_self_signed),
+                SqlValue::String(fingerprint_sha256),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let key_type: String = row.get("key_type");
+        let key_bits: Option<i32> = row.get("key_bits");
+
+        Ok(TlsCertificate {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: TlsCertificateBase {
+                port_id: row.get("port_id"),
+                host_id: row.get("host_id"),
+                network_id: row.get("network_id"),
+                subject: row.get("subject"),
+                issuer: row.get("issuer"),
+                subject_alt_names: row.get("subject_alt_names"),
+                serial_number: row.get("serial_number"),
+                not_before: row.get("not_before"),
+                not_after: row.get("not_after"),
+                key_type: CertificateKeyType::from_str(&key_type).unwrap_or_default(),
+                key_bits: key_bits.map(|b| b as u32),
+                is_self_signed: row.get("is_self_signed"),
+                fingerprint_sha256: row.get("fingerprint_sha256"),
+            },
+        })
+    }
+}
+
+impl Entity for TlsCertificate {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::TlsCertificate
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "tls_certificate"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "tls_certificates"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        Some(self.base.network_id)
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::server::{
+    auth::middleware::auth::AuthenticatedEntity,
+    ports::r#impl::base::Port,
+    shared::{
+        events::bus::EventBus,
+        services::traits::{ChildCrudService, CrudService, EventBusService},
+        storage::{generic::GenericPostgresStorage, traits::Storable},
+    },
+    tags::entity_tags::EntityTagService,
+    tls_certificates::r#impl::base::TlsCertificate,
+};
+
+pub struct TlsCertificateService {
+    storage: Arc<GenericPostgresStorage<TlsCertificate>>,
+    event_bus: Arc<EventBus>,
+}
+
+impl EventBusService<TlsCertificate> for TlsCertificateService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, entity: &TlsCertificate) -> Option<Uuid> {
+        Some(entity.base.network_id)
+    }
+
+    fn get_organization_id(&self, _entity: &TlsCertificate) -> Option<Uuid> {
+        None
+    }
+}
+
+impl CrudService<TlsCertificate> for TlsCertificateService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<TlsCertificate>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl ChildCrudService<TlsCertificate> for TlsCertificateService {}
+
+impl TlsCertificateService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<TlsCertificate>>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self { storage, event_bus }
+    }
+
+    /// Store certificates reported by a daemon alongside a discovered host.
+    ///
+    /// Certificates reference the daemon's port IDs, which may have been replaced by existing
+    /// ports during host upsert, so they're matched back to the stored ports by port type.
+    /// A port holds a single certificate: a rediscovered one replaces the previous record,
+    /// keeping its ID.
+    pub async fn record_discovered(
+        &self,
+        certificates: Vec<TlsCertificate>,
+        original_ports: &[Port],
+        created_ports: &[Port],
+        authentication: AuthenticatedEntity,
+    ) -> Result<Vec<TlsCertificate>> {
+        let mut recorded = Vec::new();
+
+        for mut certificate in certificates {
+            let Some(port) = original_ports
+                .iter()
+                .find(|p| p.id == certificate.base.port_id)
+                .and_then(|original| {
+                    created_ports
+                        .iter()
+                        .find(|p| p.base.port_type == original.base.port_type)
+                })
+            else {
+                tracing::debug!(
+                    port_id = %certificate.base.port_id,
+                    "Discarding certificate for port that wasn't reported with the host"
+                );
+                continue;
+            };
+
+            certificate.base.port_id = port.id;
+            certificate.base.host_id = port.base.host_id;
+            certificate.base.network_id = port.base.network_id;
+
+            let saved = match self.get_for_parent(&port.id).await?.into_iter().next() {
+                Some(existing) if existing.base == certificate.base => existing,
+                Some(existing) => {
+                    certificate.id = existing.id;
+                    certificate.created_at = existing.created_at;
+                    self.update(&mut certificate, authentication.clone())
+                        .await?
+                }
+                None => {
+                    self.create(
+                        TlsCertificate::new(certificate.base),
+                        authentication.clone(),
+                    )
+                    .await?
+                }
+            };
+
+            recorded.push(saved);
+        }
+
+        Ok(recorded)
+    }
+}
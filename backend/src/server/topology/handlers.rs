@@ -176,6 +176,10 @@ async fn create_topology(
         .get_service_data(topology.base.network_id, &topology.base.options)
         .await?;
 
+    let physical_links = service
+        .get_physical_link_data(topology.base.network_id)
+        .await?;
+
     let (nodes, edges) = service.build_graph(BuildGraphParams {
         options: &topology.base.options,
         hosts: &hosts,
@@ -185,6 +189,7 @@ async fn create_topology(
         groups: &groups,
         ports: &ports,
         bindings: &bindings,
+        physical_links: &physical_links,
         old_edges: &[],
         old_nodes: &[],
     });
@@ -338,6 +343,8 @@ async fn rebuild(
         .get_service_data(request.network_id, &topology.base.options)
         .await?;
 
+    let physical_links = service.get_physical_link_data(request.network_id).await?;
+
     let (nodes, edges) = service.build_graph(BuildGraphParams {
         options: &topology.base.options,
         hosts: &hosts,
@@ -347,6 +354,7 @@ async fn rebuild(
         groups: &groups,
         ports: &ports,
         bindings: &bindings,
+        physical_links: &physical_links,
         old_nodes: &request.nodes,
         old_edges: &request.edges,
     });
@@ -5,6 +5,7 @@ use crate::server::{
     groups::r#impl::base::Group,
     hosts::r#impl::{base::Host, virtualization::HostVirtualization},
     interfaces::r#impl::base::Interface,
+    physical_links::r#impl::base::PhysicalLink,
     ports::r#impl::base::Port,
     services::r#impl::{
         base::Service, definitions::ServiceDefinitionExt, virtualization::ServiceVirtualization,
@@ -27,6 +28,7 @@ pub struct TopologyContext<'a> {
     pub groups: &'a [Group],
     pub ports: &'a [Port],
     pub bindings: &'a [Binding],
+    pub physical_links: &'a [PhysicalLink],
     pub options: &'a TopologyOptions,
 }
 
@@ -40,6 +42,7 @@ impl<'a> TopologyContext<'a> {
         groups: &'a [Group],
         ports: &'a [Port],
         bindings: &'a [Binding],
+        physical_links: &'a [PhysicalLink],
         options: &'a TopologyOptions,
     ) -> Self {
         Self {
@@ -50,6 +53,7 @@ impl<'a> TopologyContext<'a> {
             groups,
             ports,
             bindings,
+            physical_links,
             options,
         }
     }
@@ -166,6 +170,83 @@ impl<'a> TopologyContext<'a> {
             })
     }
 
+    // ============================================================================
+    // Physical Link Methods
+    // ============================================================================
+
+    /// Interface to draw a host's end of a physical link from. Cables connect to the host rather
+    /// than to an address, so any of its interfaces with a node will do, preferring `preferred`.
+    pub fn get_physical_link_anchor_interface(
+        &self,
+        host_id: Uuid,
+        preferred: Option<Uuid>,
+    ) -> Option<&'a Interface> {
+        if let Some(interface) = self.get_interface_by_id(preferred)
+            && interface.base.host_id == host_id
+            && self.interface_will_have_node(&interface.id)
+        {
+            return Some(interface);
+        }
+
+        self.interfaces.iter().find(|interface| {
+            interface.base.host_id == host_id
+                && self
+                    .get_subnet_by_id(interface.base.subnet_id)
+                    .is_some_and(|s| !s.base.subnet_type.is_docker_bridge())
+                && self.interface_will_have_node(&interface.id)
+        })
+    }
+
+    /// Interface for the neighbor end of a physical link. The neighbor is matched to a host by
+    /// MAC address, then management address, then by name.
+    pub fn get_physical_link_remote_interface(&self, link: &PhysicalLink) -> Option<&'a Interface> {
+        let matched_interface = self
+            .interfaces
+            .iter()
+            .find(|i| {
+                link.base.remote_mac_address.is_some()
+                    && i.base.mac_address == link.base.remote_mac_address
+            })
+            .or_else(|| {
+                self.interfaces.iter().find(|i| {
+                    link.base
+                        .remote_ip_address
+                        .is_some_and(|ip| i.base.ip_address == ip)
+                })
+            });
+
+        let host_id = match matched_interface {
+            Some(interface) => interface.base.host_id,
+            None => {
+                // LLDP sysNames are often fully qualified while host names usually aren't
+                let remote_name = link.base.remote_system_name.as_deref()?;
+                let short_name = |name: &str| {
+                    name.split('.')
+                        .next()
+                        .unwrap_or_default()
+                        .to_ascii_lowercase()
+                };
+                let remote_name = short_name(remote_name);
+                if remote_name.is_empty() {
+                    return None;
+                }
+
+                self.hosts
+                    .iter()
+                    .find(|h| {
+                        short_name(&h.base.name) == remote_name
+                            || h.base
+                                .hostname
+                                .as_deref()
+                                .is_some_and(|hostname| short_name(hostname) == remote_name)
+                    })?
+                    .id
+            }
+        };
+
+        self.get_physical_link_anchor_interface(host_id, matched_interface.map(|i| i.id))
+    }
+
     // ============================================================================
     // Infrastructure Zone Methods
     // ============================================================================
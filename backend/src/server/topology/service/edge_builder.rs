@@ -1,6 +1,6 @@
 use itertools::Itertools;
 use petgraph::{Graph, graph::NodeIndex};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use strum::IntoDiscriminant;
 use uuid::Uuid;
 
@@ -378,6 +378,71 @@ impl EdgeBuilder {
             .collect()
     }
 
+    /// Create physical link edges (connecting a managed switch's port to the device cabled to it)
+    pub fn create_physical_link_edges(ctx: &TopologyContext) -> Vec<Edge> {
+        // Both ends of a cable between two switches report it, and a forwarding table entry can
+        // describe the same cable as a neighbor entry, so draw one edge per pair of hosts
+        let mut linked_hosts: HashSet<(Uuid, Uuid)> = HashSet::new();
+
+        ctx.physical_links
+            .iter()
+            // Neighbor protocols first, since they name the ports at both ends
+            .sorted_by_key(|link| !link.base.protocol.is_neighbor_protocol())
+            .filter_map(|link| {
+                let local_interface =
+                    ctx.get_physical_link_anchor_interface(link.base.host_id, None)?;
+                let remote_interface = ctx.get_physical_link_remote_interface(link)?;
+
+                let local_host_id = local_interface.base.host_id;
+                let remote_host_id = remote_interface.base.host_id;
+                if local_host_id == remote_host_id {
+                    return None;
+                }
+
+                let host_pair = if local_host_id < remote_host_id {
+                    (local_host_id, remote_host_id)
+                } else {
+                    (remote_host_id, local_host_id)
+                };
+                if !linked_hosts.insert(host_pair) {
+                    return None;
+                }
+
+                let is_multi_hop = ctx.edge_is_multi_hop(&local_interface.id, &remote_interface.id);
+
+                let (source_handle, target_handle) = EdgeBuilder::determine_interface_handles(
+                    ctx,
+                    &local_interface.id,
+                    &remote_interface.id,
+                    is_multi_hop,
+                )?;
+
+                let label = match (&link.base.local_port, &link.base.remote_port) {
+                    (Some(local_port), Some(remote_port)) => {
+                        Some(format!("{} ↔ {}", local_port, remote_port))
+                    }
+                    (Some(port), None) | (None, Some(port)) => Some(port.clone()),
+                    (None, None) => None,
+                };
+
+                Some(Edge {
+                    id: Uuid::new_v4(),
+                    source: local_interface.id,
+                    target: remote_interface.id,
+                    edge_type: EdgeType::PhysicalLink {
+                        physical_link_id: link.id,
+                        source_port: link.base.local_port.clone(),
+                        target_port: link.base.remote_port.clone(),
+                    },
+                    label,
+                    source_handle,
+                    target_handle,
+                    is_multi_hop,
+                })
+            })
+            .collect()
+    }
+
     /// Figure out handles for two interfaces
     pub fn determine_interface_handles(
         ctx: &TopologyContext,
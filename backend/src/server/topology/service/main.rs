@@ -13,6 +13,7 @@ use crate::server::{
     groups::{r#impl::base::Group, service::GroupService},
     hosts::{r#impl::base::Host, service::HostService},
     interfaces::{r#impl::base::Interface, service::InterfaceService},
+    physical_links::{r#impl::base::PhysicalLink, service::PhysicalLinkService},
     ports::{r#impl::base::Port, service::PortService},
     services::{r#impl::base::Service, service::ServiceService},
     shared::{
@@ -52,6 +53,7 @@ pub struct TopologyService {
     service_service: Arc<ServiceService>,
     port_service: Arc<PortService>,
     binding_service: Arc<BindingService>,
+    physical_link_service: Arc<PhysicalLinkService>,
     event_bus: Arc<EventBus>,
     pub staleness_tx: broadcast::Sender<Topology>,
 }
@@ -98,6 +100,10 @@ impl CrudService<Topology> for TopologyService {
             .get_service_data(topology.base.network_id, &topology.base.options)
             .await?;
 
+        let physical_links = self
+            .get_physical_link_data(topology.base.network_id)
+            .await?;
+
         let params = BuildGraphParams {
             hosts: &hosts,
             interfaces: &interfaces,
@@ -106,6 +112,7 @@ impl CrudService<Topology> for TopologyService {
             groups: &groups,
             ports: &ports,
             bindings: &bindings,
+            physical_links: &physical_links,
             old_edges: &[],
             old_nodes: &[],
             options: &topology.base.options,
@@ -154,6 +161,7 @@ pub struct BuildGraphParams<'a> {
     pub groups: &'a [Group],
     pub ports: &'a [Port],
     pub bindings: &'a [Binding],
+    pub physical_links: &'a [PhysicalLink],
     pub old_nodes: &'a [Node],
     pub old_edges: &'a [Edge],
 }
@@ -168,6 +176,7 @@ impl TopologyService {
         service_service: Arc<ServiceService>,
         port_service: Arc<PortService>,
         binding_service: Arc<BindingService>,
+        physical_link_service: Arc<PhysicalLinkService>,
         storage: Arc<GenericPostgresStorage<Topology>>,
         event_bus: Arc<EventBus>,
     ) -> Self {
@@ -181,6 +190,7 @@ impl TopologyService {
             storage,
             port_service,
             binding_service,
+            physical_link_service,
             event_bus,
             staleness_tx,
         }
@@ -259,6 +269,15 @@ impl TopologyService {
             .collect())
     }
 
+    pub async fn get_physical_link_data(
+        &self,
+        network_id: Uuid,
+    ) -> Result<Vec<PhysicalLink>, Error> {
+        self.physical_link_service
+            .get_all(StorableFilter::<PhysicalLink>::new().network_ids(&[network_id]))
+            .await
+    }
+
     pub fn build_graph(&self, params: BuildGraphParams) -> (Vec<Node>, Vec<Edge>) {
         let BuildGraphParams {
             hosts,
@@ -268,6 +287,7 @@ impl TopologyService {
             groups,
             ports,
             bindings,
+            physical_links,
             old_edges,
             old_nodes,
             options,
@@ -275,7 +295,15 @@ impl TopologyService {
 
         // Create context to avoid parameter passing
         let ctx = TopologyContext::new(
-            hosts, interfaces, subnets, services, groups, ports, bindings, options,
+            hosts,
+            interfaces,
+            subnets,
+            services,
+            groups,
+            ports,
+            bindings,
+            physical_links,
+            options,
         );
 
         // Create all edges (needed for anchor analysis)
@@ -285,6 +313,7 @@ impl TopologyService {
 
         all_edges.extend(EdgeBuilder::create_group_edges(&ctx));
         all_edges.extend(EdgeBuilder::create_vm_host_edges(&ctx));
+        all_edges.extend(EdgeBuilder::create_physical_link_edges(&ctx));
         let (container_edges, docker_bridge_host_subnet_id_to_group_on) =
             EdgeBuilder::create_containerized_service_edges(
                 &ctx,
@@ -49,6 +49,7 @@ impl EventSubscriber for TopologyService {
             (EntityDiscriminants::Group, None),
             (EntityDiscriminants::Port, None),
             (EntityDiscriminants::Binding, None),
+            (EntityDiscriminants::PhysicalLink, None),
             (
                 EntityDiscriminants::Topology,
                 Some(vec![EntityOperation::Created, EntityOperation::Updated]),
@@ -282,6 +282,11 @@ pub enum EdgeType {
         source_binding_id: Uuid,
         target_binding_id: Uuid,
     },
+    PhysicalLink {
+        physical_link_id: Uuid,
+        source_port: Option<String>,
+        target_port: Option<String>,
+    }, // Cabling reported by a managed switch, from its port to the neighbor's
 }
 
 impl HasId for EdgeType {
@@ -298,6 +303,7 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EntityDiscriminants::Host.color(),
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.color(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.color(),
+            EdgeType::PhysicalLink { .. } => EntityDiscriminants::PhysicalLink.color(),
         }
     }
 
@@ -308,6 +314,7 @@ impl EntityMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EntityDiscriminants::Host.icon(),
             EdgeType::HostVirtualization { .. } => Concept::Virtualization.icon(),
             EdgeType::ServiceVirtualization { .. } => Concept::Virtualization.icon(),
+            EdgeType::PhysicalLink { .. } => EntityDiscriminants::PhysicalLink.icon(),
         }
     }
 }
@@ -320,6 +327,7 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => "Host Interface",
             EdgeType::HostVirtualization { .. } => "Virtualized Host",
             EdgeType::ServiceVirtualization { .. } => "Virtualized Service",
+            EdgeType::PhysicalLink { .. } => "Physical Link",
         }
     }
 
@@ -330,6 +338,7 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => EdgeStyle::SmoothStep.into(),
             EdgeType::HostVirtualization { .. } => EdgeStyle::Straight.into(),
             EdgeType::ServiceVirtualization { .. } => EdgeStyle::SmoothStep.into(),
+            EdgeType::PhysicalLink { .. } => EdgeStyle::Straight.into(),
         };
 
         let is_dashed = match &self {
@@ -338,6 +347,7 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => true,
             EdgeType::HostVirtualization { .. } => true,
             EdgeType::ServiceVirtualization { .. } => true,
+            EdgeType::PhysicalLink { .. } => false,
         };
 
         let has_start_marker = false;
@@ -348,6 +358,7 @@ impl TypeMetadataProvider for EdgeType {
             EdgeType::Interface { .. } => false,
             EdgeType::HostVirtualization { .. } => false,
             EdgeType::ServiceVirtualization { .. } => false,
+            EdgeType::PhysicalLink { .. } => false,
         };
 
         let is_host_edge = matches!(
@@ -0,0 +1,302 @@
+use crate::server::auth::middleware::features::{RequireFeature, WebhooksFeature};
+use crate::server::auth::middleware::permissions::{Admin, Authorized, Member};
+use crate::server::shared::api_key_common::generate_base62_string;
+use crate::server::shared::extractors::Query;
+use crate::server::shared::handlers::query::{NoFilterQuery, PaginationParams};
+use crate::server::shared::handlers::traits::{
+    CrudHandlers, create_handler, delete_handler, get_all_handler, get_by_id_handler,
+    update_handler,
+};
+use crate::server::shared::secrets;
+use crate::server::shared::services::traits::CrudService;
+use crate::server::shared::storage::filter::StorableFilter;
+use crate::server::shared::types::api::{
+    ApiError, ApiErrorResponse, EmptyApiResponse, PaginatedApiResponse,
+};
+use crate::server::webhooks::{
+    r#impl::{base::Webhook, delivery::WebhookDelivery},
+    service::WebhookService,
+};
+use crate::server::{
+    config::AppState,
+    shared::types::api::{ApiResponse, ApiResult},
+};
+use axum::extract::Path;
+use axum::{extract::State, response::Json};
+use std::sync::Arc;
+use utoipa_axum::{router::OpenApiRouter, routes};
+use uuid::Uuid;
+
+impl CrudHandlers for Webhook {
+    type Service = WebhookService;
+    type FilterQuery = NoFilterQuery;
+
+    fn get_service(state: &AppState) -> &Self::Service {
+        &state.services.webhook_service
+    }
+}
+
+pub fn create_router() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new()
+        .routes(routes!(get_all_webhooks, create_webhook))
+        .routes(routes!(get_webhook_by_id, update_webhook, delete_webhook))
+        .routes(routes!(get_webhook_deliveries))
+        .routes(routes!(redeliver_webhook_delivery))
+}
+
+/// Webhook in the caller's organization
+async fn get_organization_webhook(
+    state: &AppState,
+    auth: &Authorized<Admin>,
+    id: Uuid,
+) -> Result<Webhook, ApiError> {
+    let organization_id = auth
+        .organization_id()
+        .ok_or_else(ApiError::organization_required)?;
+
+    state
+        .services
+        .webhook_service
+        .get_by_id(&id)
+        .await?
+        .filter(|w| w.base.organization_id == organization_id)
+        .ok_or_else(|| ApiError::entity_not_found::<Webhook>(id))
+}
+
+/// List all webhooks
+///
+/// Returns the webhooks in your organization. Secrets are blanked; they're only returned when a
+/// webhook is created. Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "webhooks",
+    params(NoFilterQuery),
+    responses(
+        (status = 200, description = "List of webhooks", body = PaginatedApiResponse<Webhook>),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_all_webhooks(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    query: Query<NoFilterQuery>,
+) -> ApiResult<Json<PaginatedApiResponse<Webhook>>> {
+    let Json(mut response) =
+        get_all_handler::<Webhook>(state, auth.into_permission(), query).await?;
+    response.data = response.data.into_iter().map(Webhook::redacted).collect();
+    Ok(Json(response))
+}
+
+/// Get webhook by ID
+///
+/// The secret is blanked in the response. Requires admin permissions.
+#[utoipa::path(
+    get,
+    path = "/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Webhook found", body = ApiResponse<Webhook>),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_webhook_by_id(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<Webhook>>> {
+    let Json(response) = get_by_id_handler::<Webhook>(state, auth.into_permission(), path).await?;
+    Ok(Json(response.map_data(Webhook::redacted)))
+}
+
+/// Create a new webhook
+///
+/// Each event the webhook subscribes to is POSTed to its URL as JSON, with the event name in
+/// the `X-Scanopy-Event` header and the hex HMAC-SHA256 of the body, keyed with the secret, in
+/// the `X-Scanopy-Signature-256` header as `sha256=<hex>`. Failed deliveries are retried with
+/// exponential backoff, up to 6 attempts. This response is the only one that includes the
+/// secret.
+///
+/// ### Validation
+///
+/// - Name must be 1-100 characters
+/// - URL must be an absolute http or https URL
+/// - At least one event is required
+/// - Secret must be at least 16 characters. Leave it empty to have one generated.
+/// - The server must have a secret key configured to encrypt the secret with
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "webhooks",
+    request_body = Webhook,
+    responses(
+        (status = 200, description = "Webhook created successfully", body = ApiResponse<Webhook>),
+        (status = 400, description = "Invalid webhook", body = ApiErrorResponse),
+        (status = 402, description = "Your plan does not include webhooks", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn create_webhook(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    RequireFeature { .. }: RequireFeature<WebhooksFeature>,
+    Json(mut webhook): Json<Webhook>,
+) -> ApiResult<Json<ApiResponse<Webhook>>> {
+    secrets::require_configured("Webhooks")?;
+    if webhook.base.secret.is_empty() {
+        webhook.base.secret = format!("whsec_{}", generate_base62_string(32));
+    }
+
+    create_handler::<Webhook>(state, auth.into_permission::<Member>(), Json(webhook)).await
+}
+
+/// Update webhook
+///
+/// Same validation rules as creating a webhook. Leave the secret empty to keep the current one.
+#[utoipa::path(
+    put,
+    path = "/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    request_body = Webhook,
+    responses(
+        (status = 200, description = "Webhook updated", body = ApiResponse<Webhook>),
+        (status = 400, description = "Invalid webhook", body = ApiErrorResponse),
+        (status = 402, description = "Your plan does not include webhooks", body = ApiErrorResponse),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn update_webhook(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    RequireFeature { .. }: RequireFeature<WebhooksFeature>,
+    Path(id): Path<Uuid>,
+    Json(mut webhook): Json<Webhook>,
+) -> ApiResult<Json<ApiResponse<Webhook>>> {
+    secrets::require_configured("Webhooks")?;
+    if webhook.base.secret.is_empty() {
+        webhook.base.secret = get_organization_webhook(&state, &auth, id)
+            .await?
+            .base
+            .secret;
+    }
+
+    let Json(response) = update_handler::<Webhook>(
+        State(state),
+        auth.into_permission::<Member>(),
+        Path(id),
+        Json(webhook),
+    )
+    .await?;
+    Ok(Json(response.map_data(Webhook::redacted)))
+}
+
+/// Delete webhook
+///
+/// Deletes the webhook and its delivery log. Deliveries being retried are abandoned.
+#[utoipa::path(
+    delete,
+    path = "/{id}",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID")),
+    responses(
+        (status = 200, description = "Webhook deleted", body = EmptyApiResponse),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn delete_webhook(
+    state: State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    path: Path<Uuid>,
+) -> ApiResult<Json<ApiResponse<()>>> {
+    delete_handler::<Webhook>(state, auth.into_permission::<Member>(), path).await
+}
+
+/// List webhook deliveries
+///
+/// Returns the deliveries made to a webhook, newest first, with the payload sent and the outcome
+/// of the last attempt.
+#[utoipa::path(
+    get,
+    path = "/{id}/deliveries",
+    tag = "webhooks",
+    params(("id" = Uuid, Path, description = "Webhook ID"), PaginationParams),
+    responses(
+        (status = 200, description = "List of deliveries", body = PaginatedApiResponse<WebhookDelivery>),
+        (status = 404, description = "Webhook not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn get_webhook_deliveries(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    Path(id): Path<Uuid>,
+    Query(pagination): Query<PaginationParams>,
+) -> ApiResult<Json<PaginatedApiResponse<WebhookDelivery>>> {
+    let webhook = get_organization_webhook(&state, &auth, id).await?;
+
+    let filter = pagination.apply_to_filter(
+        StorableFilter::<WebhookDelivery>::new().uuid_column("webhook_id", &webhook.id),
+    );
+
+    let result = state
+        .services
+        .webhook_service
+        .get_deliveries_paginated(filter)
+        .await?;
+
+    Ok(Json(PaginatedApiResponse::success(
+        result.items,
+        result.total_count,
+        pagination.effective_limit().unwrap_or(0),
+        pagination.effective_offset(),
+    )))
+}
+
+/// Redeliver a webhook delivery
+///
+/// Sends the payload of a past delivery to the webhook again, as a new delivery with its own
+/// retries. The payload keeps its original event ID, so receivers can deduplicate.
+#[utoipa::path(
+    post,
+    path = "/{id}/deliveries/{delivery_id}/redeliver",
+    tag = "webhooks",
+    params(
+        ("id" = Uuid, Path, description = "Webhook ID"),
+        ("delivery_id" = Uuid, Path, description = "Delivery ID"),
+    ),
+    responses(
+        (status = 200, description = "Redelivery queued", body = ApiResponse<WebhookDelivery>),
+        (status = 402, description = "Your plan does not include webhooks", body = ApiErrorResponse),
+        (status = 404, description = "Webhook or delivery not found", body = ApiErrorResponse),
+    ),
+    security(("user_api_key" = []), ("session" = []))
+)]
+async fn redeliver_webhook_delivery(
+    State(state): State<Arc<AppState>>,
+    auth: Authorized<Admin>,
+    RequireFeature { .. }: RequireFeature<WebhooksFeature>,
+    Path((id, delivery_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Json<ApiResponse<WebhookDelivery>>> {
+    let webhook = get_organization_webhook(&state, &auth, id).await?;
+
+    let delivery = state
+        .services
+        .webhook_service
+        .get_delivery(&delivery_id)
+        .await?
+        .filter(|d| d.base.webhook_id == webhook.id)
+        .ok_or_else(|| ApiError::not_found(format!("Delivery {} not found", delivery_id)))?;
+
+    let redelivery = state
+        .services
+        .webhook_service
+        .redeliver(webhook, &delivery)
+        .await?;
+
+    Ok(Json(ApiResponse::success(redelivery)))
+}
@@ -0,0 +1,455 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr};
+use strum::{Display as StrumDisplay, EnumIter, EnumString, IntoDiscriminant, IntoEnumIterator};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::daemon::discovery::types::base::DiscoveryPhase;
+use crate::server::{
+    discovery::r#impl::types::RunType,
+    shared::{
+        entities::{ChangeTriggersTopologyStaleness, Entity, EntityDiscriminants},
+        events::{
+            bus::EventFilter,
+            types::{EntityEvent, EntityOperation, Event},
+        },
+    },
+};
+
+/// Events a webhook can be sent for
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    EnumIter,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookEventType {
+    #[default]
+    HostCreated,
+    HostUpdated,
+    HostDeleted,
+    InterfaceCreated,
+    InterfaceUpdated,
+    InterfaceDeleted,
+    PortCreated,
+    PortUpdated,
+    PortDeleted,
+    ServiceCreated,
+    ServiceUpdated,
+    ServiceDeleted,
+    SubnetCreated,
+    SubnetUpdated,
+    SubnetDeleted,
+    DiscoveryStarted,
+    DiscoveryCompleted,
+    DiscoveryFailed,
+    DiscoveryCancelled,
+}
+
+impl WebhookEventType {
+    /// The entity type and operation of the events this is sent for. Finished discovery runs are
+    /// all published as created historical discoveries, and told apart by their phase.
+    pub fn entity_operation(&self) -> (EntityDiscriminants, EntityOperation) {
+        use EntityDiscriminants as E;
+        use EntityOperation as O;
+
+        match self {
+            Self::HostCreated => (E::Host, O::Created),
+            Self::HostUpdated => (E::Host, O::Updated),
+            Self::HostDeleted => (E::Host, O::Deleted),
+            Self::InterfaceCreated => (E::Interface, O::Created),
+            Self::InterfaceUpdated => (E::Interface, O::Updated),
+            Self::InterfaceDeleted => (E::Interface, O::Deleted),
+            Self::PortCreated => (E::Port, O::Created),
+            Self::PortUpdated => (E::Port, O::Updated),
+            Self::PortDeleted => (E::Port, O::Deleted),
+            Self::ServiceCreated => (E::Service, O::Created),
+            Self::ServiceUpdated => (E::Service, O::Updated),
+            Self::ServiceDeleted => (E::Service, O::Deleted),
+            Self::SubnetCreated => (E::Subnet, O::Created),
+            Self::SubnetUpdated => (E::Subnet, O::Updated),
+            Self::SubnetDeleted => (E::Subnet, O::Deleted),
+            Self::DiscoveryStarted => (E::Daemon, O::DiscoveryStarted),
+            Self::DiscoveryCompleted | Self::DiscoveryFailed | Self::DiscoveryCancelled => {
+                (E::Discovery, O::Created)
+            }
+        }
+    }
+
+    /// The webhook event an entity event is, if webhooks can be sent for it
+    pub fn from_event(event: &EntityEvent) -> Option<Self> {
+        if let Entity::Discovery(discovery) = &event.entity_type {
+            return match (&event.operation, &discovery.base.run_type) {
+                (EntityOperation::Created, RunType::Historical { results }) => {
+                    match results.phase {
+                        DiscoveryPhase::Complete => Some(Self::DiscoveryCompleted),
+                        DiscoveryPhase::Failed => Some(Self::DiscoveryFailed),
+                        DiscoveryPhase::Cancelled => Some(Self::DiscoveryCancelled),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+        }
+
+        let entity_operation = (event.entity_type.discriminant(), event.operation.clone());
+        Self::iter().find(|t| t.entity_operation() == entity_operation)
+    }
+
+    /// Filter matching the entity events for any of `event_types`
+    pub fn event_filter(event_types: impl IntoIterator<Item = Self>) -> EventFilter {
+        let mut entity_operations: HashMap<EntityDiscriminants, Option<Vec<EntityOperation>>> =
+            HashMap::new();
+
+        for event_type in event_types {
+            let (entity_type, operation) = event_type.entity_operation();
+            let operations = entity_operations
+                .entry(entity_type)
+                .or_insert_with(|| Some(Vec::new()))
+                .get_or_insert_default();
+            if !operations.contains(&operation) {
+                operations.push(operation);
+            }
+        }
+
+        EventFilter::entity_only(entity_operations)
+    }
+}
+
+/// Shared secrets are used as HMAC keys, so short ones are rejected
+const MIN_SECRET_LENGTH: usize = 16;
+
+/// Whether a webhook may be sent to `ip`. Loopback, private, link-local and other non-routable
+/// addresses are refused, so webhooks can't be used to reach the server's own network.
+pub fn is_public_address(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_public_v4(&v4),
+            None => {
+                // 64:ff9b::/96, NAT64, which can embed any IPv4 address including private ones
+                let nat64 = v6.segments()[..6] == [0x64, 0xff9b, 0, 0, 0, 0];
+
+                !(v6.is_loopback()
+                    || v6.is_unspecified()
+                    || v6.is_multicast()
+                    || v6.is_unique_local()
+                    || v6.is_unicast_link_local()
+                    || nat64)
+            }
+        },
+    }
+}
+
+fn is_public_v4(ip: &Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    // 100.64.0.0/10, carrier-grade NAT
+    let shared = octets[0] == 100 && (octets[1] & 0xc0) == 64;
+    // 198.18.0.0/15, benchmarking
+    let benchmarking = octets[0] == 198 && (octets[1] & 0xfe) == 18;
+    // 240.0.0.0/4, reserved
+    let reserved = octets[0] >= 240;
+
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || octets[0] == 0
+        || shared
+        || benchmarking
+        || reserved)
+}
+
+/// Checks what can be known about a webhook URL without resolving it: an http(s) scheme, and a
+/// host that isn't `localhost` or a non-public IP. Hostnames are checked again once resolved,
+/// when deliveries are sent.
+pub fn check_url(url: &str) -> Result<(), &'static str> {
+    let parsed = url::Url::parse(url).map_err(|_| "URL must be an absolute http or https URL")?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("URL must be an absolute http or https URL");
+    }
+
+    let is_public = match parsed.host() {
+        None => return Err("URL must be an absolute http or https URL"),
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(ip)) => is_public_address(&IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => is_public_address(&IpAddr::V6(ip)),
+    };
+
+    if is_public {
+        Ok(())
+    } else {
+        Err("URL must not point to a loopback, private or link-local address")
+    }
+}
+
+fn validate_url(url: &str) -> Result<(), ValidationError> {
+    check_url(url).map_err(|message| {
+        let mut err = ValidationError::new("webhook_url");
+        err.message = Some(message.into());
+        err
+    })
+}
+
+fn validate_secret(secret: &str) -> Result<(), ValidationError> {
+    if !secret.is_empty() && secret.len() < MIN_SECRET_LENGTH {
+        let mut err = ValidationError::new("webhook_secret");
+        err.message = Some("Secret must be at least 16 characters".into());
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// The base data for a Webhook entity (everything except id, created_at, updated_at)
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+pub struct WebhookBase {
+    pub organization_id: Uuid,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Endpoint deliveries are POSTed to. It must resolve to a public address, and redirects
+    /// aren't followed.
+    #[validate(custom(function = "validate_url"))]
+    pub url: String,
+    /// Key for the `X-Scanopy-Signature-256` header. Generated when left empty on create, and
+    /// kept when left empty on update. Stored encrypted, and only returned when the webhook is
+    /// created.
+    #[serde(default)]
+    #[validate(custom(function = "validate_secret"))]
+    pub secret: String,
+    #[validate(length(min = 1, message = "Select at least one event"))]
+    pub events: Vec<WebhookEventType>,
+    /// Only send events from these networks. Empty for every network in the organization.
+    #[serde(default)]
+    pub network_ids: Vec<Uuid>,
+    pub enabled: bool,
+}
+
+/// An endpoint that is sent signed HTTP requests when hosts, services or discovery runs in the
+/// organization change.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, Default, Validate, Serialize, Deserialize, ToSchema,
+)]
+#[schema(example = crate::server::shared::types::examples::webhook)]
+pub struct Webhook {
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub id: Uuid,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    #[schema(read_only, required)]
+    pub updated_at: DateTime<Utc>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub base: WebhookBase,
+}
+
+impl Webhook {
+    /// The webhook with its secret blanked, as returned after creation
+    pub fn redacted(mut self) -> Self {
+        self.base.secret = String::new();
+        self
+    }
+
+    /// Whether `event`, which is a `event_type` event, should be sent to this webhook
+    pub fn wants(&self, event_type: WebhookEventType, event: &Event) -> bool {
+        if !self.base.enabled || !self.base.events.contains(&event_type) {
+            return false;
+        }
+
+        let mut filter = WebhookEventType::event_filter(self.base.events.iter().copied());
+        if !self.base.network_ids.is_empty() {
+            filter.network_ids = Some(self.base.network_ids.clone());
+        }
+
+        filter.matches(event)
+    }
+}
+
+impl ChangeTriggersTopologyStaleness<Webhook> for Webhook {
+    fn triggers_staleness(&self, _other: Option<Webhook>) -> bool {
+        false
+    }
+}
+
+impl Display for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Webhook {} (ID: {})", self.base.name, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::{
+        auth::middleware::auth::AuthenticatedEntity,
+        daemons::r#impl::api::DiscoveryUpdatePayload,
+        discovery::r#impl::base::{Discovery, DiscoveryBase},
+        hosts::r#impl::base::Host,
+    };
+
+    fn event(entity: Entity, operation: EntityOperation, network_id: Uuid) -> EntityEvent {
+        EntityEvent::new(
+            Uuid::new_v4(),
+            entity,
+            Uuid::new_v4(),
+            Some(network_id),
+            None,
+            operation,
+            Utc::now(),
+            AuthenticatedEntity::System,
+            serde_json::Value::Null,
+        )
+    }
+
+    fn webhook(events: Vec<WebhookEventType>, network_ids: Vec<Uuid>) -> Webhook {
+        Webhook {
+            base: WebhookBase {
+                name: "Alerts".to_string(),
+                url: "https://hooks.example.com/scanopy".to_string(),
+                events,
+                network_ids,
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_event_type_from_discovery_phase() {
+        let network_id = Uuid::new_v4();
+        let mut results = DiscoveryUpdatePayload::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            network_id,
+            Default::default(),
+        );
+        results.phase = DiscoveryPhase::Failed;
+
+        let discovery = Discovery {
+            base: DiscoveryBase {
+                network_id,
+                run_type: RunType::Historical { results },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let failed = event(discovery.into(), EntityOperation::Created, network_id);
+        assert_eq!(
+            WebhookEventType::from_event(&failed),
+            Some(WebhookEventType::DiscoveryFailed)
+        );
+
+        let host_created = event(Host::default().into(), EntityOperation::Created, network_id);
+        assert_eq!(
+            WebhookEventType::from_event(&host_created),
+            Some(WebhookEventType::HostCreated)
+        );
+
+        let host_read = event(Host::default().into(), EntityOperation::Get, network_id);
+        assert_eq!(WebhookEventType::from_event(&host_read), None);
+    }
+
+    #[test]
+    fn test_webhook_wants_subscribed_events_on_its_networks() {
+        let network_id = Uuid::new_v4();
+        let host_created = Event::Entity(Box::new(event(
+            Host::default().into(),
+            EntityOperation::Created,
+            network_id,
+        )));
+
+        let all_networks = webhook(vec![WebhookEventType::HostCreated], vec![]);
+        assert!(all_networks.wants(WebhookEventType::HostCreated, &host_created));
+        assert!(!all_networks.wants(WebhookEventType::HostDeleted, &host_created));
+
+        let other_network = webhook(vec![WebhookEventType::HostCreated], vec![Uuid::new_v4()]);
+        assert!(!other_network.wants(WebhookEventType::HostCreated, &host_created));
+
+        let mut disabled = webhook(vec![WebhookEventType::HostCreated], vec![network_id]);
+        disabled.base.enabled = false;
+        assert!(!disabled.wants(WebhookEventType::HostCreated, &host_created));
+    }
+
+    #[test]
+    fn test_is_public_address() {
+        assert!(is_public_address(&"93.184.216.34".parse().unwrap()));
+        assert!(is_public_address(&"2606:2800:220:1::".parse().unwrap()));
+        assert!(is_public_address(&"198.20.0.1".parse().unwrap()));
+
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fe80::1",
+            "fc00::1",
+            "::ffff:127.0.0.1",
+            "198.18.0.1",
+            "198.19.255.254",
+            "240.0.0.1",
+            "64:ff9b::a00:1",
+        ] {
+            assert!(!is_public_address(&ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn test_validate_webhook() {
+        let mut valid = webhook(vec![WebhookEventType::ServiceDeleted], vec![]);
+        assert!(valid.validate().is_ok());
+
+        valid.base.secret = "short".to_string();
+        assert!(valid.validate().is_err());
+
+        let mut bad_url = webhook(vec![WebhookEventType::ServiceDeleted], vec![]);
+        bad_url.base.url = "ftp://example.com".to_string();
+        assert!(bad_url.validate().is_err());
+
+        for internal in [
+            "http://localhost:8080/hook",
+            "http://127.0.0.1/hook",
+            "http://10.0.0.5/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[::ffff:192.168.1.1]/hook",
+        ] {
+            bad_url.base.url = internal.to_string();
+            assert!(bad_url.validate().is_err(), "{internal} should be rejected");
+        }
+
+        assert!(webhook(vec![], vec![]).validate().is_err());
+    }
+}
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::fmt::Display;
+use strum::{Display as StrumDisplay, EnumString};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::server::{shared::events::types::EntityEvent, webhooks::r#impl::base::WebhookEventType};
+
+/// Header carrying the hex HMAC-SHA256 of the request body, keyed with the webhook's secret
+pub const SIGNATURE_HEADER: &str = "X-Scanopy-Signature-256";
+pub const EVENT_HEADER: &str = "X-Scanopy-Event";
+pub const DELIVERY_HEADER: &str = "X-Scanopy-Delivery";
+
+/// Where a delivery is at
+#[derive(
+    Copy,
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    StrumDisplay,
+    EnumString,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+pub enum WebhookDeliveryStatus {
+    /// Not sent yet, or waiting to be retried
+    #[default]
+    Pending,
+    Succeeded,
+    /// Gave up after the endpoint kept failing, or rejected the request
+    Failed,
+}
+
+/// The body POSTed to a webhook endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookPayload {
+    /// ID of the event. Redeliveries of the same event share it.
+    pub id: Uuid,
+    pub event: WebhookEventType,
+    pub timestamp: DateTime<Utc>,
+    pub organization_id: Uuid,
+    pub network_id: Option<Uuid>,
+    /// The entity the event is about. For deletions, as it was just before.
+    #[schema(value_type = Object)]
+    pub data: serde_json::Value,
+}
+
+impl WebhookPayload {
+    pub fn new(
+        event_type: WebhookEventType,
+        event: &EntityEvent,
+        organization_id: Uuid,
+    ) -> Result<Self, serde_json::Error> {
+        let data = event.entity_type.inner_json()?;
+
+        Ok(Self {
+            id: event.id,
+            event: event_type,
+            timestamp: event.timestamp,
+            organization_id,
+            network_id: event.network_id,
+            data,
+        })
+    }
+}
+
+/// Value of the signature header for a request body: `sha256=<hex HMAC-SHA256>`
+pub fn sign(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// The base data for a WebhookDelivery record (everything except id and created_at)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDeliveryBase {
+    pub webhook_id: Uuid,
+    pub organization_id: Uuid,
+    /// ID of the event, shared by redeliveries
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// The request body, as sent
+    #[schema(value_type = Object)]
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    /// HTTP status of the last response, if the endpoint answered
+    pub response_status: Option<i32>,
+    /// Why the last attempt failed
+    pub error: Option<String>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// When a pending delivery is next tried. None for deliveries not tried yet, which are due
+    /// straight away.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// One attempt to get an event to a webhook, retried until it succeeds or runs out of attempts
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub created_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub base: WebhookDeliveryBase,
+}
+
+impl WebhookDelivery {
+    pub fn new(base: WebhookDeliveryBase) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            base,
+        }
+    }
+}
+
+impl Display for WebhookDelivery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WebhookDelivery({} to webhook {}: {})",
+            self.id, self.base.webhook_id, self.base.status
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod base;
+pub mod delivery;
+mod storage; // Storable impls for Webhook and WebhookDelivery
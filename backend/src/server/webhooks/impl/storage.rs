@@ -0,0 +1,266 @@
+use chrono::{DateTime, Utc};
+use sqlx::Row;
+use sqlx::postgres::PgRow;
+use std::str::FromStr;
+use uuid::Uuid;
+
+use crate::server::{
+    shared::{
+        entities::EntityDiscriminants,
+        secrets,
+        storage::traits::{Entity, SqlValue, Storable},
+    },
+    webhooks::r#impl::{
+        base::{Webhook, WebhookBase, WebhookEventType},
+        delivery::{WebhookDelivery, WebhookDeliveryBase, WebhookDeliveryStatus},
+    },
+};
+
+impl Storable for Webhook {
+    type BaseData = WebhookBase;
+
+    fn table_name() -> &'static str {
+        "webhooks"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        let now = chrono::Utc::now();
+
+        Self {
+            id: Uuid::new_v4(),
+            created_at: now,
+            updated_at: now,
+            base,
+        }
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            updated_at,
+            base:
+                Self::BaseData {
+                    organization_id,
+                    name,
+                    url,
+                    secret,
+                    events,
+                    network_ids,
+                    enabled,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "organization_id",
+                "name",
+                "url",
+                "secret",
+                "events",
+                "network_ids",
+                "enabled",
+                "created_at",
+                "updated_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::String(name),
+                SqlValue::String(url),
+                SqlValue::String(secrets::encrypt(&secret)?),
+                SqlValue::StringArray(events.iter().map(|e| e.to_string()).collect()),
+                SqlValue::UuidArray(network_ids),
+                SqlValue::Bool(enabled),
+                SqlValue::Timestamp(created_at),
+                SqlValue::Timestamp(updated_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let events = row
+            .get::<Vec<String>, _>("events")
+            .iter()
+            .map(|e| WebhookEventType::from_str(e))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("Failed to deserialize events: {}", e))?;
+
+        Ok(Webhook {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            base: WebhookBase {
+                organization_id: row.get("organization_id"),
+                name: row.get("name"),
+                url: row.get("url"),
+                secret: secrets::decrypt(&row.get::<String, _>("secret"))?,
+                events,
+                network_ids: row.get("network_ids"),
+                enabled: row.get("enabled"),
+            },
+        })
+    }
+}
+
+impl Entity for Webhook {
+    fn entity_type() -> EntityDiscriminants {
+        EntityDiscriminants::Webhook
+    }
+
+    fn entity_name_singular() -> &'static str {
+        "webhook"
+    }
+
+    fn entity_name_plural() -> &'static str {
+        "webhooks"
+    }
+
+    fn network_id(&self) -> Option<Uuid> {
+        None
+    }
+
+    fn organization_id(&self) -> Option<Uuid> {
+        Some(self.base.organization_id)
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+
+    fn set_updated_at(&mut self, time: DateTime<Utc>) {
+        self.updated_at = time;
+    }
+}
+
+impl Storable for WebhookDelivery {
+    type BaseData = WebhookDeliveryBase;
+
+    fn table_name() -> &'static str {
+        "webhook_deliveries"
+    }
+
+    fn new(base: Self::BaseData) -> Self {
+        WebhookDelivery::new(base)
+    }
+
+    fn get_base(&self) -> Self::BaseData {
+        self.base.clone()
+    }
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    fn set_created_at(&mut self, time: DateTime<Utc>) {
+        self.created_at = time;
+    }
+
+    fn to_params(&self) -> Result<(Vec<&'static str>, Vec<SqlValue>), anyhow::Error> {
+        let Self {
+            id,
+            created_at,
+            base:
+                Self::BaseData {
+                    webhook_id,
+                    organization_id,
+                    event_id,
+                    event_type,
+                    payload,
+                    status,
+                    attempts,
+                    response_status,
+                    error,
+                    last_attempt_at,
+                    next_attempt_at,
+                },
+        } = self.clone();
+
+        Ok((
+            vec![
+                "id",
+                "webhook_id",
+                "organization_id",
+                "event_id",
+                "event_type",
+                "payload",
+                "status",
+                "attempts",
+                "response_status",
+                "error",
+                "last_attempt_at",
+                "next_attempt_at",
+                "created_at",
+            ],
+            vec![
+                SqlValue::Uuid(id),
+                SqlValue::Uuid(webhook_id),
+                SqlValue::Uuid(organization_id),
+                SqlValue::Uuid(event_id),
+                SqlValue::String(event_type.to_string()),
+                SqlValue::JsonValue(payload),
+                SqlValue::String(status.to_string()),
+                SqlValue::I32(attempts),
+                SqlValue::OptionalI32(response_status),
+                SqlValue::OptionalString(error),
+                SqlValue::OptionTimestamp(last_attempt_at),
+                SqlValue::OptionTimestamp(next_attempt_at),
+                SqlValue::Timestamp(created_at),
+            ],
+        ))
+    }
+
+    fn from_row(row: &PgRow) -> Result<Self, anyhow::Error> {
+        let event_type: String = row.get("event_type");
+        let status: String = row.get("status");
+
+        Ok(WebhookDelivery {
+            id: row.get("id"),
+            created_at: row.get("created_at"),
+            base: WebhookDeliveryBase {
+                webhook_id: row.get("webhook_id"),
+                organization_id: row.get("organization_id"),
+                event_id: row.get("event_id"),
+                event_type: WebhookEventType::from_str(&event_type)
+                    .map_err(|e| anyhow::anyhow!("Failed to deserialize event_type: {}", e))?,
+                payload: row.get("payload"),
+                status: WebhookDeliveryStatus::from_str(&status)
+                    .map_err(|e| anyhow::anyhow!("Failed to deserialize status: {}", e))?,
+                attempts: row.get("attempts"),
+                response_status: row.get("response_status"),
+                error: row.get("error"),
+                last_attempt_at: row.get("last_attempt_at"),
+                next_attempt_at: row.get("next_attempt_at"),
+            },
+        })
+    }
+}
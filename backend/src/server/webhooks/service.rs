@@ -0,0 +1,431 @@
+use anyhow::Result;
+use chrono::Utc;
+use reqwest::{
+    StatusCode,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header, redirect,
+};
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::server::{
+    organizations::r#impl::base::Organization,
+    shared::{
+        events::{bus::EventBus, types::Event},
+        services::{
+            network_organizations::NetworkOrganizations,
+            traits::{CrudService, EventBusService},
+        },
+        storage::{
+            filter::StorableFilter,
+            generic::GenericPostgresStorage,
+            traits::{PaginatedResult, Storage},
+        },
+    },
+    tags::entity_tags::EntityTagService,
+    webhooks::r#impl::{
+        base::{Webhook, WebhookEventType, check_url, is_public_address},
+        delivery::{
+            DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER, WebhookDelivery, WebhookDeliveryBase,
+            WebhookDeliveryStatus, WebhookPayload, sign,
+        },
+    },
+};
+
+/// Attempts per delivery before it's marked failed
+const MAX_ATTEMPTS: i32 = 6;
+/// Delay before the first retry, doubled after each failed attempt
+const INITIAL_RETRY_DELAY: Duration = Duration::from_secs(5);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Characters of a failed response's body kept on the delivery
+const MAX_ERROR_BODY_LENGTH: usize = 500;
+
+const ORDER_NEWEST_FIRST: &str = "webhook_deliveries.created_at DESC, webhook_deliveries.id DESC";
+
+pub struct WebhookService {
+    storage: Arc<GenericPostgresStorage<Webhook>>,
+    delivery_storage: Arc<GenericPostgresStorage<WebhookDelivery>>,
+    organization_storage: Arc<GenericPostgresStorage<Organization>>,
+    network_organizations: Arc<NetworkOrganizations>,
+    event_bus: Arc<EventBus>,
+    client: reqwest::Client,
+    /// Deliveries with a task sending them, so resuming doesn't send them twice
+    in_flight: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+/// Resolves webhook hosts, refusing any that resolve to loopback, private or link-local
+/// addresses. Checking at connect time rather than only on save stops hostnames from being
+/// pointed at internal addresses after the webhook is created.
+struct PublicOnlyResolver;
+
+impl Resolve for PublicOnlyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+
+            if addrs.iter().any(|addr| !is_public_address(&addr.ip())) {
+                return Err(format!(
+                    "{} resolves to a loopback, private or link-local address",
+                    host
+                )
+                .into());
+            }
+
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+impl EventBusService<Webhook> for WebhookService {
+    fn event_bus(&self) -> &Arc<EventBus> {
+        &self.event_bus
+    }
+
+    fn get_network_id(&self, _entity: &Webhook) -> Option<Uuid> {
+        None
+    }
+    fn get_organization_id(&self, entity: &Webhook) -> Option<Uuid> {
+        Some(entity.base.organization_id)
+    }
+}
+
+impl CrudService<Webhook> for WebhookService {
+    fn storage(&self) -> &Arc<GenericPostgresStorage<Webhook>> {
+        &self.storage
+    }
+
+    fn entity_tag_service(&self) -> Option<&Arc<EntityTagService>> {
+        None
+    }
+}
+
+impl WebhookService {
+    pub fn new(
+        storage: Arc<GenericPostgresStorage<Webhook>>,
+        delivery_storage: Arc<GenericPostgresStorage<WebhookDelivery>>,
+        organization_storage: Arc<GenericPostgresStorage<Organization>>,
+        network_organizations: Arc<NetworkOrganizations>,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .dns_resolver(Arc::new(PublicOnlyResolver))
+            // A proxy would resolve the host itself, bypassing the resolver's address check
+            .no_proxy()
+            // A redirect could send the request on to an internal address
+            .redirect(redirect::Policy::none())
+            .user_agent(format!("Scanopy-Webhook/{}", env!("CARGO_PKG_VERSION")))
+            .build()
+            // A default client would drop the address checks above, so don't fall back to one
+            .expect("Failed to create webhook HTTP client");
+
+        Self {
+            storage,
+            delivery_storage,
+            organization_storage,
+            network_organizations,
+            event_bus,
+            client,
+            in_flight: Arc::new(Mutex::new(HashSet::new())),
+        }
+    }
+
+    /// Pick up pending deliveries that nothing is sending, such as those waiting for a retry when
+    /// the server last stopped. Each is sent when its next attempt is due.
+    pub async fn resume_pending_deliveries(&self) {
+        let pending = match self
+            .delivery_storage
+            .get_all(
+                StorableFilter::<WebhookDelivery>::new()
+                    .string_column("status", &WebhookDeliveryStatus::Pending.to_string()),
+            )
+            .await
+        {
+            Ok(pending) => pending,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load pending webhook deliveries");
+                return;
+            }
+        };
+
+        let mut webhooks: HashMap<Uuid, Option<Webhook>> = HashMap::new();
+        let mut resumed = 0;
+
+        for delivery in pending {
+            if self.in_flight.lock().unwrap().contains(&delivery.id) {
+                continue;
+            }
+
+            let webhook = match webhooks.get(&delivery.base.webhook_id) {
+                Some(webhook) => webhook.clone(),
+                None => {
+                    let webhook = match self.storage.get_by_id(&delivery.base.webhook_id).await {
+                        Ok(webhook) => webhook,
+                        Err(e) => {
+                            tracing::error!(webhook_id = %delivery.base.webhook_id, error = %e, "Failed to load webhook");
+                            continue;
+                        }
+                    };
+                    webhooks.insert(delivery.base.webhook_id, webhook.clone());
+                    webhook
+                }
+            };
+
+            // Deliveries are deleted with their webhook, so this is only a race with a delete
+            let Some(webhook) = webhook else {
+                continue;
+            };
+
+            self.spawn_delivery(webhook, delivery);
+            resumed += 1;
+        }
+
+        if resumed > 0 {
+            tracing::info!(count = resumed, "Resumed pending webhook deliveries");
+        }
+    }
+
+    /// Queue a delivery of `event` to each of its organization's webhooks that want it
+    pub async fn dispatch(&self, event: &Event) -> Result<()> {
+        let Event::Entity(entity_event) = event else {
+            return Ok(());
+        };
+        let Some(event_type) = WebhookEventType::from_event(entity_event) else {
+            return Ok(());
+        };
+        let Some(organization_id) = self
+            .network_organizations
+            .resolve(entity_event.organization_id, entity_event.network_id)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let webhooks: Vec<Webhook> = self
+            .storage
+            .get_all(StorableFilter::<Webhook>::new().organization_id(&organization_id))
+            .await?
+            .into_iter()
+            .filter(|w| w.wants(event_type, event))
+            .collect();
+
+        if webhooks.is_empty() || !self.plan_includes_webhooks(organization_id).await? {
+            return Ok(());
+        }
+
+        let payload = serde_json::to_value(WebhookPayload::new(
+            event_type,
+            entity_event,
+            organization_id,
+        )?)?;
+
+        for webhook in webhooks {
+            let delivery = self
+                .delivery_storage
+                .create(&WebhookDelivery::new(WebhookDeliveryBase {
+                    webhook_id: webhook.id,
+                    organization_id,
+                    event_id: entity_event.id,
+                    event_type,
+                    payload: payload.clone(),
+                    ..Default::default()
+                }))
+                .await?;
+
+            self.spawn_delivery(webhook, delivery);
+        }
+
+        Ok(())
+    }
+
+    /// Send a past delivery's event to its webhook again, as a new delivery
+    pub async fn redeliver(
+        &self,
+        webhook: Webhook,
+        delivery: &WebhookDelivery,
+    ) -> Result<WebhookDelivery> {
+        let redelivery = self
+            .delivery_storage
+            .create(&WebhookDelivery::new(WebhookDeliveryBase {
+                webhook_id: webhook.id,
+                organization_id: webhook.base.organization_id,
+                event_id: delivery.base.event_id,
+                event_type: delivery.base.event_type,
+                payload: delivery.base.payload.clone(),
+                ..Default::default()
+            }))
+            .await?;
+
+        self.spawn_delivery(webhook, redelivery.clone());
+
+        Ok(redelivery)
+    }
+
+    pub async fn get_delivery(&self, id: &Uuid) -> Result<Option<WebhookDelivery>> {
+        self.delivery_storage.get_by_id(id).await
+    }
+
+    pub async fn get_deliveries_paginated(
+        &self,
+        filter: StorableFilter<WebhookDelivery>,
+    ) -> Result<PaginatedResult<WebhookDelivery>> {
+        self.delivery_storage
+            .get_paginated(filter, ORDER_NEWEST_FIRST)
+            .await
+    }
+
+    /// Webhooks stop firing when the organization moves to a plan without them
+    async fn plan_includes_webhooks(&self, organization_id: Uuid) -> Result<bool> {
+        Ok(self
+            .organization_storage
+            .get_by_id(&organization_id)
+            .await?
+            .is_some_and(|o| o.base.plan.unwrap_or_default().features().webhooks))
+    }
+
+    fn spawn_delivery(&self, webhook: Webhook, delivery: WebhookDelivery) {
+        if !self.in_flight.lock().unwrap().insert(delivery.id) {
+            return;
+        }
+
+        let client = self.client.clone();
+        let delivery_storage = self.delivery_storage.clone();
+        let in_flight = self.in_flight.clone();
+
+        tokio::spawn(async move {
+            let delivery_id = delivery.id;
+            deliver(client, delivery_storage, webhook, delivery).await;
+            in_flight.lock().unwrap().remove(&delivery_id);
+        });
+    }
+}
+
+/// Delay before retrying a delivery that has failed `attempts` times
+fn retry_delay(attempts: i32) -> Duration {
+    let doublings = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    std::cmp::min(INITIAL_RETRY_DELAY * 2u32.pow(doublings), MAX_RETRY_DELAY)
+}
+
+/// POST a delivery to its webhook, retrying with exponential backoff on connection errors, timeouts,
+/// 408, 429 and 5xx responses. The delivery is saved after every attempt, along with when the next
+/// one is due, so retries survive a restart.
+async fn deliver(
+    client: reqwest::Client,
+    delivery_storage: Arc<GenericPostgresStorage<WebhookDelivery>>,
+    webhook: Webhook,
+    mut delivery: WebhookDelivery,
+) {
+    let body = match serde_json::to_vec(&delivery.base.payload) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!(delivery_id = %delivery.id, error = %e, "Failed to serialize webhook payload");
+            return;
+        }
+    };
+    let signature = sign(&webhook.base.secret, &body);
+
+    // Webhooks saved before URLs were checked may still point at internal addresses
+    if let Err(e) = check_url(&webhook.base.url) {
+        delivery.base.status = WebhookDeliveryStatus::Failed;
+        delivery.base.error = Some(e.to_string());
+        delivery.base.next_attempt_at = None;
+        if let Err(e) = delivery_storage.update(&mut delivery).await {
+            tracing::error!(delivery_id = %delivery.id, error = %e, "Failed to save webhook delivery");
+        }
+        return;
+    }
+
+    loop {
+        if let Some(wait) = delivery
+            .base
+            .next_attempt_at
+            .and_then(|at| (at - Utc::now()).to_std().ok())
+        {
+            tokio::time::sleep(wait).await;
+        }
+
+        let response = client
+            .post(&webhook.base.url)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, delivery.base.event_type.to_string())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .header(SIGNATURE_HEADER, &signature)
+            .body(body.clone())
+            .send()
+            .await;
+
+        delivery.base.attempts += 1;
+        delivery.base.last_attempt_at = Some(Utc::now());
+
+        let retriable = match response {
+            Ok(response) if response.status().is_success() => {
+                delivery.base.status = WebhookDeliveryStatus::Succeeded;
+                delivery.base.response_status = Some(response.status().as_u16().into());
+                delivery.base.error = None;
+                false
+            }
+            Ok(response) => {
+                let status = response.status();
+                let text = response.text().await.unwrap_or_default();
+                delivery.base.response_status = Some(status.as_u16().into());
+                delivery.base.error = Some(format!(
+                    "HTTP {}: {}",
+                    status,
+                    text.chars().take(MAX_ERROR_BODY_LENGTH).collect::<String>()
+                ));
+                status.is_server_error()
+                    || status == StatusCode::REQUEST_TIMEOUT
+                    || status == StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(e) => {
+                delivery.base.response_status = None;
+                delivery.base.error = Some(e.to_string());
+                true
+            }
+        };
+
+        if delivery.base.status != WebhookDeliveryStatus::Succeeded
+            && (!retriable || delivery.base.attempts >= MAX_ATTEMPTS)
+        {
+            delivery.base.status = WebhookDeliveryStatus::Failed;
+            tracing::warn!(
+                webhook_id = %webhook.id,
+                delivery_id = %delivery.id,
+                attempts = delivery.base.attempts,
+                error = ?delivery.base.error,
+                "Webhook delivery failed"
+            );
+        }
+
+        delivery.base.next_attempt_at = (delivery.base.status == WebhookDeliveryStatus::Pending)
+            .then(|| Utc::now() + retry_delay(delivery.base.attempts));
+
+        if let Err(e) = delivery_storage.update(&mut delivery).await {
+            tracing::error!(delivery_id = %delivery.id, error = %e, "Failed to save webhook delivery");
+        }
+
+        if delivery.base.status != WebhookDeliveryStatus::Pending {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        assert_eq!(retry_delay(1), INITIAL_RETRY_DELAY);
+        assert_eq!(retry_delay(2), INITIAL_RETRY_DELAY * 2);
+        assert_eq!(retry_delay(3), INITIAL_RETRY_DELAY * 4);
+        assert_eq!(retry_delay(MAX_ATTEMPTS * 10), MAX_RETRY_DELAY);
+    }
+}
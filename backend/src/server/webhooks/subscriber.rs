@@ -0,0 +1,34 @@
+use anyhow::Error;
+use async_trait::async_trait;
+use strum::IntoEnumIterator;
+
+use crate::server::{
+    shared::events::{
+        bus::{EventFilter, EventSubscriber},
+        types::Event,
+    },
+    webhooks::{r#impl::base::WebhookEventType, service::WebhookService},
+};
+
+#[async_trait]
+impl EventSubscriber for WebhookService {
+    fn event_filter(&self) -> EventFilter {
+        WebhookEventType::event_filter(WebhookEventType::iter())
+    }
+
+    async fn handle_events(&self, events: Vec<Event>) -> Result<(), Error> {
+        for event in events {
+            if let Err(e) = self.dispatch(&event).await {
+                tracing::error!(event_id = %event.id(), error = %e, "Failed to queue webhook deliveries");
+            }
+        }
+
+        Ok(())
+    }
+
+    // No debounce: batches dedupe events by entity and operation, which would drop deliveries
+
+    fn name(&self) -> &str {
+        "webhooks"
+    }
+}
@@ -98,6 +98,7 @@ pub fn host(network_id: &Uuid) -> Host {
         source: EntitySource::System,
         virtualization: None,
         hidden: false,
+        device_info: None,
         tags: Vec::new(),
     })
 }
@@ -111,6 +112,8 @@ pub fn interface(network_id: &Uuid, subnet_id: &Uuid) -> Interface {
         mac_address: Some(MacAddress::new(random_mac)),
         position: 0,
         name: Some("eth0".to_string()),
+        dhcp_lease: None,
+        rtt_ms: None,
         host_id: Uuid::nil(), // Placeholder - tests will set correct host_id
     })
 }
@@ -146,6 +149,8 @@ pub fn service(network_id: &Uuid, host_id: &Uuid) -> Service {
         network_id: *network_id,
         service_definition: service_def,
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
@@ -178,6 +183,7 @@ pub fn daemon(network_id: &Uuid, host_id: &Uuid) -> Daemon {
         capabilities: DaemonCapabilities {
             has_docker_socket: false,
             interfaced_subnet_ids: Vec::new(),
+            enforces_scan_exclusions: true,
         },
         version: None,
         user_id: Uuid::nil(),
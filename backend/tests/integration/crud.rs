@@ -188,6 +188,8 @@ async fn test_service_crud(ctx: &TestContext) -> Result<(), String> {
         network_id: ctx.network_id,
         service_definition: service_def,
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
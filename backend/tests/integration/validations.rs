@@ -57,6 +57,8 @@ async fn test_service_network_validation(ctx: &TestContext) -> Result<(), String
         network_id: second_network.id, // Different network than host!
         service_definition: service_def,
         virtualization: None,
+        product: None,
+        version: None,
         source: EntitySource::System,
         tags: Vec::new(),
         position: 0,
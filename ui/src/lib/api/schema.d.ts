@@ -422,6 +422,7 @@ export interface paths {
         /**
          * Get metadata registry
          * @description Returns metadata about all entity types, service definitions, and other system metadata.
+         *     Signed-in requests also get their organization's custom service definitions.
          */
         get: operations["get_metadata_registry"];
         put?: never;
@@ -432,6 +433,51 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/audit-logs": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List audit log entries
+         * @description Returns who did what in your organization, newest first: entity changes with a diff of the
+         *     fields that changed, discovery runs started and cancelled, and logins, password changes and
+         *     API key rotations. Entries are kept for the organization's audit log retention period.
+         *     Requires admin permissions.
+         */
+        get: operations["get_audit_logs"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/audit-logs/export": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Export audit log entries
+         * @description Downloads the entries matching the same filters as listing them, newest first, as CSV or JSON
+         *     Lines. `limit` and `offset` are ignored; at most 100,000 entries are exported, so use `since`
+         *     and `until` to export larger logs in parts. Requires admin permissions.
+         */
+        get: operations["export_audit_logs"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/auth/daemon": {
         parameters: {
             query?: never;
@@ -655,6 +701,116 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/custom-service-definitions": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all custom service definitions
+         * @description Returns all custom service definitions in the authenticated user's organization.
+         *     Supports pagination via `limit` and `offset` query parameters,
+         *     and ordering via `group_by`, `order_by`, and `order_direction`.
+         *
+         *     Daemons receive every definition for their network's organization, unpaginated.
+         */
+        get: operations["get_all_custom_service_definitions"];
+        put?: never;
+        /**
+         * Create a new custom service definition
+         * @description Creates a declarative service definition scoped to your organization. Daemons pick it up
+         *     at the start of their next discovery session and match it alongside the built-in definitions.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-40 characters and unique within your organization
+         *     - Category must not be `Scanopy` or `OpenPorts`
+         *     - Pattern must contain at least one port, endpoint, header, banner or MAC vendor check that isn't negated
+         *     - Ports must be written as `<number>/<protocol>`, ie `8443/tcp`
+         *     - Endpoint paths must start with `/`; status ranges must be within 100..600
+         */
+        post: operations["create_custom_service_definition"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete custom_service_definitions */
+        post: operations["bulk_delete_custom_service_definitions"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/import": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Import a custom service definition file
+         * @description Parses a JSON, TOML or YAML definition file and creates it as a custom service definition
+         *     in your organization. The file contains the same fields as the create request body, minus
+         *     the organization.
+         *
+         *     ### Validation
+         *
+         *     - File must parse in the given format
+         *     - Same rules as creating a definition
+         */
+        post: operations["import_custom_service_definition"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/custom-service-definitions/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get custom_service_definition by ID */
+        get: operations["get_custom_service_definition_by_id"];
+        /**
+         * Update a custom service definition
+         * @description Daemons pick up the change at the start of their next discovery session.
+         *
+         *     ### Validation
+         *
+         *     - Same rules as creating a definition; the name must not be used by another definition
+         */
+        put: operations["update_custom_service_definition"];
+        post?: never;
+        /**
+         * Delete a custom service definition
+         * @description Daemons stop matching it at the start of their next discovery session.
+         */
+        delete: operations["delete_custom_service_definition"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/daemons": {
         parameters: {
             query?: never;
@@ -831,7 +987,9 @@ export interface paths {
         put?: never;
         /**
          * Receive discovery progress update from daemon
-         * @description Internal endpoint for daemons to report discovery progress.
+         * @description Internal endpoint for daemons to report discovery progress. A daemon that couldn't reach the
+         *     server replays the final update after the session's results; updates sent with
+         *     `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are applied once.
          */
         post: operations["receive_discovery_update"];
         delete?: never;
@@ -840,6 +998,70 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/entity-changes": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List entity changes
+         * @description Returns the change journal for hosts, interfaces, ports, services and bindings, newest first.
+         *     Each entry holds the entity as it was after the change (or just before, for deletions).
+         */
+        get: operations["get_entity_changes"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/entity-changes/diff": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Compare a network between two points in time
+         * @description Lists the hosts, interfaces, ports, services and bindings that were added, removed or
+         *     modified on a network between two times, or between two discovery runs. Modified entities
+         *     include the fields that changed and their state at both ends.
+         */
+        get: operations["get_network_diff"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/entity-changes/hosts/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get a host at a point in time
+         * @description Rebuilds a host and its interfaces, ports, services and bindings from the change journal,
+         *     as they were at the given time.
+         */
+        get: operations["get_host_snapshot"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/groups": {
         parameters: {
             query?: never;
@@ -911,7 +1133,8 @@ export interface paths {
          * @description Returns all hosts the authenticated user has access to, with their
          *     interfaces, ports, and services included. Supports pagination via
          *     `limit` and `offset` query parameters, and ordering via `group_by`,
-         *     `order_by`, and `order_direction`.
+         *     `order_by`, and `order_direction`. Daemons get every host on their
+         *     network, unpaginated.
          */
         get: operations["get_all_hosts"];
         put?: never;
@@ -967,6 +1190,8 @@ export interface paths {
          * Internal endpoint for daemon discovery
          * @description Used by daemons to report discovered hosts. Accepts full entities with
          *     pre-generated IDs. Uses upsert behavior to merge with existing hosts.
+         *     Results sent with `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are
+         *     applied once per session; replays return the response recorded the first time.
          *
          *     Tagged as "internal" - included in OpenAPI spec for client generation
          *     but hidden from public documentation.
@@ -1255,6 +1480,27 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/organizations/{id}/audit-log-retention": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        /**
+         * Update audit log retention
+         * @description Sets how many days audit log entries are kept for, between 1 and 3650. Older entries are
+         *     deleted periodically.
+         */
+        put: operations["update_audit_log_retention"];
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/organizations/{id}/populate-demo": {
         parameters: {
             query?: never;
@@ -1289,6 +1535,58 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/physical-links": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all physical_links */
+        get: operations["list_physical_links"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/physical-links/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete physical_links */
+        post: operations["bulk_delete_physical_links"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/physical-links/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get physical_link by ID */
+        get: operations["get_physical_link_by_id"];
+        put?: never;
+        post?: never;
+        /** Delete physical_link */
+        delete: operations["delete_physical_link"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/ports": {
         parameters: {
             query?: never;
@@ -1343,7 +1641,7 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/v1/services": {
+    "/api/v1/scan-exclusions": {
         parameters: {
             query?: never;
             header?: never;
@@ -1351,37 +1649,33 @@ export interface paths {
             cookie?: never;
         };
         /**
-         * List all services
-         * @description Returns all services the authenticated user has access to.
-         *     Supports pagination via `limit` and `offset` query parameters,
-         *     and ordering via `group_by`, `order_by`, and `order_direction`.
+         * List all scan exclusions
+         * @description Returns the organization-wide scan exclusions and those on networks you have access to.
+         *     Pass `network_id` to get only the exclusions discoveries on that network honour.
          */
-        get: operations["get_all_services"];
+        get: operations["get_all_scan_exclusions"];
         put?: never;
         /**
-         * Create a new service
-         * @description Creates a service with optional bindings to interfaces or ports.
-         *     The `id`, `created_at`, `updated_at`, and `source` fields are generated server-side.
-         *     Bindings are specified without `service_id` or `network_id` - these are assigned automatically.
+         * Create a new scan exclusion
+         * @description Creates an exclusion that every discovery in your organization honours, or only those on
+         *     `network_id` when set. Daemons that don't report the `enforces_scan_exclusions` capability
+         *     ignore exclusions, so network discovery on them is refused while any exclusion applies to
+         *     their network.
          *
-         *     ### Binding Validation Rules
+         *     ### Validation
          *
-         *     - **Cross-host validation**: All bindings must reference ports/interfaces that belong to the
-         *       service's host. Bindings referencing entities from other hosts will be rejected.
-         *     - **Deduplication**: Duplicate bindings in the same request are automatically deduplicated.
-         *     - **All-interfaces precedence**: If a port binding with `interface_id: null` (all interfaces)
-         *       is included, any specific-interface bindings for the same port are automatically removed.
-         *     - **Conflict detection**: Interface bindings conflict with port bindings on the same interface.
-         *       A port binding on all interfaces conflicts with any interface binding.
+         *     - Name must be 1-100 characters
+         *     - An OUI must be three hex octets, ie `00:1B:63`
+         *     - A tag must belong to your organization
          */
-        post: operations["create_service"];
+        post: operations["create_scan_exclusion"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/services/bulk-delete": {
+    "/api/v1/scan-exclusions/bulk-delete": {
         parameters: {
             query?: never;
             header?: never;
@@ -1390,53 +1684,237 @@ export interface paths {
         };
         get?: never;
         put?: never;
-        /** Bulk delete services */
-        post: operations["bulk_delete_services"];
+        /** Bulk delete scan_exclusions */
+        post: operations["bulk_delete_scan_exclusions"];
         delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/services/{id}": {
+    "/api/v1/scan-exclusions/resolved": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get service by ID */
-        get: operations["get_service_by_id"];
         /**
-         * Update a service
-         * @description Updates an existing service. All binding validation rules from service creation apply here as well.
-         *
-         *     ## Binding Validation Rules
-         *
-         *     - **Cross-host validation**: All bindings must reference ports/interfaces that belong to the
-         *       service's host. Bindings referencing entities from other hosts will be rejected.
-         *     - **Deduplication**: Duplicate bindings are automatically deduplicated.
-         *     - **All-interfaces precedence**: If a port binding with `interface_id: null` (all interfaces)
-         *       is included, any specific-interface bindings for the same port are automatically removed.
-         *     - **Conflict detection**: Interface bindings conflict with port bindings on the same interface.
+         * Get resolved scan exclusions
+         * @description Internal endpoint for daemons scanning outside a discovery session, ie deep scans of hosts
+         *     passive discovery sees. Returns the exclusions on the daemon's network with tag rules
+         *     resolved to addresses, and MAC and OUI rules also resolved to known matching addresses.
          */
-        put: operations["update_service"];
+        get: operations["get_resolved_scan_exclusions"];
+        put?: never;
         post?: never;
-        /** Delete service */
-        delete: operations["delete_service"];
+        delete?: never;
         options?: never;
         head?: never;
         patch?: never;
         trace?: never;
     };
-    "/api/v1/shares": {
+    "/api/v1/scan-exclusions/{id}": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** List all shares */
+        /** Get scan_exclusion by ID */
+        get: operations["get_scan_exclusion_by_id"];
+        /**
+         * Update scan exclusion
+         * @description Same validation rules as creating a scan exclusion.
+         */
+        put: operations["update_scan_exclusion"];
+        post?: never;
+        /** Delete scan_exclusion */
+        delete: operations["delete_scan_exclusion"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/scan-profiles": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all scan profiles
+         * @description Returns all scan profiles in the authenticated user's organization.
+         *     Supports pagination via `limit` and `offset` query parameters,
+         *     and ordering via `group_by`, `order_by`, and `order_direction`.
+         */
+        get: operations["get_all_scan_profiles"];
+        put?: never;
+        /**
+         * Create a new scan profile
+         * @description Creates a scan profile in your organization that network discoveries can refer to.
+         *     Settings left out take the values discoveries without a profile use.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-100 characters
+         *     - `connect_timeout_ms` must be within 50..=30000 and `retries` at most 5
+         *     - `max_concurrency` must be within 1..=1000 when set
+         *     - A top port count must be at least 1, and a port list must not be empty or contain port 0
+         */
+        post: operations["create_scan_profile"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/scan-profiles/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Bulk delete scan profiles
+         * @description Nothing is deleted if any of the profiles is used by a network discovery.
+         */
+        post: operations["bulk_delete_scan_profiles"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/scan-profiles/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get scan profile by ID
+         * @description Daemons fetch the profile of the network discovery they are running, as long as it belongs
+         *     to their network's organization.
+         */
+        get: operations["get_scan_profile_by_id"];
+        /** Update scan_profile */
+        put: operations["update_scan_profile"];
+        post?: never;
+        /**
+         * Delete a scan profile
+         * @description Profiles that a scheduled or ad-hoc network discovery refers to can't be deleted; point
+         *     those discoveries at another profile first.
+         */
+        delete: operations["delete_scan_profile"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/services": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all services
+         * @description Returns all services the authenticated user has access to.
+         *     Supports pagination via `limit` and `offset` query parameters,
+         *     and ordering via `group_by`, `order_by`, and `order_direction`.
+         */
+        get: operations["get_all_services"];
+        put?: never;
+        /**
+         * Create a new service
+         * @description Creates a service with optional bindings to interfaces or ports.
+         *     The `id`, `created_at`, `updated_at`, and `source` fields are generated server-side.
+         *     Bindings are specified without `service_id` or `network_id` - these are assigned automatically.
+         *
+         *     ### Binding Validation Rules
+         *
+         *     - **Cross-host validation**: All bindings must reference ports/interfaces that belong to the
+         *       service's host. Bindings referencing entities from other hosts will be rejected.
+         *     - **Deduplication**: Duplicate bindings in the same request are automatically deduplicated.
+         *     - **All-interfaces precedence**: If a port binding with `interface_id: null` (all interfaces)
+         *       is included, any specific-interface bindings for the same port are automatically removed.
+         *     - **Conflict detection**: Interface bindings conflict with port bindings on the same interface.
+         *       A port binding on all interfaces conflicts with any interface binding.
+         *
+         *     Daemons may also create services on their own network. Their requests sent with
+         *     `X-Discovery-Session-ID` and `X-Discovery-Result-ID` headers are applied once per session;
+         *     replays return the service created the first time.
+         */
+        post: operations["create_service"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/services/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete services */
+        post: operations["bulk_delete_services"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/services/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get service by ID */
+        get: operations["get_service_by_id"];
+        /**
+         * Update a service
+         * @description Updates an existing service. All binding validation rules from service creation apply here as well.
+         *
+         *     ## Binding Validation Rules
+         *
+         *     - **Cross-host validation**: All bindings must reference ports/interfaces that belong to the
+         *       service's host. Bindings referencing entities from other hosts will be rejected.
+         *     - **Deduplication**: Duplicate bindings are automatically deduplicated.
+         *     - **All-interfaces precedence**: If a port binding with `interface_id: null` (all interfaces)
+         *       is included, any specific-interface bindings for the same port are automatically removed.
+         *     - **Conflict detection**: Interface bindings conflict with port bindings on the same interface.
+         */
+        put: operations["update_service"];
+        post?: never;
+        /** Delete service */
+        delete: operations["delete_service"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/shares": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all shares */
         get: operations["list_shares"];
         put?: never;
         /** Create a new share */
@@ -1520,6 +1998,85 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/snmp-credentials": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List all SNMP credentials
+         * @description Returns the SNMP credentials for the networks you have access to, with their communities and
+         *     passwords blanked. Requires admin permissions.
+         *
+         *     Daemons receive every credential for their network in creation order, unpaginated, with
+         *     their secrets.
+         */
+        get: operations["get_all_snmp_credentials"];
+        put?: never;
+        /**
+         * Create a new SNMP credential
+         * @description Daemons on the credential's network use it from their next discovery session on.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-100 characters
+         *     - v2c communities and v3 usernames must not be empty
+         *     - v3 privacy requires authentication
+         *     - v3 authentication and privacy passwords must be at least 8 characters
+         *     - The server must have a secret key configured to encrypt the credential with
+         */
+        post: operations["create_snmp_credential"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/snmp-credentials/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete snmp_credentials */
+        post: operations["bulk_delete_snmp_credentials"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/snmp-credentials/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get SNMP credential by ID
+         * @description The community or passwords are blanked in the response. Requires admin permissions.
+         */
+        get: operations["get_snmp_credential_by_id"];
+        /**
+         * Update SNMP credential
+         * @description Same validation rules as creating a credential. Leave the community or a password empty to
+         *     keep the current one.
+         */
+        put: operations["update_snmp_credential"];
+        post?: never;
+        /** Delete snmp_credential */
+        delete: operations["delete_snmp_credential"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/subnets": {
         parameters: {
             query?: never;
@@ -1727,6 +2284,58 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
+    "/api/v1/tls-certificates": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** List all tls_certificates */
+        get: operations["list_tls_certificates"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/tls-certificates/bulk-delete": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /** Bulk delete tls_certificates */
+        post: operations["bulk_delete_tls_certificates"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/tls-certificates/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get tls_certificate by ID */
+        get: operations["get_tls_certificate_by_id"];
+        put?: never;
+        post?: never;
+        /** Delete tls_certificate */
+        delete: operations["delete_tls_certificate"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
     "/api/v1/topology": {
         parameters: {
             query?: never;
@@ -1901,18 +2510,125 @@ export interface paths {
         patch?: never;
         trace?: never;
     };
-    "/api/version": {
+    "/api/v1/webhooks": {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** Get API version information */
-        get: operations["get_version"];
+        /**
+         * List all webhooks
+         * @description Returns the webhooks in your organization. Secrets are blanked; they're only returned when a
+         *     webhook is created. Requires admin permissions.
+         */
+        get: operations["get_all_webhooks"];
         put?: never;
-        post?: never;
-        delete?: never;
+        /**
+         * Create a new webhook
+         * @description Each event the webhook subscribes to is POSTed to its URL as JSON, with the event name in
+         *     the `X-Scanopy-Event` header and the hex HMAC-SHA256 of the body, keyed with the secret, in
+         *     the `X-Scanopy-Signature-256` header as `sha256=<hex>`. Failed deliveries are retried with
+         *     exponential backoff, up to 6 attempts. This response is the only one that includes the
+         *     secret.
+         *
+         *     ### Validation
+         *
+         *     - Name must be 1-100 characters
+         *     - URL must be an absolute http or https URL
+         *     - At least one event is required
+         *     - Secret must be at least 16 characters. Leave it empty to have one generated.
+         *     - The server must have a secret key configured to encrypt the secret with
+         */
+        post: operations["create_webhook"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * Get webhook by ID
+         * @description The secret is blanked in the response. Requires admin permissions.
+         */
+        get: operations["get_webhook_by_id"];
+        /**
+         * Update webhook
+         * @description Same validation rules as creating a webhook. Leave the secret empty to keep the current one.
+         */
+        put: operations["update_webhook"];
+        post?: never;
+        /**
+         * Delete webhook
+         * @description Deletes the webhook and its delivery log. Deliveries being retried are abandoned.
+         */
+        delete: operations["delete_webhook"];
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}/deliveries": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /**
+         * List webhook deliveries
+         * @description Returns the deliveries made to a webhook, newest first, with the payload sent and the outcome
+         *     of the last attempt.
+         */
+        get: operations["get_webhook_deliveries"];
+        put?: never;
+        post?: never;
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/v1/webhooks/{id}/deliveries/{delivery_id}/redeliver": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        get?: never;
+        put?: never;
+        /**
+         * Redeliver a webhook delivery
+         * @description Sends the payload of a past delivery to the webhook again, as a new delivery with its own
+         *     retries. The payload keeps its original event ID, so receivers can deduplicate.
+         */
+        post: operations["redeliver_webhook_delivery"];
+        delete?: never;
+        options?: never;
+        head?: never;
+        patch?: never;
+        trace?: never;
+    };
+    "/api/version": {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** Get API version information */
+        get: operations["get_version"];
+        put?: never;
+        post?: never;
+        delete?: never;
         options?: never;
         head?: never;
         patch?: never;
@@ -1939,7 +2655,7 @@ export interface components {
          * @description API metadata included in all responses
          * @example {
          *       "api_version": 1,
-         *       "server_version": "0.13.5"
+         *       "server_version": "0.13.6"
          *     }
          */
         ApiMeta: {
@@ -1950,7 +2666,7 @@ export interface components {
             api_version: number;
             /**
              * @description Server version (semver)
-             * @example 0.13.5
+             * @example 0.13.6
              */
             server_version: string;
         };
@@ -1964,14 +2680,14 @@ export interface components {
             /**
              * @description Association between a service and a port / interface that the service is listening on
              * @example {
-             *       "created_at": "2026-01-13T22:33:31.255874Z",
-             *       "id": "0af52bdb-4f50-4c41-a558-3f62c024af16",
+             *       "created_at": "2026-10-18T19:41:53.473026265Z",
+             *       "id": "02e0f91a-36d8-4967-8712-30c0ffd0b0af",
              *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
              *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
              *       "service_id": "550e8400-e29b-41d4-a716-446655440007",
              *       "type": "Port",
-             *       "updated_at": "2026-01-13T22:33:31.255874Z"
+             *       "updated_at": "2026-10-18T19:41:53.473026265Z"
              *     }
              */
             data?: components["schemas"]["BindingBase"] & {
@@ -2005,6 +2721,48 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_CustomServiceDefinition: {
+            /**
+             * @example {
+             *       "category": "Monitoring",
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "description": "Acme appliance management console",
+             *       "id": "550e8400-e29b-41d4-a716-44665544000f",
+             *       "is_generic": false,
+             *       "logo_needs_white_background": false,
+             *       "logo_url": "",
+             *       "name": "Acme Console",
+             *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+             *       "pattern": {
+             *         "patterns": [
+             *           {
+             *             "port": "8443/tcp",
+             *             "type": "port"
+             *           },
+             *           {
+             *             "contains": "Acme",
+             *             "path": "/login",
+             *             "port": "8443/tcp",
+             *             "type": "endpoint"
+             *           }
+             *         ],
+             *         "type": "all_of"
+             *       },
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["CustomServiceDefinitionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_DaemonApiKey: {
             data?: components["schemas"]["DaemonApiKeyBase"] & {
                 /** Format: date-time */
@@ -2084,6 +2842,11 @@ export interface components {
                 daemon_id: string;
                 discovery_type: components["schemas"]["DiscoveryType"];
                 error?: string | null;
+                /**
+                 * @description Hosts the daemon has to leave alone, resolved when the session is queued so pull mode
+                 *     daemons get them with the session. Updates from the daemon leave it empty.
+                 */
+                exclusions?: components["schemas"]["ScanExclusions"];
                 /** Format: date-time */
                 finished_at?: string | null;
                 /** Format: uuid */
@@ -2091,6 +2854,8 @@ export interface components {
                 phase: components["schemas"]["DiscoveryPhase"];
                 /** Format: int32 */
                 progress: number;
+                /** @description Scan parameters the daemon adapted to for each subnet, reported when a session ends */
+                scan_tuning?: components["schemas"]["SubnetScanTuning"][];
                 /** Format: uuid */
                 session_id: string;
                 /** Format: date-time */
@@ -2138,12 +2903,14 @@ export interface components {
              * @example {
              *       "created_at": "2026-01-15T10:30:00Z",
              *       "description": "Primary web server",
+             *       "device_info": null,
              *       "hidden": false,
              *       "hostname": "web-server-01.local",
              *       "id": "550e8400-e29b-41d4-a716-446655440003",
              *       "interfaces": [
              *         {
              *           "created_at": "2026-01-15T10:30:00Z",
+             *           "dhcp_lease": null,
              *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
              *           "id": "550e8400-e29b-41d4-a716-446655440005",
              *           "ip_address": "192.168.1.100",
@@ -2151,6 +2918,7 @@ export interface components {
              *           "name": "eth0",
              *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *           "position": 0,
+             *           "rtt_ms": null,
              *           "subnet_id": "550e8400-e29b-41d4-a716-446655440004",
              *           "updated_at": "2026-01-15T10:30:00Z"
              *         }
@@ -2182,6 +2950,7 @@ export interface components {
                 /** Format: date-time */
                 created_at: string;
                 description?: string | null;
+                device_info?: null | components["schemas"]["DeviceInfo"];
                 hidden: boolean;
                 hostname?: string | null;
                 /** Format: uuid */
@@ -2202,10 +2971,28 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_HostSnapshot: {
+            /** @description A host and its children as they were at a point in time */
+            data?: {
+                /** Format: date-time */
+                at: string;
+                bindings: Record<string, never>[];
+                host: Record<string, never>;
+                /** Format: uuid */
+                host_id: string;
+                interfaces: Record<string, never>[];
+                ports: Record<string, never>[];
+                services: Record<string, never>[];
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Interface: {
             /**
              * @example {
              *       "created_at": "2026-01-15T10:30:00Z",
+             *       "dhcp_lease": null,
              *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
              *       "id": "550e8400-e29b-41d4-a716-446655440005",
              *       "ip_address": "192.168.1.100",
@@ -2213,6 +3000,7 @@ export interface components {
              *       "name": "eth0",
              *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *       "position": 0,
+             *       "rtt_ms": null,
              *       "subnet_id": "550e8400-e29b-41d4-a716-446655440004",
              *       "updated_at": "2026-01-15T10:30:00Z"
              *     }
@@ -2283,6 +3071,21 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_NetworkChangeDiff: {
+            /** @description Everything that differs on a network between two points in time */
+            data?: {
+                changes: components["schemas"]["EntityDiff"][];
+                /** Format: date-time */
+                from: string;
+                /** Format: uuid */
+                network_id: string;
+                /** Format: date-time */
+                to: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Organization: {
             data?: components["schemas"]["OrganizationBase"] & {
                 /** Format: date-time */
@@ -2296,6 +3099,38 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_PhysicalLink: {
+            /**
+             * @description A cable between a port on a managed device and a neighbor, as seen by the managed device.
+             *     The neighbor is identified by what the device knows about it; the topology matches that to
+             *     a host when it's built.
+             * @example {
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
+             *       "id": "550e8400-e29b-41d4-a716-446655440012",
+             *       "local_if_index": 10112,
+             *       "local_port": "Gi1/0/12",
+             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "protocol": "Lldp",
+             *       "remote_ip_address": "192.168.1.2",
+             *       "remote_mac_address": "00:1A:2B:3C:4D:5E",
+             *       "remote_port": "Gi0/24",
+             *       "remote_system_name": "access-switch-2",
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["PhysicalLinkBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Port: {
             /**
              * @description Port entity with custom serialization that flattens PortType fields.
@@ -2355,6 +3190,84 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_ScanExclusion: {
+            /**
+             * @description Hosts that discovery must never probe, ie a PLC or a printer that reboots when port scanned
+             * @example {
+             *       "arp_only": true,
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "description": "Reboot when port scanned",
+             *       "id": "550e8400-e29b-41d4-a716-446655440015",
+             *       "name": "Label printers",
+             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+             *       "rule": {
+             *         "oui": "00:07:4D",
+             *         "type": "Oui"
+             *       },
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["ScanExclusionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ScanExclusions: {
+            /** @description The exclusions a discovery session honours, handed to the daemon with the session */
+            data?: components["schemas"]["ScanExclusionTarget"][];
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_ScanProfile: {
+            /**
+             * @description Reusable scan settings that network discoveries across an organization can refer to, ie a
+             *     quick sweep of common ports and a full weekly scan of the same subnets
+             * @example {
+             *       "connect_timeout_ms": 500,
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "description": "Common ports only, for hourly runs",
+             *       "id": "550e8400-e29b-41d4-a716-446655440014",
+             *       "max_concurrency": 100,
+             *       "name": "Quick sweep",
+             *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+             *       "ping_ports": [
+             *         22,
+             *         443
+             *       ],
+             *       "probe_endpoints": true,
+             *       "retries": 0,
+             *       "tcp_ports": {
+             *         "count": 1000,
+             *         "type": "Top"
+             *       },
+             *       "udp_probes": [
+             *         "Dns",
+             *         "Snmp"
+             *       ],
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["ScanProfileBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_ServerCapabilities: {
             /** @description Server capabilities returned on startup/registration */
             data?: {
@@ -2374,14 +3287,14 @@ export interface components {
              * @example {
              *       "bindings": [
              *         {
-             *           "created_at": "2026-01-13T22:33:31.251337Z",
-             *           "id": "2adaa4fc-d1a9-4b4b-ae39-c59e23107fab",
+             *           "created_at": "2026-10-18T19:41:53.464198141Z",
+             *           "id": "01d1a6d4-4cda-4b5a-a34a-c175fd78c146",
              *           "interface_id": "550e8400-e29b-41d4-a716-446655440005",
              *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *           "port_id": "550e8400-e29b-41d4-a716-446655440006",
              *           "service_id": "550e8400-e29b-41d4-a716-446655440007",
              *           "type": "Port",
-             *           "updated_at": "2026-01-13T22:33:31.251337Z"
+             *           "updated_at": "2026-10-18T19:41:53.464198141Z"
              *         }
              *       ],
              *       "created_at": "2026-01-15T10:30:00Z",
@@ -2390,12 +3303,14 @@ export interface components {
              *       "name": "nginx",
              *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
              *       "position": 0,
-             *       "service_definition": "Veeam",
+             *       "product": null,
+             *       "service_definition": "Mealie",
              *       "source": {
              *         "type": "Manual"
              *       },
              *       "tags": [],
              *       "updated_at": "2026-01-15T10:30:00Z",
+             *       "version": null,
              *       "virtualization": null
              *     }
              */
@@ -2433,6 +3348,45 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_SnmpCredential: {
+            /**
+             * @description Credentials daemons on a network use to query devices over SNMP. Daemons try each of the
+             *     network's credentials in creation order and use the first one a device answers to.
+             *
+             *     Communities and passwords are encrypted at rest and write-only: they're blank in responses
+             *     to users, and left blank in an update to keep the current ones.
+             * @example {
+             *       "config": {
+             *         "auth": {
+             *           "password": "auth-password",
+             *           "protocol": "Sha256"
+             *         },
+             *         "privacy": {
+             *           "password": "privacy-password",
+             *           "protocol": "Aes128"
+             *         },
+             *         "username": "scanopy",
+             *         "version": "V3"
+             *       },
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "id": "550e8400-e29b-41d4-a716-446655440011",
+             *       "name": "Core switches",
+             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["SnmpCredentialBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_String: {
             data?: string;
             error?: string | null;
@@ -2492,6 +3446,43 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_TlsCertificate: {
+            /**
+             * @description Leaf certificate presented by a TLS-capable port
+             * @example {
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "fingerprint_sha256": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+             *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
+             *       "id": "550e8400-e29b-41d4-a716-446655440010",
+             *       "is_self_signed": true,
+             *       "issuer": "CN=nas.example.com",
+             *       "key_bits": 256,
+             *       "key_type": "Ec",
+             *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+             *       "not_after": "2027-01-15T10:30:00Z",
+             *       "not_before": "2026-01-15T10:30:00Z",
+             *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
+             *       "serial_number": "4a:0b:6e:1f:92:3c:d5:07",
+             *       "subject": "CN=nas.example.com",
+             *       "subject_alt_names": [
+             *         "nas.example.com",
+             *         "192.168.1.100"
+             *       ],
+             *       "updated_at": "2026-01-15T10:30:00Z"
+             *     }
+             */
+            data?: components["schemas"]["TlsCertificateBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_Topology: {
             data?: components["schemas"]["TopologyBase"] & {
                 /** Format: date-time */
@@ -2581,6 +3572,11 @@ export interface components {
                 daemon_id: string;
                 discovery_type: components["schemas"]["DiscoveryType"];
                 error?: string | null;
+                /**
+                 * @description Hosts the daemon has to leave alone, resolved when the session is queued so pull mode
+                 *     daemons get them with the session. Updates from the daemon leave it empty.
+                 */
+                exclusions?: components["schemas"]["ScanExclusions"];
                 /** Format: date-time */
                 finished_at?: string | null;
                 /** Format: uuid */
@@ -2588,6 +3584,8 @@ export interface components {
                 phase: components["schemas"]["DiscoveryPhase"];
                 /** Format: int32 */
                 progress: number;
+                /** @description Scan parameters the daemon adapted to for each subnet, reported when a session ends */
+                scan_tuning?: components["schemas"]["SubnetScanTuning"][];
                 /** Format: uuid */
                 session_id: string;
                 /** Format: date-time */
@@ -2630,6 +3628,51 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
+        ApiResponse_Webhook: {
+            /**
+             * @description An endpoint that is sent signed HTTP requests when hosts, services or discovery runs in the
+             *     organization change.
+             * @example {
+             *       "created_at": "2026-01-15T10:30:00Z",
+             *       "enabled": true,
+             *       "events": [
+             *         "host_created",
+             *         "service_deleted",
+             *         "discovery_failed"
+             *       ],
+             *       "id": "550e8400-e29b-41d4-a716-446655440013",
+             *       "name": "New device alerts",
+             *       "network_ids": [],
+             *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+             *       "secret": "whsec_0123456789abcdefghijklmnopqrstuv",
+             *       "updated_at": "2026-01-15T10:30:00Z",
+             *       "url": "https://hooks.example.com/scanopy"
+             *     }
+             */
+            data?: components["schemas"]["WebhookBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
+        ApiResponse_WebhookDelivery: {
+            /** @description One attempt to get an event to a webhook, retried until it succeeds or runs out of attempts */
+            data?: components["schemas"]["WebhookDeliveryBase"] & {
+                /** Format: date-time */
+                created_at: string;
+                /** Format: uuid */
+                id: string;
+            };
+            error?: string | null;
+            meta: components["schemas"]["ApiMeta"];
+            success: boolean;
+        };
         ApiResponse_bool: {
             data?: boolean;
             error?: string | null;
@@ -2643,15 +3686,71 @@ export interface components {
             meta: components["schemas"]["ApiMeta"];
             success: boolean;
         };
-        BillingPlan: (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Community";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Starter";
-        }) | (components["schemas"]["PlanConfig"] & {
-            /** @enum {string} */
-            type: "Pro";
+        /**
+         * @description Who performed the action
+         * @enum {string}
+         */
+        AuditActorType: "user" | "api_key" | "daemon" | "external_service" | "system" | "anonymous";
+        /**
+         * @description Kind of event an entry was recorded from
+         * @enum {string}
+         */
+        AuditLogCategory: "entity" | "auth";
+        /** @description One action in an organization's audit log. `created_at` is when the action was performed. */
+        AuditLogEntry: components["schemas"]["AuditLogEntryBase"] & {
+            /** Format: date-time */
+            created_at: string;
+            /** Format: uuid */
+            id: string;
+        };
+        /** @description The base data for an AuditLogEntry record (everything except id and created_at) */
+        AuditLogEntryBase: {
+            /**
+             * Format: uuid
+             * @description ID of the user, API key or daemon that performed the action
+             */
+            actor_id?: string | null;
+            /** @description Email of the user, or name of the external service */
+            actor_name?: string | null;
+            actor_type: components["schemas"]["AuditActorType"];
+            /**
+             * Format: uuid
+             * @description The user behind the action: the user themselves, or the owner of the API key
+             */
+            actor_user_id?: string | null;
+            category: components["schemas"]["AuditLogCategory"];
+            /**
+             * @description For updates, the top-level fields that changed, as `{"field": {"before": .., "after": ..}}`.
+             *     Empty when there's no earlier entry for the entity to compare against.
+             */
+            changes?: Record<string, never> | null;
+            /** Format: uuid */
+            entity_id?: string | null;
+            entity_type?: null | components["schemas"]["EntityDiscriminants"];
+            ip_address?: string | null;
+            metadata: Record<string, never>;
+            /** Format: uuid */
+            network_id?: string | null;
+            /** @description Entity operation (ie `created`) or auth operation (ie `login_success`) */
+            operation: string;
+            /** Format: uuid */
+            organization_id: string;
+            /**
+             * @description The entity after the change. For deletions, as it was just before. Secrets are replaced
+             *     with a fingerprint.
+             */
+            snapshot?: Record<string, never> | null;
+            user_agent?: string | null;
+        };
+        BillingPlan: (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Community";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Starter";
+        }) | (components["schemas"]["PlanConfig"] & {
+            /** @enum {string} */
+            type: "Pro";
         }) | (components["schemas"]["PlanConfig"] & {
             /** @enum {string} */
             type: "Team";
@@ -2673,14 +3772,14 @@ export interface components {
         /**
          * @description Association between a service and a port / interface that the service is listening on
          * @example {
-         *       "created_at": "2026-01-13T22:33:31.241489Z",
-         *       "id": "e95ed47c-ed9d-4252-a7a3-e47f27d1c8fc",
+         *       "created_at": "2026-10-18T19:41:53.448384101Z",
+         *       "id": "abde8e1c-a669-40e2-9ee9-e2c188fbb49e",
          *       "interface_id": "550e8400-e29b-41d4-a716-446655440005",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
          *       "service_id": "550e8400-e29b-41d4-a716-446655440007",
          *       "type": "Port",
-         *       "updated_at": "2026-01-13T22:33:31.241489Z"
+         *       "updated_at": "2026-10-18T19:41:53.448384101Z"
          *     }
          */
         Binding: components["schemas"]["BindingBase"] & {
@@ -2780,6 +3879,16 @@ export interface components {
             /** @description Number of entities affected */
             affected_count: number;
         };
+        /**
+         * @description Public key algorithm of a certificate
+         * @enum {string}
+         */
+        CertificateKeyType: "Rsa" | "Ec" | "Ed25519" | "Ed448" | "Dsa" | "Unknown";
+        /**
+         * @description What happened to the entity
+         * @enum {string}
+         */
+        ChangeOperation: "Created" | "Updated" | "Deleted";
         /** @enum {string} */
         Color: "Pink" | "Rose" | "Red" | "Orange" | "Green" | "Emerald" | "Teal" | "Cyan" | "Blue" | "Indigo" | "Purple" | "Gray" | "Yellow";
         /**
@@ -2844,7 +3953,7 @@ export interface components {
          *           "id": "550e8400-e29b-41d4-a716-446655440007",
          *           "name": "nginx",
          *           "position": 0,
-         *           "service_definition": "Veeam",
+         *           "service_definition": "Mealie",
          *           "tags": [],
          *           "virtualization": null
          *         }
@@ -2900,6 +4009,52 @@ export interface components {
             password?: string | null;
             share: components["schemas"]["Share"];
         };
+        /**
+         * @example {
+         *       "category": "Monitoring",
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "description": "Acme appliance management console",
+         *       "id": "550e8400-e29b-41d4-a716-44665544000f",
+         *       "is_generic": false,
+         *       "logo_needs_white_background": false,
+         *       "logo_url": "",
+         *       "name": "Acme Console",
+         *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+         *       "pattern": {
+         *         "patterns": [
+         *           {
+         *             "port": "8443/tcp",
+         *             "type": "port"
+         *           },
+         *           {
+         *             "contains": "Acme",
+         *             "path": "/login",
+         *             "port": "8443/tcp",
+         *             "type": "endpoint"
+         *           }
+         *         ],
+         *         "type": "all_of"
+         *       },
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        CustomServiceDefinition: components["schemas"]["CustomServiceDefinitionBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        CustomServiceDefinitionBase: components["schemas"]["ServiceDefinitionSpec"] & {
+            /** Format: uuid */
+            organization_id: string;
+        };
+        /**
+         * @description Fields that custom service definitions can be ordered/grouped by.
+         * @enum {string}
+         */
+        CustomServiceDefinitionOrderField: "created_at" | "name" | "category" | "updated_at";
         Daemon: components["schemas"]["DaemonBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -2954,6 +4109,11 @@ export interface components {
         };
         /** @description Daemon capabilities */
         DaemonCapabilities: {
+            /**
+             * @description Whether the daemon skips the hosts a session's scan exclusions match. Daemons that
+             *     predate exclusions don't report it, and scan every host.
+             */
+            enforces_scan_exclusions: boolean;
             has_docker_socket?: boolean;
             interfaced_subnet_ids: string[];
         };
@@ -3028,6 +4188,11 @@ export interface components {
             version?: string | null;
             warnings?: components["schemas"]["DeprecationWarning"][];
         };
+        /**
+         * @description Supported encodings for definition files
+         * @enum {string}
+         */
+        DefinitionFormat: "json" | "toml" | "yaml";
         /** @enum {string} */
         DeploymentType: "cloud" | "commercial" | "community";
         /**
@@ -3041,6 +4206,78 @@ export interface components {
             severity: components["schemas"]["DeprecationSeverity"];
             sunset_date?: string | null;
         };
+        /** @description Hardware details a device reported about itself over SNMP or in its UPnP device description */
+        DeviceInfo: {
+            /** Format: date-time */
+            collected_at: string;
+            /** @description Name the owner gave the device, from the UPnP device description */
+            friendly_name?: string | null;
+            /** @description Interfaces listed in the device's IF-MIB, ordered by ifIndex */
+            interfaces?: components["schemas"]["DeviceInterface"][];
+            /** @description Manufacturer, from the UPnP device description */
+            manufacturer?: string | null;
+            /** @description Model name of the chassis from ENTITY-MIB, or of the UPnP device */
+            model?: string | null;
+            /** @description Serial number of the chassis from ENTITY-MIB, or of the UPnP device */
+            serial_number?: string | null;
+            /** @description Full sysDescr; the host description holds a truncated copy */
+            system_description?: string | null;
+            /** @description sysObjectID, which identifies the vendor and product line */
+            system_object_id?: string | null;
+            /**
+             * Format: int64
+             * @description Time since the SNMP agent last restarted, as of `collected_at`
+             */
+            uptime_seconds?: number | null;
+        };
+        /** @description An entry of the device's IF-MIB interface table */
+        DeviceInterface: {
+            /** @description ifAlias, the description an administrator gave the interface */
+            alias?: string | null;
+            /**
+             * Format: int32
+             * @description ifIndex, which other MIBs use to refer to the interface
+             */
+            index: number;
+            mac_address?: string | null;
+            /** @description ifName, falling back to ifDescr when the device doesn't implement IF-MIB ifXTable */
+            name?: string | null;
+        };
+        /** @description What a DHCP server's lease database says about the address on an interface */
+        DhcpLease: {
+            /** @description Name the client gave when it requested the lease */
+            client_hostname?: string | null;
+            /**
+             * Format: date-time
+             * @description When the lease database was read
+             */
+            collected_at: string;
+            /**
+             * Format: date-time
+             * @description When the lease runs out; none for infinite leases and reservations
+             */
+            ends?: string | null;
+            /**
+             * @description The address is reserved for this client in the server's config rather than leased from a
+             *     pool
+             */
+            reserved: boolean;
+            /**
+             * Format: date-time
+             * @description When the lease was granted or last renewed, if the server records it
+             */
+            starts?: string | null;
+        };
+        /**
+         * @description Lease file formats understood by DHCP lease discovery
+         * @enum {string}
+         */
+        DhcpLeaseFormat: "IscDhcpd" | "Dnsmasq" | "Kea" | "Json";
+        /**
+         * @description How an entity differs between the two ends of a diff
+         * @enum {string}
+         */
+        DiffKind: "Added" | "Removed" | "Modified";
         Discovery: components["schemas"]["DiscoveryBase"] & {
             /** Format: date-time */
             readonly created_at: string;
@@ -3065,8 +4302,15 @@ export interface components {
          *     This supports the discovery workflow where daemons manage entity IDs.
          */
         DiscoveryHostRequest: {
+            /** @description Leaf certificates presented by the host's TLS-capable ports, keyed to `ports` by port_id */
+            certificates?: components["schemas"]["TlsCertificate"][];
             host: components["schemas"]["Host"];
             interfaces: components["schemas"]["Interface"][];
+            /**
+             * @description Cabling the host reported from its LLDP/CDP neighbor and forwarding tables. None if the
+             *     host wasn't queried, which leaves previously reported links in place.
+             */
+            physical_links?: components["schemas"]["PhysicalLink"][] | null;
             ports: components["schemas"]["Port"][];
             services: components["schemas"]["Service"][];
         };
@@ -3085,15 +4329,77 @@ export interface components {
             type: "SelfReport";
         } | {
             host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * Format: uuid
+             * @description Scan profile to scan hosts with; the default settings when not set
+             */
+            scan_profile_id: string | null;
             subnet_ids: string[] | null;
             /** @enum {string} */
             type: "Network";
         } | {
+            endpoint: null | components["schemas"]["DockerEndpoint"];
             /** Format: uuid */
             host_id: string;
             host_naming_fallback: components["schemas"]["HostNamingFallback"];
             /** @enum {string} */
             type: "Docker";
+        } | {
+            /** @enum {string} */
+            type: "Passive";
+        } | {
+            format: components["schemas"]["DhcpLeaseFormat"];
+            host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * @description Lease file to import. Must be inside the directory the daemon was started with
+             *     `--dhcp-lease-dir`; relative paths are taken from that directory.
+             */
+            path: string;
+            /** @enum {string} */
+            type: "DhcpLeases";
+        } | {
+            /** @description Also trace to every remote subnet on the network */
+            include_remote_subnets: boolean;
+            /**
+             * Format: int32
+             * @description Hops to trace before giving up on a target, at most 64
+             */
+            max_hops: number;
+            protocol: components["schemas"]["TracerouteProtocol"];
+            /** @description Addresses to trace the route to */
+            targets: string[];
+            /** @enum {string} */
+            type: "Traceroute";
+        } | {
+            /** @description Kubeconfig context to use; the current context when not set */
+            context: string | null;
+            host_naming_fallback: components["schemas"]["HostNamingFallback"];
+            /**
+             * @description Kubeconfig file in the directory the daemon was started with as `--kubeconfig-dir`.
+             *     Relative paths are taken from that directory. When not set, the daemon's service account
+             *     is used if it runs in the cluster, and the default kubeconfig otherwise
+             */
+            kubeconfig_path: string | null;
+            /** @enum {string} */
+            type: "Kubernetes";
+        } | {
+            /** @description Accept the self-signed certificate Proxmox VE installs by default */
+            allow_self_signed_certs: boolean;
+            /**
+             * @description API token in the form `user@realm!tokenid`. It needs the VM.Audit and Sys.Audit
+             *     privileges, and VM.Monitor to read guest agent addresses.
+             */
+            token_id: string;
+            /**
+             * @description File holding the token's secret, in the directory the daemon was started with as
+             *     `--proxmox-secrets-dir`, so the secret is never stored on the server. Relative paths
+             *     are taken from that directory.
+             */
+            token_secret_path: string;
+            /** @enum {string} */
+            type: "Proxmox";
+            /** @description Address of the API, ie "https://pve.lan:8006" */
+            url: string;
         };
         /** @description Progress update from daemon to server during discovery */
         DiscoveryUpdatePayload: {
@@ -3101,6 +4407,11 @@ export interface components {
             daemon_id: string;
             discovery_type: components["schemas"]["DiscoveryType"];
             error?: string | null;
+            /**
+             * @description Hosts the daemon has to leave alone, resolved when the session is queued so pull mode
+             *     daemons get them with the session. Updates from the daemon leave it empty.
+             */
+            exclusions?: components["schemas"]["ScanExclusions"];
             /** Format: date-time */
             finished_at?: string | null;
             /** Format: uuid */
@@ -3108,16 +4419,63 @@ export interface components {
             phase: components["schemas"]["DiscoveryPhase"];
             /** Format: int32 */
             progress: number;
+            /** @description Scan parameters the daemon adapted to for each subnet, reported when a session ends */
+            scan_tuning?: components["schemas"]["SubnetScanTuning"][];
             /** Format: uuid */
             session_id: string;
             /** Format: date-time */
             started_at?: string | null;
         };
+        /** @description A Docker engine reached over the network rather than through the daemon's local socket */
+        DockerEndpoint: {
+            tls: null | components["schemas"]["DockerTlsFiles"];
+            /**
+             * @description `tcp://`, `http://` or `https://` URL of the engine's API or a socket proxy in front of
+             *     it, or a `unix://` path to a socket in the directory the daemon was started with as
+             *     `--docker-endpoint-dir`. `ssh://` is out of scope: to reach an engine over SSH, forward
+             *     its socket into that directory yourself, ie
+             *     `ssh -NL /run/scanopy/docker/nas.sock:/var/run/docker.sock admin@nas`, keep the tunnel up
+             *     while discovery runs, and give the local end as `unix:///run/scanopy/docker/nas.sock`.
+             */
+            url: string;
+        };
+        /** @enum {string} */
+        DockerHealthStatus: "Starting" | "Healthy" | "Unhealthy";
+        /** @enum {string} */
+        DockerRestartPolicy: "No" | "Always" | "UnlessStopped" | "OnFailure";
+        DockerStack: {
+            kind: components["schemas"]["DockerStackKind"];
+            /** @description Compose project or Swarm stack name */
+            name: string;
+            /** @description Service within the project or stack that the container runs */
+            service?: string | null;
+        };
+        /** @enum {string} */
+        DockerStackKind: "Compose" | "Swarm";
+        /**
+         * @description PEM files for a TLS-protected Docker engine, in the directory the daemon was started with as
+         *     `--docker-endpoint-dir`. Relative paths are taken from that directory.
+         */
+        DockerTlsFiles: {
+            /** @description CA the engine's certificate is verified against */
+            ca_path: string;
+            cert_path: string;
+            key_path: string;
+        };
         DockerVirtualization: {
             container_id?: string | null;
             container_name?: string | null;
+            health: null | components["schemas"]["DockerHealthStatus"];
+            /** @description Image the container was created from, without its tag, ie "ghcr.io/immich-app/server" */
+            image: string | null;
+            image_tag: string | null;
+            labels: {
+                [key: string]: string;
+            };
+            restart_policy: null | components["schemas"]["DockerRestartPolicy"];
             /** Format: uuid */
             service_id: string;
+            stack: null | components["schemas"]["DockerStack"];
         };
         Edge: components["schemas"]["EdgeType"] & {
             /** Format: uuid */
@@ -3170,11 +4528,56 @@ export interface components {
             source_binding_id: string;
             /** Format: uuid */
             target_binding_id: string;
+        } | {
+            /** @enum {string} */
+            edge_type: "PhysicalLink";
+            /** Format: uuid */
+            physical_link_id: string;
+            source_port?: string | null;
+            target_port?: string | null;
         };
         /** @enum {string} */
-        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke";
+        EdgeTypeDiscriminants: "Interface" | "HostVirtualization" | "ServiceVirtualization" | "RequestPath" | "HubAndSpoke" | "PhysicalLink";
+        /** @description One entry in the append-only change journal. `created_at` is when the change was made. */
+        EntityChange: components["schemas"]["EntityChangeBase"] & {
+            /** Format: date-time */
+            created_at: string;
+            /** Format: uuid */
+            id: string;
+        };
+        /** @description The base data for an EntityChange record (everything except id and created_at) */
+        EntityChangeBase: {
+            /** @description Who made the change, ie `user:<id>`, `daemon:<id>` or `system` */
+            changed_by: string;
+            /** Format: uuid */
+            entity_id: string;
+            entity_type: components["schemas"]["EntityDiscriminants"];
+            /**
+             * Format: uuid
+             * @description Host the entity belongs to. For hosts, the host itself.
+             */
+            host_id?: string | null;
+            /** Format: uuid */
+            network_id: string;
+            operation: components["schemas"]["ChangeOperation"];
+            /** @description The entity as it was after the change. For deletions, as it was just before. */
+            snapshot: Record<string, never>;
+        };
+        /** @description An entity that differs between the two ends of a diff */
+        EntityDiff: {
+            after?: Record<string, never> | null;
+            before?: Record<string, never> | null;
+            /** @description Top-level fields whose values differ. Empty for added and removed entities. */
+            changed_fields: string[];
+            /** Format: uuid */
+            entity_id: string;
+            entity_type: components["schemas"]["EntityDiscriminants"];
+            /** Format: uuid */
+            host_id?: string | null;
+            kind: components["schemas"]["DiffKind"];
+        };
         /** @enum {string} */
-        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Network" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "Discovery" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "Subnet" | "Group" | "Topology" | "Unknown";
+        EntityDiscriminants: "Organization" | "Invite" | "Share" | "Network" | "DaemonApiKey" | "UserApiKey" | "User" | "Tag" | "CustomServiceDefinition" | "SnmpCredential" | "Webhook" | "ScanProfile" | "ScanExclusion" | "Discovery" | "Daemon" | "Host" | "Service" | "Port" | "Binding" | "Interface" | "TlsCertificate" | "PhysicalLink" | "Subnet" | "Group" | "Topology" | "Unknown";
         EntityMetadata: {
             color: components["schemas"]["Color"];
             icon: string;
@@ -3195,6 +4598,11 @@ export interface components {
             metadata: components["schemas"]["DiscoveryMetadata"][];
             /** @enum {string} */
             type: "DiscoveryWithMatch";
+        } | {
+            metadata: components["schemas"]["DiscoveryMetadata"][];
+            reason: string;
+            /** @enum {string} */
+            type: "Inferred";
         } | {
             /** @enum {string} */
             type: "Unknown";
@@ -3254,6 +4662,7 @@ export interface components {
          * @example {
          *       "created_at": "2026-01-15T10:30:00Z",
          *       "description": "Primary web server",
+         *       "device_info": null,
          *       "hidden": false,
          *       "hostname": "web-server-01.local",
          *       "id": "550e8400-e29b-41d4-a716-446655440003",
@@ -3282,6 +4691,7 @@ export interface components {
          */
         HostBase: {
             description: string | null;
+            device_info?: null | components["schemas"]["DeviceInfo"];
             hidden: boolean;
             hostname: string | null;
             name: string;
@@ -3304,12 +4714,14 @@ export interface components {
          * @example {
          *       "created_at": "2026-01-15T10:30:00Z",
          *       "description": "Primary web server",
+         *       "device_info": null,
          *       "hidden": false,
          *       "hostname": "web-server-01.local",
          *       "id": "550e8400-e29b-41d4-a716-446655440003",
          *       "interfaces": [
          *         {
          *           "created_at": "2026-01-15T10:30:00Z",
+         *           "dhcp_lease": null,
          *           "host_id": "550e8400-e29b-41d4-a716-446655440003",
          *           "id": "550e8400-e29b-41d4-a716-446655440005",
          *           "ip_address": "192.168.1.100",
@@ -3317,6 +4729,7 @@ export interface components {
          *           "name": "eth0",
          *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *           "position": 0,
+         *           "rtt_ms": null,
          *           "subnet_id": "550e8400-e29b-41d4-a716-446655440004",
          *           "updated_at": "2026-01-15T10:30:00Z"
          *         }
@@ -3348,6 +4761,7 @@ export interface components {
             /** Format: date-time */
             created_at: string;
             description?: string | null;
+            device_info?: null | components["schemas"]["DeviceInfo"];
             hidden: boolean;
             hostname?: string | null;
             /** Format: uuid */
@@ -3364,15 +4778,35 @@ export interface components {
             updated_at: string;
             virtualization?: null | components["schemas"]["HostVirtualization"];
         };
+        /** @description A host and its children as they were at a point in time */
+        HostSnapshot: {
+            /** Format: date-time */
+            at: string;
+            bindings: Record<string, never>[];
+            host: Record<string, never>;
+            /** Format: uuid */
+            host_id: string;
+            interfaces: Record<string, never>[];
+            ports: Record<string, never>[];
+            services: Record<string, never>[];
+        };
         /** HostVirtualization */
         HostVirtualization: {
             details: components["schemas"]["ProxmoxVirtualization"];
             /** @enum {string} */
             type: "Proxmox";
         };
+        /** @description Request body for importing a definition file */
+        ImportServiceDefinitionRequest: {
+            /** @description Encoding of `source` */
+            format: components["schemas"]["DefinitionFormat"];
+            /** @description Definition file contents */
+            source: string;
+        };
         /**
          * @example {
          *       "created_at": "2026-01-15T10:30:00Z",
+         *       "dhcp_lease": null,
          *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
          *       "id": "550e8400-e29b-41d4-a716-446655440005",
          *       "ip_address": "192.168.1.100",
@@ -3380,6 +4814,7 @@ export interface components {
          *       "name": "eth0",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *       "position": 0,
+         *       "rtt_ms": null,
          *       "subnet_id": "550e8400-e29b-41d4-a716-446655440004",
          *       "updated_at": "2026-01-15T10:30:00Z"
          *     }
@@ -3393,6 +4828,7 @@ export interface components {
             readonly updated_at: string;
         };
         InterfaceBase: {
+            dhcp_lease?: null | components["schemas"]["DhcpLease"];
             /** Format: uuid */
             host_id: string;
             ip_address: string;
@@ -3405,6 +4841,12 @@ export interface components {
              * @description Position of this interface in the host's interface list (for ordering)
              */
             position?: number;
+            /**
+             * Format: int32
+             * @description Round trip time of an echo to this address, from the ping sweep of subnets the daemon
+             *     isn't attached to
+             */
+            readonly rtt_ms?: number | null;
             /** Format: uuid */
             subnet_id: string;
         };
@@ -3458,6 +4900,12 @@ export interface components {
             x: number;
             y: number;
         };
+        KubernetesVirtualization: {
+            namespace: string;
+            pod?: string | null;
+            /** @description Deployment, StatefulSet, DaemonSet or other controller the pod belongs to */
+            workload?: string | null;
+        };
         /** @description Login request from client */
         LoginRequest: {
             /** Format: email */
@@ -3518,6 +4966,16 @@ export interface components {
             organization_id: string;
             tags: string[];
         };
+        /** @description Everything that differs on a network between two points in time */
+        NetworkChangeDiff: {
+            changes: components["schemas"]["EntityDiff"][];
+            /** Format: date-time */
+            from: string;
+            /** Format: uuid */
+            network_id: string;
+            /** Format: date-time */
+            to: string;
+        };
         /** @description Network configuration for setup */
         NetworkSetup: {
             name: string;
@@ -3563,6 +5021,11 @@ export interface components {
             readonly updated_at: string;
         };
         OrganizationBase: {
+            /**
+             * Format: int32
+             * @description Days audit log entries are kept for. Set through the audit log retention endpoint.
+             */
+            readonly audit_log_retention_days: number;
             name: string;
             onboarding: components["schemas"]["TelemetryOperation"][];
             plan: null | components["schemas"]["BillingPlan"];
@@ -3579,7 +5042,7 @@ export interface components {
          *         "offset": 0,
          *         "total_count": 142
          *       },
-         *       "server_version": "0.13.5"
+         *       "server_version": "0.13.6"
          *     }
          */
         PaginatedApiMeta: {
@@ -3592,11 +5055,37 @@ export interface components {
             pagination: components["schemas"]["PaginationMeta"];
             /**
              * @description Server version (semver)
-             * @example 0.13.5
+             * @example 0.13.6
              */
             server_version: string;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_AuditLogEntry: {
+            data: (components["schemas"]["AuditLogEntryBase"] & {
+                /** Format: date-time */
+                created_at: string;
+                /** Format: uuid */
+                id: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_CustomServiceDefinition: {
+            data: (components["schemas"]["CustomServiceDefinitionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_DaemonResponse: {
             data: (components["schemas"]["DaemonBase"] & {
                 /** Format: date-time */
@@ -3613,6 +5102,18 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_EntityChange: {
+            data: (components["schemas"]["EntityChangeBase"] & {
+                /** Format: date-time */
+                created_at: string;
+                /** Format: uuid */
+                id: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_Group: {
             data: (components["schemas"]["GroupBase"] & {
                 /** Format: date-time */
@@ -3632,6 +5133,7 @@ export interface components {
                 /** Format: date-time */
                 created_at: string;
                 description?: string | null;
+                device_info?: null | components["schemas"]["DeviceInfo"];
                 hidden: boolean;
                 hostname?: string | null;
                 /** Format: uuid */
@@ -3653,6 +5155,34 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_ScanExclusion: {
+            data: (components["schemas"]["ScanExclusionBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_ScanProfile: {
+            data: (components["schemas"]["ScanProfileBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_Service: {
             data: (components["schemas"]["ServiceBase"] & {
                 /** Format: date-time */
@@ -3667,6 +5197,20 @@ export interface components {
             success: boolean;
         };
         /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_SnmpCredential: {
+            data: (components["schemas"]["SnmpCredentialBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
         PaginatedApiResponse_Subnet: {
             data: (components["schemas"]["SubnetBase"] & {
                 /** Format: date-time */
@@ -3736,12 +5280,38 @@ export interface components {
             meta: components["schemas"]["PaginatedApiMeta"];
             success: boolean;
         };
-        /**
-         * @description Pagination metadata returned with paginated responses.
-         * @example {
-         *       "has_more": true,
-         *       "limit": 50,
-         *       "offset": 0,
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_Webhook: {
+            data: (components["schemas"]["WebhookBase"] & {
+                /** Format: date-time */
+                readonly created_at: string;
+                /** Format: uuid */
+                readonly id: string;
+                /** Format: date-time */
+                readonly updated_at: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /** @description Response type for paginated list endpoints (pagination is always present in meta) */
+        PaginatedApiResponse_WebhookDelivery: {
+            data: (components["schemas"]["WebhookDeliveryBase"] & {
+                /** Format: date-time */
+                created_at: string;
+                /** Format: uuid */
+                id: string;
+            })[];
+            error?: string | null;
+            meta: components["schemas"]["PaginatedApiMeta"];
+            success: boolean;
+        };
+        /**
+         * @description Pagination metadata returned with paginated responses.
+         * @example {
+         *       "has_more": true,
+         *       "limit": 50,
+         *       "offset": 0,
          *       "total_count": 142
          *     }
          */
@@ -3785,6 +5355,117 @@ export interface components {
              */
             offset?: number | null;
         };
+        /**
+         * @description Declarative form of a match `Pattern`. Ports are written as `<number>/<protocol>`, ie
+         *     `8443/tcp`.
+         */
+        PatternSpec: {
+            patterns: components["schemas"]["PatternSpec"][];
+            /** @enum {string} */
+            type: "any_of";
+        } | {
+            patterns: components["schemas"]["PatternSpec"][];
+            /** @enum {string} */
+            type: "all_of";
+        } | {
+            pattern: components["schemas"]["PatternSpec"];
+            /** @enum {string} */
+            type: "not";
+        } | {
+            port: string;
+            /** @enum {string} */
+            type: "port";
+        } | {
+            contains: string;
+            path: string;
+            port: string;
+            status?: null | components["schemas"]["StatusRange"];
+            /** @enum {string} */
+            type: "endpoint";
+        } | {
+            header: string;
+            port?: string | null;
+            status?: null | components["schemas"]["StatusRange"];
+            /** @enum {string} */
+            type: "header";
+            value: string;
+        } | {
+            contains: string;
+            port?: string | null;
+            /** @enum {string} */
+            type: "banner";
+        } | {
+            /** @enum {string} */
+            type: "mac_vendor";
+            vendor: string;
+        } | {
+            service_type: string;
+            /** @enum {string} */
+            type: "mdns_service";
+        } | {
+            manufacturer: string;
+            model?: string | null;
+            /** @enum {string} */
+            type: "upnp_device";
+        };
+        /**
+         * @description A cable between a port on a managed device and a neighbor, as seen by the managed device.
+         *     The neighbor is identified by what the device knows about it; the topology matches that to
+         *     a host when it's built.
+         * @example {
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
+         *       "id": "550e8400-e29b-41d4-a716-446655440012",
+         *       "local_if_index": 10112,
+         *       "local_port": "Gi1/0/12",
+         *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+         *       "protocol": "Lldp",
+         *       "remote_ip_address": "192.168.1.2",
+         *       "remote_mac_address": "00:1A:2B:3C:4D:5E",
+         *       "remote_port": "Gi0/24",
+         *       "remote_system_name": "access-switch-2",
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        PhysicalLink: components["schemas"]["PhysicalLinkBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a PhysicalLink entity (everything except id, created_at, updated_at) */
+        PhysicalLinkBase: {
+            /**
+             * Format: uuid
+             * @description The switch (or other managed device) that reported the link
+             */
+            host_id: string;
+            /**
+             * Format: int32
+             * @description ifIndex of the reporting device's port
+             */
+            local_if_index?: number | null;
+            /** @description Name of the reporting device's port, ie `Gi1/0/12` */
+            local_port?: string | null;
+            /** Format: uuid */
+            network_id: string;
+            protocol: components["schemas"]["PhysicalLinkProtocol"];
+            /** @description Management address the neighbor advertised */
+            remote_ip_address?: string | null;
+            /** @description MAC address of the neighbor: the LLDP chassis ID or the forwarding table entry */
+            remote_mac_address?: string | null;
+            /** @description Name of the neighbor's port, if it advertised one */
+            remote_port?: string | null;
+            /** @description System name or device ID the neighbor advertised */
+            remote_system_name?: string | null;
+        };
+        /**
+         * @description Where a physical link was learned from
+         * @enum {string}
+         */
+        PhysicalLinkProtocol: "Lldp" | "Cdp" | "ForwardingTable";
         PlanConfig: {
             /** Format: int64 */
             base_cents: number;
@@ -3847,6 +5528,23 @@ export interface components {
             /** @description Transport protocol (Tcp or Udp) */
             protocol: components["schemas"]["TransportProtocol"];
         };
+        /** @description Which TCP ports a deep scan connects to */
+        PortSelection: {
+            /** @enum {string} */
+            type: "All";
+        } | {
+            /** Format: int32 */
+            count: number;
+            /** @enum {string} */
+            type: "Top";
+        } | {
+            ports: number[];
+            /** @enum {string} */
+            type: "List";
+        } | {
+            /** @enum {string} */
+            type: "ServiceDefinitions";
+        };
         /** @description Port type with number, protocol, and optional type identifier */
         PortType: {
             number: number;
@@ -3917,6 +5615,164 @@ export interface components {
             /** @enum {string} */
             type: "AdHoc";
         };
+        /**
+         * @description Hosts that discovery must never probe, ie a PLC or a printer that reboots when port scanned
+         * @example {
+         *       "arp_only": true,
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "description": "Reboot when port scanned",
+         *       "id": "550e8400-e29b-41d4-a716-446655440015",
+         *       "name": "Label printers",
+         *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+         *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+         *       "rule": {
+         *         "oui": "00:07:4D",
+         *         "type": "Oui"
+         *       },
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        ScanExclusion: components["schemas"]["ScanExclusionBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a ScanExclusion entity (everything except id, created_at, updated_at) */
+        ScanExclusionBase: {
+            /** @description Still record matching hosts that answer ARP, with only their address and MAC */
+            arp_only?: boolean;
+            description?: string | null;
+            name: string;
+            /**
+             * Format: uuid
+             * @description Network the exclusion applies to; every network in the organization when not set
+             */
+            network_id: string | null;
+            /** Format: uuid */
+            organization_id: string;
+            rule: components["schemas"]["ScanExclusionRule"];
+        };
+        /**
+         * @description Fields that scan exclusions can be ordered/grouped by.
+         * @enum {string}
+         */
+        ScanExclusionOrderField: "created_at" | "name" | "updated_at";
+        /** @description What a scan exclusion matches on */
+        ScanExclusionRule: {
+            ip: string;
+            /** @enum {string} */
+            type: "Ip";
+        } | {
+            cidr: string;
+            /** @enum {string} */
+            type: "Cidr";
+        } | {
+            mac: string;
+            /** @enum {string} */
+            type: "Mac";
+        } | {
+            oui: string;
+            /** @enum {string} */
+            type: "Oui";
+        } | {
+            /** Format: uuid */
+            tag_id: string;
+            /** @enum {string} */
+            type: "Tag";
+        };
+        /** @description An exclusion as a daemon enforces it */
+        ScanExclusionTarget: {
+            arp_only: boolean;
+            rule: components["schemas"]["ScanExclusionRule"];
+        };
+        /** @description The exclusions a discovery session honours, handed to the daemon with the session */
+        ScanExclusions: components["schemas"]["ScanExclusionTarget"][];
+        /**
+         * @description Reusable scan settings that network discoveries across an organization can refer to, ie a
+         *     quick sweep of common ports and a full weekly scan of the same subnets
+         * @example {
+         *       "connect_timeout_ms": 500,
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "description": "Common ports only, for hourly runs",
+         *       "id": "550e8400-e29b-41d4-a716-446655440014",
+         *       "max_concurrency": 100,
+         *       "name": "Quick sweep",
+         *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+         *       "ping_ports": [
+         *         22,
+         *         443
+         *       ],
+         *       "probe_endpoints": true,
+         *       "retries": 0,
+         *       "tcp_ports": {
+         *         "count": 1000,
+         *         "type": "Top"
+         *       },
+         *       "udp_probes": [
+         *         "Dns",
+         *         "Snmp"
+         *       ],
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        ScanProfile: components["schemas"]["ScanProfileBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a ScanProfile entity (everything except id, created_at, updated_at) */
+        ScanProfileBase: components["schemas"]["ScanSettings"] & {
+            description?: string | null;
+            name: string;
+            /** Format: uuid */
+            organization_id: string;
+        };
+        /**
+         * @description Fields that scan profiles can be ordered/grouped by.
+         * @enum {string}
+         */
+        ScanProfileOrderField: "created_at" | "name" | "updated_at";
+        /**
+         * @description How a network discovery scans each host it finds. The defaults are what discoveries without a
+         *     profile use.
+         */
+        ScanSettings: {
+            /**
+             * Format: int32
+             * @description How long to wait for a TCP connection before counting the port as closed
+             */
+            connect_timeout_ms?: number;
+            /**
+             * Format: int32
+             * @description Most connections open against one host at a time. Leave unset to let the daemon size it
+             *     from its file descriptor limit; lower it for devices that fall over under load.
+             */
+            max_concurrency?: number | null;
+            /**
+             * @description Ports connected to on hosts of routed subnets that don't answer an ICMP echo. A refused
+             *     connection counts too, so closed ports on a live host still give it away. Empty to only
+             *     trust ICMP.
+             */
+            ping_ports?: number[];
+            /**
+             * @description Request HTTP endpoints on open ports to identify web services, read the banners other
+             *     open ports greet clients with, and record the certificates presented by HTTPS ports
+             */
+            probe_endpoints?: boolean;
+            /**
+             * Format: int32
+             * @description Further attempts at a port whose connection timed out
+             */
+            retries?: number;
+            tcp_ports?: components["schemas"]["PortSelection"];
+            udp_probes?: components["schemas"]["UdpProbe"][];
+        };
         /** @description Server capabilities returned on startup/registration */
         ServerCapabilities: {
             /** @description Deprecation warnings for the daemon */
@@ -3930,14 +5786,14 @@ export interface components {
          * @example {
          *       "bindings": [
          *         {
-         *           "created_at": "2026-01-13T22:33:31.241392Z",
-         *           "id": "276d1c1c-f8e2-452f-80b6-30703f2a043e",
+         *           "created_at": "2026-10-18T19:41:53.448186121Z",
+         *           "id": "e0a108ec-5cdd-4086-9673-f49cb0265583",
          *           "interface_id": "550e8400-e29b-41d4-a716-446655440005",
          *           "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *           "port_id": "550e8400-e29b-41d4-a716-446655440006",
          *           "service_id": "550e8400-e29b-41d4-a716-446655440007",
          *           "type": "Port",
-         *           "updated_at": "2026-01-13T22:33:31.241392Z"
+         *           "updated_at": "2026-10-18T19:41:53.448186121Z"
          *         }
          *       ],
          *       "created_at": "2026-01-15T10:30:00Z",
@@ -3946,12 +5802,14 @@ export interface components {
          *       "name": "nginx",
          *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
          *       "position": 0,
-         *       "service_definition": "Veeam",
+         *       "product": null,
+         *       "service_definition": "Mealie",
          *       "source": {
          *         "type": "Manual"
          *       },
          *       "tags": [],
          *       "updated_at": "2026-01-15T10:30:00Z",
+         *       "version": null,
          *       "virtualization": null
          *     }
          */
@@ -3975,14 +5833,33 @@ export interface components {
              * @description Position of this service in the host's service list (for ordering)
              */
             position: number;
+            /** @description Software identified from the service's banner during discovery, ie "OpenSSH" */
+            product?: string | null;
             service_definition: string;
             /** @description Will be automatically set to Manual for creation through API */
             source: components["schemas"]["EntitySource"];
             tags: string[];
+            /** @description Software version identified from the service's banner during discovery, ie "9.6p1" */
+            version?: string | null;
             virtualization?: null | components["schemas"]["ServiceVirtualization"];
         };
         /** @enum {string} */
         ServiceCategory: "NetworkCore" | "NetworkAccess" | "NetworkSecurity" | "Storage" | "Backup" | "Media" | "HomeAutomation" | "Virtualization" | "DNS" | "VPN" | "Monitoring" | "AdBlock" | "ReverseProxy" | "Workstation" | "Mobile" | "IoT" | "Printer" | "Database" | "Development" | "Dashboard" | "MessageQueue" | "IdentityAndAccess" | "Office" | "ProjectManagement" | "Messaging" | "Conferencing" | "Telephony" | "Email" | "Publishing" | "Unknown" | "Custom" | "Scanopy" | "OpenPorts";
+        /**
+         * @description Declarative service definition, as stored per organization and as accepted in
+         *     JSON / TOML / YAML definition files.
+         */
+        ServiceDefinitionSpec: {
+            category: components["schemas"]["ServiceCategory"];
+            description?: string;
+            /** @description If service is not associated with a particular brand or vendor */
+            is_generic?: boolean;
+            logo_needs_white_background?: boolean;
+            /** @description URL of icon, or static path if serving from /logos */
+            logo_url?: string;
+            name: string;
+            pattern: components["schemas"]["PatternSpec"];
+        };
         /**
          * @description Input for creating or updating a service.
          *     Used in both CreateHostRequest and UpdateHostRequest.
@@ -4022,6 +5899,10 @@ export interface components {
             details: components["schemas"]["DockerVirtualization"];
             /** @enum {string} */
             type: "Docker";
+        } | {
+            details: components["schemas"]["KubernetesVirtualization"];
+            /** @enum {string} */
+            type: "Kubernetes";
         };
         /** @description Request body for setting all tags on an entity */
         SetTagsRequest: {
@@ -4072,6 +5953,77 @@ export interface components {
             show_inspect_panel: boolean;
             show_zoom_controls: boolean;
         };
+        /**
+         * @description SNMPv3 USM authentication protocol
+         * @enum {string}
+         */
+        SnmpAuthProtocol: "Md5" | "Sha1" | "Sha224" | "Sha256" | "Sha384" | "Sha512";
+        /**
+         * @description Credentials daemons on a network use to query devices over SNMP. Daemons try each of the
+         *     network's credentials in creation order and use the first one a device answers to.
+         *
+         *     Communities and passwords are encrypted at rest and write-only: they're blank in responses
+         *     to users, and left blank in an update to keep the current ones.
+         * @example {
+         *       "config": {
+         *         "auth": {
+         *           "password": "auth-password",
+         *           "protocol": "Sha256"
+         *         },
+         *         "privacy": {
+         *           "password": "privacy-password",
+         *           "protocol": "Aes128"
+         *         },
+         *         "username": "scanopy",
+         *         "version": "V3"
+         *       },
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "id": "550e8400-e29b-41d4-a716-446655440011",
+         *       "name": "Core switches",
+         *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        SnmpCredential: components["schemas"]["SnmpCredentialBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a SnmpCredential entity (everything except id, created_at, updated_at) */
+        SnmpCredentialBase: {
+            config: components["schemas"]["SnmpCredentialConfig"];
+            name: string;
+            /** Format: uuid */
+            network_id: string;
+        };
+        /** @description SNMP version and the secrets needed to query a device with it */
+        SnmpCredentialConfig: {
+            community: string;
+            /** @enum {string} */
+            version: "V2c";
+        } | {
+            auth?: null | components["schemas"]["SnmpV3Auth"];
+            privacy?: null | components["schemas"]["SnmpV3Privacy"];
+            username: string;
+            /** @enum {string} */
+            version: "V3";
+        };
+        /**
+         * @description SNMPv3 USM privacy (encryption) protocol
+         * @enum {string}
+         */
+        SnmpPrivProtocol: "Des" | "Aes128" | "Aes192" | "Aes256";
+        SnmpV3Auth: {
+            password: string;
+            protocol: components["schemas"]["SnmpAuthProtocol"];
+        };
+        SnmpV3Privacy: {
+            password: string;
+            protocol: components["schemas"]["SnmpPrivProtocol"];
+        };
         /**
          * @example {
          *       "cidr": "192.168.1.0/24",
@@ -4112,8 +6064,42 @@ export interface components {
          * @enum {string}
          */
         SubnetOrderField: "created_at" | "name" | "cidr" | "subnet_type" | "updated_at" | "network_id";
+        /**
+         * @description TCP scan parameters a daemon settled on for one subnet after adapting to its round trip
+         *     times and timeouts
+         */
+        SubnetScanTuning: {
+            /**
+             * Format: int64
+             * @description Connection attempts made
+             */
+            attempts: number;
+            /** @description Ports scanned concurrently per host */
+            batch_size: number;
+            cidr: string;
+            /**
+             * Format: int32
+             * @description Retries after a timeout
+             */
+            retries: number;
+            /**
+             * Format: int64
+             * @description Smoothed round trip time of answered connection attempts
+             */
+            smoothed_rtt_ms?: number | null;
+            /**
+             * Format: int64
+             * @description Connect timeout per port
+             */
+            timeout_ms: number;
+            /**
+             * Format: int64
+             * @description Connection attempts that timed out
+             */
+            timeouts: number;
+        };
         /** @enum {string} */
-        SubnetType: "Internet" | "Remote" | "Gateway" | "VpnTunnel" | "Dmz" | "Lan" | "WiFi" | "IoT" | "Guest" | "DockerBridge" | "MacVlan" | "IpVlan" | "Management" | "Storage" | "Unknown" | "None";
+        SubnetType: "Internet" | "Remote" | "Gateway" | "VpnTunnel" | "Dmz" | "Lan" | "WiFi" | "IoT" | "Guest" | "DockerBridge" | "MacVlan" | "IpVlan" | "Kubernetes" | "Management" | "Storage" | "Unknown" | "None";
         /**
          * @example {
          *       "color": "Green",
@@ -4147,7 +6133,31 @@ export interface components {
         TagOrderField: "created_at" | "name" | "color" | "updated_at";
         /** @enum {string} */
         TelemetryOperation: "OrgCreated" | "OnboardingModalCompleted" | "PlanSelected" | "PersonalPlanSelected" | "CommercialPlanSelected" | "FirstApiKeyCreated" | "FirstDaemonRegistered" | "FirstTopologyRebuild" | "CheckoutStarted" | "CheckoutCompleted" | "TrialStarted" | "TrialEnded" | "SubscriptionCancelled";
-        Topology: components["schemas"]["TopologyBase"] & {
+        /**
+         * @description Leaf certificate presented by a TLS-capable port
+         * @example {
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "fingerprint_sha256": "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+         *       "host_id": "550e8400-e29b-41d4-a716-446655440003",
+         *       "id": "550e8400-e29b-41d4-a716-446655440010",
+         *       "is_self_signed": true,
+         *       "issuer": "CN=nas.example.com",
+         *       "key_bits": 256,
+         *       "key_type": "Ec",
+         *       "network_id": "550e8400-e29b-41d4-a716-446655440002",
+         *       "not_after": "2027-01-15T10:30:00Z",
+         *       "not_before": "2026-01-15T10:30:00Z",
+         *       "port_id": "550e8400-e29b-41d4-a716-446655440006",
+         *       "serial_number": "4a:0b:6e:1f:92:3c:d5:07",
+         *       "subject": "CN=nas.example.com",
+         *       "subject_alt_names": [
+         *         "nas.example.com",
+         *         "192.168.1.100"
+         *       ],
+         *       "updated_at": "2026-01-15T10:30:00Z"
+         *     }
+         */
+        TlsCertificate: components["schemas"]["TlsCertificateBase"] & {
             /** Format: date-time */
             readonly created_at: string;
             /** Format: uuid */
@@ -4155,16 +6165,55 @@ export interface components {
             /** Format: date-time */
             readonly updated_at: string;
         };
-        TopologyBase: {
-            bindings: components["schemas"]["Binding"][];
-            edges: components["schemas"]["Edge"][];
-            groups: components["schemas"]["Group"][];
-            hosts: components["schemas"]["Host"][];
-            interfaces: components["schemas"]["Interface"][];
-            is_locked: boolean;
-            is_stale: boolean;
+        /** @description The base data for a TlsCertificate entity (everything except id, created_at, updated_at) */
+        TlsCertificateBase: {
+            /** @description Hex SHA-256 of the DER-encoded certificate */
+            fingerprint_sha256: string;
+            /** Format: uuid */
+            host_id: string;
+            /** @description Subject and issuer are the same name */
+            is_self_signed: boolean;
+            /** @description Issuer distinguished name */
+            issuer: string;
+            /**
+             * Format: int32
+             * @description Key size in bits, if known for the key type
+             */
+            key_bits?: number | null;
+            key_type: components["schemas"]["CertificateKeyType"];
+            /** Format: uuid */
+            network_id: string;
             /** Format: date-time */
-            last_refreshed: string;
+            not_after: string;
+            /** Format: date-time */
+            not_before: string;
+            /** Format: uuid */
+            port_id: string;
+            /** @description Serial number as colon-separated hex */
+            serial_number: string;
+            /** @description Subject distinguished name, ie `CN=nas.example.com, O=Example` */
+            subject: string;
+            /** @description DNS names, IP addresses, emails and URIs from the subjectAltName extension */
+            subject_alt_names: string[];
+        };
+        Topology: components["schemas"]["TopologyBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        TopologyBase: {
+            bindings: components["schemas"]["Binding"][];
+            edges: components["schemas"]["Edge"][];
+            groups: components["schemas"]["Group"][];
+            hosts: components["schemas"]["Host"][];
+            interfaces: components["schemas"]["Interface"][];
+            is_locked: boolean;
+            is_stale: boolean;
+            /** Format: date-time */
+            last_refreshed: string;
             /** Format: date-time */
             locked_at?: string | null;
             /** Format: uuid */
@@ -4227,6 +6276,12 @@ export interface components {
             left_zone_service_categories: components["schemas"]["ServiceCategory"][];
             show_gateway_in_left_zone: boolean;
         };
+        /**
+         * @description Probes traceroute discovery sends. Routers and firewalls treat them differently, so the one
+         *     that gets furthest depends on the path.
+         * @enum {string}
+         */
+        TracerouteProtocol: "Udp" | "Icmp" | "Tcp";
         /** @enum {string} */
         TransportProtocol: "Udp" | "Tcp";
         TypeMetadata: {
@@ -4238,6 +6293,12 @@ export interface components {
             metadata: unknown;
             name: string | null;
         };
+        /**
+         * @description UDP services probed on each host. UDP ports only answer a request in their own protocol, so
+         *     they are checked per protocol rather than by port.
+         * @enum {string}
+         */
+        UdpProbe: "Dns" | "Ntp" | "Dhcp" | "Snmp";
         UpdateEmailPasswordRequest: {
             /** Format: email */
             email?: string | null;
@@ -4368,6 +6429,105 @@ export interface components {
              */
             server_version: string;
         };
+        /**
+         * @description An endpoint that is sent signed HTTP requests when hosts, services or discovery runs in the
+         *     organization change.
+         * @example {
+         *       "created_at": "2026-01-15T10:30:00Z",
+         *       "enabled": true,
+         *       "events": [
+         *         "host_created",
+         *         "service_deleted",
+         *         "discovery_failed"
+         *       ],
+         *       "id": "550e8400-e29b-41d4-a716-446655440013",
+         *       "name": "New device alerts",
+         *       "network_ids": [],
+         *       "organization_id": "550e8400-e29b-41d4-a716-446655440001",
+         *       "secret": "whsec_0123456789abcdefghijklmnopqrstuv",
+         *       "updated_at": "2026-01-15T10:30:00Z",
+         *       "url": "https://hooks.example.com/scanopy"
+         *     }
+         */
+        Webhook: components["schemas"]["WebhookBase"] & {
+            /** Format: date-time */
+            readonly created_at: string;
+            /** Format: uuid */
+            readonly id: string;
+            /** Format: date-time */
+            readonly updated_at: string;
+        };
+        /** @description The base data for a Webhook entity (everything except id, created_at, updated_at) */
+        WebhookBase: {
+            enabled: boolean;
+            events: components["schemas"]["WebhookEventType"][];
+            name: string;
+            /** @description Only send events from these networks. Empty for every network in the organization. */
+            network_ids?: string[];
+            /** Format: uuid */
+            organization_id: string;
+            /**
+             * @description Key for the `X-Scanopy-Signature-256` header. Generated when left empty on create, and
+             *     kept when left empty on update. Stored encrypted, and only returned when the webhook is
+             *     created.
+             */
+            secret?: string;
+            /**
+             * @description Endpoint deliveries are POSTed to. It must resolve to a public address, and redirects
+             *     aren't followed.
+             */
+            url: string;
+        };
+        /** @description One attempt to get an event to a webhook, retried until it succeeds or runs out of attempts */
+        WebhookDelivery: components["schemas"]["WebhookDeliveryBase"] & {
+            /** Format: date-time */
+            created_at: string;
+            /** Format: uuid */
+            id: string;
+        };
+        /** @description The base data for a WebhookDelivery record (everything except id and created_at) */
+        WebhookDeliveryBase: {
+            /** Format: int32 */
+            attempts: number;
+            /** @description Why the last attempt failed */
+            error?: string | null;
+            /**
+             * Format: uuid
+             * @description ID of the event, shared by redeliveries
+             */
+            event_id: string;
+            event_type: components["schemas"]["WebhookEventType"];
+            /** Format: date-time */
+            last_attempt_at?: string | null;
+            /**
+             * Format: date-time
+             * @description When a pending delivery is next tried. None for deliveries not tried yet, which are due
+             *     straight away.
+             */
+            next_attempt_at?: string | null;
+            /** Format: uuid */
+            organization_id: string;
+            /** @description The request body, as sent */
+            payload: Record<string, never>;
+            /**
+             * Format: int32
+             * @description HTTP status of the last response, if the endpoint answered
+             */
+            response_status?: number | null;
+            status: components["schemas"]["WebhookDeliveryStatus"];
+            /** Format: uuid */
+            webhook_id: string;
+        };
+        /**
+         * @description Where a delivery is at
+         * @enum {string}
+         */
+        WebhookDeliveryStatus: "Pending" | "Succeeded" | "Failed";
+        /**
+         * @description Events a webhook can be sent for
+         * @enum {string}
+         */
+        WebhookEventType: "host_created" | "host_updated" | "host_deleted" | "interface_created" | "interface_updated" | "interface_deleted" | "port_created" | "port_updated" | "port_deleted" | "service_created" | "service_updated" | "service_deleted" | "subnet_created" | "subnet_updated" | "subnet_deleted" | "discovery_started" | "discovery_completed" | "discovery_failed" | "discovery_cancelled";
     };
     responses: never;
     parameters: never;
@@ -5168,6 +7328,117 @@ export interface operations {
             };
         };
     };
+    get_audit_logs: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by kind of event */
+                category?: null | components["schemas"]["AuditLogCategory"];
+                /** @description Filter by operation, ie `created` or `login_success` */
+                operation?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Filter by the ID of the entity acted on */
+                entity_id?: string | null;
+                /** @description Filter by kind of actor */
+                actor_type?: null | components["schemas"]["AuditActorType"];
+                /** @description Filter by the ID of the user, API key or daemon that performed the action */
+                actor_id?: string | null;
+                /** @description Filter by the user behind the action, including through their API keys */
+                actor_user_id?: string | null;
+                /** @description Only return entries after this time */
+                since?: string | null;
+                /** @description Only return entries at or before this time */
+                until?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of audit log entries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_AuditLogEntry"];
+                };
+            };
+            /** @description Your plan does not include audit logs */
+            402: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    export_audit_logs: {
+        parameters: {
+            query?: {
+                /** @description File format. Default: csv. */
+                format?: components["schemas"]["AuditLogExportFormat"];
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by kind of event */
+                category?: null | components["schemas"]["AuditLogCategory"];
+                /** @description Filter by operation, ie `created` or `login_success` */
+                operation?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Filter by the ID of the entity acted on */
+                entity_id?: string | null;
+                /** @description Filter by kind of actor */
+                actor_type?: null | components["schemas"]["AuditActorType"];
+                /** @description Filter by the ID of the user, API key or daemon that performed the action */
+                actor_id?: string | null;
+                /** @description Filter by the user behind the action, including through their API keys */
+                actor_user_id?: string | null;
+                /** @description Only return entries after this time */
+                since?: string | null;
+                /** @description Only return entries at or before this time */
+                until?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Audit log export */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "text/csv": string;
+                    "application/x-ndjson": string;
+                };
+            };
+            /** @description Your plan does not include audit logs */
+            402: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
     list_daemon_api_keys: {
         parameters: {
             query?: {
@@ -5893,15 +8164,13 @@ export interface operations {
             };
         };
     };
-    get_daemons: {
+    get_all_custom_service_definitions: {
         parameters: {
             query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
                 /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["DaemonOrderField"];
+                group_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
                 /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["DaemonOrderField"];
+                order_by?: null | components["schemas"]["CustomServiceDefinitionOrderField"];
                 /** @description Direction for order_by field (group_by always uses ASC). */
                 order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -5915,32 +8184,74 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of daemons */
+            /** @description List of custom service definitions */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_CustomServiceDefinition"];
                 };
             };
         };
     };
-    bulk_delete_daemons: {
+    create_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of daemons IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CustomServiceDefinition"];
+            };
+        };
+        responses: {
+            /** @description Custom service definition created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
+                };
+            };
+            /** @description Validation error in definition or pattern */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Definition name already exists in this organization */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_custom_service_definitions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of custom_service_definitions IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Daemons deleted */
+            /** @description CustomServiceDefinitions deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -5951,29 +8262,30 @@ export interface operations {
             };
         };
     };
-    get_daemon_by_id: {
+    import_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Daemon ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ImportServiceDefinitionRequest"];
+            };
+        };
         responses: {
-            /** @description Daemon found */
+            /** @description Custom service definition imported successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description Definition file could not be parsed or failed validation */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5981,8 +8293,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Daemon not found */
-            404: {
+            /** @description Definition name already exists in this organization */
+            409: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -5992,28 +8304,28 @@ export interface operations {
             };
         };
     };
-    delete_daemon: {
+    get_custom_service_definition_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Daemon ID */
+                /** @description CustomServiceDefinition ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Daemon deleted */
+            /** @description CustomServiceDefinition found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Daemon not found */
+            /** @description CustomServiceDefinition not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6024,63 +8336,32 @@ export interface operations {
             };
         };
     };
-    list_discoveries: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by daemon ID */
-                daemon_id?: string | null;
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of discoveries */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": {
-                        data: components["schemas"]["Discovery"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
-                };
-            };
-        };
-    };
-    create_discovery: {
+    update_custom_service_definition: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description CustomServiceDefinition ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Discovery"];
+                "application/json": components["schemas"]["CustomServiceDefinition"];
             };
         };
         responses: {
-            /** @description Discovery created successfully */
+            /** @description Custom service definition updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                    "application/json": components["schemas"]["ApiResponse_CustomServiceDefinition"];
                 };
             };
-            /** @description Can't create historical discovery */
+            /** @description Validation error in definition or pattern */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -6089,7 +8370,255 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
+            /** @description Custom service definition not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Definition name already exists in this organization */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_custom_service_definition: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description CustomServiceDefinition ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Custom service definition deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Custom service definition not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_daemons: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["DaemonOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["DaemonOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of daemons */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_DaemonResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_daemons: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of daemons IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Daemons deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+        };
+    };
+    get_daemon_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_DaemonResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_daemon: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Daemon ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Daemon deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Daemon not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_discoveries: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by daemon ID */
+                daemon_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of discoveries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["Discovery"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Discovery"];
+            };
+        };
+        responses: {
+            /** @description Discovery created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Discovery"];
+                };
+            };
+            /** @description Unsupported Docker endpoint */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
     };
     get_active_sessions: {
         parameters: {
@@ -6226,7 +8755,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_Discovery"];
                 };
             };
-            /** @description Can't update historical discovery */
+            /** @description Unsupported Docker endpoint */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -6319,17 +8848,21 @@ export interface operations {
             };
         };
     };
-    get_all_groups: {
+    get_entity_changes: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["GroupOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["GroupOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Filter by host ID. Includes changes to the host's interfaces, ports, services and bindings. */
+                host_id?: string | null;
+                /** @description Filter by the ID of the changed entity */
+                entity_id?: string | null;
+                /** @description Filter by entity type */
+                entity_type?: null | components["schemas"]["EntityDiscriminants"];
+                /** @description Only return changes made after this time */
+                since?: string | null;
+                /** @description Only return changes made at or before this time */
+                until?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -6341,20 +8874,137 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of groups */
+            /** @description List of entity changes */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Group"];
+                    "application/json": components["schemas"]["PaginatedApiResponse_EntityChange"];
                 };
             };
         };
     };
-    create_group: {
+    get_network_diff: {
         parameters: {
-            query?: never;
+            query: {
+                /** @description Network to compare */
+                network_id: string;
+                /** @description Start of the comparison. Either this or `from_discovery_id` is required. */
+                from?: string | null;
+                /** @description End of the comparison. Defaults to now. */
+                to?: string | null;
+                /** @description Start the comparison when this discovery run finished */
+                from_discovery_id?: string | null;
+                /** @description End the comparison when this discovery run finished */
+                to_discovery_id?: string | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Differences between the two points in time */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_NetworkChangeDiff"];
+                };
+            };
+            /** @description Missing or invalid range */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Discovery run not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_host_snapshot: {
+        parameters: {
+            query?: {
+                /** @description Point in time to rebuild the host at. Defaults to now. */
+                at?: string | null;
+            };
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host as it was at the given time */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostSnapshot"];
+                };
+            };
+            /** @description Host did not exist at the given time */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_groups: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["GroupOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of groups */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Group"];
+                };
+            };
+        };
+    };
+    create_group: {
+        parameters: {
+            query?: never;
             header?: never;
             path?: never;
             cookie?: never;
@@ -6473,10 +9123,815 @@ export interface operations {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_group: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Group ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Group deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Group not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_all_hosts: {
+        parameters: {
+            query?: {
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["HostOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of hosts with their children */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
+                };
+            };
+        };
+    };
+    create_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description No access to the specified network */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_hosts: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of host IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Hosts deleted successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+            /** @description One or more hosts has an associated daemon - delete daemons first */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    create_host_discovery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["DiscoveryHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host discovered/updated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Daemon cannot create hosts on other networks */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    consolidate_hosts: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Destination host ID - will receive all children */
+                destination_host: string;
+                /** @description Host to merge into destination - will be deleted */
+                other_host: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Hosts consolidated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: same host, has daemon, or different networks */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description One or both hosts not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_host_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["UpdateHostRequest"];
+            };
+        };
+        responses: {
+            /** @description Host updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                };
+            };
+            /** @description Validation error: invalid tags */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_host: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Host ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Host deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Host not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Host has associated daemon */
+            409: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_interfaces: {
+        parameters: {
+            query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by subnet ID */
+                subnet_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of interfaces */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["Interface"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_interface: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Interface"];
+            };
+        };
+        responses: {
+            /** @description Interface created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                };
+            };
+            /** @description Network mismatch or invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    bulk_delete_interfaces: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Interfaces deleted successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+            /** @description No IDs provided */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_interface_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Interface ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Interface found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                };
+            };
+            /** @description Interface not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_interface: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Interface ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Interface"];
+            };
+        };
+        responses: {
+            /** @description Interface updated successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                };
+            };
+            /** @description Network mismatch or invalid request */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Interface not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_interface: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Interface ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Interface deleted successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Interface not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_invites: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of active invites */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
+                };
+            };
+        };
+    };
+    create_invite: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateInviteRequest"];
+            };
+        };
+        responses: {
+            /** @description Invite created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                };
+            };
+            /** @description Cannot create invite with higher permissions */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_invite: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Invite ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Invite details */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                };
+            };
+            /** @description Invalid or expired invite */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Access denied */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    revoke_invite: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Invite ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Invite revoked */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Invalid invite */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Cannot revoke this invite */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_networks: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of networks */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["Network"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    create_network: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Network"];
+            };
+        };
+        responses: {
+            /** @description Network created */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Network"];
+                };
+            };
+        };
+    };
+    bulk_delete_networks: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of network IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
+        responses: {
+            /** @description Networks deleted successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                };
+            };
+            /** @description User not admin */
+            403: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_network_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Network ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Network found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Group not found */
+            /** @description Network not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6487,29 +9942,33 @@ export interface operations {
             };
         };
     };
-    delete_group: {
+    update_network: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Group ID */
+                /** @description Network ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Network"];
+            };
+        };
         responses: {
-            /** @description Group deleted */
+            /** @description Network updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Network"];
                 };
             };
-            /** @description Group not found */
-            404: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6517,69 +9976,40 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_all_hosts: {
-        parameters: {
-            query?: {
-                /** @description Filter by network ID */
-                network_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns hosts that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["HostOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
-                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
-                limit?: number | null;
-                /** @description Number of results to skip. Default: 0. */
-                offset?: number | null;
-            };
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description List of hosts with their children */
-            200: {
+            /** @description Network not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    create_host: {
+    delete_network: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["CreateHostRequest"];
+            path: {
+                /** @description Network ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Host created successfully */
+            /** @description Network deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Validation error: network not found, subnet mismatch, or invalid tags */
-            400: {
+            /** @description User not admin */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6587,8 +10017,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description No access to the specified network */
-            401: {
+            /** @description Network not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6598,31 +10028,26 @@ export interface operations {
             };
         };
     };
-    bulk_delete_hosts: {
+    get_organization: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of host IDs to delete */
-        requestBody: {
-            content: {
-                "application/json": string[];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Hosts deleted successfully */
+            /** @description Organization details */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description One or more hosts has an associated daemon - delete daemons first */
-            409: {
+            /** @description Organization not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6632,29 +10057,32 @@ export interface operations {
             };
         };
     };
-    create_host_discovery: {
+    update_org_name: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
+            path: {
+                /** @description Organization ID */
+                id: string;
+            };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["DiscoveryHostRequest"];
+                "text/plain": string;
             };
         };
         responses: {
-            /** @description Host discovered/updated successfully */
+            /** @description Organization updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description Daemon cannot create hosts on other networks */
+            /** @description Only owners can update organization */
             403: {
                 headers: {
                     [name: string]: unknown;
@@ -6663,32 +10091,43 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
+            /** @description Organization not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
         };
     };
-    consolidate_hosts: {
+    update_audit_log_retention: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Destination host ID - will receive all children */
-                destination_host: string;
-                /** @description Host to merge into destination - will be deleted */
-                other_host: string;
+                /** @description Organization ID */
+                id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "text/plain": number;
+            };
+        };
         responses: {
-            /** @description Hosts consolidated successfully */
+            /** @description Organization updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Organization"];
                 };
             };
-            /** @description Validation error: same host, has daemon, or different networks */
+            /** @description Retention out of range */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -6697,8 +10136,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description One or both hosts not found */
-            404: {
+            /** @description Your plan does not include audit logs */
+            402: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6706,30 +10145,16 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    get_host_by_id: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Host ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody?: never;
-        responses: {
-            /** @description Host found */
-            200: {
+            /** @description Only owners can update organization */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Organization not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6740,33 +10165,29 @@ export interface operations {
             };
         };
     };
-    update_host: {
+    populate_demo_data: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["UpdateHostRequest"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Host updated */
+            /** @description Demo data populated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_HostResponse"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Validation error: invalid tags */
-            400: {
+            /** @description Only available for demo organizations */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6774,7 +10195,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host not found */
+            /** @description Organization not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6785,19 +10206,19 @@ export interface operations {
             };
         };
     };
-    delete_host: {
+    reset: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Host ID */
+                /** @description Organization ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Host deleted */
+            /** @description Organization reset */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6806,8 +10227,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Host not found */
-            404: {
+            /** @description Cannot reset another organization */
+            403: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6815,8 +10236,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Host has associated daemon */
-            409: {
+            /** @description Organization not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6826,13 +10247,11 @@ export interface operations {
             };
         };
     };
-    list_interfaces: {
+    list_physical_links: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
+                /** @description Filter by the host that reported the link */
                 host_id?: string | null;
-                /** @description Filter by subnet ID */
-                subnet_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
@@ -6846,14 +10265,14 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of interfaces */
+            /** @description List of physical_links */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
                     "application/json": {
-                        data: components["schemas"]["Interface"][];
+                        data: components["schemas"]["PhysicalLink"][];
                         error?: string | null;
                         meta: components["schemas"]["PaginatedApiMeta"];
                         success: boolean;
@@ -6862,53 +10281,21 @@ export interface operations {
             };
         };
     };
-    create_interface: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Interface"];
-            };
-        };
-        responses: {
-            /** @description Interface created successfully */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
-                };
-            };
-            /** @description Network mismatch or invalid request */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    bulk_delete_interfaces: {
+    bulk_delete_physical_links: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
+        /** @description Array of physical_links IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Interfaces deleted successfully */
+            /** @description PhysicalLinks deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -6917,39 +10304,30 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description No IDs provided */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
         };
     };
-    get_interface_by_id: {
+    get_physical_link_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description PhysicalLink ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Interface found */
+            /** @description PhysicalLink found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse_PhysicalLink"];
                 };
             };
-            /** @description Interface not found */
+            /** @description PhysicalLink not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -6960,33 +10338,29 @@ export interface operations {
             };
         };
     };
-    update_interface: {
+    delete_physical_link: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Interface ID */
+                /** @description PhysicalLink ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Interface"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Interface updated successfully */
+            /** @description PhysicalLink deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Interface"];
+                    "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Network mismatch or invalid request */
-            400: {
+            /** @description PhysicalLink not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -6994,40 +10368,68 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Interface not found */
-            404: {
+        };
+    };
+    list_ports: {
+        parameters: {
+            query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of ports */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": {
+                        data: components["schemas"]["Port"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    delete_interface: {
+    create_port: {
         parameters: {
             query?: never;
             header?: never;
-            path: {
-                /** @description Interface ID */
-                id: string;
-            };
+            path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Port"];
+            };
+        };
         responses: {
-            /** @description Interface deleted successfully */
+            /** @description Port created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Interface not found */
-            404: {
+            /** @description Network mismatch or duplicate port */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7037,50 +10439,54 @@ export interface operations {
             };
         };
     };
-    get_invites: {
+    bulk_delete_ports: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        /** @description Array of ports IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description List of active invites */
+            /** @description Ports deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Vec_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    create_invite: {
+    get_port_by_id: {
         parameters: {
             query?: never;
             header?: never;
-            path?: never;
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["CreateInviteRequest"];
+            path: {
+                /** @description Port ID */
+                id: string;
             };
+            cookie?: never;
         };
+        requestBody?: never;
         responses: {
-            /** @description Invite created */
+            /** @description Port found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Cannot create invite with higher permissions */
-            403: {
+            /** @description Port not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7090,28 +10496,32 @@ export interface operations {
             };
         };
     };
-    get_invite: {
+    update_port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Invite ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Port"];
+            };
+        };
         responses: {
-            /** @description Invite details */
+            /** @description Port updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Invite"];
+                    "application/json": components["schemas"]["ApiResponse_Port"];
                 };
             };
-            /** @description Invalid or expired invite */
+            /** @description Network mismatch or invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7120,8 +10530,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Access denied */
-            403: {
+            /** @description Port not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7131,19 +10541,19 @@ export interface operations {
             };
         };
     };
-    revoke_invite: {
+    delete_port: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Invite ID */
+                /** @description Port ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Invite revoked */
+            /** @description Port deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7152,17 +10562,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Invalid invite */
-            400: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Cannot revoke this invite */
-            403: {
+            /** @description Port not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7172,9 +10573,17 @@ export interface operations {
             };
         };
     };
-    list_networks: {
+    get_all_scan_exclusions: {
         parameters: {
             query?: {
+                /** @description Only the exclusions that apply on this network: its own and the organization-wide ones. */
+                network_id?: string | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["ScanExclusionOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["ScanExclusionOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7186,23 +10595,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of networks */
+            /** @description List of scan exclusions */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Network"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_ScanExclusion"];
                 };
             };
         };
     };
-    create_network: {
+    create_scan_exclusion: {
         parameters: {
             query?: never;
             header?: never;
@@ -7211,36 +10615,45 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["ScanExclusion"];
             };
         };
         responses: {
-            /** @description Network created */
+            /** @description Scan exclusion created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_ScanExclusion"];
+                };
+            };
+            /** @description Tag not found */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
         };
     };
-    bulk_delete_networks: {
+    bulk_delete_scan_exclusions: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of network IDs to delete */
+        /** @description Array of scan_exclusions IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Networks deleted successfully */
+            /** @description ScanExclusions deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7249,39 +10662,50 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
+        };
+    };
+    get_resolved_scan_exclusions: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Exclusions on the daemon's network */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_ScanExclusions"];
                 };
             };
         };
     };
-    get_network_by_id: {
+    get_scan_exclusion_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description ScanExclusion ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network found */
+            /** @description ScanExclusion found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_ScanExclusion"];
                 };
             };
-            /** @description Network not found */
+            /** @description ScanExclusion not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7292,33 +10716,33 @@ export interface operations {
             };
         };
     };
-    update_network: {
+    update_scan_exclusion: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description ScanExclusion ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Network"];
+                "application/json": components["schemas"]["ScanExclusion"];
             };
         };
         responses: {
-            /** @description Network updated */
+            /** @description Scan exclusion updated successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Network"];
+                    "application/json": components["schemas"]["ApiResponse_ScanExclusion"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description Tag not found */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7326,7 +10750,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Network not found */
+            /** @description Scan exclusion not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7337,19 +10761,19 @@ export interface operations {
             };
         };
     };
-    delete_network: {
+    delete_scan_exclusion: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Network ID */
+                /** @description ScanExclusion ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Network deleted */
+            /** @description ScanExclusion deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7358,8 +10782,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description User not admin */
-            403: {
+            /** @description ScanExclusion not found */
+            404: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7367,8 +10791,63 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Network not found */
-            404: {
+        };
+    };
+    get_all_scan_profiles: {
+        parameters: {
+            query?: {
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["ScanProfileOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["ScanProfileOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of scan profiles */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_ScanProfile"];
+                };
+            };
+        };
+    };
+    create_scan_profile: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ScanProfile"];
+            };
+        };
+        responses: {
+            /** @description Scan profile created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_ScanProfile"];
+                };
+            };
+            /** @description Invalid scan settings */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7378,26 +10857,31 @@ export interface operations {
             };
         };
     };
-    get_organization: {
+    bulk_delete_scan_profiles: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        requestBody?: never;
+        /** @description Array of scan_profiles IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description Organization details */
+            /** @description ScanProfiles deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
-            /** @description Organization not found */
-            404: {
+            /** @description A ScanProfile is used by a discovery */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7407,41 +10891,28 @@ export interface operations {
             };
         };
     };
-    update_org_name: {
+    get_scan_profile_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description ScanProfile ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "text/plain": string;
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Organization updated */
+            /** @description ScanProfile found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Organization"];
-                };
-            };
-            /** @description Only owners can update organization */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_ScanProfile"];
                 };
             };
-            /** @description Organization not found */
+            /** @description ScanProfile not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7452,37 +10923,32 @@ export interface operations {
             };
         };
     };
-    populate_demo_data: {
+    update_scan_profile: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description ScanProfile ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["ScanProfile"];
+            };
+        };
         responses: {
-            /** @description Demo data populated */
+            /** @description ScanProfile updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse"];
-                };
-            };
-            /** @description Only available for demo organizations */
-            403: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_ScanProfile"];
                 };
             };
-            /** @description Organization not found */
+            /** @description ScanProfile not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7493,19 +10959,19 @@ export interface operations {
             };
         };
     };
-    reset: {
+    delete_scan_profile: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Organization ID */
+                /** @description ScanProfile ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Organization reset */
+            /** @description ScanProfile deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7514,8 +10980,8 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Cannot reset another organization */
-            403: {
+            /** @description ScanProfile is used by a discovery */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -7523,7 +10989,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Organization not found */
+            /** @description ScanProfile not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7534,15 +11000,23 @@ export interface operations {
             };
         };
     };
-    list_ports: {
+    get_all_services: {
         parameters: {
             query?: {
-                /** @description Filter by host ID */
-                host_id?: string | null;
                 /** @description Filter by network ID */
                 network_id?: string | null;
+                /** @description Filter by host ID */
+                host_id?: string | null;
                 /** @description Filter by specific entity IDs (for selective loading) */
                 ids?: string[] | null;
+                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
+                tag_ids?: string[] | null;
+                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
+                group_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Secondary ordering field (sorting within groups or standalone sort). */
+                order_by?: null | components["schemas"]["ServiceOrderField"];
+                /** @description Direction for order_by field (group_by always uses ASC). */
+                order_direction?: null | components["schemas"]["OrderDirection"];
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7554,23 +11028,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of ports */
+            /** @description List of services */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Port"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_Service"];
                 };
             };
         };
     };
-    create_port: {
+    create_service: {
         parameters: {
             query?: never;
             header?: never;
@@ -7579,20 +11048,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "application/json": components["schemas"]["CreateServiceRequest"];
             };
         };
         responses: {
-            /** @description Port created successfully */
+            /** @description Service created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Network mismatch or duplicate port */
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7603,21 +11072,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_ports: {
+    bulk_delete_services: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of ports IDs to delete */
+        /** @description Array of services IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Ports deleted */
+            /** @description Services deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7628,28 +11097,28 @@ export interface operations {
             };
         };
     };
-    get_port_by_id: {
+    get_service_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port found */
+            /** @description Service found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Port not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7660,32 +11129,32 @@ export interface operations {
             };
         };
     };
-    update_port: {
+    update_service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Port"];
+                "application/json": components["schemas"]["Service"];
             };
         };
         responses: {
-            /** @description Port updated successfully */
+            /** @description Service updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Port"];
+                    "application/json": components["schemas"]["ApiResponse_Service"];
                 };
             };
-            /** @description Network mismatch or invalid request */
+            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7694,7 +11163,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Port not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7705,19 +11174,19 @@ export interface operations {
             };
         };
     };
-    delete_port: {
+    delete_service: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Port ID */
+                /** @description Service ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Port deleted */
+            /** @description Service deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7726,7 +11195,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Port not found */
+            /** @description Service not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7737,23 +11206,13 @@ export interface operations {
             };
         };
     };
-    get_all_services: {
+    list_shares: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by host ID */
-                host_id?: string | null;
-                /** @description Filter by specific entity IDs (for selective loading) */
-                ids?: string[] | null;
-                /** @description Filter by tag IDs (returns services that have ANY of the specified tags) */
-                tag_ids?: string[] | null;
-                /** @description Primary ordering field (used for grouping). Always sorts ASC to keep groups together. */
-                group_by?: null | components["schemas"]["ServiceOrderField"];
-                /** @description Secondary ordering field (sorting within groups or standalone sort). */
-                order_by?: null | components["schemas"]["ServiceOrderField"];
-                /** @description Direction for order_by field (group_by always uses ASC). */
-                order_direction?: null | components["schemas"]["OrderDirection"];
+                /** @description Filter by topology ID */
+                topology_id?: string | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7765,18 +11224,23 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of services */
+            /** @description List of shares */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["PaginatedApiResponse_Service"];
+                    "application/json": {
+                        data: components["schemas"]["Share"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
                 };
             };
         };
     };
-    create_service: {
+    create_share: {
         parameters: {
             query?: never;
             header?: never;
@@ -7785,20 +11249,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateServiceRequest"];
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
             };
         };
         responses: {
-            /** @description Service created successfully */
+            /** @description Share created */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_Share"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
+            /** @description Invalid request */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -7809,21 +11273,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_services: {
+    bulk_delete_shares: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of services IDs to delete */
+        /** @description Array of shares IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Services deleted */
+            /** @description Shares deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7834,28 +11298,28 @@ export interface operations {
             };
         };
     };
-    get_service_by_id: {
+    get_public_share_metadata: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service found */
+            /** @description Share metadata */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
                 };
             };
-            /** @description Service not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7866,41 +11330,109 @@ export interface operations {
             };
         };
     };
-    update_service: {
+    verify_share_password: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["Service"];
+                "text/plain": string;
             };
         };
         responses: {
-            /** @description Service updated */
+            /** @description Password verified */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Service"];
+                    "application/json": components["schemas"]["ApiResponse_bool"];
+                };
+            };
+            /** @description Invalid password */
+            401: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_share_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Share found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Share"];
+                };
+            };
+            /** @description Share not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-            /** @description Validation error: host network mismatch, cross-host binding, or binding conflict */
-            400: {
+        };
+    };
+    update_share: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Share ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+            };
+        };
+        responses: {
+            /** @description Share updated */
+            200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_Share"];
                 };
             };
-            /** @description Service not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7911,19 +11443,19 @@ export interface operations {
             };
         };
     };
-    delete_service: {
+    delete_share: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Service ID */
+                /** @description Share ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Service deleted */
+            /** @description Share deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -7932,7 +11464,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Service not found */
+            /** @description Share not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -7943,13 +11475,13 @@ export interface operations {
             };
         };
     };
-    list_shares: {
+    get_all_snmp_credentials: {
         parameters: {
             query?: {
                 /** @description Filter by network ID */
                 network_id?: string | null;
-                /** @description Filter by topology ID */
-                topology_id?: string | null;
+                /** @description Filter by specific entity IDs (for selective loading) */
+                ids?: string[] | null;
                 /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
                 limit?: number | null;
                 /** @description Number of results to skip. Default: 0. */
@@ -7961,23 +11493,18 @@ export interface operations {
         };
         requestBody?: never;
         responses: {
-            /** @description List of shares */
+            /** @description List of SNMP credentials */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": {
-                        data: components["schemas"]["Share"][];
-                        error?: string | null;
-                        meta: components["schemas"]["PaginatedApiMeta"];
-                        success: boolean;
-                    };
+                    "application/json": components["schemas"]["PaginatedApiResponse_SnmpCredential"];
                 };
             };
         };
     };
-    create_share: {
+    create_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
@@ -7986,20 +11513,20 @@ export interface operations {
         };
         requestBody: {
             content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
+                "application/json": components["schemas"]["SnmpCredential"];
             };
         };
         responses: {
-            /** @description Share created */
+            /** @description SNMP credential created successfully */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Invalid request */
+            /** @description Invalid credential */
             400: {
                 headers: {
                     [name: string]: unknown;
@@ -8010,21 +11537,21 @@ export interface operations {
             };
         };
     };
-    bulk_delete_shares: {
+    bulk_delete_snmp_credentials: {
         parameters: {
             query?: never;
             header?: never;
             path?: never;
             cookie?: never;
         };
-        /** @description Array of shares IDs to delete */
+        /** @description Array of snmp_credentials IDs to delete */
         requestBody: {
             content: {
                 "application/json": string[];
             };
         };
         responses: {
-            /** @description Shares deleted */
+            /** @description SnmpCredentials deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8035,28 +11562,28 @@ export interface operations {
             };
         };
     };
-    get_public_share_metadata: {
+    get_snmp_credential_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description SnmpCredential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share metadata */
+            /** @description SnmpCredential found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_PublicShareMetadata"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Share not found */
+            /** @description SnmpCredential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8067,74 +11594,33 @@ export interface operations {
             };
         };
     };
-    verify_share_password: {
+    update_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description SnmpCredential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody: {
             content: {
-                "text/plain": string;
-            };
-        };
-        responses: {
-            /** @description Password verified */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_bool"];
-                };
-            };
-            /** @description Invalid password */
-            401: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-            /** @description Share not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
-                };
-            };
-        };
-    };
-    get_share_by_id: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
+                "application/json": components["schemas"]["SnmpCredential"];
             };
-            cookie?: never;
         };
-        requestBody?: never;
         responses: {
-            /** @description Share found */
+            /** @description SNMP credential updated */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
+                    "application/json": components["schemas"]["ApiResponse_SnmpCredential"];
                 };
             };
-            /** @description Share not found */
-            404: {
+            /** @description Invalid credential */
+            400: {
                 headers: {
                     [name: string]: unknown;
                 };
@@ -8142,34 +11628,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiErrorResponse"];
                 };
             };
-        };
-    };
-    update_share: {
-        parameters: {
-            query?: never;
-            header?: never;
-            path: {
-                /** @description Share ID */
-                id: string;
-            };
-            cookie?: never;
-        };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["CreateUpdateShareRequest"];
-            };
-        };
-        responses: {
-            /** @description Share updated */
-            200: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiResponse_Share"];
-                };
-            };
-            /** @description Share not found */
+            /** @description SNMP credential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8180,19 +11639,19 @@ export interface operations {
             };
         };
     };
-    delete_share: {
+    delete_snmp_credential: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Share ID */
+                /** @description SnmpCredential ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Share deleted */
+            /** @description SnmpCredential deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8201,7 +11660,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Share not found */
+            /** @description SnmpCredential not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8637,54 +12096,184 @@ export interface operations {
             };
             cookie?: never;
         };
-        requestBody?: never;
+        requestBody?: never;
+        responses: {
+            /** @description Tag found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                };
+            };
+            /** @description Tag not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_tag: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Tag ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Tag"];
+            };
+        };
+        responses: {
+            /** @description Tag updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                };
+            };
+            /** @description Tag not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_tag: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Tag ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Tag deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Tag not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    list_tls_certificates: {
+        parameters: {
+            query?: {
+                /** @description Filter by host ID */
+                host_id?: string | null;
+                /** @description Filter by port ID */
+                port_id?: string | null;
+                /** @description Filter by network ID */
+                network_id?: string | null;
+                /**
+                 * @description Only return certificates that expire within this many days from now, including those
+                 *     that have already expired
+                 */
+                expiring_within_days?: number | null;
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of tls_certificates */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": {
+                        data: components["schemas"]["TlsCertificate"][];
+                        error?: string | null;
+                        meta: components["schemas"]["PaginatedApiMeta"];
+                        success: boolean;
+                    };
+                };
+            };
+        };
+    };
+    bulk_delete_tls_certificates: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        /** @description Array of tls_certificates IDs to delete */
+        requestBody: {
+            content: {
+                "application/json": string[];
+            };
+        };
         responses: {
-            /** @description Tag found */
+            /** @description TlsCertificates deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
-                };
-            };
-            /** @description Tag not found */
-            404: {
-                headers: {
-                    [name: string]: unknown;
-                };
-                content: {
-                    "application/json": components["schemas"]["ApiErrorResponse"];
+                    "application/json": components["schemas"]["ApiResponse_BulkDeleteResponse"];
                 };
             };
         };
     };
-    update_tag: {
+    get_tls_certificate_by_id: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description TlsCertificate ID */
                 id: string;
             };
             cookie?: never;
         };
-        requestBody: {
-            content: {
-                "application/json": components["schemas"]["Tag"];
-            };
-        };
+        requestBody?: never;
         responses: {
-            /** @description Tag updated */
+            /** @description TlsCertificate found */
             200: {
                 headers: {
                     [name: string]: unknown;
                 };
                 content: {
-                    "application/json": components["schemas"]["ApiResponse_Tag"];
+                    "application/json": components["schemas"]["ApiResponse_TlsCertificate"];
                 };
             };
-            /** @description Tag not found */
+            /** @description TlsCertificate not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -8695,19 +12284,19 @@ export interface operations {
             };
         };
     };
-    delete_tag: {
+    delete_tls_certificate: {
         parameters: {
             query?: never;
             header?: never;
             path: {
-                /** @description Tag ID */
+                /** @description TlsCertificate ID */
                 id: string;
             };
             cookie?: never;
         };
         requestBody?: never;
         responses: {
-            /** @description Tag deleted */
+            /** @description TlsCertificate deleted */
             200: {
                 headers: {
                     [name: string]: unknown;
@@ -8716,7 +12305,7 @@ export interface operations {
                     "application/json": components["schemas"]["ApiResponse"];
                 };
             };
-            /** @description Tag not found */
+            /** @description TlsCertificate not found */
             404: {
                 headers: {
                     [name: string]: unknown;
@@ -9301,6 +12890,271 @@ export interface operations {
             };
         };
     };
+    get_all_webhooks: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of webhooks */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_Webhook"];
+                };
+            };
+        };
+    };
+    create_webhook: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path?: never;
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Webhook"];
+            };
+        };
+        responses: {
+            /** @description Webhook created successfully */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
+                };
+            };
+            /** @description Invalid webhook */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Your plan does not include webhooks */
+            402: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_webhook_by_id: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Webhook ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Webhook found */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
+                };
+            };
+            /** @description Webhook not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    update_webhook: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Webhook ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody: {
+            content: {
+                "application/json": components["schemas"]["Webhook"];
+            };
+        };
+        responses: {
+            /** @description Webhook updated */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_Webhook"];
+                };
+            };
+            /** @description Invalid webhook */
+            400: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Your plan does not include webhooks */
+            402: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Webhook not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    delete_webhook: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Webhook ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Webhook deleted */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse"];
+                };
+            };
+            /** @description Webhook not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    get_webhook_deliveries: {
+        parameters: {
+            query?: {
+                /** @description Maximum number of results to return (1-1000, default: 50). Use 0 for no limit. */
+                limit?: number | null;
+                /** @description Number of results to skip. Default: 0. */
+                offset?: number | null;
+            };
+            header?: never;
+            path: {
+                /** @description Webhook ID */
+                id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description List of deliveries */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["PaginatedApiResponse_WebhookDelivery"];
+                };
+            };
+            /** @description Webhook not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
+    redeliver_webhook_delivery: {
+        parameters: {
+            query?: never;
+            header?: never;
+            path: {
+                /** @description Webhook ID */
+                id: string;
+                /** @description Delivery ID */
+                delivery_id: string;
+            };
+            cookie?: never;
+        };
+        requestBody?: never;
+        responses: {
+            /** @description Redelivery queued */
+            200: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiResponse_WebhookDelivery"];
+                };
+            };
+            /** @description Your plan does not include webhooks */
+            402: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+            /** @description Webhook or delivery not found */
+            404: {
+                headers: {
+                    [name: string]: unknown;
+                };
+                content: {
+                    "application/json": components["schemas"]["ApiErrorResponse"];
+                };
+            };
+        };
+    };
     get_version: {
         parameters: {
             query?: never;
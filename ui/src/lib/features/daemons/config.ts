@@ -247,6 +247,95 @@ export const fieldDefs: FieldDef[] = [
 		helpText: () => m.daemons_config_useNpcapArpHelp(),
 		section: () => m.daemons_config_sectionNetworkDiscovery()
 	},
+	{
+		id: 'passive_discovery',
+		label: () => m.daemons_config_passiveDiscovery(),
+		type: 'boolean',
+		defaultValue: false,
+		cliFlag: '--passive-discovery',
+		envVar: 'SCANOPY_PASSIVE_DISCOVERY',
+		helpText: () => m.daemons_config_passiveDiscoveryHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'passive_deep_scan',
+		label: () => m.daemons_config_passiveDeepScan(),
+		type: 'boolean',
+		defaultValue: false,
+		cliFlag: '--passive-deep-scan',
+		envVar: 'SCANOPY_PASSIVE_DEEP_SCAN',
+		helpText: () => m.daemons_config_passiveDeepScanHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'use_syn_scan',
+		label: () => m.daemons_config_useSynScan(),
+		type: 'boolean',
+		defaultValue: false,
+		cliFlag: '--use-syn-scan',
+		envVar: 'SCANOPY_USE_SYN_SCAN',
+		helpText: () => m.daemons_config_useSynScanHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'syn_rate_pps',
+		label: () => m.daemons_config_synPacketsPerSecond(),
+		type: 'number',
+		cliFlag: '--syn-rate-pps',
+		envVar: 'SCANOPY_SYN_RATE_PPS',
+		helpText: () => m.daemons_config_synPacketsPerSecondHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'dhcp_lease_dir',
+		label: () => m.daemons_config_dhcpLeaseDir(),
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--dhcp-lease-dir',
+		envVar: 'SCANOPY_DHCP_LEASE_DIR',
+		helpText: () => m.daemons_config_dhcpLeaseDirHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'proxmox_secrets_dir',
+		label: () => m.daemons_config_proxmoxSecretsDir(),
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--proxmox-secrets-dir',
+		envVar: 'SCANOPY_PROXMOX_SECRETS_DIR',
+		helpText: () => m.daemons_config_proxmoxSecretsDirHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'kubeconfig_dir',
+		label: () => m.daemons_config_kubeconfigDir(),
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--kubeconfig-dir',
+		envVar: 'SCANOPY_KUBECONFIG_DIR',
+		helpText: () => m.daemons_config_kubeconfigDirHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'allow_kubeconfig_exec',
+		label: () => m.daemons_config_allowKubeconfigExec(),
+		type: 'boolean',
+		defaultValue: false,
+		cliFlag: '--allow-kubeconfig-exec',
+		envVar: 'SCANOPY_ALLOW_KUBECONFIG_EXEC',
+		helpText: () => m.daemons_config_allowKubeconfigExecHelp(),
+		section: () => m.daemons_config_sectionNetworkDiscovery()
+	},
+	{
+		id: 'docker_endpoint_dir',
+		label: () => m.daemons_config_dockerEndpointDir(),
+		type: 'string',
+		defaultValue: '',
+		cliFlag: '--docker-endpoint-dir',
+		envVar: 'SCANOPY_DOCKER_ENDPOINT_DIR',
+		helpText: () => m.daemons_config_dockerEndpointDirHelp(),
+		section: () => m.daemons_config_sectionDockerDiscovery()
+	},
 	{
 		id: 'concurrentScans',
 		label: () => m.daemons_config_concurrentScans(),
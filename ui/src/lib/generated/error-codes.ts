@@ -45,6 +45,11 @@ export const ERROR_CODES = {
   discovery_historical_read_only: "Historical discovery cannot be modified via API",
   discovery_subnet_network_mismatch: "Subnet '{subnet}' is on a different network",
   discovery_session_not_found: "Discovery session '{id}' not found",
+  discovery_passive_not_schedulable: "Passive discovery is enabled in the daemon's config rather than scheduled",
+  discovery_traceroute_no_targets: "Traceroute discovery needs at least one target, or remote subnets to trace",
+  discovery_docker_endpoint_invalid: "Docker endpoint '{url}' must be a tcp://, http:// or unix:// URL, or https:// with TLS files. ssh:// isn't supported; forward the engine's socket into the daemon's --docker-endpoint-dir and give the local end as a unix:// path",
+  discovery_scan_profile_not_found: "Scan profile '{id}' not found",
+  scan_exclusion_tag_not_found: "Tag '{id}' not found",
   interface_ip_out_of_range: "IP address '{ip}' is not within subnet '{subnet}' range",
   daemon_network_mismatch: "Cannot send updates for a different network",
   daemon_identity_mismatch: "Cannot send updates for a different daemon",
@@ -105,6 +110,11 @@ export interface ErrorParams {
   discovery_historical_read_only: Record<string, never>;
   discovery_subnet_network_mismatch: { subnet: string | number };
   discovery_session_not_found: { id: string | number };
+  discovery_passive_not_schedulable: Record<string, never>;
+  discovery_traceroute_no_targets: Record<string, never>;
+  discovery_docker_endpoint_invalid: { url: string | number };
+  discovery_scan_profile_not_found: { id: string | number };
+  scan_exclusion_tag_not_found: { id: string | number };
   interface_ip_out_of_range: { ip: string | number; subnet: string | number };
   daemon_network_mismatch: Record<string, never>;
   daemon_identity_mismatch: Record<string, never>;
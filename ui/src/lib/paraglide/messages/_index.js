@@ -305,6 +305,8 @@ export * from './daemons_advancedconfiguration1.js'
 export * from './daemons_apikeyhelp2.js'
 export * from './daemons_binaryinstallation1.js'
 export * from './daemons_binaryinstallationhelp2.js'
+export * from './daemons_config_allowkubeconfigexec2.js'
+export * from './daemons_config_allowkubeconfigexechelp3.js'
 export * from './daemons_config_allowselfsignedcerts3.js'
 export * from './daemons_config_allowselfsignedcertshelp4.js'
 export * from './daemons_config_apikeyhelp2.js'
@@ -318,6 +320,10 @@ export * from './daemons_config_concurrentscans1.js'
 export * from './daemons_config_concurrentscanshelp2.js'
 export * from './daemons_config_daemonurl1.js'
 export * from './daemons_config_daemonurlhelp2.js'
+export * from './daemons_config_dhcpleasedir2.js'
+export * from './daemons_config_dhcpleasedirhelp3.js'
+export * from './daemons_config_dockerendpointdir2.js'
+export * from './daemons_config_dockerendpointdirhelp3.js'
 export * from './daemons_config_dockerproxy1.js'
 export * from './daemons_config_dockerproxyhelp2.js'
 export * from './daemons_config_dockerproxysslcert3.js'
@@ -329,6 +335,8 @@ export * from './daemons_config_dockerproxysslkeyhelp4.js'
 export * from './daemons_config_heartbeatinterval1.js'
 export * from './daemons_config_heartbeatintervalhelp2.js'
 export * from './daemons_config_interfaceshelp1.js'
+export * from './daemons_config_kubeconfigdir1.js'
+export * from './daemons_config_kubeconfigdirhelp2.js'
 export * from './daemons_config_loglevel1.js'
 export * from './daemons_config_loglevelhelp2.js'
 export * from './daemons_config_mode.js'
@@ -337,14 +345,24 @@ export * from './daemons_config_namehelp1.js'
 export * from './daemons_config_nameplaceholder1.js'
 export * from './daemons_config_networkid1.js'
 export * from './daemons_config_networkidhelp2.js'
+export * from './daemons_config_passivedeepscan2.js'
+export * from './daemons_config_passivedeepscanhelp3.js'
+export * from './daemons_config_passivediscovery1.js'
+export * from './daemons_config_passivediscoveryhelp2.js'
 export * from './daemons_config_porthelp1.js'
+export * from './daemons_config_proxmoxsecretsdir2.js'
+export * from './daemons_config_proxmoxsecretsdirhelp3.js'
 export * from './daemons_config_sectiondockerdiscovery2.js'
 export * from './daemons_config_sectionnetworkdiscovery2.js'
 export * from './daemons_config_sectionserverconnection2.js'
 export * from './daemons_config_serverurl1.js'
 export * from './daemons_config_serverurlhelp2.js'
+export * from './daemons_config_synpacketspersecond3.js'
+export * from './daemons_config_synpacketspersecondhelp4.js'
 export * from './daemons_config_usenpcaparp2.js'
 export * from './daemons_config_usenpcaparphelp3.js'
+export * from './daemons_config_usesynscan2.js'
+export * from './daemons_config_usesynscanhelp3.js'
 export * from './daemons_confirmbulkdelete2.js'
 export * from './daemons_confirmdelete1.js'
 export * from './daemons_createdaemon1.js'